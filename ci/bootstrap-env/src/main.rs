@@ -94,8 +94,11 @@ fn main() -> Result<()> {
                                 denom: DepositToken::VotingModuleToken {},
                                 amount: Uint128::new(1000000000),
                                 refund_policy: DepositRefundPolicy::OnlyPassed,
+                                additional: vec![],
+                                refund_on_cancel: false,
                             }),
                             open_proposal_submission: false,
+                            nft_deposit_info: None,
                             extension: Empty::default(),
                         })
                         .unwrap(),
@@ -104,6 +107,13 @@ fn main() -> Result<()> {
                     },
                 },
                 close_proposal_on_execution_failure: false,
+                max_proposal_size: None,
+                max_open_proposals: None,
+                pre_propose_fallback: None,
+                require_content_cid: None,
+                abstain_counting: None,
+                execution_delay: None,
+                vote_extension: None,
             })?,
             admin: Some(Admin::CoreModule {}),
             label: "DAO DAO Proposal Module".to_string(),
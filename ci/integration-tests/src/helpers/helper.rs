@@ -69,6 +69,13 @@ pub fn create_dao(
                 allow_revoting: false,
                 only_members_execute: true,
                 close_proposal_on_execution_failure: false,
+                max_proposal_size: None,
+                max_open_proposals: None,
+                pre_propose_fallback: None,
+                require_content_cid: None,
+                abstain_counting: None,
+                execution_delay: None,
+                vote_extension: None,
                 pre_propose_info: PreProposeInfo::ModuleMayPropose {
                     info: ModuleInstantiateInfo {
                         code_id: chain.orc.contract_map.code_id("dao_pre_propose_single")?,
@@ -77,8 +84,11 @@ pub fn create_dao(
                                 denom: DepositToken::VotingModuleToken {},
                                 amount: Uint128::new(1000000000),
                                 refund_policy: DepositRefundPolicy::OnlyPassed,
+                                additional: vec![],
+                                refund_on_cancel: false,
                             }),
                             open_proposal_submission: false,
+                            nft_deposit_info: None,
                             extension: Empty::default(),
                         })
                         .unwrap(),
@@ -97,9 +107,13 @@ pub fn create_dao(
         .orc
         .instantiate("dao_core", op_name, &msg, key, None, vec![])?;
 
-    let res = chain
-        .orc
-        .query("dao_core", &dao_core::msg::QueryMsg::DumpState {})?;
+    let res = chain.orc.query(
+        "dao_core",
+        &dao_core::msg::QueryMsg::DumpState {
+            start_after: None,
+            limit: None,
+        },
+    )?;
 
     Ok(DaoState {
         addr: chain.orc.contract_map.address("dao_core")?,
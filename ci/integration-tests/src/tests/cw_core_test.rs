@@ -42,6 +42,7 @@ fn execute_execute_admin_msgs(chain: &mut Chain) {
                 contract_addr: dao.addr,
                 msg: to_binary(&dao_core::msg::ExecuteMsg::Pause {
                     duration: Duration::Time(100),
+                    reason: None,
                 })
                 .unwrap(),
                 funds: vec![],
@@ -81,6 +82,7 @@ fn execute_execute_admin_msgs(chain: &mut Chain) {
                     contract_addr: dao.addr,
                     msg: to_binary(&dao_core::msg::ExecuteMsg::Pause {
                         duration: Duration::Height(100),
+                        reason: None,
                     })
                     .unwrap(),
                     funds: vec![],
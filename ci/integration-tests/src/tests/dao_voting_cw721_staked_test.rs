@@ -53,6 +53,8 @@ fn setup_test(
                 owner,
                 nft_address: cw721.clone(),
                 unstaking_duration,
+                ics721: None,
+                active_threshold: None,
             },
             key,
             None,
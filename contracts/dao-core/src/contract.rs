@@ -1,34 +1,59 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    from_binary, to_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Order,
-    Reply, Response, StdError, StdResult, SubMsg,
+    from_binary, to_binary, Addr, BankMsg, Binary, CosmosMsg, Deps, DepsMut, Empty, Env,
+    MessageInfo, Order, Reply, Response, StdError, StdResult, Storage, SubMsg, Uint128, WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version};
-use cw_storage_plus::Map;
-use cw_utils::{parse_reply_instantiate_data, Duration};
+use cw_denom::UncheckedDenom;
+use cw_storage_plus::{Bound, Map};
+use cw_utils::{parse_reply_instantiate_data, Duration, Expiration};
 
-use cw_paginate::{paginate_map, paginate_map_keys, paginate_map_values};
-use dao_interface::{voting, ModuleInstantiateCallback, ModuleInstantiateInfo};
+use cw_paginate::{
+    clamp_limit, count_map_keys, keyset_bounds, paginate_map, paginate_map_keys,
+    paginate_map_values, resolve_order, take_limit, PageOrder,
+};
+use dao_events::EventSource;
+use dao_interface::message_filter::{MessageFilterExecuteMsg, MessageFilterMsg};
+use dao_interface::proposal::{CreationPolicyQuery, ProposalCreationPolicy, ReplacementMsg};
+use dao_interface::querier::VotingModuleQuerier;
+use dao_interface::{ModuleInstantiateCallback, ModuleInstantiateInfo};
+use dao_pausable::PauseError;
 
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InitialItem, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::msg::{
+    ExecuteMsg, FromV1Msg, InitialItem, InstantiateMsg, MigrateMsg, QueryMsg, Section,
+    SweepDestination,
+};
+use crate::proto::{created_denom, fund_community_pool_msg};
 use crate::query::{
-    AdminNominationResponse, Cw20BalanceResponse, DaoURIResponse, DumpStateResponse,
-    GetItemResponse, PauseInfoResponse, SubDao,
+    AdminNominationResponse, CountResponse, Cw20BalanceResponse, Cw20BalancesResponse,
+    DaoURIResponse, DumpStateResponse, DumpStateSelectiveResponse, DustSweepResponse,
+    GetItemResponse, NativeBalancesResponse, PauseInfoResponse, PermissionGrant,
+    ProposalModuleCounts, ProposalModuleCountsResponse, SubDao, SubDaoNode,
 };
 use crate::state::{
-    Config, ProposalModule, ProposalModuleStatus, ACTIVE_PROPOSAL_MODULE_COUNT, ADMIN, CONFIG,
-    CW20_LIST, CW721_LIST, ITEMS, NOMINATED_ADMIN, PAUSED, PROPOSAL_MODULES, SUBDAO_LIST,
-    TOTAL_PROPOSAL_MODULE_COUNT, VOTING_MODULE,
+    Config, Executor, Permission, ProposalModule, ProposalModuleStatus, SpendLimit, SubDaoInfo,
+    ACTIVE_PROPOSAL_MODULE_COUNT, ADMIN, CONFIG, CW20_LIST, CW721_LIST, EXECUTION_PAUSED, EXECUTOR,
+    ITEMS, LEGACY_SUBDAO_LIST, MESSAGE_FILTERS, NATIVE_DENOM_LIST, NOMINATED_ADMIN, PAUSED,
+    PENDING_MIGRATION, PENDING_REPLACEMENT, PENDING_SUBDAO, PERMISSIONS, PROPOSAL_MODULES,
+    SPEND_LIMITS, SUBDAO_LIST, TOTAL_PROPOSAL_MODULE_COUNT, VOTING_MODULE,
 };
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-core";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+const EVENTS: EventSource = EventSource::new(CONTRACT_NAME, CONTRACT_VERSION);
+
 const PROPOSAL_MODULE_REPLY_ID: u64 = 0;
 const VOTE_MODULE_INSTANTIATE_REPLY_ID: u64 = 1;
 const VOTE_MODULE_UPDATE_REPLY_ID: u64 = 2;
+const PROPOSAL_MODULE_REPLACEMENT_REPLY_ID: u64 = 3;
+const SUBDAO_INSTANTIATE_REPLY_ID: u64 = 4;
+const MODULE_MIGRATION_REPLY_ID: u64 = 5;
+
+const DEFAULT_SUB_DAO_TREE_DEPTH: u32 = 2;
+const MAX_SUB_DAO_TREE_DEPTH: u32 = 5;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -82,7 +107,13 @@ pub fn instantiate(
 
     Ok(Response::new()
         .add_attribute("action", "instantiate")
-        .add_attribute("sender", info.sender)
+        .add_attribute("sender", info.sender.clone())
+        .add_event(
+            EVENTS
+                .event("instantiate")
+                .address("sender", &info.sender)
+                .into(),
+        )
         .add_submessage(vote_module_msg)
         .add_submessages(proposal_module_msgs))
 }
@@ -94,21 +125,32 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
-    // No actions can be performed while the DAO is paused.
-    if let Some(expiration) = PAUSED.may_load(deps.storage)? {
-        if !expiration.is_expired(&env.block) {
+    // No actions can be performed while the DAO is paused, other
+    // than the admin ending or extending the pause.
+    if !matches!(msg, ExecuteMsg::Unpause {} | ExecuteMsg::ExtendPause { .. }) {
+        if let PauseInfoResponse::Paused { .. } =
+            PAUSED.query_pause_info(deps.storage, &env.block)?
+        {
             return Err(ContractError::Paused {});
         }
     }
 
     match msg {
-        ExecuteMsg::ExecuteAdminMsgs { msgs } => {
-            execute_admin_msgs(deps.as_ref(), info.sender, msgs)
-        }
+        ExecuteMsg::ExecuteAdminMsgs { msgs } => execute_admin_msgs(deps, env, info.sender, msgs),
         ExecuteMsg::ExecuteProposalHook { msgs } => {
-            execute_proposal_hook(deps.as_ref(), info.sender, msgs)
+            execute_proposal_hook(deps, env, info.sender, msgs)
+        }
+        ExecuteMsg::Pause { duration, reason } => {
+            execute_pause(deps, env, info.sender, duration, reason)
         }
-        ExecuteMsg::Pause { duration } => execute_pause(deps, env, info.sender, duration),
+        ExecuteMsg::Unpause {} => execute_unpause(deps, env, info.sender),
+        ExecuteMsg::ExtendPause { expiration } => {
+            execute_extend_pause(deps, env, info.sender, expiration)
+        }
+        ExecuteMsg::PauseExecution { duration, reason } => {
+            execute_pause_execution(deps, env, info.sender, duration, reason)
+        }
+        ExecuteMsg::UnpauseExecution {} => execute_unpause_execution(deps, env, info.sender),
         ExecuteMsg::Receive(_) => execute_receive_cw20(deps, info.sender),
         ExecuteMsg::ReceiveNft(_) => execute_receive_cw721(deps, info.sender),
         ExecuteMsg::RemoveItem { key } => execute_remove_item(deps, env, info.sender, key),
@@ -122,12 +164,27 @@ pub fn execute(
         ExecuteMsg::UpdateCw721List { to_add, to_remove } => {
             execute_update_cw721_list(deps, env, info.sender, to_add, to_remove)
         }
+        ExecuteMsg::UpdateNativeDenomList { to_add, to_remove } => {
+            execute_update_native_denom_list(deps, env, info.sender, to_add, to_remove)
+        }
+        ExecuteMsg::RegisterDenom { denom } => {
+            execute_register_denom(deps, env, info.sender, denom)
+        }
         ExecuteMsg::UpdateVotingModule { module } => {
             execute_update_voting_module(env, info.sender, module)
         }
+        ExecuteMsg::MigrateModule {
+            module,
+            new_code_id,
+            msg,
+        } => execute_migrate_module(deps, env, info.sender, module, new_code_id, msg),
         ExecuteMsg::UpdateProposalModules { to_add, to_disable } => {
             execute_update_proposal_modules(deps, env, info.sender, to_add, to_disable)
         }
+        ExecuteMsg::ReplaceProposalModule {
+            address,
+            replacement,
+        } => execute_replace_proposal_module(deps, env, info.sender, address, replacement),
         ExecuteMsg::NominateAdmin { admin } => {
             execute_nominate_admin(deps, env, info.sender, admin)
         }
@@ -138,32 +195,451 @@ pub fn execute(
         ExecuteMsg::UpdateSubDaos { to_add, to_remove } => {
             execute_update_sub_daos_list(deps, env, info.sender, to_add, to_remove)
         }
+        ExecuteMsg::CreateSubDao {
+            instantiate_info,
+            charter,
+        } => execute_create_sub_dao(deps, env, info.sender, instantiate_info, charter),
+        ExecuteMsg::SetSubDaoActive { address, active } => {
+            execute_set_sub_dao_active(deps, env, info.sender, address, active)
+        }
+        ExecuteMsg::RemoveZeroBalances { limit } => {
+            execute_remove_zero_balances(deps, env, info.sender, limit)
+        }
+        ExecuteMsg::RepairProposalModuleCounts {} => {
+            execute_repair_proposal_module_counts(deps, env, info.sender)
+        }
+        ExecuteMsg::SweepDust {
+            threshold,
+            destination,
+        } => execute_sweep_dust(deps, env, info.sender, threshold, destination),
+        ExecuteMsg::AddMessageFilter { address } => {
+            execute_add_message_filter(deps, env, info.sender, address)
+        }
+        ExecuteMsg::RemoveMessageFilter { address } => {
+            execute_remove_message_filter(deps, env, info.sender, address)
+        }
+        ExecuteMsg::GrantPermission {
+            address,
+            permission,
+        } => execute_grant_permission(deps, env, info.sender, address, permission),
+        ExecuteMsg::RevokePermission {
+            address,
+            permission,
+        } => execute_revoke_permission(deps, env, info.sender, address, permission),
+        ExecuteMsg::SetSpendLimit {
+            address,
+            denom,
+            amount,
+            period,
+        } => execute_set_spend_limit(deps, env, info.sender, address, denom, amount, period),
+        ExecuteMsg::RemoveSpendLimit { address, denom } => {
+            execute_remove_spend_limit(deps, env, info.sender, address, denom)
+        }
+        ExecuteMsg::Spend {
+            denom,
+            amount,
+            recipient,
+        } => execute_spend(deps, env, info.sender, denom, amount, recipient),
+    }
+}
+
+/// Errors unless SENDER is the DAO or has been granted a permission
+/// for which ALLOWS returns true.
+fn assert_permitted(
+    storage: &dyn Storage,
+    env: &Env,
+    sender: &Addr,
+    allows: impl Fn(&Permission) -> bool,
+) -> Result<(), ContractError> {
+    if *sender == env.contract.address
+        || PERMISSIONS
+            .may_load(storage, sender)?
+            .unwrap_or_default()
+            .iter()
+            .any(allows)
+    {
+        Ok(())
+    } else {
+        Err(ContractError::Unauthorized {})
+    }
+}
+
+pub fn execute_grant_permission(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    address: String,
+    permission: Permission,
+) -> Result<Response, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let grantee = deps.api.addr_validate(&address)?;
+    let mut permissions = PERMISSIONS
+        .may_load(deps.storage, &grantee)?
+        .unwrap_or_default();
+    if permissions.contains(&permission) {
+        return Err(ContractError::PermissionAlreadyGranted {});
+    }
+    permissions.push(permission.clone());
+    PERMISSIONS.save(deps.storage, &grantee, &permissions)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_grant_permission")
+        .add_attribute("grantee", grantee.clone())
+        .add_event(
+            EVENTS
+                .event("grant_permission")
+                .address("grantee", &grantee)
+                .attribute("permission", permission.to_string())
+                .into(),
+        ))
+}
+
+pub fn execute_revoke_permission(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    address: String,
+    permission: Permission,
+) -> Result<Response, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let grantee = deps.api.addr_validate(&address)?;
+    let mut permissions = PERMISSIONS
+        .may_load(deps.storage, &grantee)?
+        .unwrap_or_default();
+    let len = permissions.len();
+    permissions.retain(|p| *p != permission);
+    if permissions.len() == len {
+        return Err(ContractError::PermissionNotGranted {});
+    }
+    if permissions.is_empty() {
+        PERMISSIONS.remove(deps.storage, &grantee);
+    } else {
+        PERMISSIONS.save(deps.storage, &grantee, &permissions)?;
+    }
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_revoke_permission")
+        .add_attribute("grantee", grantee.clone())
+        .add_event(
+            EVENTS
+                .event("revoke_permission")
+                .address("grantee", &grantee)
+                .attribute("permission", permission.to_string())
+                .into(),
+        ))
+}
+
+/// Gets the key DENOM's spend limits are stored under. The denom is
+/// not checked to exist, so limits for tokens which stop responding
+/// to queries may still be removed.
+fn spend_limit_key(deps: Deps, denom: UncheckedDenom) -> StdResult<String> {
+    match denom {
+        UncheckedDenom::Native(denom) => Ok(denom),
+        UncheckedDenom::Cw20(addr) => Ok(deps.api.addr_validate(&addr)?.into_string()),
     }
 }
 
+pub fn execute_set_spend_limit(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    address: String,
+    denom: UncheckedDenom,
+    amount: Uint128,
+    period: Duration,
+) -> Result<Response, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    if amount.is_zero() || matches!(period, Duration::Height(0) | Duration::Time(0)) {
+        return Err(ContractError::InvalidSpendLimit {});
+    }
+
+    let grantee = deps.api.addr_validate(&address)?;
+    let denom = denom.into_checked(deps.as_ref())?;
+    let limit = SpendLimit {
+        denom,
+        amount,
+        period,
+        spent: Uint128::zero(),
+        period_end: period.after(&env.block),
+    };
+    SPEND_LIMITS.save(deps.storage, (&grantee, limit.denom.to_string()), &limit)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_set_spend_limit")
+        .add_attribute("grantee", grantee.clone())
+        .add_event(
+            EVENTS
+                .event("set_spend_limit")
+                .address("grantee", &grantee)
+                .attribute("denom", limit.denom.to_string())
+                .attribute("amount", amount.to_string())
+                .into(),
+        ))
+}
+
+pub fn execute_remove_spend_limit(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    address: String,
+    denom: UncheckedDenom,
+) -> Result<Response, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let grantee = deps.api.addr_validate(&address)?;
+    let key = spend_limit_key(deps.as_ref(), denom)?;
+    if !SPEND_LIMITS.has(deps.storage, (&grantee, key.clone())) {
+        return Err(ContractError::NoSpendLimit {});
+    }
+    SPEND_LIMITS.remove(deps.storage, (&grantee, key.clone()));
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_remove_spend_limit")
+        .add_attribute("grantee", grantee.clone())
+        .add_event(
+            EVENTS
+                .event("remove_spend_limit")
+                .address("grantee", &grantee)
+                .attribute("denom", key)
+                .into(),
+        ))
+}
+
+pub fn execute_spend(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    denom: UncheckedDenom,
+    amount: Uint128,
+    recipient: String,
+) -> Result<Response, ContractError> {
+    if let PauseInfoResponse::Paused { .. } =
+        EXECUTION_PAUSED.query_pause_info(deps.storage, &env.block)?
+    {
+        return Err(ContractError::ExecutionPaused {});
+    }
+
+    let key = spend_limit_key(deps.as_ref(), denom)?;
+    let mut limit = SPEND_LIMITS
+        .may_load(deps.storage, (&sender, key.clone()))?
+        .ok_or(ContractError::NoSpendLimit {})?
+        .current(&env.block);
+    if amount > limit.remaining() {
+        return Err(ContractError::SpendLimitExceeded {
+            remaining: limit.remaining(),
+        });
+    }
+    limit.spent += amount;
+    SPEND_LIMITS.save(deps.storage, (&sender, key), &limit)?;
+
+    let recipient = deps.api.addr_validate(&recipient)?;
+    let transfer = limit.denom.get_transfer_to_message(&recipient, amount)?;
+    // Spends are held to the same policy as proposal execution.
+    let filters = message_filter_submsgs(deps.storage, &sender, &[transfer.clone()])?;
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_spend")
+        .add_attribute("sender", sender.clone())
+        .add_attribute("recipient", recipient.clone())
+        .add_event(
+            EVENTS
+                .event("spend")
+                .address("spender", &sender)
+                .address("recipient", &recipient)
+                .attribute("denom", limit.denom.to_string())
+                .attribute("amount", amount.to_string())
+                .into(),
+        )
+        .add_submessages(filters)
+        .add_message(transfer))
+}
+
 pub fn execute_pause(
     deps: DepsMut,
     env: Env,
     sender: Addr,
     pause_duration: Duration,
+    reason: Option<String>,
 ) -> Result<Response, ContractError> {
     // Only the core contract may call this method.
     if sender != env.contract.address {
         return Err(ContractError::Unauthorized {});
     }
 
-    let until = pause_duration.after(&env.block);
+    let paused_by = pauser(deps.storage, &env, &sender)?;
+    let until = PAUSED.pause(
+        deps.storage,
+        &env.block,
+        pause_duration,
+        paused_by.clone(),
+        reason.clone(),
+    )?;
 
-    PAUSED.save(deps.storage, &until)?;
+    let mut event = EVENTS
+        .event("pause")
+        .attribute("until", until.to_string())
+        .address("paused_by", &paused_by);
+    if let Some(reason) = reason {
+        event = event.attribute("reason", reason);
+    }
 
     Ok(Response::new()
         .add_attribute("action", "execute_pause")
         .add_attribute("sender", sender)
-        .add_attribute("until", until.to_string()))
+        .add_attribute("until", until.to_string())
+        .add_event(event.into()))
+}
+
+/// Errors unless SENDER is the DAO's admin and the admin is not the
+/// DAO itself. As the DAO can not act while paused, only such an
+/// admin may end or extend its pause.
+fn assert_pause_admin(
+    storage: &dyn Storage,
+    env: &Env,
+    sender: &Addr,
+) -> Result<(), ContractError> {
+    let admin = ADMIN.load(storage)?;
+    if admin != *sender || admin == env.contract.address {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(())
+}
+
+pub fn execute_unpause(deps: DepsMut, env: Env, sender: Addr) -> Result<Response, ContractError> {
+    assert_pause_admin(deps.storage, &env, &sender)?;
+    if let PauseInfoResponse::Unpaused {} = PAUSED.query_pause_info(deps.storage, &env.block)? {
+        return Err(PauseError::NotPaused {}.into());
+    }
+
+    PAUSED.unpause(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute("action", "execute_unpause")
+        .add_attribute("sender", sender.clone())
+        .add_event(
+            EVENTS
+                .event("unpause")
+                .address("unpaused_by", &sender)
+                .into(),
+        ))
+}
+
+pub fn execute_extend_pause(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    expiration: Expiration,
+) -> Result<Response, ContractError> {
+    assert_pause_admin(deps.storage, &env, &sender)?;
+    PAUSED.extend(deps.storage, &env.block, expiration)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "execute_extend_pause")
+        .add_attribute("sender", sender.clone())
+        .add_attribute("until", expiration.to_string())
+        .add_event(
+            EVENTS
+                .event("extend_pause")
+                .attribute("until", expiration.to_string())
+                .address("extended_by", &sender)
+                .into(),
+        ))
+}
+
+pub fn execute_pause_execution(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    pause_duration: Duration,
+    reason: Option<String>,
+) -> Result<Response, ContractError> {
+    assert_permitted(deps.storage, &env, &sender, |p| {
+        *p == Permission::PauseExecution {}
+    })?;
+
+    let paused_by = pauser(deps.storage, &env, &sender)?;
+    let until = EXECUTION_PAUSED.pause(
+        deps.storage,
+        &env.block,
+        pause_duration,
+        paused_by.clone(),
+        reason.clone(),
+    )?;
+
+    let mut event = EVENTS
+        .event("pause_execution")
+        .attribute("until", until.to_string())
+        .address("paused_by", &paused_by);
+    if let Some(reason) = reason {
+        event = event.attribute("reason", reason);
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "execute_pause_execution")
+        .add_attribute("sender", sender)
+        .add_attribute("until", until.to_string())
+        .add_event(event.into()))
+}
+
+pub fn execute_unpause_execution(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+) -> Result<Response, ContractError> {
+    if sender != env.contract.address {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    EXECUTION_PAUSED.unpause(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute("action", "execute_unpause_execution")
+        .add_event(EVENTS.event("unpause_execution").into()))
+}
+
+/// The address a pause made by SENDER is recorded as made by. If the
+/// DAO sent the pause message, it did so on behalf of whichever
+/// proposal module or admin had it execute messages during this
+/// transaction.
+fn pauser(storage: &dyn Storage, env: &Env, sender: &Addr) -> StdResult<Addr> {
+    Ok(match EXECUTOR.may_load(storage)? {
+        Some(executor)
+            if *sender == env.contract.address
+                && executor.height == env.block.height
+                && executor.transaction == env.transaction.as_ref().map(|t| t.index) =>
+        {
+            executor.address
+        }
+        _ => sender.clone(),
+    })
+}
+
+/// Records `executor` as the address the DAO is executing messages
+/// on behalf of.
+fn save_executor(storage: &mut dyn Storage, env: &Env, executor: &Addr) -> StdResult<()> {
+    EXECUTOR.save(
+        storage,
+        &Executor {
+            address: executor.clone(),
+            height: env.block.height,
+            transaction: env.transaction.as_ref().map(|t| t.index),
+        },
+    )
 }
 
 pub fn execute_admin_msgs(
-    deps: Deps,
+    deps: DepsMut,
+    env: Env,
     sender: Addr,
     msgs: Vec<CosmosMsg<Empty>>,
 ) -> Result<Response, ContractError> {
@@ -174,13 +650,46 @@ pub fn execute_admin_msgs(
         return Err(ContractError::Unauthorized {});
     }
 
-    Ok(Response::default()
+    save_executor(deps.storage, &env, &sender)?;
+
+    // The admin's messages are held to the same policy as proposal
+    // execution.
+    let filters = message_filter_submsgs(deps.storage, &sender, &msgs)?;
+    let response = register_created_denoms(deps.storage, &env, &msgs, Response::default())?;
+
+    Ok(response
         .add_attribute("action", "execute_admin_msgs")
+        .add_event(
+            EVENTS
+                .event("execute_admin_msgs")
+                .address("admin", &sender)
+                .into(),
+        )
+        .add_submessages(filters)
         .add_messages(msgs))
 }
 
+/// Registers the tokenfactory denoms created by MSGS with the
+/// treasury, adding a `register_denom` event to RESPONSE for each.
+fn register_created_denoms(
+    storage: &mut dyn Storage,
+    env: &Env,
+    msgs: &[CosmosMsg<Empty>],
+    mut response: Response,
+) -> StdResult<Response> {
+    for denom in msgs
+        .iter()
+        .filter_map(|msg| created_denom(msg, env.contract.address.as_str()))
+    {
+        NATIVE_DENOM_LIST.save(storage, denom.clone(), &Empty {})?;
+        response = response.add_event(EVENTS.event("register_denom").id("denom", denom).into());
+    }
+    Ok(response)
+}
+
 pub fn execute_proposal_hook(
-    deps: Deps,
+    deps: DepsMut,
+    env: Env,
     sender: Addr,
     msgs: Vec<CosmosMsg<Empty>>,
 ) -> Result<Response, ContractError> {
@@ -193,11 +702,102 @@ pub fn execute_proposal_hook(
         return Err(ContractError::ModuleDisabledCannotExecute { address: sender });
     }
 
-    Ok(Response::default()
+    if let PauseInfoResponse::Paused { .. } =
+        EXECUTION_PAUSED.query_pause_info(deps.storage, &env.block)?
+    {
+        return Err(ContractError::ExecutionPaused {});
+    }
+
+    save_executor(deps.storage, &env, &sender)?;
+
+    // Filters are called before the proposal's messages. If one
+    // errors the whole transaction is reverted, and proposal modules
+    // see an execution failure.
+    let filters = message_filter_submsgs(deps.storage, &sender, &msgs)?;
+    let response = register_created_denoms(deps.storage, &env, &msgs, Response::default())?;
+
+    Ok(response
         .add_attribute("action", "execute_proposal_hook")
+        .add_event(
+            EVENTS
+                .event("execute_proposal_hook")
+                .address("proposal_module", &sender)
+                .into(),
+        )
+        .add_submessages(filters)
         .add_messages(msgs))
 }
 
+/// Builds the messages which ask each of the DAO's message filters to
+/// check MSGS before they are executed on behalf of EXECUTOR.
+fn message_filter_submsgs(
+    storage: &dyn Storage,
+    executor: &Addr,
+    msgs: &[CosmosMsg<Empty>],
+) -> Result<Vec<SubMsg>, ContractError> {
+    let filter_msg = to_binary(&MessageFilterExecuteMsg::MessageFilterHook(
+        MessageFilterMsg::FilterMessages {
+            proposal_module: executor.to_string(),
+            msgs: msgs.to_vec(),
+        },
+    ))?;
+    Ok(MESSAGE_FILTERS.prepare_hooks(storage, |filter| {
+        Ok(SubMsg::new(WasmMsg::Execute {
+            contract_addr: filter.into_string(),
+            msg: filter_msg.clone(),
+            funds: vec![],
+        }))
+    })?)
+}
+
+pub fn execute_add_message_filter(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    address: String,
+) -> Result<Response, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let filter = deps.api.addr_validate(&address)?;
+    MESSAGE_FILTERS.add_hook(deps.storage, filter.clone())?;
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_add_message_filter")
+        .add_attribute("filter", filter.clone())
+        .add_event(
+            EVENTS
+                .event("add_message_filter")
+                .address("filter", &filter)
+                .into(),
+        ))
+}
+
+pub fn execute_remove_message_filter(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    address: String,
+) -> Result<Response, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let filter = deps.api.addr_validate(&address)?;
+    MESSAGE_FILTERS.remove_hook(deps.storage, filter.clone())?;
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_remove_message_filter")
+        .add_attribute("filter", filter.clone())
+        .add_event(
+            EVENTS
+                .event("remove_message_filter")
+                .address("filter", &filter)
+                .into(),
+        ))
+}
+
 pub fn execute_nominate_admin(
     deps: DepsMut,
     env: Env,
@@ -223,13 +823,17 @@ pub fn execute_nominate_admin(
         None => ADMIN.save(deps.storage, &env.contract.address)?,
     }
 
+    let nomination = nomination
+        .map(|n| n.to_string())
+        .unwrap_or_else(|| "None".to_string());
     Ok(Response::default()
         .add_attribute("action", "execute_nominate_admin")
-        .add_attribute(
-            "nomination",
-            nomination
-                .map(|n| n.to_string())
-                .unwrap_or_else(|| "None".to_string()),
+        .add_attribute("nomination", nomination.clone())
+        .add_event(
+            EVENTS
+                .event("nominate_admin")
+                .attribute("nomination", nomination)
+                .into(),
         ))
 }
 
@@ -248,7 +852,13 @@ pub fn execute_accept_admin_nomination(
 
     Ok(Response::default()
         .add_attribute("action", "execute_accept_admin_nomination")
-        .add_attribute("new_admin", sender))
+        .add_attribute("new_admin", sender.clone())
+        .add_event(
+            EVENTS
+                .event("accept_admin_nomination")
+                .address("admin", &sender)
+                .into(),
+        ))
 }
 
 pub fn execute_withdraw_admin_nomination(
@@ -270,7 +880,13 @@ pub fn execute_withdraw_admin_nomination(
 
     Ok(Response::default()
         .add_attribute("action", "execute_withdraw_admin_nomination")
-        .add_attribute("sender", sender))
+        .add_attribute("sender", sender.clone())
+        .add_event(
+            EVENTS
+                .event("withdraw_admin_nomination")
+                .address("sender", &sender)
+                .into(),
+        ))
 }
 
 pub fn execute_update_config(
@@ -289,13 +905,19 @@ pub fn execute_update_config(
     // simple to ask "when did this field in the config change" by
     // running something like `junod query txs --events
     // 'wasm._contract_address=core&wasm.name=name'`.
+    let image_url = config.image_url.unwrap_or_else(|| "None".to_string());
     Ok(Response::default()
         .add_attribute("action", "execute_update_config")
-        .add_attribute("name", config.name)
-        .add_attribute("description", config.description)
-        .add_attribute(
-            "image_url",
-            config.image_url.unwrap_or_else(|| "None".to_string()),
+        .add_attribute("name", config.name.clone())
+        .add_attribute("description", config.description.clone())
+        .add_attribute("image_url", image_url.clone())
+        .add_event(
+            EVENTS
+                .event("update_config")
+                .attribute("name", config.name)
+                .attribute("description", config.description)
+                .attribute("image_url", image_url)
+                .into(),
         ))
 }
 
@@ -313,6 +935,7 @@ pub fn execute_update_voting_module(
 
     Ok(Response::default()
         .add_attribute("action", "execute_update_voting_module")
+        .add_event(EVENTS.event("update_voting_module").into())
         .add_submessage(submessage))
 }
 
@@ -327,6 +950,8 @@ pub fn execute_update_proposal_modules(
         return Err(ContractError::Unauthorized {});
     }
 
+    let mut event = EVENTS.event("update_proposal_modules");
+
     let disable_count = to_disable.len() as u32;
     for addr in to_disable {
         let addr = deps.api.addr_validate(&addr)?;
@@ -342,7 +967,8 @@ pub fn execute_update_proposal_modules(
             });
         }
 
-        module.status = ProposalModuleStatus::Disabled {};
+        module.status = ProposalModuleStatus::Disabled;
+        event = event.address("disabled", &addr);
         PROPOSAL_MODULES.save(deps.storage, addr, &module)?;
     }
 
@@ -364,9 +990,139 @@ pub fn execute_update_proposal_modules(
 
     Ok(Response::default()
         .add_attribute("action", "execute_update_proposal_modules")
+        .add_event(event.into())
         .add_submessages(to_add))
 }
 
+pub fn execute_migrate_module(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    module: String,
+    new_code_id: u64,
+    msg: Binary,
+) -> Result<Response, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let module = deps.api.addr_validate(&module)?;
+    if !PROPOSAL_MODULES.has(deps.storage, module.clone())
+        && VOTING_MODULE.load(deps.storage)? != module
+    {
+        return Err(ContractError::ModuleNotRegistered { address: module });
+    }
+    PENDING_MIGRATION.save(deps.storage, &module)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_migrate_module")
+        .add_attribute("module", module.clone())
+        .add_attribute("new_code_id", new_code_id.to_string())
+        .add_submessage(SubMsg::reply_on_success(
+            WasmMsg::Migrate {
+                contract_addr: module.into_string(),
+                new_code_id,
+                msg,
+            },
+            MODULE_MIGRATION_REPLY_ID,
+        )))
+}
+
+pub fn execute_replace_proposal_module(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    address: String,
+    replacement: ModuleInstantiateInfo,
+) -> Result<Response, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let address = deps.api.addr_validate(&address)?;
+    let mut module = PROPOSAL_MODULES
+        .load(deps.storage, address.clone())
+        .map_err(|_| ContractError::ProposalModuleDoesNotExist {
+            address: address.clone(),
+        })?;
+    if module.status == ProposalModuleStatus::Disabled {
+        return Err(ContractError::ModuleAlreadyDisabled { address });
+    }
+    module.status = ProposalModuleStatus::Disabled;
+    PROPOSAL_MODULES.save(deps.storage, address.clone(), &module)?;
+    // The replacement is counted once it has been instantiated.
+    ACTIVE_PROPOSAL_MODULE_COUNT
+        .update::<_, StdError>(deps.storage, |count| Ok(count.saturating_sub(1)))?;
+    PENDING_REPLACEMENT.save(deps.storage, &address)?;
+
+    let wasm = replacement.into_wasm_msg(env.contract.address);
+    Ok(Response::default()
+        .add_attribute("action", "execute_replace_proposal_module")
+        .add_attribute("replaced", address)
+        .add_submessage(SubMsg::reply_on_success(
+            wasm,
+            PROPOSAL_MODULE_REPLACEMENT_REPLY_ID,
+        )))
+}
+
+pub fn execute_repair_proposal_module_counts(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+) -> Result<Response, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let stored = load_proposal_module_counts(deps.storage)?;
+    let computed = compute_proposal_module_counts(deps.storage)?;
+    ACTIVE_PROPOSAL_MODULE_COUNT.save(deps.storage, &computed.active)?;
+    TOTAL_PROPOSAL_MODULE_COUNT.save(deps.storage, &computed.total)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_repair_proposal_module_counts")
+        .add_attribute("active", computed.active.to_string())
+        .add_attribute("total", computed.total.to_string())
+        .add_event(
+            EVENTS
+                .event("repair_proposal_module_counts")
+                .attribute("previous_active", stored.active.to_string())
+                .attribute("previous_total", stored.total.to_string())
+                .attribute("active", computed.active.to_string())
+                .attribute("total", computed.total.to_string())
+                .into(),
+        ))
+}
+
+fn load_proposal_module_counts(storage: &dyn Storage) -> StdResult<ProposalModuleCounts> {
+    Ok(ProposalModuleCounts {
+        active: ACTIVE_PROPOSAL_MODULE_COUNT.load(storage)?,
+        total: TOTAL_PROPOSAL_MODULE_COUNT.load(storage)?,
+    })
+}
+
+/// Counts the proposal modules in `PROPOSAL_MODULES`. Modules are
+/// disabled rather than removed, so every module ever added is
+/// counted in the total.
+fn compute_proposal_module_counts(storage: &dyn Storage) -> StdResult<ProposalModuleCounts> {
+    PROPOSAL_MODULES
+        .range(storage, None, None, Order::Ascending)
+        .try_fold(
+            ProposalModuleCounts {
+                active: 0,
+                total: 0,
+            },
+            |counts, item| {
+                let (_, module) = item?;
+                Ok(ProposalModuleCounts {
+                    active: counts.active
+                        + u32::from(module.status == ProposalModuleStatus::Enabled),
+                    total: counts.total + 1,
+                })
+            },
+        )
+}
+
 /// Updates a set of addresses in state applying VERIFY to each item
 /// that will be added.
 fn do_update_addr_list(
@@ -404,9 +1160,9 @@ pub fn execute_update_cw20_list(
     to_add: Vec<String>,
     to_remove: Vec<String>,
 ) -> Result<Response, ContractError> {
-    if env.contract.address != sender {
-        return Err(ContractError::Unauthorized {});
-    }
+    assert_permitted(deps.storage, &env, &sender, |p| {
+        *p == Permission::UpdateCw20List {}
+    })?;
     do_update_addr_list(deps, CW20_LIST, to_add, to_remove, |addr, deps| {
         // Perform a balance query here as this is the query performed
         // by the `Cw20Balances` query.
@@ -418,26 +1174,216 @@ pub fn execute_update_cw20_list(
         )?;
         Ok(())
     })?;
-    Ok(Response::default().add_attribute("action", "update_cw20_list"))
+    Ok(Response::default()
+        .add_attribute("action", "update_cw20_list")
+        .add_event(EVENTS.event("update_cw20_list").into()))
 }
 
-pub fn execute_update_cw721_list(
+pub fn execute_remove_zero_balances(
     deps: DepsMut,
     env: Env,
     sender: Addr,
-    to_add: Vec<String>,
-    to_remove: Vec<String>,
+    limit: Option<u32>,
 ) -> Result<Response, ContractError> {
     if env.contract.address != sender {
         return Err(ContractError::Unauthorized {});
     }
+
+    let to_remove = {
+        let deps = deps.as_ref();
+        let removable = CW20_LIST
+            .keys(deps.storage, None, None, Order::Ascending)
+            .filter(|addr| match addr {
+                // Tokens which fail to respond to balance queries are
+                // removed as they would cause the `Cw20Balances`
+                // query to fail.
+                Ok(addr) => query_cw20_balance(deps, addr, &env.contract.address)
+                    .map_or(true, |balance| balance.is_zero()),
+                Err(_) => true,
+            });
+        match limit {
+            Some(limit) => removable
+                .take(limit as usize)
+                .collect::<StdResult<Vec<_>>>()?,
+            None => removable.collect::<StdResult<Vec<_>>>()?,
+        }
+    };
+
+    let mut event = EVENTS.event("remove_zero_balances");
+    for addr in &to_remove {
+        CW20_LIST.remove(deps.storage, addr.clone());
+        event = event.address("token", addr);
+    }
+
+    Ok(Response::default()
+        .add_attribute("action", "remove_zero_balances")
+        .add_attribute("removed", to_remove.len().to_string())
+        .add_event(event.into()))
+}
+
+pub fn execute_sweep_dust(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    threshold: Uint128,
+    destination: SweepDestination,
+) -> Result<Response, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let dust = find_dust(deps.as_ref(), &env, threshold, &destination)?;
+
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    if !dust.native.is_empty() {
+        let amount = dust.native.clone();
+        msgs.push(match &destination {
+            SweepDestination::Burn {} => BankMsg::Burn { amount }.into(),
+            SweepDestination::CommunityPool {} => {
+                fund_community_pool_msg(env.contract.address.as_str(), &amount)
+            }
+            SweepDestination::Address { address } => BankMsg::Send {
+                to_address: address.clone(),
+                amount,
+            }
+            .into(),
+        });
+    }
+    for Cw20BalanceResponse { addr, balance } in &dust.cw20 {
+        let msg = match &destination {
+            SweepDestination::Burn {} => cw20::Cw20ExecuteMsg::Burn { amount: *balance },
+            SweepDestination::Address { address } => cw20::Cw20ExecuteMsg::Transfer {
+                recipient: address.clone(),
+                amount: *balance,
+            },
+            // `find_dust` does not return cw20 balances for the
+            // community pool.
+            SweepDestination::CommunityPool {} => continue,
+        };
+        msgs.push(
+            WasmMsg::Execute {
+                contract_addr: addr.to_string(),
+                msg: to_binary(&msg)?,
+                funds: vec![],
+            }
+            .into(),
+        );
+    }
+
+    let destination = match destination {
+        SweepDestination::Burn {} => "burn".to_string(),
+        SweepDestination::CommunityPool {} => "community_pool".to_string(),
+        SweepDestination::Address { address } => address,
+    };
+    Ok(Response::default()
+        .add_messages(msgs)
+        .add_attribute("action", "sweep_dust")
+        .add_attribute("destination", destination.clone())
+        .add_event(
+            EVENTS
+                .event("sweep_dust")
+                .attribute("destination", destination)
+                .attribute("native", dust.native.len().to_string())
+                .attribute("cw20", dust.cw20.len().to_string())
+                .into(),
+        ))
+}
+
+/// Finds the balances which are greater than zero and less than
+/// THRESHOLD that would be swept to DESTINATION.
+fn find_dust(
+    deps: Deps,
+    env: &Env,
+    threshold: Uint128,
+    destination: &SweepDestination,
+) -> StdResult<DustSweepResponse> {
+    if let SweepDestination::Address { address } = destination {
+        deps.api.addr_validate(address)?;
+    }
+    let is_dust = |amount: Uint128| !amount.is_zero() && amount < threshold;
+
+    let native = deps
+        .querier
+        .query_all_balances(&env.contract.address)?
+        .into_iter()
+        .filter(|coin| is_dust(coin.amount))
+        .collect();
+
+    let mut cw20 = vec![];
+    if !matches!(destination, SweepDestination::CommunityPool {}) {
+        for addr in CW20_LIST.keys(deps.storage, None, None, Order::Ascending) {
+            let addr = addr?;
+            // Tokens which fail to respond to balance queries are
+            // skipped. `RemoveZeroBalances` removes them.
+            if let Ok(balance) = query_cw20_balance(deps, &addr, &env.contract.address) {
+                if is_dust(balance) {
+                    cw20.push(Cw20BalanceResponse { addr, balance });
+                }
+            }
+        }
+    }
+
+    Ok(DustSweepResponse { native, cw20 })
+}
+
+pub fn execute_update_cw721_list(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    to_add: Vec<String>,
+    to_remove: Vec<String>,
+) -> Result<Response, ContractError> {
+    assert_permitted(deps.storage, &env, &sender, |p| {
+        *p == Permission::UpdateCw721List {}
+    })?;
     do_update_addr_list(deps, CW721_LIST, to_add, to_remove, |addr, deps| {
         let _info: cw721::ContractInfoResponse = deps
             .querier
             .query_wasm_smart(addr, &cw721::Cw721QueryMsg::ContractInfo {})?;
         Ok(())
     })?;
-    Ok(Response::default().add_attribute("action", "update_cw721_list"))
+    Ok(Response::default()
+        .add_attribute("action", "update_cw721_list")
+        .add_event(EVENTS.event("update_cw721_list").into()))
+}
+
+pub fn execute_update_native_denom_list(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    to_add: Vec<String>,
+    to_remove: Vec<String>,
+) -> Result<Response, ContractError> {
+    assert_permitted(deps.storage, &env, &sender, |p| {
+        *p == Permission::UpdateNativeDenomList {}
+    })?;
+    for denom in to_add {
+        cw_denom::validate_native_denom(denom.clone())?;
+        NATIVE_DENOM_LIST.save(deps.storage, denom, &Empty {})?;
+    }
+    for denom in to_remove {
+        NATIVE_DENOM_LIST.remove(deps.storage, denom);
+    }
+    Ok(Response::default()
+        .add_attribute("action", "update_native_denom_list")
+        .add_event(EVENTS.event("update_native_denom_list").into()))
+}
+
+pub fn execute_register_denom(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    denom: String,
+) -> Result<Response, ContractError> {
+    assert_permitted(deps.storage, &env, &sender, |p| {
+        *p == Permission::UpdateNativeDenomList {}
+    })?;
+    cw_denom::validate_native_denom(denom.clone())?;
+    NATIVE_DENOM_LIST.save(deps.storage, denom.clone(), &Empty {})?;
+    Ok(Response::default()
+        .add_attribute("action", "register_denom")
+        .add_attribute("denom", denom.clone())
+        .add_event(EVENTS.event("register_denom").id("denom", denom).into()))
 }
 
 pub fn execute_set_item(
@@ -447,15 +1393,20 @@ pub fn execute_set_item(
     key: String,
     value: String,
 ) -> Result<Response, ContractError> {
-    if env.contract.address != sender {
-        return Err(ContractError::Unauthorized {});
-    }
+    assert_permitted(deps.storage, &env, &sender, |p| p.allows_item(&key))?;
 
     ITEMS.save(deps.storage, key.clone(), &value)?;
     Ok(Response::default()
         .add_attribute("action", "execute_set_item")
-        .add_attribute("key", key)
-        .add_attribute("addr", value))
+        .add_attribute("key", key.clone())
+        .add_attribute("addr", value.clone())
+        .add_event(
+            EVENTS
+                .event("set_item")
+                .id("key", key)
+                .attribute("value", value)
+                .into(),
+        ))
 }
 
 pub fn execute_remove_item(
@@ -464,15 +1415,14 @@ pub fn execute_remove_item(
     sender: Addr,
     key: String,
 ) -> Result<Response, ContractError> {
-    if env.contract.address != sender {
-        return Err(ContractError::Unauthorized {});
-    }
+    assert_permitted(deps.storage, &env, &sender, |p| p.allows_item(&key))?;
 
     if ITEMS.has(deps.storage, key.clone()) {
         ITEMS.remove(deps.storage, key.clone());
         Ok(Response::default()
             .add_attribute("action", "execute_remove_item")
-            .add_attribute("key", key))
+            .add_attribute("key", key.clone())
+            .add_event(EVENTS.event("remove_item").id("key", key).into()))
     } else {
         Err(ContractError::KeyMissing {})
     }
@@ -496,12 +1446,95 @@ pub fn execute_update_sub_daos_list(
 
     for subdao in to_add {
         let addr = deps.api.addr_validate(&subdao.addr)?;
-        SUBDAO_LIST.save(deps.storage, &addr, &subdao.charter)?;
+        // SubDAOs which are already listed keep the height they were
+        // added at and their status.
+        let info = match SUBDAO_LIST.may_load(deps.storage, &addr)? {
+            Some(info) => SubDaoInfo {
+                name: subdao.name,
+                charter: subdao.charter,
+                ..info
+            },
+            None => SubDaoInfo {
+                name: subdao.name,
+                charter: subdao.charter,
+                added_at: env.block.height,
+                active: true,
+            },
+        };
+        SUBDAO_LIST.save(deps.storage, &addr, &info)?;
     }
 
     Ok(Response::default()
         .add_attribute("action", "execute_update_sub_daos_list")
-        .add_attribute("sender", sender))
+        .add_attribute("sender", sender)
+        .add_event(EVENTS.event("update_sub_daos").into()))
+}
+
+pub fn execute_create_sub_dao(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    mut instantiate_info: ModuleInstantiateInfo,
+    charter: Option<String>,
+) -> Result<Response, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut msg: InstantiateMsg = from_binary(&instantiate_info.msg)?;
+    msg.admin = Some(env.contract.address.to_string());
+    instantiate_info.msg = to_binary(&msg)?;
+
+    PENDING_SUBDAO.save(
+        deps.storage,
+        &SubDaoInfo {
+            name: Some(msg.name),
+            charter,
+            added_at: env.block.height,
+            active: true,
+        },
+    )?;
+    let instantiate = SubMsg::reply_on_success(
+        instantiate_info.into_wasm_msg(env.contract.address),
+        SUBDAO_INSTANTIATE_REPLY_ID,
+    );
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_create_sub_dao")
+        .add_attribute("sender", sender)
+        .add_submessage(instantiate))
+}
+
+pub fn execute_set_sub_dao_active(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    address: String,
+    active: bool,
+) -> Result<Response, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let address = deps.api.addr_validate(&address)?;
+    let mut info = SUBDAO_LIST
+        .may_load(deps.storage, &address)?
+        .ok_or_else(|| ContractError::SubDaoDoesNotExist {
+            address: address.clone(),
+        })?;
+    info.active = active;
+    SUBDAO_LIST.save(deps.storage, &address, &info)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_set_sub_dao_active")
+        .add_attribute("sub_dao", address.clone())
+        .add_event(
+            EVENTS
+                .event("set_sub_dao_active")
+                .address("sub_dao", &address)
+                .attribute("active", active.to_string())
+                .into(),
+        ))
 }
 
 pub fn execute_receive_cw20(deps: DepsMut, sender: Addr) -> Result<Response, ContractError> {
@@ -512,7 +1545,13 @@ pub fn execute_receive_cw20(deps: DepsMut, sender: Addr) -> Result<Response, Con
         CW20_LIST.save(deps.storage, sender.clone(), &Empty {})?;
         Ok(Response::new()
             .add_attribute("action", "receive_cw20")
-            .add_attribute("token", sender))
+            .add_attribute("token", sender.clone())
+            .add_event(
+                EVENTS
+                    .event("receive_cw20")
+                    .address("token", &sender)
+                    .into(),
+            ))
     }
 }
 
@@ -524,7 +1563,13 @@ pub fn execute_receive_cw721(deps: DepsMut, sender: Addr) -> Result<Response, Co
         CW721_LIST.save(deps.storage, sender.clone(), &Empty {})?;
         Ok(Response::new()
             .add_attribute("action", "receive_cw721")
-            .add_attribute("token", sender))
+            .add_attribute("token", sender.clone())
+            .add_event(
+                EVENTS
+                    .event("receive_cw721")
+                    .address("token", &sender)
+                    .into(),
+            ))
     }
 }
 
@@ -534,18 +1579,48 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::Admin {} => query_admin(deps),
         QueryMsg::AdminNomination {} => query_admin_nomination(deps),
         QueryMsg::Config {} => query_config(deps),
-        QueryMsg::Cw20TokenList { start_after, limit } => query_cw20_list(deps, start_after, limit),
-        QueryMsg::Cw20Balances { start_after, limit } => {
-            query_cw20_balances(deps, env, start_after, limit)
+        QueryMsg::Cw20TokenList {
+            start_after,
+            limit,
+            order,
+        } => query_cw20_list(deps, start_after, limit, order),
+        QueryMsg::Cw20TokenCount {} => query_cw20_count(deps),
+        QueryMsg::Cw20Balances {
+            start_after,
+            limit,
+            min_balance,
+        } => query_cw20_balances(deps, env, start_after, limit, min_balance),
+        QueryMsg::NativeDenomList { start_after, limit } => {
+            query_native_denom_list(deps, start_after, limit)
+        }
+        QueryMsg::NativeBalances { start_after, limit } => {
+            query_native_balances(deps, env, start_after, limit)
         }
-        QueryMsg::Cw721TokenList { start_after, limit } => {
-            query_cw721_list(deps, start_after, limit)
+        QueryMsg::Cw721TokenList {
+            start_after,
+            limit,
+            order,
+        } => query_cw721_list(deps, start_after, limit, order),
+        QueryMsg::Cw721TokenCount {} => query_cw721_count(deps),
+        QueryMsg::DumpState { start_after, limit } => {
+            query_dump_state(deps, env, start_after, limit)
+        }
+        QueryMsg::DumpStateSelective { sections } => {
+            query_dump_state_selective(deps, env, sections)
         }
-        QueryMsg::DumpState {} => query_dump_state(deps, env),
         QueryMsg::GetItem { key } => query_get_item(deps, key),
         QueryMsg::Info {} => query_info(deps),
-        QueryMsg::ListItems { start_after, limit } => query_list_items(deps, start_after, limit),
+        QueryMsg::ListItems {
+            start_after,
+            limit,
+            prefix,
+            order,
+        } => query_list_items(deps, start_after, limit, prefix, order),
+        QueryMsg::ItemCount { prefix } => query_item_count(deps, prefix),
         QueryMsg::PauseInfo {} => query_paused(deps, env),
+        QueryMsg::ExecutionPauseInfo {} => {
+            to_binary(&EXECUTION_PAUSED.query_pause_info(deps.storage, &env.block)?)
+        }
         QueryMsg::ProposalModules { start_after, limit } => {
             query_proposal_modules(deps, start_after, limit)
         }
@@ -557,10 +1632,33 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::ActiveProposalModules { start_after, limit } => {
             query_active_proposal_modules(deps, start_after, limit)
         }
-        QueryMsg::ListSubDaos { start_after, limit } => {
-            query_list_sub_daos(deps, start_after, limit)
+        QueryMsg::ListSubDaos {
+            start_after,
+            limit,
+            active_only,
+            added_after,
+            order,
+        } => query_list_sub_daos(deps, start_after, limit, active_only, added_after, order),
+        QueryMsg::SubDaoCount {
+            active_only,
+            added_after,
+        } => query_sub_dao_count(deps, active_only, added_after),
+        QueryMsg::SubDaoInfo { address } => query_sub_dao_info(deps, address),
+        QueryMsg::ListSubDaosRecursive { max_depth, limit } => {
+            query_list_sub_daos_recursive(deps, env, max_depth, limit)
         }
         QueryMsg::DaoURI {} => query_dao_uri(deps),
+        QueryMsg::ProposalModuleCounts {} => query_proposal_module_counts(deps),
+        QueryMsg::DustSweep {
+            threshold,
+            destination,
+        } => to_binary(&find_dust(deps, &env, threshold, &destination)?),
+        QueryMsg::MessageFilters {} => to_binary(&MESSAGE_FILTERS.query_hooks(deps)?),
+        QueryMsg::Permissions { address } => query_permissions(deps, address),
+        QueryMsg::ListPermissions { start_after, limit } => {
+            query_list_permissions(deps, start_after, limit)
+        }
+        QueryMsg::SpendLimits { address } => query_spend_limits(deps, env, address),
     }
 }
 
@@ -642,30 +1740,42 @@ pub fn query_active_proposal_modules(
 }
 
 fn get_pause_info(deps: Deps, env: Env) -> StdResult<PauseInfoResponse> {
-    Ok(match PAUSED.may_load(deps.storage)? {
-        Some(expiration) => {
-            if expiration.is_expired(&env.block) {
-                PauseInfoResponse::Unpaused {}
-            } else {
-                PauseInfoResponse::Paused { expiration }
-            }
-        }
-        None => PauseInfoResponse::Unpaused {},
-    })
+    PAUSED.query_pause_info(deps.storage, &env.block)
 }
 
 pub fn query_paused(deps: Deps, env: Env) -> StdResult<Binary> {
     to_binary(&get_pause_info(deps, env)?)
 }
 
-pub fn query_dump_state(deps: Deps, env: Env) -> StdResult<Binary> {
+pub fn query_dump_state(
+    deps: Deps,
+    env: Env,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
     let admin = ADMIN.load(deps.storage)?;
     let config = CONFIG.load(deps.storage)?;
     let voting_module = VOTING_MODULE.load(deps.storage)?;
-    let proposal_modules = PROPOSAL_MODULES
-        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
-        .map(|kv| Ok(kv?.1))
-        .collect::<StdResult<Vec<ProposalModule>>>()?;
+    let start_after = start_after
+        .map(|s| deps.api.addr_validate(&s))
+        .transpose()?;
+    let (min, max) = keyset_bounds(start_after, Order::Ascending);
+    let proposal_modules = PROPOSAL_MODULES.range(deps.storage, min, max, Order::Ascending);
+    let (proposal_modules, next_start_after): (Vec<ProposalModule>, _) = match limit {
+        Some(limit) => {
+            let page = cw_paginate::page(proposal_modules, limit)?;
+            (
+                page.items.into_iter().map(|(_, module)| module).collect(),
+                page.next_key,
+            )
+        }
+        None => (
+            proposal_modules
+                .map(|kv| Ok(kv?.1))
+                .collect::<StdResult<_>>()?,
+            None,
+        ),
+    };
     let pause_info = get_pause_info(deps, env)?;
     let version = get_contract_version(deps.storage)?;
     let active_proposal_module_count = ACTIVE_PROPOSAL_MODULE_COUNT.load(deps.storage)?;
@@ -679,6 +1789,58 @@ pub fn query_dump_state(deps: Deps, env: Env) -> StdResult<Binary> {
         voting_module,
         active_proposal_module_count,
         total_proposal_module_count,
+        next_start_after,
+    })
+}
+
+pub fn query_dump_state_selective(
+    deps: Deps,
+    env: Env,
+    sections: Vec<Section>,
+) -> StdResult<Binary> {
+    let mut response = DumpStateSelectiveResponse {
+        admin: None,
+        config: None,
+        pause_info: None,
+        version: None,
+        proposal_modules: None,
+        voting_module: None,
+        active_proposal_module_count: None,
+        total_proposal_module_count: None,
+    };
+    for section in sections {
+        match section {
+            Section::Admin => response.admin = Some(ADMIN.load(deps.storage)?),
+            Section::Config => {
+                response.config = Some(CONFIG.load(deps.storage)?);
+                response.pause_info = Some(get_pause_info(deps, env.clone())?);
+            }
+            Section::Modules => {
+                response.proposal_modules = Some(
+                    PROPOSAL_MODULES
+                        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+                        .map(|kv| Ok(kv?.1))
+                        .collect::<StdResult<Vec<ProposalModule>>>()?,
+                );
+                response.voting_module = Some(VOTING_MODULE.load(deps.storage)?);
+                response.active_proposal_module_count =
+                    Some(ACTIVE_PROPOSAL_MODULE_COUNT.load(deps.storage)?);
+                response.total_proposal_module_count =
+                    Some(TOTAL_PROPOSAL_MODULE_COUNT.load(deps.storage)?);
+            }
+            Section::Version => response.version = Some(get_contract_version(deps.storage)?),
+        }
+    }
+    to_binary(&response)
+}
+
+pub fn query_proposal_module_counts(deps: Deps) -> StdResult<Binary> {
+    let stored = load_proposal_module_counts(deps.storage)?;
+    let computed = compute_proposal_module_counts(deps.storage)?;
+    to_binary(&ProposalModuleCountsResponse {
+        consistent: stored == computed,
+        stored,
+        computed,
     })
 }
 
@@ -688,18 +1850,15 @@ pub fn query_voting_power_at_height(
     height: Option<u64>,
 ) -> StdResult<Binary> {
     let voting_module = VOTING_MODULE.load(deps.storage)?;
-    let voting_power: voting::VotingPowerAtHeightResponse = deps.querier.query_wasm_smart(
-        voting_module,
-        &voting::Query::VotingPowerAtHeight { height, address },
-    )?;
+    let voting_power = VotingModuleQuerier::new(deps.querier, voting_module)
+        .voting_power_at_height(address, height)?;
     to_binary(&voting_power)
 }
 
 pub fn query_total_power_at_height(deps: Deps, height: Option<u64>) -> StdResult<Binary> {
     let voting_module = VOTING_MODULE.load(deps.storage)?;
-    let total_power: voting::TotalPowerAtHeightResponse = deps
-        .querier
-        .query_wasm_smart(voting_module, &voting::Query::TotalPowerAtHeight { height })?;
+    let total_power =
+        VotingModuleQuerier::new(deps.querier, voting_module).total_power_at_height(height)?;
     to_binary(&total_power)
 }
 
@@ -717,20 +1876,78 @@ pub fn query_list_items(
     deps: Deps,
     start_after: Option<String>,
     limit: Option<u32>,
+    prefix: Option<String>,
+    order: Option<PageOrder>,
 ) -> StdResult<Binary> {
-    to_binary(&paginate_map(
-        deps,
-        &ITEMS,
-        start_after,
-        limit,
-        cosmwasm_std::Order::Descending,
-    )?)
+    let order = resolve_order(order, Order::Descending);
+    let (min, max) = item_bounds(prefix, start_after, order);
+    let items: Vec<(String, String)> =
+        take_limit(ITEMS.range(deps.storage, min, max, order), limit)?;
+    to_binary(&items)
+}
+
+pub fn query_item_count(deps: Deps, prefix: Option<String>) -> StdResult<Binary> {
+    let (min, max) = item_bounds(prefix, None, Order::Ascending);
+    let count = ITEMS
+        .keys_raw(deps.storage, min, max, Order::Ascending)
+        .count() as u64;
+    to_binary(&CountResponse { count })
+}
+
+/// Computes range bounds for the items after `start_after` when
+/// iterating in `order`. If `prefix` is set and not empty, the range
+/// only includes items whose keys start with it.
+fn item_bounds<'a>(
+    prefix: Option<String>,
+    start_after: Option<String>,
+    order: Order,
+) -> (Option<Bound<'a, String>>, Option<Bound<'a, String>>) {
+    let prefix = match prefix {
+        Some(prefix) if !prefix.is_empty() => prefix,
+        _ => return keyset_bounds(start_after, order),
+    };
+    let end = prefix_end(prefix.as_bytes());
+
+    // `start_after` only tightens the range if it sorts within the
+    // prefix's keys. Otherwise, the range either includes every key
+    // with the prefix or is empty.
+    match (start_after, order) {
+        (Some(start_after), Order::Ascending) if start_after >= prefix => (
+            Some(Bound::exclusive(start_after)),
+            end.map(Bound::ExclusiveRaw),
+        ),
+        (Some(start_after), Order::Descending)
+            if end
+                .as_ref()
+                .map_or(true, |end| start_after.as_bytes() < end.as_slice()) =>
+        {
+            (
+                Some(Bound::inclusive(prefix)),
+                Some(Bound::exclusive(start_after)),
+            )
+        }
+        _ => (Some(Bound::inclusive(prefix)), end.map(Bound::ExclusiveRaw)),
+    }
+}
+
+/// The smallest key greater than every key starting with `prefix`,
+/// or `None` if there is no such key.
+fn prefix_end(prefix: &[u8]) -> Option<Vec<u8>> {
+    let mut end = prefix.to_vec();
+    while let Some(last) = end.pop() {
+        if last < u8::MAX {
+            end.push(last + 1);
+            return Some(end);
+        }
+    }
+    None
 }
 
 pub fn query_cw20_list(
     deps: Deps,
     start_after: Option<String>,
     limit: Option<u32>,
+    order: Option<PageOrder>,
 ) -> StdResult<Binary> {
     to_binary(&paginate_map_keys(
         deps,
@@ -739,14 +1956,65 @@ pub fn query_cw20_list(
             .map(|s| deps.api.addr_validate(&s))
             .transpose()?,
         limit,
+        resolve_order(order, Order::Descending),
+    )?)
+}
+
+pub fn query_cw20_count(deps: Deps) -> StdResult<Binary> {
+    to_binary(&CountResponse {
+        count: count_map_keys(deps, &CW20_LIST),
+    })
+}
+
+pub fn query_native_denom_list(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    to_binary(&paginate_map_keys(
+        deps,
+        &NATIVE_DENOM_LIST,
+        start_after,
+        limit,
         cosmwasm_std::Order::Descending,
     )?)
 }
 
+pub fn query_native_balances(
+    deps: Deps,
+    env: Env,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let (min, max) = keyset_bounds(start_after, Order::Descending);
+    let balances = NATIVE_DENOM_LIST
+        .keys(deps.storage, min, max, Order::Descending)
+        .map(|denom| {
+            let denom = denom?;
+            let balance = deps.querier.query_balance(&env.contract.address, &denom)?;
+            Ok((denom, balance))
+        });
+
+    let (balances, has_more, next_start_after) = match limit {
+        Some(limit) => {
+            let page = cw_paginate::page(balances, limit)?;
+            (page.items, page.has_more, page.next_key)
+        }
+        None => (balances.collect::<StdResult<Vec<_>>>()?, false, None),
+    };
+
+    to_binary(&NativeBalancesResponse {
+        balances: balances.into_iter().map(|(_, balance)| balance).collect(),
+        has_more,
+        next_start_after,
+    })
+}
+
 pub fn query_cw721_list(
     deps: Deps,
     start_after: Option<String>,
     limit: Option<u32>,
+    order: Option<PageOrder>,
 ) -> StdResult<Binary> {
     to_binary(&paginate_map_keys(
         deps,
@@ -755,69 +2023,260 @@ pub fn query_cw721_list(
             .map(|s| deps.api.addr_validate(&s))
             .transpose()?,
         limit,
-        cosmwasm_std::Order::Descending,
+        resolve_order(order, Order::Descending),
     )?)
 }
 
+pub fn query_cw721_count(deps: Deps) -> StdResult<Binary> {
+    to_binary(&CountResponse {
+        count: count_map_keys(deps, &CW721_LIST),
+    })
+}
+
+fn query_cw20_balance(deps: Deps, token: &Addr, holder: &Addr) -> StdResult<Uint128> {
+    let balance: cw20::BalanceResponse = deps.querier.query_wasm_smart(
+        token,
+        &cw20::Cw20QueryMsg::Balance {
+            address: holder.to_string(),
+        },
+    )?;
+    Ok(balance.balance)
+}
+
 pub fn query_cw20_balances(
     deps: Deps,
     env: Env,
     start_after: Option<String>,
     limit: Option<u32>,
+    min_balance: Option<Uint128>,
 ) -> StdResult<Binary> {
-    let addrs = paginate_map_keys(
-        deps,
-        &CW20_LIST,
-        start_after
-            .map(|a| deps.api.addr_validate(&a))
-            .transpose()?,
-        limit,
-        cosmwasm_std::Order::Descending,
-    )?;
-    let balances = addrs
-        .into_iter()
+    let start_after = start_after
+        .map(|a| deps.api.addr_validate(&a))
+        .transpose()?;
+    let (min, max) = keyset_bounds(start_after, Order::Descending);
+    let min_balance = min_balance.unwrap_or_default();
+
+    let balances = CW20_LIST
+        .keys(deps.storage, min, max, Order::Descending)
         .map(|addr| {
-            let balance: cw20::BalanceResponse = deps.querier.query_wasm_smart(
-                addr.clone(),
-                &cw20::Cw20QueryMsg::Balance {
-                    address: env.contract.address.to_string(),
-                },
-            )?;
-            Ok(Cw20BalanceResponse {
-                addr,
-                balance: balance.balance,
-            })
+            let addr = addr?;
+            let balance = query_cw20_balance(deps, &addr, &env.contract.address)?;
+            Ok((addr, balance))
         })
-        .collect::<StdResult<Vec<_>>>()?;
-    to_binary(&balances)
+        .filter(|item| !matches!(item, Ok((_, balance)) if *balance < min_balance));
+
+    // As tokens may be skipped, the number of balances returned does
+    // not tell the caller if there are more pages.
+    let (balances, has_more, next_start_after) = match limit {
+        Some(limit) => {
+            let page = cw_paginate::page(balances, limit)?;
+            (page.items, page.has_more, page.next_key)
+        }
+        None => (balances.collect::<StdResult<Vec<_>>>()?, false, None),
+    };
+
+    to_binary(&Cw20BalancesResponse {
+        balances: balances
+            .into_iter()
+            .map(|(addr, balance)| Cw20BalanceResponse { addr, balance })
+            .collect(),
+        has_more,
+        next_start_after,
+    })
 }
 
 pub fn query_list_sub_daos(
     deps: Deps,
     start_after: Option<String>,
     limit: Option<u32>,
+    active_only: Option<bool>,
+    added_after: Option<u64>,
+    order: Option<PageOrder>,
 ) -> StdResult<Binary> {
     let start_at = start_after
         .map(|addr| deps.api.addr_validate(&addr))
         .transpose()?;
 
-    let subdaos = cw_paginate::paginate_map(
+    to_binary(&list_sub_daos(
         deps,
-        &SUBDAO_LIST,
         start_at.as_ref(),
         limit,
-        cosmwasm_std::Order::Ascending,
-    )?;
+        resolve_order(order, Order::Ascending),
+        sub_dao_filter(active_only, added_after),
+    )?)
+}
+
+pub fn query_sub_dao_count(
+    deps: Deps,
+    active_only: Option<bool>,
+    added_after: Option<u64>,
+) -> StdResult<Binary> {
+    let filter = sub_dao_filter(active_only, added_after);
+    let count = SUBDAO_LIST
+        .range(deps.storage, None, None, Order::Ascending)
+        .try_fold(0u64, |count, item| -> StdResult<u64> {
+            let (_, info) = item?;
+            Ok(count + filter(&info) as u64)
+        })?;
+    to_binary(&CountResponse { count })
+}
+
+/// The filter applied to SubDAOs by the `ListSubDaos` and
+/// `SubDaoCount` queries.
+fn sub_dao_filter(
+    active_only: Option<bool>,
+    added_after: Option<u64>,
+) -> impl Fn(&SubDaoInfo) -> bool {
+    let active_only = active_only.unwrap_or(false);
+    move |info: &SubDaoInfo| {
+        (info.active || !active_only) && added_after.map_or(true, |h| info.added_at > h)
+    }
+}
+
+/// Lists up to LIMIT SubDAOs after START_AFTER in ORDER for which
+/// FILTER returns true.
+fn list_sub_daos(
+    deps: Deps,
+    start_after: Option<&Addr>,
+    limit: Option<u32>,
+    order: Order,
+    filter: impl Fn(&SubDaoInfo) -> bool,
+) -> StdResult<Vec<SubDao>> {
+    let (min, max) = keyset_bounds(start_after, order);
+    take_limit(
+        SUBDAO_LIST
+            .range(deps.storage, min, max, order)
+            .filter(|item| item.as_ref().map_or(true, |(_, info)| filter(info)))
+            .map(|item| {
+                item.map(|(address, info)| SubDao {
+                    addr: address.into_string(),
+                    name: info.name,
+                    charter: info.charter,
+                })
+            }),
+        limit,
+    )
+}
+
+pub fn query_sub_dao_info(deps: Deps, address: String) -> StdResult<Binary> {
+    let address = deps.api.addr_validate(&address)?;
+    to_binary(&SUBDAO_LIST.may_load(deps.storage, &address)?)
+}
+
+pub fn query_permissions(deps: Deps, address: String) -> StdResult<Binary> {
+    let address = deps.api.addr_validate(&address)?;
+    to_binary(
+        &PERMISSIONS
+            .may_load(deps.storage, &address)?
+            .unwrap_or_default(),
+    )
+}
+
+pub fn query_list_permissions(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let start_at = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+
+    let grants: Vec<PermissionGrant> = paginate_map(
+        deps,
+        &PERMISSIONS,
+        start_at.as_ref(),
+        limit,
+        Order::Ascending,
+    )?
+    .into_iter()
+    .map(|(address, permissions)| PermissionGrant {
+        address,
+        permissions,
+    })
+    .collect();
+
+    to_binary(&grants)
+}
+
+pub fn query_spend_limits(deps: Deps, env: Env, address: String) -> StdResult<Binary> {
+    let address = deps.api.addr_validate(&address)?;
+    let limits = SPEND_LIMITS
+        .prefix(&address)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, limit)| limit.current(&env.block)))
+        .collect::<StdResult<Vec<SpendLimit>>>()?;
+    to_binary(&limits)
+}
 
-    let subdaos: Vec<SubDao> = subdaos
+pub fn query_list_sub_daos_recursive(
+    deps: Deps,
+    env: Env,
+    max_depth: Option<u32>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let max_depth = clamp_limit(
+        max_depth,
+        DEFAULT_SUB_DAO_TREE_DEPTH,
+        MAX_SUB_DAO_TREE_DEPTH,
+    );
+    if max_depth == 0 {
+        return to_binary(&Vec::<SubDaoNode>::new());
+    }
+    let subdaos = list_sub_daos(deps, None, limit, Order::Ascending, |_| true)?;
+    to_binary(&sub_dao_tree(
+        deps,
+        &env.contract.address,
+        subdaos,
+        max_depth,
+        limit,
+    )?)
+}
+
+/// Builds the tree below SUBDAOS, the SubDAOs of PARENT, descending
+/// DEPTH levels including their own. SubDAOs which do not respond to
+/// DAO queries are listed as unverified leaves. Cycles are cut off by
+/// the depth limit.
+fn sub_dao_tree(
+    deps: Deps,
+    parent: &Addr,
+    subdaos: Vec<SubDao>,
+    depth: u32,
+    limit: Option<u32>,
+) -> StdResult<Vec<SubDaoNode>> {
+    subdaos
         .into_iter()
-        .map(|(address, charter)| SubDao {
-            addr: address.into_string(),
-            charter,
+        .map(|SubDao { addr, charter, .. }| {
+            let admin: Option<Addr> = deps
+                .querier
+                .query_wasm_smart(&addr, &QueryMsg::Admin {})
+                .ok();
+            let sub_daos = match admin {
+                Some(_) if depth > 1 => {
+                    let children: Vec<SubDao> = deps
+                        .querier
+                        .query_wasm_smart(
+                            &addr,
+                            &QueryMsg::ListSubDaos {
+                                start_after: None,
+                                limit,
+                                active_only: None,
+                                added_after: None,
+                                order: None,
+                            },
+                        )
+                        .unwrap_or_default();
+                    sub_dao_tree(deps, &Addr::unchecked(&addr), children, depth - 1, limit)?
+                }
+                _ => vec![],
+            };
+            Ok(SubDaoNode {
+                verified: admin.as_ref() == Some(parent),
+                addr,
+                charter,
+                sub_daos,
+            })
         })
-        .collect();
-
-    to_binary(&subdaos)
+        .collect()
 }
 
 pub fn query_dao_uri(deps: Deps) -> StdResult<Binary> {
@@ -828,89 +2287,150 @@ pub fn query_dao_uri(deps: Deps) -> StdResult<Binary> {
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    msg.check_stored_version(deps.storage, CONTRACT_VERSION)?;
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    // The SubDAO list changed format within this major version, so it
+    // is converted by either message.
+    migrate_sub_daos(deps.storage, env.block.height)?;
     match msg {
-        MigrateMsg::FromV1 { dao_uri } => {
-            use cw_core_v1 as v1;
-
-            let current_keys = v1::state::PROPOSAL_MODULES
-                .keys(deps.storage, None, None, Order::Ascending)
-                .collect::<StdResult<Vec<Addr>>>()?;
-
-            // All proposal modules are considered active in v1.
-            let module_count = &(current_keys.len() as u32);
-            TOTAL_PROPOSAL_MODULE_COUNT.save(deps.storage, module_count)?;
-            ACTIVE_PROPOSAL_MODULE_COUNT.save(deps.storage, module_count)?;
-
-            // Update proposal modules to v2.
-            current_keys
-                .into_iter()
-                .enumerate()
-                .try_for_each::<_, StdResult<()>>(|(idx, address)| {
-                    let prefix = derive_proposal_module_prefix(idx)?;
-                    let proposal_module = &ProposalModule {
-                        address: address.clone(),
-                        status: ProposalModuleStatus::Enabled {},
-                        prefix,
-                    };
-                    PROPOSAL_MODULES.save(deps.storage, address, proposal_module)?;
-                    Ok(())
-                })?;
-
-            // Update config to have the V2 "dao_uri" field.
-            let v1_config = v1::state::CONFIG.load(deps.storage)?;
-            CONFIG.save(
-                deps.storage,
-                &Config {
-                    name: v1_config.name,
-                    description: v1_config.description,
-                    image_url: v1_config.image_url,
-                    automatically_add_cw20s: v1_config.automatically_add_cw20s,
-                    automatically_add_cw721s: v1_config.automatically_add_cw721s,
-                    dao_uri,
-                },
-            )?;
-
-            Ok(Response::default())
+        // Legacy cw-core state is only converted when requested, so
+        // that `FromCompatible` never rewrites a DAO's config or
+        // proposal modules.
+        MigrateMsg::FromV1(FromV1Msg { dao_uri }) => {
+            if !migrate_legacy_state(deps.storage, dao_uri)? {
+                return Err(ContractError::NoLegacyState {});
+            }
+            Ok(Response::default().add_attribute("migrated_legacy_state", "true"))
         }
         MigrateMsg::FromCompatible {} => Ok(Response::default()),
     }
 }
 
+/// Moves SubDAOs from the legacy SubDAO list, which stored only
+/// charters, to the current one. The height they were added at is not
+/// known, so HEIGHT is used. SubDAOs already in the current list are
+/// left as they are, so this may safely be run more than once.
+fn migrate_sub_daos(storage: &mut dyn Storage, height: u64) -> StdResult<()> {
+    let legacy = LEGACY_SUBDAO_LIST
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(Addr, Option<String>)>>>()?;
+    for (address, charter) in legacy {
+        LEGACY_SUBDAO_LIST.remove(storage, &address);
+        if SUBDAO_LIST.has(storage, &address) {
+            continue;
+        }
+        SUBDAO_LIST.save(
+            storage,
+            &address,
+            &SubDaoInfo {
+                name: None,
+                charter,
+                added_at: height,
+                active: true,
+            },
+        )?;
+    }
+    Ok(())
+}
+
+/// Rewrites v1 (cw-core) config and proposal modules into their v2
+/// namespaces and initializes the proposal module counts. Legacy
+/// keys are removed once migrated. Returns false if there is no
+/// legacy state to migrate.
+fn migrate_legacy_state(
+    storage: &mut dyn Storage,
+    dao_uri: Option<String>,
+) -> Result<bool, ContractError> {
+    use cw_core_v1 as v1;
+
+    let v1_config = match v1::state::CONFIG.may_load(storage)? {
+        Some(config) => config,
+        None => return Ok(false),
+    };
+    let current_keys = v1::state::PROPOSAL_MODULES
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<Addr>>>()?;
+
+    // All proposal modules are considered active in v1.
+    let module_count = &(current_keys.len() as u32);
+    TOTAL_PROPOSAL_MODULE_COUNT.save(storage, module_count)?;
+    ACTIVE_PROPOSAL_MODULE_COUNT.save(storage, module_count)?;
+
+    // Update proposal modules to v2.
+    for (idx, address) in current_keys.into_iter().enumerate() {
+        let prefix = derive_proposal_module_prefix(idx)?;
+        let proposal_module = &ProposalModule {
+            address: address.clone(),
+            status: ProposalModuleStatus::Enabled {},
+            prefix,
+        };
+        v1::state::PROPOSAL_MODULES.remove(storage, address.clone());
+        PROPOSAL_MODULES.save(storage, address, proposal_module)?;
+    }
+
+    // Update config to have the V2 "dao_uri" field.
+    CONFIG.save(
+        storage,
+        &Config {
+            name: v1_config.name,
+            description: v1_config.description,
+            image_url: v1_config.image_url,
+            automatically_add_cw20s: v1_config.automatically_add_cw20s,
+            automatically_add_cw721s: v1_config.automatically_add_cw721s,
+            dao_uri,
+        },
+    )?;
+    v1::state::CONFIG.remove(storage);
+
+    Ok(true)
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+pub fn reply(mut deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
     match msg.id {
         PROPOSAL_MODULE_REPLY_ID => {
-            let res = parse_reply_instantiate_data(msg)?;
-            let prop_module_addr = deps.api.addr_validate(&res.contract_address)?;
-            let total_module_count = TOTAL_PROPOSAL_MODULE_COUNT.load(deps.storage)?;
-
-            let prefix = derive_proposal_module_prefix(total_module_count as usize)?;
-            let prop_module = ProposalModule {
-                address: prop_module_addr.clone(),
-                status: ProposalModuleStatus::Enabled,
-                prefix,
-            };
-
-            PROPOSAL_MODULES.save(deps.storage, prop_module_addr, &prop_module)?;
-
-            // Save active and total proposal module counts.
-            ACTIVE_PROPOSAL_MODULE_COUNT
-                .update::<_, StdError>(deps.storage, |count| Ok(count + 1))?;
-            TOTAL_PROPOSAL_MODULE_COUNT.save(deps.storage, &(total_module_count + 1))?;
+            let (response, _) = register_proposal_module(deps, msg)?;
+            Ok(response)
+        }
 
-            // Check for module instantiation callbacks
-            let callback_msgs = match res.data {
-                Some(data) => from_binary::<ModuleInstantiateCallback>(&data)
-                    .map(|m| m.msgs)
-                    .unwrap_or_else(|_| vec![]),
-                None => vec![],
-            };
+        PROPOSAL_MODULE_REPLACEMENT_REPLY_ID => {
+            let (response, module) = register_proposal_module(deps.branch(), msg)?;
+            let replaced = PENDING_REPLACEMENT.load(deps.storage)?;
+            PENDING_REPLACEMENT.remove(deps.storage);
+
+            let mut response = response
+                .add_message(WasmMsg::Execute {
+                    contract_addr: module.to_string(),
+                    msg: to_binary(&ReplacementMsg::ImportModuleState {
+                        module: replaced.to_string(),
+                    })?,
+                    funds: vec![],
+                })
+                .add_event(
+                    EVENTS
+                        .event("replace_proposal_module")
+                        .address("replaced", &replaced)
+                        .address("proposal_module", &module)
+                        .into(),
+                );
+
+            // Move the replaced module's pre-propose module, if any,
+            // to the new module.
+            let policy: ProposalCreationPolicy = deps
+                .querier
+                .query_wasm_smart(&replaced, &CreationPolicyQuery::ProposalCreationPolicy {})?;
+            if let ProposalCreationPolicy::Module { addr } = policy {
+                response = response.add_message(WasmMsg::Execute {
+                    contract_addr: addr.into_string(),
+                    msg: to_binary(&ReplacementMsg::UpdateProposalModule {
+                        address: module.into_string(),
+                    })?,
+                    funds: vec![],
+                });
+            }
 
-            Ok(Response::default()
-                .add_attribute("prop_module".to_string(), res.contract_address)
-                .add_messages(callback_msgs))
+            Ok(response)
         }
 
         VOTE_MODULE_INSTANTIATE_REPLY_ID => {
@@ -935,21 +2455,117 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
             };
 
             Ok(Response::default()
-                .add_attribute("voting_module", vote_module_addr)
+                .add_attribute("voting_module", vote_module_addr.clone())
+                .add_event(
+                    EVENTS
+                        .event("set_voting_module")
+                        .address("voting_module", &vote_module_addr)
+                        .into(),
+                )
                 .add_messages(callback_msgs))
         }
+        SUBDAO_INSTANTIATE_REPLY_ID => {
+            let res = parse_reply_instantiate_data(msg)?;
+            let sub_dao = deps.api.addr_validate(&res.contract_address)?;
+            let info = PENDING_SUBDAO.load(deps.storage)?;
+            PENDING_SUBDAO.remove(deps.storage);
+
+            SUBDAO_LIST.save(deps.storage, &sub_dao, &info)?;
+
+            Ok(Response::default()
+                .add_attribute("sub_dao", sub_dao.clone())
+                .add_event(
+                    EVENTS
+                        .event("create_sub_dao")
+                        .address("sub_dao", &sub_dao)
+                        .into(),
+                ))
+        }
+        MODULE_MIGRATION_REPLY_ID => {
+            let module = PENDING_MIGRATION.load(deps.storage)?;
+            PENDING_MIGRATION.remove(deps.storage);
+
+            // A module which no longer answers `Info` queries was
+            // likely migrated to the wrong code. Erroring reverts the
+            // migration.
+            let info: dao_interface::voting::InfoResponse = deps
+                .querier
+                .query_wasm_smart(&module, &dao_interface::voting::Query::Info {})
+                .map_err(|error| ContractError::MigratedModuleUnresponsive {
+                    address: module.clone(),
+                    error,
+                })?;
+
+            Ok(Response::default().add_event(
+                EVENTS
+                    .event("migrate_module")
+                    .address("module", &module)
+                    .attribute("contract", info.info.contract)
+                    .attribute("version", info.info.version)
+                    .into(),
+            ))
+        }
         VOTE_MODULE_UPDATE_REPLY_ID => {
             let res = parse_reply_instantiate_data(msg)?;
             let vote_module_addr = deps.api.addr_validate(&res.contract_address)?;
 
             VOTING_MODULE.save(deps.storage, &vote_module_addr)?;
 
-            Ok(Response::default().add_attribute("voting_module", vote_module_addr))
+            Ok(Response::default()
+                .add_attribute("voting_module", vote_module_addr.clone())
+                .add_event(
+                    EVENTS
+                        .event("set_voting_module")
+                        .address("voting_module", &vote_module_addr)
+                        .into(),
+                ))
         }
         _ => Err(ContractError::UnknownReplyID {}),
     }
 }
 
+/// Saves the proposal module instantiated by MSG, returning a
+/// response with any module instantiation callbacks and the module's
+/// address.
+fn register_proposal_module(deps: DepsMut, msg: Reply) -> Result<(Response, Addr), ContractError> {
+    let res = parse_reply_instantiate_data(msg)?;
+    let prop_module_addr = deps.api.addr_validate(&res.contract_address)?;
+    let total_module_count = TOTAL_PROPOSAL_MODULE_COUNT.load(deps.storage)?;
+
+    let prefix = derive_proposal_module_prefix(total_module_count as usize)?;
+    let prop_module = ProposalModule {
+        address: prop_module_addr.clone(),
+        status: ProposalModuleStatus::Enabled,
+        prefix,
+    };
+
+    PROPOSAL_MODULES.save(deps.storage, prop_module_addr.clone(), &prop_module)?;
+
+    // Save active and total proposal module counts.
+    ACTIVE_PROPOSAL_MODULE_COUNT.update::<_, StdError>(deps.storage, |count| Ok(count + 1))?;
+    TOTAL_PROPOSAL_MODULE_COUNT.save(deps.storage, &(total_module_count + 1))?;
+
+    // Check for module instantiation callbacks
+    let callback_msgs = match res.data {
+        Some(data) => from_binary::<ModuleInstantiateCallback>(&data)
+            .map(|m| m.msgs)
+            .unwrap_or_else(|_| vec![]),
+        None => vec![],
+    };
+
+    let response = Response::default()
+        .add_attribute("prop_module".to_string(), res.contract_address)
+        .add_event(
+            EVENTS
+                .event("add_proposal_module")
+                .address("proposal_module", &prop_module.address)
+                .attribute("prefix", prop_module.prefix)
+                .into(),
+        )
+        .add_messages(callback_msgs);
+    Ok((response, prop_module_addr))
+}
+
 pub(crate) fn derive_proposal_module_prefix(mut dividend: usize) -> StdResult<String> {
     dividend += 1;
     // Pre-allocate string
@@ -968,7 +2584,18 @@ pub(crate) fn derive_proposal_module_prefix(mut dividend: usize) -> StdResult<St
 
 #[cfg(test)]
 mod test {
-    use crate::contract::derive_proposal_module_prefix;
+    use crate::contract::{
+        derive_proposal_module_prefix, execute_repair_proposal_module_counts,
+        query_proposal_module_counts,
+    };
+    use crate::error::ContractError;
+    use crate::query::{ProposalModuleCounts, ProposalModuleCountsResponse};
+    use crate::state::{
+        ProposalModule, ProposalModuleStatus, ACTIVE_PROPOSAL_MODULE_COUNT, PROPOSAL_MODULES,
+        TOTAL_PROPOSAL_MODULE_COUNT,
+    };
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cosmwasm_std::{from_binary, Addr};
     use std::collections::HashSet;
 
     #[test]
@@ -1001,4 +2628,77 @@ mod test {
             seen.insert(prefix);
         }
     }
+
+    #[test]
+    fn test_repair_proposal_module_counts() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let statuses = [
+            ProposalModuleStatus::Enabled,
+            ProposalModuleStatus::Disabled,
+            ProposalModuleStatus::Enabled,
+        ];
+        for (idx, status) in statuses.into_iter().enumerate() {
+            let address = Addr::unchecked(format!("module{idx}"));
+            let module = ProposalModule {
+                address: address.clone(),
+                prefix: derive_proposal_module_prefix(idx).unwrap(),
+                status,
+            };
+            PROPOSAL_MODULES
+                .save(deps.as_mut().storage, address, &module)
+                .unwrap();
+        }
+        // Drift the active count.
+        ACTIVE_PROPOSAL_MODULE_COUNT
+            .save(deps.as_mut().storage, &1)
+            .unwrap();
+        TOTAL_PROPOSAL_MODULE_COUNT
+            .save(deps.as_mut().storage, &3)
+            .unwrap();
+
+        let counts: ProposalModuleCountsResponse =
+            from_binary(&query_proposal_module_counts(deps.as_ref()).unwrap()).unwrap();
+        assert_eq!(
+            counts,
+            ProposalModuleCountsResponse {
+                stored: ProposalModuleCounts {
+                    active: 1,
+                    total: 3
+                },
+                computed: ProposalModuleCounts {
+                    active: 2,
+                    total: 3
+                },
+                consistent: false,
+            }
+        );
+
+        let err = execute_repair_proposal_module_counts(
+            deps.as_mut(),
+            env.clone(),
+            Addr::unchecked("ekez"),
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        execute_repair_proposal_module_counts(
+            deps.as_mut(),
+            env.clone(),
+            env.contract.address.clone(),
+        )
+        .unwrap();
+
+        let counts: ProposalModuleCountsResponse =
+            from_binary(&query_proposal_module_counts(deps.as_ref()).unwrap()).unwrap();
+        assert!(counts.consistent);
+        assert_eq!(
+            counts.stored,
+            ProposalModuleCounts {
+                active: 2,
+                total: 3
+            }
+        );
+    }
 }
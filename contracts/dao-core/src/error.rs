@@ -1,5 +1,6 @@
-use cosmwasm_std::{Addr, StdError};
+use cosmwasm_std::{Addr, StdError, Uint128};
 use cw_utils::ParseReplyError;
+use dao_interface::migrate::MigrationError;
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -10,12 +11,42 @@ pub enum ContractError {
     #[error(transparent)]
     ParseReplyError(#[from] ParseReplyError),
 
+    #[error(transparent)]
+    MigrationError(#[from] MigrationError),
+
+    #[error(transparent)]
+    HookError(#[from] cw_hooks::HookError),
+
+    #[error(transparent)]
+    DenomError(#[from] cw_denom::DenomError),
+
+    #[error(transparent)]
+    PauseError(#[from] dao_pausable::PauseError),
+
     #[error("Unauthorized.")]
     Unauthorized {},
 
+    #[error("This permission has already been granted to the address.")]
+    PermissionAlreadyGranted {},
+
+    #[error("This permission has not been granted to the address.")]
+    PermissionNotGranted {},
+
+    #[error("Spend limits must have a non-zero amount and period.")]
+    InvalidSpendLimit {},
+
+    #[error("No spend limit has been granted for this denom.")]
+    NoSpendLimit {},
+
+    #[error("Spend exceeds the spend limit. ({remaining}) remains this period.")]
+    SpendLimitExceeded { remaining: Uint128 },
+
     #[error("The contract is paused.")]
     Paused {},
 
+    #[error("The execution of proposals is paused.")]
+    ExecutionPaused {},
+
     #[error("No voting module provided.")]
     NoVotingModule {},
 
@@ -45,9 +76,21 @@ pub enum ContractError {
     #[error("Proposal module with address ({address}) does not exist.")]
     ProposalModuleDoesNotExist { address: Addr },
 
+    #[error("({address}) is not the voting module or a proposal module of this DAO.")]
+    ModuleNotRegistered { address: Addr },
+
+    #[error("Module ({address}) does not respond to an Info query after migrating. {error}")]
+    MigratedModuleUnresponsive { address: Addr, error: StdError },
+
     #[error("Proposal module with address ({address}) is already disabled.")]
     ModuleAlreadyDisabled { address: Addr },
 
     #[error("Proposal module with address is disabled and cannot execute messages.")]
     ModuleDisabledCannotExecute { address: Addr },
+
+    #[error("SubDAO with address ({address}) does not exist.")]
+    SubDaoDoesNotExist { address: Addr },
+
+    #[error("No v1 state was found to migrate.")]
+    NoLegacyState {},
 }
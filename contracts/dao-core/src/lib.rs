@@ -3,6 +3,7 @@
 pub mod contract;
 mod error;
 pub mod msg;
+pub mod proto;
 pub mod query;
 pub mod state;
 
@@ -1,8 +1,10 @@
 use crate::query::SubDao;
-use crate::state::Config;
+use crate::state::{Config, Permission};
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{CosmosMsg, Empty};
-use cw_utils::Duration;
+use cosmwasm_std::{Binary, CosmosMsg, Empty, Uint128};
+use cw_denom::UncheckedDenom;
+use cw_paginate::PageOrder;
+use cw_utils::{Duration, Expiration};
 use dao_interface::ModuleInstantiateInfo;
 
 /// Information about an item to be stored in the items list.
@@ -14,6 +16,33 @@ pub struct InitialItem {
     pub value: String,
 }
 
+/// A part of the core contract's state which may be requested with
+/// the `DumpStateSelective` query.
+#[cw_serde]
+pub enum Section {
+    /// The DAO's admin.
+    Admin,
+    /// The DAO's config and pause info.
+    Config,
+    /// The voting and proposal modules, and proposal module counts.
+    Modules,
+    /// The contract's version.
+    Version,
+}
+
+/// Where the `SweepDust` message sends swept balances.
+#[cw_serde]
+pub enum SweepDestination {
+    /// Native tokens are burned with the bank module and cw20 tokens
+    /// with their `Burn` message.
+    Burn {},
+    /// Native tokens are deposited into the chain's community
+    /// pool. cw20 tokens can not be deposited and are not swept.
+    CommunityPool {},
+    /// Balances are sent to ADDRESS.
+    Address { address: String },
+}
+
 #[cw_serde]
 pub struct InstantiateMsg {
     /// Optional Admin with the ability to execute DAO messages
@@ -55,14 +84,45 @@ pub struct InstantiateMsg {
 #[cw_serde]
 pub enum ExecuteMsg {
     /// Callable by the Admin, if one is configured.
-    /// Executes messages in order.
+    /// Executes messages in order, after sending them to each of the
+    /// DAO's message filters.
     ExecuteAdminMsgs { msgs: Vec<CosmosMsg<Empty>> },
     /// Callable by proposal modules. The DAO will execute the
-    /// messages in the hook in order.
+    /// messages in the hook in order, after sending them to each of
+    /// its message filters. If any filter errors, no messages are
+    /// executed.
     ExecuteProposalHook { msgs: Vec<CosmosMsg<Empty>> },
     /// Pauses the DAO for a set duration.
     /// When paused the DAO is unable to execute proposals
-    Pause { duration: Duration },
+    Pause {
+        duration: Duration,
+        /// An optional explanation of why the DAO was paused,
+        /// returned from `PauseInfo` queries while paused.
+        reason: Option<String>,
+    },
+    /// Ends the DAO's pause. Callable by the admin, if it is not the
+    /// DAO itself, while the DAO is paused.
+    Unpause {},
+    /// Extends the DAO's pause to end at EXPIRATION, which must be
+    /// after the pause's current expiration. Callable by the admin,
+    /// if it is not the DAO itself, while the DAO is paused.
+    ExtendPause { expiration: Expiration },
+    /// Pauses the execution of proposals, and spending from spend
+    /// limits, for a set duration. Unlike `Pause`, proposals may
+    /// still be created and voted on, and the admin and other
+    /// addresses with permissions may still act on the DAO. Callable
+    /// by the DAO and addresses granted the `PauseExecution`
+    /// permission.
+    PauseExecution {
+        duration: Duration,
+        /// An optional explanation of why execution was paused,
+        /// returned from `ExecutionPauseInfo` queries while paused.
+        reason: Option<String>,
+    },
+    /// Ends a pause made with `PauseExecution`. Callable by the DAO,
+    /// which may do so with `ExecuteAdminMsgs` while execution is
+    /// paused.
+    UnpauseExecution {},
     /// Executed when the contract receives a cw20 token. Depending on
     /// the contract's configuration the contract will automatically
     /// add the token to its treasury.
@@ -111,6 +171,28 @@ pub enum ExecuteMsg {
         to_add: Vec<String>,
         to_remove: Vec<String>,
     },
+    /// Updates the list of native denoms this contract has
+    /// registered.
+    UpdateNativeDenomList {
+        to_add: Vec<String>,
+        to_remove: Vec<String>,
+    },
+    /// Adds DENOM to the list of native denoms this contract has
+    /// registered. Intended for contracts which issue tokens on the
+    /// DAO's behalf, such as tokenfactory issuers, which must be
+    /// granted the `UpdateNativeDenomList` permission. Denoms created
+    /// by proposals with a tokenfactory `MsgCreateDenom` are
+    /// registered automatically.
+    RegisterDenom { denom: String },
+    /// Migrates MODULE, which must be the DAO's voting module or one
+    /// of its proposal modules, to NEW_CODE_ID with MSG. The DAO
+    /// must be the module's admin. If the migrated module does not
+    /// respond to an `Info {}` query, the migration is reverted.
+    MigrateModule {
+        module: String,
+        new_code_id: u64,
+        msg: Binary,
+    },
     /// Updates the governance contract's governance modules. Module
     /// instantiate info in `to_add` is used to create new modules and
     /// install them.
@@ -120,6 +202,16 @@ pub enum ExecuteMsg {
         to_add: Vec<ModuleInstantiateInfo>,
         to_disable: Vec<String>,
     },
+    /// Replaces the proposal module at ADDRESS with a new module
+    /// instantiated from REPLACEMENT. The replaced module is disabled
+    /// and the new module imports its hook consumers, proposal
+    /// creation policy, and proposal count. If the replaced module
+    /// has a pre-propose module, it is moved to the new module. Only
+    /// callable by the DAO.
+    ReplaceProposalModule {
+        address: String,
+        replacement: ModuleInstantiateInfo,
+    },
     /// Callable by the core contract. Replaces the current
     /// voting module with a new one instantiated by the governance
     /// contract.
@@ -129,6 +221,78 @@ pub enum ExecuteMsg {
         to_add: Vec<SubDao>,
         to_remove: Vec<String>,
     },
+    /// Callable by the core contract. Instantiates a new core
+    /// contract from INSTANTIATE_INFO, whose message must be a core
+    /// contract `InstantiateMsg`, and adds it to the SubDAO list with
+    /// CHARTER. The admin in the message is replaced with this DAO,
+    /// so that it may execute messages on the SubDAO's behalf.
+    CreateSubDao {
+        instantiate_info: ModuleInstantiateInfo,
+        charter: Option<String>,
+    },
+    /// Callable by the core contract. Marks the SubDAO at ADDRESS as
+    /// active or inactive.
+    SetSubDaoActive { address: String, active: bool },
+    /// Callable by the core contract. Removes cw20 tokens which the
+    /// contract holds none of, or which fail to respond to balance
+    /// queries, from the contract's list of cw20 tokens. Removes at
+    /// most LIMIT tokens, or all such tokens if LIMIT is None.
+    RemoveZeroBalances { limit: Option<u32> },
+    /// Callable by the core contract. Recomputes the active and total
+    /// proposal module counts from the stored proposal modules,
+    /// correcting any drift between them.
+    RepairProposalModuleCounts {},
+    /// Callable by the core contract. Sends every native balance and
+    /// balance of a token in the contract's cw20 list which is
+    /// greater than zero and less than THRESHOLD to DESTINATION. The
+    /// `DustSweep` query lists what would be swept.
+    SweepDust {
+        threshold: Uint128,
+        destination: SweepDestination,
+    },
+    /// Callable by the core contract. Adds a message filter, which is
+    /// sent the messages of every proposal and of the admin before
+    /// they are executed and may reject them.
+    AddMessageFilter { address: String },
+    /// Callable by the core contract. Removes a message filter.
+    RemoveMessageFilter { address: String },
+    /// Callable by the core contract. Allows ADDRESS to manage the
+    /// part of the DAO's state described by PERMISSION without a
+    /// proposal.
+    GrantPermission {
+        address: String,
+        permission: Permission,
+    },
+    /// Callable by the core contract. Revokes a permission previously
+    /// granted to ADDRESS.
+    RevokePermission {
+        address: String,
+        permission: Permission,
+    },
+    /// Callable by the core contract. Allows ADDRESS to spend up to
+    /// AMOUNT of DENOM from the treasury every PERIOD with the
+    /// `Spend` message. Replaces any existing limit for the denom,
+    /// and starts a new period.
+    SetSpendLimit {
+        address: String,
+        denom: UncheckedDenom,
+        amount: Uint128,
+        period: Duration,
+    },
+    /// Callable by the core contract. Removes the spend limit
+    /// ADDRESS has been granted for DENOM.
+    RemoveSpendLimit {
+        address: String,
+        denom: UncheckedDenom,
+    },
+    /// Sends AMOUNT of DENOM from the treasury to RECIPIENT. The
+    /// sender must have been granted a spend limit for the denom
+    /// with at least AMOUNT remaining in the current period.
+    Spend {
+        denom: UncheckedDenom,
+        amount: Uint128,
+        recipient: String,
+    },
 }
 
 #[cw_serde]
@@ -144,31 +308,74 @@ pub enum QueryMsg {
     #[returns(Config)]
     Config {},
     /// Gets the token balance for each cw20 registered with the
-    /// contract.
-    #[returns(crate::query::Cw20BalanceResponse)]
+    /// contract. If MIN_BALANCE is set, tokens with a smaller
+    /// balance are skipped.
+    #[returns(crate::query::Cw20BalancesResponse)]
     Cw20Balances {
         start_after: Option<String>,
         limit: Option<u32>,
+        min_balance: Option<Uint128>,
     },
     /// Lists the addresses of the cw20 tokens in this contract's
-    /// treasury.
+    /// treasury, in descending order of address unless `order` is
+    /// set.
     #[returns(Vec<cosmwasm_std::Addr>)]
     Cw20TokenList {
         start_after: Option<String>,
         limit: Option<u32>,
+        #[serde(default)]
+        order: Option<PageOrder>,
     },
+    /// Gets the number of cw20 tokens in this contract's treasury.
+    #[returns(crate::query::CountResponse)]
+    Cw20TokenCount {},
     /// Lists the addresses of the cw721 tokens in this contract's
-    /// treasury.
+    /// treasury, in descending order of address unless `order` is
+    /// set.
     #[returns(Vec<cosmwasm_std::Addr>)]
     Cw721TokenList {
         start_after: Option<String>,
         limit: Option<u32>,
+        #[serde(default)]
+        order: Option<PageOrder>,
+    },
+    /// Gets the number of cw721 tokens in this contract's treasury.
+    #[returns(crate::query::CountResponse)]
+    Cw721TokenCount {},
+    /// Lists the native denoms in this contract's treasury.
+    #[returns(Vec<String>)]
+    NativeDenomList {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Gets the contract's balance of each native denom registered
+    /// with the contract, in descending order of denom.
+    #[returns(crate::query::NativeBalancesResponse)]
+    NativeBalances {
+        start_after: Option<String>,
+        limit: Option<u32>,
     },
     /// Dumps all of the core contract's state in a single
     /// query. Useful for frontends as performance for queries is more
     /// limited by network times than compute times.
+    ///
+    /// The number of proposal modules listed is unbounded unless
+    /// `limit` is set, which may exceed query gas limits for DAOs
+    /// with many proposal modules. Such DAOs should page through
+    /// them with `start_after` and `limit`, starting each page after
+    /// the previous response's `next_start_after`.
     #[returns(crate::query::DumpStateResponse)]
-    DumpState {},
+    DumpState {
+        #[serde(default)]
+        start_after: Option<String>,
+        #[serde(default)]
+        limit: Option<u32>,
+    },
+    /// Same as `DumpState` but only loads the requested sections of
+    /// state. Sections which were not requested are `None` in the
+    /// response.
+    #[returns(crate::query::DumpStateSelectiveResponse)]
+    DumpStateSelective { sections: Vec<Section> },
     /// Gets the address associated with an item key.
     #[returns(crate::query::GetItemResponse)]
     GetItem { key: String },
@@ -176,11 +383,26 @@ pub enum QueryMsg {
     /// example, given the items `{ "group": "foo", "subdao": "bar"}`
     /// this query would return `[("group", "foo"), ("subdao",
     /// "bar")]`.
-    #[returns(Vec<String>)]
+    ///
+    /// If `prefix` is set, only items whose keys start with it are
+    /// returned. For example, a prefix of `"payroll:"` lists all
+    /// items with keys of the form `payroll:*`.
+    ///
+    /// Items are listed in descending order of key unless `order` is
+    /// set.
+    #[returns(Vec<(String, String)>)]
     ListItems {
         start_after: Option<String>,
         limit: Option<u32>,
+        prefix: Option<String>,
+        #[serde(default)]
+        order: Option<PageOrder>,
     },
+    /// Gets the number of items associated with the contract. If
+    /// `prefix` is set, only items whose keys start with it are
+    /// counted.
+    #[returns(crate::query::CountResponse)]
+    ItemCount { prefix: Option<String> },
     /// Returns contract version info
     #[returns(dao_interface::voting::InfoResponse)]
     Info {},
@@ -201,15 +423,51 @@ pub enum QueryMsg {
     /// Returns information about if the contract is currently paused.
     #[returns(crate::query::PauseInfoResponse)]
     PauseInfo {},
+    /// Returns information about if the execution of proposals is
+    /// currently paused.
+    #[returns(crate::query::PauseInfoResponse)]
+    ExecutionPauseInfo {},
     /// Gets the contract's voting module.
     #[returns(cosmwasm_std::Addr)]
     VotingModule {},
     /// Returns all SubDAOs with their charters in a vec.
     /// start_after is bound exclusive and asks for a string address.
+    /// If `active_only` is true, inactive SubDAOs are skipped. If
+    /// `added_after` is set, only SubDAOs added after that block
+    /// height are listed. SubDAOs are listed in ascending order of
+    /// address unless `order` is set.
     #[returns(Vec<crate::query::SubDao>)]
     ListSubDaos {
         start_after: Option<String>,
         limit: Option<u32>,
+        #[serde(default)]
+        active_only: Option<bool>,
+        #[serde(default)]
+        added_after: Option<u64>,
+        #[serde(default)]
+        order: Option<PageOrder>,
+    },
+    /// Gets the number of SubDAOs `ListSubDaos` would list with the
+    /// same `active_only` and `added_after` arguments.
+    #[returns(crate::query::CountResponse)]
+    SubDaoCount {
+        #[serde(default)]
+        active_only: Option<bool>,
+        #[serde(default)]
+        added_after: Option<u64>,
+    },
+    /// Gets the information stored about the SubDAO at ADDRESS, if
+    /// it is a SubDAO of this DAO.
+    #[returns(Option<crate::state::SubDaoInfo>)]
+    SubDaoInfo { address: String },
+    /// Returns the tree of SubDAOs below this DAO, querying each
+    /// SubDAO for its own SubDAOs. `max_depth` is the number of
+    /// levels to descend (default 2, at most 5), and `limit` is the
+    /// maximum number of SubDAOs listed for each DAO in the tree.
+    #[returns(Vec<crate::query::SubDaoNode>)]
+    ListSubDaosRecursive {
+        max_depth: Option<u32>,
+        limit: Option<u32>,
     },
     /// Implements the DAO Star standard: <https://daostar.one/EIP>
     #[returns(crate::query::DaoURIResponse)]
@@ -223,10 +481,41 @@ pub enum QueryMsg {
     /// Returns the total voting power at a given block height.
     #[returns(dao_interface::voting::TotalPowerAtHeightResponse)]
     TotalPowerAtHeight { height: Option<u64> },
+    /// Compares the stored active and total proposal module counts
+    /// with counts computed from the stored proposal modules.
+    #[returns(crate::query::ProposalModuleCountsResponse)]
+    ProposalModuleCounts {},
+    /// Lists the balances a `SweepDust` message with the same
+    /// arguments would sweep.
+    #[returns(crate::query::DustSweepResponse)]
+    DustSweep {
+        threshold: Uint128,
+        destination: SweepDestination,
+    },
+    /// Lists the contracts consulted before the messages of a
+    /// proposal are executed.
+    #[returns(cw_hooks::HooksResponse)]
+    MessageFilters {},
+    /// Gets the permissions granted to an address.
+    #[returns(Vec<crate::state::Permission>)]
+    Permissions { address: String },
+    /// Lists the addresses which have been granted permissions, and
+    /// their permissions, in ascending order of address.
+    #[returns(Vec<crate::query::PermissionGrant>)]
+    ListPermissions {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Gets the spend limits granted to an address, with the amount
+    /// spent in the current period.
+    #[returns(Vec<crate::state::SpendLimit>)]
+    SpendLimits { address: String },
 }
 
+/// Information needed to migrate from DAO DAO v1.
 #[cw_serde]
-pub enum MigrateMsg {
-    FromV1 { dao_uri: Option<String> },
-    FromCompatible {},
+pub struct FromV1Msg {
+    pub dao_uri: Option<String>,
 }
+
+pub type MigrateMsg = dao_interface::migrate::MigrateMsg<FromV1Msg>;
@@ -0,0 +1,74 @@
+//! Minimal protobuf definitions for the messages this contract sends
+//! or inspects which cosmwasm has no message type for. Field numbers
+//! match `cosmos.distribution.v1beta1` and
+//! `osmosis.tokenfactory.v1beta1`.
+
+use cosmwasm_std::{Binary, Coin, CosmosMsg, Empty};
+use prost::Message;
+
+pub const MSG_FUND_COMMUNITY_POOL_TYPE_URL: &str =
+    "/cosmos.distribution.v1beta1.MsgFundCommunityPool";
+
+#[derive(Clone, PartialEq, Message)]
+pub struct ProtoCoin {
+    #[prost(string, tag = "1")]
+    pub denom: String,
+    #[prost(string, tag = "2")]
+    pub amount: String,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgFundCommunityPool {
+    #[prost(message, repeated, tag = "1")]
+    pub amount: Vec<ProtoCoin>,
+    #[prost(string, tag = "2")]
+    pub depositor: String,
+}
+
+/// Type URLs of tokenfactory `MsgCreateDenom` messages. Chains which
+/// forked the module under a different package use the second.
+pub const MSG_CREATE_DENOM_TYPE_URLS: [&str; 2] = [
+    "/osmosis.tokenfactory.v1beta1.MsgCreateDenom",
+    "/cosmwasm.tokenfactory.v1beta1.MsgCreateDenom",
+];
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgCreateDenom {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(string, tag = "2")]
+    pub subdenom: String,
+}
+
+/// If MSG creates a tokenfactory denom owned by CREATOR, returns the
+/// denom it creates.
+pub fn created_denom(msg: &CosmosMsg<Empty>, creator: &str) -> Option<String> {
+    match msg {
+        CosmosMsg::Stargate { type_url, value }
+            if MSG_CREATE_DENOM_TYPE_URLS.contains(&type_url.as_str()) =>
+        {
+            let msg = MsgCreateDenom::decode(value.as_slice()).ok()?;
+            (msg.sender == creator).then(|| format!("factory/{}/{}", msg.sender, msg.subdenom))
+        }
+        _ => None,
+    }
+}
+
+/// Creates a message depositing AMOUNT from DEPOSITOR into the
+/// chain's community pool.
+pub fn fund_community_pool_msg<T>(depositor: &str, amount: &[Coin]) -> CosmosMsg<T> {
+    let msg = MsgFundCommunityPool {
+        amount: amount
+            .iter()
+            .map(|c| ProtoCoin {
+                denom: c.denom.clone(),
+                amount: c.amount.to_string(),
+            })
+            .collect(),
+        depositor: depositor.to_string(),
+    };
+    CosmosMsg::Stargate {
+        type_url: MSG_FUND_COMMUNITY_POOL_TYPE_URL.to_string(),
+        value: Binary(msg.encode_to_vec()),
+    }
+}
@@ -1,10 +1,11 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Coin, Uint128};
 use cw2::ContractVersion;
-use cw_utils::Expiration;
 
 use crate::state::{Config, ProposalModule};
 
+pub use dao_pausable::PauseInfoResponse;
+
 /// Relevant state for the governance module. Returned by the
 /// `DumpState` query.
 #[cw_serde]
@@ -26,13 +27,33 @@ pub struct DumpStateResponse {
     pub active_proposal_module_count: u32,
     /// The total number of proposal modules.
     pub total_proposal_module_count: u32,
+    /// The proposal module to use as `start_after` when loading the
+    /// next page of proposal modules. `None` if this is the last
+    /// page.
+    #[serde(default)]
+    pub next_start_after: Option<Addr>,
 }
 
-/// Information about if the contract is currently paused.
+/// Returned by the `DumpStateSelective` query. Fields are `None` if
+/// the section containing them was not requested.
 #[cw_serde]
-pub enum PauseInfoResponse {
-    Paused { expiration: Expiration },
-    Unpaused {},
+pub struct DumpStateSelectiveResponse {
+    /// Set if `Section::Admin` is requested.
+    pub admin: Option<Addr>,
+    /// Set if `Section::Config` is requested.
+    pub config: Option<Config>,
+    /// Set if `Section::Config` is requested.
+    pub pause_info: Option<PauseInfoResponse>,
+    /// Set if `Section::Version` is requested.
+    pub version: Option<ContractVersion>,
+    /// Set if `Section::Modules` is requested.
+    pub proposal_modules: Option<Vec<ProposalModule>>,
+    /// Set if `Section::Modules` is requested.
+    pub voting_module: Option<Addr>,
+    /// Set if `Section::Modules` is requested.
+    pub active_proposal_module_count: Option<u32>,
+    /// Set if `Section::Modules` is requested.
+    pub total_proposal_module_count: Option<u32>,
 }
 
 /// Returned by the `GetItem` query.
@@ -43,7 +64,7 @@ pub struct GetItemResponse {
     pub item: Option<String>,
 }
 
-/// Returned by the `Cw20Balances` query.
+/// A cw20 token balance of the contract.
 #[cw_serde]
 pub struct Cw20BalanceResponse {
     /// The address of the token.
@@ -52,6 +73,64 @@ pub struct Cw20BalanceResponse {
     pub balance: Uint128,
 }
 
+/// Returned by the `Cw20Balances` query.
+#[cw_serde]
+pub struct Cw20BalancesResponse {
+    pub balances: Vec<Cw20BalanceResponse>,
+    /// True if there are more balances after this page.
+    pub has_more: bool,
+    /// The token to use as `start_after` when loading the next
+    /// page. `None` if this is the last page.
+    pub next_start_after: Option<Addr>,
+}
+
+/// Returned by the `NativeBalances` query.
+#[cw_serde]
+pub struct NativeBalancesResponse {
+    pub balances: Vec<Coin>,
+    /// True if there are more balances after this page.
+    pub has_more: bool,
+    /// The denom to use as `start_after` when loading the next page.
+    /// `None` if this is the last page.
+    pub next_start_after: Option<String>,
+}
+
+/// Returned by the `DustSweep` query.
+#[cw_serde]
+pub struct DustSweepResponse {
+    /// The native balances which would be swept.
+    pub native: Vec<Coin>,
+    /// The cw20 balances which would be swept.
+    pub cw20: Vec<Cw20BalanceResponse>,
+}
+
+/// Counts of a DAO's proposal modules.
+#[cw_serde]
+pub struct ProposalModuleCounts {
+    /// The number of enabled proposal modules.
+    pub active: u32,
+    /// The number of proposal modules, enabled or disabled.
+    pub total: u32,
+}
+
+/// Returned by the `ProposalModuleCounts` query.
+#[cw_serde]
+pub struct ProposalModuleCountsResponse {
+    /// The counts saved in the contract's state.
+    pub stored: ProposalModuleCounts,
+    /// The counts computed from the contract's proposal modules.
+    pub computed: ProposalModuleCounts,
+    /// True if the stored counts match the computed ones. If this is
+    /// false, `RepairProposalModuleCounts` will correct them.
+    pub consistent: bool,
+}
+
+/// Returned by queries which count a list, such as `Cw20TokenCount`.
+#[cw_serde]
+pub struct CountResponse {
+    pub count: u64,
+}
+
 /// Returned by the `AdminNomination` query.
 #[cw_serde]
 pub struct AdminNominationResponse {
@@ -60,14 +139,41 @@ pub struct AdminNominationResponse {
     pub nomination: Option<Addr>,
 }
 
+/// The permissions granted to an address, as returned by the
+/// `ListPermissions` query.
+#[cw_serde]
+pub struct PermissionGrant {
+    pub address: Addr,
+    pub permissions: Vec<crate::state::Permission>,
+}
+
 #[cw_serde]
 pub struct SubDao {
     /// The contract address of the SubDAO
     pub addr: String,
+    /// The name of the SubDAO.
+    #[serde(default)]
+    pub name: Option<String>,
     /// The purpose/constitution for the SubDAO
     pub charter: Option<String>,
 }
 
+/// A SubDAO in the tree returned by the `ListSubDaosRecursive`
+/// query.
+#[cw_serde]
+pub struct SubDaoNode {
+    /// The contract address of the SubDAO.
+    pub addr: String,
+    /// The purpose/constitution for the SubDAO.
+    pub charter: Option<String>,
+    /// True if the SubDAO is a DAO whose admin is the DAO that lists
+    /// it as a SubDAO.
+    pub verified: bool,
+    /// The SubDAO's own SubDAOs. Empty if the SubDAO is not a DAO or
+    /// the maximum depth was reached.
+    pub sub_daos: Vec<SubDaoNode>,
+}
+
 #[cw_serde]
 pub struct DaoURIResponse {
     pub dao_uri: Option<String>,
@@ -1,8 +1,11 @@
 use cosmwasm_schema::cw_serde;
-use cw_utils::Expiration;
 
-use cosmwasm_std::{Addr, Empty};
+use cosmwasm_std::{Addr, BlockInfo, Empty, Uint128};
+use cw_denom::CheckedDenom;
+use cw_hooks::Hooks;
 use cw_storage_plus::{Item, Map};
+use cw_utils::{Duration, Expiration};
+use dao_pausable::Pausable;
 
 /// Top level config type for core module.
 #[cw_serde]
@@ -43,6 +46,106 @@ pub enum ProposalModuleStatus {
     Disabled,
 }
 
+/// A part of the DAO's state which the DAO may allow an address other
+/// than itself to manage.
+#[cw_serde]
+pub enum Permission {
+    /// May set and remove items whose keys start with PREFIX. An
+    /// empty prefix allows all items to be managed.
+    SetItems { prefix: String },
+    /// May update the list of cw20 tokens registered with the
+    /// treasury.
+    UpdateCw20List {},
+    /// May update the list of cw721 tokens registered with the
+    /// treasury.
+    UpdateCw721List {},
+    /// May update the list of native denoms registered with the
+    /// treasury. Tokenfactory issuer contracts may be granted this
+    /// to register the denoms they create.
+    UpdateNativeDenomList {},
+    /// May pause the execution of proposals with `PauseExecution`,
+    /// for example an emergency multisig.
+    PauseExecution {},
+}
+
+impl Permission {
+    /// Whether this permission allows the item KEY to be set or
+    /// removed.
+    pub fn allows_item(&self, key: &str) -> bool {
+        match self {
+            Permission::SetItems { prefix } => key.starts_with(prefix.as_str()),
+            _ => false,
+        }
+    }
+}
+
+impl std::fmt::Display for Permission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Permission::SetItems { prefix } => write!(f, "set_items:{prefix}"),
+            Permission::UpdateCw20List {} => write!(f, "update_cw20_list"),
+            Permission::UpdateCw721List {} => write!(f, "update_cw721_list"),
+            Permission::UpdateNativeDenomList {} => write!(f, "update_native_denom_list"),
+            Permission::PauseExecution {} => write!(f, "pause_execution"),
+        }
+    }
+}
+
+/// An allowance to spend up to AMOUNT of DENOM from the DAO's
+/// treasury every PERIOD without a proposal.
+#[cw_serde]
+pub struct SpendLimit {
+    pub denom: CheckedDenom,
+    /// The amount which may be spent each period.
+    pub amount: Uint128,
+    pub period: Duration,
+    /// The amount spent in the current period.
+    pub spent: Uint128,
+    /// When the current period ends. The first spend after this
+    /// starts a new period.
+    pub period_end: Expiration,
+}
+
+impl SpendLimit {
+    /// Gets this limit as of BLOCK, starting a new period if the
+    /// current one has ended.
+    pub fn current(mut self, block: &BlockInfo) -> Self {
+        if self.period_end.is_expired(block) {
+            self.spent = Uint128::zero();
+            self.period_end = self.period.after(block);
+        }
+        self
+    }
+
+    /// The amount which may still be spent in the current period.
+    pub fn remaining(&self) -> Uint128 {
+        self.amount.saturating_sub(self.spent)
+    }
+}
+
+/// Information about a SubDAO of this DAO.
+#[cw_serde]
+pub struct SubDaoInfo {
+    /// The name of the SubDAO.
+    pub name: Option<String>,
+    /// The purpose/constitution for the SubDAO.
+    pub charter: Option<String>,
+    /// The block height at which the SubDAO was added.
+    pub added_at: u64,
+    /// Inactive SubDAOs remain in the SubDAO list, but may be
+    /// filtered out of `ListSubDaos` queries.
+    pub active: bool,
+}
+
+/// The proposal module or admin whose messages the DAO most recently
+/// executed, and the block and transaction it did so in.
+#[cw_serde]
+pub struct Executor {
+    pub address: Addr,
+    pub height: u64,
+    pub transaction: Option<u32>,
+}
+
 /// The admin of the contract. Typically a DAO. The contract admin may
 /// unilaterally execute messages on this contract.
 ///
@@ -63,9 +166,19 @@ pub const NOMINATED_ADMIN: Item<Addr> = Item::new("nominated_admin");
 /// The current configuration of the module.
 pub const CONFIG: Item<Config> = Item::new("config_v2");
 
-/// The time the DAO will unpause. Here be dragons: this is not set if
-/// the DAO has never been paused.
-pub const PAUSED: Item<Expiration> = Item::new("paused");
+/// The time the DAO will unpause, if it has been paused, and who
+/// paused it.
+pub const PAUSED: Pausable = Pausable::new("paused", "pause_metadata");
+
+/// While paused, proposal modules may not execute proposals. Other
+/// actions are unaffected.
+pub const EXECUTION_PAUSED: Pausable =
+    Pausable::new("execution_paused", "execution_pause_metadata");
+
+/// The last proposal module or admin to have the DAO execute
+/// messages. Used to attribute pauses to the module or admin whose
+/// messages paused the DAO.
+pub const EXECUTOR: Item<Executor> = Item::new("executor");
 
 /// The voting module associated with this contract.
 pub const VOTING_MODULE: Item<Addr> = Item::new("voting_module");
@@ -81,6 +194,13 @@ pub const ACTIVE_PROPOSAL_MODULE_COUNT: Item<u32> = Item::new("active_proposal_m
 /// The count of total proposal modules associated with this contract.
 pub const TOTAL_PROPOSAL_MODULE_COUNT: Item<u32> = Item::new("total_proposal_module_count");
 
+/// The module being migrated while its migration is checked.
+pub const PENDING_MIGRATION: Item<Addr> = Item::new("pending_module_migration");
+
+/// The proposal module being replaced while its replacement is
+/// instantiated.
+pub const PENDING_REPLACEMENT: Item<Addr> = Item::new("pending_proposal_module_replacement");
+
 // General purpose KV store for DAO associated state.
 pub const ITEMS: Map<String, String> = Map::new("items");
 
@@ -90,6 +210,32 @@ pub const CW20_LIST: Map<Addr, Empty> = Map::new("cw20s");
 /// Set of cw721 tokens that have been registered with this contract's
 /// treasury.
 pub const CW721_LIST: Map<Addr, Empty> = Map::new("cw721s");
+/// Set of native denoms that have been registered with this
+/// contract's treasury.
+pub const NATIVE_DENOM_LIST: Map<String, Empty> = Map::new("native_denoms");
+
+/// Contracts consulted before the messages of a proposal are
+/// executed. Any of them may reject the messages by erroring.
+pub const MESSAGE_FILTERS: Hooks = Hooks::new("message_filters");
+
+/// The permissions the DAO has granted to addresses other than
+/// itself.
+pub const PERMISSIONS: Map<&Addr, Vec<Permission>> = Map::new("permissions");
+
+/// The spend limits the DAO has granted, keyed by grantee and the
+/// denom they may spend.
+pub const SPEND_LIMITS: Map<(&Addr, String), SpendLimit> = Map::new("spend_limits");
+
+/// List of SubDAOs associated to this DAO.
+/// When we changed the data format of this map from only a charter,
+/// we updated the key (previously "sub_daos"). Entries are moved on
+/// migration.
+pub const SUBDAO_LIST: Map<&Addr, SubDaoInfo> = Map::new("sub_daos_v2");
+
+/// The SubDAO list before it stored `SubDaoInfo`. Each SubDAO has an
+/// optional charter.
+pub const LEGACY_SUBDAO_LIST: Map<&Addr, Option<String>> = Map::new("sub_daos");
 
-/// List of SubDAOs associated to this DAO. Each SubDAO has an optional charter.
-pub const SUBDAO_LIST: Map<&Addr, Option<String>> = Map::new("sub_daos");
+/// Information about the SubDAO being created by `CreateSubDao`,
+/// saved to the SubDAO list once it is instantiated.
+pub const PENDING_SUBDAO: Item<SubDaoInfo> = Item::new("pending_subdao");
@@ -1,28 +1,45 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    from_slice,
-    testing::{mock_dependencies, mock_env},
-    to_binary, Addr, CosmosMsg, Empty, Storage, Uint128, WasmMsg,
+    coin, from_slice,
+    testing::{mock_dependencies, mock_env, mock_info},
+    to_binary, Addr, Attribute, BankMsg, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo,
+    Order, Response, StdError, StdResult, Storage, Uint128, WasmMsg,
 };
 use cw2::ContractVersion;
-use cw_multi_test::{App, Contract, ContractWrapper, Executor};
+use cw_multi_test::{App, BankSudo, Contract, ContractWrapper, Executor, SudoMsg};
+use cw_paginate::PageOrder;
 use cw_storage_plus::{Item, Map};
 use cw_utils::{Duration, Expiration};
 use dao_interface::{
+    message_filter::{MessageFilterExecuteMsg, MessageFilterMsg},
+    migrate::MigrationError,
     voting::{InfoResponse, VotingPowerAtHeightResponse},
     Admin, ModuleInstantiateInfo,
 };
+use dao_pausable::PauseError;
 
 use crate::{
-    contract::{derive_proposal_module_prefix, migrate, CONTRACT_NAME, CONTRACT_VERSION},
-    msg::{ExecuteMsg, InitialItem, InstantiateMsg, MigrateMsg, QueryMsg},
+    contract::{
+        derive_proposal_module_prefix, execute, migrate, query, CONTRACT_NAME, CONTRACT_VERSION,
+    },
+    msg::{
+        ExecuteMsg, FromV1Msg, InitialItem, InstantiateMsg, MigrateMsg, QueryMsg, Section,
+        SweepDestination,
+    },
     query::{
-        AdminNominationResponse, Cw20BalanceResponse, DaoURIResponse, DumpStateResponse,
-        GetItemResponse, PauseInfoResponse, SubDao,
+        AdminNominationResponse, CountResponse, Cw20BalanceResponse, Cw20BalancesResponse,
+        DaoURIResponse, DumpStateResponse, DumpStateSelectiveResponse, DustSweepResponse,
+        GetItemResponse, NativeBalancesResponse, PauseInfoResponse, PermissionGrant,
+        ProposalModuleCountsResponse, SubDao, SubDaoNode,
+    },
+    state::{
+        Config, Permission, ProposalModule, ProposalModuleStatus, SpendLimit, SubDaoInfo,
+        ACTIVE_PROPOSAL_MODULE_COUNT, CONFIG, LEGACY_SUBDAO_LIST, PROPOSAL_MODULES, SUBDAO_LIST,
+        TOTAL_PROPOSAL_MODULE_COUNT,
     },
-    state::{Config, ProposalModule, ProposalModuleStatus, PROPOSAL_MODULES},
     ContractError,
 };
+use prost::Message;
 
 const CREATOR_ADDR: &str = "creator";
 
@@ -53,6 +70,71 @@ fn sudo_proposal_contract() -> Box<dyn Contract<Empty>> {
     Box::new(contract)
 }
 
+/// A message filter which rejects every bank message.
+fn no_bank_msgs_filter() -> Box<dyn Contract<Empty>> {
+    fn execute(
+        _deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        msg: MessageFilterExecuteMsg,
+    ) -> StdResult<Response> {
+        match msg {
+            MessageFilterExecuteMsg::MessageFilterHook(MessageFilterMsg::FilterMessages {
+                msgs,
+                ..
+            }) if msgs.iter().any(|msg| matches!(msg, CosmosMsg::Bank(_))) => {
+                Err(StdError::generic_err("bank messages are not allowed"))
+            }
+            _ => Ok(Response::default()),
+        }
+    }
+    fn instantiate(
+        _deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        _msg: Empty,
+    ) -> StdResult<Response> {
+        Ok(Response::default())
+    }
+    fn query(_deps: Deps, _env: Env, _msg: Empty) -> StdResult<Binary> {
+        to_binary(&Empty {})
+    }
+    Box::new(ContractWrapper::new(execute, instantiate, query))
+}
+
+/// A module which answers `Info` queries until it is migrated with
+/// `true`.
+fn migratable_module() -> Box<dyn Contract<Empty>> {
+    const BROKEN: Item<bool> = Item::new("broken");
+    fn execute(_deps: DepsMut, _env: Env, _info: MessageInfo, _msg: Empty) -> StdResult<Response> {
+        Ok(Response::default())
+    }
+    fn instantiate(
+        _deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        _msg: Empty,
+    ) -> StdResult<Response> {
+        Ok(Response::default())
+    }
+    fn query(deps: Deps, _env: Env, _msg: dao_interface::voting::Query) -> StdResult<Binary> {
+        if BROKEN.may_load(deps.storage)?.unwrap_or_default() {
+            return Err(StdError::generic_err("broken"));
+        }
+        to_binary(&InfoResponse {
+            info: ContractVersion {
+                contract: "migratable-module".to_string(),
+                version: "2.0.0".to_string(),
+            },
+        })
+    }
+    fn migrate(deps: DepsMut, _env: Env, broken: bool) -> StdResult<Response> {
+        BROKEN.save(deps.storage, &broken)?;
+        Ok(Response::default())
+    }
+    Box::new(ContractWrapper::new(execute, instantiate, query).with_migrate(migrate))
+}
+
 fn cw20_balances_voting() -> Box<dyn Contract<Empty>> {
     let contract = ContractWrapper::new(
         dao_voting_cw20_balance::contract::execute,
@@ -135,7 +217,13 @@ fn test_instantiate_with_n_gov_modules(n: usize) {
 
     let state: DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(gov_addr, &QueryMsg::DumpState {})
+        .query_wasm_smart(
+            gov_addr.clone(),
+            &QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
 
     assert_eq!(
@@ -154,6 +242,31 @@ fn test_instantiate_with_n_gov_modules(n: usize) {
 
     assert_eq!(state.active_proposal_module_count, n as u32);
     assert_eq!(state.total_proposal_module_count, n as u32);
+    assert_eq!(state.next_start_after, None);
+
+    // Paging through the proposal modules lists each of them once.
+    let mut proposal_modules = vec![];
+    let mut start_after = None;
+    loop {
+        let page: DumpStateResponse = app
+            .wrap()
+            .query_wasm_smart(
+                gov_addr.clone(),
+                &QueryMsg::DumpState {
+                    start_after: start_after.map(|addr: Addr| addr.into_string()),
+                    limit: Some(2),
+                },
+            )
+            .unwrap();
+        assert!(page.proposal_modules.len() <= 2);
+        assert_eq!(page.total_proposal_module_count, n as u32);
+        proposal_modules.extend(page.proposal_modules);
+        start_after = page.next_start_after;
+        if start_after.is_none() {
+            break;
+        }
+    }
+    assert_eq!(proposal_modules, state.proposal_modules);
 }
 
 #[test]
@@ -447,7 +560,13 @@ fn test_swap_governance(swaps: Vec<(u32, u32)>) {
 
         let state: DumpStateResponse = app
             .wrap()
-            .query_wasm_smart(gov_addr.clone(), &QueryMsg::DumpState {})
+            .query_wasm_smart(
+                gov_addr.clone(),
+                &QueryMsg::DumpState {
+                    start_after: None,
+                    limit: None,
+                },
+            )
             .unwrap();
 
         assert_eq!(
@@ -458,7 +577,15 @@ fn test_swap_governance(swaps: Vec<(u32, u32)>) {
         assert_eq!(
             state.total_proposal_module_count,
             start_modules.len() as u32 + add
-        )
+        );
+
+        let counts: ProposalModuleCountsResponse = app
+            .wrap()
+            .query_wasm_smart(gov_addr.clone(), &QueryMsg::ProposalModuleCounts {})
+            .unwrap();
+        assert!(counts.consistent);
+        assert_eq!(counts.stored.active, state.active_proposal_module_count);
+        assert_eq!(counts.stored.total, state.total_proposal_module_count);
     }
 }
 
@@ -904,6 +1031,12 @@ fn test_permissions() {
         },
     );
 
+    test_unauthorized(
+        &mut app,
+        gov_addr.clone(),
+        ExecuteMsg::RepairProposalModuleCounts {},
+    );
+
     test_unauthorized(
         &mut app,
         gov_addr,
@@ -922,6 +1055,11 @@ fn test_permissions() {
 
 fn do_standard_instantiate(auto_add: bool, admin: Option<String>) -> (Addr, App) {
     let mut app = App::default();
+    let gov_addr = instantiate_standard_dao(&mut app, auto_add, admin);
+    (gov_addr, app)
+}
+
+fn instantiate_standard_dao(app: &mut App, auto_add: bool, admin: Option<String>) -> Addr {
     let govmod_id = app.store_code(sudo_proposal_contract());
     let voting_id = app.store_code(cw20_balances_voting());
     let gov_id = app.store_code(cw_core_contract());
@@ -968,18 +1106,15 @@ fn do_standard_instantiate(auto_add: bool, admin: Option<String>) -> (Addr, App)
         initial_items: None,
     };
 
-    let gov_addr = app
-        .instantiate_contract(
-            gov_id,
-            Addr::unchecked(CREATOR_ADDR),
-            &gov_instantiate,
-            &[],
-            "cw-governance",
-            None,
-        )
-        .unwrap();
-
-    (gov_addr, app)
+    app.instantiate_contract(
+        gov_id,
+        Addr::unchecked(CREATOR_ADDR),
+        &gov_instantiate,
+        &[],
+        "cw-governance",
+        None,
+    )
+    .unwrap()
 }
 
 #[test]
@@ -1010,6 +1145,7 @@ fn test_admin_permissions() {
                 contract_addr: core_addr.to_string(),
                 msg: to_binary(&ExecuteMsg::Pause {
                     duration: Duration::Height(10),
+                    reason: None,
                 })
                 .unwrap(),
                 funds: vec![],
@@ -1029,6 +1165,7 @@ fn test_admin_permissions() {
                 contract_addr: core_addr.to_string(),
                 msg: to_binary(&ExecuteMsg::Pause {
                     duration: Duration::Height(10),
+                    reason: None,
                 })
                 .unwrap(),
                 funds: vec![],
@@ -1083,6 +1220,7 @@ fn test_admin_permissions() {
                 contract_addr: core_with_admin_addr.to_string(),
                 msg: to_binary(&ExecuteMsg::Pause {
                     duration: Duration::Height(10),
+                    reason: None,
                 })
                 .unwrap(),
                 funds: vec![],
@@ -1102,6 +1240,7 @@ fn test_admin_permissions() {
                 contract_addr: core_with_admin_addr.to_string(),
                 msg: to_binary(&ExecuteMsg::Pause {
                     duration: Duration::Height(10),
+                    reason: None,
                 })
                 .unwrap(),
                 funds: vec![],
@@ -1119,7 +1258,10 @@ fn test_admin_permissions() {
     assert_eq!(
         paused,
         PauseInfoResponse::Paused {
-            expiration: Expiration::AtHeight(start_height + 10)
+            expiration: Expiration::AtHeight(start_height + 10),
+            paused_by: Some(Addr::unchecked("admin")),
+            reason: None,
+            duration: Some(Duration::Height(10)),
         }
     );
 
@@ -1347,6 +1489,7 @@ fn test_admin_nomination() {
                     contract_addr: core_addr.to_string(),
                     msg: to_binary(&ExecuteMsg::Pause {
                         duration: Duration::Height(10),
+                        reason: None,
                     })
                     .unwrap(),
                     funds: vec![],
@@ -1368,6 +1511,7 @@ fn test_admin_nomination() {
                 contract_addr: core_addr.to_string(),
                 msg: to_binary(&ExecuteMsg::Pause {
                     duration: Duration::Height(10),
+                    reason: None,
                 })
                 .unwrap(),
                 funds: vec![],
@@ -1385,7 +1529,10 @@ fn test_admin_nomination() {
     assert_eq!(
         paused,
         PauseInfoResponse::Paused {
-            expiration: Expiration::AtHeight(start_height + 10)
+            expiration: Expiration::AtHeight(start_height + 10),
+            paused_by: Some(Addr::unchecked("meow")),
+            reason: None,
+            duration: Some(Duration::Height(10)),
         }
     );
 
@@ -1479,6 +1626,8 @@ fn list_items(
             &QueryMsg::ListItems {
                 start_after: start_at,
                 limit,
+                prefix: None,
+                order: None,
             },
         )
         .unwrap()
@@ -1668,6 +1817,104 @@ fn test_list_items() {
     );
 }
 
+#[test]
+fn test_list_items_with_prefix() {
+    let (gov_addr, mut app) = do_standard_instantiate(true, None);
+
+    for (key, value) in [
+        ("payroll", "bare"),
+        ("payroll:ekez", "10"),
+        ("payroll:keze", "20"),
+        ("payroll:zeke", "30"),
+        ("payrolm", "after"),
+        ("pay", "before"),
+    ] {
+        set_item(
+            &mut app,
+            gov_addr.clone(),
+            key.to_string(),
+            value.to_string(),
+        );
+    }
+
+    let list = |app: &App, start_after: Option<&str>, limit: Option<u32>| {
+        let items: Vec<(String, String)> = app
+            .wrap()
+            .query_wasm_smart(
+                gov_addr.clone(),
+                &QueryMsg::ListItems {
+                    start_after: start_after.map(str::to_string),
+                    limit,
+                    prefix: Some("payroll:".to_string()),
+                    order: None,
+                },
+            )
+            .unwrap();
+        items
+    };
+
+    let expected = vec![
+        ("payroll:zeke".to_string(), "30".to_string()),
+        ("payroll:keze".to_string(), "20".to_string()),
+        ("payroll:ekez".to_string(), "10".to_string()),
+    ];
+    assert_eq!(list(&app, None, None), expected);
+    assert_eq!(list(&app, None, Some(2)), expected[..2].to_vec());
+    assert_eq!(
+        list(&app, Some("payroll:keze"), None),
+        expected[2..].to_vec()
+    );
+    // Starting after a key past the end of the prefix lists every
+    // item with the prefix.
+    assert_eq!(list(&app, Some("zzz"), None), expected);
+    assert!(list(&app, Some("pay"), None).is_empty());
+
+    let ascending: Vec<(String, String)> = app
+        .wrap()
+        .query_wasm_smart(
+            gov_addr.clone(),
+            &QueryMsg::ListItems {
+                start_after: Some("payroll:ekez".to_string()),
+                limit: None,
+                prefix: Some("payroll:".to_string()),
+                order: Some(PageOrder::Ascending),
+            },
+        )
+        .unwrap();
+    assert_eq!(ascending, vec![expected[1].clone(), expected[0].clone()]);
+
+    let count = |app: &App, prefix: Option<&str>| {
+        let count: CountResponse = app
+            .wrap()
+            .query_wasm_smart(
+                gov_addr.clone(),
+                &QueryMsg::ItemCount {
+                    prefix: prefix.map(str::to_string),
+                },
+            )
+            .unwrap();
+        count.count
+    };
+    assert_eq!(count(&app, Some("payroll:")), 3);
+    assert_eq!(count(&app, Some("payroll")), 4);
+    assert_eq!(count(&app, None), 6);
+
+    // An empty prefix lists all items.
+    let all: Vec<(String, String)> = app
+        .wrap()
+        .query_wasm_smart(
+            gov_addr,
+            &QueryMsg::ListItems {
+                start_after: None,
+                limit: None,
+                prefix: Some("".to_string()),
+                order: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(all.len(), 6);
+}
+
 #[test]
 fn test_instantiate_with_items() {
     let mut app = App::default();
@@ -1796,17 +2043,18 @@ fn test_cw20_receive_auto_add() {
         .unwrap();
 
     // Check that the balances query works with no tokens.
-    let cw20_balances: Vec<Cw20BalanceResponse> = app
+    let cw20_balances: Cw20BalancesResponse = app
         .wrap()
         .query_wasm_smart(
             gov_addr.clone(),
             &QueryMsg::Cw20Balances {
                 start_after: None,
                 limit: None,
+                min_balance: None,
             },
         )
         .unwrap();
-    assert_eq!(cw20_balances, vec![]);
+    assert_eq!(cw20_balances.balances, vec![]);
 
     // Send a gov token to the governance contract.
     app.execute_contract(
@@ -1828,23 +2076,25 @@ fn test_cw20_receive_auto_add() {
             &QueryMsg::Cw20TokenList {
                 start_after: None,
                 limit: None,
+                order: None,
             },
         )
         .unwrap();
     assert_eq!(cw20_list, vec![gov_token.clone()]);
 
-    let cw20_balances: Vec<Cw20BalanceResponse> = app
+    let cw20_balances: Cw20BalancesResponse = app
         .wrap()
         .query_wasm_smart(
             gov_addr.clone(),
             &QueryMsg::Cw20Balances {
                 start_after: None,
                 limit: None,
+                min_balance: None,
             },
         )
         .unwrap();
     assert_eq!(
-        cw20_balances,
+        cw20_balances.balances,
         vec![Cw20BalanceResponse {
             addr: gov_token.clone(),
             balance: Uint128::new(1),
@@ -1897,14 +2147,21 @@ fn test_cw20_receive_auto_add() {
     let cw20_list: Vec<Addr> = app
         .wrap()
         .query_wasm_smart(
-            gov_addr,
+            gov_addr.clone(),
             &QueryMsg::Cw20TokenList {
                 start_after: None,
                 limit: None,
+                order: None,
             },
         )
         .unwrap();
     assert_eq!(cw20_list, vec![another_cw20]);
+
+    let count: CountResponse = app
+        .wrap()
+        .query_wasm_smart(gov_addr, &QueryMsg::Cw20TokenCount {})
+        .unwrap();
+    assert_eq!(count.count, 1);
 }
 
 #[test]
@@ -1963,6 +2220,7 @@ fn test_cw20_receive_no_auto_add() {
             &QueryMsg::Cw20TokenList {
                 start_after: None,
                 limit: None,
+                order: None,
             },
         )
         .unwrap();
@@ -1986,6 +2244,7 @@ fn test_cw20_receive_no_auto_add() {
             &QueryMsg::Cw20TokenList {
                 start_after: None,
                 limit: None,
+                order: None,
             },
         )
         .unwrap();
@@ -1993,120 +2252,145 @@ fn test_cw20_receive_no_auto_add() {
 }
 
 #[test]
-fn test_cw721_receive() {
+fn test_cw20_balance_maintenance() {
     let (gov_addr, mut app) = do_standard_instantiate(true, None);
 
-    let cw721_id = app.store_code(cw721_contract());
-
-    let cw721_addr = app
+    let cw20_id = app.store_code(cw20_contract());
+    let dust_cw20 = app
         .instantiate_contract(
-            cw721_id,
+            cw20_id,
             Addr::unchecked(CREATOR_ADDR),
-            &cw721_base::msg::InstantiateMsg {
-                name: "ekez".to_string(),
-                symbol: "ekez".to_string(),
-                minter: CREATOR_ADDR.to_string(),
+            &cw20_base::msg::InstantiateMsg {
+                name: "Dust".to_string(),
+                symbol: "DUST".to_string(),
+                decimals: 6,
+                initial_balances: vec![],
+                mint: None,
+                marketing: None,
             },
             &[],
-            "cw721",
+            "dust-token",
             None,
         )
         .unwrap();
 
-    let another_cw721 = app
-        .instantiate_contract(
-            cw721_id,
-            Addr::unchecked(CREATOR_ADDR),
-            &cw721_base::msg::InstantiateMsg {
-                name: "ekez".to_string(),
-                symbol: "ekez".to_string(),
-                minter: CREATOR_ADDR.to_string(),
-            },
-            &[],
-            "cw721",
-            None,
+    let voting_module: Addr = app
+        .wrap()
+        .query_wasm_smart(gov_addr.clone(), &QueryMsg::VotingModule {})
+        .unwrap();
+    let gov_token: Addr = app
+        .wrap()
+        .query_wasm_smart(
+            voting_module,
+            &dao_interface::voting::Query::TokenContract {},
         )
         .unwrap();
 
     app.execute_contract(
         Addr::unchecked(CREATOR_ADDR),
-        cw721_addr.clone(),
-        &cw721_base::msg::ExecuteMsg::<Option<Empty>, Empty>::Mint(cw721_base::msg::MintMsg::<
-            Option<Empty>,
-        > {
-            token_id: "ekez".to_string(),
-            owner: CREATOR_ADDR.to_string(),
-            token_uri: None,
-            extension: None,
-        }),
+        gov_token.clone(),
+        &cw20::Cw20ExecuteMsg::Send {
+            contract: gov_addr.to_string(),
+            amount: Uint128::new(10),
+            msg: to_binary(&"").unwrap(),
+        },
         &[],
     )
     .unwrap();
-
     app.execute_contract(
-        Addr::unchecked(CREATOR_ADDR),
-        cw721_addr.clone(),
-        &cw721_base::msg::ExecuteMsg::<Option<Empty>, Empty>::SendNft {
-            contract: gov_addr.to_string(),
-            token_id: "ekez".to_string(),
-            msg: to_binary("").unwrap(),
+        gov_addr.clone(),
+        gov_addr.clone(),
+        &ExecuteMsg::UpdateCw20List {
+            to_add: vec![dust_cw20.to_string()],
+            to_remove: vec![],
         },
         &[],
     )
     .unwrap();
 
-    let cw721_list: Vec<Addr> = app
+    // Balances are listed in descending order.
+    let page: Cw20BalancesResponse = app
         .wrap()
         .query_wasm_smart(
             gov_addr.clone(),
-            &QueryMsg::Cw721TokenList {
+            &QueryMsg::Cw20Balances {
                 start_after: None,
-                limit: None,
+                limit: Some(1),
+                min_balance: None,
             },
         )
         .unwrap();
-    assert_eq!(cw721_list, vec![cw721_addr.clone()]);
+    assert_eq!(
+        page,
+        Cw20BalancesResponse {
+            balances: vec![Cw20BalanceResponse {
+                addr: dust_cw20.clone(),
+                balance: Uint128::zero(),
+            }],
+            has_more: true,
+            next_start_after: Some(dust_cw20.clone()),
+        }
+    );
+    let page: Cw20BalancesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            gov_addr.clone(),
+            &QueryMsg::Cw20Balances {
+                start_after: Some(dust_cw20.to_string()),
+                limit: Some(1),
+                min_balance: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        page,
+        Cw20BalancesResponse {
+            balances: vec![Cw20BalanceResponse {
+                addr: gov_token.clone(),
+                balance: Uint128::new(10),
+            }],
+            has_more: false,
+            next_start_after: None,
+        }
+    );
 
-    // Try to add an invalid cw721.
-    let err: ContractError = app
-        .execute_contract(
-            Addr::unchecked(gov_addr.clone()),
+    // Balances below the minimum are skipped.
+    let page: Cw20BalancesResponse = app
+        .wrap()
+        .query_wasm_smart(
             gov_addr.clone(),
-            &ExecuteMsg::UpdateCw721List {
-                to_add: vec!["new".to_string(), cw721_addr.to_string()],
-                to_remove: vec![cw721_addr.to_string()],
+            &QueryMsg::Cw20Balances {
+                start_after: None,
+                limit: Some(1),
+                min_balance: Some(Uint128::new(1)),
             },
-            &[],
         )
-        .unwrap_err()
-        .downcast()
         .unwrap();
-    assert!(matches!(err, ContractError::Std(_)));
+    assert_eq!(
+        page.balances,
+        vec![Cw20BalanceResponse {
+            addr: gov_token.clone(),
+            balance: Uint128::new(10),
+        }]
+    );
+    assert!(!page.has_more);
 
-    // Test that non-DAO can not update the list.
     let err: ContractError = app
         .execute_contract(
             Addr::unchecked("ekez"),
             gov_addr.clone(),
-            &ExecuteMsg::UpdateCw721List {
-                to_add: vec![],
-                to_remove: vec![cw721_addr.to_string()],
-            },
+            &ExecuteMsg::RemoveZeroBalances { limit: None },
             &[],
         )
         .unwrap_err()
         .downcast()
         .unwrap();
-    assert!(matches!(err, ContractError::Unauthorized {}));
+    assert_eq!(err, ContractError::Unauthorized {});
 
-    // Add a real cw721.
     app.execute_contract(
-        Addr::unchecked(gov_addr.clone()),
         gov_addr.clone(),
-        &ExecuteMsg::UpdateCw721List {
-            to_add: vec![another_cw721.to_string(), cw721_addr.to_string()],
-            to_remove: vec![cw721_addr.to_string()],
-        },
+        gov_addr.clone(),
+        &ExecuteMsg::RemoveZeroBalances { limit: Some(1) },
         &[],
     )
     .unwrap();
@@ -2115,25 +2399,162 @@ fn test_cw721_receive() {
         .wrap()
         .query_wasm_smart(
             gov_addr,
-            &QueryMsg::Cw721TokenList {
+            &QueryMsg::Cw20TokenList {
                 start_after: None,
                 limit: None,
+                order: None,
             },
         )
         .unwrap();
-    assert_eq!(cw20_list, vec![another_cw721]);
+    assert_eq!(cw20_list, vec![gov_token]);
 }
 
 #[test]
-fn test_cw721_receive_no_auto_add() {
-    let (gov_addr, mut app) = do_standard_instantiate(false, None);
-
-    let cw721_id = app.store_code(cw721_contract());
+fn test_sweep_dust() {
+    let (gov_addr, mut app) = do_standard_instantiate(true, None);
 
-    let cw721_addr = app
-        .instantiate_contract(
-            cw721_id,
-            Addr::unchecked(CREATOR_ADDR),
+    let voting_module: Addr = app
+        .wrap()
+        .query_wasm_smart(gov_addr.clone(), &QueryMsg::VotingModule {})
+        .unwrap();
+    let gov_token: Addr = app
+        .wrap()
+        .query_wasm_smart(
+            voting_module,
+            &dao_interface::voting::Query::TokenContract {},
+        )
+        .unwrap();
+
+    // Sending tokens to the DAO adds them to its cw20 list.
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        gov_token.clone(),
+        &cw20::Cw20ExecuteMsg::Send {
+            contract: gov_addr.to_string(),
+            amount: Uint128::new(10),
+            msg: to_binary(&"").unwrap(),
+        },
+        &[],
+    )
+    .unwrap();
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: gov_addr.to_string(),
+        amount: vec![coin(5, "ujuno"), coin(1000, "uatom")],
+    }))
+    .unwrap();
+
+    let dust: DustSweepResponse = app
+        .wrap()
+        .query_wasm_smart(
+            gov_addr.clone(),
+            &QueryMsg::DustSweep {
+                threshold: Uint128::new(100),
+                destination: SweepDestination::Address {
+                    address: "ekez".to_string(),
+                },
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        dust,
+        DustSweepResponse {
+            native: vec![coin(5, "ujuno")],
+            cw20: vec![Cw20BalanceResponse {
+                addr: gov_token.clone(),
+                balance: Uint128::new(10),
+            }],
+        }
+    );
+
+    // cw20 tokens can not be sent to the community pool.
+    let dust: DustSweepResponse = app
+        .wrap()
+        .query_wasm_smart(
+            gov_addr.clone(),
+            &QueryMsg::DustSweep {
+                threshold: Uint128::new(100),
+                destination: SweepDestination::CommunityPool {},
+            },
+        )
+        .unwrap();
+    assert_eq!(dust.native, vec![coin(5, "ujuno")]);
+    assert_eq!(dust.cw20, vec![]);
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            gov_addr.clone(),
+            &ExecuteMsg::SweepDust {
+                threshold: Uint128::new(100),
+                destination: SweepDestination::Burn {},
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    app.execute_contract(
+        gov_addr.clone(),
+        gov_addr.clone(),
+        &ExecuteMsg::SweepDust {
+            threshold: Uint128::new(100),
+            destination: SweepDestination::Address {
+                address: "ekez".to_string(),
+            },
+        },
+        &[],
+    )
+    .unwrap();
+
+    assert_eq!(
+        app.wrap().query_all_balances("ekez").unwrap(),
+        vec![coin(5, "ujuno")]
+    );
+    let balance: cw20::BalanceResponse = app
+        .wrap()
+        .query_wasm_smart(
+            gov_token,
+            &cw20::Cw20QueryMsg::Balance {
+                address: "ekez".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(balance.balance, Uint128::new(10));
+
+    // Balances at or above the threshold are kept.
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: gov_addr.to_string(),
+        amount: vec![coin(3, "ujuno")],
+    }))
+    .unwrap();
+    app.execute_contract(
+        gov_addr.clone(),
+        gov_addr.clone(),
+        &ExecuteMsg::SweepDust {
+            threshold: Uint128::new(1000),
+            destination: SweepDestination::Burn {},
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        app.wrap().query_all_balances(gov_addr).unwrap(),
+        vec![coin(1000, "uatom")]
+    );
+}
+
+#[test]
+fn test_cw721_receive() {
+    let (gov_addr, mut app) = do_standard_instantiate(true, None);
+
+    let cw721_id = app.store_code(cw721_contract());
+
+    let cw721_addr = app
+        .instantiate_contract(
+            cw721_id,
+            Addr::unchecked(CREATOR_ADDR),
             &cw721_base::msg::InstantiateMsg {
                 name: "ekez".to_string(),
                 symbol: "ekez".to_string(),
@@ -2194,22 +2615,51 @@ fn test_cw721_receive_no_auto_add() {
             &QueryMsg::Cw721TokenList {
                 start_after: None,
                 limit: None,
+                order: None,
             },
         )
         .unwrap();
-    assert_eq!(cw721_list, Vec::<Addr>::new());
+    assert_eq!(cw721_list, vec![cw721_addr.clone()]);
 
-    // Duplicates OK. Just adds one.
+    // Try to add an invalid cw721.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(gov_addr.clone()),
+            gov_addr.clone(),
+            &ExecuteMsg::UpdateCw721List {
+                to_add: vec!["new".to_string(), cw721_addr.to_string()],
+                to_remove: vec![cw721_addr.to_string()],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::Std(_)));
+
+    // Test that non-DAO can not update the list.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            gov_addr.clone(),
+            &ExecuteMsg::UpdateCw721List {
+                to_add: vec![],
+                to_remove: vec![cw721_addr.to_string()],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    // Add a real cw721.
     app.execute_contract(
         Addr::unchecked(gov_addr.clone()),
         gov_addr.clone(),
         &ExecuteMsg::UpdateCw721List {
-            to_add: vec![
-                another_cw721.to_string(),
-                cw721_addr.to_string(),
-                cw721_addr.to_string(),
-            ],
-            to_remove: vec![],
+            to_add: vec![another_cw721.to_string(), cw721_addr.to_string()],
+            to_remove: vec![cw721_addr.to_string()],
         },
         &[],
     )
@@ -2222,251 +2672,414 @@ fn test_cw721_receive_no_auto_add() {
             &QueryMsg::Cw721TokenList {
                 start_after: None,
                 limit: None,
+                order: None,
             },
         )
         .unwrap();
-    assert_eq!(cw20_list, vec![another_cw721, cw721_addr]);
+    assert_eq!(cw20_list, vec![another_cw721]);
 }
 
 #[test]
-fn test_pause() {
-    let (core_addr, mut app) = do_standard_instantiate(false, None);
+fn test_cw721_receive_no_auto_add() {
+    let (gov_addr, mut app) = do_standard_instantiate(false, None);
 
-    let start_height = app.block_info().height;
+    let cw721_id = app.store_code(cw721_contract());
 
-    let proposal_modules: Vec<ProposalModule> = app
-        .wrap()
-        .query_wasm_smart(
-            core_addr.clone(),
-            &QueryMsg::ProposalModules {
-                start_after: None,
-                limit: None,
+    let cw721_addr = app
+        .instantiate_contract(
+            cw721_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &cw721_base::msg::InstantiateMsg {
+                name: "ekez".to_string(),
+                symbol: "ekez".to_string(),
+                minter: CREATOR_ADDR.to_string(),
             },
+            &[],
+            "cw721",
+            None,
         )
         .unwrap();
 
-    assert_eq!(proposal_modules.len(), 1);
-    let proposal_module = proposal_modules.into_iter().next().unwrap();
-
-    let paused: PauseInfoResponse = app
-        .wrap()
-        .query_wasm_smart(core_addr.clone(), &QueryMsg::PauseInfo {})
-        .unwrap();
-    assert_eq!(paused, PauseInfoResponse::Unpaused {});
-    let all_state: DumpStateResponse = app
-        .wrap()
-        .query_wasm_smart(core_addr.clone(), &QueryMsg::DumpState {})
+    let another_cw721 = app
+        .instantiate_contract(
+            cw721_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &cw721_base::msg::InstantiateMsg {
+                name: "ekez".to_string(),
+                symbol: "ekez".to_string(),
+                minter: CREATOR_ADDR.to_string(),
+            },
+            &[],
+            "cw721",
+            None,
+        )
         .unwrap();
-    assert_eq!(all_state.pause_info, PauseInfoResponse::Unpaused {});
 
-    // DAO is not paused. Check that we can execute things.
-    //
-    // Tests intentionally use the core address to send these
-    // messsages to simulate a worst case scenerio where the core
-    // contract has a vulnerability.
     app.execute_contract(
-        core_addr.clone(),
-        core_addr.clone(),
-        &ExecuteMsg::UpdateConfig {
-            config: Config {
-                dao_uri: None,
-                name: "The Empire Strikes Back".to_string(),
-                description: "haha lol we have pwned your DAO".to_string(),
-                image_url: None,
-                automatically_add_cw20s: true,
-                automatically_add_cw721s: true,
-            },
-        },
+        Addr::unchecked(CREATOR_ADDR),
+        cw721_addr.clone(),
+        &cw721_base::msg::ExecuteMsg::<Option<Empty>, Empty>::Mint(cw721_base::msg::MintMsg::<
+            Option<Empty>,
+        > {
+            token_id: "ekez".to_string(),
+            owner: CREATOR_ADDR.to_string(),
+            token_uri: None,
+            extension: None,
+        }),
         &[],
     )
     .unwrap();
 
-    // Oh no the DAO is under attack! Quick! Pause the DAO while we
-    // figure out what to do!
-    let err: ContractError = app
-        .execute_contract(
-            proposal_module.address.clone(),
-            core_addr.clone(),
-            &ExecuteMsg::Pause {
-                duration: Duration::Height(10),
-            },
-            &[],
-        )
-        .unwrap_err()
-        .downcast()
-        .unwrap();
-
-    // Only the DAO may call this on itself. Proposal modules must use
-    // the execute hook.
-    assert_eq!(err, ContractError::Unauthorized {});
-
     app.execute_contract(
-        proposal_module.address.clone(),
-        core_addr.clone(),
-        &ExecuteMsg::ExecuteProposalHook {
-            msgs: vec![WasmMsg::Execute {
-                contract_addr: core_addr.to_string(),
-                msg: to_binary(&ExecuteMsg::Pause {
-                    duration: Duration::Height(10),
-                })
-                .unwrap(),
-                funds: vec![],
-            }
-            .into()],
+        Addr::unchecked(CREATOR_ADDR),
+        cw721_addr.clone(),
+        &cw721_base::msg::ExecuteMsg::<Option<Empty>, Empty>::SendNft {
+            contract: gov_addr.to_string(),
+            token_id: "ekez".to_string(),
+            msg: to_binary("").unwrap(),
         },
         &[],
     )
     .unwrap();
 
-    let paused: PauseInfoResponse = app
+    let cw721_list: Vec<Addr> = app
         .wrap()
-        .query_wasm_smart(core_addr.clone(), &QueryMsg::PauseInfo {})
-        .unwrap();
-    assert_eq!(
-        paused,
-        PauseInfoResponse::Paused {
-            expiration: Expiration::AtHeight(start_height + 10)
-        }
-    );
-    let all_state: DumpStateResponse = app
+        .query_wasm_smart(
+            gov_addr.clone(),
+            &QueryMsg::Cw721TokenList {
+                start_after: None,
+                limit: None,
+                order: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(cw721_list, Vec::<Addr>::new());
+
+    // Duplicates OK. Just adds one.
+    app.execute_contract(
+        Addr::unchecked(gov_addr.clone()),
+        gov_addr.clone(),
+        &ExecuteMsg::UpdateCw721List {
+            to_add: vec![
+                another_cw721.to_string(),
+                cw721_addr.to_string(),
+                cw721_addr.to_string(),
+            ],
+            to_remove: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let cw20_list: Vec<Addr> = app
         .wrap()
-        .query_wasm_smart(core_addr.clone(), &QueryMsg::DumpState {})
+        .query_wasm_smart(
+            gov_addr,
+            &QueryMsg::Cw721TokenList {
+                start_after: None,
+                limit: None,
+                order: None,
+            },
+        )
         .unwrap();
-    assert_eq!(
-        all_state.pause_info,
-        PauseInfoResponse::Paused {
-            expiration: Expiration::AtHeight(start_height + 10)
-        }
-    );
+    assert_eq!(cw20_list, vec![another_cw721, cw721_addr]);
+}
 
-    let err: ContractError = app
-        .execute_contract(
-            core_addr.clone(),
+#[test]
+fn test_pause_execution() {
+    let (core_addr, mut app) = do_standard_instantiate(false, None);
+    let start_height = app.block_info().height;
+    let guardian = Addr::unchecked("guardian");
+    let proposal_module = get_active_modules(&app, core_addr.clone())
+        .into_iter()
+        .next()
+        .unwrap()
+        .address;
+
+    let pause_execution = |app: &mut App, sender: &Addr| {
+        app.execute_contract(
+            sender.clone(),
             core_addr.clone(),
-            &ExecuteMsg::UpdateConfig {
-                config: Config {
-                    dao_uri: None,
-                    name: "The Empire Strikes Back Again".to_string(),
-                    description: "haha lol we have pwned your DAO again".to_string(),
-                    image_url: None,
-                    automatically_add_cw20s: true,
-                    automatically_add_cw721s: true,
-                },
+            &ExecuteMsg::PauseExecution {
+                duration: Duration::Height(10),
+                reason: Some("bad proposal".to_string()),
             },
             &[],
         )
+    };
+    let execute_proposal = |app: &mut App| {
+        app.execute_contract(
+            proposal_module.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteProposalHook { msgs: vec![] },
+            &[],
+        )
+    };
+
+    let err: ContractError = pause_execution(&mut app, &guardian)
         .unwrap_err()
         .downcast()
         .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
 
-    assert!(matches!(err, ContractError::Paused { .. }));
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::GrantPermission {
+            address: guardian.to_string(),
+            permission: Permission::PauseExecution {},
+        },
+        &[],
+    )
+    .unwrap();
+    pause_execution(&mut app, &guardian).unwrap();
+
+    let paused: PauseInfoResponse = app
+        .wrap()
+        .query_wasm_smart(core_addr.clone(), &QueryMsg::ExecutionPauseInfo {})
+        .unwrap();
+    assert_eq!(
+        paused,
+        PauseInfoResponse::Paused {
+            expiration: Expiration::AtHeight(start_height + 10),
+            paused_by: Some(guardian.clone()),
+            reason: Some("bad proposal".to_string()),
+            duration: Some(Duration::Height(10)),
+        }
+    );
+    let paused: PauseInfoResponse = app
+        .wrap()
+        .query_wasm_smart(core_addr.clone(), &QueryMsg::PauseInfo {})
+        .unwrap();
+    assert_eq!(paused, PauseInfoResponse::Unpaused {});
+
+    let err: ContractError = execute_proposal(&mut app).unwrap_err().downcast().unwrap();
+    assert_eq!(err, ContractError::ExecutionPaused {});
+
+    // Other actions are not paused.
+    set_item(
+        &mut app,
+        core_addr.clone(),
+        "key".to_string(),
+        "value".to_string(),
+    );
 
+    // Only the DAO may unpause execution.
     let err: ContractError = app
         .execute_contract(
-            proposal_module.address.clone(),
+            guardian.clone(),
             core_addr.clone(),
-            &ExecuteMsg::ExecuteProposalHook {
-                msgs: vec![WasmMsg::Execute {
-                    contract_addr: core_addr.to_string(),
-                    msg: to_binary(&ExecuteMsg::Pause {
-                        duration: Duration::Height(10),
-                    })
-                    .unwrap(),
-                    funds: vec![],
-                }
-                .into()],
-            },
+            &ExecuteMsg::UnpauseExecution {},
             &[],
         )
         .unwrap_err()
         .downcast()
         .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UnpauseExecution {},
+        &[],
+    )
+    .unwrap();
+    execute_proposal(&mut app).unwrap();
 
-    assert!(matches!(err, ContractError::Paused { .. }));
+    // Execution pauses expire.
+    pause_execution(&mut app, &guardian).unwrap();
+    execute_proposal(&mut app).unwrap_err();
+    app.update_block(|mut block| block.height += 10);
+    execute_proposal(&mut app).unwrap();
+}
 
-    app.update_block(|mut block| block.height += 9);
+#[test]
+fn test_admin_unpause_and_extend_pause() {
+    let (core_addr, mut app) = do_standard_instantiate(false, Some("admin".to_string()));
+    let start_height = app.block_info().height;
+    let admin = Addr::unchecked("admin");
 
-    // Still not unpaused.
-    let err: ContractError = app
-        .execute_contract(
-            proposal_module.address.clone(),
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::Pause {
+            duration: Duration::Height(10),
+            reason: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let extend_pause = |app: &mut App, sender: &Addr, height: u64| {
+        app.execute_contract(
+            sender.clone(),
             core_addr.clone(),
-            &ExecuteMsg::ExecuteProposalHook {
-                msgs: vec![WasmMsg::Execute {
-                    contract_addr: core_addr.to_string(),
-                    msg: to_binary(&ExecuteMsg::Pause {
-                        duration: Duration::Height(10),
-                    })
-                    .unwrap(),
-                    funds: vec![],
-                }
-                .into()],
+            &ExecuteMsg::ExtendPause {
+                expiration: Expiration::AtHeight(height),
             },
             &[],
         )
+    };
+    let unpause = |app: &mut App, sender: &Addr| {
+        app.execute_contract(
+            sender.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::Unpause {},
+            &[],
+        )
+    };
+    let pause_expiration = |app: &App| {
+        let paused: PauseInfoResponse = app
+            .wrap()
+            .query_wasm_smart(core_addr.clone(), &QueryMsg::PauseInfo {})
+            .unwrap();
+        match paused {
+            PauseInfoResponse::Paused { expiration, .. } => Some(expiration),
+            PauseInfoResponse::Unpaused {} => None,
+        }
+    };
+
+    // Only the admin may end or extend the pause. The paused DAO
+    // may not.
+    for sender in [&core_addr, &Addr::unchecked("ekez")] {
+        let err: ContractError = unpause(&mut app, sender).unwrap_err().downcast().unwrap();
+        assert_eq!(err, ContractError::Unauthorized {});
+        let err: ContractError = extend_pause(&mut app, sender, start_height + 20)
+            .unwrap_err()
+            .downcast()
+            .unwrap();
+        assert_eq!(err, ContractError::Unauthorized {});
+    }
+
+    // Pauses may not be shortened.
+    let err: ContractError = extend_pause(&mut app, &admin, start_height + 5)
         .unwrap_err()
         .downcast()
         .unwrap();
+    assert_eq!(
+        err,
+        ContractError::PauseError(PauseError::InvalidExtension {
+            expiration: Expiration::AtHeight(start_height + 10)
+        })
+    );
 
-    assert!(matches!(err, ContractError::Paused { .. }));
+    extend_pause(&mut app, &admin, start_height + 20).unwrap();
+    app.update_block(|mut block| block.height += 15);
+    assert_eq!(
+        pause_expiration(&app),
+        Some(Expiration::AtHeight(start_height + 20))
+    );
 
-    app.update_block(|mut block| block.height += 1);
+    unpause(&mut app, &admin).unwrap();
+    assert_eq!(pause_expiration(&app), None);
+    let err: ContractError = unpause(&mut app, &admin).unwrap_err().downcast().unwrap();
+    assert_eq!(err, ContractError::PauseError(PauseError::NotPaused {}));
 
-    let paused: PauseInfoResponse = app
-        .wrap()
-        .query_wasm_smart(core_addr.clone(), &QueryMsg::PauseInfo {})
-        .unwrap();
-    assert_eq!(paused, PauseInfoResponse::Unpaused {});
-    let all_state: DumpStateResponse = app
-        .wrap()
-        .query_wasm_smart(core_addr.clone(), &QueryMsg::DumpState {})
-        .unwrap();
-    assert_eq!(all_state.pause_info, PauseInfoResponse::Unpaused {});
+    // The DAO may act again once unpaused.
+    set_item(
+        &mut app,
+        core_addr.clone(),
+        "key".to_string(),
+        "value".to_string(),
+    );
+}
+
+#[test]
+fn test_migrate_module() {
+    let (core_addr, mut app) = do_standard_instantiate(false, None);
+    let module_id = app.store_code(migratable_module());
 
-    // Now its unpaused so we should be able to pause again.
     app.execute_contract(
-        proposal_module.address,
         core_addr.clone(),
-        &ExecuteMsg::ExecuteProposalHook {
-            msgs: vec![WasmMsg::Execute {
-                contract_addr: core_addr.to_string(),
-                msg: to_binary(&ExecuteMsg::Pause {
-                    duration: Duration::Height(10),
-                })
-                .unwrap(),
-                funds: vec![],
-            }
-            .into()],
+        core_addr.clone(),
+        &ExecuteMsg::UpdateProposalModules {
+            to_add: vec![ModuleInstantiateInfo {
+                code_id: module_id,
+                msg: to_binary(&Empty {}).unwrap(),
+                admin: Some(Admin::CoreModule {}),
+                label: "migratable module".to_string(),
+            }],
+            to_disable: vec![],
         },
         &[],
     )
     .unwrap();
+    let module = get_active_modules(&app, core_addr.clone())
+        .into_iter()
+        .map(|module| module.address)
+        .find(|address| {
+            app.wrap()
+                .query_wasm_contract_info(address)
+                .unwrap()
+                .code_id
+                == module_id
+        })
+        .unwrap();
 
-    let paused: PauseInfoResponse = app
-        .wrap()
-        .query_wasm_smart(core_addr.clone(), &QueryMsg::PauseInfo {})
+    let migrate = |app: &mut App, sender: &Addr, module: &Addr, broken: bool| {
+        app.execute_contract(
+            sender.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::MigrateModule {
+                module: module.to_string(),
+                new_code_id: module_id,
+                msg: to_binary(&broken).unwrap(),
+            },
+            &[],
+        )
+    };
+
+    let err: ContractError = migrate(&mut app, &module, &module, false)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let err: ContractError = migrate(&mut app, &core_addr, &Addr::unchecked("ekez"), false)
+        .unwrap_err()
+        .downcast()
         .unwrap();
     assert_eq!(
-        paused,
-        PauseInfoResponse::Paused {
-            expiration: Expiration::AtHeight(start_height + 20)
+        err,
+        ContractError::ModuleNotRegistered {
+            address: Addr::unchecked("ekez")
         }
     );
-    let all_state: DumpStateResponse = app
+
+    let res = migrate(&mut app, &core_addr, &module, false).unwrap();
+    let migrated = res.events.iter().find(|e| {
+        e.attributes
+            .iter()
+            .any(|a| a.key == "action" && a.value == "migrate_module")
+    });
+    assert!(migrated
+        .unwrap()
+        .attributes
+        .iter()
+        .any(|a| a.key == "version" && a.value == "2.0.0"));
+
+    // A migration which leaves the module unable to answer `Info`
+    // queries is reverted.
+    let err: ContractError = migrate(&mut app, &core_addr, &module, true)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::MigratedModuleUnresponsive { address, .. } if address == module
+    ));
+    let info: InfoResponse = app
         .wrap()
-        .query_wasm_smart(core_addr, &QueryMsg::DumpState {})
+        .query_wasm_smart(module, &dao_interface::voting::Query::Info {})
         .unwrap();
-    assert_eq!(
-        all_state.pause_info,
-        PauseInfoResponse::Paused {
-            expiration: Expiration::AtHeight(start_height + 20)
-        }
-    );
+    assert_eq!(info.info.version, "2.0.0");
 }
 
 #[test]
-fn test_dump_state_proposal_modules() {
-    let (core_addr, app) = do_standard_instantiate(false, None);
+fn test_pause() {
+    let (core_addr, mut app) = do_standard_instantiate(false, None);
+
+    let start_height = app.block_info().height;
+
     let proposal_modules: Vec<ProposalModule> = app
         .wrap()
         .query_wasm_smart(
@@ -2481,289 +3094,1398 @@ fn test_dump_state_proposal_modules() {
     assert_eq!(proposal_modules.len(), 1);
     let proposal_module = proposal_modules.into_iter().next().unwrap();
 
-    let all_state: DumpStateResponse = app
+    let paused: PauseInfoResponse = app
+        .wrap()
+        .query_wasm_smart(core_addr.clone(), &QueryMsg::PauseInfo {})
+        .unwrap();
+    assert_eq!(paused, PauseInfoResponse::Unpaused {});
+    let all_state: DumpStateResponse = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(all_state.pause_info, PauseInfoResponse::Unpaused {});
+
+    // DAO is not paused. Check that we can execute things.
+    //
+    // Tests intentionally use the core address to send these
+    // messsages to simulate a worst case scenerio where the core
+    // contract has a vulnerability.
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateConfig {
+            config: Config {
+                dao_uri: None,
+                name: "The Empire Strikes Back".to_string(),
+                description: "haha lol we have pwned your DAO".to_string(),
+                image_url: None,
+                automatically_add_cw20s: true,
+                automatically_add_cw721s: true,
+            },
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Oh no the DAO is under attack! Quick! Pause the DAO while we
+    // figure out what to do!
+    let err: ContractError = app
+        .execute_contract(
+            proposal_module.address.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::Pause {
+                duration: Duration::Height(10),
+                reason: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+
+    // Only the DAO may call this on itself. Proposal modules must use
+    // the execute hook.
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    app.execute_contract(
+        proposal_module.address.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::ExecuteProposalHook {
+            msgs: vec![WasmMsg::Execute {
+                contract_addr: core_addr.to_string(),
+                msg: to_binary(&ExecuteMsg::Pause {
+                    duration: Duration::Height(10),
+                    reason: Some("under attack".to_string()),
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into()],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let paused: PauseInfoResponse = app
+        .wrap()
+        .query_wasm_smart(core_addr.clone(), &QueryMsg::PauseInfo {})
+        .unwrap();
+    assert_eq!(
+        paused,
+        PauseInfoResponse::Paused {
+            expiration: Expiration::AtHeight(start_height + 10),
+            paused_by: Some(proposal_module.address.clone()),
+            reason: Some("under attack".to_string()),
+            duration: Some(Duration::Height(10)),
+        }
+    );
+    let all_state: DumpStateResponse = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        all_state.pause_info,
+        PauseInfoResponse::Paused {
+            expiration: Expiration::AtHeight(start_height + 10),
+            paused_by: Some(proposal_module.address.clone()),
+            reason: Some("under attack".to_string()),
+            duration: Some(Duration::Height(10)),
+        }
+    );
+
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::UpdateConfig {
+                config: Config {
+                    dao_uri: None,
+                    name: "The Empire Strikes Back Again".to_string(),
+                    description: "haha lol we have pwned your DAO again".to_string(),
+                    image_url: None,
+                    automatically_add_cw20s: true,
+                    automatically_add_cw721s: true,
+                },
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+
+    assert!(matches!(err, ContractError::Paused { .. }));
+
+    let err: ContractError = app
+        .execute_contract(
+            proposal_module.address.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteProposalHook {
+                msgs: vec![WasmMsg::Execute {
+                    contract_addr: core_addr.to_string(),
+                    msg: to_binary(&ExecuteMsg::Pause {
+                        duration: Duration::Height(10),
+                        reason: None,
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }
+                .into()],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+
+    assert!(matches!(err, ContractError::Paused { .. }));
+
+    app.update_block(|mut block| block.height += 9);
+
+    // Still not unpaused.
+    let err: ContractError = app
+        .execute_contract(
+            proposal_module.address.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteProposalHook {
+                msgs: vec![WasmMsg::Execute {
+                    contract_addr: core_addr.to_string(),
+                    msg: to_binary(&ExecuteMsg::Pause {
+                        duration: Duration::Height(10),
+                        reason: None,
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }
+                .into()],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+
+    assert!(matches!(err, ContractError::Paused { .. }));
+
+    app.update_block(|mut block| block.height += 1);
+
+    let paused: PauseInfoResponse = app
+        .wrap()
+        .query_wasm_smart(core_addr.clone(), &QueryMsg::PauseInfo {})
+        .unwrap();
+    assert_eq!(paused, PauseInfoResponse::Unpaused {});
+    let all_state: DumpStateResponse = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(all_state.pause_info, PauseInfoResponse::Unpaused {});
+
+    // Now its unpaused so we should be able to pause again.
+    app.execute_contract(
+        proposal_module.address.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::ExecuteProposalHook {
+            msgs: vec![WasmMsg::Execute {
+                contract_addr: core_addr.to_string(),
+                msg: to_binary(&ExecuteMsg::Pause {
+                    duration: Duration::Height(10),
+                    reason: None,
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into()],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let paused: PauseInfoResponse = app
+        .wrap()
+        .query_wasm_smart(core_addr.clone(), &QueryMsg::PauseInfo {})
+        .unwrap();
+    assert_eq!(
+        paused,
+        PauseInfoResponse::Paused {
+            expiration: Expiration::AtHeight(start_height + 20),
+            paused_by: Some(proposal_module.address.clone()),
+            reason: None,
+            duration: Some(Duration::Height(10)),
+        }
+    );
+    let all_state: DumpStateResponse = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr,
+            &QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        all_state.pause_info,
+        PauseInfoResponse::Paused {
+            expiration: Expiration::AtHeight(start_height + 20),
+            paused_by: Some(proposal_module.address),
+            reason: None,
+            duration: Some(Duration::Height(10)),
+        }
+    );
+}
+
+#[test]
+fn test_dump_state_proposal_modules() {
+    let (core_addr, app) = do_standard_instantiate(false, None);
+    let proposal_modules: Vec<ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(proposal_modules.len(), 1);
+    let proposal_module = proposal_modules.into_iter().next().unwrap();
+
+    let all_state: DumpStateResponse = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr,
+            &QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(all_state.pause_info, PauseInfoResponse::Unpaused {});
+    assert_eq!(all_state.proposal_modules.len(), 1);
+    assert_eq!(all_state.proposal_modules[0], proposal_module);
+}
+
+#[test]
+fn test_dump_state_selective() {
+    let (core_addr, app) = do_standard_instantiate(false, None);
+    let all_state: DumpStateResponse = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+
+    let state: DumpStateSelectiveResponse = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::DumpStateSelective {
+                sections: vec![Section::Admin, Section::Version],
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        state,
+        DumpStateSelectiveResponse {
+            admin: Some(all_state.admin.clone()),
+            config: None,
+            pause_info: None,
+            version: Some(all_state.version.clone()),
+            proposal_modules: None,
+            voting_module: None,
+            active_proposal_module_count: None,
+            total_proposal_module_count: None,
+        }
+    );
+
+    let state: DumpStateSelectiveResponse = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr,
+            &QueryMsg::DumpStateSelective {
+                sections: vec![Section::Config, Section::Modules],
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        state,
+        DumpStateSelectiveResponse {
+            admin: None,
+            config: Some(all_state.config),
+            pause_info: Some(all_state.pause_info),
+            version: None,
+            proposal_modules: Some(all_state.proposal_modules),
+            voting_module: Some(all_state.voting_module),
+            active_proposal_module_count: Some(all_state.active_proposal_module_count),
+            total_proposal_module_count: Some(all_state.total_proposal_module_count),
+        }
+    );
+}
+
+// Note that this isn't actually testing that we are migrating from the previous version since
+// with multitest contract instantiation we can't manipulate storage to the previous version of state before invoking migrate. So if anything,
+// this just tests the idempotency of migrate.
+#[test]
+fn test_migrate_from_compatible() {
+    let mut app = App::default();
+    let govmod_id = app.store_code(sudo_proposal_contract());
+    let voting_id = app.store_code(cw20_balances_voting());
+    let gov_id = app.store_code(cw_core_contract());
+    let cw20_id = app.store_code(cw20_contract());
+
+    let govmod_instantiate = dao_proposal_sudo::msg::InstantiateMsg {
+        root: CREATOR_ADDR.to_string(),
+    };
+    let voting_instantiate = dao_voting_cw20_balance::msg::InstantiateMsg {
+        token_info: dao_voting_cw20_balance::msg::TokenInfo::New {
+            code_id: cw20_id,
+            label: "DAO DAO voting".to_string(),
+            name: "DAO DAO".to_string(),
+            symbol: "DAO".to_string(),
+            decimals: 6,
+            initial_balances: vec![cw20::Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::from(2u64),
+            }],
+            marketing: None,
+        },
+    };
+
+    // Instantiate the core module with an admin to do migrations.
+    let gov_instantiate = InstantiateMsg {
+        dao_uri: None,
+        admin: None,
+        name: "DAO DAO".to_string(),
+        description: "A DAO that builds DAOs.".to_string(),
+        image_url: None,
+        automatically_add_cw20s: false,
+        automatically_add_cw721s: false,
+        voting_module_instantiate_info: ModuleInstantiateInfo {
+            code_id: voting_id,
+            msg: to_binary(&voting_instantiate).unwrap(),
+            admin: Some(Admin::CoreModule {}),
+            label: "voting module".to_string(),
+        },
+        proposal_modules_instantiate_info: vec![ModuleInstantiateInfo {
+            code_id: govmod_id,
+            msg: to_binary(&govmod_instantiate).unwrap(),
+            admin: Some(Admin::CoreModule {}),
+            label: "governance module".to_string(),
+        }],
+        initial_items: None,
+    };
+
+    let core_addr = app
+        .instantiate_contract(
+            gov_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &gov_instantiate,
+            &[],
+            "cw-governance",
+            Some(CREATOR_ADDR.to_string()),
+        )
+        .unwrap();
+
+    let state: DumpStateResponse = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+
+    app.execute(
+        Addr::unchecked(CREATOR_ADDR),
+        CosmosMsg::Wasm(WasmMsg::Migrate {
+            contract_addr: core_addr.to_string(),
+            new_code_id: gov_id,
+            msg: to_binary(&MigrateMsg::FromCompatible {}).unwrap(),
+        }),
+    )
+    .unwrap();
+
+    let new_state: DumpStateResponse = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr,
+            &QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(new_state, state);
+}
+
+#[test]
+fn test_migrate_from_beta() {
+    use cw_core_v1 as v1;
+
+    let mut app = App::default();
+    let govmod_id = app.store_code(sudo_proposal_contract());
+    let voting_id = app.store_code(cw20_balances_voting());
+    let core_id = app.store_code(cw_core_contract());
+    let v1_core_id = app.store_code(v1_cw_core_contract());
+    let cw20_id = app.store_code(cw20_contract());
+
+    let proposal_instantiate = dao_proposal_sudo::msg::InstantiateMsg {
+        root: CREATOR_ADDR.to_string(),
+    };
+    let voting_instantiate = dao_voting_cw20_balance::msg::InstantiateMsg {
+        token_info: dao_voting_cw20_balance::msg::TokenInfo::New {
+            code_id: cw20_id,
+            label: "DAO DAO voting".to_string(),
+            name: "DAO DAO".to_string(),
+            symbol: "DAO".to_string(),
+            decimals: 6,
+            initial_balances: vec![cw20::Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::from(2u64),
+            }],
+            marketing: None,
+        },
+    };
+
+    // Instantiate the core module with an admin to do migrations.
+    let v1_core_instantiate = v1::msg::InstantiateMsg {
+        admin: None,
+        name: "DAO DAO".to_string(),
+        description: "A DAO that builds DAOs.".to_string(),
+        image_url: None,
+        automatically_add_cw20s: false,
+        automatically_add_cw721s: false,
+        voting_module_instantiate_info: v1::msg::ModuleInstantiateInfo {
+            code_id: voting_id,
+            msg: to_binary(&voting_instantiate).unwrap(),
+            admin: v1::msg::Admin::CoreContract {},
+            label: "voting module".to_string(),
+        },
+        proposal_modules_instantiate_info: vec![
+            v1::msg::ModuleInstantiateInfo {
+                code_id: govmod_id,
+                msg: to_binary(&proposal_instantiate).unwrap(),
+                admin: v1::msg::Admin::CoreContract {},
+                label: "governance module 1".to_string(),
+            },
+            v1::msg::ModuleInstantiateInfo {
+                code_id: govmod_id,
+                msg: to_binary(&proposal_instantiate).unwrap(),
+                admin: v1::msg::Admin::CoreContract {},
+                label: "governance module 2".to_string(),
+            },
+        ],
+        initial_items: None,
+    };
+
+    let core_addr = app
+        .instantiate_contract(
+            v1_core_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &v1_core_instantiate,
+            &[],
+            "cw-governance",
+            Some(CREATOR_ADDR.to_string()),
+        )
+        .unwrap();
+
+    app.execute(
+        Addr::unchecked(CREATOR_ADDR),
+        CosmosMsg::Wasm(WasmMsg::Migrate {
+            contract_addr: core_addr.to_string(),
+            new_code_id: core_id,
+            msg: to_binary(&MigrateMsg::FromV1(FromV1Msg { dao_uri: None })).unwrap(),
+        }),
+    )
+    .unwrap();
+
+    let new_state: DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(core_addr, &QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr,
+            &QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+
+    let proposal_modules = new_state.proposal_modules;
+    assert_eq!(2, proposal_modules.len());
+    for (idx, module) in proposal_modules.iter().enumerate() {
+        let prefix = derive_proposal_module_prefix(idx).unwrap();
+        assert_eq!(prefix, module.prefix);
+        assert_eq!(ProposalModuleStatus::Enabled, module.status);
+    }
+}
+
+#[test]
+fn test_migrate_mock() {
+    let mut deps = mock_dependencies();
+    let dao_uri: String = "/dao/uri".to_string();
+    let msg = MigrateMsg::FromV1(FromV1Msg {
+        dao_uri: Some(dao_uri.clone()),
+    });
+    let env = mock_env();
+
+    // Write to storage in old proposal module format
+    let proposal_modules_key = Addr::unchecked("addr");
+    let old_map: Map<Addr, Empty> = Map::new("proposal_modules");
+    let path = old_map.key(proposal_modules_key.clone());
+    deps.storage.set(&path, &to_binary(&Empty {}).unwrap());
+
+    // Write to storage in old config format
+    #[cw_serde]
+    struct V1Config {
+        pub name: String,
+        pub description: String,
+        pub image_url: Option<String>,
+        pub automatically_add_cw20s: bool,
+        pub automatically_add_cw721s: bool,
+    }
+
+    let v1_config = V1Config {
+        name: "core dao".to_string(),
+        description: "a dao".to_string(),
+        image_url: None,
+        automatically_add_cw20s: false,
+        automatically_add_cw721s: false,
+    };
+
+    let config_item: Item<V1Config> = Item::new("config");
+    config_item.save(&mut deps.storage, &v1_config).unwrap();
+
+    // Migrate to v2
+    migrate(deps.as_mut(), env, msg).unwrap();
+
+    let new_path = PROPOSAL_MODULES.key(proposal_modules_key);
+    let prop_module_bytes = deps.storage.get(&new_path).unwrap();
+    let module: ProposalModule = from_slice(&prop_module_bytes).unwrap();
+    assert_eq!(module.address, Addr::unchecked("addr"));
+    assert_eq!(module.prefix, derive_proposal_module_prefix(0).unwrap());
+    assert_eq!(module.status, ProposalModuleStatus::Enabled {});
+
+    let v2_config_item: Item<Config> = Item::new("config_v2");
+    let v2_config = v2_config_item.load(&deps.storage).unwrap();
+    assert_eq!(v2_config.dao_uri, Some(dao_uri));
+    assert_eq!(v2_config.name, v1_config.name);
+    assert_eq!(v2_config.description, v1_config.description);
+    assert_eq!(v2_config.image_url, v1_config.image_url);
+    assert_eq!(
+        v2_config.automatically_add_cw20s,
+        v1_config.automatically_add_cw20s
+    );
+    assert_eq!(
+        v2_config.automatically_add_cw721s,
+        v1_config.automatically_add_cw721s
+    );
+
+    // Legacy state is removed and module counts are initialized.
+    assert!(deps.storage.get(&path).is_none());
+    assert!(!config_item.exists(&deps.storage));
+    assert_eq!(TOTAL_PROPOSAL_MODULE_COUNT.load(&deps.storage).unwrap(), 1);
+    assert_eq!(ACTIVE_PROPOSAL_MODULE_COUNT.load(&deps.storage).unwrap(), 1);
+}
+
+#[test]
+fn test_migrate_detects_legacy_state() {
+    let mut deps = mock_dependencies();
+
+    // There is nothing to migrate from v1.
+    let err = migrate(
+        deps.as_mut(),
+        mock_env(),
+        MigrateMsg::FromV1(FromV1Msg { dao_uri: None }),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NoLegacyState {});
+
+    // Legacy DAOs whose version can not be checked may migrate with
+    // `FromV1`.
+    cw2::set_contract_version(&mut deps.storage, "crates.io:cw-core", "old-version").unwrap();
+    let old_modules: Map<Addr, Empty> = Map::new("proposal_modules");
+    for module in ["one", "two"] {
+        old_modules
+            .save(&mut deps.storage, Addr::unchecked(module), &Empty {})
+            .unwrap();
+    }
+    cw_core_v1::state::CONFIG
+        .save(
+            &mut deps.storage,
+            &cw_core_v1::state::Config {
+                name: "core dao".to_string(),
+                description: "a dao".to_string(),
+                image_url: None,
+                automatically_add_cw20s: true,
+                automatically_add_cw721s: false,
+            },
+        )
         .unwrap();
-    assert_eq!(all_state.pause_info, PauseInfoResponse::Unpaused {});
-    assert_eq!(all_state.proposal_modules.len(), 1);
-    assert_eq!(all_state.proposal_modules[0], proposal_module);
+
+    // `FromCompatible` only updates the contract's version, leaving
+    // legacy state alone.
+    migrate(deps.as_mut(), mock_env(), MigrateMsg::FromCompatible {}).unwrap();
+    assert!(!CONFIG.exists(&deps.storage));
+    assert!(cw_core_v1::state::CONFIG.exists(&deps.storage));
+
+    cw2::set_contract_version(&mut deps.storage, "crates.io:cw-core", "old-version").unwrap();
+    let res = migrate(
+        deps.as_mut(),
+        mock_env(),
+        MigrateMsg::FromV1(FromV1Msg { dao_uri: None }),
+    )
+    .unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![Attribute::new("migrated_legacy_state", "true")]
+    );
+    let config = CONFIG.load(&deps.storage).unwrap();
+    assert_eq!(config.name, "core dao");
+    assert_eq!(config.dao_uri, None);
+    assert!(config.automatically_add_cw20s);
+    let modules = PROPOSAL_MODULES
+        .range(&deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, module)| module.prefix))
+        .collect::<StdResult<Vec<_>>>()
+        .unwrap();
+    assert_eq!(
+        modules,
+        vec![
+            derive_proposal_module_prefix(0).unwrap(),
+            derive_proposal_module_prefix(1).unwrap()
+        ]
+    );
+    assert_eq!(TOTAL_PROPOSAL_MODULE_COUNT.load(&deps.storage).unwrap(), 2);
+    assert_eq!(ACTIVE_PROPOSAL_MODULE_COUNT.load(&deps.storage).unwrap(), 2);
+
+    // Migrating again leaves the migrated state alone.
+    migrate(deps.as_mut(), mock_env(), MigrateMsg::FromCompatible {}).unwrap();
+    assert_eq!(CONFIG.load(&deps.storage).unwrap(), config);
 }
 
-// Note that this isn't actually testing that we are migrating from the previous version since
-// with multitest contract instantiation we can't manipulate storage to the previous version of state before invoking migrate. So if anything,
-// this just tests the idempotency of migrate.
 #[test]
-fn test_migrate_from_compatible() {
-    let mut app = App::default();
-    let govmod_id = app.store_code(sudo_proposal_contract());
-    let voting_id = app.store_code(cw20_balances_voting());
-    let gov_id = app.store_code(cw_core_contract());
-    let cw20_id = app.store_code(cw20_contract());
+fn test_execute_stargate_msg() {
+    let (core_addr, mut app) = do_standard_instantiate(true, None);
+    let proposal_modules: Vec<ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
 
-    let govmod_instantiate = dao_proposal_sudo::msg::InstantiateMsg {
-        root: CREATOR_ADDR.to_string(),
-    };
-    let voting_instantiate = dao_voting_cw20_balance::msg::InstantiateMsg {
-        token_info: dao_voting_cw20_balance::msg::TokenInfo::New {
-            code_id: cw20_id,
-            label: "DAO DAO voting".to_string(),
-            name: "DAO DAO".to_string(),
-            symbol: "DAO".to_string(),
-            decimals: 6,
-            initial_balances: vec![cw20::Cw20Coin {
-                address: CREATOR_ADDR.to_string(),
-                amount: Uint128::from(2u64),
+    assert_eq!(proposal_modules.len(), 1);
+    let proposal_module = proposal_modules.into_iter().next().unwrap();
+
+    let res = app.execute_contract(
+        proposal_module.address,
+        core_addr,
+        &ExecuteMsg::ExecuteProposalHook {
+            msgs: vec![CosmosMsg::Stargate {
+                type_url: "foo_type".to_string(),
+                value: to_binary("foo_bin").unwrap(),
             }],
-            marketing: None,
         },
-    };
+        &[],
+    );
+    // TODO: Once cw-multi-test supports executing stargate/ibc messages we can change this test assert
+    assert!(res.is_err());
+}
 
-    // Instantiate the core module with an admin to do migrations.
-    let gov_instantiate = InstantiateMsg {
-        dao_uri: None,
-        admin: None,
-        name: "DAO DAO".to_string(),
-        description: "A DAO that builds DAOs.".to_string(),
-        image_url: None,
-        automatically_add_cw20s: false,
-        automatically_add_cw721s: false,
-        voting_module_instantiate_info: ModuleInstantiateInfo {
-            code_id: voting_id,
-            msg: to_binary(&voting_instantiate).unwrap(),
-            admin: Some(Admin::CoreModule {}),
-            label: "voting module".to_string(),
+#[test]
+fn test_message_filters() {
+    let (core_addr, mut app) = do_standard_instantiate(true, Some("admin".to_string()));
+    let proposal_module = get_active_modules(&app, core_addr.clone())
+        .into_iter()
+        .next()
+        .unwrap()
+        .address;
+    let filter_id = app.store_code(no_bank_msgs_filter());
+    let filter = app
+        .instantiate_contract(
+            filter_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &Empty {},
+            &[],
+            "filter",
+            None,
+        )
+        .unwrap();
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: core_addr.to_string(),
+        amount: vec![coin(10, "ujuno")],
+    }))
+    .unwrap();
+
+    test_unauthorized(
+        &mut app,
+        core_addr.clone(),
+        ExecuteMsg::AddMessageFilter {
+            address: filter.to_string(),
         },
-        proposal_modules_instantiate_info: vec![ModuleInstantiateInfo {
-            code_id: govmod_id,
-            msg: to_binary(&govmod_instantiate).unwrap(),
-            admin: Some(Admin::CoreModule {}),
-            label: "governance module".to_string(),
-        }],
-        initial_items: None,
+    );
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::AddMessageFilter {
+            address: filter.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    let filters: cw_hooks::HooksResponse = app
+        .wrap()
+        .query_wasm_smart(core_addr.clone(), &QueryMsg::MessageFilters {})
+        .unwrap();
+    assert_eq!(filters.hooks, vec![filter.to_string()]);
+
+    let send = |app: &mut App| {
+        app.execute_contract(
+            proposal_module.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteProposalHook {
+                msgs: vec![BankMsg::Send {
+                    to_address: CREATOR_ADDR.to_string(),
+                    amount: vec![coin(1, "ujuno")],
+                }
+                .into()],
+            },
+            &[],
+        )
     };
 
-    let core_addr = app
-        .instantiate_contract(
-            gov_id,
-            Addr::unchecked(CREATOR_ADDR),
-            &gov_instantiate,
+    // The filter rejects the proposal's messages, so none of them
+    // are executed.
+    let err: StdError = send(&mut app).unwrap_err().downcast().unwrap();
+    assert_eq!(err, StdError::generic_err("bank messages are not allowed"));
+    assert_eq!(
+        app.wrap()
+            .query_balance(core_addr.clone(), "ujuno")
+            .unwrap()
+            .amount,
+        Uint128::new(10)
+    );
+
+    // The admin's messages are filtered too.
+    let err: StdError = app
+        .execute_contract(
+            Addr::unchecked("admin"),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteAdminMsgs {
+                msgs: vec![BankMsg::Send {
+                    to_address: CREATOR_ADDR.to_string(),
+                    amount: vec![coin(1, "ujuno")],
+                }
+                .into()],
+            },
             &[],
-            "cw-governance",
-            Some(CREATOR_ADDR.to_string()),
         )
+        .unwrap_err()
+        .downcast()
         .unwrap();
+    assert_eq!(err, StdError::generic_err("bank messages are not allowed"));
 
-    let state: DumpStateResponse = app
+    // Messages the filter allows are executed.
+    app.execute_contract(
+        proposal_module.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::ExecuteProposalHook {
+            msgs: vec![WasmMsg::Execute {
+                contract_addr: core_addr.to_string(),
+                msg: to_binary(&ExecuteMsg::SetItem {
+                    key: "k".to_string(),
+                    value: "v".to_string(),
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into()],
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::RemoveMessageFilter {
+            address: filter.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    send(&mut app).unwrap();
+    assert_eq!(
+        app.wrap().query_balance(core_addr, "ujuno").unwrap().amount,
+        Uint128::new(9)
+    );
+}
+
+#[test]
+fn test_native_denom_list() {
+    let (core_addr, mut app) = do_standard_instantiate(true, None);
+    let issuer = Addr::unchecked("issuer");
+    let register = |app: &mut App, denom: &str| {
+        app.execute_contract(
+            issuer.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::RegisterDenom {
+                denom: denom.to_string(),
+            },
+            &[],
+        )
+    };
+
+    let err: ContractError = register(&mut app, "factory/issuer/gov")
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::GrantPermission {
+            address: issuer.to_string(),
+            permission: Permission::UpdateNativeDenomList {},
+        },
+        &[],
+    )
+    .unwrap();
+    register(&mut app, "factory/issuer/gov").unwrap();
+    let err: ContractError = register(&mut app, "1gov").unwrap_err().downcast().unwrap();
+    assert!(matches!(err, ContractError::DenomError(_)));
+
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateNativeDenomList {
+            to_add: vec!["ujuno".to_string()],
+            to_remove: vec!["factory/issuer/gov".to_string()],
+        },
+        &[],
+    )
+    .unwrap();
+    let denoms: Vec<String> = app
         .wrap()
-        .query_wasm_smart(core_addr.clone(), &QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::NativeDenomList {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
+    assert_eq!(denoms, vec!["ujuno".to_string()]);
 
-    app.execute(
-        Addr::unchecked(CREATOR_ADDR),
-        CosmosMsg::Wasm(WasmMsg::Migrate {
-            contract_addr: core_addr.to_string(),
-            new_code_id: gov_id,
-            msg: to_binary(&MigrateMsg::FromCompatible {}).unwrap(),
-        }),
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateNativeDenomList {
+            to_add: vec!["uatom".to_string()],
+            to_remove: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: core_addr.to_string(),
+        amount: vec![coin(10, "ujuno"), coin(5, "uosmo")],
+    }))
+    .unwrap();
+
+    // Balances of registered denoms are listed, including zero
+    // balances, and unregistered denoms are skipped.
+    let balances = |app: &App, start_after: Option<&str>, limit: Option<u32>| {
+        let balances: NativeBalancesResponse = app
+            .wrap()
+            .query_wasm_smart(
+                core_addr.clone(),
+                &QueryMsg::NativeBalances {
+                    start_after: start_after.map(str::to_string),
+                    limit,
+                },
+            )
+            .unwrap();
+        balances
+    };
+    assert_eq!(
+        balances(&app, None, None),
+        NativeBalancesResponse {
+            balances: vec![coin(10, "ujuno"), coin(0, "uatom")],
+            has_more: false,
+            next_start_after: None,
+        }
+    );
+    assert_eq!(
+        balances(&app, None, Some(1)),
+        NativeBalancesResponse {
+            balances: vec![coin(10, "ujuno")],
+            has_more: true,
+            next_start_after: Some("ujuno".to_string()),
+        }
+    );
+    assert_eq!(
+        balances(&app, Some("ujuno"), Some(1)),
+        NativeBalancesResponse {
+            balances: vec![coin(0, "uatom")],
+            has_more: false,
+            next_start_after: None,
+        }
+    );
+}
+
+#[test]
+fn test_register_created_denoms() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let module = Addr::unchecked("module");
+    PROPOSAL_MODULES
+        .save(
+            deps.as_mut().storage,
+            module.clone(),
+            &ProposalModule {
+                address: module,
+                prefix: "A".to_string(),
+                status: ProposalModuleStatus::Enabled,
+            },
+        )
+        .unwrap();
+    let create_denom = |sender: &str| CosmosMsg::Stargate {
+        type_url: "/osmosis.tokenfactory.v1beta1.MsgCreateDenom".to_string(),
+        value: Binary(
+            crate::proto::MsgCreateDenom {
+                sender: sender.to_string(),
+                subdenom: "gov".to_string(),
+            }
+            .encode_to_vec(),
+        ),
+    };
+
+    // Only denoms created by the DAO itself are registered.
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("module", &[]),
+        ExecuteMsg::ExecuteProposalHook {
+            msgs: vec![
+                create_denom(env.contract.address.as_str()),
+                create_denom("other"),
+            ],
+        },
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 2);
+    let registered = res
+        .events
+        .iter()
+        .filter(|e| {
+            e.attributes
+                .iter()
+                .any(|a| a.key == "action" && a.value == "register_denom")
+        })
+        .count();
+    assert_eq!(registered, 1);
+
+    let denoms: Vec<String> = from_slice(
+        &query(
+            deps.as_ref(),
+            env,
+            QueryMsg::NativeDenomList {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
     )
     .unwrap();
-
-    let new_state: DumpStateResponse = app
-        .wrap()
-        .query_wasm_smart(core_addr, &QueryMsg::DumpState {})
-        .unwrap();
-
-    assert_eq!(new_state, state);
+    assert_eq!(denoms, vec!["factory/cosmos2contract/gov".to_string()]);
 }
 
 #[test]
-fn test_migrate_from_beta() {
-    use cw_core_v1 as v1;
-
-    let mut app = App::default();
-    let govmod_id = app.store_code(sudo_proposal_contract());
-    let voting_id = app.store_code(cw20_balances_voting());
-    let core_id = app.store_code(cw_core_contract());
-    let v1_core_id = app.store_code(v1_cw_core_contract());
-    let cw20_id = app.store_code(cw20_contract());
-
-    let proposal_instantiate = dao_proposal_sudo::msg::InstantiateMsg {
-        root: CREATOR_ADDR.to_string(),
+fn test_granted_permissions() {
+    let (core_addr, mut app) = do_standard_instantiate(true, None);
+    let operator = Addr::unchecked("operator");
+    let set_item = |app: &mut App, key: &str| {
+        app.execute_contract(
+            operator.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::SetItem {
+                key: key.to_string(),
+                value: "v".to_string(),
+            },
+            &[],
+        )
     };
-    let voting_instantiate = dao_voting_cw20_balance::msg::InstantiateMsg {
-        token_info: dao_voting_cw20_balance::msg::TokenInfo::New {
-            code_id: cw20_id,
-            label: "DAO DAO voting".to_string(),
-            name: "DAO DAO".to_string(),
-            symbol: "DAO".to_string(),
-            decimals: 6,
-            initial_balances: vec![cw20::Cw20Coin {
-                address: CREATOR_ADDR.to_string(),
-                amount: Uint128::from(2u64),
-            }],
-            marketing: None,
+    let grant = ExecuteMsg::GrantPermission {
+        address: operator.to_string(),
+        permission: Permission::SetItems {
+            prefix: "payroll:".to_string(),
         },
     };
 
-    // Instantiate the core module with an admin to do migrations.
-    let v1_core_instantiate = v1::msg::InstantiateMsg {
-        admin: None,
-        name: "DAO DAO".to_string(),
-        description: "A DAO that builds DAOs.".to_string(),
-        image_url: None,
-        automatically_add_cw20s: false,
-        automatically_add_cw721s: false,
-        voting_module_instantiate_info: v1::msg::ModuleInstantiateInfo {
-            code_id: voting_id,
-            msg: to_binary(&voting_instantiate).unwrap(),
-            admin: v1::msg::Admin::CoreContract {},
-            label: "voting module".to_string(),
+    test_unauthorized(&mut app, core_addr.clone(), grant.clone());
+    let err: ContractError = set_item(&mut app, "payroll:alice")
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    for msg in [
+        grant.clone(),
+        ExecuteMsg::GrantPermission {
+            address: operator.to_string(),
+            permission: Permission::UpdateCw20List {},
         },
-        proposal_modules_instantiate_info: vec![
-            v1::msg::ModuleInstantiateInfo {
-                code_id: govmod_id,
-                msg: to_binary(&proposal_instantiate).unwrap(),
-                admin: v1::msg::Admin::CoreContract {},
-                label: "governance module 1".to_string(),
-            },
-            v1::msg::ModuleInstantiateInfo {
-                code_id: govmod_id,
-                msg: to_binary(&proposal_instantiate).unwrap(),
-                admin: v1::msg::Admin::CoreContract {},
-                label: "governance module 2".to_string(),
-            },
-        ],
-        initial_items: None,
-    };
+    ] {
+        app.execute_contract(core_addr.clone(), core_addr.clone(), &msg, &[])
+            .unwrap();
+    }
+    let err: ContractError = app
+        .execute_contract(core_addr.clone(), core_addr.clone(), &grant, &[])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::PermissionAlreadyGranted {});
 
-    let core_addr = app
-        .instantiate_contract(
-            v1_core_id,
-            Addr::unchecked(CREATOR_ADDR),
-            &v1_core_instantiate,
-            &[],
-            "cw-governance",
-            Some(CREATOR_ADDR.to_string()),
+    let grants: Vec<PermissionGrant> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::ListPermissions {
+                start_after: None,
+                limit: None,
+            },
         )
         .unwrap();
+    assert_eq!(
+        grants,
+        vec![PermissionGrant {
+            address: operator.clone(),
+            permissions: vec![
+                Permission::SetItems {
+                    prefix: "payroll:".to_string(),
+                },
+                Permission::UpdateCw20List {},
+            ],
+        }]
+    );
 
-    app.execute(
-        Addr::unchecked(CREATOR_ADDR),
-        CosmosMsg::Wasm(WasmMsg::Migrate {
-            contract_addr: core_addr.to_string(),
-            new_code_id: core_id,
-            msg: to_binary(&MigrateMsg::FromV1 { dao_uri: None }).unwrap(),
-        }),
+    // Items under the granted prefix may be managed, others may not.
+    set_item(&mut app, "payroll:alice").unwrap();
+    app.execute_contract(
+        operator.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::RemoveItem {
+            key: "payroll:alice".to_string(),
+        },
+        &[],
     )
     .unwrap();
+    let err: ContractError = set_item(&mut app, "treasury")
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
 
-    let new_state: DumpStateResponse = app
-        .wrap()
-        .query_wasm_smart(core_addr, &QueryMsg::DumpState {})
+    app.execute_contract(
+        operator.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateCw20List {
+            to_add: vec![],
+            to_remove: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+    let err: ContractError = app
+        .execute_contract(
+            operator.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::UpdateCw721List {
+                to_add: vec![],
+                to_remove: vec![],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
         .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
 
-    let proposal_modules = new_state.proposal_modules;
-    assert_eq!(2, proposal_modules.len());
-    for (idx, module) in proposal_modules.iter().enumerate() {
-        let prefix = derive_proposal_module_prefix(idx).unwrap();
-        assert_eq!(prefix, module.prefix);
-        assert_eq!(ProposalModuleStatus::Enabled, module.status);
-    }
+    let revoke = ExecuteMsg::RevokePermission {
+        address: operator.to_string(),
+        permission: Permission::SetItems {
+            prefix: "payroll:".to_string(),
+        },
+    };
+    app.execute_contract(core_addr.clone(), core_addr.clone(), &revoke, &[])
+        .unwrap();
+    let err: ContractError = app
+        .execute_contract(core_addr.clone(), core_addr.clone(), &revoke, &[])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::PermissionNotGranted {});
+    let err: ContractError = set_item(&mut app, "payroll:alice")
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let permissions: Vec<Permission> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr,
+            &QueryMsg::Permissions {
+                address: operator.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(permissions, vec![Permission::UpdateCw20List {}]);
 }
 
 #[test]
-fn test_migrate_mock() {
-    let mut deps = mock_dependencies();
-    let dao_uri: String = "/dao/uri".to_string();
-    let msg = MigrateMsg::FromV1 {
-        dao_uri: Some(dao_uri.clone()),
+fn test_spend_limits() {
+    let (core_addr, mut app) = do_standard_instantiate(true, None);
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: core_addr.to_string(),
+        amount: vec![coin(100, "ujuno")],
+    }))
+    .unwrap();
+    let spender = Addr::unchecked("spender");
+    let spend = |app: &mut App, amount: u128| {
+        app.execute_contract(
+            spender.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::Spend {
+                denom: cw_denom::UncheckedDenom::Native("ujuno".to_string()),
+                amount: Uint128::new(amount),
+                recipient: "recipient".to_string(),
+            },
+            &[],
+        )
+    };
+    let set_limit = |amount: u128| ExecuteMsg::SetSpendLimit {
+        address: spender.to_string(),
+        denom: cw_denom::UncheckedDenom::Native("ujuno".to_string()),
+        amount: Uint128::new(amount),
+        period: Duration::Height(10),
     };
-    let env = mock_env();
 
-    // Write to storage in old proposal module format
-    let proposal_modules_key = Addr::unchecked("addr");
-    let old_map: Map<Addr, Empty> = Map::new("proposal_modules");
-    let path = old_map.key(proposal_modules_key.clone());
-    deps.storage.set(&path, &to_binary(&Empty {}).unwrap());
+    test_unauthorized(&mut app, core_addr.clone(), set_limit(10));
+    let err: ContractError = spend(&mut app, 1).unwrap_err().downcast().unwrap();
+    assert_eq!(err, ContractError::NoSpendLimit {});
+    let err: ContractError = app
+        .execute_contract(core_addr.clone(), core_addr.clone(), &set_limit(0), &[])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidSpendLimit {});
 
-    // Write to storage in old config format
-    #[cw_serde]
-    struct V1Config {
-        pub name: String,
-        pub description: String,
-        pub image_url: Option<String>,
-        pub automatically_add_cw20s: bool,
-        pub automatically_add_cw721s: bool,
-    }
+    app.execute_contract(core_addr.clone(), core_addr.clone(), &set_limit(10), &[])
+        .unwrap();
+    spend(&mut app, 6).unwrap();
+    let err: ContractError = spend(&mut app, 5).unwrap_err().downcast().unwrap();
+    assert_eq!(
+        err,
+        ContractError::SpendLimitExceeded {
+            remaining: Uint128::new(4)
+        }
+    );
 
-    let v1_config = V1Config {
-        name: "core dao".to_string(),
-        description: "a dao".to_string(),
-        image_url: None,
-        automatically_add_cw20s: false,
-        automatically_add_cw721s: false,
+    let query_limits = |app: &App| -> Vec<SpendLimit> {
+        app.wrap()
+            .query_wasm_smart(
+                core_addr.clone(),
+                &QueryMsg::SpendLimits {
+                    address: spender.to_string(),
+                },
+            )
+            .unwrap()
     };
+    let start = app.block_info().height;
+    assert_eq!(
+        query_limits(&app),
+        vec![SpendLimit {
+            denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
+            amount: Uint128::new(10),
+            period: Duration::Height(10),
+            spent: Uint128::new(6),
+            period_end: Expiration::AtHeight(start + 10),
+        }]
+    );
 
-    let config_item: Item<V1Config> = Item::new("config");
-    config_item.save(&mut deps.storage, &v1_config).unwrap();
+    // A new period starts once the current one ends.
+    app.update_block(|mut block| block.height += 10);
+    assert_eq!(query_limits(&app)[0].spent, Uint128::zero());
+    spend(&mut app, 10).unwrap();
+    let balance = app.wrap().query_balance("recipient", "ujuno").unwrap();
+    assert_eq!(balance.amount, Uint128::new(16));
 
-    // Migrate to v2
-    migrate(deps.as_mut(), env, msg).unwrap();
+    let remove = ExecuteMsg::RemoveSpendLimit {
+        address: spender.to_string(),
+        denom: cw_denom::UncheckedDenom::Native("ujuno".to_string()),
+    };
+    app.execute_contract(core_addr.clone(), core_addr.clone(), &remove, &[])
+        .unwrap();
+    let err: ContractError = app
+        .execute_contract(core_addr.clone(), core_addr.clone(), &remove, &[])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NoSpendLimit {});
+    let err: ContractError = spend(&mut app, 1).unwrap_err().downcast().unwrap();
+    assert_eq!(err, ContractError::NoSpendLimit {});
+    assert!(query_limits(&app).is_empty());
+}
 
-    let new_path = PROPOSAL_MODULES.key(proposal_modules_key);
-    let prop_module_bytes = deps.storage.get(&new_path).unwrap();
-    let module: ProposalModule = from_slice(&prop_module_bytes).unwrap();
-    assert_eq!(module.address, Addr::unchecked("addr"));
-    assert_eq!(module.prefix, derive_proposal_module_prefix(0).unwrap());
-    assert_eq!(module.status, ProposalModuleStatus::Enabled {});
+#[test]
+fn test_spend_message_filters() {
+    let (core_addr, mut app) = do_standard_instantiate(true, None);
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: core_addr.to_string(),
+        amount: vec![coin(10, "ujuno")],
+    }))
+    .unwrap();
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::SetSpendLimit {
+            address: "spender".to_string(),
+            denom: cw_denom::UncheckedDenom::Native("ujuno".to_string()),
+            amount: Uint128::new(10),
+            period: Duration::Height(10),
+        },
+        &[],
+    )
+    .unwrap();
+    let filter_id = app.store_code(no_bank_msgs_filter());
+    let filter = app
+        .instantiate_contract(
+            filter_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &Empty {},
+            &[],
+            "filter",
+            None,
+        )
+        .unwrap();
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::AddMessageFilter {
+            address: filter.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
 
-    let v2_config_item: Item<Config> = Item::new("config_v2");
-    let v2_config = v2_config_item.load(&deps.storage).unwrap();
-    assert_eq!(v2_config.dao_uri, Some(dao_uri));
-    assert_eq!(v2_config.name, v1_config.name);
-    assert_eq!(v2_config.description, v1_config.description);
-    assert_eq!(v2_config.image_url, v1_config.image_url);
+    // The filter rejects the transfer, so nothing is spent.
+    let err: StdError = app
+        .execute_contract(
+            Addr::unchecked("spender"),
+            core_addr.clone(),
+            &ExecuteMsg::Spend {
+                denom: cw_denom::UncheckedDenom::Native("ujuno".to_string()),
+                amount: Uint128::new(1),
+                recipient: "recipient".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, StdError::generic_err("bank messages are not allowed"));
     assert_eq!(
-        v2_config.automatically_add_cw20s,
-        v1_config.automatically_add_cw20s
+        app.wrap().query_balance(core_addr, "ujuno").unwrap().amount,
+        Uint128::new(10)
     );
-    assert_eq!(
-        v2_config.automatically_add_cw721s,
-        v1_config.automatically_add_cw721s
-    )
 }
 
 #[test]
-fn test_execute_stargate_msg() {
+fn test_spend_execution_paused() {
     let (core_addr, mut app) = do_standard_instantiate(true, None);
-    let proposal_modules: Vec<ProposalModule> = app
-        .wrap()
-        .query_wasm_smart(
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: core_addr.to_string(),
+        amount: vec![coin(10, "ujuno")],
+    }))
+    .unwrap();
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::SetSpendLimit {
+            address: "spender".to_string(),
+            denom: cw_denom::UncheckedDenom::Native("ujuno".to_string()),
+            amount: Uint128::new(10),
+            period: Duration::Height(100),
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::PauseExecution {
+            duration: Duration::Height(10),
+            reason: None,
+        },
+        &[],
+    )
+    .unwrap();
+    let spend = |app: &mut App| {
+        app.execute_contract(
+            Addr::unchecked("spender"),
             core_addr.clone(),
-            &QueryMsg::ProposalModules {
-                start_after: None,
-                limit: None,
+            &ExecuteMsg::Spend {
+                denom: cw_denom::UncheckedDenom::Native("ujuno".to_string()),
+                amount: Uint128::new(1),
+                recipient: "recipient".to_string(),
             },
+            &[],
         )
-        .unwrap();
+    };
 
-    assert_eq!(proposal_modules.len(), 1);
-    let proposal_module = proposal_modules.into_iter().next().unwrap();
+    let err: ContractError = spend(&mut app).unwrap_err().downcast().unwrap();
+    assert_eq!(err, ContractError::ExecutionPaused {});
 
-    let res = app.execute_contract(
-        proposal_module.address,
-        core_addr,
-        &ExecuteMsg::ExecuteProposalHook {
-            msgs: vec![CosmosMsg::Stargate {
-                type_url: "foo_type".to_string(),
-                value: to_binary("foo_bin").unwrap(),
-            }],
-        },
-        &[],
+    // Spending resumes once the pause expires.
+    app.update_block(|block| block.height += 10);
+    spend(&mut app).unwrap();
+    assert_eq!(
+        app.wrap()
+            .query_balance("recipient", "ujuno")
+            .unwrap()
+            .amount,
+        Uint128::new(1)
     );
-    // TODO: Once cw-multi-test supports executing stargate/ibc messages we can change this test assert
-    assert!(res.is_err());
 }
 
 #[test]
@@ -2887,18 +4609,22 @@ fn test_add_remove_subdaos() {
     let to_add: Vec<SubDao> = vec![
         SubDao {
             addr: "subdao001".to_string(),
+            name: None,
             charter: None,
         },
         SubDao {
             addr: "subdao002".to_string(),
+            name: None,
             charter: Some("cool charter bro".to_string()),
         },
         SubDao {
             addr: "subdao005".to_string(),
+            name: None,
             charter: None,
         },
         SubDao {
             addr: "subdao007".to_string(),
+            name: None,
             charter: None,
         },
     ];
@@ -2919,6 +4645,9 @@ fn test_add_remove_subdaos() {
             &QueryMsg::ListSubDaos {
                 start_after: None,
                 limit: None,
+                active_only: None,
+                added_after: None,
+                order: None,
             },
         )
         .unwrap();
@@ -2941,10 +4670,13 @@ fn test_add_remove_subdaos() {
     let res: Vec<SubDao> = app
         .wrap()
         .query_wasm_smart(
-            core_addr,
+            core_addr.clone(),
             &QueryMsg::ListSubDaos {
                 start_after: None,
                 limit: None,
+                active_only: None,
+                added_after: None,
+                order: None,
             },
         )
         .unwrap();
@@ -2953,6 +4685,7 @@ fn test_add_remove_subdaos() {
 
     let test_res: SubDao = SubDao {
         addr: "subdao002".to_string(),
+        name: None,
         charter: Some("cool charter bro".to_string()),
     };
 
@@ -2961,19 +4694,410 @@ fn test_add_remove_subdaos() {
     let full_result_set: Vec<SubDao> = vec![
         SubDao {
             addr: "subdao001".to_string(),
+            name: None,
             charter: None,
         },
         SubDao {
             addr: "subdao002".to_string(),
+            name: None,
             charter: Some("cool charter bro".to_string()),
         },
         SubDao {
             addr: "subdao007".to_string(),
+            name: None,
             charter: None,
         },
     ];
 
     assert_eq!(res, full_result_set);
+
+    let count: CountResponse = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::SubDaoCount {
+                active_only: None,
+                added_after: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(count.count, 3);
+
+    // Listing in descending order starts after the given address
+    // and moves towards the start of the list.
+    let res: Vec<SubDao> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr,
+            &QueryMsg::ListSubDaos {
+                start_after: Some("subdao007".to_string()),
+                limit: Some(1),
+                active_only: None,
+                added_after: None,
+                order: Some(PageOrder::Descending),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, vec![full_result_set[1].clone()]);
+}
+
+#[test]
+fn test_sub_dao_info() {
+    let (core_addr, mut app) = do_standard_instantiate(false, None);
+    let add_sub_dao = |app: &mut App, addr: &str, name: &str| {
+        app.execute_contract(
+            core_addr.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::UpdateSubDaos {
+                to_add: vec![SubDao {
+                    addr: addr.to_string(),
+                    name: Some(name.to_string()),
+                    charter: None,
+                }],
+                to_remove: vec![],
+            },
+            &[],
+        )
+        .unwrap();
+    };
+    let list = |app: &App, active_only: Option<bool>, added_after: Option<u64>| -> Vec<String> {
+        let sub_daos: Vec<SubDao> = app
+            .wrap()
+            .query_wasm_smart(
+                core_addr.clone(),
+                &QueryMsg::ListSubDaos {
+                    start_after: None,
+                    limit: None,
+                    active_only,
+                    added_after,
+                    order: None,
+                },
+            )
+            .unwrap();
+        sub_daos.into_iter().map(|sub_dao| sub_dao.addr).collect()
+    };
+
+    let added_at = app.block_info().height;
+    add_sub_dao(&mut app, "subdao001", "one");
+    app.update_block(|mut block| block.height += 5);
+    add_sub_dao(&mut app, "subdao002", "two");
+
+    let info: Option<SubDaoInfo> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::SubDaoInfo {
+                address: "subdao001".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        info,
+        Some(SubDaoInfo {
+            name: Some("one".to_string()),
+            charter: None,
+            added_at,
+            active: true,
+        })
+    );
+
+    let set_active = ExecuteMsg::SetSubDaoActive {
+        address: "subdao001".to_string(),
+        active: false,
+    };
+    test_unauthorized(&mut app, core_addr.clone(), set_active.clone());
+    app.execute_contract(core_addr.clone(), core_addr.clone(), &set_active, &[])
+        .unwrap();
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::SetSubDaoActive {
+                address: "subdao003".to_string(),
+                active: false,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::SubDaoDoesNotExist {
+            address: Addr::unchecked("subdao003")
+        }
+    );
+
+    // Updating a SubDAO keeps its status and the height it was added
+    // at.
+    add_sub_dao(&mut app, "subdao001", "uno");
+    let info: Option<SubDaoInfo> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::SubDaoInfo {
+                address: "subdao001".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        info,
+        Some(SubDaoInfo {
+            name: Some("uno".to_string()),
+            charter: None,
+            added_at,
+            active: false,
+        })
+    );
+
+    assert_eq!(list(&app, None, None), vec!["subdao001", "subdao002"]);
+    assert_eq!(list(&app, Some(true), None), vec!["subdao002"]);
+    assert_eq!(list(&app, None, Some(added_at)), vec!["subdao002"]);
+    assert!(list(&app, None, Some(added_at + 5)).is_empty());
+}
+
+#[test]
+fn test_migrate_sub_daos() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let sub_dao = Addr::unchecked("subdao");
+    LEGACY_SUBDAO_LIST
+        .save(
+            &mut deps.storage,
+            &sub_dao,
+            &Some("legacy charter".to_string()),
+        )
+        .unwrap();
+
+    migrate(deps.as_mut(), env.clone(), MigrateMsg::FromCompatible {}).unwrap();
+    assert!(!LEGACY_SUBDAO_LIST.has(&deps.storage, &sub_dao));
+    let migrated = SubDaoInfo {
+        name: None,
+        charter: Some("legacy charter".to_string()),
+        added_at: env.block.height,
+        active: true,
+    };
+    assert_eq!(SUBDAO_LIST.load(&deps.storage, &sub_dao).unwrap(), migrated);
+
+    // Migrating again does not overwrite SubDAOs which have already
+    // been moved to the current list.
+    LEGACY_SUBDAO_LIST
+        .save(&mut deps.storage, &sub_dao, &None)
+        .unwrap();
+    let mut later = env;
+    later.block.height += 10;
+    migrate(deps.as_mut(), later, MigrateMsg::FromCompatible {}).unwrap();
+    assert!(!LEGACY_SUBDAO_LIST.has(&deps.storage, &sub_dao));
+    assert_eq!(SUBDAO_LIST.load(&deps.storage, &sub_dao).unwrap(), migrated);
+}
+
+#[test]
+fn test_create_sub_dao() {
+    let (core_addr, mut app) = do_standard_instantiate(false, None);
+    let govmod_id = app.store_code(sudo_proposal_contract());
+    let gov_id = app.store_code(cw_core_contract());
+
+    let govmod_instantiate = dao_proposal_sudo::msg::InstantiateMsg {
+        root: CREATOR_ADDR.to_string(),
+    };
+    let sub_dao_instantiate = InstantiateMsg {
+        dao_uri: None,
+        // Replaced with the parent DAO.
+        admin: None,
+        name: "SubDAO".to_string(),
+        description: "A DAO made by a DAO.".to_string(),
+        image_url: None,
+        voting_module_instantiate_info: ModuleInstantiateInfo {
+            code_id: govmod_id,
+            msg: to_binary(&govmod_instantiate).unwrap(),
+            admin: Some(Admin::CoreModule {}),
+            label: "voting module".to_string(),
+        },
+        proposal_modules_instantiate_info: vec![ModuleInstantiateInfo {
+            code_id: govmod_id,
+            msg: to_binary(&govmod_instantiate).unwrap(),
+            admin: Some(Admin::CoreModule {}),
+            label: "governance module".to_string(),
+        }],
+        initial_items: None,
+        automatically_add_cw20s: true,
+        automatically_add_cw721s: true,
+    };
+    let create = ExecuteMsg::CreateSubDao {
+        instantiate_info: ModuleInstantiateInfo {
+            code_id: gov_id,
+            msg: to_binary(&sub_dao_instantiate).unwrap(),
+            admin: Some(Admin::CoreModule {}),
+            label: "SubDAO".to_string(),
+        },
+        charter: Some("charter".to_string()),
+    };
+
+    test_unauthorized(&mut app, core_addr.clone(), create.clone());
+    app.execute_contract(core_addr.clone(), core_addr.clone(), &create, &[])
+        .unwrap();
+
+    let sub_daos: Vec<SubDao> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::ListSubDaos {
+                start_after: None,
+                limit: None,
+                active_only: None,
+                added_after: None,
+                order: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(sub_daos.len(), 1);
+    assert_eq!(sub_daos[0].name, Some("SubDAO".to_string()));
+    assert_eq!(sub_daos[0].charter, Some("charter".to_string()));
+
+    let admin: Addr = app
+        .wrap()
+        .query_wasm_smart(sub_daos[0].addr.clone(), &QueryMsg::Admin {})
+        .unwrap();
+    assert_eq!(admin, core_addr);
+    let contract = app
+        .wrap()
+        .query_wasm_contract_info(sub_daos[0].addr.clone())
+        .unwrap();
+    assert_eq!(contract.admin, Some(core_addr.to_string()));
+
+    // The message must instantiate a core contract.
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            core_addr,
+            &ExecuteMsg::CreateSubDao {
+                instantiate_info: ModuleInstantiateInfo {
+                    code_id: govmod_id,
+                    msg: to_binary(&govmod_instantiate).unwrap(),
+                    admin: None,
+                    label: "not a DAO".to_string(),
+                },
+                charter: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::Std(StdError::ParseErr { .. })));
+}
+
+#[test]
+fn test_list_sub_daos_recursive() {
+    let (core_addr, mut app) = do_standard_instantiate(false, None);
+    let child = instantiate_standard_dao(&mut app, false, Some(core_addr.to_string()));
+    // Lists the parent as a SubDAO without being administered by it.
+    let rogue = instantiate_standard_dao(&mut app, false, None);
+    let grandchild = instantiate_standard_dao(&mut app, false, Some(child.to_string()));
+
+    let add_sub_daos = |app: &mut App, dao: &Addr, to_add: Vec<SubDao>| {
+        app.execute_contract(
+            dao.clone(),
+            dao.clone(),
+            &ExecuteMsg::UpdateSubDaos {
+                to_add,
+                to_remove: vec![],
+            },
+            &[],
+        )
+        .unwrap();
+    };
+    add_sub_daos(
+        &mut app,
+        &core_addr,
+        vec![
+            SubDao {
+                addr: child.to_string(),
+                name: None,
+                charter: Some("child charter".to_string()),
+            },
+            SubDao {
+                addr: rogue.to_string(),
+                name: None,
+                charter: None,
+            },
+            SubDao {
+                addr: "notadao".to_string(),
+                name: None,
+                charter: None,
+            },
+        ],
+    );
+    add_sub_daos(
+        &mut app,
+        &child,
+        vec![SubDao {
+            addr: grandchild.to_string(),
+            name: None,
+            charter: None,
+        }],
+    );
+    add_sub_daos(
+        &mut app,
+        &rogue,
+        vec![SubDao {
+            addr: core_addr.to_string(),
+            name: None,
+            charter: None,
+        }],
+    );
+
+    let list = |app: &App, max_depth: Option<u32>, limit: Option<u32>| -> Vec<SubDaoNode> {
+        app.wrap()
+            .query_wasm_smart(
+                core_addr.clone(),
+                &QueryMsg::ListSubDaosRecursive { max_depth, limit },
+            )
+            .unwrap()
+    };
+    let leaf = |addr: &Addr, verified: bool| SubDaoNode {
+        addr: addr.to_string(),
+        charter: None,
+        verified,
+        sub_daos: vec![],
+    };
+
+    let tree = list(&app, None, None);
+    let mut expected = vec![
+        SubDaoNode {
+            addr: child.to_string(),
+            charter: Some("child charter".to_string()),
+            verified: true,
+            sub_daos: vec![leaf(&grandchild, true)],
+        },
+        SubDaoNode {
+            addr: rogue.to_string(),
+            charter: None,
+            verified: false,
+            sub_daos: vec![leaf(&core_addr, false)],
+        },
+        leaf(&Addr::unchecked("notadao"), false),
+    ];
+    expected.sort_by(|a, b| a.addr.cmp(&b.addr));
+    assert_eq!(tree, expected);
+
+    // One level lists only direct SubDAOs.
+    let tree = list(&app, Some(1), None);
+    assert!(tree.iter().all(|node| node.sub_daos.is_empty()));
+    assert_eq!(tree.len(), 3);
+
+    // The cycle through the rogue DAO is cut off at the maximum
+    // depth.
+    let tree = list(&app, Some(100), None);
+    let rogue_node = tree
+        .iter()
+        .find(|node| node.addr == rogue.as_str())
+        .unwrap();
+    assert_eq!(rogue_node.sub_daos[0].addr, core_addr.as_str());
+    assert_eq!(rogue_node.sub_daos[0].sub_daos.len(), 3);
+
+    assert_eq!(list(&app, None, Some(1)).len(), 1);
+    assert!(list(&app, Some(0), None).is_empty());
 }
 
 #[test]
@@ -2986,6 +5110,33 @@ pub fn test_migrate_update_version() {
     assert_eq!(version.contract, CONTRACT_NAME);
 }
 
+#[test]
+pub fn test_migrate_wrong_version() {
+    let mut deps = mock_dependencies();
+    cw2::set_contract_version(&mut deps.storage, "crates.io:cw-core", "0.1.0").unwrap();
+    let err = migrate(deps.as_mut(), mock_env(), MigrateMsg::FromCompatible {}).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::MigrationError(MigrationError::NotCompatible {
+            stored: "0.1.0".to_string()
+        })
+    );
+
+    cw2::set_contract_version(&mut deps.storage, CONTRACT_NAME, CONTRACT_VERSION).unwrap();
+    let err = migrate(
+        deps.as_mut(),
+        mock_env(),
+        MigrateMsg::FromV1(FromV1Msg { dao_uri: None }),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::MigrationError(MigrationError::NotPreviousMajor {
+            stored: CONTRACT_VERSION.to_string()
+        })
+    );
+}
+
 #[test]
 fn test_query_info() {
     let (core_addr, app) = do_standard_instantiate(true, None);
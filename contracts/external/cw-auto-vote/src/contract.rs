@@ -0,0 +1,250 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_binary, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Order, Reply, Response,
+    StdResult, SubMsg,
+};
+use cw2::set_contract_version;
+use cw_paginate::keyset_bounds;
+use dao_proposal_single::msg::{ExecuteMsg as ProposalExecuteMsg, QueryMsg as ProposalQueryMsg};
+use dao_proposal_single::query::VoteResponse;
+use dao_vote_hooks::VoteHookMsg;
+use dao_voting::voting::Vote;
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::proto::exec_contract_msg;
+use crate::state::{Config, CONFIG, DELEGATES, FOLLOWERS, FOLLOWER_COUNT, MAX_FOLLOWERS};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:cw-auto-vote";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Reply ID for votes cast on behalf of followers. Dispatched as
+/// `reply_on_error` so that one follower's failed vote does not fail
+/// the delegate's.
+pub(crate) const AUTO_VOTE_REPLY_ID: u64 = 0;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let proposal_module = deps.api.addr_validate(&msg.proposal_module)?;
+    let dao: Addr = deps
+        .querier
+        .query_wasm_smart(&proposal_module, &ProposalQueryMsg::Dao {})?;
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            proposal_module: proposal_module.clone(),
+            dao: dao.clone(),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("proposal_module", proposal_module)
+        .add_attribute("dao", dao))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Follow { delegate } => execute_follow(deps, info, delegate),
+        ExecuteMsg::Unfollow {} => execute_unfollow(deps, info),
+        ExecuteMsg::VoteHook(hook) => execute_vote_hook(deps, env, info, hook),
+    }
+}
+
+/// Removes FOLLOWER from the followers of their current delegate, if
+/// any.
+fn unfollow(deps: DepsMut, follower: &Addr) -> StdResult<Option<Addr>> {
+    let delegate = DELEGATES.may_load(deps.storage, follower)?;
+    if let Some(delegate) = &delegate {
+        DELEGATES.remove(deps.storage, follower);
+        FOLLOWERS.remove(deps.storage, (delegate, follower));
+        FOLLOWER_COUNT.update(deps.storage, delegate, |count| -> StdResult<_> {
+            Ok(count.unwrap_or_default().saturating_sub(1))
+        })?;
+    }
+    Ok(delegate)
+}
+
+pub fn execute_follow(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    delegate: String,
+) -> Result<Response, ContractError> {
+    let delegate = deps.api.addr_validate(&delegate)?;
+    if delegate == info.sender {
+        return Err(ContractError::SelfFollow {});
+    }
+
+    unfollow(deps.branch(), &info.sender)?;
+    let count = FOLLOWER_COUNT
+        .may_load(deps.storage, &delegate)?
+        .unwrap_or_default();
+    if count >= MAX_FOLLOWERS {
+        return Err(ContractError::TooManyFollowers { max: MAX_FOLLOWERS });
+    }
+    FOLLOWER_COUNT.save(deps.storage, &delegate, &(count + 1))?;
+    FOLLOWERS.save(deps.storage, (&delegate, &info.sender), &Empty {})?;
+    DELEGATES.save(deps.storage, &info.sender, &delegate)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "follow")
+        .add_attribute("follower", info.sender)
+        .add_attribute("delegate", delegate))
+}
+
+pub fn execute_unfollow(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let delegate = unfollow(deps, &info.sender)?.ok_or(ContractError::NotFollowing {})?;
+
+    Ok(Response::new()
+        .add_attribute("action", "unfollow")
+        .add_attribute("follower", info.sender)
+        .add_attribute("delegate", delegate))
+}
+
+fn parse_vote(vote: &str) -> Option<Vote> {
+    match vote {
+        "yes" => Some(Vote::Yes),
+        "no" => Some(Vote::No),
+        "abstain" => Some(Vote::Abstain),
+        _ => None,
+    }
+}
+
+pub fn execute_vote_hook(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    hook: VoteHookMsg,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.proposal_module {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let votes = match hook {
+        VoteHookMsg::NewVote {
+            proposal_id,
+            voter,
+            vote,
+            ..
+        } => vec![(proposal_id, voter, vote)],
+        VoteHookMsg::NewVotes { votes } => votes
+            .into_iter()
+            .map(|v| (v.proposal_id, v.voter, v.vote))
+            .collect(),
+    };
+
+    let mut messages = vec![];
+    for (proposal_id, voter, vote) in votes {
+        let vote = match parse_vote(&vote) {
+            Some(vote) => vote,
+            None => continue,
+        };
+        let delegate = deps.api.addr_validate(&voter)?;
+        let followers = FOLLOWERS
+            .prefix(&delegate)
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<Addr>>>()?;
+        for follower in followers {
+            let VoteResponse { vote: ballot } = deps.querier.query_wasm_smart(
+                &config.proposal_module,
+                &ProposalQueryMsg::GetVote {
+                    proposal_id,
+                    voter: follower.to_string(),
+                },
+            )?;
+            if ballot.is_some() {
+                continue;
+            }
+            let msg = to_binary(&ProposalExecuteMsg::Vote {
+                proposal_id,
+                vote,
+                rationale: Some(format!("Following {delegate}")),
+            })?;
+            messages.push(SubMsg::reply_on_error(
+                exec_contract_msg(
+                    env.contract.address.as_str(),
+                    follower.as_str(),
+                    config.proposal_module.as_str(),
+                    msg,
+                ),
+                AUTO_VOTE_REPLY_ID,
+            ));
+        }
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "vote_hook")
+        .add_attribute("votes_cast", messages.len().to_string())
+        .add_submessages(messages))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::Delegate { follower } => query_delegate(deps, follower),
+        QueryMsg::ListFollowers {
+            delegate,
+            start_after,
+            limit,
+        } => query_list_followers(deps, delegate, start_after, limit),
+    }
+}
+
+pub fn query_delegate(deps: Deps, follower: String) -> StdResult<Binary> {
+    let follower = deps.api.addr_validate(&follower)?;
+    to_binary(&DELEGATES.may_load(deps.storage, &follower)?)
+}
+
+pub fn query_list_followers(
+    deps: Deps,
+    delegate: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let delegate = deps.api.addr_validate(&delegate)?;
+    let start_after = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    let (min, max) = keyset_bounds(start_after.as_ref(), Order::Ascending);
+    let followers = FOLLOWERS
+        .prefix(&delegate)
+        .keys(deps.storage, min, max, Order::Ascending);
+    let followers: StdResult<Vec<Addr>> = match limit {
+        Some(limit) => followers.take(limit as usize).collect(),
+        None => followers.collect(),
+    };
+    to_binary(&followers?)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(_deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        // Only dispatched as `reply_on_error`. Followers who have not
+        // granted authorization or have no voting power are skipped.
+        AUTO_VOTE_REPLY_ID => Ok(Response::new().add_attribute("auto_vote_skipped", "true")),
+        id => Err(ContractError::UnknownReplyId { id }),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    msg.check_stored_version(deps.storage, CONTRACT_VERSION)?;
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::default())
+}
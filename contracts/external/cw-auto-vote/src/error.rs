@@ -0,0 +1,27 @@
+use cosmwasm_std::StdError;
+use dao_interface::migrate::MigrationError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    MigrationError(#[from] MigrationError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("An address may not follow itself")]
+    SelfFollow {},
+
+    #[error("Delegate already has the maximum number of followers ({max})")]
+    TooManyFollowers { max: u64 },
+
+    #[error("Not following a delegate")]
+    NotFollowing {},
+
+    #[error("Got a reply with an unknown ID: ({id})")]
+    UnknownReplyId { id: u64 },
+}
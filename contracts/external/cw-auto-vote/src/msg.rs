@@ -0,0 +1,41 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use dao_vote_hooks::VoteHookMsg;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The `dao-proposal-single` module to vote on. This contract
+    /// must be added as one of its vote hooks.
+    pub proposal_module: String,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Mirrors DELEGATE's future votes, replacing the sender's
+    /// current delegate if any. The sender must grant this contract
+    /// authorization to execute the proposal module on their behalf.
+    Follow { delegate: String },
+    /// Stops mirroring the sender's delegate.
+    Unfollow {},
+    /// Casts the delegate's vote for each of their followers who has
+    /// not yet voted. Only callable by the proposal module.
+    VoteHook(VoteHookMsg),
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(crate::state::Config)]
+    Config {},
+    /// Returns the delegate FOLLOWER mirrors, if any.
+    #[returns(Option<::cosmwasm_std::Addr>)]
+    Delegate { follower: String },
+    /// Lists the followers of DELEGATE.
+    #[returns(Vec<::cosmwasm_std::Addr>)]
+    ListFollowers {
+        delegate: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+
+pub use dao_interface::migrate::MigrateMsg;
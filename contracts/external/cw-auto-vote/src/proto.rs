@@ -0,0 +1,53 @@
+//! Minimal protobuf definitions for the x/authz and x/wasm messages
+//! this contract sends. Field numbers match `cosmos.authz.v1beta1`
+//! and `cosmwasm.wasm.v1`.
+
+use cosmwasm_std::{Binary, CosmosMsg};
+use prost::Message;
+
+pub const MSG_EXEC_TYPE_URL: &str = "/cosmos.authz.v1beta1.MsgExec";
+pub const MSG_EXECUTE_CONTRACT_TYPE_URL: &str = "/cosmwasm.wasm.v1.MsgExecuteContract";
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgExec {
+    #[prost(string, tag = "1")]
+    pub grantee: String,
+    #[prost(message, repeated, tag = "2")]
+    pub msgs: Vec<prost_types::Any>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgExecuteContract {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(string, tag = "2")]
+    pub contract: String,
+    #[prost(bytes = "vec", tag = "3")]
+    pub msg: Vec<u8>,
+}
+
+/// Creates a message that, using an authorization granted by SENDER
+/// to GRANTEE, executes CONTRACT with MSG as SENDER.
+pub fn exec_contract_msg<T>(
+    grantee: &str,
+    sender: &str,
+    contract: &str,
+    msg: Binary,
+) -> CosmosMsg<T> {
+    let execute = MsgExecuteContract {
+        sender: sender.to_string(),
+        contract: contract.to_string(),
+        msg: msg.0,
+    };
+    let msg = MsgExec {
+        grantee: grantee.to_string(),
+        msgs: vec![prost_types::Any {
+            type_url: MSG_EXECUTE_CONTRACT_TYPE_URL.to_string(),
+            value: execute.encode_to_vec(),
+        }],
+    };
+    CosmosMsg::Stargate {
+        type_url: MSG_EXEC_TYPE_URL.to_string(),
+        value: Binary(msg.encode_to_vec()),
+    }
+}
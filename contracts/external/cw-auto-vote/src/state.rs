@@ -0,0 +1,26 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Empty};
+use cw_storage_plus::{Item, Map};
+
+#[cw_serde]
+pub struct Config {
+    /// The proposal module whose vote hooks this contract consumes
+    /// and on which it votes for followers.
+    pub proposal_module: Addr,
+    /// The DAO the proposal module belongs to.
+    pub dao: Addr,
+}
+
+/// The maximum number of followers a delegate may have.
+pub const MAX_FOLLOWERS: u64 = 50;
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+/// The delegate each follower mirrors the votes of.
+pub const DELEGATES: Map<&Addr, Addr> = Map::new("delegates");
+/// The set of followers of each delegate. The existence of a
+/// `(delegate, follower)` pair implies that `follower` follows
+/// `delegate`.
+pub const FOLLOWERS: Map<(&Addr, &Addr), Empty> = Map::new("followers");
+/// The number of followers each delegate has.
+pub const FOLLOWER_COUNT: Map<&Addr, u64> = Map::new("follower_count");
@@ -0,0 +1,260 @@
+use cosmwasm_std::testing::{
+    mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+};
+use cosmwasm_std::{
+    from_binary, from_slice, to_binary, Addr, ContractResult, CosmosMsg, Empty, OwnedDeps,
+    SystemResult, Uint128, WasmQuery,
+};
+use dao_interface::migrate::MigrationError;
+use dao_proposal_single::msg::{ExecuteMsg as ProposalExecuteMsg, QueryMsg as ProposalQueryMsg};
+use dao_proposal_single::query::{VoteInfo, VoteResponse};
+use dao_vote_hooks::{VoteHookMsg, VoteTally};
+use dao_voting::voting::{Vote, Votes};
+use prost::Message;
+
+use crate::contract::{
+    execute, instantiate, migrate, query, AUTO_VOTE_REPLY_ID, CONTRACT_NAME, CONTRACT_VERSION,
+};
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::proto::{MsgExec, MsgExecuteContract, MSG_EXECUTE_CONTRACT_TYPE_URL, MSG_EXEC_TYPE_URL};
+use crate::state::{Config, MAX_FOLLOWERS};
+use crate::ContractError;
+
+const DAO: &str = "dao";
+const PROPOSAL_MODULE: &str = "proposal";
+const DELEGATE: &str = "delegate";
+const FOLLOWER: &str = "follower";
+/// Votes on every proposal before the delegate does.
+const EAGER_FOLLOWER: &str = "eager";
+
+fn setup() -> OwnedDeps<MockStorage, MockApi, MockQuerier, Empty> {
+    let mut deps = mock_dependencies();
+    deps.querier.update_wasm(|query| match query {
+        WasmQuery::Smart { contract_addr, msg } if contract_addr == PROPOSAL_MODULE => {
+            let res = match from_slice(msg).unwrap() {
+                ProposalQueryMsg::Dao {} => to_binary(&Addr::unchecked(DAO)),
+                ProposalQueryMsg::GetVote { voter, .. } => to_binary(&VoteResponse {
+                    vote: (voter == EAGER_FOLLOWER).then(|| VoteInfo {
+                        voter: Addr::unchecked(voter),
+                        vote: Vote::No,
+                        power: Uint128::new(1),
+                        rationale: None,
+                    }),
+                }),
+                _ => panic!("unexpected query"),
+            };
+            SystemResult::Ok(ContractResult::Ok(res.unwrap()))
+        }
+        _ => panic!("unexpected query"),
+    });
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        InstantiateMsg {
+            proposal_module: PROPOSAL_MODULE.to_string(),
+        },
+    )
+    .unwrap();
+    deps
+}
+
+fn follow(
+    deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>,
+    follower: &str,
+    delegate: &str,
+) -> Result<(), ContractError> {
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(follower, &[]),
+        ExecuteMsg::Follow {
+            delegate: delegate.to_string(),
+        },
+    )
+    .map(|_| ())
+}
+
+fn list_followers(
+    deps: &OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>,
+    delegate: &str,
+) -> Vec<Addr> {
+    from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ListFollowers {
+                delegate: delegate.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+fn new_vote(voter: &str, vote: Vote) -> VoteHookMsg {
+    VoteHookMsg::NewVote {
+        proposal_id: 1,
+        voter: voter.to_string(),
+        vote: vote.to_string(),
+        power: Uint128::new(1),
+        height: 0,
+        tally: VoteTally::SingleChoice(Votes::zero()),
+    }
+}
+
+#[test]
+fn test_instantiate() {
+    let deps = setup();
+    let config: Config =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap()).unwrap();
+    assert_eq!(
+        config,
+        Config {
+            proposal_module: Addr::unchecked(PROPOSAL_MODULE),
+            dao: Addr::unchecked(DAO),
+        }
+    );
+}
+
+#[test]
+fn test_follow_unfollow() {
+    let mut deps = setup();
+
+    let err = follow(&mut deps, DELEGATE, DELEGATE).unwrap_err();
+    assert!(matches!(err, ContractError::SelfFollow {}));
+
+    follow(&mut deps, FOLLOWER, DELEGATE).unwrap();
+    follow(&mut deps, EAGER_FOLLOWER, DELEGATE).unwrap();
+    assert_eq!(
+        list_followers(&deps, DELEGATE),
+        vec![Addr::unchecked(EAGER_FOLLOWER), Addr::unchecked(FOLLOWER)]
+    );
+    let delegate: Option<Addr> = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Delegate {
+                follower: FOLLOWER.to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(delegate, Some(Addr::unchecked(DELEGATE)));
+
+    // Following another delegate replaces the current one.
+    follow(&mut deps, FOLLOWER, "other").unwrap();
+    assert_eq!(
+        list_followers(&deps, DELEGATE),
+        vec![Addr::unchecked(EAGER_FOLLOWER)]
+    );
+    assert_eq!(
+        list_followers(&deps, "other"),
+        vec![Addr::unchecked(FOLLOWER)]
+    );
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(FOLLOWER, &[]),
+        ExecuteMsg::Unfollow {},
+    )
+    .unwrap();
+    assert!(list_followers(&deps, "other").is_empty());
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(FOLLOWER, &[]),
+        ExecuteMsg::Unfollow {},
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::NotFollowing {}));
+}
+
+#[test]
+fn test_max_followers() {
+    let mut deps = setup();
+    for i in 0..MAX_FOLLOWERS {
+        follow(&mut deps, &format!("follower{i}"), DELEGATE).unwrap();
+    }
+    let err = follow(&mut deps, FOLLOWER, DELEGATE).unwrap_err();
+    assert!(matches!(err, ContractError::TooManyFollowers { .. }));
+
+    // Refollowing the same delegate does not count twice.
+    follow(&mut deps, "follower0", DELEGATE).unwrap();
+}
+
+#[test]
+fn test_vote_hook() {
+    let mut deps = setup();
+    let env = mock_env();
+    follow(&mut deps, FOLLOWER, DELEGATE).unwrap();
+    follow(&mut deps, EAGER_FOLLOWER, DELEGATE).unwrap();
+
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(DELEGATE, &[]),
+        ExecuteMsg::VoteHook(new_vote(DELEGATE, Vote::Yes)),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    // Votes by addresses without followers do nothing.
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(PROPOSAL_MODULE, &[]),
+        ExecuteMsg::VoteHook(new_vote(FOLLOWER, Vote::Yes)),
+    )
+    .unwrap();
+    assert!(res.messages.is_empty());
+
+    // The follower who already voted is skipped.
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(PROPOSAL_MODULE, &[]),
+        ExecuteMsg::VoteHook(new_vote(DELEGATE, Vote::Yes)),
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 1);
+    assert_eq!(res.messages[0].id, AUTO_VOTE_REPLY_ID);
+    match &res.messages[0].msg {
+        CosmosMsg::Stargate { type_url, value } => {
+            assert_eq!(type_url, MSG_EXEC_TYPE_URL);
+            let exec = MsgExec::decode(value.as_slice()).unwrap();
+            assert_eq!(exec.grantee, env.contract.address.to_string());
+            assert_eq!(exec.msgs.len(), 1);
+            assert_eq!(exec.msgs[0].type_url, MSG_EXECUTE_CONTRACT_TYPE_URL);
+            let execute = MsgExecuteContract::decode(exec.msgs[0].value.as_slice()).unwrap();
+            assert_eq!(execute.sender, FOLLOWER);
+            assert_eq!(execute.contract, PROPOSAL_MODULE);
+            let msg: ProposalExecuteMsg = from_slice(&execute.msg).unwrap();
+            assert_eq!(
+                msg,
+                ProposalExecuteMsg::Vote {
+                    proposal_id: 1,
+                    vote: Vote::Yes,
+                    rationale: Some(format!("Following {DELEGATE}")),
+                }
+            );
+        }
+        _ => panic!("expected a stargate message"),
+    }
+}
+
+#[test]
+fn test_migrate_checks_stored_version() {
+    let mut deps = mock_dependencies();
+    cw2::set_contract_version(&mut deps.storage, CONTRACT_NAME, CONTRACT_VERSION).unwrap();
+    let err = migrate(deps.as_mut(), mock_env(), MigrateMsg::FromV1(Empty {})).unwrap_err();
+    assert!(matches!(
+        err,
+        ContractError::MigrationError(MigrationError::NotPreviousMajor { .. })
+    ));
+    migrate(deps.as_mut(), mock_env(), MigrateMsg::FromCompatible {}).unwrap();
+}
@@ -0,0 +1,339 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    from_binary, to_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Order, Response,
+    StdResult, Storage, Uint128,
+};
+use cw2::set_contract_version;
+use cw20::Cw20ReceiveMsg;
+use cw_denom::CheckedDenom;
+use cw_storage_plus::Bound;
+use cw_utils::{must_pay, nonpayable};
+use dao_interface::querier::CoreQuerier;
+
+use crate::error::ContractError;
+use crate::msg::{
+    EpochResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, PendingReward, QueryMsg, ReceiveMsg,
+};
+use crate::state::{Config, EpochRewards, CLAIMS, CONFIG, EPOCH_REWARDS};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:cw-epoch-rewards";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The maximum number of epochs funds may be split across.
+pub const MAX_FUNDED_EPOCHS: u64 = 100;
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    if msg.epoch_length == 0 {
+        return Err(ContractError::ZeroEpochLength {});
+    }
+    if msg.claim_window == 0 {
+        return Err(ContractError::ZeroClaimWindow {});
+    }
+    let dao = deps.api.addr_validate(&msg.dao)?;
+    let denom = msg.denom.into_checked(deps.as_ref())?;
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            dao: dao.clone(),
+            denom,
+            epoch_length: msg.epoch_length,
+            claim_window: msg.claim_window,
+            start_height: env.block.height,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("dao", dao)
+        .add_attribute("epoch_length", msg.epoch_length.to_string()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Fund { epochs } => execute_fund_native(deps, env, info, epochs),
+        ExecuteMsg::Receive(msg) => execute_receive(deps, env, info, msg),
+        ExecuteMsg::Claim { epochs } => execute_claim(deps, env, info, epochs),
+        ExecuteMsg::Carryover { epoch } => execute_carryover(deps, env, info, epoch),
+    }
+}
+
+pub fn execute_fund_native(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    epochs: Option<u64>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let amount = match &config.denom {
+        CheckedDenom::Native(denom) => must_pay(&info, denom)?,
+        CheckedDenom::Cw20(_) => return Err(ContractError::InvalidToken {}),
+    };
+    fund(deps, env, config, amount, epochs)
+}
+
+pub fn execute_receive(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.denom != CheckedDenom::Cw20(info.sender) {
+        return Err(ContractError::InvalidToken {});
+    }
+    match from_binary(&msg.msg)? {
+        ReceiveMsg::Fund { epochs } => fund(deps, env, config, msg.amount, epochs),
+    }
+}
+
+/// Splits AMOUNT evenly between the current epoch and the EPOCHS - 1
+/// epochs after it. The remainder of the split goes to the current
+/// epoch.
+fn fund(
+    deps: DepsMut,
+    env: Env,
+    config: Config,
+    amount: Uint128,
+    epochs: Option<u64>,
+) -> Result<Response, ContractError> {
+    let epochs = epochs.unwrap_or(1);
+    if epochs == 0 || epochs > MAX_FUNDED_EPOCHS {
+        return Err(ContractError::InvalidEpochCount {
+            max: MAX_FUNDED_EPOCHS,
+        });
+    }
+    let current = config.epoch_at(env.block.height);
+    let share = amount / Uint128::from(epochs);
+    let remainder = amount - share * Uint128::from(epochs);
+    for epoch in current..current + epochs {
+        let funded = if epoch == current {
+            share + remainder
+        } else {
+            share
+        };
+        add_rewards(deps.storage, epoch, funded)?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "fund")
+        .add_attribute("amount", amount)
+        .add_attribute("first_epoch", current.to_string())
+        .add_attribute("epochs", epochs.to_string()))
+}
+
+fn add_rewards(storage: &mut dyn Storage, epoch: u64, amount: Uint128) -> StdResult<()> {
+    let mut rewards = EPOCH_REWARDS.may_load(storage, epoch)?.unwrap_or_default();
+    rewards.amount = rewards.amount.checked_add(amount)?;
+    EPOCH_REWARDS.save(storage, epoch, &rewards)
+}
+
+/// Gets the rewards ADDRESS may claim from EPOCH, which must have
+/// ended and be within its claim window.
+fn claimable(
+    deps: Deps,
+    config: &Config,
+    height: u64,
+    address: &Addr,
+    epoch: u64,
+) -> Result<(EpochRewards, Uint128), ContractError> {
+    if height < config.snapshot_height(epoch) {
+        return Err(ContractError::EpochNotEnded { epoch });
+    }
+    if height >= config.claim_deadline(epoch) {
+        return Err(ContractError::ClaimWindowClosed { epoch });
+    }
+    let rewards = EPOCH_REWARDS
+        .may_load(deps.storage, epoch)?
+        .unwrap_or_default();
+    if rewards.carried_over {
+        return Err(ContractError::CarriedOver { epoch });
+    }
+    if CLAIMS.has(deps.storage, (epoch, address)) {
+        return Err(ContractError::AlreadyClaimed { epoch });
+    }
+    if rewards.amount.is_zero() {
+        return Ok((rewards, Uint128::zero()));
+    }
+
+    let dao = CoreQuerier::new(deps.querier, config.dao.clone());
+    let height = Some(config.snapshot_height(epoch));
+    let total = dao.total_power_at_height(height)?.power;
+    let power = dao.voting_power_at_height(address, height)?.power;
+    let amount = if total.is_zero() {
+        Uint128::zero()
+    } else {
+        rewards.amount.multiply_ratio(power, total)
+    };
+    Ok((rewards, amount))
+}
+
+pub fn execute_claim(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    epochs: Vec<u64>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    let mut total = Uint128::zero();
+    for epoch in epochs {
+        let (mut rewards, amount) = claimable(
+            deps.as_ref(),
+            &config,
+            env.block.height,
+            &info.sender,
+            epoch,
+        )?;
+        rewards.claimed += amount;
+        EPOCH_REWARDS.save(deps.storage, epoch, &rewards)?;
+        CLAIMS.save(deps.storage, (epoch, &info.sender), &amount)?;
+        total += amount;
+    }
+    if total.is_zero() {
+        return Err(ContractError::NothingToClaim {});
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "claim")
+        .add_attribute("claimer", info.sender.clone())
+        .add_attribute("amount", total)
+        .add_message(config.denom.get_transfer_to_message(&info.sender, total)?))
+}
+
+pub fn execute_carryover(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    epoch: u64,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    let config = CONFIG.load(deps.storage)?;
+    let height = env.block.height;
+
+    if height < config.snapshot_height(epoch) {
+        return Err(ContractError::EpochNotEnded { epoch });
+    }
+    let mut rewards = EPOCH_REWARDS
+        .may_load(deps.storage, epoch)?
+        .unwrap_or_default();
+    if rewards.carried_over {
+        return Err(ContractError::CarriedOver { epoch });
+    }
+    // Rewards for epochs no one had voting power in can never be
+    // claimed, so need not wait for the claim window to close.
+    if height < config.claim_deadline(epoch)
+        && !CoreQuerier::new(deps.querier, config.dao.clone())
+            .total_power_at_height(Some(config.snapshot_height(epoch)))?
+            .power
+            .is_zero()
+    {
+        return Err(ContractError::ClaimWindowOpen { epoch });
+    }
+
+    let unclaimed = rewards.amount.checked_sub(rewards.claimed)?;
+    rewards.carried_over = true;
+    EPOCH_REWARDS.save(deps.storage, epoch, &rewards)?;
+    let current = config.epoch_at(height);
+    add_rewards(deps.storage, current, unclaimed)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "carryover")
+        .add_attribute("epoch", epoch.to_string())
+        .add_attribute("to_epoch", current.to_string())
+        .add_attribute("amount", unclaimed))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::CurrentEpoch {} => {
+            let config = CONFIG.load(deps.storage)?;
+            to_binary(&query_epoch(
+                deps,
+                &config,
+                config.epoch_at(env.block.height),
+            )?)
+        }
+        QueryMsg::Epoch { epoch } => {
+            to_binary(&query_epoch(deps, &CONFIG.load(deps.storage)?, epoch)?)
+        }
+        QueryMsg::PendingRewards {
+            address,
+            start_after,
+            limit,
+        } => query_pending_rewards(deps, env, address, start_after, limit),
+    }
+}
+
+pub fn query_epoch(deps: Deps, config: &Config, epoch: u64) -> StdResult<EpochResponse> {
+    Ok(EpochResponse {
+        epoch,
+        start_height: config.epoch_start(epoch),
+        snapshot_height: config.snapshot_height(epoch),
+        rewards: EPOCH_REWARDS
+            .may_load(deps.storage, epoch)?
+            .unwrap_or_default(),
+    })
+}
+
+pub fn query_pending_rewards(
+    deps: Deps,
+    env: Env,
+    address: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let address = deps.api.addr_validate(&address)?;
+    let limit = cw_paginate::clamp_limit(limit, DEFAULT_LIMIT, MAX_LIMIT);
+    let height = env.block.height;
+
+    // Only epochs whose claim windows are open can have pending
+    // rewards.
+    let first = config.epoch_at(height).saturating_sub(config.claim_window);
+    let min = match start_after {
+        Some(start_after) if start_after >= first => Bound::exclusive(start_after),
+        _ => Bound::inclusive(first),
+    };
+
+    let mut pending = vec![];
+    for epoch in EPOCH_REWARDS.keys(deps.storage, Some(min), None, Order::Ascending) {
+        let epoch = epoch?;
+        if pending.len() as u32 >= limit || height < config.snapshot_height(epoch) {
+            break;
+        }
+        match claimable(deps, &config, height, &address, epoch) {
+            Ok((_, amount)) if !amount.is_zero() => pending.push(PendingReward { epoch, amount }),
+            Err(ContractError::Std(err)) => return Err(err),
+            _ => (),
+        }
+    }
+    to_binary(&pending)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    msg.check_stored_version(deps.storage, CONTRACT_VERSION)?;
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::default())
+}
@@ -0,0 +1,50 @@
+use cosmwasm_std::StdError;
+use cw_denom::DenomError;
+use cw_utils::PaymentError;
+use dao_interface::migrate::MigrationError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    MigrationError(#[from] MigrationError),
+
+    #[error(transparent)]
+    DenomError(#[from] DenomError),
+
+    #[error(transparent)]
+    PaymentError(#[from] PaymentError),
+
+    #[error("Only the reward token may be sent to this contract")]
+    InvalidToken {},
+
+    #[error("Epoch length cannot be 0")]
+    ZeroEpochLength {},
+
+    #[error("Claim window cannot be 0")]
+    ZeroClaimWindow {},
+
+    #[error("Rewards must be split across between 1 and ({max}) epochs")]
+    InvalidEpochCount { max: u64 },
+
+    #[error("Epoch ({epoch}) has not ended")]
+    EpochNotEnded { epoch: u64 },
+
+    #[error("The claim window for epoch ({epoch}) has closed")]
+    ClaimWindowClosed { epoch: u64 },
+
+    #[error("The claim window for epoch ({epoch}) is still open")]
+    ClaimWindowOpen { epoch: u64 },
+
+    #[error("Rewards for epoch ({epoch}) have already been claimed")]
+    AlreadyClaimed { epoch: u64 },
+
+    #[error("Rewards for epoch ({epoch}) have been carried over")]
+    CarriedOver { epoch: u64 },
+
+    #[error("No rewards to claim")]
+    NothingToClaim {},
+}
@@ -0,0 +1,80 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Uint128;
+use cw20::Cw20ReceiveMsg;
+use cw_denom::UncheckedDenom;
+
+use crate::state::EpochRewards;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The DAO whose members receive rewards.
+    pub dao: String,
+    /// The token rewards are paid in.
+    pub denom: UncheckedDenom,
+    /// The number of blocks in an epoch.
+    pub epoch_length: u64,
+    /// The number of epochs after an epoch ends during which its
+    /// rewards may be claimed.
+    pub claim_window: u64,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Funds rewards with the native tokens attached. The rewards are
+    /// split evenly between the current epoch and the EPOCHS - 1
+    /// epochs after it. EPOCHS defaults to 1.
+    Fund { epochs: Option<u64> },
+    /// Funds rewards with cw20 tokens. The message must be a
+    /// `ReceiveMsg`.
+    Receive(Cw20ReceiveMsg),
+    /// Claims the sender's rewards from EPOCHS, which must have ended
+    /// and be within their claim windows.
+    Claim { epochs: Vec<u64> },
+    /// Moves EPOCH's unclaimed rewards into the current epoch. Callable
+    /// by anyone once EPOCH's claim window has closed, or once it has
+    /// ended if no one had voting power at its snapshot height.
+    Carryover { epoch: u64 },
+}
+
+#[cw_serde]
+pub enum ReceiveMsg {
+    Fund { epochs: Option<u64> },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(crate::state::Config)]
+    Config {},
+    /// Gets information about the current epoch.
+    #[returns(EpochResponse)]
+    CurrentEpoch {},
+    /// Gets information about EPOCH.
+    #[returns(EpochResponse)]
+    Epoch { epoch: u64 },
+    /// Lists the rewards ADDRESS may claim from ended epochs, in
+    /// ascending order of epoch.
+    #[returns(Vec<PendingReward>)]
+    PendingRewards {
+        address: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct EpochResponse {
+    pub epoch: u64,
+    pub start_height: u64,
+    /// The height at which the epoch ends and voting power is read.
+    pub snapshot_height: u64,
+    pub rewards: EpochRewards,
+}
+
+#[cw_serde]
+pub struct PendingReward {
+    pub epoch: u64,
+    pub amount: Uint128,
+}
+
+pub use dao_interface::migrate::MigrateMsg;
@@ -0,0 +1,64 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Uint128};
+use cw_denom::CheckedDenom;
+use cw_storage_plus::{Item, Map};
+
+#[cw_serde]
+pub struct Config {
+    /// The DAO whose members receive rewards.
+    pub dao: Addr,
+    /// The token rewards are paid in.
+    pub denom: CheckedDenom,
+    /// The number of blocks in an epoch.
+    pub epoch_length: u64,
+    /// The number of epochs after an epoch ends during which its
+    /// rewards may be claimed.
+    pub claim_window: u64,
+    /// The block height at which the first epoch began.
+    pub start_height: u64,
+}
+
+impl Config {
+    /// The epoch that contains HEIGHT.
+    pub fn epoch_at(&self, height: u64) -> u64 {
+        (height - self.start_height) / self.epoch_length
+    }
+
+    /// The height at which EPOCH begins.
+    pub fn epoch_start(&self, epoch: u64) -> u64 {
+        self.start_height + epoch * self.epoch_length
+    }
+
+    /// The height at which EPOCH ends, and at which the voting power
+    /// its rewards are divided by is read.
+    pub fn snapshot_height(&self, epoch: u64) -> u64 {
+        self.epoch_start(epoch + 1)
+    }
+
+    /// The height after which EPOCH's rewards may no longer be
+    /// claimed.
+    pub fn claim_deadline(&self, epoch: u64) -> u64 {
+        self.snapshot_height(epoch + self.claim_window)
+    }
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct EpochRewards {
+    /// The rewards funded for the epoch, including any carried over
+    /// from earlier epochs.
+    pub amount: Uint128,
+    /// The amount of the rewards that has been claimed.
+    pub claimed: Uint128,
+    /// True once the unclaimed rewards have been carried over to a
+    /// later epoch.
+    pub carried_over: bool,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+/// Rewards for each epoch that has been funded.
+pub const EPOCH_REWARDS: Map<u64, EpochRewards> = Map::new("epoch_rewards");
+
+/// The amount each address has claimed from each epoch.
+pub const CLAIMS: Map<(u64, &Addr), Uint128> = Map::new("claims");
@@ -0,0 +1,333 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::testing::{
+    mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+};
+use cosmwasm_std::{
+    coins, from_binary, from_slice, to_binary, BankMsg, ContractResult, CosmosMsg, Empty, Env,
+    OwnedDeps, SystemResult, Uint128, WasmQuery,
+};
+use cw20::Cw20ReceiveMsg;
+use cw_denom::UncheckedDenom;
+use dao_interface::{
+    migrate::MigrationError,
+    voting::{TotalPowerAtHeightResponse, VotingPowerAtHeightResponse},
+};
+
+use crate::contract::{execute, instantiate, migrate, query, CONTRACT_NAME, CONTRACT_VERSION};
+use crate::msg::{
+    EpochResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, PendingReward, QueryMsg, ReceiveMsg,
+};
+use crate::state::EpochRewards;
+use crate::ContractError;
+
+/// The voting power queries this contract makes of the DAO.
+#[cw_serde]
+enum DaoQuery {
+    VotingPowerAtHeight {
+        address: String,
+        height: Option<u64>,
+    },
+    TotalPowerAtHeight {
+        height: Option<u64>,
+    },
+}
+
+const DAO: &str = "dao";
+const ALICE: &str = "alice";
+const BOB: &str = "bob";
+const DENOM: &str = "ujuno";
+const EPOCH_LENGTH: u64 = 10;
+
+fn height(blocks: u64) -> u64 {
+    mock_env().block.height + blocks
+}
+
+fn blocks_later(blocks: u64) -> Env {
+    let mut env = mock_env();
+    env.block.height = height(blocks);
+    env
+}
+
+/// Alice has 10 voting power. Bob joins with 30 during epoch 1, and
+/// both leave at the start of epoch 10.
+fn power(address: &str, at: u64) -> Uint128 {
+    match address {
+        _ if at >= height(100) => Uint128::zero(),
+        ALICE => Uint128::new(10),
+        BOB if at >= height(15) => Uint128::new(30),
+        _ => Uint128::zero(),
+    }
+}
+
+fn setup() -> OwnedDeps<MockStorage, MockApi, MockQuerier, Empty> {
+    let mut deps = mock_dependencies();
+    deps.querier.update_wasm(|query| match query {
+        WasmQuery::Smart { contract_addr, msg } if contract_addr == DAO => {
+            let response = match from_slice(msg).unwrap() {
+                DaoQuery::VotingPowerAtHeight { address, height } => {
+                    to_binary(&VotingPowerAtHeightResponse {
+                        power: power(&address, height.unwrap()),
+                        height: height.unwrap(),
+                    })
+                }
+                DaoQuery::TotalPowerAtHeight { height } => to_binary(&TotalPowerAtHeightResponse {
+                    power: power(ALICE, height.unwrap()) + power(BOB, height.unwrap()),
+                    height: height.unwrap(),
+                }),
+            };
+            SystemResult::Ok(ContractResult::Ok(response.unwrap()))
+        }
+        _ => panic!("unexpected query"),
+    });
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        InstantiateMsg {
+            dao: DAO.to_string(),
+            denom: UncheckedDenom::Native(DENOM.to_string()),
+            epoch_length: EPOCH_LENGTH,
+            claim_window: 2,
+        },
+    )
+    .unwrap();
+    deps
+}
+
+fn fund(
+    deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>,
+    env: Env,
+    amount: u128,
+    epochs: Option<u64>,
+) {
+    execute(
+        deps.as_mut(),
+        env,
+        mock_info("funder", &coins(amount, DENOM)),
+        ExecuteMsg::Fund { epochs },
+    )
+    .unwrap();
+}
+
+fn claim(
+    deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>,
+    env: Env,
+    sender: &str,
+    epochs: Vec<u64>,
+) -> Result<Vec<CosmosMsg>, ContractError> {
+    execute(
+        deps.as_mut(),
+        env,
+        mock_info(sender, &[]),
+        ExecuteMsg::Claim { epochs },
+    )
+    .map(|res| res.messages.into_iter().map(|m| m.msg).collect())
+}
+
+fn carryover(
+    deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>,
+    env: Env,
+    epoch: u64,
+) -> Result<(), ContractError> {
+    execute(
+        deps.as_mut(),
+        env,
+        mock_info("anyone", &[]),
+        ExecuteMsg::Carryover { epoch },
+    )
+    .map(|_| ())
+}
+
+fn query_epoch(
+    deps: &OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>,
+    epoch: u64,
+) -> EpochResponse {
+    from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Epoch { epoch }).unwrap()).unwrap()
+}
+
+fn query_pending(
+    deps: &OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>,
+    env: Env,
+    address: &str,
+) -> Vec<PendingReward> {
+    from_binary(
+        &query(
+            deps.as_ref(),
+            env,
+            QueryMsg::PendingRewards {
+                address: address.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+fn payment(to: &str, amount: u128) -> CosmosMsg {
+    BankMsg::Send {
+        to_address: to.to_string(),
+        amount: coins(amount, DENOM),
+    }
+    .into()
+}
+
+#[test]
+fn test_instantiate_validation() {
+    let mut deps = mock_dependencies();
+    let msg = InstantiateMsg {
+        dao: DAO.to_string(),
+        denom: UncheckedDenom::Native(DENOM.to_string()),
+        epoch_length: 0,
+        claim_window: 1,
+    };
+    let err = instantiate(deps.as_mut(), mock_env(), mock_info(DAO, &[]), msg.clone()).unwrap_err();
+    assert!(matches!(err, ContractError::ZeroEpochLength {}));
+    let err = instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        InstantiateMsg {
+            epoch_length: 1,
+            claim_window: 0,
+            ..msg
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::ZeroClaimWindow {}));
+}
+
+#[test]
+fn test_fund() {
+    let mut deps = setup();
+    fund(&mut deps, blocks_later(5), 101, Some(2));
+    assert_eq!(
+        query_epoch(&deps, 0),
+        EpochResponse {
+            epoch: 0,
+            start_height: height(0),
+            snapshot_height: height(EPOCH_LENGTH),
+            rewards: EpochRewards {
+                amount: Uint128::new(51),
+                claimed: Uint128::zero(),
+                carried_over: false,
+            },
+        }
+    );
+    assert_eq!(query_epoch(&deps, 1).rewards.amount, Uint128::new(50));
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("funder", &coins(100, DENOM)),
+        ExecuteMsg::Fund { epochs: Some(0) },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidEpochCount { .. }));
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("funder", &coins(100, "uatom")),
+        ExecuteMsg::Fund { epochs: None },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::PaymentError(_)));
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("token", &[]),
+        ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: "funder".to_string(),
+            amount: Uint128::new(100),
+            msg: to_binary(&ReceiveMsg::Fund { epochs: None }).unwrap(),
+        }),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidToken {}));
+}
+
+#[test]
+fn test_claim() {
+    let mut deps = setup();
+    fund(&mut deps, mock_env(), 100, Some(2));
+
+    let err = claim(&mut deps, blocks_later(5), ALICE, vec![0]).unwrap_err();
+    assert!(matches!(err, ContractError::EpochNotEnded { epoch: 0 }));
+
+    // Only Alice had voting power at the end of epoch 0.
+    let err = claim(&mut deps, blocks_later(10), BOB, vec![0]).unwrap_err();
+    assert!(matches!(err, ContractError::NothingToClaim {}));
+    assert_eq!(
+        claim(&mut deps, blocks_later(10), ALICE, vec![0]).unwrap(),
+        vec![payment(ALICE, 50)]
+    );
+    let err = claim(&mut deps, blocks_later(11), ALICE, vec![0]).unwrap_err();
+    assert!(matches!(err, ContractError::AlreadyClaimed { epoch: 0 }));
+
+    // Epoch 1 is split 10:30.
+    assert_eq!(
+        query_pending(&deps, blocks_later(20), ALICE),
+        vec![PendingReward {
+            epoch: 1,
+            amount: Uint128::new(12),
+        }]
+    );
+    assert_eq!(
+        claim(&mut deps, blocks_later(20), BOB, vec![1]).unwrap(),
+        vec![payment(BOB, 37)]
+    );
+    assert!(query_pending(&deps, blocks_later(20), BOB).is_empty());
+
+    // The claim window closes two epochs after the epoch ends.
+    let err = claim(&mut deps, blocks_later(40), ALICE, vec![1]).unwrap_err();
+    assert!(matches!(err, ContractError::ClaimWindowClosed { epoch: 1 }));
+    assert!(query_pending(&deps, blocks_later(40), ALICE).is_empty());
+}
+
+#[test]
+fn test_carryover() {
+    let mut deps = setup();
+    fund(&mut deps, mock_env(), 100, Some(2));
+    claim(&mut deps, blocks_later(20), BOB, vec![1]).unwrap();
+
+    let err = carryover(&mut deps, blocks_later(15), 1).unwrap_err();
+    assert!(matches!(err, ContractError::EpochNotEnded { epoch: 1 }));
+    let err = carryover(&mut deps, blocks_later(39), 1).unwrap_err();
+    assert!(matches!(err, ContractError::ClaimWindowOpen { epoch: 1 }));
+
+    // Alice's unclaimed share of epoch 1 moves to epoch 4.
+    carryover(&mut deps, blocks_later(40), 1).unwrap();
+    assert!(query_epoch(&deps, 1).rewards.carried_over);
+    assert_eq!(query_epoch(&deps, 4).rewards.amount, Uint128::new(13));
+    let err = carryover(&mut deps, blocks_later(40), 1).unwrap_err();
+    assert!(matches!(err, ContractError::CarriedOver { epoch: 1 }));
+    let err = claim(&mut deps, blocks_later(40), ALICE, vec![0]).unwrap_err();
+    assert!(matches!(err, ContractError::ClaimWindowClosed { epoch: 0 }));
+
+    assert_eq!(
+        query_pending(&deps, blocks_later(50), ALICE),
+        vec![PendingReward {
+            epoch: 4,
+            amount: Uint128::new(3),
+        }]
+    );
+
+    // No one has voting power in epoch 10, so its rewards may be
+    // carried over as soon as it ends.
+    fund(&mut deps, blocks_later(100), 7, None);
+    carryover(&mut deps, blocks_later(110), 10).unwrap();
+    assert_eq!(query_epoch(&deps, 11).rewards.amount, Uint128::new(7));
+}
+
+#[test]
+fn test_migrate_checks_stored_version() {
+    let mut deps = mock_dependencies();
+    cw2::set_contract_version(&mut deps.storage, CONTRACT_NAME, CONTRACT_VERSION).unwrap();
+    let err = migrate(deps.as_mut(), mock_env(), MigrateMsg::FromV1(Empty {})).unwrap_err();
+    assert!(matches!(
+        err,
+        ContractError::MigrationError(MigrationError::NotPreviousMajor { .. })
+    ));
+    migrate(deps.as_mut(), mock_env(), MigrateMsg::FromCompatible {}).unwrap();
+}
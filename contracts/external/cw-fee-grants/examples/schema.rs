@@ -0,0 +1,11 @@
+use cw_fee_grants::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use dao_schema::write_schema;
+
+fn main() {
+    write_schema! {
+        instantiate: InstantiateMsg,
+        query: QueryMsg,
+        execute: ExecuteMsg,
+        migrate: MigrateMsg,
+    }
+}
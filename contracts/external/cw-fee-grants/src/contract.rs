@@ -0,0 +1,275 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_binary, Addr, BankMsg, Binary, Coin, Deps, DepsMut, Env, MessageInfo, Order, Reply,
+    Response, StdResult, SubMsg,
+};
+use cw2::set_contract_version;
+use cw_utils::Duration;
+use dao_interface::voting::VotingPowerAtHeightResponse;
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, GrantResponse, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::proto::{grant_allowance_msg, revoke_allowance_msg};
+use crate::state::{Config, CONFIG, GRANTS};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:cw-fee-grants";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Reply ID for revoke messages sent before a new allowance is
+/// granted. x/feegrant will not overwrite an existing allowance, so
+/// we revoke first and ignore the failure if none exists.
+pub(crate) const REVOKE_REPLY_ID: u64 = 0;
+
+fn validate_config(spend_limit: &[Coin], epoch: Duration) -> Result<(), ContractError> {
+    match epoch {
+        Duration::Height(_) => return Err(ContractError::HeightEpoch {}),
+        Duration::Time(0) => return Err(ContractError::ZeroEpoch {}),
+        Duration::Time(_) => (),
+    }
+    if spend_limit.is_empty() || spend_limit.iter().any(|c| c.amount.is_zero()) {
+        return Err(ContractError::InvalidSpendLimit {});
+    }
+    Ok(())
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    validate_config(&msg.spend_limit, msg.epoch)?;
+    let dao = deps.api.addr_validate(&msg.dao)?;
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            dao: dao.clone(),
+            spend_limit: msg.spend_limit,
+            epoch: msg.epoch,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("dao", dao)
+        .add_attribute("epoch", msg.epoch.to_string()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Refresh { addresses } => execute_refresh(deps, env, info, addresses),
+        ExecuteMsg::Revoke { addresses } => execute_revoke(deps, env, info, addresses),
+        ExecuteMsg::UpdateConfig { spend_limit, epoch } => {
+            execute_update_config(deps, info, spend_limit, epoch)
+        }
+        ExecuteMsg::Withdraw { amount } => execute_withdraw(deps, info, amount),
+    }
+}
+
+pub fn execute_refresh(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    addresses: Vec<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let granter = env.contract.address.as_str();
+    // Only the DAO may issue an address its first allowance. Anyone
+    // else may only refresh existing grantees.
+    let is_dao = info.sender == config.dao;
+
+    let mut messages = vec![];
+    let mut granted = 0u64;
+    let mut revoked = 0u64;
+    for address in addresses {
+        let grantee = deps.api.addr_validate(&address)?;
+        let existing = GRANTS.may_load(deps.storage, &grantee)?;
+        if existing.is_none() && !is_dao {
+            return Err(ContractError::NotGrantee { address });
+        }
+        let VotingPowerAtHeightResponse { power, .. } = deps.querier.query_wasm_smart(
+            &config.dao,
+            &dao_interface::voting::Query::VotingPowerAtHeight {
+                address: grantee.to_string(),
+                height: None,
+            },
+        )?;
+
+        if power.is_zero() {
+            // No longer a member. Revoke any outstanding allowance.
+            if existing.is_some() {
+                messages.push(SubMsg::reply_on_error(
+                    revoke_allowance_msg(granter, grantee.as_str()),
+                    REVOKE_REPLY_ID,
+                ));
+                GRANTS.remove(deps.storage, &grantee);
+                revoked += 1;
+            }
+            continue;
+        }
+
+        match existing {
+            // Allowance for the current epoch is still live.
+            Some(expiration) if expiration > env.block.time => continue,
+            Some(_) => messages.push(SubMsg::reply_on_error(
+                revoke_allowance_msg(granter, grantee.as_str()),
+                REVOKE_REPLY_ID,
+            )),
+            None => (),
+        }
+
+        let expiration = match config.epoch {
+            Duration::Time(seconds) => env.block.time.plus_seconds(seconds),
+            Duration::Height(_) => return Err(ContractError::HeightEpoch {}),
+        };
+        messages.push(SubMsg::new(grant_allowance_msg(
+            granter,
+            grantee.as_str(),
+            &config.spend_limit,
+            expiration,
+        )));
+        GRANTS.save(deps.storage, &grantee, &expiration)?;
+        granted += 1;
+    }
+
+    Ok(Response::new()
+        .add_submessages(messages)
+        .add_attribute("action", "refresh")
+        .add_attribute("granted", granted.to_string())
+        .add_attribute("revoked", revoked.to_string()))
+}
+
+pub fn execute_revoke(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    addresses: Vec<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.dao {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut messages = vec![];
+    for address in addresses {
+        let grantee = deps.api.addr_validate(&address)?;
+        if GRANTS.has(deps.storage, &grantee) {
+            messages.push(SubMsg::reply_on_error(
+                revoke_allowance_msg(env.contract.address.as_str(), grantee.as_str()),
+                REVOKE_REPLY_ID,
+            ));
+            GRANTS.remove(deps.storage, &grantee);
+        }
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "revoke")
+        .add_attribute("revoked", messages.len().to_string())
+        .add_submessages(messages))
+}
+
+pub fn execute_update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    spend_limit: Vec<Coin>,
+    epoch: Duration,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.dao {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    validate_config(&spend_limit, epoch)?;
+    config.spend_limit = spend_limit;
+    config.epoch = epoch;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_config")
+        .add_attribute("epoch", epoch.to_string()))
+}
+
+pub fn execute_withdraw(
+    deps: DepsMut,
+    info: MessageInfo,
+    amount: Vec<Coin>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.dao {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "withdraw")
+        .add_message(BankMsg::Send {
+            to_address: config.dao.into_string(),
+            amount,
+        }))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::Grant { address } => query_grant(deps, address),
+        QueryMsg::ListGrants { start_after, limit } => query_list_grants(deps, start_after, limit),
+    }
+}
+
+pub fn query_grant(deps: Deps, address: String) -> StdResult<Binary> {
+    let grantee = deps.api.addr_validate(&address)?;
+    let grant = GRANTS
+        .may_load(deps.storage, &grantee)?
+        .map(|expiration| GrantResponse {
+            grantee: grantee.into_string(),
+            expiration,
+        });
+    to_binary(&grant)
+}
+
+pub fn query_list_grants(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let start_after = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    let grants: Vec<(Addr, _)> =
+        cw_paginate::paginate_map(deps, &GRANTS, start_after.as_ref(), limit, Order::Ascending)?;
+    to_binary(
+        &grants
+            .into_iter()
+            .map(|(grantee, expiration)| GrantResponse {
+                grantee: grantee.into_string(),
+                expiration,
+            })
+            .collect::<Vec<_>>(),
+    )
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(_deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        // Only dispatched as `reply_on_error`. Revoking an allowance
+        // that has already been pruned fails, which is fine.
+        REVOKE_REPLY_ID => Ok(Response::new().add_attribute("revoke_skipped", "true")),
+        id => Err(ContractError::UnknownReplyId { id }),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::default())
+}
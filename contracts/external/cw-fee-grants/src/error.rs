@@ -0,0 +1,26 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Fee grant epochs must be measured in time, not blocks")]
+    HeightEpoch {},
+
+    #[error("Fee grant epoch duration cannot be 0")]
+    ZeroEpoch {},
+
+    #[error("Spend limit must be non-empty and contain no zero amounts")]
+    InvalidSpendLimit {},
+
+    #[error("({address}) has no allowance. Only the DAO may issue first allowances")]
+    NotGrantee { address: String },
+
+    #[error("Got a reply with an unknown ID: ({id})")]
+    UnknownReplyId { id: u64 },
+}
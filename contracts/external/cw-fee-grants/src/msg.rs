@@ -0,0 +1,62 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Coin, Timestamp};
+use cw_utils::Duration;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The DAO whose members should receive fee grants.
+    pub dao: String,
+    /// The maximum amount of fees each member may spend per epoch.
+    pub spend_limit: Vec<Coin>,
+    /// How long an allowance lasts before it is refreshed. Must be a
+    /// time duration.
+    pub epoch: Duration,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Issues a new allowance to each address that has voting power
+    /// in the DAO and whose previous allowance (if any) has
+    /// expired. Revokes allowances from addresses that no longer have
+    /// voting power. Callable by anyone for addresses which hold an
+    /// allowance, and by the DAO for any address.
+    Refresh { addresses: Vec<String> },
+    /// Revokes the allowances of the provided addresses. Only
+    /// callable by the DAO.
+    Revoke { addresses: Vec<String> },
+    /// Updates the spend limit and epoch. Existing allowances are
+    /// unaffected until they are next refreshed. Only callable by the
+    /// DAO.
+    UpdateConfig {
+        spend_limit: Vec<Coin>,
+        epoch: Duration,
+    },
+    /// Sends funds held by this contract back to the DAO. Only
+    /// callable by the DAO.
+    Withdraw { amount: Vec<Coin> },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(crate::state::Config)]
+    Config {},
+    /// Returns the expiration of ADDRESS's allowance, if one has been
+    /// issued.
+    #[returns(Option<GrantResponse>)]
+    Grant { address: String },
+    #[returns(Vec<GrantResponse>)]
+    ListGrants {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct GrantResponse {
+    pub grantee: String,
+    pub expiration: Timestamp,
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
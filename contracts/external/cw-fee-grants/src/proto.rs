@@ -0,0 +1,91 @@
+//! Minimal protobuf definitions for the x/feegrant messages this
+//! contract sends. Field numbers match `cosmos.feegrant.v1beta1`.
+
+use cosmwasm_std::{Binary, Coin, CosmosMsg, Timestamp};
+use prost::Message;
+
+pub const MSG_GRANT_ALLOWANCE_TYPE_URL: &str = "/cosmos.feegrant.v1beta1.MsgGrantAllowance";
+pub const MSG_REVOKE_ALLOWANCE_TYPE_URL: &str = "/cosmos.feegrant.v1beta1.MsgRevokeAllowance";
+pub const BASIC_ALLOWANCE_TYPE_URL: &str = "/cosmos.feegrant.v1beta1.BasicAllowance";
+
+#[derive(Clone, PartialEq, Message)]
+pub struct ProtoCoin {
+    #[prost(string, tag = "1")]
+    pub denom: String,
+    #[prost(string, tag = "2")]
+    pub amount: String,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct BasicAllowance {
+    #[prost(message, repeated, tag = "1")]
+    pub spend_limit: Vec<ProtoCoin>,
+    #[prost(message, optional, tag = "2")]
+    pub expiration: Option<prost_types::Timestamp>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgGrantAllowance {
+    #[prost(string, tag = "1")]
+    pub granter: String,
+    #[prost(string, tag = "2")]
+    pub grantee: String,
+    #[prost(message, optional, tag = "3")]
+    pub allowance: Option<prost_types::Any>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgRevokeAllowance {
+    #[prost(string, tag = "1")]
+    pub granter: String,
+    #[prost(string, tag = "2")]
+    pub grantee: String,
+}
+
+/// Creates a message granting GRANTEE a basic allowance of
+/// SPEND_LIMIT from GRANTER that expires at EXPIRATION.
+pub fn grant_allowance_msg<T>(
+    granter: &str,
+    grantee: &str,
+    spend_limit: &[Coin],
+    expiration: Timestamp,
+) -> CosmosMsg<T> {
+    let allowance = BasicAllowance {
+        spend_limit: spend_limit
+            .iter()
+            .map(|c| ProtoCoin {
+                denom: c.denom.clone(),
+                amount: c.amount.to_string(),
+            })
+            .collect(),
+        expiration: Some(prost_types::Timestamp {
+            seconds: expiration.seconds() as i64,
+            nanos: expiration.subsec_nanos() as i32,
+        }),
+    };
+    let msg = MsgGrantAllowance {
+        granter: granter.to_string(),
+        grantee: grantee.to_string(),
+        allowance: Some(prost_types::Any {
+            type_url: BASIC_ALLOWANCE_TYPE_URL.to_string(),
+            value: allowance.encode_to_vec(),
+        }),
+    };
+    CosmosMsg::Stargate {
+        type_url: MSG_GRANT_ALLOWANCE_TYPE_URL.to_string(),
+        value: Binary(msg.encode_to_vec()),
+    }
+}
+
+/// Creates a message revoking the allowance GRANTER has issued to
+/// GRANTEE.
+pub fn revoke_allowance_msg<T>(granter: &str, grantee: &str) -> CosmosMsg<T> {
+    let msg = MsgRevokeAllowance {
+        granter: granter.to_string(),
+        grantee: grantee.to_string(),
+    };
+    CosmosMsg::Stargate {
+        type_url: MSG_REVOKE_ALLOWANCE_TYPE_URL.to_string(),
+        value: Binary(msg.encode_to_vec()),
+    }
+}
@@ -0,0 +1,23 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin, Timestamp};
+use cw_storage_plus::{Item, Map};
+use cw_utils::Duration;
+
+#[cw_serde]
+pub struct Config {
+    /// The DAO whose members receive allowances. Only the DAO may
+    /// update the config, revoke grants, or withdraw funds.
+    pub dao: Addr,
+    /// The maximum amount of fees each member may spend per epoch.
+    pub spend_limit: Vec<Coin>,
+    /// How long each allowance lasts before it must be refreshed.
+    /// Always a `Duration::Time` as x/feegrant expirations are
+    /// timestamps.
+    pub epoch: Duration,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+/// Members that currently hold an allowance from this contract
+/// mapped to the time at which that allowance expires.
+pub const GRANTS: Map<&Addr, Timestamp> = Map::new("grants");
@@ -0,0 +1,245 @@
+use cosmwasm_std::testing::{
+    mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+};
+use cosmwasm_std::{
+    coins, from_binary, from_slice, to_binary, ContractResult, CosmosMsg, Empty, OwnedDeps,
+    SystemResult, Uint128, WasmQuery,
+};
+use cw_utils::Duration;
+use dao_interface::voting::{Query as VotingQuery, VotingPowerAtHeightResponse};
+use prost::Message;
+
+use crate::contract::{execute, instantiate, query, REVOKE_REPLY_ID};
+use crate::msg::{ExecuteMsg, GrantResponse, InstantiateMsg, QueryMsg};
+use crate::proto::{
+    BasicAllowance, MsgGrantAllowance, MsgRevokeAllowance, MSG_GRANT_ALLOWANCE_TYPE_URL,
+    MSG_REVOKE_ALLOWANCE_TYPE_URL,
+};
+use crate::ContractError;
+
+const DAO: &str = "dao";
+const MEMBER: &str = "member";
+const NON_MEMBER: &str = "nonmember";
+const EPOCH: u64 = 60 * 60 * 24 * 7;
+
+fn setup() -> OwnedDeps<MockStorage, MockApi, MockQuerier, Empty> {
+    let mut deps = mock_dependencies();
+    deps.querier.update_wasm(|query| match query {
+        WasmQuery::Smart { contract_addr, msg } if contract_addr == DAO => {
+            let power = match from_slice(msg).unwrap() {
+                VotingQuery::VotingPowerAtHeight { address, .. } if address == MEMBER => {
+                    Uint128::new(10)
+                }
+                _ => Uint128::zero(),
+            };
+            SystemResult::Ok(ContractResult::Ok(
+                to_binary(&VotingPowerAtHeightResponse { power, height: 0 }).unwrap(),
+            ))
+        }
+        _ => panic!("unexpected query"),
+    });
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        InstantiateMsg {
+            dao: DAO.to_string(),
+            spend_limit: coins(1_000_000, "ujuno"),
+            epoch: Duration::Time(EPOCH),
+        },
+    )
+    .unwrap();
+    deps
+}
+
+#[test]
+fn test_instantiate_validation() {
+    let mut deps = mock_dependencies();
+    let err = instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        InstantiateMsg {
+            dao: DAO.to_string(),
+            spend_limit: coins(1, "ujuno"),
+            epoch: Duration::Height(10),
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::HeightEpoch {}));
+
+    let err = instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        InstantiateMsg {
+            dao: DAO.to_string(),
+            spend_limit: vec![],
+            epoch: Duration::Time(EPOCH),
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidSpendLimit {}));
+}
+
+#[test]
+fn test_refresh_grants_members_once_per_epoch() {
+    let mut deps = setup();
+    let env = mock_env();
+
+    // Only the DAO may issue first allowances.
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(NON_MEMBER, &[]),
+        ExecuteMsg::Refresh {
+            addresses: vec![MEMBER.to_string()],
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::NotGrantee { .. }));
+
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(DAO, &[]),
+        ExecuteMsg::Refresh {
+            addresses: vec![MEMBER.to_string(), NON_MEMBER.to_string()],
+        },
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 1);
+    match &res.messages[0].msg {
+        CosmosMsg::Stargate { type_url, value } => {
+            assert_eq!(type_url, MSG_GRANT_ALLOWANCE_TYPE_URL);
+            let msg = MsgGrantAllowance::decode(value.as_slice()).unwrap();
+            assert_eq!(msg.granter, env.contract.address.to_string());
+            assert_eq!(msg.grantee, MEMBER);
+            let allowance =
+                BasicAllowance::decode(msg.allowance.unwrap().value.as_slice()).unwrap();
+            assert_eq!(allowance.spend_limit[0].amount, "1000000");
+            assert_eq!(
+                allowance.expiration.unwrap().seconds as u64,
+                env.block.time.plus_seconds(EPOCH).seconds()
+            );
+        }
+        _ => panic!("expected a stargate message"),
+    }
+
+    let grant: Option<GrantResponse> = from_binary(
+        &query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::Grant {
+                address: MEMBER.to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        grant,
+        Some(GrantResponse {
+            grantee: MEMBER.to_string(),
+            expiration: env.block.time.plus_seconds(EPOCH),
+        })
+    );
+
+    // Refreshing within the same epoch does nothing.
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(NON_MEMBER, &[]),
+        ExecuteMsg::Refresh {
+            addresses: vec![MEMBER.to_string()],
+        },
+    )
+    .unwrap();
+    assert!(res.messages.is_empty());
+
+    // Once the epoch ends anyone may refresh the allowance, which is
+    // revoked and reissued.
+    let mut env = env;
+    env.block.time = env.block.time.plus_seconds(EPOCH);
+    let res = execute(
+        deps.as_mut(),
+        env,
+        mock_info(NON_MEMBER, &[]),
+        ExecuteMsg::Refresh {
+            addresses: vec![MEMBER.to_string()],
+        },
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 2);
+    assert_eq!(res.messages[0].id, REVOKE_REPLY_ID);
+    match &res.messages[0].msg {
+        CosmosMsg::Stargate { type_url, value } => {
+            assert_eq!(type_url, MSG_REVOKE_ALLOWANCE_TYPE_URL);
+            let msg = MsgRevokeAllowance::decode(value.as_slice()).unwrap();
+            assert_eq!(msg.grantee, MEMBER);
+        }
+        _ => panic!("expected a stargate message"),
+    }
+}
+
+#[test]
+fn test_revoke_and_update_config_auth() {
+    let mut deps = setup();
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        ExecuteMsg::Refresh {
+            addresses: vec![MEMBER.to_string()],
+        },
+    )
+    .unwrap();
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(MEMBER, &[]),
+        ExecuteMsg::Revoke {
+            addresses: vec![MEMBER.to_string()],
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(MEMBER, &[]),
+        ExecuteMsg::UpdateConfig {
+            spend_limit: coins(1, "ujuno"),
+            epoch: Duration::Time(1),
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        ExecuteMsg::Revoke {
+            addresses: vec![MEMBER.to_string(), NON_MEMBER.to_string()],
+        },
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 1);
+
+    let grants: Vec<GrantResponse> = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ListGrants {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert!(grants.is_empty());
+}
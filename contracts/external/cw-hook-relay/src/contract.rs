@@ -0,0 +1,302 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    from_binary, to_binary, Binary, Deps, DepsMut, Empty, Env, IbcMsg, IbcTimeout, MessageInfo,
+    Order, Reply, Response, StdResult, SubMsg, SubMsgResult, WasmMsg,
+};
+use cw2::set_contract_version;
+use cw_paginate::paginate_map_keys;
+use cw_storage_plus::Bound;
+use dao_proposal_hooks::ProposalHookExecuteMsg;
+use dao_vote_hooks::VoteHookExecuteMsg;
+
+use crate::error::ContractError;
+use crate::msg::{
+    Acknowledgement, ExecuteMsg, FailedPacketResponse, HookPacket, HookPayload, InstantiateMsg,
+    MigrateMsg, QueryMsg,
+};
+use crate::state::{Config, CHANNELS, CONFIG, CONSUMERS, FAILED_PACKETS, SOURCES};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:cw-hook-relay";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Reply ID used when delivering a relayed hook to the consumers. Only
+/// errors are replied to, and they become error acknowledgements.
+pub(crate) const DELIVER_REPLY_ID: u64 = 0;
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    let dao = deps.api.addr_validate(&msg.dao)?;
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            dao: dao.clone(),
+            packet_timeout: msg.packet_timeout,
+        },
+    )?;
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("dao", dao))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::ProposalHook(hook) => {
+            execute_relay(deps, env, info, HookPayload::Proposal(hook))
+        }
+        ExecuteMsg::VoteHook(hook) => execute_relay(deps, env, info, HookPayload::Vote(hook)),
+        ExecuteMsg::AddSource { addr } => execute_add_source(deps, info, addr),
+        ExecuteMsg::RemoveSource { addr } => execute_remove_source(deps, info, addr),
+        ExecuteMsg::AddConsumer { addr } => execute_add_consumer(deps, info, addr),
+        ExecuteMsg::RemoveConsumer { addr } => execute_remove_consumer(deps, info, addr),
+        ExecuteMsg::Retry { channel, sequence } => execute_retry(deps, env, channel, sequence),
+        ExecuteMsg::Deliver(hook) => execute_deliver(deps, env, info, hook),
+    }
+}
+
+fn assert_dao(deps: Deps, info: &MessageInfo) -> Result<(), ContractError> {
+    if CONFIG.load(deps.storage)?.dao != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(())
+}
+
+fn packet_timeout(deps: Deps, env: &Env) -> StdResult<IbcTimeout> {
+    let config = CONFIG.load(deps.storage)?;
+    Ok(IbcTimeout::with_timestamp(
+        env.block.time.plus_seconds(config.packet_timeout),
+    ))
+}
+
+pub fn execute_relay(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    hook: HookPayload,
+) -> Result<Response, ContractError> {
+    if !SOURCES.has(deps.storage, &info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+    let packet = to_binary(&HookPacket {
+        dao: CONFIG.load(deps.storage)?.dao,
+        source: info.sender.clone(),
+        hook,
+    })?;
+    let timeout = packet_timeout(deps.as_ref(), &env)?;
+    let msgs = CHANNELS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .map(|channel_id| {
+            Ok(IbcMsg::SendPacket {
+                channel_id: channel_id?,
+                data: packet.clone(),
+                timeout: timeout.clone(),
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(Response::new()
+        .add_attribute("action", "relay_hook")
+        .add_attribute("source", info.sender)
+        .add_attribute("channels", msgs.len().to_string())
+        .add_messages(msgs))
+}
+
+pub fn execute_add_source(
+    deps: DepsMut,
+    info: MessageInfo,
+    addr: String,
+) -> Result<Response, ContractError> {
+    assert_dao(deps.as_ref(), &info)?;
+    let addr = deps.api.addr_validate(&addr)?;
+    SOURCES.save(deps.storage, &addr, &Empty::default())?;
+    Ok(Response::new()
+        .add_attribute("action", "add_source")
+        .add_attribute("source", addr))
+}
+
+pub fn execute_remove_source(
+    deps: DepsMut,
+    info: MessageInfo,
+    addr: String,
+) -> Result<Response, ContractError> {
+    assert_dao(deps.as_ref(), &info)?;
+    let addr = deps.api.addr_validate(&addr)?;
+    SOURCES.remove(deps.storage, &addr);
+    Ok(Response::new()
+        .add_attribute("action", "remove_source")
+        .add_attribute("source", addr))
+}
+
+pub fn execute_add_consumer(
+    deps: DepsMut,
+    info: MessageInfo,
+    addr: String,
+) -> Result<Response, ContractError> {
+    assert_dao(deps.as_ref(), &info)?;
+    let addr = deps.api.addr_validate(&addr)?;
+    CONSUMERS.add_hook(deps.storage, addr.clone())?;
+    Ok(Response::new()
+        .add_attribute("action", "add_consumer")
+        .add_attribute("consumer", addr))
+}
+
+pub fn execute_remove_consumer(
+    deps: DepsMut,
+    info: MessageInfo,
+    addr: String,
+) -> Result<Response, ContractError> {
+    assert_dao(deps.as_ref(), &info)?;
+    let addr = deps.api.addr_validate(&addr)?;
+    CONSUMERS.remove_hook(deps.storage, addr.clone())?;
+    Ok(Response::new()
+        .add_attribute("action", "remove_consumer")
+        .add_attribute("consumer", addr))
+}
+
+pub fn execute_retry(
+    deps: DepsMut,
+    env: Env,
+    channel: String,
+    sequence: u64,
+) -> Result<Response, ContractError> {
+    let data = FAILED_PACKETS
+        .may_load(deps.storage, (channel.as_str(), sequence))?
+        .ok_or_else(|| ContractError::NoSuchFailedPacket {
+            channel: channel.clone(),
+            sequence,
+        })?;
+    if !CHANNELS.has(deps.storage, channel.clone()) {
+        return Err(ContractError::ChannelClosed { channel });
+    }
+    FAILED_PACKETS.remove(deps.storage, (channel.as_str(), sequence));
+
+    let timeout = packet_timeout(deps.as_ref(), &env)?;
+    Ok(Response::new()
+        .add_attribute("action", "retry")
+        .add_attribute("channel", &channel)
+        .add_attribute("sequence", sequence.to_string())
+        .add_message(IbcMsg::SendPacket {
+            channel_id: channel,
+            data,
+            timeout,
+        }))
+}
+
+pub fn execute_deliver(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    hook: HookPayload,
+) -> Result<Response, ContractError> {
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized {});
+    }
+    let msg = match &hook {
+        HookPayload::Proposal(hook) => {
+            to_binary(&ProposalHookExecuteMsg::ProposalHook(hook.clone()))?
+        }
+        HookPayload::Vote(hook) => to_binary(&VoteHookExecuteMsg::VoteHook(hook.clone()))?,
+    };
+    // Consumers are not replied to: any failure fails this message,
+    // reverting the delivery to every consumer.
+    let msgs = CONSUMERS.prepare_hooks(deps.storage, |consumer| {
+        Ok(SubMsg::new(WasmMsg::Execute {
+            contract_addr: consumer.into_string(),
+            msg: msg.clone(),
+            funds: vec![],
+        }))
+    })?;
+    Ok(Response::new()
+        .add_attribute("action", "deliver")
+        .add_attribute("consumers", msgs.len().to_string())
+        .add_submessages(msgs))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::Sources { start_after, limit } => {
+            let start_after = start_after
+                .map(|addr| deps.api.addr_validate(&addr))
+                .transpose()?;
+            to_binary(&paginate_map_keys(
+                deps,
+                &SOURCES,
+                start_after.as_ref(),
+                limit,
+                Order::Ascending,
+            )?)
+        }
+        QueryMsg::Consumers {} => to_binary(&CONSUMERS.query_hooks(deps)?),
+        QueryMsg::Channels {} => to_binary(
+            &CHANNELS
+                .keys(deps.storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<_>>>()?,
+        ),
+        QueryMsg::FailedPackets { start_after, limit } => {
+            to_binary(&query_failed_packets(deps, start_after, limit)?)
+        }
+    }
+}
+
+pub fn query_failed_packets(
+    deps: Deps,
+    start_after: Option<(String, u64)>,
+    limit: Option<u32>,
+) -> StdResult<Vec<FailedPacketResponse>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after
+        .as_ref()
+        .map(|(channel, sequence)| Bound::exclusive((channel.as_str(), *sequence)));
+    FAILED_PACKETS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let ((channel, sequence), data) = item?;
+            Ok(FailedPacketResponse {
+                channel,
+                sequence,
+                packet: from_binary(&data)?,
+            })
+        })
+        .collect()
+}
+
+/// Converts a failure to deliver a relayed hook into an error
+/// acknowledgement so the sending relay can record it for retry. The
+/// failed delivery has been reverted for every consumer, so a retry
+/// delivers the hook to each of them once.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(_deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        DELIVER_REPLY_ID => match msg.result {
+            SubMsgResult::Err(err) => Ok(Response::new()
+                .add_attribute("action", "deliver_failed")
+                .set_data(to_binary(&Acknowledgement::Error(err))?)),
+            SubMsgResult::Ok(_) => Ok(Response::new()),
+        },
+        id => Err(ContractError::UnknownReplyId { id }),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::default())
+}
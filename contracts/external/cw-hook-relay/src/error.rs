@@ -0,0 +1,27 @@
+use cosmwasm_std::StdError;
+use cw_hooks::HookError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    HookError(#[from] HookError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Hook relay channels must be unordered and use version ({expected}), got ({version})")]
+    InvalidChannel { expected: String, version: String },
+
+    #[error("No failed packet with sequence ({sequence}) on channel ({channel})")]
+    NoSuchFailedPacket { channel: String, sequence: u64 },
+
+    #[error("Channel ({channel}) is closed")]
+    ChannelClosed { channel: String },
+
+    #[error("Got a reply with an unknown ID: ({id})")]
+    UnknownReplyId { id: u64 },
+}
@@ -0,0 +1,154 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    from_binary, to_binary, DepsMut, Empty, Env, IbcBasicResponse, IbcChannelCloseMsg,
+    IbcChannelConnectMsg, IbcChannelOpenMsg, IbcChannelOpenResponse, IbcOrder, IbcPacketAckMsg,
+    IbcPacketReceiveMsg, IbcPacketTimeoutMsg, IbcReceiveResponse, SubMsg, WasmMsg,
+};
+use dao_proposal_hooks::ProposalHookMsg;
+use dao_vote_hooks::VoteHookMsg;
+
+use crate::contract::DELIVER_REPLY_ID;
+use crate::error::ContractError;
+use crate::msg::{Acknowledgement, ExecuteMsg, HookPacket, HookPayload};
+use crate::state::{CHANNELS, FAILED_PACKETS};
+
+pub const RELAY_VERSION: &str = "dao-hook-relay-1";
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_open(
+    _deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelOpenMsg,
+) -> Result<IbcChannelOpenResponse, ContractError> {
+    let channel = msg.channel();
+    let version = msg
+        .counterparty_version()
+        .unwrap_or(channel.version.as_str());
+    if channel.order != IbcOrder::Unordered || version != RELAY_VERSION {
+        return Err(ContractError::InvalidChannel {
+            expected: RELAY_VERSION.to_string(),
+            version: version.to_string(),
+        });
+    }
+    Ok(None)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_connect(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelConnectMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let channel = msg.channel().endpoint.channel_id.clone();
+    CHANNELS.save(deps.storage, channel.clone(), &Empty::default())?;
+    Ok(IbcBasicResponse::new()
+        .add_attribute("action", "channel_connect")
+        .add_attribute("channel", channel))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_close(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelCloseMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let channel = msg.channel().endpoint.channel_id.clone();
+    CHANNELS.remove(deps.storage, channel.clone());
+    Ok(IbcBasicResponse::new()
+        .add_attribute("action", "channel_close")
+        .add_attribute("channel", channel))
+}
+
+/// Delivers a hook relayed from the counterparty to every consumer.
+/// All consumers are called from one submessage, so if any of them
+/// fails the delivery is reverted for all of them and the packet is
+/// acknowledged with an error (see `reply`).
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_receive(
+    _deps: DepsMut,
+    env: Env,
+    msg: IbcPacketReceiveMsg,
+) -> Result<IbcReceiveResponse, ContractError> {
+    let packet: HookPacket = match from_binary(&msg.packet.data) {
+        Ok(packet) => packet,
+        Err(err) => {
+            return Ok(IbcReceiveResponse::new()
+                .add_attribute("action", "receive_hook")
+                .add_attribute("error", err.to_string())
+                .set_ack(to_binary(&Acknowledgement::Error(err.to_string()))?))
+        }
+    };
+
+    let kind = hook_kind(&packet.hook);
+    let deliver = SubMsg::reply_on_error(
+        WasmMsg::Execute {
+            contract_addr: env.contract.address.into_string(),
+            msg: to_binary(&ExecuteMsg::Deliver(packet.hook))?,
+            funds: vec![],
+        },
+        DELIVER_REPLY_ID,
+    );
+
+    Ok(IbcReceiveResponse::new()
+        .add_attribute("action", "receive_hook")
+        .add_attribute("dao", packet.dao)
+        .add_attribute("source", packet.source)
+        .add_attribute("hook", kind)
+        .add_submessage(deliver)
+        .set_ack(to_binary(&Acknowledgement::Result(to_binary(&true)?))?))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_ack(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketAckMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let packet = msg.original_packet;
+    let response = IbcBasicResponse::new()
+        .add_attribute("action", "hook_ack")
+        .add_attribute("channel", &packet.src.channel_id)
+        .add_attribute("sequence", packet.sequence.to_string());
+    match from_binary(&msg.acknowledgement.data)? {
+        Acknowledgement::Result(_) => Ok(response),
+        Acknowledgement::Error(err) => {
+            FAILED_PACKETS.save(
+                deps.storage,
+                (packet.src.channel_id.as_str(), packet.sequence),
+                &packet.data,
+            )?;
+            Ok(response.add_attribute("error", err))
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_timeout(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketTimeoutMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let packet = msg.packet;
+    FAILED_PACKETS.save(
+        deps.storage,
+        (packet.src.channel_id.as_str(), packet.sequence),
+        &packet.data,
+    )?;
+    Ok(IbcBasicResponse::new()
+        .add_attribute("action", "hook_timeout")
+        .add_attribute("channel", packet.src.channel_id)
+        .add_attribute("sequence", packet.sequence.to_string()))
+}
+
+fn hook_kind(hook: &HookPayload) -> &'static str {
+    match hook {
+        HookPayload::Proposal(ProposalHookMsg::NewProposal { .. }) => "new_proposal",
+        HookPayload::Proposal(ProposalHookMsg::ProposalStatusChanged { .. }) => {
+            "proposal_status_changed"
+        }
+        HookPayload::Proposal(ProposalHookMsg::ProposalUpdated { .. }) => "proposal_updated",
+        HookPayload::Vote(VoteHookMsg::NewVote { .. }) => "new_vote",
+        HookPayload::Vote(VoteHookMsg::NewVotes { .. }) => "new_votes",
+    }
+}
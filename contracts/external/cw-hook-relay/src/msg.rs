@@ -0,0 +1,93 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Binary};
+use dao_proposal_hooks::ProposalHookMsg;
+use dao_vote_hooks::VoteHookMsg;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub dao: String,
+    /// How long, in seconds, a relayed hook may wait to be delivered
+    /// before timing out.
+    pub packet_timeout: u64,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Forwards a proposal hook to the counterparty. Only callable by
+    /// sources.
+    ProposalHook(ProposalHookMsg),
+    /// Forwards a vote hook to the counterparty. Only callable by
+    /// sources.
+    VoteHook(VoteHookMsg),
+    /// Adds a contract whose hooks will be forwarded. Only callable
+    /// by the DAO.
+    AddSource { addr: String },
+    /// Only callable by the DAO.
+    RemoveSource { addr: String },
+    /// Adds a contract that will receive hooks relayed from the
+    /// counterparty. Only callable by the DAO.
+    AddConsumer { addr: String },
+    /// Only callable by the DAO.
+    RemoveConsumer { addr: String },
+    /// Resends a packet that failed or timed out. Callable by anyone.
+    Retry { channel: String, sequence: u64 },
+    /// Delivers a relayed hook to every consumer. Only callable by the
+    /// relay itself while receiving a packet, so that all consumers
+    /// are called in one submessage and a failure reverts every
+    /// delivery.
+    Deliver(HookPayload),
+}
+
+/// The hook payload sent over IBC.
+#[cw_serde]
+pub enum HookPayload {
+    Proposal(ProposalHookMsg),
+    Vote(VoteHookMsg),
+}
+
+#[cw_serde]
+pub struct HookPacket {
+    /// The DAO the hook originated from.
+    pub dao: Addr,
+    /// The contract that fired the hook.
+    pub source: Addr,
+    pub hook: HookPayload,
+}
+
+/// The standard ICS-4 acknowledgement envelope.
+#[cw_serde]
+pub enum Acknowledgement {
+    Result(Binary),
+    Error(String),
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(crate::state::Config)]
+    Config {},
+    #[returns(Vec<Addr>)]
+    Sources {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(::cw_hooks::HooksResponse)]
+    Consumers {},
+    #[returns(Vec<String>)]
+    Channels {},
+    #[returns(Vec<FailedPacketResponse>)]
+    FailedPackets {
+        start_after: Option<(String, u64)>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct FailedPacketResponse {
+    pub channel: String,
+    pub sequence: u64,
+    pub packet: HookPacket,
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
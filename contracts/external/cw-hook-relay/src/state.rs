@@ -0,0 +1,30 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Binary, Empty};
+use cw_hooks::Hooks;
+use cw_storage_plus::{Item, Map};
+
+#[cw_serde]
+pub struct Config {
+    /// The DAO that manages this relay.
+    pub dao: Addr,
+    /// How long, in seconds, a relayed hook may wait to be delivered
+    /// before timing out.
+    pub packet_timeout: u64,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+/// Contracts (usually proposal modules) whose hooks are forwarded
+/// over IBC.
+pub const SOURCES: Map<&Addr, Empty> = Map::new("sources");
+
+/// Local contracts that receive hooks relayed from the counterparty.
+pub const CONSUMERS: Hooks = Hooks::new("consumers");
+
+/// Open channels to counterparty relays. Hooks are forwarded over
+/// every open channel.
+pub const CHANNELS: Map<String, Empty> = Map::new("channels");
+
+/// Packets that failed to be delivered, keyed by `(channel, sequence)`,
+/// that may be resent with `Retry`.
+pub const FAILED_PACKETS: Map<(&str, u64), Binary> = Map::new("failed_packets");
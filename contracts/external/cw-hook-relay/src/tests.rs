@@ -0,0 +1,393 @@
+use cosmwasm_std::testing::{
+    mock_dependencies, mock_env, mock_ibc_channel_connect_ack, mock_ibc_channel_open_try,
+    mock_ibc_packet_ack, mock_ibc_packet_recv, mock_ibc_packet_timeout, mock_info, MockApi,
+    MockQuerier, MockStorage,
+};
+use cosmwasm_std::{
+    from_binary, to_binary, Addr, CosmosMsg, Empty, IbcAcknowledgement, IbcMsg, IbcOrder,
+    OwnedDeps, Reply, SubMsg, SubMsgResult, Uint128, WasmMsg,
+};
+use dao_proposal_hooks::{ProposalHookExecuteMsg, ProposalHookMsg};
+use dao_vote_hooks::{VoteHookMsg, VoteTally};
+use dao_voting::voting::Votes;
+
+use crate::contract::{execute, instantiate, query, reply, DELIVER_REPLY_ID};
+use crate::ibc::{
+    ibc_channel_connect, ibc_channel_open, ibc_packet_ack, ibc_packet_receive, ibc_packet_timeout,
+    RELAY_VERSION,
+};
+use crate::msg::{
+    Acknowledgement, ExecuteMsg, FailedPacketResponse, HookPacket, HookPayload, InstantiateMsg,
+    QueryMsg,
+};
+use crate::ContractError;
+
+const DAO: &str = "dao";
+const PROPOSAL_MODULE: &str = "proposal_module";
+const CONSUMER: &str = "consumer";
+const CHANNEL: &str = "channel-0";
+
+fn setup() -> OwnedDeps<MockStorage, MockApi, MockQuerier, Empty> {
+    let mut deps = mock_dependencies();
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        InstantiateMsg {
+            dao: DAO.to_string(),
+            packet_timeout: 600,
+        },
+    )
+    .unwrap();
+    ibc_channel_connect(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_connect_ack(CHANNEL, IbcOrder::Unordered, RELAY_VERSION),
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        ExecuteMsg::AddSource {
+            addr: PROPOSAL_MODULE.to_string(),
+        },
+    )
+    .unwrap();
+    deps
+}
+
+fn new_proposal_packet() -> HookPacket {
+    HookPacket {
+        dao: Addr::unchecked(DAO),
+        source: Addr::unchecked(PROPOSAL_MODULE),
+        hook: HookPayload::Proposal(ProposalHookMsg::NewProposal {
+            id: 1,
+            proposer: "ekez".to_string(),
+            content: None,
+        }),
+    }
+}
+
+#[test]
+fn test_channel_open_version() {
+    let mut deps = mock_dependencies();
+    let err = ibc_channel_open(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_open_try(CHANNEL, IbcOrder::Unordered, "ics20-1"),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidChannel {
+            expected: RELAY_VERSION.to_string(),
+            version: "ics20-1".to_string(),
+        }
+    );
+    ibc_channel_open(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_open_try(CHANNEL, IbcOrder::Ordered, RELAY_VERSION),
+    )
+    .unwrap_err();
+    ibc_channel_open(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_open_try(CHANNEL, IbcOrder::Unordered, RELAY_VERSION),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_relay_hooks() {
+    let mut deps = setup();
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("rando", &[]),
+        ExecuteMsg::VoteHook(VoteHookMsg::NewVote {
+            proposal_id: 1,
+            voter: "ekez".to_string(),
+            vote: "yes".to_string(),
+            power: Uint128::new(10),
+            height: 1,
+            tally: VoteTally::SingleChoice(Votes::with_yes(Uint128::new(10))),
+        }),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(PROPOSAL_MODULE, &[]),
+        ExecuteMsg::ProposalHook(ProposalHookMsg::NewProposal {
+            id: 1,
+            proposer: "ekez".to_string(),
+            content: None,
+        }),
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 1);
+    match &res.messages[0].msg {
+        CosmosMsg::Ibc(IbcMsg::SendPacket {
+            channel_id, data, ..
+        }) => {
+            assert_eq!(channel_id, CHANNEL);
+            let packet: HookPacket = from_binary(data).unwrap();
+            assert_eq!(packet, new_proposal_packet());
+        }
+        msg => panic!("unexpected message: {msg:?}"),
+    }
+}
+
+#[test]
+fn test_only_dao_manages_sources_and_consumers() {
+    let mut deps = setup();
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("rando", &[]),
+        ExecuteMsg::AddConsumer {
+            addr: CONSUMER.to_string(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        ExecuteMsg::RemoveSource {
+            addr: PROPOSAL_MODULE.to_string(),
+        },
+    )
+    .unwrap();
+    let sources: Vec<Addr> = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Sources {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert!(sources.is_empty());
+}
+
+#[test]
+fn test_receive_forwards_to_consumers() {
+    let mut deps = setup();
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        ExecuteMsg::AddConsumer {
+            addr: CONSUMER.to_string(),
+        },
+    )
+    .unwrap();
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        ExecuteMsg::AddConsumer {
+            addr: "other_consumer".to_string(),
+        },
+    )
+    .unwrap();
+
+    // Every consumer is delivered to from one submessage.
+    let packet = new_proposal_packet();
+    let res = ibc_packet_receive(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_packet_recv(CHANNEL, &packet).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::reply_on_error(
+            WasmMsg::Execute {
+                contract_addr: mock_env().contract.address.into_string(),
+                msg: to_binary(&ExecuteMsg::Deliver(packet.hook.clone())).unwrap(),
+                funds: vec![],
+            },
+            DELIVER_REPLY_ID
+        )]
+    );
+    let ack: Acknowledgement = from_binary(&res.acknowledgement).unwrap();
+    assert!(matches!(ack, Acknowledgement::Result(_)));
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(CONSUMER, &[]),
+        ExecuteMsg::Deliver(packet.hook.clone()),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(mock_env().contract.address.as_str(), &[]),
+        ExecuteMsg::Deliver(packet.hook),
+    )
+    .unwrap();
+    let hook = to_binary(&ProposalHookExecuteMsg::ProposalHook(
+        ProposalHookMsg::NewProposal {
+            id: 1,
+            proposer: "ekez".to_string(),
+            content: None,
+        },
+    ))
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        [CONSUMER, "other_consumer"]
+            .into_iter()
+            .map(|consumer| SubMsg::new(WasmMsg::Execute {
+                contract_addr: consumer.to_string(),
+                msg: hook.clone(),
+                funds: vec![],
+            }))
+            .collect::<Vec<_>>()
+    );
+
+    // A consumer failing turns into an error acknowledgement.
+    let res = reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: DELIVER_REPLY_ID,
+            result: SubMsgResult::Err("consumer failed".to_string()),
+        },
+    )
+    .unwrap();
+    let ack: Acknowledgement = from_binary(&res.data.unwrap()).unwrap();
+    assert_eq!(ack, Acknowledgement::Error("consumer failed".to_string()));
+
+    // Garbage packets are acknowledged with an error.
+    let res = ibc_packet_receive(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_packet_recv(CHANNEL, &"garbage").unwrap(),
+    )
+    .unwrap();
+    assert!(res.messages.is_empty());
+    let ack: Acknowledgement = from_binary(&res.acknowledgement).unwrap();
+    assert!(matches!(ack, Acknowledgement::Error(_)));
+}
+
+#[test]
+fn test_failed_packets_retry() {
+    let mut deps = setup();
+    let packet = new_proposal_packet();
+
+    ibc_packet_ack(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_packet_ack(
+            CHANNEL,
+            &packet,
+            IbcAcknowledgement::new(
+                to_binary(&Acknowledgement::Result(to_binary(&true).unwrap())).unwrap(),
+            ),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    ibc_packet_ack(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_packet_ack(
+            CHANNEL,
+            &packet,
+            IbcAcknowledgement::new(
+                to_binary(&Acknowledgement::Error("consumer failed".to_string())).unwrap(),
+            ),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    ibc_packet_timeout(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_packet_timeout("channel-1", &packet).unwrap(),
+    )
+    .unwrap();
+
+    let failed: Vec<FailedPacketResponse> = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::FailedPackets {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(failed.len(), 2);
+    assert_eq!(failed[0].channel, CHANNEL);
+    assert_eq!(failed[0].packet, packet);
+    assert_eq!(failed[1].channel, "channel-1");
+    let sequence = failed[0].sequence;
+
+    // channel-1 was never connected.
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("rando", &[]),
+        ExecuteMsg::Retry {
+            channel: "channel-1".to_string(),
+            sequence,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ChannelClosed {
+            channel: "channel-1".to_string()
+        }
+    );
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("rando", &[]),
+        ExecuteMsg::Retry {
+            channel: CHANNEL.to_string(),
+            sequence,
+        },
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 1);
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("rando", &[]),
+        ExecuteMsg::Retry {
+            channel: CHANNEL.to_string(),
+            sequence,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::NoSuchFailedPacket {
+            channel: CHANNEL.to_string(),
+            sequence,
+        }
+    );
+}
@@ -0,0 +1,193 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_binary, BankMsg, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Order, Response, StdResult,
+    WasmMsg,
+};
+use cw2::set_contract_version;
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, UncheckedHookAction};
+use crate::state::{HookAction, RegisteredAction, ACTIONS, DAO, RECEIVED_DENOMS};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:cw-ibc-hooks-handler";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let dao = deps.api.addr_validate(&msg.dao)?;
+    DAO.save(deps.storage, &dao)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("dao", dao))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Trigger { action } => execute_trigger(deps, info, action),
+        ExecuteMsg::RegisterAction {
+            name,
+            action,
+            allowed_senders,
+        } => execute_register_action(deps, info, name, action, allowed_senders),
+        ExecuteMsg::RemoveAction { name } => execute_remove_action(deps, info, name),
+    }
+}
+
+pub fn execute_trigger(
+    deps: DepsMut,
+    info: MessageInfo,
+    name: String,
+) -> Result<Response, ContractError> {
+    let registered = ACTIONS
+        .may_load(deps.storage, name.clone())?
+        .ok_or_else(|| ContractError::NoSuchAction { name: name.clone() })?;
+    if let Some(ref allowed) = registered.allowed_senders {
+        if !allowed.contains(&info.sender) {
+            return Err(ContractError::SenderNotAllowed {
+                name,
+                sender: info.sender.into_string(),
+            });
+        }
+    }
+
+    let dao = DAO.load(deps.storage)?;
+    let mut response = Response::new()
+        .add_attribute("action", "trigger")
+        .add_attribute("name", name)
+        .add_attribute("sender", info.sender);
+    if !info.funds.is_empty() {
+        response = response.add_message(BankMsg::Send {
+            to_address: dao.to_string(),
+            amount: info.funds.clone(),
+        });
+    }
+
+    match registered.action {
+        HookAction::ForwardFunds {} => (),
+        HookAction::RegisterDenom {} => {
+            for coin in info.funds {
+                RECEIVED_DENOMS.save(deps.storage, coin.denom.clone(), &Empty::default())?;
+                response = response.add_message(WasmMsg::Execute {
+                    contract_addr: dao.to_string(),
+                    msg: to_binary(&dao_core::msg::ExecuteMsg::RegisterDenom {
+                        denom: coin.denom,
+                    })?,
+                    funds: vec![],
+                });
+            }
+        }
+        HookAction::Notify { contract, msg } => {
+            response = response.add_message(WasmMsg::Execute {
+                contract_addr: contract.into_string(),
+                msg,
+                funds: vec![],
+            })
+        }
+    }
+
+    Ok(response)
+}
+
+pub fn execute_register_action(
+    deps: DepsMut,
+    info: MessageInfo,
+    name: String,
+    action: UncheckedHookAction,
+    allowed_senders: Option<Vec<String>>,
+) -> Result<Response, ContractError> {
+    if info.sender != DAO.load(deps.storage)? {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let action = match action {
+        UncheckedHookAction::ForwardFunds {} => HookAction::ForwardFunds {},
+        UncheckedHookAction::RegisterDenom {} => HookAction::RegisterDenom {},
+        UncheckedHookAction::Notify { contract, msg } => HookAction::Notify {
+            contract: deps.api.addr_validate(&contract)?,
+            msg,
+        },
+    };
+    let allowed_senders = allowed_senders
+        .map(|senders| {
+            senders
+                .into_iter()
+                .map(|s| deps.api.addr_validate(&s))
+                .collect::<StdResult<Vec<_>>>()
+        })
+        .transpose()?;
+    ACTIONS.save(
+        deps.storage,
+        name.clone(),
+        &RegisteredAction {
+            action,
+            allowed_senders,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "register_action")
+        .add_attribute("name", name))
+}
+
+pub fn execute_remove_action(
+    deps: DepsMut,
+    info: MessageInfo,
+    name: String,
+) -> Result<Response, ContractError> {
+    if info.sender != DAO.load(deps.storage)? {
+        return Err(ContractError::Unauthorized {});
+    }
+    if !ACTIONS.has(deps.storage, name.clone()) {
+        return Err(ContractError::NoSuchAction { name });
+    }
+    ACTIONS.remove(deps.storage, name.clone());
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_action")
+        .add_attribute("name", name))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Dao {} => to_binary(&DAO.load(deps.storage)?),
+        QueryMsg::Action { name } => to_binary(&ACTIONS.may_load(deps.storage, name)?),
+        QueryMsg::ListActions { start_after, limit } => to_binary(&cw_paginate::paginate_map(
+            deps,
+            &ACTIONS,
+            start_after,
+            limit,
+            Order::Ascending,
+        )?),
+        QueryMsg::ReceivedDenoms { start_after, limit } => {
+            to_binary(&cw_paginate::paginate_map_keys(
+                deps,
+                &RECEIVED_DENOMS,
+                start_after,
+                limit,
+                Order::Ascending,
+            )?)
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::default())
+}
@@ -0,0 +1,17 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("No action registered with name ({name})")]
+    NoSuchAction { name: String },
+
+    #[error("Sender ({sender}) may not trigger action ({name})")]
+    SenderNotAllowed { name: String, sender: String },
+}
@@ -0,0 +1,57 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Binary;
+
+use crate::state::RegisteredAction;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub dao: String,
+}
+
+#[cw_serde]
+pub enum UncheckedHookAction {
+    ForwardFunds {},
+    RegisterDenom {},
+    Notify { contract: String, msg: Binary },
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Executed by the ibc-hooks middleware when a transfer arrives
+    /// with a memo of the form `{"wasm": {"contract": "<this
+    /// contract>", "msg": {"trigger": {"action": "<name>"}}}}`.
+    /// Funds sent with the transfer are attached.
+    Trigger { action: String },
+    /// Registers, or replaces, the action with NAME. Only callable by
+    /// the DAO.
+    RegisterAction {
+        name: String,
+        action: UncheckedHookAction,
+        allowed_senders: Option<Vec<String>>,
+    },
+    /// Removes the action with NAME. Only callable by the DAO.
+    RemoveAction { name: String },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(cosmwasm_std::Addr)]
+    Dao {},
+    #[returns(Option<RegisteredAction>)]
+    Action { name: String },
+    #[returns(Vec<(String, RegisteredAction)>)]
+    ListActions {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Lists denoms received by `RegisterDenom` actions.
+    #[returns(Vec<String>)]
+    ReceivedDenoms {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
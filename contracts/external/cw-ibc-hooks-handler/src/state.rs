@@ -0,0 +1,31 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Binary, Empty};
+use cw_storage_plus::{Item, Map};
+
+#[cw_serde]
+pub enum HookAction {
+    /// Forwards received funds to the DAO.
+    ForwardFunds {},
+    /// Forwards received funds to the DAO, records their denoms in
+    /// the `ReceivedDenoms` list, and registers them with the DAO's
+    /// native denom list. This contract must have been granted the
+    /// DAO's `UpdateNativeDenomList` permission.
+    RegisterDenom {},
+    /// Forwards received funds to the DAO and executes CONTRACT with
+    /// MSG, for example to notify a subDAO of the transfer.
+    Notify { contract: Addr, msg: Binary },
+}
+
+#[cw_serde]
+pub struct RegisteredAction {
+    pub action: HookAction,
+    /// Addresses allowed to trigger this action. For transfers
+    /// executed by ibc-hooks this is the intermediate sender derived
+    /// from the source channel and the remote sender. If `None`,
+    /// anyone may trigger the action.
+    pub allowed_senders: Option<Vec<Addr>>,
+}
+
+pub const DAO: Item<Addr> = Item::new("dao");
+pub const ACTIONS: Map<String, RegisteredAction> = Map::new("actions");
+pub const RECEIVED_DENOMS: Map<String, Empty> = Map::new("received_denoms");
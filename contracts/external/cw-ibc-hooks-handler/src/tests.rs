@@ -0,0 +1,179 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use cosmwasm_std::{coins, from_binary, to_binary, BankMsg, CosmosMsg, Empty, WasmMsg};
+
+use crate::contract::{execute, instantiate, query};
+use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, UncheckedHookAction};
+use crate::ContractError;
+
+const DAO: &str = "dao";
+const REMOTE: &str = "ibc-hooks-sender";
+
+fn register(
+    deps: cosmwasm_std::DepsMut,
+    name: &str,
+    action: UncheckedHookAction,
+    allowed_senders: Option<Vec<String>>,
+) -> Result<cosmwasm_std::Response, ContractError> {
+    execute(
+        deps,
+        mock_env(),
+        mock_info(DAO, &[]),
+        ExecuteMsg::RegisterAction {
+            name: name.to_string(),
+            action,
+            allowed_senders,
+        },
+    )
+}
+
+#[test]
+fn test_register_denom_action() {
+    let mut deps = mock_dependencies();
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        InstantiateMsg {
+            dao: DAO.to_string(),
+        },
+    )
+    .unwrap();
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(REMOTE, &[]),
+        ExecuteMsg::RegisterAction {
+            name: "register".to_string(),
+            action: UncheckedHookAction::RegisterDenom {},
+            allowed_senders: None,
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    register(
+        deps.as_mut(),
+        "register",
+        UncheckedHookAction::RegisterDenom {},
+        Some(vec![REMOTE.to_string()]),
+    )
+    .unwrap();
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("someone", &coins(10, "ibc/abc")),
+        ExecuteMsg::Trigger {
+            action: "register".to_string(),
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::SenderNotAllowed { .. }));
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(REMOTE, &coins(10, "ibc/abc")),
+        ExecuteMsg::Trigger {
+            action: "register".to_string(),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: DAO.to_string(),
+            amount: coins(10, "ibc/abc"),
+        })
+    );
+    assert_eq!(
+        res.messages[1].msg,
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: DAO.to_string(),
+            msg: to_binary(&dao_core::msg::ExecuteMsg::RegisterDenom {
+                denom: "ibc/abc".to_string(),
+            })
+            .unwrap(),
+            funds: vec![],
+        })
+    );
+
+    let denoms: Vec<String> = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ReceivedDenoms {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(denoms, vec!["ibc/abc".to_string()]);
+}
+
+#[test]
+fn test_notify_action() {
+    let mut deps = mock_dependencies();
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        InstantiateMsg {
+            dao: DAO.to_string(),
+        },
+    )
+    .unwrap();
+
+    let msg = to_binary(&Empty {}).unwrap();
+    register(
+        deps.as_mut(),
+        "notify",
+        UncheckedHookAction::Notify {
+            contract: "subdao".to_string(),
+            msg: msg.clone(),
+        },
+        None,
+    )
+    .unwrap();
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(REMOTE, &[]),
+        ExecuteMsg::Trigger {
+            action: "notify".to_string(),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "subdao".to_string(),
+            msg,
+            funds: vec![],
+        })
+    );
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        ExecuteMsg::RemoveAction {
+            name: "notify".to_string(),
+        },
+    )
+    .unwrap();
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(REMOTE, &[]),
+        ExecuteMsg::Trigger {
+            action: "notify".to_string(),
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::NoSuchAction { .. }));
+}
@@ -0,0 +1,419 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_binary, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Reply, Response, StdResult,
+    SubMsg, Uint128, WasmMsg,
+};
+use cw2::set_contract_version;
+use cw3::{Cw3QueryMsg, ProposalListResponse, Status, ThresholdResponse, VoterListResponse};
+use cw_utils::{parse_reply_instantiate_data, Duration};
+use dao_interface::{Admin, ModuleInstantiateInfo};
+use dao_voting::{
+    pre_propose::PreProposeInfo,
+    proposal::SingleChoiceProposeMsg,
+    threshold::{PercentageThreshold, Threshold},
+};
+
+use crate::error::ContractError;
+use crate::msg::{CodeIds, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::state::{
+    ImportedProposal, PendingImport, PendingRecreation, PENDING_IMPORT, PENDING_RECREATION,
+};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:cw-multisig-importer";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub const INSTANTIATE_DAO_REPLY_ID: u64 = 0;
+
+/// Page size used when listing the multisig's voters and proposals.
+const PAGE_SIZE: u32 = 30;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    _msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("creator", info.sender))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Import {
+            name,
+            description,
+            image_url,
+            code_ids,
+            max_voting_period,
+            pre_propose_info,
+            recreate_open_proposals,
+        } => execute_import(
+            deps,
+            env,
+            info,
+            name,
+            description,
+            image_url,
+            code_ids,
+            max_voting_period,
+            pre_propose_info,
+            recreate_open_proposals,
+        ),
+        ExecuteMsg::RecreateProposals {} => execute_recreate_proposals(deps, env),
+    }
+}
+
+/// Converts a cw3 threshold into its dao-voting equivalent.
+pub fn convert_threshold(threshold: ThresholdResponse) -> Threshold {
+    match threshold {
+        ThresholdResponse::AbsoluteCount { weight, .. } => Threshold::AbsoluteCount {
+            threshold: Uint128::from(weight),
+        },
+        ThresholdResponse::AbsolutePercentage { percentage, .. } => Threshold::AbsolutePercentage {
+            percentage: PercentageThreshold::Percent(percentage),
+        },
+        ThresholdResponse::ThresholdQuorum {
+            threshold, quorum, ..
+        } => Threshold::ThresholdQuorum {
+            threshold: PercentageThreshold::Percent(threshold),
+            quorum: PercentageThreshold::Percent(quorum),
+        },
+    }
+}
+
+fn query_voters(deps: Deps, multisig: &Addr) -> StdResult<Vec<cw4::Member>> {
+    let mut members = vec![];
+    let mut start_after = None;
+    loop {
+        let page: VoterListResponse = deps.querier.query_wasm_smart(
+            multisig,
+            &Cw3QueryMsg::ListVoters {
+                start_after,
+                limit: Some(PAGE_SIZE),
+            },
+        )?;
+        let done = page.voters.len() < PAGE_SIZE as usize;
+        start_after = page.voters.last().map(|voter| voter.addr.clone());
+        members.extend(page.voters.into_iter().map(|voter| cw4::Member {
+            addr: voter.addr,
+            weight: voter.weight,
+        }));
+        if done {
+            return Ok(members);
+        }
+    }
+}
+
+fn query_open_proposals(deps: Deps, multisig: &Addr) -> StdResult<Vec<ImportedProposal>> {
+    let mut proposals = vec![];
+    let mut start_after = None;
+    loop {
+        let page: ProposalListResponse = deps.querier.query_wasm_smart(
+            multisig,
+            &Cw3QueryMsg::ListProposals {
+                start_after,
+                limit: Some(PAGE_SIZE),
+            },
+        )?;
+        let done = page.proposals.len() < PAGE_SIZE as usize;
+        start_after = page.proposals.last().map(|proposal| proposal.id);
+        proposals.extend(
+            page.proposals
+                .into_iter()
+                .filter(|proposal| proposal.status == Status::Open)
+                .map(|proposal| ImportedProposal {
+                    id: proposal.id,
+                    title: proposal.title,
+                    description: proposal.description,
+                    msgs: proposal.msgs,
+                }),
+        );
+        if done {
+            return Ok(proposals);
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn execute_import(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name: String,
+    description: String,
+    image_url: Option<String>,
+    code_ids: CodeIds,
+    max_voting_period: Duration,
+    pre_propose_info: Option<PreProposeInfo>,
+    recreate_open_proposals: bool,
+) -> Result<Response, ContractError> {
+    if PENDING_IMPORT.exists(deps.storage) || PENDING_RECREATION.exists(deps.storage) {
+        return Err(ContractError::ImportInProgress {});
+    }
+    let multisig = info.sender;
+    // Only multisigs may be imported, and only by a vote of their
+    // members.
+    let threshold: ThresholdResponse = deps
+        .querier
+        .query_wasm_smart(&multisig, &Cw3QueryMsg::Threshold {})
+        .map_err(|_| ContractError::Unauthorized {})?;
+
+    let members = query_voters(deps.as_ref(), &multisig)?;
+    if members.is_empty() {
+        return Err(ContractError::NoVoters {});
+    }
+    let proposals = if recreate_open_proposals {
+        query_open_proposals(deps.as_ref(), &multisig)?
+    } else {
+        vec![]
+    };
+
+    let voting_module_instantiate_info = ModuleInstantiateInfo {
+        code_id: code_ids.dao_voting_cw4,
+        msg: to_binary(&dao_voting_cw4::msg::InstantiateMsg {
+            cw4_group_code_id: code_ids.cw4_group,
+            initial_members: members,
+        })?,
+        admin: Some(Admin::CoreModule {}),
+        label: format!("{name} voting module"),
+    };
+    let pre_propose_info = match pre_propose_info {
+        Some(info) => info,
+        None => PreProposeInfo::ModuleMayPropose {
+            info: ModuleInstantiateInfo {
+                code_id: code_ids.dao_pre_propose_single,
+                msg: to_binary(&dao_pre_propose_single::InstantiateMsg {
+                    deposit_info: None,
+                    open_proposal_submission: false,
+                    nft_deposit_info: None,
+                    extension: Empty::default(),
+                })?,
+                admin: Some(Admin::CoreModule {}),
+                label: format!("{name} pre-propose module"),
+            },
+        },
+    };
+    // If there are open proposals to re-create, anyone may propose
+    // until this contract has re-created them, after which
+    // `pre_propose_info` is installed.
+    let (initial_pre_propose_info, pre_propose_info) = if proposals.is_empty() {
+        (pre_propose_info, None)
+    } else {
+        (PreProposeInfo::AnyoneMayPropose {}, Some(pre_propose_info))
+    };
+    let proposal_module_instantiate_info = ModuleInstantiateInfo {
+        code_id: code_ids.dao_proposal_single,
+        msg: to_binary(&dao_proposal_single::msg::InstantiateMsg {
+            threshold: convert_threshold(threshold),
+            max_voting_period,
+            min_voting_period: None,
+            only_members_execute: true,
+            allow_revoting: false,
+            pre_propose_info: initial_pre_propose_info,
+            close_proposal_on_execution_failure: true,
+            max_proposal_size: None,
+            max_open_proposals: None,
+            pre_propose_fallback: None,
+            require_content_cid: None,
+            abstain_counting: None,
+            execution_delay: None,
+            vote_extension: None,
+        })?,
+        admin: Some(Admin::CoreModule {}),
+        label: format!("{name} proposal module"),
+    };
+
+    // This contract is the DAO's admin until the import completes so
+    // that it may install the final proposal creation policy. Once
+    // complete, the DAO is made its own admin and the admin of its
+    // contract.
+    let instantiate = WasmMsg::Instantiate {
+        admin: Some(env.contract.address.to_string()),
+        code_id: code_ids.dao_core,
+        msg: to_binary(&dao_core::msg::InstantiateMsg {
+            admin: Some(env.contract.address.into_string()),
+            name: name.clone(),
+            description,
+            image_url,
+            automatically_add_cw20s: true,
+            automatically_add_cw721s: true,
+            voting_module_instantiate_info,
+            proposal_modules_instantiate_info: vec![proposal_module_instantiate_info],
+            initial_items: None,
+            dao_uri: None,
+        })?,
+        funds: vec![],
+        label: name,
+    };
+
+    let proposal_count = proposals.len();
+    PENDING_IMPORT.save(
+        deps.storage,
+        &PendingImport {
+            multisig: multisig.clone(),
+            proposals,
+            pre_propose_info,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "import")
+        .add_attribute("multisig", multisig)
+        .add_attribute("open_proposals", proposal_count.to_string())
+        .add_submessage(SubMsg::reply_on_success(
+            instantiate,
+            INSTANTIATE_DAO_REPLY_ID,
+        )))
+}
+
+pub fn execute_recreate_proposals(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let pending = PENDING_RECREATION
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingRecreation {})?;
+    // Voting power set when the DAO is instantiated is not visible at
+    // that height, so proposals created then would have no voters.
+    if env.block.height <= pending.height {
+        return Err(ContractError::RecreateTooEarly {});
+    }
+    PENDING_RECREATION.remove(deps.storage);
+
+    let proposal_count = pending.proposals.len();
+    let mut msgs = pending
+        .proposals
+        .into_iter()
+        .map(|proposal| {
+            Ok(WasmMsg::Execute {
+                contract_addr: pending.proposal_module.to_string(),
+                msg: to_binary(&dao_proposal_single::msg::ExecuteMsg::Propose(
+                    SingleChoiceProposeMsg {
+                        title: proposal.title,
+                        description: format!(
+                            "{}\n\nImported from proposal {} of {}.",
+                            proposal.description, proposal.id, pending.multisig
+                        ),
+                        msgs: proposal.msgs,
+                        proposer: None,
+                        description_hash: None,
+                        content_cid: None,
+                        disable_revoting: false,
+                    },
+                ))?,
+                funds: vec![],
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    msgs.push(WasmMsg::Execute {
+        contract_addr: pending.dao.to_string(),
+        msg: to_binary(&dao_core::msg::ExecuteMsg::ExecuteAdminMsgs {
+            msgs: vec![WasmMsg::Execute {
+                contract_addr: pending.proposal_module.to_string(),
+                msg: to_binary(
+                    &dao_proposal_single::msg::ExecuteMsg::UpdatePreProposeInfo {
+                        info: pending.pre_propose_info,
+                    },
+                )?,
+                funds: vec![],
+            }
+            .into()],
+        })?,
+        funds: vec![],
+    });
+    msgs.extend(admin_handoff_msgs(&pending.dao)?);
+
+    Ok(Response::new()
+        .add_attribute("action", "recreate_proposals")
+        .add_attribute("dao", pending.dao)
+        .add_attribute("proposals", proposal_count.to_string())
+        .add_messages(msgs))
+}
+
+/// Messages making DAO its own admin and the admin of its contract.
+fn admin_handoff_msgs(dao: &Addr) -> StdResult<Vec<WasmMsg>> {
+    Ok(vec![
+        // Nominating no admin makes the DAO its own admin.
+        WasmMsg::Execute {
+            contract_addr: dao.to_string(),
+            msg: to_binary(&dao_core::msg::ExecuteMsg::NominateAdmin { admin: None })?,
+            funds: vec![],
+        },
+        WasmMsg::UpdateAdmin {
+            contract_addr: dao.to_string(),
+            admin: dao.to_string(),
+        },
+    ])
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::PendingRecreation {} => to_binary(&PENDING_RECREATION.may_load(deps.storage)?),
+    }
+}
+
+/// Records the new DAO. If there are open proposals to re-create,
+/// they are left for `RecreateProposals`. Otherwise the DAO is made
+/// its own admin and contract admin.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        INSTANTIATE_DAO_REPLY_ID => {
+            let res = parse_reply_instantiate_data(msg)?;
+            let dao = deps.api.addr_validate(&res.contract_address)?;
+            let import = PENDING_IMPORT.load(deps.storage)?;
+            PENDING_IMPORT.remove(deps.storage);
+
+            let response = Response::new()
+                .add_attribute("action", "import_complete")
+                .add_attribute("multisig", import.multisig.clone())
+                .add_attribute("dao", dao.clone());
+            let pre_propose_info = match import.pre_propose_info {
+                Some(info) => info,
+                None => return Ok(response.add_messages(admin_handoff_msgs(&dao)?)),
+            };
+
+            let proposal_module = deps
+                .querier
+                .query_wasm_smart::<Vec<dao_core::state::ProposalModule>>(
+                    &dao,
+                    &dao_core::msg::QueryMsg::ProposalModules {
+                        start_after: None,
+                        limit: Some(1),
+                    },
+                )?
+                .into_iter()
+                .next()
+                .ok_or(ContractError::NoProposalModule {})?
+                .address;
+            PENDING_RECREATION.save(
+                deps.storage,
+                &PendingRecreation {
+                    multisig: import.multisig,
+                    dao,
+                    proposal_module: proposal_module.clone(),
+                    proposals: import.proposals,
+                    pre_propose_info,
+                    height: env.block.height,
+                },
+            )?;
+
+            Ok(response.add_attribute("proposal_module", proposal_module))
+        }
+        id => Err(ContractError::UnknownReplyId { id }),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::default())
+}
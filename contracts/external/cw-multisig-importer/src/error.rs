@@ -0,0 +1,33 @@
+use cosmwasm_std::StdError;
+use cw_utils::ParseReplyError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    ParseReplyError(#[from] ParseReplyError),
+
+    #[error("Only the multisig being imported may start an import")]
+    Unauthorized {},
+
+    #[error("An import is already in progress")]
+    ImportInProgress {},
+
+    #[error("The multisig has no voters")]
+    NoVoters {},
+
+    #[error("The DAO was created without a proposal module")]
+    NoProposalModule {},
+
+    #[error("No imported proposals are waiting to be re-created")]
+    NoPendingRecreation {},
+
+    #[error("Proposals may not be re-created in the block the DAO was instantiated in")]
+    RecreateTooEarly {},
+
+    #[error("Got a reply with an unknown ID: ({id})")]
+    UnknownReplyId { id: u64 },
+}
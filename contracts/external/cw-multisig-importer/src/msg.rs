@@ -0,0 +1,58 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cw_utils::Duration;
+use dao_voting::pre_propose::PreProposeInfo;
+
+#[cw_serde]
+pub struct InstantiateMsg {}
+
+/// Code IDs of the contracts that make up the new DAO.
+#[cw_serde]
+pub struct CodeIds {
+    pub dao_core: u64,
+    pub dao_voting_cw4: u64,
+    pub cw4_group: u64,
+    pub dao_proposal_single: u64,
+    /// Used to install a pre-propose module which only allows
+    /// members to propose if no proposal creation policy is
+    /// provided.
+    pub dao_pre_propose_single: u64,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Creates a DAO with the multisig's voters and threshold. Must
+    /// be sent by the multisig being imported.
+    Import {
+        name: String,
+        description: String,
+        image_url: Option<String>,
+        code_ids: CodeIds,
+        /// The maximum amount of time proposals in the new DAO may
+        /// be voted on.
+        max_voting_period: Duration,
+        /// Proposal creation policy to install once open proposals
+        /// have been re-created. If None, only members may propose
+        /// and no deposit is required.
+        pre_propose_info: Option<PreProposeInfo>,
+        /// If true the multisig's open proposals are re-created in
+        /// the DAO.
+        recreate_open_proposals: bool,
+    },
+    /// Re-creates the open proposals of an imported multisig in its
+    /// DAO, then installs the DAO's proposal creation policy and
+    /// makes the DAO its own admin. The DAO's voting module reports
+    /// no voting power in the block it is instantiated in, so this
+    /// must be called in a later block. Callable by anyone.
+    RecreateProposals {},
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Returns the import waiting on `RecreateProposals`, if any.
+    #[returns(Option<crate::state::PendingRecreation>)]
+    PendingRecreation {},
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
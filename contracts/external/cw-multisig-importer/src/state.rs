@@ -0,0 +1,42 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, CosmosMsg, Empty};
+use cw_storage_plus::Item;
+use dao_voting::pre_propose::PreProposeInfo;
+
+/// An open multisig proposal to be re-created in the DAO.
+#[cw_serde]
+pub struct ImportedProposal {
+    /// The ID of the proposal in the multisig.
+    pub id: u64,
+    pub title: String,
+    pub description: String,
+    pub msgs: Vec<CosmosMsg<Empty>>,
+}
+
+/// An import waiting on its DAO to be instantiated.
+#[cw_serde]
+pub struct PendingImport {
+    pub multisig: Addr,
+    pub proposals: Vec<ImportedProposal>,
+    /// The proposal creation policy to install once PROPOSALS have
+    /// been re-created. None if the policy was installed when the
+    /// DAO was instantiated.
+    pub pre_propose_info: Option<PreProposeInfo>,
+}
+
+/// An instantiated DAO whose open proposals have not yet been
+/// re-created.
+#[cw_serde]
+pub struct PendingRecreation {
+    pub multisig: Addr,
+    pub dao: Addr,
+    pub proposal_module: Addr,
+    pub proposals: Vec<ImportedProposal>,
+    pub pre_propose_info: PreProposeInfo,
+    /// The height at which the DAO was instantiated. Proposals may
+    /// only be re-created after it.
+    pub height: u64,
+}
+
+pub const PENDING_IMPORT: Item<PendingImport> = Item::new("pending_import");
+pub const PENDING_RECREATION: Item<PendingRecreation> = Item::new("pending_recreation");
@@ -0,0 +1,499 @@
+use cosmwasm_std::testing::{
+    mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+};
+use cosmwasm_std::{
+    coins, from_binary, from_slice, to_binary, Addr, BankMsg, Binary, ContractResult, CosmosMsg,
+    Decimal, Deps, DepsMut, Empty, Env, MessageInfo, OwnedDeps, Reply, Response, StdError,
+    StdResult, SubMsgResponse, SubMsgResult, SystemError, SystemResult, Uint128, WasmMsg,
+    WasmQuery,
+};
+use cw3::{
+    Cw3QueryMsg, ProposalListResponse, ProposalResponse, Status, ThresholdResponse, VoterDetail,
+    VoterListResponse,
+};
+use cw_multi_test::{next_block, App, ContractWrapper, Executor};
+use cw_utils::{Duration, Expiration};
+use dao_core::state::{ProposalModule, ProposalModuleStatus};
+use dao_testing::contracts::{
+    cw4_group_contract, dao_core_contract, dao_voting_cw4_contract, pre_propose_single_contract,
+    proposal_single_contract,
+};
+use dao_voting::{
+    pre_propose::{PreProposeInfo, ProposalCreationPolicy},
+    proposal::SingleChoiceProposeMsg,
+    threshold::{PercentageThreshold, Threshold},
+    voting::Vote,
+};
+
+use crate::contract::{
+    convert_threshold, execute, instantiate, query, reply, INSTANTIATE_DAO_REPLY_ID,
+};
+use crate::msg::{CodeIds, ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::state::{PendingRecreation, PENDING_IMPORT, PENDING_RECREATION};
+use crate::ContractError;
+
+const MULTISIG: &str = "multisig";
+const DAO: &str = "dao";
+const PROPOSAL_MODULE: &str = "proposal_module";
+
+fn cw3_proposal(id: u64, status: Status) -> ProposalResponse {
+    ProposalResponse {
+        id,
+        title: format!("proposal {id}"),
+        description: "description".to_string(),
+        msgs: vec![],
+        status,
+        expires: Expiration::Never {},
+        threshold: ThresholdResponse::AbsoluteCount {
+            weight: 2,
+            total_weight: 3,
+        },
+        proposer: Addr::unchecked("ekez"),
+        deposit: None,
+    }
+}
+
+fn setup() -> OwnedDeps<MockStorage, MockApi, MockQuerier, Empty> {
+    let mut deps = mock_dependencies();
+    deps.querier.update_wasm(|query| match query {
+        WasmQuery::Smart { contract_addr, msg } if contract_addr == MULTISIG => {
+            let response = match from_slice(msg).unwrap() {
+                Cw3QueryMsg::Threshold {} => to_binary(&ThresholdResponse::AbsoluteCount {
+                    weight: 2,
+                    total_weight: 3,
+                }),
+                Cw3QueryMsg::ListVoters { .. } => to_binary(&VoterListResponse {
+                    voters: vec![
+                        VoterDetail {
+                            addr: "ekez".to_string(),
+                            weight: 2,
+                        },
+                        VoterDetail {
+                            addr: "zeke".to_string(),
+                            weight: 1,
+                        },
+                    ],
+                }),
+                Cw3QueryMsg::ListProposals { .. } => to_binary(&ProposalListResponse {
+                    proposals: vec![
+                        cw3_proposal(1, Status::Executed),
+                        cw3_proposal(2, Status::Open),
+                    ],
+                }),
+                _ => panic!("unexpected query"),
+            };
+            SystemResult::Ok(ContractResult::Ok(response.unwrap()))
+        }
+        WasmQuery::Smart { contract_addr, .. } if contract_addr == DAO => {
+            SystemResult::Ok(ContractResult::Ok(
+                to_binary(&vec![ProposalModule {
+                    address: Addr::unchecked(PROPOSAL_MODULE),
+                    prefix: "A".to_string(),
+                    status: ProposalModuleStatus::Enabled,
+                }])
+                .unwrap(),
+            ))
+        }
+        WasmQuery::Smart { contract_addr, .. } => SystemResult::Err(SystemError::NoSuchContract {
+            addr: contract_addr.clone(),
+        }),
+        _ => panic!("unexpected query"),
+    });
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("creator", &[]),
+        InstantiateMsg {},
+    )
+    .unwrap();
+    deps
+}
+
+fn import_msg(pre_propose_info: Option<PreProposeInfo>) -> ExecuteMsg {
+    ExecuteMsg::Import {
+        name: "imported".to_string(),
+        description: "an imported multisig".to_string(),
+        image_url: None,
+        code_ids: CodeIds {
+            dao_core: 1,
+            dao_voting_cw4: 2,
+            cw4_group: 3,
+            dao_proposal_single: 4,
+            dao_pre_propose_single: 5,
+        },
+        max_voting_period: Duration::Time(604800),
+        pre_propose_info,
+        recreate_open_proposals: true,
+    }
+}
+
+/// Protobuf encodes a `MsgInstantiateContractResponse` with the
+/// given contract address.
+fn instantiate_reply(address: &str) -> Reply {
+    let mut data = vec![0x0a, address.len() as u8];
+    data.extend_from_slice(address.as_bytes());
+    Reply {
+        id: INSTANTIATE_DAO_REPLY_ID,
+        result: SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: Some(Binary::from(data)),
+        }),
+    }
+}
+
+#[test]
+fn test_convert_threshold() {
+    assert_eq!(
+        convert_threshold(ThresholdResponse::AbsoluteCount {
+            weight: 2,
+            total_weight: 3
+        }),
+        Threshold::AbsoluteCount {
+            threshold: Uint128::new(2)
+        }
+    );
+    assert_eq!(
+        convert_threshold(ThresholdResponse::ThresholdQuorum {
+            threshold: Decimal::percent(50),
+            quorum: Decimal::percent(20),
+            total_weight: 3
+        }),
+        Threshold::ThresholdQuorum {
+            threshold: PercentageThreshold::Percent(Decimal::percent(50)),
+            quorum: PercentageThreshold::Percent(Decimal::percent(20)),
+        }
+    );
+}
+
+#[test]
+fn test_only_multisig_may_import() {
+    let mut deps = setup();
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("rando", &[]),
+        import_msg(None),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+}
+
+#[test]
+fn test_import() {
+    let mut deps = setup();
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(MULTISIG, &[]),
+        import_msg(Some(PreProposeInfo::AnyoneMayPropose {})),
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 1);
+    let msg = match &res.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Instantiate {
+            code_id: 1, msg, ..
+        }) => msg,
+        msg => panic!("unexpected message: {msg:?}"),
+    };
+    let msg: dao_core::msg::InstantiateMsg = from_binary(msg).unwrap();
+    let voting: dao_voting_cw4::msg::InstantiateMsg =
+        from_binary(&msg.voting_module_instantiate_info.msg).unwrap();
+    assert_eq!(
+        voting.initial_members,
+        vec![
+            cw4::Member {
+                addr: "ekez".to_string(),
+                weight: 2
+            },
+            cw4::Member {
+                addr: "zeke".to_string(),
+                weight: 1
+            }
+        ]
+    );
+    let proposal: dao_proposal_single::msg::InstantiateMsg =
+        from_binary(&msg.proposal_modules_instantiate_info[0].msg).unwrap();
+    assert_eq!(
+        proposal.threshold,
+        Threshold::AbsoluteCount {
+            threshold: Uint128::new(2)
+        }
+    );
+
+    // Only the open proposal is imported.
+    let pending = PENDING_IMPORT.load(deps.as_ref().storage).unwrap();
+    assert_eq!(pending.proposals.len(), 1);
+    assert_eq!(pending.proposals[0].id, 2);
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(MULTISIG, &[]),
+        import_msg(None),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::ImportInProgress {});
+
+    let res = reply(deps.as_mut(), mock_env(), instantiate_reply(DAO)).unwrap();
+    assert!(!PENDING_IMPORT.exists(deps.as_ref().storage));
+    assert!(res.messages.is_empty());
+
+    // Proposals may not be re-created in the block the DAO was
+    // instantiated in.
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("rando", &[]),
+        ExecuteMsg::RecreateProposals {},
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::RecreateTooEarly {});
+
+    let mut env = mock_env();
+    env.block.height += 1;
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("rando", &[]),
+        ExecuteMsg::RecreateProposals {},
+    )
+    .unwrap();
+    assert!(!PENDING_RECREATION.exists(deps.as_ref().storage));
+    // Re-created proposal, pre-propose update, admin nomination, and
+    // contract admin update.
+    assert_eq!(res.messages.len(), 4);
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: PROPOSAL_MODULE.to_string(),
+            msg: to_binary(&dao_proposal_single::msg::ExecuteMsg::Propose(
+                SingleChoiceProposeMsg {
+                    title: "proposal 2".to_string(),
+                    description: "description\n\nImported from proposal 2 of multisig.".to_string(),
+                    msgs: vec![],
+                    proposer: None,
+                    description_hash: None,
+                    content_cid: None,
+                    disable_revoting: false,
+                }
+            ))
+            .unwrap(),
+            funds: vec![],
+        })
+    );
+    assert_eq!(
+        res.messages[3].msg,
+        CosmosMsg::Wasm(WasmMsg::UpdateAdmin {
+            contract_addr: DAO.to_string(),
+            admin: DAO.to_string(),
+        })
+    );
+
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("rando", &[]),
+        ExecuteMsg::RecreateProposals {},
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NoPendingRecreation {});
+}
+
+fn multisig_instantiate(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: Empty,
+) -> StdResult<Response> {
+    Ok(Response::new())
+}
+
+/// Executes any message it is sent, standing in for a multisig
+/// passing a proposal.
+fn multisig_execute(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: CosmosMsg,
+) -> StdResult<Response> {
+    Ok(Response::new().add_message(msg))
+}
+
+fn multisig_query(_deps: Deps, _env: Env, msg: Cw3QueryMsg) -> StdResult<Binary> {
+    match msg {
+        Cw3QueryMsg::Threshold {} => to_binary(&ThresholdResponse::AbsoluteCount {
+            weight: 2,
+            total_weight: 3,
+        }),
+        Cw3QueryMsg::ListVoters { .. } => to_binary(&VoterListResponse {
+            voters: vec![
+                VoterDetail {
+                    addr: "ekez".to_string(),
+                    weight: 2,
+                },
+                VoterDetail {
+                    addr: "zeke".to_string(),
+                    weight: 1,
+                },
+            ],
+        }),
+        Cw3QueryMsg::ListProposals { .. } => to_binary(&ProposalListResponse {
+            proposals: vec![ProposalResponse {
+                msgs: vec![BankMsg::Send {
+                    to_address: "zeke".to_string(),
+                    amount: coins(100, "ujuno"),
+                }
+                .into()],
+                ..cw3_proposal(1, Status::Open)
+            }],
+        }),
+        _ => Err(StdError::generic_err("unsupported query")),
+    }
+}
+
+#[test]
+fn test_import_and_execute_recreated_proposal() {
+    let mut app = App::default();
+    let importer_id = app.store_code(Box::new(
+        ContractWrapper::new(execute, instantiate, query).with_reply(reply),
+    ));
+    let multisig_id = app.store_code(Box::new(ContractWrapper::new(
+        multisig_execute,
+        multisig_instantiate,
+        multisig_query,
+    )));
+    let code_ids = CodeIds {
+        dao_core: app.store_code(dao_core_contract()),
+        dao_voting_cw4: app.store_code(dao_voting_cw4_contract()),
+        cw4_group: app.store_code(cw4_group_contract()),
+        dao_proposal_single: app.store_code(proposal_single_contract()),
+        dao_pre_propose_single: app.store_code(pre_propose_single_contract()),
+    };
+    let importer = app
+        .instantiate_contract(
+            importer_id,
+            Addr::unchecked("creator"),
+            &InstantiateMsg {},
+            &[],
+            "importer",
+            None,
+        )
+        .unwrap();
+    let multisig = app
+        .instantiate_contract(
+            multisig_id,
+            Addr::unchecked("creator"),
+            &Empty {},
+            &[],
+            "multisig",
+            None,
+        )
+        .unwrap();
+
+    let import: CosmosMsg = WasmMsg::Execute {
+        contract_addr: importer.to_string(),
+        msg: to_binary(&ExecuteMsg::Import {
+            name: "imported".to_string(),
+            description: "an imported multisig".to_string(),
+            image_url: None,
+            code_ids,
+            max_voting_period: Duration::Time(604800),
+            pre_propose_info: None,
+            recreate_open_proposals: true,
+        })
+        .unwrap(),
+        funds: vec![],
+    }
+    .into();
+    app.execute_contract(Addr::unchecked("ekez"), multisig, &import, &[])
+        .unwrap();
+    let pending: PendingRecreation = app
+        .wrap()
+        .query_wasm_smart::<Option<PendingRecreation>>(&importer, &QueryMsg::PendingRecreation {})
+        .unwrap()
+        .unwrap();
+    app.init_modules(|router, _, storage| {
+        router
+            .bank
+            .init_balance(storage, &pending.dao, coins(100, "ujuno"))
+            .unwrap()
+    });
+
+    app.update_block(next_block);
+    app.execute_contract(
+        Addr::unchecked("rando"),
+        importer,
+        &ExecuteMsg::RecreateProposals {},
+        &[],
+    )
+    .unwrap();
+
+    // Only members may propose once the import completes.
+    let policy: ProposalCreationPolicy = app
+        .wrap()
+        .query_wasm_smart(
+            &pending.proposal_module,
+            &dao_proposal_single::msg::QueryMsg::ProposalCreationPolicy {},
+        )
+        .unwrap();
+    let pre_propose = match policy {
+        ProposalCreationPolicy::Module { addr } => addr,
+        policy => panic!("unexpected policy: {policy:?}"),
+    };
+    let config: dao_pre_propose_single::Config = app
+        .wrap()
+        .query_wasm_smart(&pre_propose, &dao_pre_propose_single::QueryMsg::Config {})
+        .unwrap();
+    assert!(!config.open_proposal_submission);
+
+    // The re-created proposal may be voted on by the multisig's
+    // voters and executed.
+    let proposal: dao_proposal_single::query::ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &pending.proposal_module,
+            &dao_proposal_single::msg::QueryMsg::Proposal { proposal_id: 1 },
+        )
+        .unwrap();
+    assert_eq!(proposal.proposal.total_power, Uint128::new(3));
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        pending.proposal_module.clone(),
+        &dao_proposal_single::msg::ExecuteMsg::Vote {
+            proposal_id: 1,
+            vote: Vote::Yes,
+            rationale: None,
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        pending.proposal_module.clone(),
+        &dao_proposal_single::msg::ExecuteMsg::Execute { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+    let proposal: dao_proposal_single::query::ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &pending.proposal_module,
+            &dao_proposal_single::msg::QueryMsg::Proposal { proposal_id: 1 },
+        )
+        .unwrap();
+    assert_eq!(
+        proposal.proposal.status,
+        dao_voting::status::Status::Executed
+    );
+    assert_eq!(
+        app.wrap().query_balance("zeke", "ujuno").unwrap().amount,
+        Uint128::new(100)
+    );
+
+    // The DAO is its own admin.
+    let admin: Addr = app
+        .wrap()
+        .query_wasm_smart(&pending.dao, &dao_core::msg::QueryMsg::Admin {})
+        .unwrap();
+    assert_eq!(admin, pending.dao);
+}
@@ -0,0 +1,275 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_binary, Binary, Coin, Deps, DepsMut, Env, IbcMsg, IbcTimeout, MessageInfo, Order, Response,
+    StdResult,
+};
+use cw2::set_contract_version;
+use cw_storage_plus::Bound;
+use prost::Message;
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, RemoteAccountResponse};
+use crate::proto::{
+    CosmosQuery, InterchainQueryPacketData, PageRequest, QueryAllBalancesRequest, RequestQuery,
+    ALL_BALANCES_PATH,
+};
+use crate::state::{
+    remove_from_totals, Config, RemoteAccount, ACCOUNTS, CHANNELS, CONFIG, PENDING_BALANCES, TOTALS,
+};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:cw-remote-treasury";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    if msg.refresh_timeout == 0 {
+        return Err(ContractError::ZeroTimeout {});
+    }
+    let dao = deps.api.addr_validate(&msg.dao)?;
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            dao: dao.clone(),
+            refresh_timeout: msg.refresh_timeout,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("dao", dao))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::RegisterAccount {
+            channel,
+            address,
+            chain_id,
+            label,
+        } => execute_register_account(deps, info, channel, address, chain_id, label),
+        ExecuteMsg::RemoveAccount { channel, address } => {
+            execute_remove_account(deps, info, channel, address)
+        }
+        ExecuteMsg::Refresh { channel } => execute_refresh(deps, env, channel),
+    }
+}
+
+pub fn execute_register_account(
+    deps: DepsMut,
+    info: MessageInfo,
+    channel: String,
+    address: String,
+    chain_id: String,
+    label: String,
+) -> Result<Response, ContractError> {
+    if info.sender != CONFIG.load(deps.storage)?.dao {
+        return Err(ContractError::Unauthorized {});
+    }
+    if !CHANNELS.has(deps.storage, channel.clone()) {
+        return Err(ContractError::UnknownChannel { channel });
+    }
+
+    let key = (channel.as_str(), address.as_str());
+    // Registering an account again resets its balances.
+    if let Some(account) = ACCOUNTS.may_load(deps.storage, key)? {
+        remove_from_totals(deps.storage, &account)?;
+    }
+    PENDING_BALANCES.remove(deps.storage, key);
+    ACCOUNTS.save(
+        deps.storage,
+        key,
+        &RemoteAccount {
+            chain_id,
+            label,
+            balances: vec![],
+            last_updated: None,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "register_account")
+        .add_attribute("channel", channel)
+        .add_attribute("address", address))
+}
+
+pub fn execute_remove_account(
+    deps: DepsMut,
+    info: MessageInfo,
+    channel: String,
+    address: String,
+) -> Result<Response, ContractError> {
+    if info.sender != CONFIG.load(deps.storage)?.dao {
+        return Err(ContractError::Unauthorized {});
+    }
+    let key = (channel.as_str(), address.as_str());
+    let account = match ACCOUNTS.may_load(deps.storage, key)? {
+        Some(account) => account,
+        None => return Err(ContractError::NoSuchAccount { channel, address }),
+    };
+    remove_from_totals(deps.storage, &account)?;
+    PENDING_BALANCES.remove(deps.storage, key);
+    ACCOUNTS.remove(deps.storage, key);
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_account")
+        .add_attribute("channel", channel)
+        .add_attribute("address", address))
+}
+
+pub fn execute_refresh(
+    deps: DepsMut,
+    env: Env,
+    channel: Option<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let channels = match channel {
+        Some(channel) => {
+            if !CHANNELS.has(deps.storage, channel.clone()) {
+                return Err(ContractError::UnknownChannel { channel });
+            }
+            vec![channel]
+        }
+        None => CHANNELS
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()?,
+    };
+
+    let mut messages = vec![];
+    for channel in channels {
+        let requests: Vec<RequestQuery> = ACCOUNTS
+            .prefix(&channel)
+            .keys(deps.storage, None, None, Order::Ascending)
+            .map(|address| Ok(balances_request(address?, None)))
+            .collect::<StdResult<_>>()?;
+        if requests.is_empty() {
+            continue;
+        }
+        messages.push(refresh_packet(&env, &config, channel, requests)?);
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "refresh")
+        .add_attribute("packets", messages.len().to_string())
+        .add_messages(messages))
+}
+
+/// A query for the page of ADDRESS's balances starting at PAGE_KEY, or
+/// the first page if `None`.
+pub(crate) fn balances_request(address: String, page_key: Option<Vec<u8>>) -> RequestQuery {
+    RequestQuery {
+        data: QueryAllBalancesRequest {
+            address,
+            pagination: page_key.map(|key| PageRequest { key }),
+        }
+        .encode_to_vec(),
+        path: ALL_BALANCES_PATH.to_string(),
+        height: 0,
+        prove: false,
+    }
+}
+
+/// A packet sending REQUESTS over CHANNEL.
+pub(crate) fn refresh_packet(
+    env: &Env,
+    config: &Config,
+    channel: String,
+    requests: Vec<RequestQuery>,
+) -> StdResult<IbcMsg> {
+    Ok(IbcMsg::SendPacket {
+        channel_id: channel,
+        data: to_binary(&InterchainQueryPacketData {
+            data: Binary(CosmosQuery { requests }.encode_to_vec()),
+            memo: String::new(),
+        })?,
+        timeout: IbcTimeout::with_timestamp(env.block.time.plus_seconds(config.refresh_timeout)),
+    })
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::Channels {} => to_binary(
+            &CHANNELS
+                .keys(deps.storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<_>>>()?,
+        ),
+        QueryMsg::RemoteTreasury { start_after, limit } => {
+            to_binary(&query_remote_treasury(deps, start_after, limit)?)
+        }
+        QueryMsg::ChainTotals {
+            chain_id,
+            start_after,
+            limit,
+        } => to_binary(&query_chain_totals(deps, chain_id, start_after, limit)?),
+    }
+}
+
+pub fn query_remote_treasury(
+    deps: Deps,
+    start_after: Option<(String, String)>,
+    limit: Option<u32>,
+) -> StdResult<Vec<RemoteAccountResponse>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after
+        .as_ref()
+        .map(|(channel, address)| Bound::exclusive((channel.as_str(), address.as_str())));
+    ACCOUNTS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let ((channel, address), account) = item?;
+            Ok(RemoteAccountResponse {
+                channel,
+                address,
+                account,
+            })
+        })
+        .collect()
+}
+
+pub fn query_chain_totals(
+    deps: Deps,
+    chain_id: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<Coin>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    TOTALS
+        .prefix(&chain_id)
+        .range(
+            deps.storage,
+            start_after.as_deref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|item| {
+            let (denom, amount) = item?;
+            Ok(Coin { denom, amount })
+        })
+        .collect()
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::default())
+}
@@ -0,0 +1,31 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Refresh timeout must be non-zero")]
+    ZeroTimeout {},
+
+    #[error("Channel ({channel}) is not an open interchain query channel")]
+    UnknownChannel { channel: String },
+
+    #[error("No account ({address}) registered on channel ({channel})")]
+    NoSuchAccount { channel: String, address: String },
+
+    #[error(
+        "Interchain query channels must be unordered and use version ({expected}), got ({version})"
+    )]
+    InvalidChannel { expected: String, version: String },
+
+    #[error("This contract does not accept packets")]
+    NoReceive {},
+
+    #[error("Failed to decode interchain query packet: {reason}")]
+    Decode { reason: String },
+}
@@ -0,0 +1,187 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    from_binary, Coin, DepsMut, Empty, Env, IbcBasicResponse, IbcChannelCloseMsg,
+    IbcChannelConnectMsg, IbcChannelOpenMsg, IbcChannelOpenResponse, IbcOrder, IbcPacketAckMsg,
+    IbcPacketReceiveMsg, IbcPacketTimeoutMsg, IbcReceiveResponse, Uint128,
+};
+use prost::Message;
+
+use crate::contract::{balances_request, refresh_packet};
+use crate::error::ContractError;
+use crate::proto::{
+    Acknowledgement, CosmosQuery, CosmosResponse, InterchainQueryPacketAck,
+    InterchainQueryPacketData, QueryAllBalancesRequest, QueryAllBalancesResponse,
+};
+use crate::state::{
+    add_to_totals, remove_from_totals, ACCOUNTS, CHANNELS, CONFIG, PENDING_BALANCES,
+};
+
+pub const ICQ_VERSION: &str = "icq-1";
+
+fn decode_error(reason: impl ToString) -> ContractError {
+    ContractError::Decode {
+        reason: reason.to_string(),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_open(
+    _deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelOpenMsg,
+) -> Result<IbcChannelOpenResponse, ContractError> {
+    let channel = msg.channel();
+    let version = msg
+        .counterparty_version()
+        .unwrap_or(channel.version.as_str());
+    if channel.order != IbcOrder::Unordered || version != ICQ_VERSION {
+        return Err(ContractError::InvalidChannel {
+            expected: ICQ_VERSION.to_string(),
+            version: version.to_string(),
+        });
+    }
+    Ok(None)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_connect(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelConnectMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let channel = msg.channel().endpoint.channel_id.clone();
+    CHANNELS.save(deps.storage, channel.clone(), &Empty::default())?;
+    Ok(IbcBasicResponse::new()
+        .add_attribute("action", "channel_connect")
+        .add_attribute("channel", channel))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_close(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelCloseMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let channel = msg.channel().endpoint.channel_id.clone();
+    CHANNELS.remove(deps.storage, channel.clone());
+    Ok(IbcBasicResponse::new()
+        .add_attribute("action", "channel_close")
+        .add_attribute("channel", channel))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_receive(
+    _deps: DepsMut,
+    _env: Env,
+    _msg: IbcPacketReceiveMsg,
+) -> Result<IbcReceiveResponse, ContractError> {
+    Err(ContractError::NoReceive {})
+}
+
+/// Stores the balances returned for each account queried by the
+/// original packet. Requests and responses are matched by index.
+/// Balances which span more than one page are held as pending and the
+/// next page is queried, until the last page is received.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_ack(
+    deps: DepsMut,
+    env: Env,
+    msg: IbcPacketAckMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let channel = msg.original_packet.src.channel_id;
+    let response = IbcBasicResponse::new()
+        .add_attribute("action", "refresh_ack")
+        .add_attribute("channel", &channel);
+
+    let result = match from_binary(&msg.acknowledgement.data)? {
+        Acknowledgement::Result(result) => result,
+        Acknowledgement::Error(err) => return Ok(response.add_attribute("error", err)),
+    };
+    let InterchainQueryPacketAck { data } = from_binary(&result)?;
+    let responses = CosmosResponse::decode(data.as_slice())
+        .map_err(decode_error)?
+        .responses;
+
+    let InterchainQueryPacketData { data, .. } = from_binary(&msg.original_packet.data)?;
+    let requests = CosmosQuery::decode(data.as_slice())
+        .map_err(decode_error)?
+        .requests;
+
+    let mut updated = 0u64;
+    let mut next_pages = vec![];
+    for (request, response) in requests.into_iter().zip(responses) {
+        if response.code != 0 {
+            continue;
+        }
+        let request =
+            QueryAllBalancesRequest::decode(request.data.as_slice()).map_err(decode_error)?;
+        let response =
+            QueryAllBalancesResponse::decode(response.value.as_slice()).map_err(decode_error)?;
+        let key = (channel.as_str(), request.address.as_str());
+
+        // The account may have been removed while the packet was in
+        // flight.
+        let mut account = match ACCOUNTS.may_load(deps.storage, key)? {
+            Some(account) => account,
+            None => continue,
+        };
+        let mut balances = match request.pagination {
+            None => vec![],
+            Some(_) => match PENDING_BALANCES.may_load(deps.storage, key)? {
+                Some(balances) => balances,
+                // The account was registered again after the
+                // earlier pages were received.
+                None => continue,
+            },
+        };
+        for c in response.balances {
+            balances.push(Coin {
+                denom: c.denom,
+                amount: c.amount.parse::<Uint128>()?,
+            });
+        }
+
+        match response.pagination.filter(|page| !page.next_key.is_empty()) {
+            Some(page) => {
+                PENDING_BALANCES.save(deps.storage, key, &balances)?;
+                next_pages.push(balances_request(
+                    request.address.clone(),
+                    Some(page.next_key),
+                ));
+            }
+            None => {
+                PENDING_BALANCES.remove(deps.storage, key);
+                remove_from_totals(deps.storage, &account)?;
+                account.balances = balances;
+                account.last_updated = Some(env.block.time);
+                add_to_totals(deps.storage, &account)?;
+                ACCOUNTS.save(deps.storage, key, &account)?;
+                updated += 1;
+            }
+        }
+    }
+
+    let response = response
+        .add_attribute("updated", updated.to_string())
+        .add_attribute("next_pages", next_pages.len().to_string());
+    if next_pages.is_empty() {
+        Ok(response)
+    } else {
+        let config = CONFIG.load(deps.storage)?;
+        Ok(response.add_message(refresh_packet(&env, &config, channel, next_pages)?))
+    }
+}
+
+/// Timed out refreshes leave balances untouched. `last_updated` makes
+/// stale balances visible and the crank may be called again.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_timeout(
+    _deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketTimeoutMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    Ok(IbcBasicResponse::new()
+        .add_attribute("action", "refresh_timeout")
+        .add_attribute("channel", msg.packet.src.channel_id))
+}
@@ -0,0 +1,66 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Coin;
+
+use crate::state::RemoteAccount;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub dao: String,
+    /// How long, in seconds, a refresh packet may wait to be relayed
+    /// before timing out.
+    pub refresh_timeout: u64,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Registers an account owned by the DAO on the chain on the
+    /// other end of CHANNEL. Only callable by the DAO.
+    RegisterAccount {
+        channel: String,
+        address: String,
+        chain_id: String,
+        label: String,
+    },
+    /// Removes an account from the registry. Only callable by the
+    /// DAO.
+    RemoveAccount { channel: String, address: String },
+    /// Sends an interchain query for the balances of every account
+    /// registered on CHANNEL, or on every channel if `None`. Callable
+    /// by anyone.
+    Refresh { channel: Option<String> },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(crate::state::Config)]
+    Config {},
+    /// Lists open interchain query channels.
+    #[returns(Vec<String>)]
+    Channels {},
+    /// Lists registered accounts and their balances, ordered by
+    /// channel and then address.
+    #[returns(Vec<RemoteAccountResponse>)]
+    RemoteTreasury {
+        start_after: Option<(String, String)>,
+        limit: Option<u32>,
+    },
+    /// Lists the total balance of each denom held by the accounts
+    /// registered on CHAIN_ID, ordered by denom.
+    #[returns(Vec<Coin>)]
+    ChainTotals {
+        chain_id: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct RemoteAccountResponse {
+    pub channel: String,
+    pub address: String,
+    pub account: RemoteAccount,
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
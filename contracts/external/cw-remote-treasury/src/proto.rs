@@ -0,0 +1,106 @@
+//! Packet formats for the interchain query (`icqhost`) IBC
+//! application and the protobuf messages carried inside them.
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Binary;
+use prost::Message;
+
+pub const ALL_BALANCES_PATH: &str = "/cosmos.bank.v1beta1.Query/AllBalances";
+
+/// JSON packet data sent to the host chain. DATA is a protobuf
+/// encoded `CosmosQuery`.
+#[cw_serde]
+pub struct InterchainQueryPacketData {
+    pub data: Binary,
+    pub memo: String,
+}
+
+/// JSON acknowledgement result returned by the host chain. DATA is a
+/// protobuf encoded `CosmosResponse`.
+#[cw_serde]
+pub struct InterchainQueryPacketAck {
+    pub data: Binary,
+}
+
+/// The standard ICS-4 acknowledgement envelope.
+#[cw_serde]
+pub enum Acknowledgement {
+    Result(Binary),
+    Error(String),
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct RequestQuery {
+    #[prost(bytes = "vec", tag = "1")]
+    pub data: Vec<u8>,
+    #[prost(string, tag = "2")]
+    pub path: String,
+    #[prost(int64, tag = "3")]
+    pub height: i64,
+    #[prost(bool, tag = "4")]
+    pub prove: bool,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct CosmosQuery {
+    #[prost(message, repeated, tag = "1")]
+    pub requests: Vec<RequestQuery>,
+}
+
+/// The subset of `tendermint.abci.ResponseQuery` this contract reads.
+#[derive(Clone, PartialEq, Message)]
+pub struct ResponseQuery {
+    #[prost(uint32, tag = "1")]
+    pub code: u32,
+    #[prost(bytes = "vec", tag = "7")]
+    pub value: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct CosmosResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub responses: Vec<ResponseQuery>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct ProtoCoin {
+    #[prost(string, tag = "1")]
+    pub denom: String,
+    #[prost(string, tag = "2")]
+    pub amount: String,
+}
+
+/// The subset of `cosmos.base.query.v1beta1.PageRequest` this
+/// contract sets.
+#[derive(Clone, PartialEq, Message)]
+pub struct PageRequest {
+    #[prost(bytes = "vec", tag = "1")]
+    pub key: Vec<u8>,
+}
+
+/// The subset of `cosmos.base.query.v1beta1.PageResponse` this
+/// contract reads.
+#[derive(Clone, PartialEq, Message)]
+pub struct PageResponse {
+    #[prost(bytes = "vec", tag = "1")]
+    pub next_key: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct QueryAllBalancesRequest {
+    #[prost(string, tag = "1")]
+    pub address: String,
+    /// `None` when querying the first page of balances.
+    #[prost(message, optional, tag = "2")]
+    pub pagination: Option<PageRequest>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct QueryAllBalancesResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub balances: Vec<ProtoCoin>,
+    /// Holds the key of the next page of balances, which is empty if
+    /// this is the last page.
+    #[prost(message, optional, tag = "2")]
+    pub pagination: Option<PageResponse>,
+}
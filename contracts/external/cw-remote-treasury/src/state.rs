@@ -0,0 +1,72 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin, Empty, StdResult, Storage, Timestamp, Uint128};
+use cw_storage_plus::{Item, Map};
+
+#[cw_serde]
+pub struct Config {
+    pub dao: Addr,
+    /// How long, in seconds, a refresh packet may wait to be relayed
+    /// before timing out.
+    pub refresh_timeout: u64,
+}
+
+#[cw_serde]
+pub struct RemoteAccount {
+    /// The chain the account lives on, e.g. `osmosis-1`. Informational
+    /// only. Queries are routed by channel.
+    pub chain_id: String,
+    /// A human readable description of the account, e.g. "ICA" or
+    /// "Bridged LP position".
+    pub label: String,
+    /// Balances as of `last_updated`.
+    pub balances: Vec<Coin>,
+    /// The time of the last successful refresh, or `None` if the
+    /// account has never been refreshed.
+    pub last_updated: Option<Timestamp>,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+/// Open channels to interchain query host modules on other chains.
+pub const CHANNELS: Map<String, Empty> = Map::new("channels");
+
+/// Registered accounts keyed by `(channel, address)` where CHANNEL is
+/// the interchain query channel to the account's chain.
+pub const ACCOUNTS: Map<(&str, &str), RemoteAccount> = Map::new("accounts");
+
+/// Balances received so far for accounts whose balances span more
+/// than one page of an interchain query, keyed by `(channel,
+/// address)`. Moved to the account once the last page is received.
+pub const PENDING_BALANCES: Map<(&str, &str), Vec<Coin>> = Map::new("pending_balances");
+
+/// The sum of the balances of every registered account keyed by
+/// `(chain_id, denom)`. Kept up to date as balances change so totals
+/// may be queried without visiting every account.
+pub const TOTALS: Map<(&str, &str), Uint128> = Map::new("totals");
+
+/// Adds the balances of ACCOUNT to `TOTALS`.
+pub fn add_to_totals(storage: &mut dyn Storage, account: &RemoteAccount) -> StdResult<()> {
+    for Coin { denom, amount } in account.balances.iter() {
+        TOTALS.update(
+            storage,
+            (account.chain_id.as_str(), denom.as_str()),
+            |total| -> StdResult<_> { Ok(total.unwrap_or_default().checked_add(*amount)?) },
+        )?;
+    }
+    Ok(())
+}
+
+/// Removes the balances of ACCOUNT from `TOTALS`, dropping totals
+/// which reach zero.
+pub fn remove_from_totals(storage: &mut dyn Storage, account: &RemoteAccount) -> StdResult<()> {
+    for Coin { denom, amount } in account.balances.iter() {
+        let key = (account.chain_id.as_str(), denom.as_str());
+        let total = TOTALS.load(storage, key)?.checked_sub(*amount)?;
+        if total.is_zero() {
+            TOTALS.remove(storage, key);
+        } else {
+            TOTALS.save(storage, key, &total)?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,343 @@
+use cosmwasm_std::testing::{
+    mock_dependencies, mock_env, mock_ibc_channel_connect_ack, mock_ibc_channel_open_try,
+    mock_ibc_packet_ack, mock_info, MockApi, MockQuerier, MockStorage,
+};
+use cosmwasm_std::{
+    coin, from_binary, to_binary, Binary, Coin, CosmosMsg, Empty, IbcAcknowledgement, IbcMsg,
+    IbcOrder, OwnedDeps,
+};
+use prost::Message;
+
+use crate::contract::{execute, instantiate, query};
+use crate::ibc::{ibc_channel_connect, ibc_channel_open, ibc_packet_ack, ICQ_VERSION};
+use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, RemoteAccountResponse};
+use crate::proto::{
+    Acknowledgement, CosmosQuery, CosmosResponse, InterchainQueryPacketAck,
+    InterchainQueryPacketData, PageResponse, ProtoCoin, QueryAllBalancesRequest,
+    QueryAllBalancesResponse, RequestQuery, ResponseQuery, ALL_BALANCES_PATH,
+};
+use crate::ContractError;
+
+const DAO: &str = "dao";
+const CHANNEL: &str = "channel-0";
+const ICA: &str = "osmo1ica";
+const MULTISIG: &str = "osmo1multisig";
+
+fn setup() -> OwnedDeps<MockStorage, MockApi, MockQuerier, Empty> {
+    let mut deps = mock_dependencies();
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        InstantiateMsg {
+            dao: DAO.to_string(),
+            refresh_timeout: 300,
+        },
+    )
+    .unwrap();
+    ibc_channel_connect(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_connect_ack(CHANNEL, IbcOrder::Unordered, ICQ_VERSION),
+    )
+    .unwrap();
+    for address in [ICA, MULTISIG] {
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DAO, &[]),
+            ExecuteMsg::RegisterAccount {
+                channel: CHANNEL.to_string(),
+                address: address.to_string(),
+                chain_id: "osmosis-1".to_string(),
+                label: address.to_string(),
+            },
+        )
+        .unwrap();
+    }
+    deps
+}
+
+fn balances_response(balances: &[(&str, u128)], next_key: &[u8]) -> ResponseQuery {
+    ResponseQuery {
+        code: 0,
+        value: QueryAllBalancesResponse {
+            balances: balances
+                .iter()
+                .map(|(denom, amount)| ProtoCoin {
+                    denom: denom.to_string(),
+                    amount: amount.to_string(),
+                })
+                .collect(),
+            pagination: Some(PageResponse {
+                next_key: next_key.to_vec(),
+            }),
+        }
+        .encode_to_vec(),
+    }
+}
+
+/// Refreshes every account and returns the sent packet.
+fn refresh(
+    deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>,
+) -> InterchainQueryPacketData {
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("crank", &[]),
+        ExecuteMsg::Refresh { channel: None },
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 1);
+    packet_data(&res.messages[0].msg)
+}
+
+fn packet_data(msg: &CosmosMsg) -> InterchainQueryPacketData {
+    match msg {
+        CosmosMsg::Ibc(IbcMsg::SendPacket {
+            channel_id, data, ..
+        }) => {
+            assert_eq!(channel_id, CHANNEL);
+            from_binary(data).unwrap()
+        }
+        _ => panic!("expected a packet"),
+    }
+}
+
+fn requests(packet: &InterchainQueryPacketData) -> Vec<RequestQuery> {
+    CosmosQuery::decode(packet.data.as_slice())
+        .unwrap()
+        .requests
+}
+
+/// Acknowledges PACKET with RESPONSES, returning the packet querying
+/// the next pages of balances if one was sent.
+fn ack(
+    deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>,
+    packet: &InterchainQueryPacketData,
+    responses: Vec<ResponseQuery>,
+) -> Option<InterchainQueryPacketData> {
+    let ack = to_binary(&Acknowledgement::Result(
+        to_binary(&InterchainQueryPacketAck {
+            data: Binary(CosmosResponse { responses }.encode_to_vec()),
+        })
+        .unwrap(),
+    ))
+    .unwrap();
+    let res = ibc_packet_ack(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_packet_ack(CHANNEL, packet, IbcAcknowledgement::new(ack)).unwrap(),
+    )
+    .unwrap();
+    res.messages.first().map(|msg| packet_data(&msg.msg))
+}
+
+fn query_accounts(
+    deps: &OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>,
+    start_after: Option<(String, String)>,
+    limit: Option<u32>,
+) -> Vec<RemoteAccountResponse> {
+    from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::RemoteTreasury { start_after, limit },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+fn query_totals(deps: &OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>) -> Vec<Coin> {
+    from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ChainTotals {
+                chain_id: "osmosis-1".to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_channel_open_validation() {
+    let mut deps = mock_dependencies();
+    let err = ibc_channel_open(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_open_try(CHANNEL, IbcOrder::Ordered, ICQ_VERSION),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidChannel { .. }));
+    let err = ibc_channel_open(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_open_try(CHANNEL, IbcOrder::Unordered, "ics20-1"),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidChannel { .. }));
+    ibc_channel_open(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_open_try(CHANNEL, IbcOrder::Unordered, ICQ_VERSION),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_register_requires_dao_and_channel() {
+    let mut deps = setup();
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("ekez", &[]),
+        ExecuteMsg::RegisterAccount {
+            channel: CHANNEL.to_string(),
+            address: ICA.to_string(),
+            chain_id: "osmosis-1".to_string(),
+            label: "ica".to_string(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        ExecuteMsg::RegisterAccount {
+            channel: "channel-9".to_string(),
+            address: ICA.to_string(),
+            chain_id: "osmosis-1".to_string(),
+            label: "ica".to_string(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::UnknownChannel {
+            channel: "channel-9".to_string()
+        }
+    );
+}
+
+#[test]
+fn test_refresh_and_ack() {
+    let mut deps = setup();
+
+    let packet = refresh(&mut deps);
+    let requests = requests(&packet);
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].path, ALL_BALANCES_PATH);
+    assert_eq!(
+        QueryAllBalancesRequest::decode(requests[0].data.as_slice()).unwrap(),
+        QueryAllBalancesRequest {
+            address: ICA.to_string(),
+            pagination: None,
+        }
+    );
+
+    let next = ack(
+        &mut deps,
+        &packet,
+        vec![
+            balances_response(&[("uosmo", 100), ("uatom", 5)], &[]),
+            balances_response(&[("uosmo", 50)], &[]),
+        ],
+    );
+    assert_eq!(next, None);
+
+    let accounts = query_accounts(&deps, None, None);
+    assert_eq!(accounts.len(), 2);
+    assert_eq!(
+        accounts[0].account.last_updated,
+        Some(mock_env().block.time)
+    );
+    assert_eq!(
+        query_totals(&deps),
+        vec![coin(5, "uatom"), coin(150, "uosmo")]
+    );
+
+    // Accounts are paginated.
+    let page = query_accounts(&deps, None, Some(1));
+    assert_eq!(page.len(), 1);
+    let page = query_accounts(
+        &deps,
+        Some((page[0].channel.clone(), page[0].address.clone())),
+        None,
+    );
+    assert_eq!(page.len(), 1);
+    assert_eq!(page[0].address, MULTISIG);
+
+    // Removing an account removes its balances from the totals.
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        ExecuteMsg::RemoveAccount {
+            channel: CHANNEL.to_string(),
+            address: ICA.to_string(),
+        },
+    )
+    .unwrap();
+    assert_eq!(query_totals(&deps), vec![coin(50, "uosmo")]);
+}
+
+#[test]
+fn test_paginated_balances() {
+    let mut deps = setup();
+
+    // Balances are stored as of the last refresh.
+    let packet = refresh(&mut deps);
+    ack(
+        &mut deps,
+        &packet,
+        vec![
+            balances_response(&[("uosmo", 100)], &[]),
+            balances_response(&[("uosmo", 50)], &[]),
+        ],
+    );
+
+    // The ICA's balances span two pages, so its second page is
+    // queried.
+    let packet = refresh(&mut deps);
+    let next = ack(
+        &mut deps,
+        &packet,
+        vec![
+            balances_response(&[("uatom", 5)], b"uosmo"),
+            balances_response(&[("uosmo", 60)], &[]),
+        ],
+    )
+    .unwrap();
+    let requests = requests(&next);
+    assert_eq!(requests.len(), 1);
+    let request = QueryAllBalancesRequest::decode(requests[0].data.as_slice()).unwrap();
+    assert_eq!(request.address, ICA);
+    assert_eq!(request.pagination.unwrap().key, b"uosmo");
+
+    // Until the last page arrives the ICA's balances are unchanged.
+    assert_eq!(query_totals(&deps), vec![coin(160, "uosmo")]);
+
+    let next = ack(
+        &mut deps,
+        &next,
+        vec![balances_response(&[("uosmo", 200)], &[])],
+    );
+    assert_eq!(next, None);
+    let accounts = query_accounts(&deps, None, None);
+    assert_eq!(
+        accounts[0].account.balances,
+        vec![coin(5, "uatom"), coin(200, "uosmo")]
+    );
+    assert_eq!(
+        query_totals(&deps),
+        vec![coin(5, "uatom"), coin(260, "uosmo")]
+    );
+}
@@ -1,8 +1,8 @@
-use cosmwasm_schema::write_api;
 use cw_token_swap::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use dao_schema::write_schema;
 
 fn main() {
-    write_api! {
+    write_schema! {
         instantiate: InstantiateMsg,
         query: QueryMsg,
         execute: ExecuteMsg,
@@ -0,0 +1,231 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_binary, BankMsg, Binary, Deps, DepsMut, Env, MessageInfo, Order, Reply, Response, StdResult,
+    SubMsg,
+};
+use cw2::set_contract_version;
+use cw_utils::one_coin;
+use prost::Message;
+
+use crate::error::ContractError;
+use crate::msg::{
+    ExecuteMsg, IbcLifecycleComplete, InstantiateMsg, MigrateMsg, PendingTransferResponse,
+    QueryMsg, SudoMsg,
+};
+use crate::proto::{MsgTransfer, MsgTransferResponse, ProtoCoin};
+use crate::state::{Config, PendingTransfer, CONFIG, DISPATCHING, PENDING};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:cw-transfer-manager";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub(crate) const TRANSFER_REPLY_ID: u64 = 0;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    if msg.default_timeout == 0 {
+        return Err(ContractError::ZeroTimeout {});
+    }
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            default_timeout: msg.default_timeout,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("default_timeout", msg.default_timeout.to_string()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Transfer {
+            channel,
+            receiver,
+            timeout,
+        } => execute_transfer(deps, env, info, channel, receiver, timeout),
+    }
+}
+
+pub fn execute_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    channel: String,
+    receiver: String,
+    timeout: Option<u64>,
+) -> Result<Response, ContractError> {
+    let amount = one_coin(&info)?;
+    let timeout = match timeout {
+        Some(0) => return Err(ContractError::ZeroTimeout {}),
+        Some(timeout) => timeout,
+        None => CONFIG.load(deps.storage)?.default_timeout,
+    };
+    let timeout = env.block.time.plus_seconds(timeout);
+
+    let transfer = MsgTransfer {
+        source_port: "transfer".to_string(),
+        source_channel: channel.clone(),
+        token: Some(ProtoCoin {
+            denom: amount.denom.clone(),
+            amount: amount.amount.to_string(),
+        }),
+        sender: env.contract.address.to_string(),
+        receiver: receiver.clone(),
+        timeout_timestamp: timeout.nanos(),
+        // Asks the ibc-hooks middleware to notify this contract when
+        // the packet is acknowledged or times out.
+        memo: format!(r#"{{"ibc_callback":"{}"}}"#, env.contract.address),
+    };
+
+    DISPATCHING.save(
+        deps.storage,
+        &PendingTransfer {
+            sender: info.sender.clone(),
+            channel: channel.clone(),
+            receiver: receiver.clone(),
+            amount: amount.clone(),
+            timeout,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "transfer")
+        .add_attribute("sender", info.sender)
+        .add_attribute("channel", channel)
+        .add_attribute("receiver", receiver)
+        .add_attribute("amount", amount.to_string())
+        .add_submessage(SubMsg::reply_on_success(
+            transfer.into_cosmos_msg(),
+            TRANSFER_REPLY_ID,
+        )))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn sudo(deps: DepsMut, _env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
+    match msg {
+        SudoMsg::IbcLifecycleComplete(IbcLifecycleComplete::IbcAck {
+            channel,
+            sequence,
+            success,
+            ..
+        }) => complete_transfer(deps, channel, sequence, success),
+        SudoMsg::IbcLifecycleComplete(IbcLifecycleComplete::IbcTimeout { channel, sequence }) => {
+            complete_transfer(deps, channel, sequence, false)
+        }
+    }
+}
+
+/// Removes a transfer from the pending set. If the transfer did not
+/// succeed the ICS-20 module will have refunded this contract, so the
+/// funds are forwarded back to the original sender.
+fn complete_transfer(
+    deps: DepsMut,
+    channel: String,
+    sequence: u64,
+    success: bool,
+) -> Result<Response, ContractError> {
+    let response = Response::new()
+        .add_attribute("action", "complete_transfer")
+        .add_attribute("channel", &channel)
+        .add_attribute("sequence", sequence.to_string())
+        .add_attribute("success", success.to_string());
+
+    // Packets sent by other contracts may name this one in their
+    // callback memo. Ignore them.
+    let transfer = match PENDING.may_load(deps.storage, (channel.as_str(), sequence))? {
+        Some(transfer) => transfer,
+        None => return Ok(response),
+    };
+    PENDING.remove(deps.storage, (channel.as_str(), sequence));
+
+    if success {
+        Ok(response)
+    } else {
+        Ok(response
+            .add_attribute("refunded", transfer.sender.as_str())
+            .add_message(BankMsg::Send {
+                to_address: transfer.sender.into_string(),
+                amount: vec![transfer.amount],
+            }))
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        TRANSFER_REPLY_ID => {
+            let data = msg
+                .result
+                .into_result()
+                .ok()
+                .and_then(|r| r.data)
+                .ok_or(ContractError::MissingSequence {})?;
+            let MsgTransferResponse { sequence } = MsgTransferResponse::decode(data.as_slice())
+                .map_err(|_| ContractError::MissingSequence {})?;
+
+            let transfer = DISPATCHING.load(deps.storage)?;
+            DISPATCHING.remove(deps.storage);
+            PENDING.save(
+                deps.storage,
+                (transfer.channel.as_str(), sequence),
+                &transfer,
+            )?;
+
+            Ok(Response::new().add_attribute("sequence", sequence.to_string()))
+        }
+        id => Err(ContractError::UnknownReplyId { id }),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::PendingTransfers { start_after, limit } => {
+            query_pending_transfers(deps, start_after, limit)
+        }
+    }
+}
+
+pub fn query_pending_transfers(
+    deps: Deps,
+    start_after: Option<(String, u64)>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let transfers = cw_paginate::paginate_map(
+        deps,
+        &PENDING,
+        start_after
+            .as_ref()
+            .map(|(channel, sequence)| (channel.as_str(), *sequence)),
+        limit,
+        Order::Ascending,
+    )?;
+    to_binary(
+        &transfers
+            .into_iter()
+            .map(|((_, sequence), transfer)| PendingTransferResponse { sequence, transfer })
+            .collect::<Vec<_>>(),
+    )
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::default())
+}
@@ -0,0 +1,21 @@
+use cosmwasm_std::StdError;
+use cw_utils::PaymentError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    PaymentError(#[from] PaymentError),
+
+    #[error("Transfer timeout must be non-zero")]
+    ZeroTimeout {},
+
+    #[error("Transfer response did not contain a packet sequence")]
+    MissingSequence {},
+
+    #[error("Got a reply with an unknown ID: ({id})")]
+    UnknownReplyId { id: u64 },
+}
@@ -0,0 +1,67 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+
+use crate::state::PendingTransfer;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// Timeout, in seconds, used for transfers that don't specify
+    /// their own.
+    pub default_timeout: u64,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Sends the single native coin attached to this message to
+    /// RECEIVER over CHANNEL. If the transfer fails or times out the
+    /// coin is returned to the sender of this message.
+    Transfer {
+        channel: String,
+        receiver: String,
+        /// Timeout in seconds. Defaults to `default_timeout`.
+        timeout: Option<u64>,
+    },
+}
+
+/// Callbacks sent by the ibc-hooks middleware once a packet sent with
+/// an `ibc_callback` memo has been acknowledged or has timed out.
+#[cw_serde]
+pub enum SudoMsg {
+    IbcLifecycleComplete(IbcLifecycleComplete),
+}
+
+#[cw_serde]
+pub enum IbcLifecycleComplete {
+    IbcAck {
+        channel: String,
+        sequence: u64,
+        ack: String,
+        success: bool,
+    },
+    IbcTimeout {
+        channel: String,
+        sequence: u64,
+    },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(crate::state::Config)]
+    Config {},
+    /// Lists transfers that have been sent but not yet acknowledged
+    /// or timed out.
+    #[returns(Vec<PendingTransferResponse>)]
+    PendingTransfers {
+        start_after: Option<(String, u64)>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct PendingTransferResponse {
+    pub sequence: u64,
+    pub transfer: PendingTransfer,
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
@@ -0,0 +1,31 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin, Timestamp};
+use cw_storage_plus::{Item, Map};
+
+#[cw_serde]
+pub struct Config {
+    /// Timeout, in seconds, used for transfers that don't specify
+    /// their own.
+    pub default_timeout: u64,
+}
+
+#[cw_serde]
+pub struct PendingTransfer {
+    /// The address that sent the funds. Refunds are returned here.
+    pub sender: Addr,
+    pub channel: String,
+    pub receiver: String,
+    pub amount: Coin,
+    pub timeout: Timestamp,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+/// The transfer currently being dispatched. Saved before the
+/// transfer message is sent and moved into `PENDING` once the packet
+/// sequence is known.
+pub const DISPATCHING: Item<PendingTransfer> = Item::new("dispatching");
+
+/// Transfers that have not yet been acknowledged or timed out, keyed
+/// by `(source_channel, sequence)`.
+pub const PENDING: Map<(&str, u64), PendingTransfer> = Map::new("pending");
@@ -0,0 +1,206 @@
+use cosmwasm_std::testing::{
+    mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+};
+use cosmwasm_std::{
+    coin, coins, from_binary, Addr, BankMsg, Binary, CosmosMsg, Empty, OwnedDeps, Reply,
+    SubMsgResponse, SubMsgResult,
+};
+use prost::Message;
+
+use crate::contract::{execute, instantiate, query, reply, sudo, TRANSFER_REPLY_ID};
+use crate::msg::{
+    ExecuteMsg, IbcLifecycleComplete, InstantiateMsg, PendingTransferResponse, QueryMsg, SudoMsg,
+};
+use crate::proto::{MsgTransfer, MsgTransferResponse, MSG_TRANSFER_TYPE_URL};
+use crate::state::PendingTransfer;
+use crate::ContractError;
+
+const DAO: &str = "dao";
+const CHANNEL: &str = "channel-0";
+const RECEIVER: &str = "osmo1receiver";
+
+fn setup() -> OwnedDeps<MockStorage, MockApi, MockQuerier, Empty> {
+    let mut deps = mock_dependencies();
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        InstantiateMsg {
+            default_timeout: 600,
+        },
+    )
+    .unwrap();
+    deps
+}
+
+/// Sends a transfer from the DAO and replies to it with SEQUENCE.
+fn transfer(deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>, sequence: u64) {
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &coins(100, "ujuno")),
+        ExecuteMsg::Transfer {
+            channel: CHANNEL.to_string(),
+            receiver: RECEIVER.to_string(),
+            timeout: None,
+        },
+    )
+    .unwrap();
+    reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: TRANSFER_REPLY_ID,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: Some(Binary(MsgTransferResponse { sequence }.encode_to_vec())),
+            }),
+        },
+    )
+    .unwrap();
+}
+
+fn pending(
+    deps: &OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>,
+) -> Vec<PendingTransferResponse> {
+    from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::PendingTransfers {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_transfer_message() {
+    let mut deps = setup();
+    let env = mock_env();
+
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(DAO, &[]),
+        ExecuteMsg::Transfer {
+            channel: CHANNEL.to_string(),
+            receiver: RECEIVER.to_string(),
+            timeout: None,
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::PaymentError(_)));
+
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(DAO, &coins(100, "ujuno")),
+        ExecuteMsg::Transfer {
+            channel: CHANNEL.to_string(),
+            receiver: RECEIVER.to_string(),
+            timeout: Some(60),
+        },
+    )
+    .unwrap();
+    assert_eq!(res.messages[0].id, TRANSFER_REPLY_ID);
+    match &res.messages[0].msg {
+        CosmosMsg::Stargate { type_url, value } => {
+            assert_eq!(type_url, MSG_TRANSFER_TYPE_URL);
+            let msg = MsgTransfer::decode(value.as_slice()).unwrap();
+            assert_eq!(msg.source_channel, CHANNEL);
+            assert_eq!(msg.receiver, RECEIVER);
+            assert_eq!(msg.sender, env.contract.address.to_string());
+            assert_eq!(
+                msg.timeout_timestamp,
+                env.block.time.plus_seconds(60).nanos()
+            );
+            assert_eq!(
+                msg.memo,
+                format!(r#"{{"ibc_callback":"{}"}}"#, env.contract.address)
+            );
+        }
+        _ => panic!("expected a stargate message"),
+    }
+}
+
+#[test]
+fn test_pending_transfers_and_callbacks() {
+    let mut deps = setup();
+    transfer(&mut deps, 1);
+    transfer(&mut deps, 2);
+    transfer(&mut deps, 3);
+
+    assert_eq!(
+        pending(&deps)[0],
+        PendingTransferResponse {
+            sequence: 1,
+            transfer: PendingTransfer {
+                sender: Addr::unchecked(DAO),
+                channel: CHANNEL.to_string(),
+                receiver: RECEIVER.to_string(),
+                amount: coin(100, "ujuno"),
+                timeout: mock_env().block.time.plus_seconds(600),
+            }
+        }
+    );
+
+    // Successful acks are removed without a refund.
+    let res = sudo(
+        deps.as_mut(),
+        mock_env(),
+        SudoMsg::IbcLifecycleComplete(IbcLifecycleComplete::IbcAck {
+            channel: CHANNEL.to_string(),
+            sequence: 1,
+            ack: "".to_string(),
+            success: true,
+        }),
+    )
+    .unwrap();
+    assert!(res.messages.is_empty());
+
+    // Failed acks and timeouts refund the sender.
+    let res = sudo(
+        deps.as_mut(),
+        mock_env(),
+        SudoMsg::IbcLifecycleComplete(IbcLifecycleComplete::IbcAck {
+            channel: CHANNEL.to_string(),
+            sequence: 2,
+            ack: "".to_string(),
+            success: false,
+        }),
+    )
+    .unwrap();
+    let refund = CosmosMsg::Bank(BankMsg::Send {
+        to_address: DAO.to_string(),
+        amount: coins(100, "ujuno"),
+    });
+    assert_eq!(res.messages[0].msg, refund);
+    let res = sudo(
+        deps.as_mut(),
+        mock_env(),
+        SudoMsg::IbcLifecycleComplete(IbcLifecycleComplete::IbcTimeout {
+            channel: CHANNEL.to_string(),
+            sequence: 3,
+        }),
+    )
+    .unwrap();
+    assert_eq!(res.messages[0].msg, refund);
+
+    assert!(pending(&deps).is_empty());
+
+    // Callbacks for unknown packets are ignored.
+    let res = sudo(
+        deps.as_mut(),
+        mock_env(),
+        SudoMsg::IbcLifecycleComplete(IbcLifecycleComplete::IbcTimeout {
+            channel: CHANNEL.to_string(),
+            sequence: 3,
+        }),
+    )
+    .unwrap();
+    assert!(res.messages.is_empty());
+}
@@ -0,0 +1,204 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_binary, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Empty, Env, IbcMsg, MessageInfo,
+    Response, StdResult, Uint128, WasmMsg,
+};
+use cw2::set_contract_version;
+use cw_utils::Duration;
+use dao_interface::message_filter::MessageFilterMsg;
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, SpendingResponse};
+use crate::state::{Config, Epoch, CONFIG, EPOCH};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:cw-treasury-policy";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+fn validate_config(limits: &[Coin], epoch: Duration) -> Result<(), ContractError> {
+    if let Duration::Height(0) | Duration::Time(0) = epoch {
+        return Err(ContractError::ZeroEpoch {});
+    }
+    let mut denoms = BTreeSet::new();
+    if let Some(duplicate) = limits.iter().find(|limit| !denoms.insert(&limit.denom)) {
+        return Err(ContractError::DuplicateLimit {
+            denom: duplicate.denom.clone(),
+        });
+    }
+    Ok(())
+}
+
+/// Totals the native tokens MSGS move out of the DAO's treasury by
+/// sending, burning, transferring over IBC, or attaching them as funds
+/// to a contract call.
+pub fn outflows(msgs: &[CosmosMsg<Empty>]) -> StdResult<BTreeMap<String, Uint128>> {
+    let mut totals: BTreeMap<String, Uint128> = BTreeMap::new();
+    let mut add = |coin: &Coin| -> StdResult<()> {
+        let total = totals.entry(coin.denom.clone()).or_default();
+        *total = total.checked_add(coin.amount)?;
+        Ok(())
+    };
+    for msg in msgs {
+        match msg {
+            CosmosMsg::Bank(BankMsg::Send { amount, .. } | BankMsg::Burn { amount }) => {
+                amount.iter().try_for_each(&mut add)?
+            }
+            CosmosMsg::Wasm(
+                WasmMsg::Execute { funds, .. } | WasmMsg::Instantiate { funds, .. },
+            ) => funds.iter().try_for_each(&mut add)?,
+            CosmosMsg::Ibc(IbcMsg::Transfer { amount, .. }) => add(amount)?,
+            _ => (),
+        }
+    }
+    Ok(totals)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    validate_config(&msg.limits, msg.epoch)?;
+    let dao = deps.api.addr_validate(&msg.dao)?;
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            dao: dao.clone(),
+            limits: msg.limits,
+            epoch: msg.epoch,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("dao", dao)
+        .add_attribute("epoch", msg.epoch.to_string()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::MessageFilterHook(MessageFilterMsg::FilterMessages { msgs, .. }) => {
+            execute_filter_messages(deps, env, info, msgs)
+        }
+        ExecuteMsg::UpdateConfig { limits, epoch } => {
+            execute_update_config(deps, info, limits, epoch)
+        }
+    }
+}
+
+pub fn execute_filter_messages(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msgs: Vec<CosmosMsg<Empty>>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.dao {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut epoch = match EPOCH.may_load(deps.storage)? {
+        Some(epoch) if !epoch.expiration.is_expired(&env.block) => epoch,
+        _ => Epoch {
+            expiration: config.epoch.after(&env.block),
+            spent: vec![],
+        },
+    };
+    let outflows = outflows(&msgs)?;
+    for limit in config.limits {
+        if let Some(outflow) = outflows.get(&limit.denom) {
+            let spent = epoch.spend(&limit.denom, *outflow)?;
+            if spent > limit.amount {
+                return Err(ContractError::LimitExceeded {
+                    denom: limit.denom,
+                    limit: limit.amount,
+                    spent,
+                });
+            }
+        }
+    }
+    EPOCH.save(deps.storage, &epoch)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "filter_messages")
+        .add_attribute("epoch_expiration", epoch.expiration.to_string()))
+}
+
+pub fn execute_update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    limits: Vec<Coin>,
+    epoch: Duration,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.dao {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    validate_config(&limits, epoch)?;
+    config.limits = limits;
+    config.epoch = epoch;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_config")
+        .add_attribute("epoch", epoch.to_string()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::Spending {} => query_spending(deps, env),
+    }
+}
+
+pub fn query_spending(deps: Deps, env: Env) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let epoch = EPOCH
+        .may_load(deps.storage)?
+        .filter(|epoch| !epoch.expiration.is_expired(&env.block));
+    let spent = epoch
+        .as_ref()
+        .map(|epoch| epoch.spent.clone())
+        .unwrap_or_default();
+    let remaining = config
+        .limits
+        .into_iter()
+        .map(|limit| {
+            let spent = spent
+                .iter()
+                .find(|coin| coin.denom == limit.denom)
+                .map(|coin| coin.amount)
+                .unwrap_or_default();
+            Coin {
+                amount: limit.amount.saturating_sub(spent),
+                denom: limit.denom,
+            }
+        })
+        .collect();
+    to_binary(&SpendingResponse {
+        expiration: epoch.map(|epoch| epoch.expiration),
+        spent,
+        remaining,
+    })
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    msg.check_stored_version(deps.storage, CONTRACT_VERSION)?;
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::default())
+}
@@ -0,0 +1,28 @@
+use cosmwasm_std::{StdError, Uint128};
+use dao_interface::migrate::MigrationError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    MigrationError(#[from] MigrationError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Epoch duration cannot be 0")]
+    ZeroEpoch {},
+
+    #[error("More than one limit was provided for ({denom})")]
+    DuplicateLimit { denom: String },
+
+    #[error("Spending ({spent}{denom}) this epoch would exceed the limit of ({limit}{denom})")]
+    LimitExceeded {
+        denom: String,
+        limit: Uint128,
+        spent: Uint128,
+    },
+}
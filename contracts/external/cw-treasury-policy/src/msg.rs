@@ -0,0 +1,50 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Coin;
+use cw_utils::{Duration, Expiration};
+use dao_interface::message_filter::MessageFilterMsg;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The DAO whose spending should be limited.
+    pub dao: String,
+    /// The maximum amount of each denom the DAO may spend per epoch.
+    pub limits: Vec<Coin>,
+    /// How long each epoch lasts.
+    pub epoch: Duration,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Sent by the DAO before it executes a proposal's messages.
+    /// Errors if the messages would take the amount of any denom
+    /// spent this epoch above its limit. Only callable by the DAO.
+    MessageFilterHook(MessageFilterMsg),
+    /// Updates the limits and epoch duration. Limits apply to the
+    /// current epoch immediately. The new duration applies from the
+    /// next epoch. Only callable by the DAO.
+    UpdateConfig { limits: Vec<Coin>, epoch: Duration },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(crate::state::Config)]
+    Config {},
+    /// Returns the amount of each limited denom spent and remaining
+    /// in the current epoch.
+    #[returns(SpendingResponse)]
+    Spending {},
+}
+
+#[cw_serde]
+pub struct SpendingResponse {
+    /// When the current epoch ends. None if no epoch is in progress,
+    /// in which case the next proposal executed begins one.
+    pub expiration: Option<Expiration>,
+    pub spent: Vec<Coin>,
+    /// The amount of each limited denom that may still be spent this
+    /// epoch.
+    pub remaining: Vec<Coin>,
+}
+
+pub use dao_interface::migrate::MigrateMsg;
@@ -0,0 +1,51 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin, StdResult, Uint128};
+use cw_storage_plus::Item;
+use cw_utils::{Duration, Expiration};
+
+#[cw_serde]
+pub struct Config {
+    /// The DAO whose spending is limited. Only the DAO may filter
+    /// messages or update the config.
+    pub dao: Addr,
+    /// The maximum amount of each denom the DAO may spend per
+    /// epoch. Denoms without a limit may be spent freely.
+    pub limits: Vec<Coin>,
+    /// How long each epoch lasts.
+    pub epoch: Duration,
+}
+
+/// The tokens spent during an epoch.
+#[cw_serde]
+pub struct Epoch {
+    /// When the epoch ends. The next proposal executed after this
+    /// begins a new epoch.
+    pub expiration: Expiration,
+    /// The amount of each limited denom spent during the epoch.
+    pub spent: Vec<Coin>,
+}
+
+impl Epoch {
+    /// Adds AMOUNT to the amount of DENOM spent during the epoch,
+    /// returning the new total.
+    pub fn spend(&mut self, denom: &str, amount: Uint128) -> StdResult<Uint128> {
+        match self.spent.iter_mut().find(|coin| coin.denom == denom) {
+            Some(coin) => {
+                coin.amount = coin.amount.checked_add(amount)?;
+                Ok(coin.amount)
+            }
+            None => {
+                self.spent.push(Coin {
+                    denom: denom.to_string(),
+                    amount,
+                });
+                Ok(amount)
+            }
+        }
+    }
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+/// The most recent epoch. May have expired.
+pub const EPOCH: Item<Epoch> = Item::new("epoch");
@@ -0,0 +1,237 @@
+use cosmwasm_std::testing::{
+    mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+};
+use cosmwasm_std::{
+    coin, coins, from_binary, BankMsg, CosmosMsg, Empty, Env, IbcMsg, IbcTimeout, OwnedDeps,
+    Uint128, WasmMsg,
+};
+use cw_utils::{Duration, Expiration};
+use dao_interface::{message_filter::MessageFilterMsg, migrate::MigrationError};
+
+use crate::contract::{
+    execute, instantiate, migrate, outflows, query, CONTRACT_NAME, CONTRACT_VERSION,
+};
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, SpendingResponse};
+use crate::ContractError;
+
+const DAO: &str = "dao";
+const EPOCH: u64 = 100;
+
+fn setup() -> OwnedDeps<MockStorage, MockApi, MockQuerier, Empty> {
+    let mut deps = mock_dependencies();
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        InstantiateMsg {
+            dao: DAO.to_string(),
+            limits: vec![coin(100, "ujuno"), coin(0, "uatom")],
+            epoch: Duration::Height(EPOCH),
+        },
+    )
+    .unwrap();
+    deps
+}
+
+fn send(amount: u128, denom: &str) -> CosmosMsg {
+    BankMsg::Send {
+        to_address: "recipient".to_string(),
+        amount: coins(amount, denom),
+    }
+    .into()
+}
+
+fn filter(
+    deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>,
+    env: Env,
+    msgs: Vec<CosmosMsg>,
+) -> Result<(), ContractError> {
+    execute(
+        deps.as_mut(),
+        env,
+        mock_info(DAO, &[]),
+        ExecuteMsg::MessageFilterHook(MessageFilterMsg::FilterMessages {
+            proposal_module: "proposal".to_string(),
+            msgs,
+        }),
+    )
+    .map(|_| ())
+}
+
+fn query_spending(
+    deps: &OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>,
+    env: Env,
+) -> SpendingResponse {
+    from_binary(&query(deps.as_ref(), env, QueryMsg::Spending {}).unwrap()).unwrap()
+}
+
+fn blocks_later(blocks: u64) -> Env {
+    let mut env = mock_env();
+    env.block.height += blocks;
+    env
+}
+
+#[test]
+fn test_instantiate_validation() {
+    let mut deps = mock_dependencies();
+    let err = instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        InstantiateMsg {
+            dao: DAO.to_string(),
+            limits: vec![coin(100, "ujuno")],
+            epoch: Duration::Time(0),
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::ZeroEpoch {}));
+
+    let err = instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        InstantiateMsg {
+            dao: DAO.to_string(),
+            limits: vec![coin(100, "ujuno"), coin(10, "ujuno")],
+            epoch: Duration::Time(10),
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::DuplicateLimit { denom } if denom == "ujuno"));
+}
+
+#[test]
+fn test_outflows() {
+    let totals = outflows(&[
+        send(1, "ujuno"),
+        BankMsg::Burn {
+            amount: coins(2, "ujuno"),
+        }
+        .into(),
+        WasmMsg::Execute {
+            contract_addr: "contract".to_string(),
+            msg: Default::default(),
+            funds: vec![coin(3, "ujuno"), coin(4, "uosmo")],
+        }
+        .into(),
+        IbcMsg::Transfer {
+            channel_id: "channel-0".to_string(),
+            to_address: "osmo1".to_string(),
+            amount: coin(5, "uosmo"),
+            timeout: IbcTimeout::with_timestamp(mock_env().block.time),
+        }
+        .into(),
+        WasmMsg::ClearAdmin {
+            contract_addr: "contract".to_string(),
+        }
+        .into(),
+    ])
+    .unwrap();
+    assert_eq!(
+        totals.into_iter().collect::<Vec<_>>(),
+        vec![
+            ("ujuno".to_string(), Uint128::new(6)),
+            ("uosmo".to_string(), Uint128::new(9)),
+        ]
+    );
+}
+
+#[test]
+fn test_spend_limits() {
+    let mut deps = setup();
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("proposal", &[]),
+        ExecuteMsg::MessageFilterHook(MessageFilterMsg::FilterMessages {
+            proposal_module: "proposal".to_string(),
+            msgs: vec![],
+        }),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    // Unlimited denoms may be spent freely.
+    filter(&mut deps, mock_env(), vec![send(1_000_000, "uosmo")]).unwrap();
+    let err = filter(&mut deps, mock_env(), vec![send(1, "uatom")]).unwrap_err();
+    assert!(matches!(err, ContractError::LimitExceeded { .. }));
+
+    filter(&mut deps, mock_env(), vec![send(60, "ujuno")]).unwrap();
+    let err = filter(
+        &mut deps,
+        blocks_later(1),
+        vec![send(30, "ujuno"), send(20, "ujuno")],
+    )
+    .unwrap_err();
+    assert!(matches!(
+        err,
+        ContractError::LimitExceeded { denom, limit, spent }
+            if denom == "ujuno" && limit == Uint128::new(100) && spent == Uint128::new(110)
+    ));
+    filter(&mut deps, blocks_later(1), vec![send(40, "ujuno")]).unwrap();
+
+    assert_eq!(
+        query_spending(&deps, blocks_later(1)),
+        SpendingResponse {
+            expiration: Some(Expiration::AtHeight(mock_env().block.height + EPOCH)),
+            spent: coins(100, "ujuno"),
+            remaining: vec![coin(0, "ujuno"), coin(0, "uatom")],
+        }
+    );
+
+    // Spending resets once the epoch ends.
+    assert_eq!(
+        query_spending(&deps, blocks_later(EPOCH)),
+        SpendingResponse {
+            expiration: None,
+            spent: vec![],
+            remaining: vec![coin(100, "ujuno"), coin(0, "uatom")],
+        }
+    );
+    filter(&mut deps, blocks_later(EPOCH), vec![send(100, "ujuno")]).unwrap();
+}
+
+#[test]
+fn test_update_config() {
+    let mut deps = setup();
+    filter(&mut deps, mock_env(), vec![send(60, "ujuno")]).unwrap();
+
+    let update = ExecuteMsg::UpdateConfig {
+        limits: vec![coin(50, "ujuno")],
+        epoch: Duration::Height(10),
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("someone", &[]),
+        update.clone(),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    execute(deps.as_mut(), mock_env(), mock_info(DAO, &[]), update).unwrap();
+
+    // New limits apply to the current epoch, which keeps its
+    // original length.
+    let err = filter(&mut deps, mock_env(), vec![send(1, "ujuno")]).unwrap_err();
+    assert!(matches!(err, ContractError::LimitExceeded { .. }));
+    filter(&mut deps, mock_env(), vec![send(1, "uatom")]).unwrap();
+    filter(&mut deps, blocks_later(EPOCH), vec![send(50, "ujuno")]).unwrap();
+    assert_eq!(
+        query_spending(&deps, blocks_later(EPOCH)).expiration,
+        Some(Expiration::AtHeight(mock_env().block.height + EPOCH + 10))
+    );
+}
+
+#[test]
+fn test_migrate_checks_stored_version() {
+    let mut deps = mock_dependencies();
+    cw2::set_contract_version(&mut deps.storage, CONTRACT_NAME, CONTRACT_VERSION).unwrap();
+    let err = migrate(deps.as_mut(), mock_env(), MigrateMsg::FromV1(Empty {})).unwrap_err();
+    assert!(matches!(
+        err,
+        ContractError::MigrationError(MigrationError::NotPreviousMajor { .. })
+    ));
+    migrate(deps.as_mut(), mock_env(), MigrateMsg::FromCompatible {}).unwrap();
+}
@@ -0,0 +1,354 @@
+use std::collections::HashSet;
+
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    coin, to_binary, BankMsg, Binary, Coin, CosmosMsg, Decimal, Deps, DepsMut, DistributionMsg,
+    Env, MessageInfo, Response, StakingMsg, StdResult, Uint128,
+};
+use cw2::set_contract_version;
+use cw_utils::must_pay;
+
+use crate::error::ContractError;
+use crate::msg::{
+    DelegationResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, ValidatorWeight,
+};
+use crate::state::{Config, CONFIG};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:cw-treasury-staking";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let dao = deps.api.addr_validate(&msg.dao)?;
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            dao: dao.clone(),
+            auto_restake: msg.auto_restake,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("dao", dao)
+        .add_attribute("auto_restake", msg.auto_restake.to_string()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.dao && !(config.auto_restake && msg == ExecuteMsg::Restake {}) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    match msg {
+        ExecuteMsg::Delegate { validator } => execute_delegate(deps, info, validator),
+        ExecuteMsg::Undelegate { validator, amount } => execute_undelegate(deps, validator, amount),
+        ExecuteMsg::Redelegate {
+            src_validator,
+            dst_validator,
+            amount,
+        } => execute_redelegate(deps, src_validator, dst_validator, amount),
+        ExecuteMsg::Rebalance { targets } => execute_rebalance(deps, env, targets),
+        ExecuteMsg::ClaimRewards {} => execute_claim_rewards(deps, env),
+        ExecuteMsg::Restake {} => execute_restake(deps, env),
+        ExecuteMsg::UpdateConfig { auto_restake } => {
+            execute_update_config(deps, config, auto_restake)
+        }
+        ExecuteMsg::Withdraw { amount } => execute_withdraw(config, amount),
+    }
+}
+
+pub fn execute_delegate(
+    deps: DepsMut,
+    info: MessageInfo,
+    validator: String,
+) -> Result<Response, ContractError> {
+    let denom = deps.querier.query_bonded_denom()?;
+    let amount = must_pay(&info, &denom)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "delegate")
+        .add_attribute("validator", &validator)
+        .add_attribute("amount", amount)
+        .add_message(StakingMsg::Delegate {
+            validator,
+            amount: coin(amount.u128(), denom),
+        }))
+}
+
+pub fn execute_undelegate(
+    deps: DepsMut,
+    validator: String,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    if amount.is_zero() {
+        return Err(ContractError::ZeroAmount {});
+    }
+    let denom = deps.querier.query_bonded_denom()?;
+
+    Ok(Response::new()
+        .add_attribute("action", "undelegate")
+        .add_attribute("validator", &validator)
+        .add_attribute("amount", amount)
+        .add_message(StakingMsg::Undelegate {
+            validator,
+            amount: coin(amount.u128(), denom),
+        }))
+}
+
+pub fn execute_redelegate(
+    deps: DepsMut,
+    src_validator: String,
+    dst_validator: String,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    if amount.is_zero() {
+        return Err(ContractError::ZeroAmount {});
+    }
+    let denom = deps.querier.query_bonded_denom()?;
+
+    Ok(Response::new()
+        .add_attribute("action", "redelegate")
+        .add_attribute("src_validator", &src_validator)
+        .add_attribute("dst_validator", &dst_validator)
+        .add_attribute("amount", amount)
+        .add_message(StakingMsg::Redelegate {
+            src_validator,
+            dst_validator,
+            amount: coin(amount.u128(), denom),
+        }))
+}
+
+pub fn execute_rebalance(
+    deps: DepsMut,
+    env: Env,
+    targets: Vec<ValidatorWeight>,
+) -> Result<Response, ContractError> {
+    let mut seen = HashSet::new();
+    let mut total_weight = Decimal::zero();
+    for ValidatorWeight { validator, weight } in targets.iter() {
+        if weight.is_zero() {
+            return Err(ContractError::InvalidWeights {});
+        }
+        if !seen.insert(validator.as_str()) {
+            return Err(ContractError::DuplicateValidator {
+                validator: validator.clone(),
+            });
+        }
+        total_weight += *weight;
+    }
+    if total_weight != Decimal::one() {
+        return Err(ContractError::InvalidWeights {});
+    }
+
+    let denom = deps.querier.query_bonded_denom()?;
+    let delegations: Vec<(String, Uint128)> = deps
+        .querier
+        .query_all_delegations(&env.contract.address)?
+        .into_iter()
+        .filter(|d| d.amount.denom == denom)
+        .map(|d| (d.validator, d.amount.amount))
+        .collect();
+    let total: Uint128 = delegations.iter().map(|(_, amount)| *amount).sum();
+    let current = |validator: &str| {
+        delegations
+            .iter()
+            .find(|(v, _)| v == validator)
+            .map(|(_, amount)| *amount)
+            .unwrap_or_default()
+    };
+
+    // Validators holding more than their target, and validators
+    // holding less.
+    let mut surplus: Vec<(String, Uint128)> = delegations
+        .iter()
+        .filter_map(|(validator, amount)| {
+            let target = targets
+                .iter()
+                .find(|t| &t.validator == validator)
+                .map(|t| total * t.weight)
+                .unwrap_or_default();
+            (*amount > target).then(|| (validator.clone(), *amount - target))
+        })
+        .collect();
+    let mut deficit: Vec<(String, Uint128)> = targets
+        .iter()
+        .filter_map(|t| {
+            let target = total * t.weight;
+            let amount = current(&t.validator);
+            (target > amount).then(|| (t.validator.clone(), target - amount))
+        })
+        .collect();
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    let (mut s, mut d) = (0, 0);
+    while s < surplus.len() && d < deficit.len() {
+        let amount = std::cmp::min(surplus[s].1, deficit[d].1);
+        messages.push(
+            StakingMsg::Redelegate {
+                src_validator: surplus[s].0.clone(),
+                dst_validator: deficit[d].0.clone(),
+                amount: coin(amount.u128(), &denom),
+            }
+            .into(),
+        );
+        surplus[s].1 -= amount;
+        deficit[d].1 -= amount;
+        if surplus[s].1.is_zero() {
+            s += 1;
+        }
+        if deficit[d].1.is_zero() {
+            d += 1;
+        }
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "rebalance")
+        .add_attribute("redelegations", messages.len().to_string())
+        .add_messages(messages))
+}
+
+pub fn execute_claim_rewards(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let messages: Vec<CosmosMsg> = deps
+        .querier
+        .query_all_delegations(&env.contract.address)?
+        .into_iter()
+        .map(|d| {
+            DistributionMsg::WithdrawDelegatorReward {
+                validator: d.validator,
+            }
+            .into()
+        })
+        .collect();
+
+    Ok(Response::new()
+        .add_attribute("action", "claim_rewards")
+        .add_messages(messages))
+}
+
+pub fn execute_restake(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let denom = deps.querier.query_bonded_denom()?;
+    let mut messages: Vec<CosmosMsg> = vec![];
+    let mut restaked = Uint128::zero();
+    for delegation in deps.querier.query_all_delegations(&env.contract.address)? {
+        let reward = deps
+            .querier
+            .query_delegation(&env.contract.address, &delegation.validator)?
+            .and_then(|full| {
+                full.accumulated_rewards
+                    .into_iter()
+                    .find(|c| c.denom == denom)
+                    .map(|c| c.amount)
+            })
+            .unwrap_or_default();
+        if reward.is_zero() {
+            continue;
+        }
+        messages.push(
+            DistributionMsg::WithdrawDelegatorReward {
+                validator: delegation.validator.clone(),
+            }
+            .into(),
+        );
+        messages.push(
+            StakingMsg::Delegate {
+                validator: delegation.validator,
+                amount: coin(reward.u128(), &denom),
+            }
+            .into(),
+        );
+        restaked += reward;
+    }
+    if restaked.is_zero() {
+        return Err(ContractError::NothingToRestake {});
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "restake")
+        .add_attribute("amount", restaked)
+        .add_messages(messages))
+}
+
+pub fn execute_update_config(
+    deps: DepsMut,
+    mut config: Config,
+    auto_restake: bool,
+) -> Result<Response, ContractError> {
+    config.auto_restake = auto_restake;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_config")
+        .add_attribute("auto_restake", auto_restake.to_string()))
+}
+
+pub fn execute_withdraw(config: Config, amount: Vec<Coin>) -> Result<Response, ContractError> {
+    Ok(Response::new()
+        .add_attribute("action", "withdraw")
+        .add_message(BankMsg::Send {
+            to_address: config.dao.into_string(),
+            amount,
+        }))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::Delegations {} => to_binary(&query_delegations(deps, env)?),
+        QueryMsg::TotalRewards {} => query_total_rewards(deps, env),
+    }
+}
+
+pub fn query_delegations(deps: Deps, env: Env) -> StdResult<Vec<DelegationResponse>> {
+    deps.querier
+        .query_all_delegations(&env.contract.address)?
+        .into_iter()
+        .map(|delegation| {
+            let accumulated_rewards = deps
+                .querier
+                .query_delegation(&env.contract.address, &delegation.validator)?
+                .map(|full| full.accumulated_rewards)
+                .unwrap_or_default();
+            Ok(DelegationResponse {
+                validator: delegation.validator,
+                amount: delegation.amount,
+                accumulated_rewards,
+            })
+        })
+        .collect()
+}
+
+pub fn query_total_rewards(deps: Deps, env: Env) -> StdResult<Binary> {
+    let mut totals: Vec<Coin> = vec![];
+    for reward in query_delegations(deps, env)?
+        .into_iter()
+        .flat_map(|d| d.accumulated_rewards)
+    {
+        match totals.iter_mut().find(|c| c.denom == reward.denom) {
+            Some(total) => total.amount += reward.amount,
+            None => totals.push(reward),
+        }
+    }
+    to_binary(&totals)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::default())
+}
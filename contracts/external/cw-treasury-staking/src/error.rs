@@ -0,0 +1,27 @@
+use cosmwasm_std::StdError;
+use cw_utils::PaymentError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    PaymentError(#[from] PaymentError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Amount must be non-zero")]
+    ZeroAmount {},
+
+    #[error("Validator weights must be non-zero and sum to one")]
+    InvalidWeights {},
+
+    #[error("Validator ({validator}) appears more than once in rebalance targets")]
+    DuplicateValidator { validator: String },
+
+    #[error("Nothing to restake")]
+    NothingToRestake {},
+}
@@ -0,0 +1,78 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Coin, Decimal, Uint128};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The DAO that owns this contract's funds.
+    pub dao: String,
+    pub auto_restake: bool,
+}
+
+#[cw_serde]
+pub struct ValidatorWeight {
+    pub validator: String,
+    pub weight: Decimal,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Delegates the bonded denom sent with this message to
+    /// VALIDATOR.
+    Delegate {
+        validator: String,
+    },
+    /// Begins unbonding AMOUNT from VALIDATOR. Once unbonded, funds
+    /// may be returned to the DAO with `Withdraw`.
+    Undelegate {
+        validator: String,
+        amount: Uint128,
+    },
+    Redelegate {
+        src_validator: String,
+        dst_validator: String,
+        amount: Uint128,
+    },
+    /// Redelegates the contract's stake so that each validator holds
+    /// its weight of the total. Validators not listed are fully
+    /// redelegated away from. Weights must sum to one.
+    Rebalance {
+        targets: Vec<ValidatorWeight>,
+    },
+    /// Withdraws rewards from every validator into this contract.
+    ClaimRewards {},
+    /// Withdraws rewards from every validator and delegates the
+    /// bonded denom portion back to the validator it was earned
+    /// from. Callable by anyone if `auto_restake` is set.
+    Restake {},
+    UpdateConfig {
+        auto_restake: bool,
+    },
+    /// Sends liquid funds held by this contract to the DAO.
+    Withdraw {
+        amount: Vec<Coin>,
+    },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(crate::state::Config)]
+    Config {},
+    /// Lists the contract's delegations along with their pending
+    /// rewards.
+    #[returns(Vec<DelegationResponse>)]
+    Delegations {},
+    /// Returns the sum of pending rewards across all delegations.
+    #[returns(Vec<Coin>)]
+    TotalRewards {},
+}
+
+#[cw_serde]
+pub struct DelegationResponse {
+    pub validator: String,
+    pub amount: Coin,
+    pub accumulated_rewards: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
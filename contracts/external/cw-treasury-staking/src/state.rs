@@ -0,0 +1,14 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Addr;
+use cw_storage_plus::Item;
+
+#[cw_serde]
+pub struct Config {
+    /// The DAO that owns the funds managed by this contract.
+    pub dao: Addr,
+    /// If true, anyone may call `Restake` to claim and re-delegate
+    /// staking rewards. Otherwise only the DAO may.
+    pub auto_restake: bool,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
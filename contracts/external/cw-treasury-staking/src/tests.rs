@@ -0,0 +1,220 @@
+use cosmwasm_std::testing::{
+    mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage, MOCK_CONTRACT_ADDR,
+};
+use cosmwasm_std::{
+    coin, coins, from_binary, Addr, BankMsg, CosmosMsg, Decimal, DistributionMsg, Empty,
+    FullDelegation, OwnedDeps, StakingMsg, Uint128, Validator,
+};
+
+use crate::contract::{execute, instantiate, query};
+use crate::msg::{DelegationResponse, ExecuteMsg, InstantiateMsg, QueryMsg, ValidatorWeight};
+use crate::ContractError;
+
+const DAO: &str = "dao";
+const DENOM: &str = "ujuno";
+const VAL1: &str = "val1";
+const VAL2: &str = "val2";
+const VAL3: &str = "val3";
+
+fn validator(address: &str) -> Validator {
+    Validator {
+        address: address.to_string(),
+        commission: Decimal::percent(5),
+        max_commission: Decimal::percent(10),
+        max_change_rate: Decimal::percent(1),
+    }
+}
+
+fn delegation(validator: &str, amount: u128, rewards: u128) -> FullDelegation {
+    FullDelegation {
+        delegator: Addr::unchecked(MOCK_CONTRACT_ADDR),
+        validator: validator.to_string(),
+        amount: coin(amount, DENOM),
+        can_redelegate: coin(amount, DENOM),
+        accumulated_rewards: coins(rewards, DENOM),
+    }
+}
+
+fn setup(
+    auto_restake: bool,
+    delegations: &[FullDelegation],
+) -> OwnedDeps<MockStorage, MockApi, MockQuerier, Empty> {
+    let mut deps = mock_dependencies();
+    deps.querier.update_staking(
+        DENOM,
+        &[validator(VAL1), validator(VAL2), validator(VAL3)],
+        delegations,
+    );
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        InstantiateMsg {
+            dao: DAO.to_string(),
+            auto_restake,
+        },
+    )
+    .unwrap();
+    deps
+}
+
+#[test]
+fn test_delegate_and_auth() {
+    let mut deps = setup(false, &[]);
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("ekez", &coins(10, DENOM)),
+        ExecuteMsg::Delegate {
+            validator: VAL1.to_string(),
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &coins(10, DENOM)),
+        ExecuteMsg::Delegate {
+            validator: VAL1.to_string(),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Staking(StakingMsg::Delegate {
+            validator: VAL1.to_string(),
+            amount: coin(10, DENOM),
+        })
+    );
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        ExecuteMsg::Withdraw {
+            amount: coins(5, DENOM),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: DAO.to_string(),
+            amount: coins(5, DENOM),
+        })
+    );
+}
+
+#[test]
+fn test_rebalance() {
+    let mut deps = setup(false, &[delegation(VAL1, 90, 0), delegation(VAL2, 10, 0)]);
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        ExecuteMsg::Rebalance {
+            targets: vec![ValidatorWeight {
+                validator: VAL1.to_string(),
+                weight: Decimal::percent(50),
+            }],
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidWeights {}));
+
+    // 90/10/0 -> 0/50/50
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        ExecuteMsg::Rebalance {
+            targets: vec![
+                ValidatorWeight {
+                    validator: VAL2.to_string(),
+                    weight: Decimal::percent(50),
+                },
+                ValidatorWeight {
+                    validator: VAL3.to_string(),
+                    weight: Decimal::percent(50),
+                },
+            ],
+        },
+    )
+    .unwrap();
+    let msgs: Vec<_> = res.messages.into_iter().map(|m| m.msg).collect();
+    assert_eq!(
+        msgs,
+        vec![
+            CosmosMsg::Staking(StakingMsg::Redelegate {
+                src_validator: VAL1.to_string(),
+                dst_validator: VAL2.to_string(),
+                amount: coin(40, DENOM),
+            }),
+            CosmosMsg::Staking(StakingMsg::Redelegate {
+                src_validator: VAL1.to_string(),
+                dst_validator: VAL3.to_string(),
+                amount: coin(50, DENOM),
+            }),
+        ]
+    );
+}
+
+#[test]
+fn test_restake_and_rewards_queries() {
+    let mut deps = setup(true, &[delegation(VAL1, 100, 7), delegation(VAL2, 100, 3)]);
+
+    let delegations: Vec<DelegationResponse> =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Delegations {}).unwrap()).unwrap();
+    assert_eq!(delegations.len(), 2);
+    assert_eq!(delegations[0].accumulated_rewards, coins(7, DENOM));
+
+    let total: Vec<cosmwasm_std::Coin> =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::TotalRewards {}).unwrap()).unwrap();
+    assert_eq!(total, coins(10, DENOM));
+
+    // Anyone may restake when auto restaking is enabled.
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("ekez", &[]),
+        ExecuteMsg::Restake {},
+    )
+    .unwrap();
+    let msgs: Vec<_> = res.messages.into_iter().map(|m| m.msg).collect();
+    assert_eq!(
+        msgs,
+        vec![
+            CosmosMsg::Distribution(DistributionMsg::WithdrawDelegatorReward {
+                validator: VAL1.to_string()
+            }),
+            CosmosMsg::Staking(StakingMsg::Delegate {
+                validator: VAL1.to_string(),
+                amount: coin(7, DENOM),
+            }),
+            CosmosMsg::Distribution(DistributionMsg::WithdrawDelegatorReward {
+                validator: VAL2.to_string()
+            }),
+            CosmosMsg::Staking(StakingMsg::Delegate {
+                validator: VAL2.to_string(),
+                amount: coin(3, DENOM),
+            }),
+        ]
+    );
+
+    // But nothing else.
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("ekez", &[]),
+        ExecuteMsg::Undelegate {
+            validator: VAL1.to_string(),
+            amount: Uint128::new(1),
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+}
@@ -0,0 +1,324 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Response,
+    StdResult, WasmMsg,
+};
+use cw2::set_contract_version;
+use cw_utils::Expiration;
+use dao_interface::{proposal::GenericProposalInfoResponse, querier::CoreQuerier};
+use dao_proposal_single::{
+    msg::{ExecuteMsg as ProposalExecuteMsg, QueryMsg as ProposalQueryMsg},
+    query::{ProposalListResponse, ProposalResponse, VoteListResponse, VoteResponse},
+    state::Config as ProposalConfig,
+};
+use dao_voting::proposal::SingleChoiceProposeMsg;
+
+use crate::convert::{from_cw3_vote, to_proposal, to_threshold, to_vote_info, to_weight};
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::state::{DAO, PROPOSAL_MODULE};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:cw3-adapter";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The adapter is instantiated by the proposal module it fronts as
+/// that module's pre-propose module. This allows it to set the
+/// proposer of the proposals it creates.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    _msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    let dao: Addr = deps
+        .querier
+        .query_wasm_smart(&info.sender, &ProposalQueryMsg::Dao {})?;
+    PROPOSAL_MODULE.save(deps.storage, &info.sender)?;
+    DAO.save(deps.storage, &dao)?;
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("proposal_module", info.sender)
+        .add_attribute("dao", dao))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Propose {
+            title,
+            description,
+            msgs,
+            earliest,
+            latest,
+        } => execute_propose(deps, info, title, description, msgs, earliest, latest),
+        ExecuteMsg::Vote { proposal_id, vote } => execute_vote(deps, info, proposal_id, vote),
+        ExecuteMsg::Execute { proposal_id } => execute_forward(
+            deps,
+            "execute",
+            proposal_id,
+            ProposalExecuteMsg::Execute { proposal_id },
+        ),
+        ExecuteMsg::Close { proposal_id } => execute_forward(
+            deps,
+            "close",
+            proposal_id,
+            ProposalExecuteMsg::Close { proposal_id },
+        ),
+        ExecuteMsg::ProposalCompletedHook { proposal_id, .. } => {
+            execute_proposal_completed_hook(deps, info, proposal_id)
+        }
+    }
+}
+
+pub fn execute_propose(
+    deps: DepsMut,
+    info: MessageInfo,
+    title: String,
+    description: String,
+    msgs: Vec<CosmosMsg<Empty>>,
+    earliest: Option<Expiration>,
+    latest: Option<Expiration>,
+) -> Result<Response, ContractError> {
+    if earliest.is_some() || latest.is_some() {
+        return Err(ContractError::CustomExpiration {});
+    }
+    // Like a cw3 multisig, only voters may create proposals.
+    let power = CoreQuerier::new(deps.querier, DAO.load(deps.storage)?)
+        .voting_power_at_height(&info.sender, None)?;
+    if power.power.is_zero() {
+        return Err(ContractError::NotVoter {});
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "propose")
+        .add_attribute("proposer", &info.sender)
+        .add_message(WasmMsg::Execute {
+            contract_addr: PROPOSAL_MODULE.load(deps.storage)?.into_string(),
+            msg: to_binary(&ProposalExecuteMsg::Propose(SingleChoiceProposeMsg {
+                title,
+                description,
+                msgs,
+                proposer: Some(info.sender.into_string()),
+                description_hash: None,
+                content_cid: None,
+                disable_revoting: false,
+            }))?,
+            funds: vec![],
+        }))
+}
+
+/// Casts the sender's vote through the proposal module's `VoteFor`,
+/// which only the adapter, as the proposal module's pre-propose
+/// module, may call.
+pub fn execute_vote(
+    deps: DepsMut,
+    info: MessageInfo,
+    proposal_id: u64,
+    vote: cw3::Vote,
+) -> Result<Response, ContractError> {
+    let vote = from_cw3_vote(vote)?;
+    Ok(Response::new()
+        .add_attribute("action", "vote")
+        .add_attribute("voter", &info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_message(WasmMsg::Execute {
+            contract_addr: PROPOSAL_MODULE.load(deps.storage)?.into_string(),
+            msg: to_binary(&ProposalExecuteMsg::VoteFor {
+                voter: info.sender.into_string(),
+                proposal_id,
+                vote,
+                rationale: None,
+            })?,
+            funds: vec![],
+        }))
+}
+
+pub fn execute_forward(
+    deps: DepsMut,
+    action: &str,
+    proposal_id: u64,
+    msg: ProposalExecuteMsg,
+) -> Result<Response, ContractError> {
+    Ok(Response::new()
+        .add_attribute("action", action)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_message(WasmMsg::Execute {
+            contract_addr: PROPOSAL_MODULE.load(deps.storage)?.into_string(),
+            msg: to_binary(&msg)?,
+            funds: vec![],
+        }))
+}
+
+/// The adapter takes no deposits so there is nothing to do when a
+/// proposal completes. Erroring here would cause the proposal module
+/// to remove the adapter, so this only checks the sender.
+pub fn execute_proposal_completed_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    if info.sender != PROPOSAL_MODULE.load(deps.storage)? {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(Response::new()
+        .add_attribute("action", "proposal_completed_hook")
+        .add_attribute("proposal_id", proposal_id.to_string()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Threshold {} => to_binary(&query_threshold(deps)?),
+        QueryMsg::Proposal { proposal_id } => {
+            let proposal: ProposalResponse = deps.querier.query_wasm_smart(
+                PROPOSAL_MODULE.load(deps.storage)?,
+                &ProposalQueryMsg::Proposal { proposal_id },
+            )?;
+            to_binary(&to_proposal(proposal)?)
+        }
+        QueryMsg::ListProposals { start_after, limit } => to_binary(&query_proposals(
+            deps,
+            ProposalQueryMsg::ListProposals {
+                start_after,
+                limit: limit.map(u64::from),
+                status: None,
+            },
+        )?),
+        QueryMsg::ReverseProposals {
+            start_before,
+            limit,
+        } => to_binary(&query_proposals(
+            deps,
+            ProposalQueryMsg::ReverseProposals {
+                start_before,
+                limit: limit.map(u64::from),
+                status: None,
+            },
+        )?),
+        QueryMsg::Vote { proposal_id, voter } => {
+            let vote: VoteResponse = deps.querier.query_wasm_smart(
+                PROPOSAL_MODULE.load(deps.storage)?,
+                &ProposalQueryMsg::GetVote { proposal_id, voter },
+            )?;
+            to_binary(&cw3::VoteResponse {
+                vote: vote
+                    .vote
+                    .map(|vote| to_vote_info(proposal_id, vote))
+                    .transpose()?,
+            })
+        }
+        QueryMsg::ListVotes {
+            proposal_id,
+            start_after,
+            limit,
+        } => {
+            let votes: VoteListResponse = deps.querier.query_wasm_smart(
+                PROPOSAL_MODULE.load(deps.storage)?,
+                &ProposalQueryMsg::ListVotes {
+                    proposal_id,
+                    start_after,
+                    limit: limit.map(u64::from),
+                    order: None,
+                },
+            )?;
+            to_binary(&cw3::VoteListResponse {
+                votes: votes
+                    .votes
+                    .into_iter()
+                    .map(|vote| to_vote_info(proposal_id, vote))
+                    .collect::<StdResult<_>>()?,
+            })
+        }
+        QueryMsg::Voter { address } => to_binary(&query_voter(deps, address)?),
+        QueryMsg::ListVoters { start_after, limit } => {
+            to_binary(&query_list_voters(deps, start_after, limit)?)
+        }
+        QueryMsg::ProposalModule {} => to_binary(&PROPOSAL_MODULE.load(deps.storage)?),
+        QueryMsg::Dao {} => to_binary(&DAO.load(deps.storage)?),
+        QueryMsg::GenericProposalInfo { proposal_id } => {
+            let info: GenericProposalInfoResponse = deps.querier.query_wasm_smart(
+                PROPOSAL_MODULE.load(deps.storage)?,
+                &ProposalQueryMsg::GenericProposalInfo { proposal_id },
+            )?;
+            to_binary(&info)
+        }
+    }
+}
+
+/// Reports the proposal module's threshold for proposals created now.
+pub fn query_threshold(deps: Deps) -> StdResult<cw3::ThresholdResponse> {
+    let config: ProposalConfig = deps.querier.query_wasm_smart(
+        PROPOSAL_MODULE.load(deps.storage)?,
+        &ProposalQueryMsg::Config {},
+    )?;
+    let total =
+        CoreQuerier::new(deps.querier, DAO.load(deps.storage)?).total_power_at_height(None)?;
+    to_threshold(config.threshold, total.power)
+}
+
+pub fn query_proposals(deps: Deps, msg: ProposalQueryMsg) -> StdResult<cw3::ProposalListResponse> {
+    let proposals: ProposalListResponse = deps
+        .querier
+        .query_wasm_smart(PROPOSAL_MODULE.load(deps.storage)?, &msg)?;
+    Ok(cw3::ProposalListResponse {
+        proposals: proposals
+            .proposals
+            .into_iter()
+            .map(to_proposal)
+            .collect::<StdResult<_>>()?,
+    })
+}
+
+pub fn query_voter(deps: Deps, address: String) -> StdResult<cw3::VoterResponse> {
+    let power = CoreQuerier::new(deps.querier, DAO.load(deps.storage)?)
+        .voting_power_at_height(address, None)?;
+    // Matching cw3 multisigs, addresses without voting power are not
+    // voters.
+    let weight = if power.power.is_zero() {
+        None
+    } else {
+        Some(to_weight(power.power)?)
+    };
+    Ok(cw3::VoterResponse { weight })
+}
+
+/// Voting modules have no standard way to list voters, so this lists
+/// the members of the cw4 group backing a dao-voting-cw4 module.
+pub fn query_list_voters(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<cw3::VoterListResponse> {
+    let voting_module = CoreQuerier::new(deps.querier, DAO.load(deps.storage)?).voting_module()?;
+    let group: Addr = deps.querier.query_wasm_smart(
+        voting_module,
+        &dao_voting_cw4::msg::QueryMsg::GroupContract {},
+    )?;
+    let members: cw4::MemberListResponse = deps
+        .querier
+        .query_wasm_smart(group, &cw4::Cw4QueryMsg::ListMembers { start_after, limit })?;
+    Ok(cw3::VoterListResponse {
+        voters: members
+            .members
+            .into_iter()
+            .map(|member| cw3::VoterDetail {
+                addr: member.addr,
+                weight: member.weight,
+            })
+            .collect(),
+    })
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::default())
+}
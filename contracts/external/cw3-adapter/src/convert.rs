@@ -0,0 +1,110 @@
+//! Conversions between dao-proposal-single types and their cw3
+//! equivalents.
+
+use cosmwasm_std::{Decimal, StdError, StdResult, Uint128};
+use dao_proposal_single::query::{ProposalResponse, VoteInfo};
+use dao_voting::{
+    status::Status,
+    threshold::{PercentageThreshold, Threshold},
+    voting::Vote,
+};
+
+use crate::error::ContractError;
+
+/// cw3 uses u64 weights while DAO voting power is a Uint128. Errors
+/// rather than truncating if voting power does not fit.
+pub(crate) fn to_weight(power: Uint128) -> StdResult<u64> {
+    u64::try_from(power.u128()).map_err(|_| {
+        StdError::generic_err(format!("voting power ({power}) overflows a cw3 weight"))
+    })
+}
+
+/// cw3 has no notion of a strict majority, so `Majority` is reported
+/// as 50%.
+fn to_percentage(percentage: PercentageThreshold) -> Decimal {
+    match percentage {
+        PercentageThreshold::Majority {} => Decimal::percent(50),
+        PercentageThreshold::Percent(percent) => percent,
+    }
+}
+
+pub(crate) fn to_threshold(
+    threshold: Threshold,
+    total_power: Uint128,
+) -> StdResult<cw3::ThresholdResponse> {
+    let total_weight = to_weight(total_power)?;
+    Ok(match threshold {
+        Threshold::AbsoluteCount { threshold } => cw3::ThresholdResponse::AbsoluteCount {
+            weight: to_weight(threshold)?,
+            total_weight,
+        },
+        Threshold::AbsolutePercentage { percentage } => {
+            cw3::ThresholdResponse::AbsolutePercentage {
+                percentage: to_percentage(percentage),
+                total_weight,
+            }
+        }
+        Threshold::ThresholdQuorum { threshold, quorum } => {
+            cw3::ThresholdResponse::ThresholdQuorum {
+                threshold: to_percentage(threshold),
+                quorum: to_percentage(quorum),
+                total_weight,
+            }
+        }
+    })
+}
+
+/// cw3 has no closed, cancelled, or execution failed statuses. Closed
+/// and cancelled proposals were not passed and proposals whose
+/// execution failed were executed, so they are reported as such.
+pub(crate) fn to_status(status: Status) -> cw3::Status {
+    match status {
+        Status::Open => cw3::Status::Open,
+        Status::Rejected | Status::Closed | Status::Cancelled => cw3::Status::Rejected,
+        Status::Passed => cw3::Status::Passed,
+        Status::Executed | Status::ExecutionFailed => cw3::Status::Executed,
+    }
+}
+
+pub(crate) fn to_vote(vote: Vote) -> cw3::Vote {
+    match vote {
+        Vote::Yes => cw3::Vote::Yes,
+        Vote::No => cw3::Vote::No,
+        Vote::Abstain => cw3::Vote::Abstain,
+    }
+}
+
+/// The proposal module has no veto, and treating one as a no would
+/// misreport the voter's intent, so vetoes error.
+pub(crate) fn from_cw3_vote(vote: cw3::Vote) -> Result<Vote, ContractError> {
+    match vote {
+        cw3::Vote::Yes => Ok(Vote::Yes),
+        cw3::Vote::No => Ok(Vote::No),
+        cw3::Vote::Abstain => Ok(Vote::Abstain),
+        cw3::Vote::Veto => Err(ContractError::Veto {}),
+    }
+}
+
+pub(crate) fn to_proposal(response: ProposalResponse) -> StdResult<cw3::ProposalResponse> {
+    let ProposalResponse { id, proposal } = response;
+    Ok(cw3::ProposalResponse {
+        id,
+        title: proposal.title,
+        description: proposal.description,
+        msgs: proposal.msgs,
+        status: to_status(proposal.status),
+        expires: proposal.expiration,
+        threshold: to_threshold(proposal.threshold, proposal.total_power)?,
+        proposer: proposal.proposer,
+        deposit: None,
+    })
+}
+
+pub(crate) fn to_vote_info(proposal_id: u64, info: VoteInfo) -> StdResult<cw3::VoteInfo> {
+    Ok(cw3::VoteInfo {
+        proposal_id,
+        voter: info.voter.into_string(),
+        vote: to_vote(info.vote),
+        weight: to_weight(info.power)?,
+    })
+}
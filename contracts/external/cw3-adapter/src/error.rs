@@ -0,0 +1,20 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Only addresses with voting power may create proposals")]
+    NotVoter {},
+
+    #[error("Proposal expiration is set by the proposal module and may not be specified")]
+    CustomExpiration {},
+
+    #[error("Veto votes are not supported, vote no instead")]
+    Veto {},
+}
@@ -0,0 +1,89 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{CosmosMsg, Empty};
+use cw3::Vote;
+use cw_utils::Expiration;
+use dao_voting::status::Status;
+
+#[cw_serde]
+pub struct InstantiateMsg {}
+
+/// The cw3 execute messages, plus the hook the proposal module sends
+/// to its pre-propose module.
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Creates a proposal on the proposal module with the sender as
+    /// the proposer. Only addresses with voting power may propose.
+    Propose {
+        title: String,
+        description: String,
+        msgs: Vec<CosmosMsg<Empty>>,
+        /// Must be None. The proposal module sets proposal
+        /// expiration.
+        earliest: Option<Expiration>,
+        /// Must be None. The proposal module sets proposal
+        /// expiration.
+        latest: Option<Expiration>,
+    },
+    /// Votes on a proposal with the sender's voting power. Errors on
+    /// `Veto`, which the proposal module does not support.
+    Vote { proposal_id: u64, vote: Vote },
+    /// Executes a passed proposal.
+    Execute { proposal_id: u64 },
+    /// Closes a rejected proposal.
+    Close { proposal_id: u64 },
+    /// Sent by the proposal module when a proposal completes.
+    ProposalCompletedHook {
+        proposal_id: u64,
+        new_status: Status,
+    },
+}
+
+/// The cw3 queries.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(cw3::ThresholdResponse)]
+    Threshold {},
+    #[returns(cw3::ProposalResponse)]
+    Proposal { proposal_id: u64 },
+    #[returns(cw3::ProposalListResponse)]
+    ListProposals {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(cw3::ProposalListResponse)]
+    ReverseProposals {
+        start_before: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(cw3::VoteResponse)]
+    Vote { proposal_id: u64, voter: String },
+    #[returns(cw3::VoteListResponse)]
+    ListVotes {
+        proposal_id: u64,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(cw3::VoterResponse)]
+    Voter { address: String },
+    /// Lists voters. Only supported when the DAO uses a cw4 voting
+    /// module.
+    #[returns(cw3::VoterListResponse)]
+    ListVoters {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Returns the proposal module this adapter fronts.
+    #[returns(::cosmwasm_std::Addr)]
+    ProposalModule {},
+    /// Returns the DAO the proposal module belongs to.
+    #[returns(::cosmwasm_std::Addr)]
+    Dao {},
+    /// Returns the proposal module's generic information about a
+    /// proposal.
+    #[returns(::dao_interface::proposal::GenericProposalInfoResponse)]
+    GenericProposalInfo { proposal_id: u64 },
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
@@ -0,0 +1,9 @@
+use cosmwasm_std::Addr;
+use cw_storage_plus::Item;
+
+/// The proposal module this adapter fronts. This is the contract that
+/// instantiated the adapter.
+pub const PROPOSAL_MODULE: Item<Addr> = Item::new("proposal_module");
+
+/// The DAO the proposal module belongs to.
+pub const DAO: Item<Addr> = Item::new("dao");
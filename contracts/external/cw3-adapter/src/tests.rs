@@ -0,0 +1,303 @@
+use cosmwasm_std::testing::{
+    mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+};
+use cosmwasm_std::{
+    from_binary, from_slice, to_binary, Addr, BankMsg, ContractResult, CosmosMsg, Decimal, Empty,
+    OwnedDeps, SystemResult, Uint128, WasmMsg, WasmQuery,
+};
+use cw_utils::Expiration;
+use dao_interface::{proposal::GenericProposalInfoResponse, voting::VotingPowerAtHeightResponse};
+use dao_proposal_single::{
+    msg::{ExecuteMsg as ProposalExecuteMsg, QueryMsg as ProposalQueryMsg},
+    proposal::SingleChoiceProposal,
+    query::ProposalResponse,
+};
+use dao_voting::{
+    proposal::SingleChoiceProposeMsg,
+    status::Status,
+    threshold::{AbstainCounting, PercentageThreshold, Threshold},
+    voting::{Vote, Votes},
+};
+
+use crate::contract::{execute, instantiate, query};
+use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::ContractError;
+
+const DAO: &str = "dao";
+const PROPOSAL_MODULE: &str = "proposal_module";
+const MEMBER: &str = "member";
+const NON_MEMBER: &str = "nonmember";
+
+fn proposal() -> SingleChoiceProposal {
+    SingleChoiceProposal {
+        title: "title".to_string(),
+        description: "description".to_string(),
+        proposer: Addr::unchecked(MEMBER),
+        start_height: 10,
+        min_voting_period: None,
+        expiration: Expiration::AtHeight(100),
+        threshold: Threshold::AbsolutePercentage {
+            percentage: PercentageThreshold::Majority {},
+        },
+        total_power: Uint128::new(30),
+        msgs: vec![],
+        status: Status::Closed,
+        votes: Votes::zero(),
+        allow_revoting: false,
+        abstain_counting: AbstainCounting::default(),
+        execution_delay: None,
+        earliest_execution: None,
+        vote_extension: None,
+        max_expiration: None,
+        description_hash: None,
+        content_cid: None,
+    }
+}
+
+fn setup() -> OwnedDeps<MockStorage, MockApi, MockQuerier, Empty> {
+    let mut deps = mock_dependencies();
+    deps.querier.update_wasm(|query| match query {
+        WasmQuery::Smart { contract_addr, msg } if contract_addr == DAO => {
+            let power = match from_slice(msg).unwrap() {
+                dao_core::msg::QueryMsg::VotingPowerAtHeight { address, .. }
+                    if address == MEMBER =>
+                {
+                    Uint128::new(10)
+                }
+                _ => Uint128::zero(),
+            };
+            SystemResult::Ok(ContractResult::Ok(
+                to_binary(&VotingPowerAtHeightResponse { power, height: 0 }).unwrap(),
+            ))
+        }
+        WasmQuery::Smart { contract_addr, msg } if contract_addr == PROPOSAL_MODULE => {
+            let response = match from_slice(msg).unwrap() {
+                ProposalQueryMsg::Dao {} => to_binary(&Addr::unchecked(DAO)),
+                ProposalQueryMsg::Proposal { proposal_id } => to_binary(&ProposalResponse {
+                    id: proposal_id,
+                    proposal: proposal(),
+                }),
+                ProposalQueryMsg::GenericProposalInfo { .. } => {
+                    let proposal = proposal();
+                    to_binary(&GenericProposalInfoResponse {
+                        proposer: proposal.proposer,
+                        status: proposal.status.to_string(),
+                        expiration: proposal.expiration,
+                        created: proposal.start_height,
+                    })
+                }
+                _ => panic!("unexpected query"),
+            };
+            SystemResult::Ok(ContractResult::Ok(response.unwrap()))
+        }
+        _ => panic!("unexpected query"),
+    });
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(PROPOSAL_MODULE, &[]),
+        InstantiateMsg {},
+    )
+    .unwrap();
+    deps
+}
+
+#[test]
+fn test_instantiate() {
+    let deps = setup();
+    let dao: Addr =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Dao {}).unwrap()).unwrap();
+    assert_eq!(dao, Addr::unchecked(DAO));
+    let module: Addr =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::ProposalModule {}).unwrap())
+            .unwrap();
+    assert_eq!(module, Addr::unchecked(PROPOSAL_MODULE));
+}
+
+#[test]
+fn test_propose() {
+    let mut deps = setup();
+    let msgs: Vec<CosmosMsg> = vec![BankMsg::Burn { amount: vec![] }.into()];
+    let propose = |earliest, latest| ExecuteMsg::Propose {
+        title: "title".to_string(),
+        description: "description".to_string(),
+        msgs: msgs.clone(),
+        earliest,
+        latest,
+    };
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(NON_MEMBER, &[]),
+        propose(None, None),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NotVoter {});
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(MEMBER, &[]),
+        propose(None, Some(Expiration::AtHeight(50))),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::CustomExpiration {});
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(MEMBER, &[]),
+        propose(None, None),
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: PROPOSAL_MODULE.to_string(),
+            msg: to_binary(&ProposalExecuteMsg::Propose(SingleChoiceProposeMsg {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs,
+                proposer: Some(MEMBER.to_string()),
+                description_hash: None,
+                content_cid: None,
+                disable_revoting: false,
+            }))
+            .unwrap(),
+            funds: vec![],
+        })
+    );
+}
+
+#[test]
+fn test_vote_and_hooks() {
+    let mut deps = setup();
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(MEMBER, &[]),
+        ExecuteMsg::Vote {
+            proposal_id: 1,
+            vote: cw3::Vote::Yes,
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: PROPOSAL_MODULE.to_string(),
+            msg: to_binary(&ProposalExecuteMsg::VoteFor {
+                voter: MEMBER.to_string(),
+                proposal_id: 1,
+                vote: Vote::Yes,
+                rationale: None,
+            })
+            .unwrap(),
+            funds: vec![],
+        })
+    );
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(MEMBER, &[]),
+        ExecuteMsg::Vote {
+            proposal_id: 1,
+            vote: cw3::Vote::Veto,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Veto {});
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(MEMBER, &[]),
+        ExecuteMsg::ProposalCompletedHook {
+            proposal_id: 1,
+            new_status: Status::Closed,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(PROPOSAL_MODULE, &[]),
+        ExecuteMsg::ProposalCompletedHook {
+            proposal_id: 1,
+            new_status: Status::Closed,
+        },
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_queries() {
+    let deps = setup();
+    let proposal: cw3::ProposalResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Proposal { proposal_id: 3 },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(proposal.id, 3);
+    assert_eq!(proposal.status, cw3::Status::Rejected);
+    assert_eq!(proposal.proposer, Addr::unchecked(MEMBER));
+    assert_eq!(
+        proposal.threshold,
+        cw3::ThresholdResponse::AbsolutePercentage {
+            percentage: Decimal::percent(50),
+            total_weight: 30,
+        }
+    );
+
+    let voter: cw3::VoterResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Voter {
+                address: MEMBER.to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(voter.weight, Some(10));
+    let voter: cw3::VoterResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Voter {
+                address: NON_MEMBER.to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(voter.weight, None);
+
+    let info: GenericProposalInfoResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GenericProposalInfo { proposal_id: 3 },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        info,
+        GenericProposalInfoResponse {
+            proposer: Addr::unchecked(MEMBER),
+            status: "closed".to_string(),
+            expiration: Expiration::AtHeight(100),
+            created: 10,
+        }
+    );
+}
@@ -6,6 +6,7 @@ use cosmwasm_std::{
 };
 use cw2::set_contract_version;
 use cw_paginate::paginate_map_values;
+use dao_events::EventSource;
 use dao_pre_propose_base::{
     error::PreProposeError, msg::ExecuteMsg as ExecuteBase, state::PreProposeContract,
 };
@@ -21,6 +22,8 @@ use crate::state::{advance_approval_id, PendingProposal, APPROVER, PENDING_PROPO
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-pre-propose-approval-single";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+const EVENTS: EventSource = EventSource::new(CONTRACT_NAME, CONTRACT_VERSION);
+
 type PrePropose = PreProposeContract<InstantiateExt, ExecuteExt, QueryExt, ProposeMessage>;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -47,6 +50,9 @@ pub fn execute(
 ) -> Result<Response, PreProposeError> {
     match msg {
         ExecuteMsg::Propose { msg } => execute_propose(deps, env, info, msg),
+        // Proposals only reach the proposal module once approved, so
+        // none may be revised there.
+        ExecuteMsg::UpdateProposal { .. } => Err(PreProposeError::ProposalApproved {}),
 
         ExecuteMsg::AddProposalSubmittedHook { address } => {
             execute_add_approver_hook(deps, info, address)
@@ -79,10 +85,13 @@ pub fn execute_propose(
     // Take deposit, if configured.
     let deposit_messages = if let Some(ref deposit_info) = config.deposit_info {
         deposit_info.check_native_deposit_paid(&info)?;
+        pre_propose_base.add_deposit_totals(deps.storage, deposit_info)?;
         deposit_info.get_take_deposit_messages(&info.sender, &env.contract.address)?
     } else {
         vec![]
     };
+    let nft_deposit =
+        pre_propose_base.take_nft_deposit(deps.storage, &config.nft_deposit_info, &info.sender)?;
 
     let approval_id = advance_approval_id(deps.storage)?;
 
@@ -91,11 +100,17 @@ pub fn execute_propose(
             title,
             description,
             msgs,
+            description_hash,
+            content_cid,
+            disable_revoting,
         } => ProposeMsg {
             title,
             description,
             msgs,
             proposer: Some(info.sender.to_string()),
+            description_hash,
+            content_cid,
+            disable_revoting,
         },
     };
 
@@ -123,6 +138,11 @@ pub fn execute_propose(
                 Ok(SubMsg::new(execute_msg))
             })?;
 
+    let event = EVENTS
+        .event("propose")
+        .id("approval_id", approval_id)
+        .address("proposer", &info.sender);
+
     // Save the proposal and its information as pending.
     PENDING_PROPOSALS.save(
         deps.storage,
@@ -132,6 +152,7 @@ pub fn execute_propose(
             proposer: info.sender,
             msg: propose_msg_internal,
             deposit: config.deposit_info,
+            nft_deposit,
         },
     )?;
 
@@ -139,7 +160,8 @@ pub fn execute_propose(
         .add_messages(deposit_messages)
         .add_submessages(hooks_msgs)
         .add_attribute("method", "pre-propose")
-        .add_attribute("id", approval_id.to_string()))
+        .add_attribute("id", approval_id.to_string())
+        .add_event(event.into()))
 }
 
 pub fn execute_approve(
@@ -170,6 +192,11 @@ pub fn execute_approve(
                 proposal_id,
                 &(proposal.deposit, proposal.proposer),
             )?;
+            if let Some(nft_deposit) = proposal.nft_deposit {
+                PrePropose::default()
+                    .nft_deposits
+                    .save(deps.storage, proposal_id, &nft_deposit)?;
+            }
 
             let propose_messsage = WasmMsg::Execute {
                 contract_addr: proposal_module.into_string(),
@@ -182,7 +209,14 @@ pub fn execute_approve(
                 .add_message(propose_messsage)
                 .add_attribute("method", "proposal_approved")
                 .add_attribute("approval_id", id.to_string())
-                .add_attribute("proposal_id", proposal_id.to_string()))
+                .add_attribute("proposal_id", proposal_id.to_string())
+                .add_event(
+                    EVENTS
+                        .event("approve")
+                        .id("approval_id", id)
+                        .proposal_id(proposal_id)
+                        .into(),
+                ))
         }
         None => Err(PreProposeError::ProposalNotFound {}),
     }
@@ -200,14 +234,19 @@ pub fn execute_reject(
     }
 
     let PendingProposal {
-        deposit, proposer, ..
+        deposit,
+        nft_deposit,
+        proposer,
+        ..
     } = PENDING_PROPOSALS
         .may_load(deps.storage, id)?
         .ok_or(PreProposeError::ProposalNotFound {})?;
 
     PENDING_PROPOSALS.remove(deps.storage, id);
 
-    let messages = if let Some(ref deposit_info) = deposit {
+    let mut messages = if let Some(ref deposit_info) = deposit {
+        PrePropose::default().remove_deposit_totals(deps.storage, deposit_info)?;
+
         // Refund can be issued if proposal if deposits are always
         // refunded. `OnlyPassed` and `Never` refund deposit policies
         // do not apply here.
@@ -222,10 +261,21 @@ pub fn execute_reject(
         vec![]
     };
 
+    // NFT deposits follow the same rule.
+    if let Some(nft_deposit) = nft_deposit {
+        let recipient = if nft_deposit.refund_policy == DepositRefundPolicy::Always {
+            proposer
+        } else {
+            PrePropose::default().dao.load(deps.storage)?
+        };
+        messages.push(nft_deposit.deposit.get_transfer_to_message(&recipient)?);
+    }
+
     Ok(Response::default()
         .add_attribute("method", "proposal_rejected")
         .add_attribute("proposal", id.to_string())
         .add_attribute("deposit_info", to_binary(&deposit)?.to_string())
+        .add_event(EVENTS.event("reject").id("approval_id", id).into())
         .add_messages(messages))
 }
 
@@ -3,7 +3,7 @@ use cosmwasm_std::{CosmosMsg, Empty};
 use dao_pre_propose_base::msg::{
     ExecuteMsg as ExecuteBase, InstantiateMsg as InstantiateBase, QueryMsg as QueryBase,
 };
-use dao_voting::proposal::SingleChoiceProposeMsg as ProposeMsg;
+use dao_voting::proposal::{DescriptionHash, SingleChoiceProposeMsg as ProposeMsg};
 
 #[cw_serde]
 pub enum ApproverProposeMessage {
@@ -20,6 +20,12 @@ pub enum ProposeMessage {
         title: String,
         description: String,
         msgs: Vec<CosmosMsg<Empty>>,
+        description_hash: Option<DescriptionHash>,
+        content_cid: Option<String>,
+        /// Disables revoting on the proposal, even if the proposal
+        /// module allows it.
+        #[serde(default)]
+        disable_revoting: bool,
     },
 }
 
@@ -2,6 +2,7 @@ use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, StdResult, Storage};
 use cw_storage_plus::{Item, Map};
 
+use dao_pre_propose_base::state::ProposalNftDeposit;
 use dao_voting::deposit::CheckedDepositInfo;
 use dao_voting::proposal::SingleChoiceProposeMsg as ProposeMsg;
 
@@ -17,6 +18,10 @@ pub struct PendingProposal {
     /// Snapshot of the deposit info at the time of proposal
     /// submission.
     pub deposit: Option<CheckedDepositInfo>,
+    /// The NFT deposited for this proposal and how it is refunded,
+    /// as of the time of proposal submission.
+    #[serde(default)]
+    pub nft_deposit: Option<ProposalNftDeposit>,
 }
 
 pub const APPROVER: Item<Addr> = Item::new("approver");
@@ -65,6 +65,7 @@ fn get_default_proposal_module_instantiate(
                 msg: to_binary(&InstantiateMsg {
                     deposit_info,
                     open_proposal_submission,
+                    nft_deposit_info: None,
                     extension: InstantiateExt {
                         approver: "approver".to_string(),
                     },
@@ -75,6 +76,13 @@ fn get_default_proposal_module_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
+        require_content_cid: None,
+        abstain_counting: None,
+        execution_delay: None,
+        vote_extension: None,
     }
 }
 
@@ -182,6 +190,9 @@ fn make_pre_proposal(app: &mut App, pre_propose: Addr, proposer: &str, funds: &[
                 title: "title".to_string(),
                 description: "description".to_string(),
                 msgs: vec![],
+                description_hash: None,
+                content_cid: None,
+                disable_revoting: false,
             },
         },
         funds,
@@ -307,6 +318,7 @@ fn update_config(
         &ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            nft_deposit_info: None,
         },
         &[],
     )
@@ -328,6 +340,7 @@ fn update_config_should_fail(
         &ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            nft_deposit_info: None,
         },
         &[],
     )
@@ -395,10 +408,20 @@ fn approve_proposal(app: &mut App, module: Addr, sender: &str, proposal_id: u64)
         )
         .unwrap();
 
-    // Parse attrs from approve_proposal response
-    let attrs = res.custom_attrs(res.events.len() - 1);
-    // Return ID
-    attrs[attrs.len() - 2].value.parse().unwrap()
+    // Return the ID of the created proposal.
+    res.events
+        .iter()
+        .find(|event| event.ty == "wasm-dao")
+        .and_then(|event| {
+            event
+                .attributes
+                .iter()
+                .find(|attribute| attribute.key == "proposal_id")
+        })
+        .unwrap()
+        .value
+        .parse()
+        .unwrap()
 }
 
 fn reject_proposal(app: &mut App, module: Addr, sender: &str, proposal_id: u64) {
@@ -448,6 +471,8 @@ fn test_native_permutation(
             },
             amount: Uint128::new(10),
             refund_policy,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false,
     );
@@ -522,6 +547,8 @@ fn test_cw20_permutation(
             },
             amount: Uint128::new(10),
             refund_policy,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false,
     );
@@ -775,6 +802,8 @@ fn test_multiple_open_proposals() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false,
     );
@@ -862,6 +891,8 @@ fn test_pending_proposal_queries() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false,
     );
@@ -932,6 +963,8 @@ fn test_set_version() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false,
     );
@@ -968,6 +1001,8 @@ fn test_permissions() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false, // no open proposal submission.
     );
@@ -998,6 +1033,9 @@ fn test_permissions() {
                     title: "I would like to join the DAO".to_string(),
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
+                    description_hash: None,
+                    content_cid: None,
+                    disable_revoting: false,
                 },
             },
             &[],
@@ -1023,6 +1061,8 @@ fn test_approval_and_rejection_permissions() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         true, // yes, open proposal submission.
     );
@@ -1067,6 +1107,75 @@ fn test_approval_and_rejection_permissions() {
     assert_eq!(err, PreProposeError::Unauthorized {});
 }
 
+#[test]
+fn test_proposer_can_not_update_approved_proposal() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(&mut app, None, true);
+
+    let pre_propose_id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+    let proposal_id = approve_proposal(&mut app, pre_propose.clone(), "approver", pre_propose_id);
+
+    // The proposer may not rewrite the messages of a proposal after
+    // the approver has approved it, either through this module or
+    // directly.
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            pre_propose,
+            &ExecuteMsg::UpdateProposal {
+                proposal_id,
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs: vec![cosmwasm_std::BankMsg::Send {
+                    to_address: "ekez".to_string(),
+                    amount: coins(10, "ujuno"),
+                }
+                .into()],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::ProposalApproved {});
+    let err: dao_proposal_single::ContractError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            proposal_single.clone(),
+            &dao_proposal_single::msg::ExecuteMsg::UpdateProposal {
+                proposal_id,
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs: vec![cosmwasm_std::BankMsg::Send {
+                    to_address: "ekez".to_string(),
+                    amount: coins(10, "ujuno"),
+                }
+                .into()],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        dao_proposal_single::ContractError::Unauthorized {}
+    ));
+
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &dao_proposal_single::msg::QueryMsg::Proposal { proposal_id },
+        )
+        .unwrap();
+    assert!(proposal.proposal.msgs.is_empty());
+}
+
 #[test]
 fn test_propose_open_proposal_submission() {
     let mut app = App::default();
@@ -1082,6 +1191,8 @@ fn test_propose_open_proposal_submission() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         true, // yes, open proposal submission.
     );
@@ -1146,6 +1257,9 @@ fn test_no_deposit_required_members_submission() {
                     title: "I would like to join the DAO".to_string(),
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
+                    description_hash: None,
+                    content_cid: None,
+                    disable_revoting: false,
                 },
             },
             &[],
@@ -1192,8 +1306,11 @@ fn test_instantiate_with_zero_native_deposit() {
                             },
                             amount: Uint128::zero(),
                             refund_policy: DepositRefundPolicy::OnlyPassed,
+                            additional: vec![],
+                            refund_on_cancel: false,
                         }),
                         open_proposal_submission: false,
+                        nft_deposit_info: None,
                         extension: InstantiateExt {
                             approver: "approver".to_string(),
                         },
@@ -1204,6 +1321,13 @@ fn test_instantiate_with_zero_native_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            max_proposal_size: None,
+            max_open_proposals: None,
+            pre_propose_fallback: None,
+            require_content_cid: None,
+            abstain_counting: None,
+            execution_delay: None,
+            vote_extension: None,
         }
     };
 
@@ -1255,8 +1379,11 @@ fn test_instantiate_with_zero_cw20_deposit() {
                             },
                             amount: Uint128::zero(),
                             refund_policy: DepositRefundPolicy::OnlyPassed,
+                            additional: vec![],
+                            refund_on_cancel: false,
                         }),
                         open_proposal_submission: false,
+                        nft_deposit_info: None,
                         extension: InstantiateExt {
                             approver: "approver".to_string(),
                         },
@@ -1267,6 +1394,13 @@ fn test_instantiate_with_zero_cw20_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            max_proposal_size: None,
+            max_open_proposals: None,
+            pre_propose_fallback: None,
+            require_content_cid: None,
+            abstain_counting: None,
+            execution_delay: None,
+            vote_extension: None,
         }
     };
 
@@ -1302,7 +1436,8 @@ fn test_update_config() {
         config,
         Config {
             deposit_info: None,
-            open_proposal_submission: false
+            open_proposal_submission: false,
+            nft_deposit_info: None,
         }
     );
 
@@ -1321,6 +1456,8 @@ fn test_update_config() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Never,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         true,
     );
@@ -1332,9 +1469,12 @@ fn test_update_config() {
             deposit_info: Some(CheckedDepositInfo {
                 denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
                 amount: Uint128::new(10),
-                refund_policy: DepositRefundPolicy::Never
+                refund_policy: DepositRefundPolicy::Never,
+                additional: vec![],
+                refund_on_cancel: false,
             }),
             open_proposal_submission: true,
+            nft_deposit_info: None,
         }
     );
 
@@ -1368,7 +1508,9 @@ fn test_update_config() {
             deposit_info: Some(CheckedDepositInfo {
                 denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
                 amount: Uint128::new(10),
-                refund_policy: DepositRefundPolicy::Never
+                refund_policy: DepositRefundPolicy::Never,
+                additional: vec![],
+                refund_on_cancel: false,
             }),
             proposer: Addr::unchecked("ekez"),
         }
@@ -1429,6 +1571,8 @@ fn test_withdraw() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false,
     );
@@ -1476,6 +1620,8 @@ fn test_withdraw() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false,
     );
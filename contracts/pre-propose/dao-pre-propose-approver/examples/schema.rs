@@ -1,8 +1,8 @@
-use cosmwasm_schema::write_api;
 use dao_pre_propose_approver::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use dao_schema::write_schema;
 
 fn main() {
-    write_api! {
+    write_schema! {
         instantiate: InstantiateMsg,
         query: QueryMsg,
         execute: ExecuteMsg,
@@ -16,7 +16,7 @@ use dao_voting::status::Status;
 use crate::msg::{
     BaseInstantiateMsg, ExecuteMsg, InstantiateMsg, ProposeMessageInternal, QueryExt, QueryMsg,
 };
-use crate::state::{PRE_PROPOSE_APPROVAL_CONTRACT, PROPOSAL_IDS};
+use crate::state::{PRE_PROPOSE_APPROVAL_CONTRACT, PRE_PROPOSE_IDS, PROPOSAL_IDS};
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-pre-propose-approver";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -35,6 +35,7 @@ pub fn instantiate(
     let base_instantiate_msg = BaseInstantiateMsg {
         deposit_info: None,
         open_proposal_submission: false,
+        nft_deposit_info: None,
         extension: Empty {},
     };
     // Default pre-propose-base instantiation
@@ -125,6 +126,7 @@ pub fn execute_propose(
         &dao_interface::proposal::Query::NextProposalId {},
     )?;
     PROPOSAL_IDS.save(deps.storage, proposal_id, &pre_propose_id)?;
+    PRE_PROPOSE_IDS.save(deps.storage, pre_propose_id, &proposal_id)?;
 
     let propose_messsage = WasmMsg::Execute {
         contract_addr: proposal_module.into_string(),
@@ -154,7 +156,7 @@ pub fn execute_proposal_completed(
 
     // On completion send rejection or approval message
     let msg = match new_status {
-        Status::Closed => Some(WasmMsg::Execute {
+        Status::Closed | Status::Cancelled => Some(WasmMsg::Execute {
             contract_addr: approval_contract.into_string(),
             msg: to_binary(&PreProposeApprovalExecuteMsg::Extension {
                 msg: ApprovalExt::Reject { id: pre_propose_id },
@@ -171,7 +173,7 @@ pub fn execute_proposal_completed(
         _ => None,
     };
 
-    // If Status is not Executed or Closed, throw error
+    // If Status is not Executed, Closed, or Cancelled, throw error
     match msg {
         Some(msg) => Ok(Response::default()
             .add_message(msg)
@@ -188,6 +190,12 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             QueryExt::PreProposeApprovalContract {} => {
                 to_binary(&PRE_PROPOSE_APPROVAL_CONTRACT.load(deps.storage)?)
             }
+            QueryExt::PreProposeApprovalIdForApproverProposalId { id } => {
+                to_binary(&PROPOSAL_IDS.may_load(deps.storage, id)?)
+            }
+            QueryExt::ApproverProposalIdForPreProposeApprovalId { id } => {
+                to_binary(&PRE_PROPOSE_IDS.may_load(deps.storage, id)?)
+            }
         },
         _ => PrePropose::default().query(deps, env, msg),
     }
@@ -15,6 +15,16 @@ pub struct InstantiateMsg {
 pub enum QueryExt {
     #[returns(cosmwasm_std::Addr)]
     PreProposeApprovalContract {},
+    /// Returns the ID of the pending proposal in the approval
+    /// contract that the approver proposal with ID `id` decides on,
+    /// if any.
+    #[returns(Option<u64>)]
+    PreProposeApprovalIdForApproverProposalId { id: u64 },
+    /// Returns the ID of the approver proposal created for the
+    /// pending proposal with ID `id` in the approval contract, if
+    /// any.
+    #[returns(Option<u64>)]
+    ApproverProposalIdForPreProposeApprovalId { id: u64 },
 }
 
 pub type BaseInstantiateMsg = InstantiateBase<Empty>;
@@ -5,3 +5,5 @@ use cw_storage_plus::{Item, Map};
 pub const PRE_PROPOSE_APPROVAL_CONTRACT: Item<Addr> = Item::new("pre_propose_approval_contract");
 // Maps proposal ids to pre-propose ids
 pub const PROPOSAL_IDS: Map<u64, u64> = Map::new("proposal_ids");
+// Maps pre-propose ids to proposal ids
+pub const PRE_PROPOSE_IDS: Map<u64, u64> = Map::new("pre_propose_ids");
@@ -25,7 +25,10 @@ use dao_voting::{
 };
 
 use crate::contract::{CONTRACT_NAME, CONTRACT_VERSION};
-use crate::msg::InstantiateMsg as ApproverInstantiateMsg;
+use crate::msg::{
+    InstantiateMsg as ApproverInstantiateMsg, QueryExt as ApproverQueryExt,
+    QueryMsg as ApproverQueryMsg,
+};
 
 // The approver dao contract is the 6th contract instantiated
 const APPROVER: &str = "contract6";
@@ -89,6 +92,7 @@ fn get_proposal_module_approval_single_instantiate(
                 msg: to_binary(&InstantiateMsg {
                     deposit_info,
                     open_proposal_submission,
+                    nft_deposit_info: None,
                     extension: InstantiateExt {
                         approver: APPROVER.to_string(),
                     },
@@ -99,6 +103,13 @@ fn get_proposal_module_approval_single_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
+        require_content_cid: None,
+        abstain_counting: None,
+        execution_delay: None,
+        vote_extension: None,
     }
 }
 
@@ -130,6 +141,13 @@ fn get_proposal_module_approver_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
+        require_content_cid: None,
+        abstain_counting: None,
+        execution_delay: None,
+        vote_extension: None,
     }
 }
 
@@ -301,6 +319,9 @@ fn make_pre_proposal(app: &mut App, pre_propose: Addr, proposer: &str, funds: &[
                 title: "title".to_string(),
                 description: "description".to_string(),
                 msgs: vec![],
+                description_hash: None,
+                content_cid: None,
+                disable_revoting: false,
             },
         },
         funds,
@@ -417,6 +438,7 @@ fn get_proposals(app: &App, module: Addr) -> ProposalListResponse {
             &cps::msg::QueryMsg::ListProposals {
                 start_after: None,
                 limit: None,
+                status: None,
             },
         )
         .unwrap()
@@ -431,6 +453,7 @@ fn get_latest_proposal_id(app: &App, module: Addr) -> u64 {
             &cps::msg::QueryMsg::ListProposals {
                 start_after: None,
                 limit: None,
+                status: None,
             },
         )
         .unwrap();
@@ -450,6 +473,7 @@ fn update_config(
         &ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            nft_deposit_info: None,
         },
         &[],
     )
@@ -471,6 +495,7 @@ fn update_config_should_fail(
         &ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            nft_deposit_info: None,
         },
         &[],
     )
@@ -574,6 +599,8 @@ fn test_native_permutation(
             },
             amount: Uint128::new(10),
             refund_policy,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false,
     );
@@ -682,6 +709,8 @@ fn test_cw20_permutation(
             },
             amount: Uint128::new(10),
             refund_policy,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false,
     );
@@ -974,6 +1003,8 @@ fn test_multiple_open_proposals() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false,
     );
@@ -1049,6 +1080,64 @@ fn test_multiple_open_proposals() {
     assert_eq!(20, balance.u128());
 }
 
+#[test]
+fn test_approver_proposal_id_mapping() {
+    let mut app = App::default();
+
+    // Need to instantiate this so contract addresses match with cw20 test cases
+    let _ = instantiate_cw20_base_default(&mut app);
+
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single: _,
+        pre_propose,
+        _approver_core_addr: _,
+        proposal_single_approver,
+        pre_propose_approver,
+    } = setup_default_test(&mut app, None, false);
+
+    let pre_propose_id = make_pre_proposal(&mut app, pre_propose, "ekez", &[]);
+    let approver_prop_id = get_latest_proposal_id(&app, proposal_single_approver);
+
+    let id: Option<u64> = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose_approver.clone(),
+            &ApproverQueryMsg::QueryExtension {
+                msg: ApproverQueryExt::ApproverProposalIdForPreProposeApprovalId {
+                    id: pre_propose_id,
+                },
+            },
+        )
+        .unwrap();
+    assert_eq!(id, Some(approver_prop_id));
+
+    let id: Option<u64> = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose_approver.clone(),
+            &ApproverQueryMsg::QueryExtension {
+                msg: ApproverQueryExt::PreProposeApprovalIdForApproverProposalId {
+                    id: approver_prop_id,
+                },
+            },
+        )
+        .unwrap();
+    assert_eq!(id, Some(pre_propose_id));
+
+    // Unknown IDs map to nothing.
+    let id: Option<u64> = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose_approver,
+            &ApproverQueryMsg::QueryExtension {
+                msg: ApproverQueryExt::PreProposeApprovalIdForApproverProposalId { id: 42 },
+            },
+        )
+        .unwrap();
+    assert_eq!(id, None);
+}
+
 #[test]
 fn test_set_version() {
     let mut app = App::default();
@@ -1071,6 +1160,8 @@ fn test_set_version() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false,
     );
@@ -1113,6 +1204,8 @@ fn test_permissions() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false, // no open proposal submission.
     );
@@ -1143,6 +1236,9 @@ fn test_permissions() {
                     title: "I would like to join the DAO".to_string(),
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
+                    description_hash: None,
+                    content_cid: None,
+                    disable_revoting: false,
                 },
             },
             &[],
@@ -1175,6 +1271,8 @@ fn test_approval_and_rejection_permissions() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         true, // yes, open proposal submission.
     );
@@ -1241,6 +1339,8 @@ fn test_propose_open_proposal_submission() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         true, // yes, open proposal submission.
     );
@@ -1281,7 +1381,8 @@ fn test_update_config() {
         config,
         Config {
             deposit_info: None,
-            open_proposal_submission: false
+            open_proposal_submission: false,
+            nft_deposit_info: None,
         }
     );
 
@@ -1307,6 +1408,8 @@ fn test_update_config() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Never,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         true,
     );
@@ -1318,9 +1421,12 @@ fn test_update_config() {
             deposit_info: Some(CheckedDepositInfo {
                 denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
                 amount: Uint128::new(10),
-                refund_policy: DepositRefundPolicy::Never
+                refund_policy: DepositRefundPolicy::Never,
+                additional: vec![],
+                refund_on_cancel: false,
             }),
             open_proposal_submission: true,
+            nft_deposit_info: None,
         }
     );
 
@@ -1356,7 +1462,9 @@ fn test_update_config() {
             deposit_info: Some(CheckedDepositInfo {
                 denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
                 amount: Uint128::new(10),
-                refund_policy: DepositRefundPolicy::Never
+                refund_policy: DepositRefundPolicy::Never,
+                additional: vec![],
+                refund_on_cancel: false,
             }),
             proposer: Addr::unchecked("ekez"),
         }
@@ -1423,6 +1531,8 @@ fn test_withdraw() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false,
     );
@@ -1472,6 +1582,8 @@ fn test_withdraw() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false,
     );
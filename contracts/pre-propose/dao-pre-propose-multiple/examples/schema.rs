@@ -1,10 +1,10 @@
-use cosmwasm_schema::write_api;
 use cosmwasm_std::Empty;
 use dao_pre_propose_base::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
 use dao_pre_propose_multiple::ProposeMessage;
+use dao_schema::write_schema;
 
 fn main() {
-    write_api! {
+    write_schema! {
         instantiate: InstantiateMsg<Empty>,
         query: QueryMsg<Empty>,
         execute: ExecuteMsg<ProposeMessage, Empty>,
@@ -9,13 +9,19 @@ use dao_pre_propose_base::{
     msg::{ExecuteMsg as ExecuteBase, InstantiateMsg as InstantiateBase, QueryMsg as QueryBase},
     state::PreProposeContract,
 };
-use dao_voting::multiple_choice::MultipleChoiceOptions;
+use dao_voting::{
+    multiple_choice::MultipleChoiceOptions, proposal::MultipleChoiceProposeMsg as ProposeMsg,
+};
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-pre-propose-multiple";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 #[cw_serde]
 pub enum ProposeMessage {
+    /// The propose message used to make a proposal to this
+    /// module. Note that this is identical to the propose message
+    /// used by dao-proposal-multiple, except that it omits the
+    /// `proposer` field which it fills in for the sender.
     Propose {
         title: String,
         description: String,
@@ -32,12 +38,7 @@ pub type QueryMsg = QueryBase<Empty>;
 /// of the external message.
 #[cw_serde]
 enum ProposeMessageInternal {
-    Propose {
-        title: String,
-        description: String,
-        choices: MultipleChoiceOptions,
-        proposer: Option<String>,
-    },
+    Propose(ProposeMsg),
 }
 
 type PrePropose = PreProposeContract<Empty, Empty, Empty, ProposeMessageInternal>;
@@ -75,21 +76,35 @@ pub fn execute(
                     choices,
                 },
         } => ExecuteInternal::Propose {
-            msg: ProposeMessageInternal::Propose {
+            msg: ProposeMessageInternal::Propose(ProposeMsg {
+                // Fill in proposer based on message sender.
                 proposer: Some(info.sender.to_string()),
                 title,
                 description,
                 choices,
-            },
+            }),
+        },
+        ExecuteMsg::UpdateProposal {
+            proposal_id,
+            title,
+            description,
+            msgs,
+        } => ExecuteInternal::UpdateProposal {
+            proposal_id,
+            title,
+            description,
+            msgs,
         },
         ExecuteMsg::Extension { msg } => ExecuteInternal::Extension { msg },
         ExecuteMsg::Withdraw { denom } => ExecuteInternal::Withdraw { denom },
         ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            nft_deposit_info,
         } => ExecuteInternal::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            nft_deposit_info,
         },
         ExecuteMsg::AddProposalSubmittedHook { address } => {
             ExecuteInternal::AddProposalSubmittedHook { address }
@@ -104,6 +119,12 @@ pub fn execute(
             proposal_id,
             new_status,
         },
+        ExecuteMsg::UpdateProposalModule { address } => {
+            ExecuteInternal::UpdateProposalModule { address }
+        }
+        ExecuteMsg::ImportDeposits { deposits } => ExecuteInternal::ImportDeposits { deposits },
+        ExecuteMsg::ReceiveNft(msg) => ExecuteInternal::ReceiveNft(msg),
+        ExecuteMsg::ReturnNftDeposit {} => ExecuteInternal::ReturnNftDeposit {},
     };
 
     PrePropose::default().execute(deps, env, info, internalized)
@@ -68,6 +68,7 @@ fn get_default_proposal_module_instantiate(
                 msg: to_binary(&InstantiateMsg {
                     deposit_info,
                     open_proposal_submission,
+                    nft_deposit_info: None,
                     extension: Empty::default(),
                 })
                 .unwrap(),
@@ -76,6 +77,9 @@ fn get_default_proposal_module_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
     }
 }
 
@@ -310,6 +314,7 @@ fn vote(
         &cpm::msg::ExecuteMsg::Vote {
             proposal_id: id,
             vote: position,
+            rationale: None,
         },
         &[],
     )
@@ -360,6 +365,7 @@ fn update_config(
         &ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            nft_deposit_info: None,
         },
         &[],
     )
@@ -381,6 +387,7 @@ fn update_config_should_fail(
         &ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            nft_deposit_info: None,
         },
         &[],
     )
@@ -464,6 +471,8 @@ fn test_native_permutation(
             },
             amount: Uint128::new(10),
             refund_policy,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false,
     );
@@ -536,6 +545,8 @@ fn test_cw20_permutation(
             },
             amount: Uint128::new(10),
             refund_policy,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false,
     );
@@ -712,6 +723,8 @@ fn test_multiple_open_proposals() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false,
     );
@@ -794,6 +807,8 @@ fn test_set_version() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false,
     );
@@ -830,6 +845,8 @@ fn test_permissions() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false, // no open proposal submission.
     );
@@ -891,6 +908,8 @@ fn test_propose_open_proposal_submission() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         true, // yes, open proposal submission.
     );
@@ -1052,8 +1071,11 @@ fn test_instantiate_with_zero_native_deposit() {
                             },
                             amount: Uint128::zero(),
                             refund_policy: DepositRefundPolicy::OnlyPassed,
+                            additional: vec![],
+                            refund_on_cancel: false,
                         }),
                         open_proposal_submission: false,
+                        nft_deposit_info: None,
                         extension: Empty::default(),
                     })
                     .unwrap(),
@@ -1062,6 +1084,9 @@ fn test_instantiate_with_zero_native_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            max_proposal_size: None,
+            max_open_proposals: None,
+            pre_propose_fallback: None,
         }
     };
 
@@ -1113,8 +1138,11 @@ fn test_instantiate_with_zero_cw20_deposit() {
                             },
                             amount: Uint128::zero(),
                             refund_policy: DepositRefundPolicy::OnlyPassed,
+                            additional: vec![],
+                            refund_on_cancel: false,
                         }),
                         open_proposal_submission: false,
+                        nft_deposit_info: None,
                         extension: Empty::default(),
                     })
                     .unwrap(),
@@ -1123,6 +1151,9 @@ fn test_instantiate_with_zero_cw20_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            max_proposal_size: None,
+            max_open_proposals: None,
+            pre_propose_fallback: None,
         }
     };
 
@@ -1158,7 +1189,8 @@ fn test_update_config() {
         config,
         Config {
             deposit_info: None,
-            open_proposal_submission: false
+            open_proposal_submission: false,
+            nft_deposit_info: None,
         }
     );
 
@@ -1180,6 +1212,8 @@ fn test_update_config() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Never,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         true,
     );
@@ -1191,9 +1225,12 @@ fn test_update_config() {
             deposit_info: Some(CheckedDepositInfo {
                 denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
                 amount: Uint128::new(10),
-                refund_policy: DepositRefundPolicy::Never
+                refund_policy: DepositRefundPolicy::Never,
+                additional: vec![],
+                refund_on_cancel: false,
             }),
             open_proposal_submission: true,
+            nft_deposit_info: None,
         }
     );
 
@@ -1223,7 +1260,9 @@ fn test_update_config() {
             deposit_info: Some(CheckedDepositInfo {
                 denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
                 amount: Uint128::new(10),
-                refund_policy: DepositRefundPolicy::Never
+                refund_policy: DepositRefundPolicy::Never,
+                additional: vec![],
+                refund_on_cancel: false,
             }),
             proposer: Addr::unchecked("ekez"),
         }
@@ -1296,6 +1335,8 @@ fn test_withdraw() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false,
     );
@@ -1340,6 +1381,8 @@ fn test_withdraw() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false,
     );
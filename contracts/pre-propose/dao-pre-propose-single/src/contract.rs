@@ -11,7 +11,7 @@ use dao_pre_propose_base::{
     msg::{ExecuteMsg as ExecuteBase, InstantiateMsg as InstantiateBase, QueryMsg as QueryBase},
     state::PreProposeContract,
 };
-use dao_voting::proposal::SingleChoiceProposeMsg as ProposeMsg;
+use dao_voting::proposal::{DescriptionHash, SingleChoiceProposeMsg as ProposeMsg};
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-pre-propose-single";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -26,6 +26,12 @@ pub enum ProposeMessage {
         title: String,
         description: String,
         msgs: Vec<CosmosMsg<Empty>>,
+        description_hash: Option<DescriptionHash>,
+        content_cid: Option<String>,
+        /// Disables revoting on the proposal, even if the proposal
+        /// module allows it.
+        #[serde(default)]
+        disable_revoting: bool,
     },
 }
 
@@ -74,6 +80,9 @@ pub fn execute(
                     title,
                     description,
                     msgs,
+                    description_hash,
+                    content_cid,
+                    disable_revoting,
                 },
         } => ExecuteInternal::Propose {
             msg: ProposeMessageInternal::Propose(ProposeMsg {
@@ -82,16 +91,32 @@ pub fn execute(
                 title,
                 description,
                 msgs,
+                description_hash,
+                content_cid,
+                disable_revoting,
             }),
         },
+        ExecuteMsg::UpdateProposal {
+            proposal_id,
+            title,
+            description,
+            msgs,
+        } => ExecuteInternal::UpdateProposal {
+            proposal_id,
+            title,
+            description,
+            msgs,
+        },
         ExecuteMsg::Extension { msg } => ExecuteInternal::Extension { msg },
         ExecuteMsg::Withdraw { denom } => ExecuteInternal::Withdraw { denom },
         ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            nft_deposit_info,
         } => ExecuteInternal::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            nft_deposit_info,
         },
         ExecuteMsg::AddProposalSubmittedHook { address } => {
             ExecuteInternal::AddProposalSubmittedHook { address }
@@ -106,6 +131,12 @@ pub fn execute(
             proposal_id,
             new_status,
         },
+        ExecuteMsg::UpdateProposalModule { address } => {
+            ExecuteInternal::UpdateProposalModule { address }
+        }
+        ExecuteMsg::ImportDeposits { deposits } => ExecuteInternal::ImportDeposits { deposits },
+        ExecuteMsg::ReceiveNft(msg) => ExecuteInternal::ReceiveNft(msg),
+        ExecuteMsg::ReturnNftDeposit {} => ExecuteInternal::ReturnNftDeposit {},
     };
 
     PrePropose::default().execute(deps, env, info, internalized)
@@ -5,11 +5,10 @@ use cw20::Cw20Coin;
 use cw_denom::UncheckedDenom;
 use cw_multi_test::{App, BankSudo, Contract, ContractWrapper, Executor};
 use cw_utils::Duration;
-use dao_core::state::ProposalModule;
 use dao_interface::{Admin, ModuleInstantiateInfo};
 use dao_pre_propose_base::{error::PreProposeError, msg::DepositInfoResponse, state::Config};
 use dao_proposal_single as cps;
-use dao_testing::helpers::instantiate_with_cw4_groups_governance;
+use dao_testing::{builder::DaoTestBuilder, helpers::instantiate_with_cw4_groups_governance};
 use dao_voting::{
     deposit::{CheckedDepositInfo, DepositRefundPolicy, DepositToken, UncheckedDepositInfo},
     pre_propose::{PreProposeInfo, ProposalCreationPolicy},
@@ -45,38 +44,6 @@ fn cw20_base_contract() -> Box<dyn Contract<Empty>> {
     Box::new(contract)
 }
 
-fn get_default_proposal_module_instantiate(
-    app: &mut App,
-    deposit_info: Option<UncheckedDepositInfo>,
-    open_proposal_submission: bool,
-) -> cps::msg::InstantiateMsg {
-    let pre_propose_id = app.store_code(cw_pre_propose_base_proposal_single());
-
-    cps::msg::InstantiateMsg {
-        threshold: Threshold::AbsolutePercentage {
-            percentage: PercentageThreshold::Majority {},
-        },
-        max_voting_period: cw_utils::Duration::Time(86400),
-        min_voting_period: None,
-        only_members_execute: false,
-        allow_revoting: false,
-        pre_propose_info: PreProposeInfo::ModuleMayPropose {
-            info: ModuleInstantiateInfo {
-                code_id: pre_propose_id,
-                msg: to_binary(&InstantiateMsg {
-                    deposit_info,
-                    open_proposal_submission,
-                    extension: Empty::default(),
-                })
-                .unwrap(),
-                admin: Some(Admin::CoreModule {}),
-                label: "baby's first pre-propose module".to_string(),
-            },
-        },
-        close_proposal_on_execution_failure: false,
-    }
-}
-
 fn instantiate_cw20_base_default(app: &mut App) -> Addr {
     let cw20_id = app.store_code(cw20_base_contract());
     let cw20_instantiate = cw20_base::msg::InstantiateMsg {
@@ -111,62 +78,22 @@ fn setup_default_test(
     deposit_info: Option<UncheckedDepositInfo>,
     open_proposal_submission: bool,
 ) -> DefaultTestSetup {
-    let cps_id = app.store_code(cw_dao_proposal_single_contract());
-
-    let proposal_module_instantiate =
-        get_default_proposal_module_instantiate(app, deposit_info, open_proposal_submission);
-
-    let core_addr = instantiate_with_cw4_groups_governance(
-        app,
-        cps_id,
-        to_binary(&proposal_module_instantiate).unwrap(),
-        Some(vec![
-            cw20::Cw20Coin {
-                address: "ekez".to_string(),
-                amount: Uint128::new(9),
-            },
-            cw20::Cw20Coin {
-                address: "keze".to_string(),
-                amount: Uint128::new(8),
-            },
-        ]),
-    );
-    let proposal_modules: Vec<ProposalModule> = app
-        .wrap()
-        .query_wasm_smart(
-            core_addr.clone(),
-            &dao_core::msg::QueryMsg::ProposalModules {
-                start_after: None,
-                limit: None,
-            },
-        )
-        .unwrap();
-
-    assert_eq!(proposal_modules.len(), 1);
-    let proposal_single = proposal_modules.into_iter().next().unwrap().address;
-    let proposal_creation_policy = app
-        .wrap()
-        .query_wasm_smart(
-            proposal_single.clone(),
-            &cps::msg::QueryMsg::ProposalCreationPolicy {},
-        )
-        .unwrap();
-
-    let pre_propose = match proposal_creation_policy {
-        ProposalCreationPolicy::Module { addr } => addr,
-        _ => panic!("expected a module for the proposal creation policy"),
-    };
+    let dao = DaoTestBuilder::new()
+        .with_cw4_voting(vec![("ekez", 9), ("keze", 8)])
+        .with_pre_propose_single(deposit_info, open_proposal_submission)
+        .build(app);
+    let pre_propose = dao.pre_propose();
 
     // Make sure things were set up correctly.
     assert_eq!(
-        proposal_single,
+        dao.proposal_single,
         get_proposal_module(app, pre_propose.clone())
     );
-    assert_eq!(core_addr, get_dao(app, pre_propose.clone()));
+    assert_eq!(dao.core, get_dao(app, pre_propose.clone()));
 
     DefaultTestSetup {
-        core_addr,
-        proposal_single,
+        core_addr: dao.core,
+        proposal_single: dao.proposal_single,
         pre_propose,
     }
 }
@@ -186,6 +113,9 @@ fn make_proposal(
                 title: "title".to_string(),
                 description: "description".to_string(),
                 msgs: vec![],
+                description_hash: None,
+                content_cid: None,
+                disable_revoting: false,
             },
         },
         funds,
@@ -342,6 +272,7 @@ fn update_config(
         &ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            nft_deposit_info: None,
         },
         &[],
     )
@@ -363,6 +294,7 @@ fn update_config_should_fail(
         &ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            nft_deposit_info: None,
         },
         &[],
     )
@@ -446,6 +378,8 @@ fn test_native_permutation(
             },
             amount: Uint128::new(10),
             refund_policy,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false,
     );
@@ -510,6 +444,8 @@ fn test_cw20_permutation(
             },
             amount: Uint128::new(10),
             refund_policy,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false,
     );
@@ -679,6 +615,8 @@ fn test_multiple_open_proposals() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false,
     );
@@ -761,6 +699,8 @@ fn test_set_version() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false,
     );
@@ -797,6 +737,8 @@ fn test_permissions() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false, // no open proposal submission.
     );
@@ -827,6 +769,9 @@ fn test_permissions() {
                     title: "I would like to join the DAO".to_string(),
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
+                    description_hash: None,
+                    content_cid: None,
+                    disable_revoting: false,
                 },
             },
             &[],
@@ -852,6 +797,8 @@ fn test_propose_open_proposal_submission() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         true, // yes, open proposal submission.
     );
@@ -915,6 +862,9 @@ fn test_no_deposit_required_members_submission() {
                     title: "I would like to join the DAO".to_string(),
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
+                    description_hash: None,
+                    content_cid: None,
+                    disable_revoting: false,
                 },
             },
             &[],
@@ -989,8 +939,11 @@ fn test_instantiate_with_zero_native_deposit() {
                             },
                             amount: Uint128::zero(),
                             refund_policy: DepositRefundPolicy::OnlyPassed,
+                            additional: vec![],
+                            refund_on_cancel: false,
                         }),
                         open_proposal_submission: false,
+                        nft_deposit_info: None,
                         extension: Empty::default(),
                     })
                     .unwrap(),
@@ -999,6 +952,13 @@ fn test_instantiate_with_zero_native_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            max_proposal_size: None,
+            max_open_proposals: None,
+            pre_propose_fallback: None,
+            require_content_cid: None,
+            abstain_counting: None,
+            execution_delay: None,
+            vote_extension: None,
         }
     };
 
@@ -1050,8 +1010,11 @@ fn test_instantiate_with_zero_cw20_deposit() {
                             },
                             amount: Uint128::zero(),
                             refund_policy: DepositRefundPolicy::OnlyPassed,
+                            additional: vec![],
+                            refund_on_cancel: false,
                         }),
                         open_proposal_submission: false,
+                        nft_deposit_info: None,
                         extension: Empty::default(),
                     })
                     .unwrap(),
@@ -1060,6 +1023,13 @@ fn test_instantiate_with_zero_cw20_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            max_proposal_size: None,
+            max_open_proposals: None,
+            pre_propose_fallback: None,
+            require_content_cid: None,
+            abstain_counting: None,
+            execution_delay: None,
+            vote_extension: None,
         }
     };
 
@@ -1095,7 +1065,8 @@ fn test_update_config() {
         config,
         Config {
             deposit_info: None,
-            open_proposal_submission: false
+            open_proposal_submission: false,
+            nft_deposit_info: None,
         }
     );
 
@@ -1117,6 +1088,8 @@ fn test_update_config() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Never,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         true,
     );
@@ -1128,9 +1101,12 @@ fn test_update_config() {
             deposit_info: Some(CheckedDepositInfo {
                 denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
                 amount: Uint128::new(10),
-                refund_policy: DepositRefundPolicy::Never
+                refund_policy: DepositRefundPolicy::Never,
+                additional: vec![],
+                refund_on_cancel: false,
             }),
             open_proposal_submission: true,
+            nft_deposit_info: None,
         }
     );
 
@@ -1160,7 +1136,9 @@ fn test_update_config() {
             deposit_info: Some(CheckedDepositInfo {
                 denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
                 amount: Uint128::new(10),
-                refund_policy: DepositRefundPolicy::Never
+                refund_policy: DepositRefundPolicy::Never,
+                additional: vec![],
+                refund_on_cancel: false,
             }),
             proposer: Addr::unchecked("ekez"),
         }
@@ -1221,6 +1199,8 @@ fn test_withdraw() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false,
     );
@@ -1265,6 +1245,8 @@ fn test_withdraw() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false,
     );
@@ -1353,3 +1335,64 @@ fn test_hook_management() {
     let hooks = query_hooks(app, pre_propose).hooks;
     assert_eq!(hooks, vec!["two".to_string()])
 }
+
+#[test]
+fn test_update_proposal() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(&mut app, None, false);
+    let id = make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &[],
+    );
+    let update = ExecuteMsg::UpdateProposal {
+        proposal_id: id,
+        title: "revised".to_string(),
+        description: "revised description".to_string(),
+        msgs: vec![],
+    };
+
+    // Only the proposer may revise their proposal.
+    let err: PreProposeError = app
+        .execute_contract(Addr::unchecked("keze"), pre_propose.clone(), &update, &[])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::NotProposer { proposal_id: id });
+
+    // The proposer may not bypass this module.
+    let err: cps::ContractError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            proposal_single.clone(),
+            &cps::msg::ExecuteMsg::UpdateProposal {
+                proposal_id: id,
+                title: "revised".to_string(),
+                description: "revised description".to_string(),
+                msgs: vec![],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, cps::ContractError::Unauthorized {}));
+
+    app.execute_contract(Addr::unchecked("ekez"), pre_propose, &update, &[])
+        .unwrap();
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &cps::msg::QueryMsg::Proposal { proposal_id: id },
+        )
+        .unwrap();
+    assert_eq!(proposal.proposal.title, "revised");
+    assert_eq!(proposal.proposal.description, "revised description");
+}
@@ -0,0 +1,474 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Order, Reply, Response,
+    StdResult, SubMsg, WasmMsg,
+};
+use cw2::set_contract_version;
+use cw_paginate::keyset_bounds;
+use cw_utils::{nonpayable, Duration};
+use dao_interface::{proposal::GenericProposalInfoResponse, querier::CoreQuerier};
+use dao_proposal_hooks::{new_proposal_hooks, proposal_status_changed_hooks, ProposalContent};
+use dao_voting::{
+    deposit::{CheckedDepositInfo, DepositRefundPolicy, UncheckedDepositInfo},
+    reply::{mask_proposal_execution_proposal_id, TaggedReplyId},
+    status::Status,
+    threshold::{validate_quorum, PercentageThreshold},
+};
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::proposal::{Choice, CondorcetProposal};
+use crate::state::{Ballot, Config, BALLOTS, CONFIG, PROPOSALS, PROPOSAL_COUNT, PROPOSAL_HOOKS};
+use crate::tally::{validate_ranking, Tally};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-proposal-condorcet";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub const DEFAULT_LIMIT: u32 = 30;
+pub const MAX_LIMIT: u32 = 100;
+
+/// The fewest choices a proposal may have.
+pub const MIN_CHOICES: u32 = 2;
+/// The most choices a proposal may have. Tallies grow with the
+/// square of the number of choices, so this is kept small.
+pub const MAX_CHOICES: u32 = 20;
+
+fn validate_config(
+    deps: Deps,
+    dao: Addr,
+    quorum: PercentageThreshold,
+    voting_period: Duration,
+    deposit_info: Option<UncheckedDepositInfo>,
+) -> Result<Config, ContractError> {
+    validate_quorum(&quorum)?;
+    let deposit_info = deposit_info
+        .map(|info| info.into_checked(deps, dao.clone()))
+        .transpose()?;
+    Ok(Config {
+        dao,
+        quorum,
+        voting_period,
+        deposit_info,
+    })
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let config = validate_config(
+        deps.as_ref(),
+        info.sender,
+        msg.quorum,
+        msg.voting_period,
+        msg.deposit_info,
+    )?;
+    CONFIG.save(deps.storage, &config)?;
+    PROPOSAL_COUNT.save(deps.storage, &0)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("dao", config.dao))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Propose {
+            title,
+            description,
+            choices,
+        } => execute_propose(deps, env, info, title, description, choices),
+        ExecuteMsg::Vote {
+            proposal_id,
+            ranking,
+        } => execute_vote(deps, env, info, proposal_id, ranking),
+        ExecuteMsg::Execute { proposal_id } => execute_execute(deps, env, proposal_id),
+        ExecuteMsg::Close { proposal_id } => execute_close(deps, env, proposal_id),
+        ExecuteMsg::UpdateConfig {
+            quorum,
+            voting_period,
+            deposit_info,
+        } => execute_update_config(deps, info, quorum, voting_period, deposit_info),
+        ExecuteMsg::AddProposalHook { address } => execute_add_proposal_hook(deps, info, address),
+        ExecuteMsg::RemoveProposalHook { address } => {
+            execute_remove_proposal_hook(deps, info, address)
+        }
+    }
+}
+
+pub fn execute_propose(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    title: String,
+    description: String,
+    choices: Vec<Choice>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let core = CoreQuerier::new(deps.querier, config.dao.clone());
+    if core
+        .voting_power_at_height(&info.sender, None)?
+        .power
+        .is_zero()
+    {
+        return Err(ContractError::NotVoter {});
+    }
+    let count = choices.len();
+    if count < MIN_CHOICES as usize || count > MAX_CHOICES as usize {
+        return Err(ContractError::WrongChoiceCount {
+            min: MIN_CHOICES,
+            max: MAX_CHOICES,
+        });
+    }
+
+    let take_deposit = match &config.deposit_info {
+        Some(deposit_info) => {
+            deposit_info.check_native_deposit_paid(&info)?;
+            deposit_info.get_take_deposit_messages(&info.sender, &env.contract.address)?
+        }
+        None => {
+            nonpayable(&info)?;
+            vec![]
+        }
+    };
+
+    let total_power = core.total_power_at_height(None)?.power;
+    let expiration = config.voting_period.after(&env.block);
+
+    let id = PROPOSAL_COUNT.load(deps.storage)? + 1;
+    PROPOSAL_COUNT.save(deps.storage, &id)?;
+    PROPOSALS.save(
+        deps.storage,
+        id,
+        &CondorcetProposal {
+            title: title.clone(),
+            description: description.clone(),
+            proposer: info.sender.clone(),
+            start_height: env.block.height,
+            expiration,
+            tally: Tally::new(count as u32),
+            choices,
+            quorum: config.quorum,
+            total_power,
+            status: Status::Open,
+            deposit: config.deposit_info,
+        },
+    )?;
+
+    let hooks = new_proposal_hooks(
+        PROPOSAL_HOOKS,
+        deps.storage,
+        id,
+        info.sender.as_str(),
+        ProposalContent {
+            title,
+            description,
+            expiration,
+            content_cid: None,
+        },
+        |_| false,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "propose")
+        .add_attribute("proposal_id", id.to_string())
+        .add_attribute("proposer", info.sender)
+        .add_messages(take_deposit)
+        .add_submessages(hooks))
+}
+
+pub fn execute_vote(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    ranking: Vec<u32>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut proposal = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+    if proposal.current_status(&env.block) != Status::Open {
+        return Err(ContractError::NotOpen { id: proposal_id });
+    }
+    validate_ranking(&ranking, proposal.tally.choices)?;
+    if BALLOTS.has(deps.storage, (proposal_id, &info.sender)) {
+        return Err(ContractError::AlreadyVoted { id: proposal_id });
+    }
+
+    let power = CoreQuerier::new(deps.querier, config.dao)
+        .voting_power_at_height(&info.sender, Some(proposal.start_height))?
+        .power;
+    if power.is_zero() {
+        return Err(ContractError::NotVoter {});
+    }
+
+    let old_status = proposal.status;
+    proposal.tally.add_vote(&ranking, power);
+    proposal.status = proposal.current_status(&env.block);
+    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+    BALLOTS.save(
+        deps.storage,
+        (proposal_id, &info.sender),
+        &Ballot { power, ranking },
+    )?;
+
+    let hooks = proposal_status_changed_hooks(
+        PROPOSAL_HOOKS,
+        deps.storage,
+        proposal_id,
+        old_status.to_string(),
+        proposal.status.to_string(),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "vote")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("voter", info.sender)
+        .add_attribute("power", power)
+        .add_attribute("status", proposal.status.to_string())
+        .add_submessages(hooks))
+}
+
+/// Gets the messages returning a proposal's deposit. Deposits are
+/// refunded to the proposer if the refund policy allows it, and are
+/// otherwise sent to the DAO.
+fn return_deposit(
+    deposit: &Option<CheckedDepositInfo>,
+    proposer: &Addr,
+    dao: &Addr,
+    executed: bool,
+) -> StdResult<Vec<CosmosMsg>> {
+    match deposit {
+        Some(deposit) => {
+            let refund = match deposit.refund_policy {
+                DepositRefundPolicy::Always => true,
+                DepositRefundPolicy::OnlyPassed => executed,
+                DepositRefundPolicy::Never => false,
+            };
+            deposit.get_return_deposit_message(if refund { proposer } else { dao })
+        }
+        None => Ok(vec![]),
+    }
+}
+
+pub fn execute_execute(
+    deps: DepsMut,
+    env: Env,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut proposal = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+    let winner = match proposal.winner(&env.block) {
+        Some(winner) if proposal.current_status(&env.block) == Status::Passed => winner,
+        _ => return Err(ContractError::NotPassed { id: proposal_id }),
+    };
+
+    let old_status = proposal.current_status(&env.block);
+    proposal.status = Status::Executed;
+    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+
+    let msgs = proposal.choices[winner as usize].msgs.clone();
+    let mut response = Response::new()
+        .add_attribute("action", "execute")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("winner", winner.to_string());
+    if !msgs.is_empty() {
+        let execute = WasmMsg::Execute {
+            contract_addr: config.dao.to_string(),
+            msg: to_binary(&dao_core::msg::ExecuteMsg::ExecuteProposalHook { msgs })?,
+            funds: vec![],
+        };
+        response = response.add_submessage(SubMsg::reply_on_error(
+            execute,
+            mask_proposal_execution_proposal_id(proposal_id),
+        ));
+    }
+
+    let refund = return_deposit(&proposal.deposit, &proposal.proposer, &config.dao, true)?;
+    let hooks = proposal_status_changed_hooks(
+        PROPOSAL_HOOKS,
+        deps.storage,
+        proposal_id,
+        old_status.to_string(),
+        proposal.status.to_string(),
+    )?;
+
+    Ok(response.add_messages(refund).add_submessages(hooks))
+}
+
+pub fn execute_close(deps: DepsMut, env: Env, proposal_id: u64) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut proposal = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+    let old_status = proposal.current_status(&env.block);
+    if old_status != Status::Rejected {
+        return Err(ContractError::NotRejected { id: proposal_id });
+    }
+
+    proposal.status = Status::Closed;
+    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+
+    let refund = return_deposit(&proposal.deposit, &proposal.proposer, &config.dao, false)?;
+    let hooks = proposal_status_changed_hooks(
+        PROPOSAL_HOOKS,
+        deps.storage,
+        proposal_id,
+        old_status.to_string(),
+        proposal.status.to_string(),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "close")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_messages(refund)
+        .add_submessages(hooks))
+}
+
+pub fn execute_update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    quorum: PercentageThreshold,
+    voting_period: Duration,
+    deposit_info: Option<UncheckedDepositInfo>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.dao {
+        return Err(ContractError::Unauthorized {});
+    }
+    let config = validate_config(
+        deps.as_ref(),
+        config.dao,
+        quorum,
+        voting_period,
+        deposit_info,
+    )?;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attribute("action", "update_config"))
+}
+
+pub fn execute_add_proposal_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    if info.sender != CONFIG.load(deps.storage)?.dao {
+        return Err(ContractError::Unauthorized {});
+    }
+    let address = deps.api.addr_validate(&address)?;
+    PROPOSAL_HOOKS.add_hook(deps.storage, address.clone())?;
+
+    Ok(Response::new()
+        .add_attribute("action", "add_proposal_hook")
+        .add_attribute("address", address))
+}
+
+pub fn execute_remove_proposal_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    if info.sender != CONFIG.load(deps.storage)?.dao {
+        return Err(ContractError::Unauthorized {});
+    }
+    let address = deps.api.addr_validate(&address)?;
+    PROPOSAL_HOOKS.remove_hook(deps.storage, address.clone())?;
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_proposal_hook")
+        .add_attribute("address", address))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::Dao {} => to_binary(&CONFIG.load(deps.storage)?.dao),
+        QueryMsg::Info {} => {
+            let info = cw2::get_contract_version(deps.storage)?;
+            to_binary(&dao_interface::voting::InfoResponse { info })
+        }
+        QueryMsg::NextProposalId {} => to_binary(&(PROPOSAL_COUNT.load(deps.storage)? + 1)),
+        QueryMsg::Proposal { proposal_id } => {
+            let proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+            to_binary(&proposal.into_response(&env.block, proposal_id))
+        }
+        QueryMsg::ListProposals { start_after, limit } => {
+            query_list_proposals(deps, env, start_after, limit)
+        }
+        QueryMsg::Ballot { proposal_id, voter } => {
+            let voter = deps.api.addr_validate(&voter)?;
+            to_binary(&BALLOTS.may_load(deps.storage, (proposal_id, &voter))?)
+        }
+        QueryMsg::ProposalHooks {} => to_binary(&PROPOSAL_HOOKS.query_hooks(deps)?),
+        QueryMsg::GenericProposalInfo { proposal_id } => {
+            let proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+            to_binary(&GenericProposalInfoResponse {
+                status: proposal.current_status(&env.block).to_string(),
+                proposer: proposal.proposer,
+                expiration: proposal.expiration,
+                created: proposal.start_height,
+            })
+        }
+    }
+}
+
+pub fn query_list_proposals(
+    deps: Deps,
+    env: Env,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = cw_paginate::clamp_limit(limit, DEFAULT_LIMIT, MAX_LIMIT);
+    let (min, max) = keyset_bounds(start_after, Order::Ascending);
+    let proposals = PROPOSALS
+        .range(deps.storage, min, max, Order::Ascending)
+        .take(limit as usize)
+        .map(|item| item.map(|(id, proposal)| proposal.into_response(&env.block, id)))
+        .collect::<StdResult<Vec<_>>>()?;
+    to_binary(&proposals)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match TaggedReplyId::new(msg.id)? {
+        TaggedReplyId::FailedProposalExecution(proposal_id) => {
+            PROPOSALS.update(deps.storage, proposal_id, |proposal| match proposal {
+                Some(mut proposal) => {
+                    proposal.status = Status::ExecutionFailed;
+                    Ok(proposal)
+                }
+                None => Err(ContractError::NoSuchProposal { id: proposal_id }),
+            })?;
+            Ok(Response::new().add_attribute("proposal_execution_failed", proposal_id.to_string()))
+        }
+        TaggedReplyId::FailedProposalHook(idx) => {
+            let hook = PROPOSAL_HOOKS.remove_hook_by_index(deps.storage, idx)?;
+            Ok(Response::new().add_attribute("removed_proposal_hook", format!("{hook}:{idx}")))
+        }
+        _ => Err(ContractError::UnexpectedReply { id: msg.id }),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    msg.check_stored_version(deps.storage, CONTRACT_VERSION)?;
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::default())
+}
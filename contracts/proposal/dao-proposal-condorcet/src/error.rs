@@ -0,0 +1,60 @@
+use cosmwasm_std::StdError;
+use cw_hooks::HookError;
+use cw_utils::PaymentError;
+use dao_interface::migrate::MigrationError;
+use dao_voting::{deposit::DepositError, reply::error::TagError, threshold::ThresholdError};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    MigrationError(#[from] MigrationError),
+
+    #[error("{0}")]
+    PaymentError(#[from] PaymentError),
+
+    #[error(transparent)]
+    DepositError(#[from] DepositError),
+
+    #[error(transparent)]
+    ThresholdError(#[from] ThresholdError),
+
+    #[error(transparent)]
+    HookError(#[from] HookError),
+
+    #[error(transparent)]
+    Tag(#[from] TagError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Only addresses with voting power may create proposals or vote")]
+    NotVoter {},
+
+    #[error("Proposals must have between {min} and {max} choices")]
+    WrongChoiceCount { min: u32, max: u32 },
+
+    #[error("A ranking must list each of the proposal's {choices} choices exactly once")]
+    InvalidRanking { choices: u32 },
+
+    #[error("No such proposal ({id})")]
+    NoSuchProposal { id: u64 },
+
+    #[error("Proposal ({id}) is not open for voting")]
+    NotOpen { id: u64 },
+
+    #[error("Already voted on proposal ({id})")]
+    AlreadyVoted { id: u64 },
+
+    #[error("Proposal ({id}) has not passed")]
+    NotPassed { id: u64 },
+
+    #[error("Proposal ({id}) has not been rejected")]
+    NotRejected { id: u64 },
+
+    #[error("Unexpected reply ({id})")]
+    UnexpectedReply { id: u64 },
+}
@@ -0,0 +1,13 @@
+#![doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"))]
+
+pub mod contract;
+mod error;
+pub mod msg;
+pub mod proposal;
+pub mod state;
+pub mod tally;
+
+#[cfg(test)]
+mod tests;
+
+pub use crate::error::ContractError;
@@ -0,0 +1,76 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cw_utils::Duration;
+use dao_macros::proposal_module_query;
+use dao_voting::{deposit::UncheckedDepositInfo, threshold::PercentageThreshold};
+
+use crate::proposal::Choice;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The share of the DAO's voting power which must vote on a
+    /// proposal for it to pass.
+    pub quorum: PercentageThreshold,
+    /// How long proposals are open for voting.
+    pub voting_period: Duration,
+    /// The deposit required to create a proposal. None for no
+    /// deposit.
+    pub deposit_info: Option<UncheckedDepositInfo>,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Creates a proposal. The sender must have voting power and pay
+    /// the deposit, if one is required.
+    Propose {
+        title: String,
+        description: String,
+        choices: Vec<Choice>,
+    },
+    /// Casts a ballot on an open proposal. `ranking` lists the index
+    /// of each of the proposal's choices exactly once, most preferred
+    /// first.
+    Vote { proposal_id: u64, ranking: Vec<u32> },
+    /// Executes the winning choice of a passed proposal.
+    Execute { proposal_id: u64 },
+    /// Closes a rejected proposal.
+    Close { proposal_id: u64 },
+    /// Updates the module's config. Only the DAO may call this.
+    /// Proposals which are already open are unaffected.
+    UpdateConfig {
+        quorum: PercentageThreshold,
+        voting_period: Duration,
+        deposit_info: Option<UncheckedDepositInfo>,
+    },
+    /// Adds an address as a consumer of proposal hooks. Only the DAO
+    /// may call this.
+    AddProposalHook { address: String },
+    /// Removes a consumer of proposal hooks. Only the DAO may call
+    /// this.
+    RemoveProposalHook { address: String },
+}
+
+#[proposal_module_query]
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Gets the module's config.
+    #[returns(crate::state::Config)]
+    Config {},
+    /// Gets information about a proposal.
+    #[returns(crate::proposal::ProposalResponse)]
+    Proposal { proposal_id: u64 },
+    /// Lists proposals in ascending order of ID.
+    #[returns(Vec<crate::proposal::ProposalResponse>)]
+    ListProposals {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Gets the ballot cast by VOTER on a proposal, if any.
+    #[returns(Option<crate::state::Ballot>)]
+    Ballot { proposal_id: u64, voter: String },
+    /// Lists the consumers of proposal hooks.
+    #[returns(cw_hooks::HooksResponse)]
+    ProposalHooks {},
+}
+
+pub use dao_interface::migrate::MigrateMsg;
@@ -0,0 +1,102 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, BlockInfo, CosmosMsg, Empty, Uint128};
+use cw_utils::Expiration;
+use dao_voting::{
+    deposit::CheckedDepositInfo, status::Status, threshold::PercentageThreshold,
+    voting::does_vote_count_pass,
+};
+
+use crate::tally::Tally;
+
+/// One of the choices voters rank on a proposal.
+#[cw_serde]
+pub struct Choice {
+    pub title: String,
+    /// The messages that will be executed should this choice win.
+    pub msgs: Vec<CosmosMsg<Empty>>,
+}
+
+#[cw_serde]
+pub struct CondorcetProposal {
+    pub title: String,
+    pub description: String,
+    /// The address that created this proposal.
+    pub proposer: Addr,
+    /// The block height at which this proposal was created. Voting
+    /// power is queried at this height.
+    pub start_height: u64,
+    /// The time at which this proposal will close for voting.
+    pub expiration: Expiration,
+    pub choices: Vec<Choice>,
+    /// The share of the total voting power which must vote for this
+    /// proposal to pass.
+    pub quorum: PercentageThreshold,
+    /// The total voting power at the time of this proposal's
+    /// creation.
+    pub total_power: Uint128,
+    pub tally: Tally,
+    pub status: Status,
+    /// The deposit paid by the proposer, if any.
+    pub deposit: Option<CheckedDepositInfo>,
+}
+
+#[cw_serde]
+pub struct ProposalResponse {
+    pub id: u64,
+    pub proposal: CondorcetProposal,
+    /// The index of the winning choice. Set once the proposal has
+    /// passed.
+    pub winner: Option<u32>,
+}
+
+impl CondorcetProposal {
+    /// Gets the current status of the proposal. Statuses are only
+    /// stored on vote, execute, and close events, so an open proposal
+    /// may have since passed or been rejected by expiring.
+    pub fn current_status(&self, block: &BlockInfo) -> Status {
+        if self.status != Status::Open {
+            return self.status;
+        }
+        let quorum_met = does_vote_count_pass(self.tally.power, self.total_power, self.quorum);
+        if self.expiration.is_expired(block) {
+            if quorum_met && self.tally.winner(Uint128::zero()).is_some() {
+                Status::Passed
+            } else {
+                Status::Rejected
+            }
+        } else {
+            // The proposal may pass early if the power which has not
+            // voted could not change the winner.
+            let undecided = self.total_power.saturating_sub(self.tally.power);
+            if quorum_met && self.tally.winner(undecided).is_some() {
+                Status::Passed
+            } else {
+                Status::Open
+            }
+        }
+    }
+
+    /// Gets the index of the winning choice if the proposal has
+    /// passed.
+    pub fn winner(&self, block: &BlockInfo) -> Option<u32> {
+        match self.current_status(block) {
+            Status::Passed | Status::Executed | Status::ExecutionFailed => {
+                self.tally.winner(Uint128::zero())
+            }
+            _ => None,
+        }
+    }
+
+    /// Consumes the proposal and returns a version which may be used
+    /// in a query response, with its status updated to the current
+    /// block.
+    pub fn into_response(mut self, block: &BlockInfo, id: u64) -> ProposalResponse {
+        let winner = self.winner(block);
+        self.status = self.current_status(block);
+        ProposalResponse {
+            id,
+            proposal: self,
+            winner,
+        }
+    }
+}
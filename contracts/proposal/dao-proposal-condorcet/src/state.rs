@@ -0,0 +1,42 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Uint128};
+use cw_hooks::Hooks;
+use cw_storage_plus::{Item, Map};
+use cw_utils::Duration;
+use dao_voting::{deposit::CheckedDepositInfo, threshold::PercentageThreshold};
+
+use crate::proposal::CondorcetProposal;
+
+/// The proposal module's configuration.
+#[cw_serde]
+pub struct Config {
+    /// The address of the DAO that this proposal module is associated
+    /// with.
+    pub dao: Addr,
+    /// The share of the DAO's voting power which must vote on a
+    /// proposal for it to pass.
+    pub quorum: PercentageThreshold,
+    /// How long proposals are open for voting.
+    pub voting_period: Duration,
+    /// The deposit required to create a proposal, if any.
+    pub deposit_info: Option<CheckedDepositInfo>,
+}
+
+/// A ballot cast on a proposal.
+#[cw_serde]
+pub struct Ballot {
+    /// The voting power behind the ballot.
+    pub power: Uint128,
+    /// The indexes of the proposal's choices, most preferred first.
+    pub ranking: Vec<u32>,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+/// The number of proposals that have been created.
+pub const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
+/// Proposals keyed by ID.
+pub const PROPOSALS: Map<u64, CondorcetProposal> = Map::new("proposals");
+/// Ballots keyed by proposal ID and voter.
+pub const BALLOTS: Map<(u64, &Addr), Ballot> = Map::new("ballots");
+/// Consumers of proposal hooks.
+pub const PROPOSAL_HOOKS: Hooks = Hooks::new("proposal_hooks");
@@ -0,0 +1,117 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Uint128;
+
+use crate::ContractError;
+
+/// Pairwise preferences between a proposal's choices.
+#[cw_serde]
+pub struct Tally {
+    /// The number of choices being ranked.
+    pub choices: u32,
+    /// `pairs[i * choices + j]` is the voting power which ranked
+    /// choice `i` above choice `j`.
+    pub pairs: Vec<Uint128>,
+    /// The voting power which has voted.
+    pub power: Uint128,
+}
+
+impl Tally {
+    pub fn new(choices: u32) -> Self {
+        Self {
+            choices,
+            pairs: vec![Uint128::zero(); (choices * choices) as usize],
+            power: Uint128::zero(),
+        }
+    }
+
+    /// The voting power which ranked choice `a` above choice `b`.
+    pub fn preferring(&self, a: u32, b: u32) -> Uint128 {
+        self.pairs[(a * self.choices + b) as usize]
+    }
+
+    /// Adds a ballot with POWER to the tally. RANKING must have been
+    /// checked with `validate_ranking`.
+    pub fn add_vote(&mut self, ranking: &[u32], power: Uint128) {
+        for (position, &above) in ranking.iter().enumerate() {
+            for &below in &ranking[position + 1..] {
+                self.pairs[(above * self.choices + below) as usize] += power;
+            }
+        }
+        self.power += power;
+    }
+
+    /// Gets the choice which beats every other choice head to head by
+    /// more than UNDECIDED voting power. Such a choice remains the
+    /// Condorcet winner however the undecided power votes. There is
+    /// at most one.
+    pub fn winner(&self, undecided: Uint128) -> Option<u32> {
+        (0..self.choices).find(|&a| {
+            (0..self.choices)
+                .filter(|&b| b != a)
+                .all(|b| self.preferring(a, b) > self.preferring(b, a) + undecided)
+        })
+    }
+}
+
+/// Checks that RANKING lists each of a proposal's CHOICES exactly
+/// once.
+pub fn validate_ranking(ranking: &[u32], choices: u32) -> Result<(), ContractError> {
+    let mut seen = vec![false; choices as usize];
+    if ranking.len() != seen.len() {
+        return Err(ContractError::InvalidRanking { choices });
+    }
+    for &choice in ranking {
+        match seen.get_mut(choice as usize) {
+            Some(seen) if !*seen => *seen = true,
+            _ => return Err(ContractError::InvalidRanking { choices }),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_ranking() {
+        validate_ranking(&[2, 0, 1], 3).unwrap();
+        for ranking in [
+            &[0, 1][..],
+            &[0, 1, 2, 3][..],
+            &[0, 0, 1][..],
+            &[0, 1, 3][..],
+        ] {
+            assert!(matches!(
+                validate_ranking(ranking, 3),
+                Err(ContractError::InvalidRanking { choices: 3 })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_winner() {
+        let mut tally = Tally::new(3);
+        tally.add_vote(&[0, 1, 2], Uint128::new(4));
+        tally.add_vote(&[1, 2, 0], Uint128::new(3));
+        assert_eq!(tally.preferring(0, 1), Uint128::new(4));
+        assert_eq!(tally.preferring(1, 0), Uint128::new(3));
+        assert_eq!(tally.preferring(1, 2), Uint128::new(7));
+        assert_eq!(tally.preferring(2, 0), Uint128::new(3));
+        assert_eq!(tally.power, Uint128::new(7));
+
+        // 0 beats 1 by 4 to 3 and 2 by 4 to 3.
+        assert_eq!(tally.winner(Uint128::zero()), Some(0));
+        // Another 1 of power ranking 1 or 2 first could tie it.
+        assert_eq!(tally.winner(Uint128::new(1)), None);
+    }
+
+    #[test]
+    fn test_cycle_has_no_winner() {
+        let mut tally = Tally::new(3);
+        tally.add_vote(&[0, 1, 2], Uint128::new(1));
+        tally.add_vote(&[1, 2, 0], Uint128::new(1));
+        tally.add_vote(&[2, 0, 1], Uint128::new(1));
+        assert_eq!(tally.winner(Uint128::zero()), None);
+    }
+}
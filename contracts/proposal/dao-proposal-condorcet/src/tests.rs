@@ -0,0 +1,545 @@
+use cosmwasm_std::testing::{
+    mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+};
+use cosmwasm_std::{
+    coins, from_binary, from_slice, to_binary, Addr, BankMsg, ContractResult, CosmosMsg, Empty,
+    Env, OwnedDeps, Reply, Response, SubMsgResult, SystemResult, Uint128, WasmMsg, WasmQuery,
+};
+use cw_denom::UncheckedDenom;
+use cw_utils::Duration;
+use dao_interface::{
+    migrate::MigrationError,
+    proposal::GenericProposalInfoResponse,
+    voting::{TotalPowerAtHeightResponse, VotingPowerAtHeightResponse},
+};
+use dao_voting::{
+    deposit::{DepositError, DepositRefundPolicy, DepositToken, UncheckedDepositInfo},
+    reply::{mask_proposal_execution_proposal_id, mask_proposal_hook_index},
+    status::Status,
+    threshold::PercentageThreshold,
+};
+
+use crate::contract::{
+    execute, instantiate, migrate, query, reply, CONTRACT_NAME, CONTRACT_VERSION,
+};
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::proposal::{Choice, ProposalResponse};
+use crate::state::Ballot;
+use crate::ContractError;
+
+const DAO: &str = "dao";
+const ALICE: &str = "alice";
+const BOB: &str = "bob";
+const CAROL: &str = "carol";
+const STRANGER: &str = "stranger";
+const DENOM: &str = "ujuno";
+const DEPOSIT: u128 = 100;
+
+type MockDeps = OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>;
+
+/// Alice, Bob, and Carol each have 10 voting power.
+fn mock_querier(deps: &mut MockDeps) {
+    deps.querier.update_wasm(|query| match query {
+        WasmQuery::Smart { contract_addr, msg } if contract_addr == DAO => {
+            let response = match from_slice(msg).unwrap() {
+                dao_core::msg::QueryMsg::VotingPowerAtHeight { address, .. } => {
+                    let power = if [ALICE, BOB, CAROL].contains(&address.as_str()) {
+                        Uint128::new(10)
+                    } else {
+                        Uint128::zero()
+                    };
+                    to_binary(&VotingPowerAtHeightResponse { power, height: 0 })
+                }
+                dao_core::msg::QueryMsg::TotalPowerAtHeight { .. } => {
+                    to_binary(&TotalPowerAtHeightResponse {
+                        power: Uint128::new(30),
+                        height: 0,
+                    })
+                }
+                _ => panic!("unexpected query"),
+            };
+            SystemResult::Ok(ContractResult::Ok(response.unwrap()))
+        }
+        _ => panic!("unexpected query"),
+    });
+}
+
+fn setup(deposit_info: Option<UncheckedDepositInfo>) -> MockDeps {
+    let mut deps = mock_dependencies();
+    mock_querier(&mut deps);
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        InstantiateMsg {
+            quorum: PercentageThreshold::Majority {},
+            voting_period: Duration::Height(10),
+            deposit_info,
+        },
+    )
+    .unwrap();
+    deps
+}
+
+fn deposit_info(refund_policy: DepositRefundPolicy) -> Option<UncheckedDepositInfo> {
+    Some(UncheckedDepositInfo {
+        denom: DepositToken::Token {
+            denom: UncheckedDenom::Native(DENOM.to_string()),
+        },
+        amount: Uint128::new(DEPOSIT),
+        refund_policy,
+        additional: vec![],
+        refund_on_cancel: false,
+    })
+}
+
+fn choice_msg(choice: u32) -> CosmosMsg {
+    BankMsg::Send {
+        to_address: format!("choice{choice}"),
+        amount: coins(1, DENOM),
+    }
+    .into()
+}
+
+fn propose(deps: &mut MockDeps, sender: &str, deposit: u128) -> Result<Response, ContractError> {
+    let funds = if deposit == 0 {
+        vec![]
+    } else {
+        coins(deposit, DENOM)
+    };
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(sender, &funds),
+        ExecuteMsg::Propose {
+            title: "title".to_string(),
+            description: "description".to_string(),
+            choices: (0..3)
+                .map(|choice| Choice {
+                    title: format!("choice {choice}"),
+                    msgs: vec![choice_msg(choice)],
+                })
+                .collect(),
+        },
+    )
+}
+
+fn vote(deps: &mut MockDeps, env: Env, voter: &str, ranking: &[u32]) -> Result<(), ContractError> {
+    execute(
+        deps.as_mut(),
+        env,
+        mock_info(voter, &[]),
+        ExecuteMsg::Vote {
+            proposal_id: 1,
+            ranking: ranking.to_vec(),
+        },
+    )
+    .map(|_| ())
+}
+
+fn after_voting_period() -> Env {
+    let mut env = mock_env();
+    env.block.height += 10;
+    env
+}
+
+fn query_proposal(deps: &MockDeps, env: Env) -> ProposalResponse {
+    from_binary(&query(deps.as_ref(), env, QueryMsg::Proposal { proposal_id: 1 }).unwrap()).unwrap()
+}
+
+fn messages(res: Response) -> Vec<CosmosMsg> {
+    res.messages.into_iter().map(|msg| msg.msg).collect()
+}
+
+fn execute_hook(choice: u32) -> CosmosMsg {
+    WasmMsg::Execute {
+        contract_addr: DAO.to_string(),
+        msg: to_binary(&dao_core::msg::ExecuteMsg::ExecuteProposalHook {
+            msgs: vec![choice_msg(choice)],
+        })
+        .unwrap(),
+        funds: vec![],
+    }
+    .into()
+}
+
+fn deposit_to(addr: &str) -> CosmosMsg {
+    BankMsg::Send {
+        to_address: addr.to_string(),
+        amount: coins(DEPOSIT, DENOM),
+    }
+    .into()
+}
+
+#[test]
+fn test_propose() {
+    let mut deps = setup(None);
+
+    let err = propose(&mut deps, STRANGER, 0).unwrap_err();
+    assert!(matches!(err, ContractError::NotVoter {}));
+    let err = propose(&mut deps, ALICE, DEPOSIT).unwrap_err();
+    assert!(matches!(err, ContractError::PaymentError(_)));
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ALICE, &[]),
+        ExecuteMsg::Propose {
+            title: "title".to_string(),
+            description: "description".to_string(),
+            choices: vec![Choice {
+                title: "only".to_string(),
+                msgs: vec![],
+            }],
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(
+        err,
+        ContractError::WrongChoiceCount { min: 2, max: 20 }
+    ));
+
+    propose(&mut deps, ALICE, 0).unwrap();
+    let next: u64 =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::NextProposalId {}).unwrap())
+            .unwrap();
+    assert_eq!(next, 2);
+
+    let proposal = query_proposal(&deps, mock_env());
+    assert_eq!(proposal.proposal.total_power, Uint128::new(30));
+    assert_eq!(proposal.proposal.status, Status::Open);
+    assert_eq!(proposal.winner, None);
+
+    // Nobody voted, so the proposal is rejected once it expires.
+    let info: GenericProposalInfoResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            after_voting_period(),
+            QueryMsg::GenericProposalInfo { proposal_id: 1 },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(info.status, "rejected");
+    assert_eq!(info.proposer, Addr::unchecked(ALICE));
+}
+
+#[test]
+fn test_vote() {
+    let mut deps = setup(None);
+    propose(&mut deps, ALICE, 0).unwrap();
+
+    let err = vote(&mut deps, mock_env(), STRANGER, &[0, 1, 2]).unwrap_err();
+    assert!(matches!(err, ContractError::NotVoter {}));
+    let err = vote(&mut deps, mock_env(), ALICE, &[0, 1]).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidRanking { choices: 3 }));
+    let err = vote(&mut deps, mock_env(), ALICE, &[0, 1, 1]).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidRanking { choices: 3 }));
+
+    vote(&mut deps, mock_env(), ALICE, &[2, 0, 1]).unwrap();
+    let err = vote(&mut deps, mock_env(), ALICE, &[0, 1, 2]).unwrap_err();
+    assert!(matches!(err, ContractError::AlreadyVoted { id: 1 }));
+
+    let ballot: Option<Ballot> = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Ballot {
+                proposal_id: 1,
+                voter: ALICE.to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        ballot,
+        Some(Ballot {
+            power: Uint128::new(10),
+            ranking: vec![2, 0, 1],
+        })
+    );
+
+    let err = vote(&mut deps, after_voting_period(), BOB, &[0, 1, 2]).unwrap_err();
+    assert!(matches!(err, ContractError::NotOpen { id: 1 }));
+}
+
+#[test]
+fn test_condorcet_winner_passes_early() {
+    let mut deps = setup(None);
+    propose(&mut deps, ALICE, 0).unwrap();
+
+    // Quorum is not met after one vote.
+    vote(&mut deps, mock_env(), ALICE, &[1, 0, 2]).unwrap();
+    assert_eq!(
+        query_proposal(&deps, mock_env()).proposal.status,
+        Status::Open
+    );
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(CAROL, &[]),
+        ExecuteMsg::Execute { proposal_id: 1 },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::NotPassed { id: 1 }));
+
+    // Choice 1 is preferred to every other choice by 20 to 0, which
+    // Carol's 10 power cannot overturn.
+    vote(&mut deps, mock_env(), BOB, &[1, 2, 0]).unwrap();
+    let proposal = query_proposal(&deps, mock_env());
+    assert_eq!(proposal.proposal.status, Status::Passed);
+    assert_eq!(proposal.winner, Some(1));
+    let err = vote(&mut deps, mock_env(), CAROL, &[0, 1, 2]).unwrap_err();
+    assert!(matches!(err, ContractError::NotOpen { id: 1 }));
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(CAROL, &[]),
+        ExecuteMsg::Execute { proposal_id: 1 },
+    )
+    .unwrap();
+    assert_eq!(res.messages[0].id, mask_proposal_execution_proposal_id(1));
+    assert_eq!(messages(res), vec![execute_hook(1)]);
+    let proposal = query_proposal(&deps, mock_env());
+    assert_eq!(proposal.proposal.status, Status::Executed);
+    assert_eq!(proposal.winner, Some(1));
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(CAROL, &[]),
+        ExecuteMsg::Execute { proposal_id: 1 },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::NotPassed { id: 1 }));
+
+    reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: mask_proposal_execution_proposal_id(1),
+            result: SubMsgResult::Err("error".to_string()),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        query_proposal(&deps, mock_env()).proposal.status,
+        Status::ExecutionFailed
+    );
+}
+
+#[test]
+fn test_cycle_is_rejected() {
+    let mut deps = setup(None);
+    propose(&mut deps, ALICE, 0).unwrap();
+
+    vote(&mut deps, mock_env(), ALICE, &[0, 1, 2]).unwrap();
+    vote(&mut deps, mock_env(), BOB, &[1, 2, 0]).unwrap();
+    vote(&mut deps, mock_env(), CAROL, &[2, 0, 1]).unwrap();
+    // Every choice loses to another, so there is no winner, but the
+    // proposal stays open until it expires.
+    assert_eq!(
+        query_proposal(&deps, mock_env()).proposal.status,
+        Status::Open
+    );
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ALICE, &[]),
+        ExecuteMsg::Close { proposal_id: 1 },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::NotRejected { id: 1 }));
+
+    let proposal = query_proposal(&deps, after_voting_period());
+    assert_eq!(proposal.proposal.status, Status::Rejected);
+    assert_eq!(proposal.winner, None);
+    let err = execute(
+        deps.as_mut(),
+        after_voting_period(),
+        mock_info(ALICE, &[]),
+        ExecuteMsg::Execute { proposal_id: 1 },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::NotPassed { id: 1 }));
+
+    let res = execute(
+        deps.as_mut(),
+        after_voting_period(),
+        mock_info(ALICE, &[]),
+        ExecuteMsg::Close { proposal_id: 1 },
+    )
+    .unwrap();
+    assert!(res.messages.is_empty());
+    assert_eq!(
+        query_proposal(&deps, after_voting_period()).proposal.status,
+        Status::Closed
+    );
+}
+
+#[test]
+fn test_last_vote_decides() {
+    let mut deps = setup(None);
+    propose(&mut deps, ALICE, 0).unwrap();
+
+    // Choices 0 and 1 are tied, so Carol's vote decides between them.
+    vote(&mut deps, mock_env(), ALICE, &[0, 1, 2]).unwrap();
+    vote(&mut deps, mock_env(), BOB, &[1, 0, 2]).unwrap();
+    assert_eq!(
+        query_proposal(&deps, mock_env()).proposal.status,
+        Status::Open
+    );
+
+    // Choice 1 now beats 0 and 2 by 20 to 10.
+    vote(&mut deps, mock_env(), CAROL, &[2, 1, 0]).unwrap();
+    let proposal = query_proposal(&deps, mock_env());
+    assert_eq!(proposal.proposal.status, Status::Passed);
+    assert_eq!(proposal.winner, Some(1));
+}
+
+#[test]
+fn test_deposit() {
+    let mut deps = setup(deposit_info(DepositRefundPolicy::OnlyPassed));
+
+    let err = propose(&mut deps, ALICE, 0).unwrap_err();
+    assert!(matches!(err, ContractError::DepositError(_)));
+    let err = propose(&mut deps, ALICE, DEPOSIT - 1).unwrap_err();
+    assert!(matches!(
+        err,
+        ContractError::DepositError(DepositError::InvalidDeposit { .. })
+    ));
+
+    // Rejected proposals forfeit their deposit to the DAO.
+    propose(&mut deps, ALICE, DEPOSIT).unwrap();
+    let res = execute(
+        deps.as_mut(),
+        after_voting_period(),
+        mock_info(BOB, &[]),
+        ExecuteMsg::Close { proposal_id: 1 },
+    )
+    .unwrap();
+    assert_eq!(messages(res), vec![deposit_to(DAO)]);
+
+    // Executed proposals have their deposit refunded.
+    propose(&mut deps, ALICE, DEPOSIT).unwrap();
+    for voter in [ALICE, BOB] {
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(voter, &[]),
+            ExecuteMsg::Vote {
+                proposal_id: 2,
+                ranking: vec![2, 1, 0],
+            },
+        )
+        .unwrap();
+    }
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(BOB, &[]),
+        ExecuteMsg::Execute { proposal_id: 2 },
+    )
+    .unwrap();
+    assert_eq!(messages(res), vec![execute_hook(2), deposit_to(ALICE)]);
+
+    let mut deps = setup(deposit_info(DepositRefundPolicy::Always));
+    propose(&mut deps, ALICE, DEPOSIT).unwrap();
+    let res = execute(
+        deps.as_mut(),
+        after_voting_period(),
+        mock_info(BOB, &[]),
+        ExecuteMsg::Close { proposal_id: 1 },
+    )
+    .unwrap();
+    assert_eq!(messages(res), vec![deposit_to(ALICE)]);
+}
+
+#[test]
+fn test_proposal_hooks() {
+    let mut deps = setup(None);
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ALICE, &[]),
+        ExecuteMsg::AddProposalHook {
+            address: "hook".to_string(),
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        ExecuteMsg::AddProposalHook {
+            address: "hook".to_string(),
+        },
+    )
+    .unwrap();
+
+    let res = propose(&mut deps, ALICE, 0).unwrap();
+    assert_eq!(res.messages.len(), 1);
+    assert_eq!(res.messages[0].id, mask_proposal_hook_index(0));
+    match &res.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, .. }) => {
+            assert_eq!(contract_addr, "hook")
+        }
+        _ => panic!("expected a wasm message"),
+    }
+
+    // A failing hook is removed.
+    reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: mask_proposal_hook_index(0),
+            result: SubMsgResult::Err("error".to_string()),
+        },
+    )
+    .unwrap();
+    let hooks: cw_hooks::HooksResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::ProposalHooks {}).unwrap())
+            .unwrap();
+    assert!(hooks.hooks.is_empty());
+}
+
+#[test]
+fn test_update_config() {
+    let mut deps = setup(None);
+    let update = ExecuteMsg::UpdateConfig {
+        quorum: PercentageThreshold::Majority {},
+        voting_period: Duration::Height(5),
+        deposit_info: deposit_info(DepositRefundPolicy::Never),
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ALICE, &[]),
+        update.clone(),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    execute(deps.as_mut(), mock_env(), mock_info(DAO, &[]), update).unwrap();
+    let config: crate::state::Config =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap()).unwrap();
+    assert_eq!(config.voting_period, Duration::Height(5));
+    assert_eq!(
+        config.deposit_info.unwrap().refund_policy,
+        DepositRefundPolicy::Never
+    );
+}
+
+#[test]
+fn test_migrate_checks_stored_version() {
+    let mut deps = mock_dependencies();
+    cw2::set_contract_version(&mut deps.storage, CONTRACT_NAME, CONTRACT_VERSION).unwrap();
+    let err = migrate(deps.as_mut(), mock_env(), MigrateMsg::FromV1(Empty {})).unwrap_err();
+    assert!(matches!(
+        err,
+        ContractError::MigrationError(MigrationError::NotPreviousMajor { .. })
+    ));
+    migrate(deps.as_mut(), mock_env(), MigrateMsg::FromCompatible {}).unwrap();
+}
@@ -1,38 +1,53 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_binary, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Reply, Response, StdResult,
-    Storage, SubMsg, WasmMsg,
+    from_binary, to_binary, Addr, Binary, BlockInfo, Deps, DepsMut, Empty, Env, MessageInfo, Order,
+    Reply, Response, StdResult, Storage, SubMsg, SubMsgResult, WasmMsg,
 };
 
 use cw2::set_contract_version;
-use cw_hooks::Hooks;
+use cw_hooks::{FailureOutcome, HookError, Hooks, HooksResponse};
+use cw_paginate::{clamp_limit, keyset_bounds};
 use cw_storage_plus::Bound;
-use cw_utils::{parse_reply_instantiate_data, Duration};
-use dao_interface::voting::IsActiveResponse;
+use cw_utils::{parse_reply_instantiate_data, Duration, Expiration};
+use dao_events::EventSource;
+use dao_interface::{proposal::GenericProposalInfoResponse, querier::CoreQuerier};
 use dao_pre_propose_multiple::contract::ExecuteMsg as PreProposeMsg;
-use dao_proposal_hooks::{new_proposal_hooks, proposal_status_changed_hooks};
-use dao_vote_hooks::new_vote_hooks;
+use dao_proposal_hooks::{new_proposal_hooks, proposal_status_changed_hooks, ProposalContent};
+use dao_vote_hooks::{NewVote, VoteHookDispatch, VoteTally};
 use dao_voting::{
     multiple_choice::{
         MultipleChoiceOptions, MultipleChoiceVote, MultipleChoiceVotes, VotingStrategy,
     },
-    pre_propose::{PreProposeInfo, ProposalCreationPolicy},
-    proposal::{DEFAULT_LIMIT, MAX_PROPOSAL_SIZE},
+    pre_propose::{PreProposeFailure, PreProposeFallback, PreProposeInfo, ProposalCreationPolicy},
+    proposal::{
+        expiration_index_bounds, expiration_index_key, validate_max_proposal_size,
+        validate_prune_statuses, MaxOpenProposals, MultipleChoiceProposeMsg, PrunedProposal,
+        RebuildIndexesCursor, DEFAULT_LIMIT, MAX_LIMIT,
+    },
     reply::{
-        failed_pre_propose_module_hook_id, mask_proposal_execution_proposal_id, TaggedReplyId,
+        failed_pre_propose_module_hook_id, mask_proposal_execution_proposal_id, HookFailureRecord,
+        HookKind, TaggedReplyId, MAX_HOOK_FAILURE_HISTORY,
     },
+    simulation::{simulate_execution, SimulationResponse},
     status::Status,
-    voting::{get_total_power, get_voting_power, validate_voting_period},
+    voting::{get_voting_power, validate_voting_period, VoteListOrder},
 };
 
-use crate::{msg::MigrateMsg, state::CREATION_POLICY};
+use crate::{
+    msg::MigrateMsg,
+    state::{CREATION_POLICY, GOVERNANCE_STATS, HOOK_FAILURE_HISTORY, PRE_PROPOSE_FAILURE},
+};
 use crate::{
     msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
     proposal::{MultipleChoiceProposal, VoteResult},
-    query::{ProposalListResponse, ProposalResponse, VoteInfo, VoteListResponse, VoteResponse},
+    query::{
+        BallotInfo, BallotListResponse, ExportStateResponse, ProposalListResponse,
+        ProposalResponse, VerifyTallyResponse, VoteInfo, VoteListResponse, VoteResponse,
+    },
     state::{
-        Ballot, Config, BALLOTS, CONFIG, PROPOSALS, PROPOSAL_COUNT, PROPOSAL_HOOKS, VOTE_HOOKS,
+        ballots, proposals, Ballot, Config, CONFIG, IMPORTING, POWER_CACHE, PROPOSAL_COUNT,
+        PROPOSAL_HOOKS, PROPOSAL_HOOK_CONTENT, PRUNED_PROPOSALS, VOTE_HOOKS, VOTE_HOOK_BUFFER,
     },
     ContractError,
 };
@@ -40,6 +55,12 @@ use crate::{
 pub const CONTRACT_NAME: &str = "crates.io:dao-proposal-multiple";
 pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+const EVENTS: EventSource = EventSource::new(CONTRACT_NAME, CONTRACT_VERSION);
+
+/// The number of times a proposal or vote hook may fail before it is
+/// removed.
+pub const HOOK_RETRIES: u32 = 3;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -50,12 +71,17 @@ pub fn instantiate(
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     msg.voting_strategy.validate()?;
+    let voting_strategy_summary = msg.voting_strategy.describe().summary;
 
     let dao = info.sender;
 
     let (min_voting_period, max_voting_period) =
         validate_voting_period(msg.min_voting_period, msg.max_voting_period)?;
 
+    if let Some(max_open_proposals) = &msg.max_open_proposals {
+        max_open_proposals.validate()?;
+    }
+
     let (initial_policy, pre_propose_messages) = msg
         .pre_propose_info
         .into_initial_policy_and_messages(dao.clone())?;
@@ -68,17 +94,22 @@ pub fn instantiate(
         allow_revoting: msg.allow_revoting,
         dao,
         close_proposal_on_execution_failure: msg.close_proposal_on_execution_failure,
+        max_proposal_size: validate_max_proposal_size(msg.max_proposal_size)?,
+        max_open_proposals: msg.max_open_proposals,
+        pre_propose_fallback: msg.pre_propose_fallback.unwrap_or_default(),
     };
 
     // Initialize proposal count to zero so that queries return zero
     // instead of None.
     PROPOSAL_COUNT.save(deps.storage, &0)?;
+    IMPORTING.save(deps.storage, &true)?;
     CONFIG.save(deps.storage, &config)?;
     CREATION_POLICY.save(deps.storage, &initial_policy)?;
 
     Ok(Response::default()
         .add_submessages(pre_propose_messages)
         .add_attribute("action", "instantiate")
+        .add_attribute("voting_strategy", voting_strategy_summary)
         .add_attribute("dao", config.dao))
 }
 
@@ -90,12 +121,12 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response<Empty>, ContractError> {
     match msg {
-        ExecuteMsg::Propose {
+        ExecuteMsg::Propose(MultipleChoiceProposeMsg {
             title,
             description,
             choices,
             proposer,
-        } => execute_propose(
+        }) => execute_propose(
             deps,
             env,
             info.sender,
@@ -104,9 +135,18 @@ pub fn execute(
             choices,
             proposer,
         ),
-        ExecuteMsg::Vote { proposal_id, vote } => execute_vote(deps, env, info, proposal_id, vote),
+        ExecuteMsg::Vote {
+            proposal_id,
+            vote,
+            rationale,
+        } => execute_vote(deps, env, info, proposal_id, vote, rationale),
+        ExecuteMsg::UpdateRationale {
+            proposal_id,
+            rationale,
+        } => execute_update_rationale(deps, info, proposal_id, rationale),
         ExecuteMsg::Execute { proposal_id } => execute_execute(deps, env, info, proposal_id),
         ExecuteMsg::Close { proposal_id } => execute_close(deps, env, info, proposal_id),
+        ExecuteMsg::Cancel { proposal_id } => execute_cancel(deps, env, info, proposal_id),
         ExecuteMsg::UpdateConfig {
             voting_strategy,
             min_voting_period,
@@ -115,6 +155,9 @@ pub fn execute(
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
+            max_proposal_size,
+            max_open_proposals,
+            pre_propose_fallback,
         } => execute_update_config(
             deps,
             info,
@@ -125,13 +168,17 @@ pub fn execute(
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
+            max_proposal_size,
+            max_open_proposals,
+            pre_propose_fallback,
         ),
         ExecuteMsg::UpdatePreProposeInfo { info: new_info } => {
             execute_update_proposal_creation_policy(deps, info, new_info)
         }
-        ExecuteMsg::AddProposalHook { address } => {
-            execute_add_proposal_hook(deps, env, info, address)
-        }
+        ExecuteMsg::AddProposalHook {
+            address,
+            include_content,
+        } => execute_add_proposal_hook(deps, env, info, address, include_content),
         ExecuteMsg::RemoveProposalHook { address } => {
             execute_remove_proposal_hook(deps, env, info, address)
         }
@@ -139,11 +186,30 @@ pub fn execute(
         ExecuteMsg::RemoveVoteHook { address } => {
             execute_remove_vote_hook(deps, env, info, address)
         }
+        ExecuteMsg::Prune {
+            older_than,
+            statuses,
+            limit,
+        } => execute_prune(deps, info, older_than, statuses, limit),
+        ExecuteMsg::RebuildIndexes { start_after, limit } => {
+            execute_rebuild_indexes(deps, info, start_after, limit)
+        }
+        ExecuteMsg::UpdateVoteHookDispatch { dispatch } => {
+            execute_update_vote_hook_dispatch(deps, info, dispatch)
+        }
+        ExecuteMsg::FlushVoteHooks {} => execute_flush_vote_hooks(deps, info),
+        ExecuteMsg::ImportModuleState { module } => execute_import_module_state(deps, info, module),
+        ExecuteMsg::ImportProposals { proposals } => {
+            execute_import_proposals(deps, info, proposals)
+        }
+        ExecuteMsg::ImportVotes { proposal_id, votes } => {
+            execute_import_votes(deps, info, proposal_id, votes)
+        }
     }
 }
 
 pub fn execute_propose(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     sender: Addr,
     title: String,
@@ -163,7 +229,9 @@ pub fn execute_propose(
     // pre-propose module, it must be specified. Otherwise, the
     // proposer should not be specified.
     let proposer = match (proposer, &proposal_creation_policy) {
-        (None, ProposalCreationPolicy::Anyone {}) => sender.clone(),
+        (None, ProposalCreationPolicy::Anyone {} | ProposalCreationPolicy::Dao { .. }) => {
+            sender.clone()
+        }
         // `is_permitted` above checks that an allowed module is
         // actually sending the propose message.
         (Some(proposer), ProposalCreationPolicy::Module { .. }) => {
@@ -172,19 +240,27 @@ pub fn execute_propose(
         _ => return Err(ContractError::InvalidProposer {}),
     };
 
-    let voting_module: Addr = deps.querier.query_wasm_smart(
-        config.dao.clone(),
-        &dao_core::msg::QueryMsg::VotingModule {},
-    )?;
+    // Open proposals have not expired, so only proposals which expire
+    // after this block are read rather than every proposal last saved
+    // as open.
+    if let Some(max_open_proposals) = &config.max_open_proposals {
+        let open = unexpired_proposals(deps.storage, &env.block)?.filter_map(|item| {
+            item.and_then(|(_, proposal)| {
+                Ok((proposal.current_status(&env.block)? == Status::Open)
+                    .then_some(proposal.proposer))
+            })
+            .transpose()
+        });
+        max_open_proposals.check(&proposer, open)?;
+    }
 
     // Voting modules are not required to implement this
     // query. Lacking an implementation they are active by default.
-    let active_resp: IsActiveResponse = deps
-        .querier
-        .query_wasm_smart(voting_module, &dao_interface::voting::Query::IsActive {})
-        .unwrap_or(IsActiveResponse { active: true });
+    let active = CoreQuerier::new(deps.querier, config.dao.clone())
+        .voting_module_querier()?
+        .is_active();
 
-    if !active_resp.active {
+    if !active {
         return Err(ContractError::InactiveDao {});
     }
 
@@ -192,7 +268,7 @@ pub fn execute_propose(
     let checked_multiple_choice_options = options.into_checked()?.options;
 
     let expiration = config.max_voting_period.after(&env.block);
-    let total_power = get_total_power(deps.as_ref(), config.dao, None)?;
+    let total_power = POWER_CACHE.total_power(deps.branch(), &config.dao, env.block.height)?;
 
     let proposal = {
         // Limit mutability to this block.
@@ -216,6 +292,7 @@ pub fn execute_propose(
         proposal
     };
     let id = advance_proposal_id(deps.storage)?;
+    IMPORTING.remove(deps.storage);
 
     // Limit the size of proposals.
     //
@@ -225,30 +302,52 @@ pub fn execute_propose(
     // this check it is possible to create a proposal that can not be
     // queried.
     //
-    // The size selected was determined by uploading versions of this
+    // The default size was determined by uploading versions of this
     // contract to the Juno mainnet until queries worked within a
-    // reasonable margin of error.
+    // reasonable margin of error. DAOs on chains with other limits
+    // may configure their own.
     //
     // `to_vec` is the method used by cosmwasm to convert a struct
     // into it's byte representation in storage.
     let proposal_size = cosmwasm_std::to_vec(&proposal)?.len() as u64;
-    if proposal_size > MAX_PROPOSAL_SIZE {
+    if proposal_size > config.max_proposal_size {
         return Err(ContractError::ProposalTooLarge {
             size: proposal_size,
-            max: MAX_PROPOSAL_SIZE,
+            max: config.max_proposal_size,
         });
     }
 
-    PROPOSALS.save(deps.storage, id, &proposal)?;
+    proposals().save(deps.storage, id, &proposal)?;
+    GOVERNANCE_STATS.proposal_created(deps.storage)?;
 
-    let hooks = new_proposal_hooks(PROPOSAL_HOOKS, deps.storage, id, proposer.as_str())?;
+    let hooks = new_proposal_hooks(
+        PROPOSAL_HOOKS,
+        deps.storage,
+        id,
+        proposer.as_str(),
+        ProposalContent {
+            title: proposal.title,
+            description: proposal.description,
+            expiration: proposal.expiration,
+            content_cid: None,
+        },
+        |hook| PROPOSAL_HOOK_CONTENT.has(deps.storage, hook.clone()),
+    )?;
 
     Ok(Response::default()
         .add_submessages(hooks)
         .add_attribute("action", "propose")
         .add_attribute("sender", sender)
         .add_attribute("proposal_id", id.to_string())
-        .add_attribute("status", proposal.status.to_string()))
+        .add_attribute("status", proposal.status.to_string())
+        .add_event(
+            EVENTS
+                .event("propose")
+                .proposal_id(id)
+                .address("proposer", &proposer)
+                .attribute("status", proposal.status.to_string())
+                .into(),
+        ))
 }
 
 pub fn execute_vote(
@@ -257,9 +356,10 @@ pub fn execute_vote(
     info: MessageInfo,
     proposal_id: u64,
     vote: MultipleChoiceVote,
+    rationale: Option<String>,
 ) -> Result<Response<Empty>, ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    let mut prop = PROPOSALS
+    let mut prop = proposals()
         .may_load(deps.storage, proposal_id)?
         .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
 
@@ -278,18 +378,23 @@ pub fn execute_vote(
     if prop.expiration.is_expired(&env.block) {
         return Err(ContractError::Expired { id: proposal_id });
     }
+    // Cancelled proposals are withdrawn and no longer tally opinions.
+    if prop.status == Status::Cancelled {
+        return Err(ContractError::NotOpen { id: proposal_id });
+    }
 
     let vote_power = get_voting_power(
         deps.as_ref(),
         info.sender.clone(),
-        config.dao,
+        config.dao.clone(),
         Some(prop.start_height),
     )?;
     if vote_power.is_zero() {
         return Err(ContractError::NotRegistered {});
     }
 
-    BALLOTS.update(
+    let first_vote = !ballots().has(deps.storage, (proposal_id, info.sender.clone()));
+    ballots().update(
         deps.storage,
         (proposal_id, info.sender.clone()),
         |bal| match bal {
@@ -307,6 +412,10 @@ pub fn execute_vote(
                         Ok(Ballot {
                             power: vote_power,
                             vote,
+                            // Roll over the previous rationale. If
+                            // you're changing your vote, you've also
+                            // likely changed your thinking.
+                            rationale: rationale.clone(),
                         })
                     }
                 } else {
@@ -316,6 +425,7 @@ pub fn execute_vote(
             None => Ok(Ballot {
                 vote,
                 power: vote_power,
+                rationale: rationale.clone(),
             }),
         },
     )?;
@@ -324,7 +434,10 @@ pub fn execute_vote(
 
     prop.votes.add_vote(vote, vote_power)?;
     prop.update_status(&env.block)?;
-    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+    proposals().save(deps.storage, proposal_id, &prop)?;
+    if first_vote {
+        GOVERNANCE_STATS.vote_cast(deps.storage, &info.sender)?;
+    }
     let new_status = prop.status;
     let change_hooks = proposal_status_changed_hooks(
         PROPOSAL_HOOKS,
@@ -333,21 +446,67 @@ pub fn execute_vote(
         old_status.to_string(),
         new_status.to_string(),
     )?;
-    let vote_hooks = new_vote_hooks(
+    let vote_hooks = VOTE_HOOK_BUFFER.new_vote(
         VOTE_HOOKS,
         deps.storage,
-        proposal_id,
-        info.sender.to_string(),
-        vote.to_string(),
+        NewVote {
+            proposal_id,
+            voter: info.sender.to_string(),
+            vote: vote.to_string(),
+            power: vote_power,
+            height: prop.start_height,
+            tally: VoteTally::MultipleChoice(prop.votes),
+        },
     )?;
     Ok(Response::default()
         .add_submessages(change_hooks)
         .add_submessages(vote_hooks)
         .add_attribute("action", "vote")
-        .add_attribute("sender", info.sender)
+        .add_attribute("sender", info.sender.clone())
         .add_attribute("proposal_id", proposal_id.to_string())
         .add_attribute("position", vote.to_string())
-        .add_attribute("status", prop.status.to_string()))
+        .add_attribute("rationale", rationale.as_deref().unwrap_or("_none"))
+        .add_attribute("status", prop.status.to_string())
+        .add_event(
+            EVENTS
+                .event("vote")
+                .proposal_id(proposal_id)
+                .address("voter", &info.sender)
+                .attribute("position", vote.to_string())
+                .attribute("power", vote_power.to_string())
+                .attribute("status", prop.status.to_string())
+                .into(),
+        ))
+}
+
+pub fn execute_update_rationale(
+    deps: DepsMut,
+    info: MessageInfo,
+    proposal_id: u64,
+    rationale: Option<String>,
+) -> Result<Response, ContractError> {
+    ballots().update(
+        deps.storage,
+        // info.sender can't be forged so we implicitly access control
+        // with the key.
+        (proposal_id, info.sender.clone()),
+        |ballot| match ballot {
+            Some(ballot) => Ok(Ballot {
+                rationale: rationale.clone(),
+                ..ballot
+            }),
+            None => Err(ContractError::NoSuchVote {
+                id: proposal_id,
+                voter: info.sender.to_string(),
+            }),
+        },
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("action", "update_rationale")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("rationale", rationale.as_deref().unwrap_or("_none")))
 }
 
 pub fn execute_execute(
@@ -369,7 +528,7 @@ pub fn execute_execute(
         }
     }
 
-    let mut prop = PROPOSALS
+    let mut prop = proposals()
         .may_load(deps.storage, proposal_id)?
         .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
 
@@ -384,7 +543,13 @@ pub fn execute_execute(
 
     prop.status = Status::Executed;
 
-    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+    proposals().save(deps.storage, proposal_id, &prop)?;
+    GOVERNANCE_STATS.proposal_completed(
+        deps.storage,
+        true,
+        prop.votes.total(),
+        prop.total_power,
+    )?;
 
     let vote_result = prop.calculate_vote_result()?;
     match vote_result {
@@ -423,7 +588,9 @@ pub fn execute_execute(
             // Add prepropose / deposit module hook which will handle deposit refunds.
             let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
             let hooks = match proposal_creation_policy {
-                ProposalCreationPolicy::Anyone {} => hooks,
+                ProposalCreationPolicy::Anyone {}
+                | ProposalCreationPolicy::Dao { .. }
+                | ProposalCreationPolicy::Frozen {} => hooks,
                 ProposalCreationPolicy::Module { addr } => {
                     let msg = to_binary(&PreProposeMsg::ProposalCompletedHook {
                         proposal_id,
@@ -445,9 +612,17 @@ pub fn execute_execute(
             Ok(response
                 .add_submessages(hooks)
                 .add_attribute("action", "execute")
-                .add_attribute("sender", info.sender)
+                .add_attribute("sender", info.sender.clone())
                 .add_attribute("proposal_id", proposal_id.to_string())
-                .add_attribute("dao", config.dao))
+                .add_attribute("dao", config.dao)
+                .add_event(
+                    EVENTS
+                        .event("execute")
+                        .proposal_id(proposal_id)
+                        .address("sender", &info.sender)
+                        .attribute("status", prop.status.to_string())
+                        .into(),
+                ))
         }
     }
 }
@@ -458,7 +633,7 @@ pub fn execute_close(
     info: MessageInfo,
     proposal_id: u64,
 ) -> Result<Response<Empty>, ContractError> {
-    let mut prop = PROPOSALS.load(deps.storage, proposal_id)?;
+    let mut prop = proposals().load(deps.storage, proposal_id)?;
 
     prop.update_status(&env.block)?;
     if prop.status != Status::Rejected {
@@ -469,7 +644,13 @@ pub fn execute_close(
 
     prop.status = Status::Closed;
 
-    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+    proposals().save(deps.storage, proposal_id, &prop)?;
+    GOVERNANCE_STATS.proposal_completed(
+        deps.storage,
+        false,
+        prop.votes.total(),
+        prop.total_power,
+    )?;
 
     let hooks = proposal_status_changed_hooks(
         PROPOSAL_HOOKS,
@@ -482,7 +663,9 @@ pub fn execute_close(
     // Add prepropose / deposit module hook which will handle deposit refunds.
     let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
     let hooks = match proposal_creation_policy {
-        ProposalCreationPolicy::Anyone {} => hooks,
+        ProposalCreationPolicy::Anyone {}
+        | ProposalCreationPolicy::Dao { .. }
+        | ProposalCreationPolicy::Frozen {} => hooks,
         ProposalCreationPolicy::Module { addr } => {
             let msg = to_binary(&PreProposeMsg::ProposalCompletedHook {
                 proposal_id,
@@ -503,8 +686,92 @@ pub fn execute_close(
     Ok(Response::default()
         .add_submessages(hooks)
         .add_attribute("action", "close")
-        .add_attribute("sender", info.sender)
-        .add_attribute("proposal_id", proposal_id.to_string()))
+        .add_attribute("sender", info.sender.clone())
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_event(
+            EVENTS
+                .event("close")
+                .proposal_id(proposal_id)
+                .address("sender", &info.sender)
+                .into(),
+        ))
+}
+
+pub fn execute_cancel(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response<Empty>, ContractError> {
+    let mut prop = proposals()
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+
+    if info.sender != prop.proposer {
+        return Err(ContractError::Unauthorized {});
+    }
+    // Update status to ensure that proposals which have since passed
+    // or been rejected may not be cancelled.
+    prop.update_status(&env.block)?;
+    if prop.status != Status::Open {
+        return Err(ContractError::NotOpen { id: proposal_id });
+    }
+
+    let old_status = prop.status;
+
+    prop.status = Status::Cancelled;
+    proposals().save(deps.storage, proposal_id, &prop)?;
+    GOVERNANCE_STATS.proposal_completed(
+        deps.storage,
+        false,
+        prop.votes.total(),
+        prop.total_power,
+    )?;
+
+    let hooks = proposal_status_changed_hooks(
+        PROPOSAL_HOOKS,
+        deps.storage,
+        proposal_id,
+        old_status.to_string(),
+        prop.status.to_string(),
+    )?;
+
+    // Add prepropose / deposit module hook which will handle deposit refunds.
+    let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
+    let hooks = match proposal_creation_policy {
+        ProposalCreationPolicy::Anyone {}
+        | ProposalCreationPolicy::Dao { .. }
+        | ProposalCreationPolicy::Frozen {} => hooks,
+        ProposalCreationPolicy::Module { addr } => {
+            let msg = to_binary(&PreProposeMsg::ProposalCompletedHook {
+                proposal_id,
+                new_status: prop.status,
+            })?;
+            let mut hooks = hooks;
+            hooks.push(SubMsg::reply_on_error(
+                WasmMsg::Execute {
+                    contract_addr: addr.into_string(),
+                    msg,
+                    funds: vec![],
+                },
+                failed_pre_propose_module_hook_id(),
+            ));
+            hooks
+        }
+    };
+
+    Ok(Response::default()
+        .add_submessages(hooks)
+        .add_attribute("action", "cancel")
+        .add_attribute("sender", info.sender.clone())
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_event(
+            EVENTS
+                .event("cancel")
+                .proposal_id(proposal_id)
+                .address("proposer", &info.sender)
+                .into(),
+        ))
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -518,6 +785,9 @@ pub fn execute_update_config(
     allow_revoting: bool,
     dao: String,
     close_proposal_on_execution_failure: bool,
+    max_proposal_size: Option<u64>,
+    max_open_proposals: Option<MaxOpenProposals>,
+    pre_propose_fallback: Option<PreProposeFallback>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
@@ -527,11 +797,16 @@ pub fn execute_update_config(
     }
 
     voting_strategy.validate()?;
+    let voting_strategy_summary = voting_strategy.describe().summary;
 
     let dao = deps.api.addr_validate(&dao)?;
 
     let (min_voting_period, max_voting_period) =
         validate_voting_period(min_voting_period, max_voting_period)?;
+    let max_proposal_size = validate_max_proposal_size(max_proposal_size)?;
+    if let Some(max_open_proposals) = &max_open_proposals {
+        max_open_proposals.validate()?;
+    }
 
     CONFIG.save(
         deps.storage,
@@ -543,12 +818,22 @@ pub fn execute_update_config(
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
+            max_proposal_size,
+            max_open_proposals,
+            pre_propose_fallback: pre_propose_fallback.unwrap_or(config.pre_propose_fallback),
         },
     )?;
 
     Ok(Response::default()
         .add_attribute("action", "update_config")
-        .add_attribute("sender", info.sender))
+        .add_attribute("sender", info.sender)
+        .add_attribute("voting_strategy", voting_strategy_summary.clone())
+        .add_event(
+            EVENTS
+                .event("update_config")
+                .attribute("voting_strategy", voting_strategy_summary)
+                .into(),
+        ))
 }
 
 pub fn execute_update_proposal_creation_policy(
@@ -576,6 +861,7 @@ pub fn execute_add_proposal_hook(
     _env: Env,
     info: MessageInfo,
     address: String,
+    include_content: bool,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
     if config.dao != info.sender {
@@ -585,7 +871,10 @@ pub fn execute_add_proposal_hook(
 
     let validated_address = deps.api.addr_validate(&address)?;
 
-    add_hook(PROPOSAL_HOOKS, deps.storage, validated_address)?;
+    add_hook(PROPOSAL_HOOKS, deps.storage, validated_address.clone())?;
+    if include_content {
+        PROPOSAL_HOOK_CONTENT.save(deps.storage, validated_address, &Empty {})?;
+    }
 
     Ok(Response::default()
         .add_attribute("action", "add_proposal_hook")
@@ -606,7 +895,8 @@ pub fn execute_remove_proposal_hook(
 
     let validated_address = deps.api.addr_validate(&address)?;
 
-    remove_hook(PROPOSAL_HOOKS, deps.storage, validated_address)?;
+    remove_hook(PROPOSAL_HOOKS, deps.storage, validated_address.clone())?;
+    PROPOSAL_HOOK_CONTENT.remove(deps.storage, validated_address);
 
     Ok(Response::default()
         .add_attribute("action", "remove_proposal_hook")
@@ -687,6 +977,358 @@ pub fn advance_proposal_id(store: &mut dyn Storage) -> StdResult<u64> {
     Ok(id)
 }
 
+pub fn execute_prune(
+    deps: DepsMut,
+    info: MessageInfo,
+    older_than: u64,
+    statuses: Vec<Status>,
+    limit: Option<u64>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.dao {
+        return Err(ContractError::Unauthorized {});
+    }
+    validate_prune_statuses(&statuses)?;
+
+    let limit = clamp_limit(limit, DEFAULT_LIMIT, MAX_LIMIT) as usize;
+    let mut pruned = vec![];
+    for status in statuses {
+        // Proposal IDs are assigned in the order proposals are
+        // created, so once a proposal that is too new is found all
+        // following proposals are as well.
+        let matching = proposals()
+            .idx
+            .status
+            .prefix(status as u8)
+            .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+            .take_while(|item| {
+                item.as_ref()
+                    .map_or(true, |(_, proposal)| proposal.start_height < older_than)
+            })
+            .take(limit - pruned.len())
+            .collect::<StdResult<Vec<_>>>()?;
+        for (id, proposal) in matching {
+            prune_proposal(deps.storage, id, proposal)?;
+            pruned.push(id.to_string());
+        }
+    }
+
+    Ok(Response::default()
+        .add_attribute("action", "prune")
+        .add_attribute("pruned", pruned.len().to_string())
+        .add_event(
+            EVENTS
+                .event("prune")
+                .address("sender", &info.sender)
+                .attribute("proposal_ids", pruned.join(","))
+                .into(),
+        ))
+}
+
+/// Iterates over the proposals which expire after BLOCK. Every open
+/// proposal is among them.
+fn unexpired_proposals<'a>(
+    storage: &'a dyn Storage,
+    block: &BlockInfo,
+) -> StdResult<impl Iterator<Item = StdResult<(u64, MultipleChoiceProposal)>> + 'a> {
+    let index = proposals().idx.expiration;
+    let (heights, after_height, _) =
+        expiration_index_bounds(Some(Expiration::AtHeight(block.height)), None)?;
+    let (times, after_time, _) =
+        expiration_index_bounds(Some(Expiration::AtTime(block.time)), None)?;
+    let (never, _) = expiration_index_key(&Expiration::Never {});
+    Ok(index
+        .sub_prefix(heights)
+        .range(storage, after_height, None, Order::Ascending)
+        .chain(
+            index
+                .sub_prefix(times)
+                .range(storage, after_time, None, Order::Ascending),
+        )
+        .chain(
+            index
+                .sub_prefix(never)
+                .range(storage, None, None, Order::Ascending),
+        ))
+}
+
+pub fn execute_rebuild_indexes(
+    deps: DepsMut,
+    info: MessageInfo,
+    start_after: Option<RebuildIndexesCursor>,
+    limit: Option<u64>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.dao {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let limit = clamp_limit(limit, DEFAULT_LIMIT, MAX_LIMIT) as usize;
+    let (mut saved, mut next, ballots_after) = match start_after {
+        Some(RebuildIndexesCursor::Ballot { proposal_id, voter }) => (
+            0,
+            None,
+            Some((proposal_id, deps.api.addr_validate(&voter)?)),
+        ),
+        Some(RebuildIndexesCursor::Proposal { id }) => {
+            let (saved, next) = rebuild_proposals(deps.storage, Some(id), limit)?;
+            (saved, next, None)
+        }
+        None => {
+            let (saved, next) = rebuild_proposals(deps.storage, None, limit)?;
+            (saved, next, None)
+        }
+    };
+    // Ballots are rebuilt once every proposal has been.
+    if saved < limit {
+        let page = ballots()
+            .range(
+                deps.storage,
+                ballots_after
+                    .as_ref()
+                    .map(|(id, voter)| Bound::exclusive((*id, voter.clone()))),
+                None,
+                Order::Ascending,
+            )
+            .take(limit - saved)
+            .collect::<StdResult<Vec<_>>>()?;
+        let done = page.len() < limit - saved;
+        saved += page.len();
+        next = None;
+        for ((proposal_id, voter), ballot) in page {
+            ballots().save(deps.storage, (proposal_id, voter.clone()), &ballot)?;
+            if !done {
+                next = Some(RebuildIndexesCursor::Ballot {
+                    proposal_id,
+                    voter: voter.into_string(),
+                });
+            }
+        }
+    }
+
+    Ok(Response::default()
+        .set_data(to_binary(&next)?)
+        .add_attribute("action", "rebuild_indexes")
+        .add_attribute("saved", saved.to_string())
+        .add_attribute("complete", next.is_none().to_string()))
+}
+
+/// Saves up to LIMIT proposals after START_AFTER again, returning how
+/// many were saved and where to continue from.
+fn rebuild_proposals(
+    storage: &mut dyn Storage,
+    start_after: Option<u64>,
+    limit: usize,
+) -> StdResult<(usize, Option<RebuildIndexesCursor>)> {
+    let page = proposals()
+        .range(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+    let next = page
+        .last()
+        .map(|(id, _)| RebuildIndexesCursor::Proposal { id: *id });
+    for (id, proposal) in &page {
+        proposals().save(storage, *id, proposal)?;
+    }
+    Ok((page.len(), next))
+}
+
+pub fn execute_update_vote_hook_dispatch(
+    deps: DepsMut,
+    info: MessageInfo,
+    dispatch: VoteHookDispatch,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.dao {
+        return Err(ContractError::Unauthorized {});
+    }
+    VOTE_HOOK_BUFFER.set_dispatch(deps.storage, &dispatch)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "update_vote_hook_dispatch")
+        .add_attribute("sender", info.sender))
+}
+
+pub fn execute_flush_vote_hooks(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let buffered = VOTE_HOOK_BUFFER.len(deps.storage)?;
+    let vote_hooks = VOTE_HOOK_BUFFER.flush(VOTE_HOOKS, deps.storage)?;
+
+    Ok(Response::default()
+        .add_submessages(vote_hooks)
+        .add_attribute("action", "flush_vote_hooks")
+        .add_attribute("sender", info.sender)
+        .add_attribute("votes", buffered.to_string()))
+}
+
+pub fn execute_import_module_state(
+    deps: DepsMut,
+    info: MessageInfo,
+    module: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.dao {
+        return Err(ContractError::Unauthorized {});
+    }
+    // Proposal IDs continue from those of the replaced module, so
+    // this module must not have assigned any yet.
+    if PROPOSAL_COUNT.may_load(deps.storage)?.unwrap_or_default() != 0 {
+        return Err(ContractError::ProposalsExist {});
+    }
+    let module = deps.api.addr_validate(&module)?;
+
+    let next_id: u64 = deps
+        .querier
+        .query_wasm_smart(&module, &QueryMsg::NextProposalId {})?;
+    PROPOSAL_COUNT.save(deps.storage, &next_id.saturating_sub(1))?;
+
+    let policy: ProposalCreationPolicy = deps
+        .querier
+        .query_wasm_smart(&module, &QueryMsg::ProposalCreationPolicy {})?;
+    CREATION_POLICY.save(deps.storage, &policy)?;
+
+    let proposal_hooks: HooksResponse = deps
+        .querier
+        .query_wasm_smart(&module, &QueryMsg::ProposalHooks {})?;
+    for hook in proposal_hooks.hooks {
+        import_hook(PROPOSAL_HOOKS, deps.storage, deps.api.addr_validate(&hook)?)?;
+    }
+    let vote_hooks: HooksResponse = deps
+        .querier
+        .query_wasm_smart(&module, &QueryMsg::VoteHooks {})?;
+    for hook in vote_hooks.hooks {
+        import_hook(VOTE_HOOKS, deps.storage, deps.api.addr_validate(&hook)?)?;
+    }
+
+    Ok(Response::default()
+        .add_attribute("action", "import_module_state")
+        .add_attribute("next_proposal_id", next_id.to_string())
+        .add_event(
+            EVENTS
+                .event("import_module_state")
+                .address("module", &module)
+                .attribute("next_proposal_id", next_id.to_string())
+                .into(),
+        ))
+}
+
+pub fn execute_import_proposals(
+    deps: DepsMut,
+    info: MessageInfo,
+    imported: Vec<ProposalResponse>,
+) -> Result<Response, ContractError> {
+    check_importing(deps.as_ref(), &info.sender)?;
+
+    let mut count = PROPOSAL_COUNT.load(deps.storage)?;
+    let imported_count = imported.len();
+    for ProposalResponse { id, mut proposal } in imported {
+        if proposals().has(deps.storage, id) || PRUNED_PROPOSALS.has(deps.storage, id) {
+            return Err(ContractError::ProposalExists { id });
+        }
+        proposal.proposer = deps.api.addr_validate(proposal.proposer.as_str())?;
+        proposals().save(deps.storage, id, &proposal)?;
+        count = count.max(id);
+    }
+    PROPOSAL_COUNT.save(deps.storage, &count)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "import_proposals")
+        .add_attribute("imported", imported_count.to_string())
+        .add_attribute("proposal_count", count.to_string()))
+}
+
+pub fn execute_import_votes(
+    deps: DepsMut,
+    info: MessageInfo,
+    proposal_id: u64,
+    votes: Vec<VoteInfo>,
+) -> Result<Response, ContractError> {
+    check_importing(deps.as_ref(), &info.sender)?;
+    if !proposals().has(deps.storage, proposal_id) {
+        return Err(ContractError::NoSuchProposal { id: proposal_id });
+    }
+
+    let imported_count = votes.len();
+    for VoteInfo {
+        voter,
+        vote,
+        power,
+        rationale,
+    } in votes
+    {
+        let voter = deps.api.addr_validate(voter.as_str())?;
+        ballots().save(
+            deps.storage,
+            (proposal_id, voter),
+            &Ballot {
+                power,
+                vote,
+                rationale,
+            },
+        )?;
+    }
+
+    Ok(Response::default()
+        .add_attribute("action", "import_votes")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("imported", imported_count.to_string()))
+}
+
+/// Checks that SENDER is the DAO and that this module has not yet
+/// created a proposal.
+fn check_importing(deps: Deps, sender: &Addr) -> Result<(), ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if *sender != config.dao {
+        return Err(ContractError::Unauthorized {});
+    }
+    if !IMPORTING.may_load(deps.storage)?.unwrap_or_default() {
+        return Err(ContractError::ImportClosed {});
+    }
+    Ok(())
+}
+
+/// Registers HOOK with HOOKS unless it is already registered.
+fn import_hook(hooks: Hooks, storage: &mut dyn Storage, hook: Addr) -> Result<(), ContractError> {
+    match hooks.add_hook(storage, hook) {
+        Ok(()) | Err(HookError::HookAlreadyRegistered {}) => Ok(()),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Deletes a proposal and its ballots, keeping a summary of it in
+/// `PRUNED_PROPOSALS`.
+fn prune_proposal(
+    storage: &mut dyn Storage,
+    id: u64,
+    proposal: MultipleChoiceProposal,
+) -> StdResult<()> {
+    let voters = ballots()
+        .prefix(id)
+        .keys(storage, None, None, cosmwasm_std::Order::Ascending)
+        .collect::<StdResult<Vec<Addr>>>()?;
+    for voter in voters {
+        ballots().remove(storage, (id, voter))?;
+    }
+    proposals().remove(storage, id)?;
+    PRUNED_PROPOSALS.save(
+        storage,
+        id,
+        &PrunedProposal {
+            title: proposal.title,
+            proposer: proposal.proposer,
+            start_height: proposal.start_height,
+            status: proposal.status,
+        },
+    )
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
@@ -696,22 +1338,56 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             query_list_proposals(deps, env, start_after, limit)
         }
         QueryMsg::NextProposalId {} => query_next_proposal_id(deps),
+        QueryMsg::GenericProposalInfo { proposal_id } => {
+            query_generic_proposal_info(deps, env, proposal_id)
+        }
+        QueryMsg::ListVotesByVoter {
+            voter,
+            start_after,
+            limit,
+        } => query_list_votes_by_voter(deps, voter, start_after, limit),
         QueryMsg::ProposalCount {} => query_proposal_count(deps),
         QueryMsg::GetVote { proposal_id, voter } => query_vote(deps, proposal_id, voter),
         QueryMsg::ListVotes {
             proposal_id,
             start_after,
             limit,
-        } => query_list_votes(deps, proposal_id, start_after, limit),
+            order,
+        } => query_list_votes(deps, proposal_id, start_after, limit, order),
         QueryMsg::Info {} => query_info(deps),
         QueryMsg::ReverseProposals {
             start_before,
             limit,
         } => query_reverse_proposals(deps, env, start_before, limit),
+        QueryMsg::ListProposalsByExpiration {
+            after,
+            before,
+            limit,
+        } => query_list_proposals_by_expiration(deps, env, after, before, limit),
         QueryMsg::ProposalCreationPolicy {} => query_creation_policy(deps),
+        QueryMsg::PreProposeFailure {} => to_binary(&PRE_PROPOSE_FAILURE.may_load(deps.storage)?),
         QueryMsg::ProposalHooks {} => to_binary(&PROPOSAL_HOOKS.query_hooks(deps)?),
         QueryMsg::VoteHooks {} => to_binary(&VOTE_HOOKS.query_hooks(deps)?),
+        QueryMsg::ProposalHookFailures {} => to_binary(&PROPOSAL_HOOKS.query_failures(deps)?),
+        QueryMsg::VoteHookFailures {} => to_binary(&VOTE_HOOKS.query_failures(deps)?),
+        QueryMsg::HookFailures {} => to_binary(
+            &HOOK_FAILURE_HISTORY
+                .may_load(deps.storage)?
+                .unwrap_or_default(),
+        ),
+        QueryMsg::GovernanceStats {} => to_binary(&GOVERNANCE_STATS.query(deps.storage)?),
+        QueryMsg::SimulateExecution { proposal_id } => {
+            to_binary(&query_simulate_execution(deps, env, proposal_id)?)
+        }
+        QueryMsg::VoteHookDispatch {} => to_binary(&VOTE_HOOK_BUFFER.query(deps.storage)?),
         QueryMsg::Dao {} => query_dao(deps),
+        QueryMsg::VerifyTally { proposal_id } => query_verify_tally(deps, proposal_id),
+        QueryMsg::PrunedProposal { proposal_id } => {
+            to_binary(&PRUNED_PROPOSALS.may_load(deps.storage, proposal_id)?)
+        }
+        QueryMsg::ExportState { start_after, limit } => {
+            query_export_state(deps, start_after, limit)
+        }
     }
 }
 
@@ -726,25 +1402,80 @@ pub fn query_dao(deps: Deps) -> StdResult<Binary> {
 }
 
 pub fn query_proposal(deps: Deps, env: Env, id: u64) -> StdResult<Binary> {
-    let proposal = PROPOSALS.load(deps.storage, id)?;
+    let proposal = proposals().load(deps.storage, id)?;
     to_binary(&proposal.into_response(&env.block, id)?)
 }
 
+pub fn query_simulate_execution(
+    deps: Deps,
+    env: Env,
+    id: u64,
+) -> StdResult<Vec<SimulationResponse>> {
+    let proposal = proposals().load(deps.storage, id)?;
+    let dao = CONFIG.load(deps.storage)?.dao;
+    proposal
+        .choices
+        .iter()
+        .map(|choice| {
+            simulate_execution(deps, &dao, &choice.msgs, |contract, msg| {
+                if contract == &dao {
+                    from_binary::<dao_core::msg::ExecuteMsg>(msg).err()
+                } else if contract == &env.contract.address {
+                    from_binary::<ExecuteMsg>(msg).err()
+                } else {
+                    None
+                }
+            })
+        })
+        .collect()
+}
+
+pub fn query_generic_proposal_info(deps: Deps, env: Env, id: u64) -> StdResult<Binary> {
+    let proposal = proposals().load(deps.storage, id)?;
+    to_binary(&GenericProposalInfoResponse {
+        status: proposal.current_status(&env.block)?.to_string(),
+        proposer: proposal.proposer,
+        expiration: proposal.expiration,
+        created: proposal.start_height,
+    })
+}
+
 pub fn query_creation_policy(deps: Deps) -> StdResult<Binary> {
     let policy = CREATION_POLICY.load(deps.storage)?;
     to_binary(&policy)
 }
 
+pub fn query_export_state(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let limit = clamp_limit(limit, DEFAULT_LIMIT, MAX_LIMIT);
+    let (min, max) = keyset_bounds(start_after, Order::Ascending);
+    let proposals = proposals()
+        .range(deps.storage, min, max, Order::Ascending)
+        .take(limit as usize)
+        .map(|item| item.map(|(id, proposal)| ProposalResponse { id, proposal }))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    to_binary(&ExportStateResponse {
+        config: CONFIG.load(deps.storage)?,
+        creation_policy: CREATION_POLICY.load(deps.storage)?,
+        proposal_count: PROPOSAL_COUNT.load(deps.storage)?,
+        proposals,
+    })
+}
+
 pub fn query_list_proposals(
     deps: Deps,
     env: Env,
     start_after: Option<u64>,
     limit: Option<u64>,
 ) -> StdResult<Binary> {
-    let min = start_after.map(Bound::exclusive);
-    let limit = limit.unwrap_or(DEFAULT_LIMIT);
-    let props: Vec<ProposalResponse> = PROPOSALS
-        .range(deps.storage, min, None, cosmwasm_std::Order::Ascending)
+    let limit = clamp_limit(limit, DEFAULT_LIMIT, MAX_LIMIT);
+    let (min, max) = keyset_bounds(start_after, cosmwasm_std::Order::Ascending);
+    let props: Vec<ProposalResponse> = proposals()
+        .range(deps.storage, min, max, cosmwasm_std::Order::Ascending)
         .take(limit as usize)
         .collect::<Result<Vec<(u64, MultipleChoiceProposal)>, _>>()?
         .into_iter()
@@ -760,10 +1491,10 @@ pub fn query_reverse_proposals(
     start_before: Option<u64>,
     limit: Option<u64>,
 ) -> StdResult<Binary> {
-    let limit = limit.unwrap_or(DEFAULT_LIMIT);
-    let max = start_before.map(Bound::exclusive);
-    let props: Vec<ProposalResponse> = PROPOSALS
-        .range(deps.storage, None, max, cosmwasm_std::Order::Descending)
+    let limit = clamp_limit(limit, DEFAULT_LIMIT, MAX_LIMIT);
+    let (min, max) = keyset_bounds(start_before, cosmwasm_std::Order::Descending);
+    let props: Vec<ProposalResponse> = proposals()
+        .range(deps.storage, min, max, cosmwasm_std::Order::Descending)
         .take(limit as usize)
         .collect::<Result<Vec<(u64, MultipleChoiceProposal)>, _>>()?
         .into_iter()
@@ -773,6 +1504,27 @@ pub fn query_reverse_proposals(
     to_binary(&ProposalListResponse { proposals: props })
 }
 
+pub fn query_list_proposals_by_expiration(
+    deps: Deps,
+    env: Env,
+    after: Option<Expiration>,
+    before: Option<Expiration>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let limit = clamp_limit(limit, DEFAULT_LIMIT, MAX_LIMIT);
+    let (prefix, min, max) = expiration_index_bounds(after, before)?;
+    let props = proposals()
+        .idx
+        .expiration
+        .sub_prefix(prefix)
+        .range(deps.storage, min, max, Order::Ascending)
+        .take(limit as usize)
+        .map(|item| item.and_then(|(id, proposal)| proposal.into_response(&env.block, id)))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    to_binary(&ProposalListResponse { proposals: props })
+}
+
 pub fn query_next_proposal_id(deps: Deps) -> StdResult<Binary> {
     to_binary(&next_proposal_id(deps.storage)?)
 }
@@ -784,11 +1536,12 @@ pub fn query_proposal_count(deps: Deps) -> StdResult<Binary> {
 
 pub fn query_vote(deps: Deps, proposal_id: u64, voter: String) -> StdResult<Binary> {
     let voter = deps.api.addr_validate(&voter)?;
-    let ballot = BALLOTS.may_load(deps.storage, (proposal_id, voter.clone()))?;
+    let ballot = ballots().may_load(deps.storage, (proposal_id, voter.clone()))?;
     let vote = ballot.map(|ballot| VoteInfo {
         voter,
         vote: ballot.vote,
         power: ballot.power,
+        rationale: ballot.rationale,
     });
     to_binary(&VoteResponse { vote })
 }
@@ -798,28 +1551,105 @@ pub fn query_list_votes(
     proposal_id: u64,
     start_after: Option<String>,
     limit: Option<u64>,
+    order: Option<VoteListOrder>,
 ) -> StdResult<Binary> {
-    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let limit = clamp_limit(limit, DEFAULT_LIMIT, MAX_LIMIT);
+    let order = order.unwrap_or(VoteListOrder::VoterAscending);
     let start_after = start_after
         .map(|addr| deps.api.addr_validate(&addr))
         .transpose()?;
-    let min = start_after.map(Bound::<Addr>::exclusive);
 
-    let votes = BALLOTS
-        .prefix(proposal_id)
+    let listed = if order.by_power() {
+        // Power is part of the index key, so paginating by voter
+        // requires knowing the power of their ballot.
+        let start_after = start_after
+            .map(|voter| -> StdResult<_> {
+                let ballot = ballots().load(deps.storage, (proposal_id, voter.clone()))?;
+                Ok((ballot.power.u128(), (proposal_id, voter)))
+            })
+            .transpose()?;
+        let (min, max) = keyset_bounds(start_after, order.storage_order());
+        ballots()
+            .idx
+            .power
+            .sub_prefix(proposal_id)
+            .range(deps.storage, min, max, order.storage_order())
+            .take(limit as usize)
+            .map(|item| item.map(|((_, voter), ballot)| (voter, ballot)))
+            .collect::<StdResult<Vec<_>>>()?
+    } else {
+        let (min, max) = keyset_bounds(start_after, order.storage_order());
+        ballots()
+            .prefix(proposal_id)
+            .range(deps.storage, min, max, order.storage_order())
+            .take(limit as usize)
+            .collect::<StdResult<Vec<_>>>()?
+    };
+
+    let votes = listed
+        .into_iter()
+        .map(|(voter, ballot)| VoteInfo {
+            voter,
+            vote: ballot.vote,
+            power: ballot.power,
+            rationale: ballot.rationale,
+        })
+        .collect();
+
+    to_binary(&VoteListResponse { votes })
+}
+
+pub fn query_list_votes_by_voter(
+    deps: Deps,
+    voter: String,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let limit = clamp_limit(limit, DEFAULT_LIMIT, MAX_LIMIT);
+    let voter = deps.api.addr_validate(&voter)?;
+    let min = start_after.map(|proposal_id| Bound::exclusive((proposal_id, voter.clone())));
+
+    let votes = ballots()
+        .idx
+        .voter
+        .prefix(voter)
         .range(deps.storage, min, None, cosmwasm_std::Order::Ascending)
         .take(limit as usize)
         .map(|item| {
-            let (voter, ballot) = item?;
-            Ok(VoteInfo {
-                voter,
+            let ((proposal_id, _), ballot) = item?;
+            Ok(BallotInfo {
+                proposal_id,
                 vote: ballot.vote,
                 power: ballot.power,
+                rationale: ballot.rationale,
             })
         })
         .collect::<StdResult<Vec<_>>>()?;
 
-    to_binary(&VoteListResponse { votes })
+    to_binary(&BallotListResponse { ballots: votes })
+}
+
+pub fn query_verify_tally(deps: Deps, proposal_id: u64) -> StdResult<Binary> {
+    let proposal = proposals().load(deps.storage, proposal_id)?;
+    let mut recounted = MultipleChoiceVotes::zero(proposal.choices.len());
+    let mut counted = 0u64;
+    for item in ballots().prefix(proposal_id).range(
+        deps.storage,
+        None,
+        None,
+        cosmwasm_std::Order::Ascending,
+    ) {
+        let (_, ballot) = item?;
+        recounted.add_vote(ballot.vote, ballot.power)?;
+        counted += 1;
+    }
+
+    to_binary(&VerifyTallyResponse {
+        consistent: proposal.votes == recounted,
+        cached: proposal.votes,
+        recounted,
+        ballots: counted,
+    })
 }
 
 pub fn query_info(deps: Deps) -> StdResult<Binary> {
@@ -828,26 +1658,59 @@ pub fn query_info(deps: Deps) -> StdResult<Binary> {
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
     let repl = TaggedReplyId::new(msg.id)?;
     match repl {
         TaggedReplyId::FailedProposalExecution(proposal_id) => {
-            PROPOSALS.update(deps.storage, proposal_id, |prop| match prop {
+            proposals().update(deps.storage, proposal_id, |prop| match prop {
                 Some(mut prop) => {
                     prop.status = Status::ExecutionFailed;
                     Ok(prop)
                 }
                 None => Err(ContractError::NoSuchProposal { id: proposal_id }),
             })?;
+            GOVERNANCE_STATS.execution_failed(deps.storage)?;
             Ok(Response::new().add_attribute("proposal execution failed", proposal_id.to_string()))
         }
         TaggedReplyId::FailedProposalHook(idx) => {
-            let addr = PROPOSAL_HOOKS.remove_hook_by_index(deps.storage, idx)?;
-            Ok(Response::new().add_attribute("removed_proposal_hook", format!("{addr}:{idx}")))
+            let outcome = PROPOSAL_HOOKS.record_failure(deps.storage, idx, HOOK_RETRIES)?;
+            record_hook_failure(
+                deps.storage,
+                &env,
+                &msg,
+                HookKind::Proposal,
+                outcome.hook().clone(),
+                outcome.is_removed(),
+            )?;
+            match outcome {
+                FailureOutcome::Removed { hook } => {
+                    PROPOSAL_HOOK_CONTENT.remove(deps.storage, hook.clone());
+                    Ok(Response::new()
+                        .add_attribute("removed_proposal_hook", format!("{hook}:{idx}")))
+                }
+                FailureOutcome::Retained { hook, failures } => Ok(Response::new()
+                    .add_attribute("failed_proposal_hook", format!("{hook}:{idx}"))
+                    .add_attribute("failures", failures.to_string())),
+            }
         }
         TaggedReplyId::FailedVoteHook(idx) => {
-            let addr = VOTE_HOOKS.remove_hook_by_index(deps.storage, idx)?;
-            Ok(Response::new().add_attribute("removed vote hook", format!("{addr}:{idx}")))
+            let outcome = VOTE_HOOKS.record_failure(deps.storage, idx, HOOK_RETRIES)?;
+            record_hook_failure(
+                deps.storage,
+                &env,
+                &msg,
+                HookKind::Vote,
+                outcome.hook().clone(),
+                outcome.is_removed(),
+            )?;
+            match outcome {
+                FailureOutcome::Removed { hook } => {
+                    Ok(Response::new().add_attribute("removed vote hook", format!("{hook}:{idx}")))
+                }
+                FailureOutcome::Retained { hook, failures } => Ok(Response::new()
+                    .add_attribute("failed_vote_hook", format!("{hook}:{idx}"))
+                    .add_attribute("failures", failures.to_string())),
+            }
         }
         TaggedReplyId::PreProposeModuleInstantiation => {
             let res = parse_reply_instantiate_data(msg)?;
@@ -861,30 +1724,87 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
         }
         TaggedReplyId::FailedPreProposeModuleHook => {
             let addr = match CREATION_POLICY.load(deps.storage)? {
-                ProposalCreationPolicy::Anyone {} => {
-                    // Something is off if we're getting this
-                    // reply and we don't have a pre-propose
-                    // module installed. This should be
-                    // unreachable.
+                ProposalCreationPolicy::Module { addr } => addr,
+                // Something is off if we're getting this reply and
+                // we don't have a pre-propose module installed. This
+                // should be unreachable.
+                ProposalCreationPolicy::Anyone {}
+                | ProposalCreationPolicy::Dao { .. }
+                | ProposalCreationPolicy::Frozen {} => {
                     return Err(ContractError::InvalidReplyID {
                         id: failed_pre_propose_module_hook_id(),
                     });
                 }
-                ProposalCreationPolicy::Module { addr } => {
-                    // If we are here, our pre-propose module has
-                    // errored while receiving a proposal
-                    // hook. Rest in peace pre-propose module.
-                    CREATION_POLICY.save(deps.storage, &ProposalCreationPolicy::Anyone {})?;
-                    addr
-                }
             };
-            Ok(Response::new().add_attribute("failed_prepropose_hook", format!("{addr}")))
+
+            // If we are here, our pre-propose module has errored
+            // while receiving a proposal hook. Rest in peace
+            // pre-propose module.
+            let config = CONFIG.load(deps.storage)?;
+            let fallback = config.pre_propose_fallback;
+            CREATION_POLICY.save(deps.storage, &fallback.clone().into_policy(config.dao))?;
+            PRE_PROPOSE_FAILURE.save(
+                deps.storage,
+                &PreProposeFailure {
+                    module: addr.clone(),
+                    height: env.block.height,
+                    fallback: fallback.clone(),
+                },
+            )?;
+            record_hook_failure(
+                deps.storage,
+                &env,
+                &msg,
+                HookKind::PreProposeModule,
+                addr.clone(),
+                true,
+            )?;
+
+            Ok(Response::new()
+                .add_attribute("failed_prepropose_hook", format!("{addr}"))
+                .add_event(
+                    EVENTS
+                        .event("pre_propose_fallback")
+                        .address("module", &addr)
+                        .attribute("fallback", fallback.to_string())
+                        .into(),
+                ))
         }
     }
 }
 
+/// Adds a hook's failure to the hook failure history, dropping the
+/// oldest failure if the history is full.
+fn record_hook_failure(
+    storage: &mut dyn Storage,
+    env: &Env,
+    reply: &Reply,
+    kind: HookKind,
+    hook: Addr,
+    removed: bool,
+) -> StdResult<()> {
+    let reason = match &reply.result {
+        SubMsgResult::Err(err) => err.clone(),
+        SubMsgResult::Ok(_) => String::new(),
+    };
+    let mut history = HOOK_FAILURE_HISTORY.may_load(storage)?.unwrap_or_default();
+    history.push(HookFailureRecord {
+        kind,
+        hook,
+        reason,
+        height: env.block.height,
+        reply_id: reply.id,
+        removed,
+    });
+    if history.len() > MAX_HOOK_FAILURE_HISTORY {
+        history.remove(0);
+    }
+    HOOK_FAILURE_HISTORY.save(storage, &history)
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    msg.check_stored_version(deps.storage, CONTRACT_VERSION)?;
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     Ok(Response::default())
 }
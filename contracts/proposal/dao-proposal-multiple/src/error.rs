@@ -3,6 +3,7 @@ use std::u64;
 use cosmwasm_std::StdError;
 use cw_hooks::HookError;
 use cw_utils::ParseReplyError;
+use dao_interface::migrate::MigrationError;
 use dao_voting::{reply::error::TagError, threshold::ThresholdError};
 use thiserror::Error;
 
@@ -11,6 +12,9 @@ pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
+    #[error(transparent)]
+    MigrationError(#[from] MigrationError),
+
     #[error(transparent)]
     ParseReplyError(#[from] ParseReplyError),
 
@@ -38,12 +42,18 @@ pub enum ContractError {
     #[error("Proposal ({id}) is expired")]
     Expired { id: u64 },
 
+    #[error("Proposal ({id}) is not open.")]
+    NotOpen { id: u64 },
+
     #[error("Not registered to vote (no voting power) at time of proposal creation.")]
     NotRegistered {},
 
     #[error("Already voted. This proposal does not support revoting.")]
     AlreadyVoted {},
 
+    #[error("no vote exists for proposal ({id}) and voter ({voter})")]
+    NoSuchVote { id: u64, voter: String },
+
     #[error("Already cast a vote with that option. Change your vote to revote.")]
     AlreadyCast {},
 
@@ -95,4 +105,13 @@ pub enum ContractError {
 
     #[error("received a reply failure with an invalid ID: ({id})")]
     InvalidReplyID { id: u64 },
+
+    #[error("module state may only be imported before any proposals have been created")]
+    ProposalsExist {},
+
+    #[error("proposals may only be imported before this module creates a proposal")]
+    ImportClosed {},
+
+    #[error("proposal ({id}) already exists")]
+    ProposalExists { id: u64 },
 }
@@ -1,11 +1,17 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cw_utils::Duration;
+use cw_utils::{Duration, Expiration};
 use dao_macros::proposal_module_query;
+use dao_vote_hooks::VoteHookDispatch;
 use dao_voting::{
-    multiple_choice::{MultipleChoiceOptions, MultipleChoiceVote, VotingStrategy},
-    pre_propose::PreProposeInfo,
+    multiple_choice::{MultipleChoiceVote, VotingStrategy},
+    pre_propose::{PreProposeFallback, PreProposeInfo},
+    proposal::{MaxOpenProposals, MultipleChoiceProposeMsg, RebuildIndexesCursor},
+    status::Status,
+    voting::VoteListOrder,
 };
 
+use crate::query::{ProposalResponse, VoteInfo};
+
 #[cw_serde]
 pub struct InstantiateMsg {
     /// Voting params configuration
@@ -37,25 +43,24 @@ pub struct InstantiateMsg {
     /// remain open until the DAO's treasury was large enough for it to be
     /// executed.
     pub close_proposal_on_execution_failure: bool,
+    /// The maximum size of a proposal in bytes. Defaults to
+    /// `MAX_PROPOSAL_SIZE` and may not exceed
+    /// `MAX_PROPOSAL_SIZE_CEILING`. Chains with a larger query size
+    /// limit than Juno may raise this.
+    pub max_proposal_size: Option<u64>,
+    /// Limits on the number of proposals which may be open at
+    /// once. Proposals created through a pre-propose module count
+    /// towards the limits of their proposer. None for no limits.
+    pub max_open_proposals: Option<MaxOpenProposals>,
+    /// Who may create proposals if the pre-propose module errors
+    /// while handling a hook. Defaults to anyone.
+    pub pre_propose_fallback: Option<PreProposeFallback>,
 }
 
 #[cw_serde]
 pub enum ExecuteMsg {
     /// Creates a proposal in the governance module.
-    Propose {
-        /// The title of the proposal.
-        title: String,
-        /// A description of the proposal.
-        description: String,
-        /// The multiple choices.
-        choices: MultipleChoiceOptions,
-        /// The address creating the proposal. If no pre-propose
-        /// module is attached to this module this must always be None
-        /// as the proposer is the sender of the propose message. If a
-        /// pre-propose module is attached, this must be Some and will
-        /// set the proposer of the proposal it creates.
-        proposer: Option<String>,
-    },
+    Propose(MultipleChoiceProposeMsg),
     /// Votes on a proposal. Voting power is determined by the DAO's
     /// voting power module.
     Vote {
@@ -63,6 +68,16 @@ pub enum ExecuteMsg {
         proposal_id: u64,
         /// The senders position on the proposal.
         vote: MultipleChoiceVote,
+        /// An optional rationale for why this vote was cast. This can
+        /// be updated, set, or removed later by the address casting
+        /// the vote.
+        rationale: Option<String>,
+    },
+    /// Updates the sender's rationale for their vote on the specified
+    /// proposal. Errors if no vote has been cast.
+    UpdateRationale {
+        proposal_id: u64,
+        rationale: Option<String>,
     },
     /// Causes the messages associated with a passed proposal to be
     /// executed by the DAO.
@@ -77,6 +92,13 @@ pub enum ExecuteMsg {
         /// The ID of the proposal to close.
         proposal_id: u64,
     },
+    /// Withdraws an open proposal. Only the proposal's proposer may
+    /// call this method. If applicable, the proposal deposit is
+    /// refunded according to its `refund_on_cancel` policy.
+    Cancel {
+        /// The ID of the proposal to cancel.
+        proposal_id: u64,
+    },
     /// Updates the governance module's config.
     UpdateConfig {
         /// The new proposal voting strategy. This will only apply
@@ -112,6 +134,17 @@ pub enum ExecuteMsg {
         /// remain open until the DAO's treasury was large enough for it to be
         /// executed.
         close_proposal_on_execution_failure: bool,
+        /// The maximum size of a proposal in bytes. Defaults to
+        /// `MAX_PROPOSAL_SIZE` if not set. Only applies to proposals
+        /// created after the config update.
+        max_proposal_size: Option<u64>,
+        /// Limits on the number of proposals which may be open at
+        /// once. None for no limits.
+        max_open_proposals: Option<MaxOpenProposals>,
+        /// Who may create proposals if the pre-propose module errors
+        /// while handling a hook. If not set, the current fallback is
+        /// kept.
+        pre_propose_fallback: Option<PreProposeFallback>,
     },
     /// Update's the proposal creation policy used for this
     /// module. Only the DAO may call this method.
@@ -120,6 +153,10 @@ pub enum ExecuteMsg {
     },
     AddProposalHook {
         address: String,
+        /// If true, new proposal hook messages sent to this consumer
+        /// include the proposal's title, description, and expiration.
+        #[serde(default)]
+        include_content: bool,
     },
     RemoveProposalHook {
         address: String,
@@ -130,6 +167,60 @@ pub enum ExecuteMsg {
     RemoveVoteHook {
         address: String,
     },
+    /// Sets whether vote hooks are sent as votes are cast or
+    /// buffered and sent in batches. Only callable by the DAO.
+    UpdateVoteHookDispatch {
+        dispatch: VoteHookDispatch,
+    },
+    /// Sends buffered votes to vote hook consumers. Callable by
+    /// anyone, for example by a crank once per block.
+    FlushVoteHooks {},
+    /// Copies the proposal and vote hook consumers, proposal
+    /// creation policy, and proposal count of MODULE, a proposal
+    /// module this one is replacing. Sent by the DAO when replacing
+    /// a proposal module and only callable by the DAO before any
+    /// proposals have been created.
+    ImportModuleState {
+        module: String,
+    },
+    /// Imports proposals exported from another proposal module with
+    /// `ExportState`, for example when re-instantiating a DAO on a
+    /// new chain. Proposals keep their IDs and subsequent proposals
+    /// are numbered after the largest imported ID. Only callable by
+    /// the DAO before this module has created a proposal.
+    ImportProposals {
+        proposals: Vec<ProposalResponse>,
+    },
+    /// Imports the votes cast on an imported proposal, as listed by
+    /// `ListVotes`. The proposal's tally is not changed. Only
+    /// callable by the DAO before this module has created a
+    /// proposal.
+    ImportVotes {
+        proposal_id: u64,
+        votes: Vec<VoteInfo>,
+    },
+    /// Deletes proposals with one of STATUSES which were created
+    /// before the block height OLDER_THAN, along with their
+    /// ballots. A summary of each pruned proposal is kept and may be
+    /// queried with `QueryMsg::PrunedProposal`. Only executed,
+    /// closed, and failed proposals may be pruned. At most LIMIT
+    /// proposals are pruned per call. Only callable by the DAO.
+    Prune {
+        older_than: u64,
+        statuses: Vec<Status>,
+        limit: Option<u64>,
+    },
+    /// Saves proposals and ballots again so that those saved before
+    /// an index was added to this module are added to it. Migrating
+    /// does not do this, so the DAO should call this after a
+    /// migration that adds an index until the response's data, the
+    /// cursor to pass as START_AFTER next, is `None`. At most LIMIT
+    /// proposals and ballots are saved per call. Only callable by the
+    /// DAO.
+    RebuildIndexes {
+        start_after: Option<RebuildIndexesCursor>,
+        limit: Option<u64>,
+    },
 }
 
 #[proposal_module_query]
@@ -155,6 +246,21 @@ pub enum QueryMsg {
         start_before: Option<u64>,
         limit: Option<u64>,
     },
+    /// Lists proposals which expire after `after` and before
+    /// `before` in ascending order of expiration. Both bounds are
+    /// exclusive. At least one bound must be set, and if both are
+    /// set they must both be heights or both be times. For example,
+    /// proposals closing in the next hour may be found by setting
+    /// `after` to the current time and `before` to an hour from now.
+    #[returns(crate::query::ProposalListResponse)]
+    ListProposalsByExpiration {
+        after: Option<Expiration>,
+        before: Option<Expiration>,
+        /// The maximum number of proposals to return as part of this
+        /// query. If no limit is set a max of 30 proposals will be
+        /// returned.
+        limit: Option<u64>,
+    },
     /// Returns a voters position on a proposal.
     #[returns(crate::query::VoteResponse)]
     GetVote { proposal_id: u64, voter: String },
@@ -164,6 +270,17 @@ pub enum QueryMsg {
         proposal_id: u64,
         start_after: Option<String>,
         limit: Option<u64>,
+        /// The order to list votes in. Defaults to ascending by
+        /// voter address.
+        order: Option<VoteListOrder>,
+    },
+    /// Lists the votes an address has cast in this module in
+    /// ascending order of proposal ID.
+    #[returns(crate::query::BallotListResponse)]
+    ListVotesByVoter {
+        voter: String,
+        start_after: Option<u64>,
+        limit: Option<u64>,
     },
     /// Returns the number of proposals that have been created in this module.
     #[returns(::std::primitive::u64)]
@@ -171,12 +288,67 @@ pub enum QueryMsg {
     /// Gets the current proposal creation policy for this module.
     #[returns(::dao_voting::pre_propose::ProposalCreationPolicy)]
     ProposalCreationPolicy {},
+    /// Gets the most recent failure of a pre-propose module, or None
+    /// if a pre-propose module has never failed.
+    #[returns(Option<::dao_voting::pre_propose::PreProposeFailure>)]
+    PreProposeFailure {},
     /// Lists all of the consumers of proposal hooks for this module.
     #[returns(::cw_hooks::HooksResponse)]
     ProposalHooks {},
     /// Lists all of the consumers of vote hooks for this module.
     #[returns(::cw_hooks::HooksResponse)]
     VoteHooks {},
+    /// Lists the number of times each proposal hook consumer has
+    /// failed. Consumers are removed after failing more than
+    /// `HOOK_RETRIES` times.
+    #[returns(::cw_hooks::HookFailuresResponse)]
+    ProposalHookFailures {},
+    /// Lists the number of times each vote hook consumer has
+    /// failed. Consumers are removed after failing more than
+    /// `HOOK_RETRIES` times.
+    #[returns(::cw_hooks::HookFailuresResponse)]
+    VoteHookFailures {},
+    /// Lists the most recent failures of proposal, vote, and
+    /// pre-propose module hooks, oldest first, including hooks that
+    /// were removed because they failed.
+    #[returns(Vec<::dao_voting::reply::HookFailureRecord>)]
+    HookFailures {},
+    /// Gets totals of the proposals created, executed, and closed in
+    /// this module, the votes cast on them, and their average
+    /// turnout and pass rate.
+    #[returns(::dao_voting::stats::GovernanceStatsResponse)]
+    GovernanceStats {},
+    /// Statically checks the messages of each of a proposal's
+    /// choices for problems that would prevent them from executing:
+    /// invalid addresses, messages to the DAO or this module that do
+    /// not deserialize, and native token spends larger than the DAO's
+    /// balance. Returns one response per choice, in order.
+    #[returns(Vec<::dao_voting::simulation::SimulationResponse>)]
+    SimulateExecution { proposal_id: u64 },
+    /// Gets how vote hooks are dispatched and the number of buffered
+    /// votes.
+    #[returns(::dao_vote_hooks::VoteHookDispatchResponse)]
+    VoteHookDispatch {},
+    /// Recounts the ballots cast on a proposal and compares the
+    /// result against the vote totals stored on the proposal. This
+    /// iterates over every ballot, so may run out of gas for
+    /// proposals with a very large number of voters.
+    #[returns(crate::query::VerifyTallyResponse)]
+    VerifyTally { proposal_id: u64 },
+    /// Gets the summary of a proposal which has been pruned, or None
+    /// if the proposal has not been pruned.
+    #[returns(Option<::dao_voting::proposal::PrunedProposal>)]
+    PrunedProposal { proposal_id: u64 },
+    /// Gets this module's config, proposal creation policy, and
+    /// proposal count, along with its proposals in ascending order of
+    /// ID. Proposals are returned as stored, without their status
+    /// updated to the current block. Votes may be exported with
+    /// `ListVotes`.
+    #[returns(crate::query::ExportStateResponse)]
+    ExportState {
+        start_after: Option<u64>,
+        limit: Option<u64>,
+    },
 }
 
 #[cw_serde]
@@ -185,28 +357,28 @@ pub struct VoteMsg {
     pub vote: MultipleChoiceVote,
 }
 
+/// Information needed to migrate from DAO DAO v1.
 #[cw_serde]
-pub enum MigrateMsg {
-    FromV1 {
-        /// This field was not present in DAO DAO v1. To migrate, a
-        /// value must be specified.
-        ///
-        /// If set to true proposals will be closed if their execution
-        /// fails. Otherwise, proposals will remain open after execution
-        /// failure. For example, with this enabled a proposal to send 5
-        /// tokens out of a DAO's treasury with 4 tokens would be closed when
-        /// it is executed. With this disabled, that same proposal would
-        /// remain open until the DAO's treasury was large enough for it to be
-        /// executed.
-        close_proposal_on_execution_failure: bool,
-        /// This field was not present in DAO DAO v1. To migrate, a
-        /// value must be specified.
-        ///
-        /// This contains information about how a pre-propose module may be configured.
-        /// If set to "AnyoneMayPropose", there will be no pre-propose module and consequently,
-        /// no deposit or membership checks when submitting a proposal. The "ModuleMayPropose"
-        /// option allows for instantiating a prepropose module which will handle deposit verification and return logic.
-        pre_propose_info: PreProposeInfo,
-    },
-    FromCompatible {},
+pub struct FromV1Msg {
+    /// This field was not present in DAO DAO v1. To migrate, a
+    /// value must be specified.
+    ///
+    /// If set to true proposals will be closed if their execution
+    /// fails. Otherwise, proposals will remain open after execution
+    /// failure. For example, with this enabled a proposal to send 5
+    /// tokens out of a DAO's treasury with 4 tokens would be closed when
+    /// it is executed. With this disabled, that same proposal would
+    /// remain open until the DAO's treasury was large enough for it to be
+    /// executed.
+    pub close_proposal_on_execution_failure: bool,
+    /// This field was not present in DAO DAO v1. To migrate, a
+    /// value must be specified.
+    ///
+    /// This contains information about how a pre-propose module may be configured.
+    /// If set to "AnyoneMayPropose", there will be no pre-propose module and consequently,
+    /// no deposit or membership checks when submitting a proposal. The "ModuleMayPropose"
+    /// option allows for instantiating a prepropose module which will handle deposit verification and return logic.
+    pub pre_propose_info: PreProposeInfo,
 }
+
+pub type MigrateMsg = dao_interface::migrate::MigrateMsg<FromV1Msg>;
@@ -2,7 +2,10 @@ use crate::{proposal::MultipleChoiceProposal, state::Config};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Uint128};
 
-use dao_voting::multiple_choice::MultipleChoiceVote;
+use dao_voting::{
+    multiple_choice::{MultipleChoiceVote, MultipleChoiceVotes},
+    pre_propose::ProposalCreationPolicy,
+};
 
 #[cw_serde]
 pub struct ProposalListResponse {
@@ -25,6 +28,9 @@ pub struct VoteInfo {
     pub vote: MultipleChoiceVote,
     /// The voting power behind the vote.
     pub power: Uint128,
+    /// Address-specified rationale for the vote.
+    #[serde(default)]
+    pub rationale: Option<String>,
 }
 
 #[cw_serde]
@@ -37,6 +43,26 @@ pub struct VoteListResponse {
     pub votes: Vec<VoteInfo>,
 }
 
+/// Information about a vote cast by an address, returned by
+/// `ListVotesByVoter`.
+#[cw_serde]
+pub struct BallotInfo {
+    /// The proposal that was voted on.
+    pub proposal_id: u64,
+    /// Position on the vote.
+    pub vote: MultipleChoiceVote,
+    /// The voting power behind the vote.
+    pub power: Uint128,
+    /// Address-specified rationale for the vote.
+    pub rationale: Option<String>,
+}
+
+/// The votes cast by an address.
+#[cw_serde]
+pub struct BallotListResponse {
+    pub ballots: Vec<BallotInfo>,
+}
+
 #[cw_serde]
 pub struct VoterResponse {
     pub weight: Option<Uint128>,
@@ -46,3 +72,28 @@ pub struct VoterResponse {
 pub struct ConfigResponse {
     pub config: Config,
 }
+
+/// The result of recounting the ballots cast on a proposal, returned
+/// by `VerifyTally`.
+#[cw_serde]
+pub struct VerifyTallyResponse {
+    /// The vote totals stored on the proposal.
+    pub cached: MultipleChoiceVotes,
+    /// The vote totals computed by summing the proposal's ballots.
+    pub recounted: MultipleChoiceVotes,
+    /// The number of ballots that were summed.
+    pub ballots: u64,
+    /// True if `cached` and `recounted` are equal.
+    pub consistent: bool,
+}
+
+/// A page of a proposal module's state, returned by `ExportState`.
+#[cw_serde]
+pub struct ExportStateResponse {
+    pub config: Config,
+    pub creation_policy: ProposalCreationPolicy,
+    /// The number of proposals that have been created. The next
+    /// proposal will have this plus one as its ID.
+    pub proposal_count: u64,
+    pub proposals: Vec<ProposalResponse>,
+}
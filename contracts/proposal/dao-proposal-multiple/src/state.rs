@@ -1,12 +1,17 @@
 use crate::proposal::MultipleChoiceProposal;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Empty, Uint128};
 use cw_hooks::Hooks;
-use cw_storage_plus::{Item, Map};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, KeyDeserialize, Map, MultiIndex};
 use cw_utils::Duration;
+use dao_vote_hooks::VoteHookBuffer;
 use dao_voting::{
     multiple_choice::{MultipleChoiceVote, VotingStrategy},
-    pre_propose::ProposalCreationPolicy,
+    pre_propose::{PreProposeFailure, PreProposeFallback, ProposalCreationPolicy},
+    proposal::{expiration_index_key, MaxOpenProposals, PrunedProposal, MAX_PROPOSAL_SIZE},
+    reply::HookFailureRecord,
+    stats::GovernanceStats,
+    voting::PowerCache,
 };
 
 /// The proposal module's configuration.
@@ -43,6 +48,23 @@ pub struct Config {
     /// remain open until the DAO's treasury was large enough for it to be
     /// executed.
     pub close_proposal_on_execution_failure: bool,
+    /// The maximum size of a proposal in bytes. Proposals which are
+    /// larger than this may not be created. Configs saved before this
+    /// was configurable use `MAX_PROPOSAL_SIZE`.
+    #[serde(default = "default_max_proposal_size")]
+    pub max_proposal_size: u64,
+    /// Limits on the number of proposals which may be open at
+    /// once. None for no limits.
+    pub max_open_proposals: Option<MaxOpenProposals>,
+    /// The creation policy to fall back to if the pre-propose module
+    /// errors while handling a hook. Configs saved before this was
+    /// configurable fall back to allowing anyone to propose.
+    #[serde(default)]
+    pub pre_propose_fallback: PreProposeFallback,
+}
+
+fn default_max_proposal_size() -> u64 {
+    MAX_PROPOSAL_SIZE
 }
 
 // we cast a ballot with our chosen vote and a given weight
@@ -53,17 +75,120 @@ pub struct Ballot {
     pub power: Uint128,
     /// The position.
     pub vote: MultipleChoiceVote,
+    /// An optional rationale for why this vote was cast. Ballots cast
+    /// before rationales were supported deserialize with None.
+    #[serde(default)]
+    pub rationale: Option<String>,
 }
 
 /// The current top level config for the module.
 pub const CONFIG: Item<Config> = Item::new("config");
 pub const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
-pub const PROPOSALS: Map<u64, MultipleChoiceProposal> = Map::new("proposals");
-pub const BALLOTS: Map<(u64, Addr), Ballot> = Map::new("ballots");
+
+pub struct ProposalIndexes<'a> {
+    /// Indexes proposals by the status they were last saved
+    /// with. Open proposals may have since passed or been rejected.
+    pub status: MultiIndex<'a, u8, MultipleChoiceProposal, u64>,
+    /// Indexes proposals by when they expire. See
+    /// `expiration_index_key` for the index's key.
+    pub expiration: MultiIndex<'a, (u8, u64), MultipleChoiceProposal, u64>,
+}
+
+impl<'a> IndexList<MultipleChoiceProposal> for ProposalIndexes<'a> {
+    fn get_indexes(
+        &'_ self,
+    ) -> Box<dyn Iterator<Item = &'_ dyn Index<MultipleChoiceProposal>> + '_> {
+        let v: Vec<&dyn Index<MultipleChoiceProposal>> = vec![&self.status, &self.expiration];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Proposals keyed by ID.
+pub fn proposals<'a>() -> IndexedMap<'a, u64, MultipleChoiceProposal, ProposalIndexes<'a>> {
+    IndexedMap::new(
+        "proposals",
+        ProposalIndexes {
+            status: MultiIndex::new(
+                |_, proposal| proposal.status as u8,
+                "proposals",
+                "proposals__status",
+            ),
+            expiration: MultiIndex::new(
+                |_, proposal| expiration_index_key(&proposal.expiration),
+                "proposals",
+                "proposals__expiration",
+            ),
+        },
+    )
+}
+
+pub struct BallotIndexes<'a> {
+    /// Indexes ballots by voter so that the votes an address has
+    /// cast may be listed without scanning every proposal.
+    pub voter: MultiIndex<'a, Addr, Ballot, (u64, Addr)>,
+    /// Indexes ballots by proposal ID and voting power so that the
+    /// votes on a proposal may be listed by power.
+    pub power: MultiIndex<'a, (u64, u128), Ballot, (u64, Addr)>,
+}
+
+impl<'a> IndexList<Ballot> for BallotIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Ballot>> + '_> {
+        let v: Vec<&dyn Index<Ballot>> = vec![&self.voter, &self.power];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Ballots keyed by proposal ID and voter.
+pub fn ballots<'a>() -> IndexedMap<'a, (u64, Addr), Ballot, BallotIndexes<'a>> {
+    IndexedMap::new(
+        "ballots",
+        BallotIndexes {
+            voter: MultiIndex::new(|pk, _| ballot_key(pk).1, "ballots", "ballots__voter"),
+            power: MultiIndex::new(
+                |pk, ballot| (ballot_key(pk).0, ballot.power.u128()),
+                "ballots",
+                "ballots__power",
+            ),
+        },
+    )
+}
+
+fn ballot_key(pk: &[u8]) -> (u64, Addr) {
+    // Ballots are only saved under valid keys.
+    <(u64, Addr)>::from_slice(pk).expect("invalid ballot key")
+}
+
 /// Consumers of proposal state change hooks.
 pub const PROPOSAL_HOOKS: Hooks = Hooks::new("proposal_hooks");
+/// Consumers of proposal hooks that are sent the content of new
+/// proposals.
+pub const PROPOSAL_HOOK_CONTENT: Map<Addr, Empty> = Map::new("proposal_hook_content");
 /// Consumers of vote hooks.
 pub const VOTE_HOOKS: Hooks = Hooks::new("vote_hooks");
+/// How vote hooks are dispatched, and votes buffered for batched
+/// dispatch.
+pub const VOTE_HOOK_BUFFER: VoteHookBuffer =
+    VoteHookBuffer::new("vote_hook_dispatch", "vote_hook_buffer");
 /// The address of the pre-propose module associated with this
 /// proposal module (if any).
 pub const CREATION_POLICY: Item<ProposalCreationPolicy> = Item::new("creation_policy");
+/// The most recent failure of a pre-propose module, if any.
+pub const PRE_PROPOSE_FAILURE: Item<PreProposeFailure> = Item::new("pre_propose_failure");
+/// The most recent hook failures, oldest first. At most
+/// `MAX_HOOK_FAILURE_HISTORY` failures are kept.
+pub const HOOK_FAILURE_HISTORY: Item<Vec<HookFailureRecord>> = Item::new("hook_failure_history");
+/// Totals of proposal and voting activity, maintained as proposals
+/// are created, voted on, and completed.
+pub const GOVERNANCE_STATS: GovernanceStats =
+    GovernanceStats::new("governance_stats", "governance_stats_voters");
+/// Total voting power queried from the DAO, keyed by voting module
+/// and height. Proposals created in the same block share a total
+/// power query.
+pub const POWER_CACHE: PowerCache = PowerCache::new("total_power_cache");
+/// Set while proposals and votes exported from another module may be
+/// imported. Set on instantiation and removed once this module
+/// creates its first proposal.
+pub const IMPORTING: Item<bool> = Item::new("importing");
+/// Summaries of proposals which have been pruned, keyed by proposal
+/// ID.
+pub const PRUNED_PROPOSALS: Map<u64, PrunedProposal> = Map::new("pruned_proposals");
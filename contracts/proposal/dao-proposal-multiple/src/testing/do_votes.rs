@@ -129,6 +129,9 @@ where
         allow_revoting: false,
         voting_strategy,
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
         pre_propose_info,
     };
 
@@ -233,6 +236,7 @@ where
             &ExecuteMsg::Vote {
                 proposal_id: 1,
                 vote: position,
+                rationale: None,
             },
             &[],
         );
@@ -273,6 +277,7 @@ where
                             // expected voting power.
                             _ => weight,
                         },
+                        rationale: None,
                     }),
                 };
                 assert_eq!(vote, expected)
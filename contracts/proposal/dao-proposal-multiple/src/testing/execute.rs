@@ -5,7 +5,7 @@ use cw_denom::CheckedDenom;
 use dao_pre_propose_multiple as cppm;
 use dao_voting::{
     deposit::CheckedDepositInfo, multiple_choice::MultipleChoiceOptions,
-    pre_propose::ProposalCreationPolicy,
+    pre_propose::ProposalCreationPolicy, proposal::MultipleChoiceProposeMsg,
 };
 
 use crate::{
@@ -29,7 +29,9 @@ pub fn make_proposal(
 
     // Collect the funding.
     let funds = match proposal_creation_policy {
-        ProposalCreationPolicy::Anyone {} => vec![],
+        ProposalCreationPolicy::Anyone {}
+        | ProposalCreationPolicy::Dao { .. }
+        | ProposalCreationPolicy::Frozen {} => vec![],
         ProposalCreationPolicy::Module {
             addr: ref pre_propose,
         } => {
@@ -39,6 +41,8 @@ pub fn make_proposal(
                     denom,
                     amount,
                     refund_policy: _,
+                    additional: _,
+                    refund_on_cancel: _,
                 }) => match denom {
                     CheckedDenom::Native(denom) => coins(amount.u128(), denom),
                     CheckedDenom::Cw20(addr) => {
@@ -64,16 +68,18 @@ pub fn make_proposal(
 
     // Make the proposal.
     match proposal_creation_policy {
-        ProposalCreationPolicy::Anyone {} => app
+        ProposalCreationPolicy::Anyone {}
+        | ProposalCreationPolicy::Dao { .. }
+        | ProposalCreationPolicy::Frozen {} => app
             .execute_contract(
                 Addr::unchecked(proposer),
                 proposal_multiple.clone(),
-                &ExecuteMsg::Propose {
+                &ExecuteMsg::Propose(MultipleChoiceProposeMsg {
                     title: "title".to_string(),
                     description: "description".to_string(),
                     choices,
                     proposer: None,
-                },
+                }),
                 &[],
             )
             .unwrap(),
@@ -38,6 +38,7 @@ fn get_pre_propose_info(
             msg: to_binary(&cppm::InstantiateMsg {
                 deposit_info,
                 open_proposal_submission,
+                nft_deposit_info: None,
                 extension: Empty::default(),
             })
             .unwrap(),
@@ -63,10 +64,15 @@ pub fn _get_default_token_dao_proposal_module_instantiate(app: &mut App) -> Inst
                 denom: dao_voting::deposit::DepositToken::VotingModuleToken {},
                 amount: Uint128::new(10_000_000),
                 refund_policy: DepositRefundPolicy::OnlyPassed,
+                additional: vec![],
+                refund_on_cancel: false,
             }),
             false,
         ),
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
     }
 }
 
@@ -83,6 +89,9 @@ fn _get_default_non_token_dao_proposal_module_instantiate(app: &mut App) -> Inst
         allow_revoting: false,
         pre_propose_info: get_pre_propose_info(app, None, false),
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
     }
 }
 
@@ -154,6 +163,8 @@ pub fn _instantiate_with_staked_cw721_governance(
                 owner: Some(Admin::CoreModule {}),
                 unstaking_duration: None,
                 nft_address: nft_address.to_string(),
+                ics721: None,
+                active_threshold: None,
             })
             .unwrap(),
             admin: None,
@@ -182,7 +193,13 @@ pub fn _instantiate_with_staked_cw721_governance(
 
     let core_state: dao_core::query::DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(core_addr.clone(), &dao_core::msg::QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr.clone(),
+            &dao_core::msg::QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
     let staking_addr = core_state.voting_module;
 
@@ -297,7 +314,13 @@ pub fn _instantiate_with_native_staked_balances_governance(
 
     let gov_state: dao_core::query::DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(core_addr.clone(), &dao_core::msg::QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr.clone(),
+            &dao_core::msg::QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
     let native_staking_addr = gov_state.voting_module;
 
@@ -492,7 +515,13 @@ pub fn instantiate_with_staked_balances_governance(
 
     let gov_state: dao_core::query::DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(core_addr.clone(), &dao_core::msg::QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr.clone(),
+            &dao_core::msg::QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
     let voting_module = gov_state.voting_module;
 
@@ -626,7 +655,13 @@ pub fn instantiate_with_multiple_staked_balances_governance(
 
     let gov_state: dao_core::query::DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(core_addr.clone(), &dao_core::msg::QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr.clone(),
+            &dao_core::msg::QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
     let voting_module = gov_state.voting_module;
 
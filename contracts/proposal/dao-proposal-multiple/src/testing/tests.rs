@@ -5,17 +5,20 @@ use cw_hooks::HooksResponse;
 use cw_multi_test::{next_block, App, BankSudo, Contract, ContractWrapper, Executor, SudoMsg};
 use cw_utils::Duration;
 use dao_core::state::ProposalModule;
-use dao_interface::{Admin, ModuleInstantiateInfo};
+use dao_interface::{proposal::GenericProposalInfoResponse, Admin, ModuleInstantiateInfo};
 use dao_voting::{
     deposit::{CheckedDepositInfo, DepositRefundPolicy, DepositToken, UncheckedDepositInfo},
+    error::VotingError,
     multiple_choice::{
         CheckedMultipleChoiceOption, MultipleChoiceOption, MultipleChoiceOptionType,
         MultipleChoiceOptions, MultipleChoiceVote, MultipleChoiceVotes, VotingStrategy,
         MAX_NUM_CHOICES,
     },
-    pre_propose::PreProposeInfo,
+    pre_propose::{PreProposeFallback, PreProposeInfo},
+    proposal::{MaxOpenProposals, MultipleChoiceProposeMsg, PrunedProposal, MAX_PROPOSAL_SIZE},
     status::Status,
     threshold::{PercentageThreshold, Threshold},
+    voting::VoteListOrder,
 };
 use dao_voting_cw20_staked::msg::ActiveThreshold;
 use std::panic;
@@ -23,7 +26,10 @@ use std::panic;
 use crate::{
     msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
     proposal::MultipleChoiceProposal,
-    query::{ProposalListResponse, ProposalResponse, VoteInfo, VoteListResponse},
+    query::{
+        BallotInfo, BallotListResponse, ProposalListResponse, ProposalResponse,
+        VerifyTallyResponse, VoteInfo, VoteListResponse, VoteResponse,
+    },
     state::Config,
     testing::{
         do_votes::do_test_votes_cw20_balances,
@@ -93,6 +99,7 @@ pub fn get_pre_propose_info(
             msg: to_binary(&cppm::InstantiateMsg {
                 deposit_info,
                 open_proposal_submission,
+                nft_deposit_info: None,
                 extension: Empty::default(),
             })
             .unwrap(),
@@ -119,6 +126,9 @@ fn test_propose() {
         voting_strategy: voting_strategy.clone(),
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
     };
 
@@ -135,6 +145,9 @@ fn test_propose() {
         voting_strategy: voting_strategy.clone(),
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        max_proposal_size: MAX_PROPOSAL_SIZE,
+        max_open_proposals: None,
+        pre_propose_fallback: PreProposeFallback::Anyone {},
     };
     assert_eq!(config, expected);
 
@@ -181,6 +194,69 @@ fn test_propose() {
     assert_eq!(created.id, 1u64);
 }
 
+#[test]
+fn test_max_open_proposals_per_proposer() {
+    let mut app = App::default();
+    let max_voting_period = Duration::Height(6);
+    let instantiate = InstantiateMsg {
+        max_voting_period,
+        only_members_execute: false,
+        allow_revoting: false,
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: Some(MaxOpenProposals {
+            total: None,
+            per_proposer: Some(1),
+        }),
+        pre_propose_fallback: None,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+    };
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let propose = |app: &mut App| {
+        app.execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            govmod.clone(),
+            &ExecuteMsg::Propose(MultipleChoiceProposeMsg {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                choices: MultipleChoiceOptions {
+                    options: vec![
+                        MultipleChoiceOption {
+                            title: "a".to_string(),
+                            description: "a".to_string(),
+                            msgs: vec![],
+                        },
+                        MultipleChoiceOption {
+                            title: "b".to_string(),
+                            description: "b".to_string(),
+                            msgs: vec![],
+                        },
+                    ],
+                },
+                proposer: None,
+            }),
+            &[],
+        )
+    };
+
+    propose(&mut app).unwrap();
+    let err: ContractError = propose(&mut app).unwrap_err().downcast().unwrap();
+    assert!(matches!(
+        err,
+        ContractError::VotingError(VotingError::TooManyOpenProposalsByProposer { max: 1 })
+    ));
+
+    // Expired proposals no longer count as open.
+    app.update_block(|b| b.height += 6);
+    propose(&mut app).unwrap();
+}
+
 #[test]
 fn test_propose_wrong_num_choices() {
     let mut app = App::default();
@@ -194,6 +270,9 @@ fn test_propose_wrong_num_choices() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -209,6 +288,9 @@ fn test_propose_wrong_num_choices() {
     let expected = Config {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        max_proposal_size: MAX_PROPOSAL_SIZE,
+        max_open_proposals: None,
+        pre_propose_fallback: PreProposeFallback::Anyone {},
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -224,12 +306,12 @@ fn test_propose_wrong_num_choices() {
     let err = app.execute_contract(
         Addr::unchecked(CREATOR_ADDR),
         govmod.clone(),
-        &ExecuteMsg::Propose {
+        &ExecuteMsg::Propose(MultipleChoiceProposeMsg {
             title: "A simple text proposal".to_string(),
             description: "A simple text proposal".to_string(),
             choices: mc_options,
             proposer: None,
-        },
+        }),
         &[],
     );
     assert!(err.is_err());
@@ -250,12 +332,12 @@ fn test_propose_wrong_num_choices() {
     let err = app.execute_contract(
         Addr::unchecked(CREATOR_ADDR),
         govmod,
-        &ExecuteMsg::Propose {
+        &ExecuteMsg::Propose(MultipleChoiceProposeMsg {
             title: "A simple text proposal".to_string(),
             description: "A simple text proposal".to_string(),
             choices: mc_options,
             proposer: None,
-        },
+        }),
         &[],
     );
     assert!(err.is_err());
@@ -272,6 +354,9 @@ fn test_proposal_count_initialized_to_zero() {
         max_voting_period: Duration::Height(10),
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
         only_members_execute: true,
         allow_revoting: false,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
@@ -280,7 +365,13 @@ fn test_proposal_count_initialized_to_zero() {
 
     let gov_state: dao_core::query::DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(core_addr, &dao_core::msg::QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr,
+            &dao_core::msg::QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
     let proposal_modules = gov_state.proposal_modules;
 
@@ -308,6 +399,9 @@ fn test_no_early_pass_with_min_duration() {
         only_members_execute: true,
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
     };
 
@@ -328,7 +422,13 @@ fn test_no_early_pass_with_min_duration() {
 
     let gov_state: dao_core::query::DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(core_addr, &dao_core::msg::QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr,
+            &dao_core::msg::QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
     let proposal_modules = gov_state.proposal_modules;
 
@@ -353,12 +453,12 @@ fn test_no_early_pass_with_min_duration() {
     app.execute_contract(
         Addr::unchecked("whale"),
         govmod.clone(),
-        &ExecuteMsg::Propose {
+        &ExecuteMsg::Propose(MultipleChoiceProposeMsg {
             title: "A simple text proposal".to_string(),
             description: "This is a simple text proposal".to_string(),
             choices: mc_options,
             proposer: None,
-        },
+        }),
         &[],
     )
     .unwrap();
@@ -371,6 +471,7 @@ fn test_no_early_pass_with_min_duration() {
         &ExecuteMsg::Vote {
             proposal_id: 1,
             vote: MultipleChoiceVote { option_id: 0 },
+            rationale: None,
         },
         &[],
     )
@@ -399,6 +500,9 @@ fn test_propose_with_messages() {
         max_voting_period: Duration::Height(10),
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
         only_members_execute: true,
         allow_revoting: false,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
@@ -421,7 +525,13 @@ fn test_propose_with_messages() {
 
     let gov_state: dao_core::query::DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(core_addr, &dao_core::msg::QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr,
+            &dao_core::msg::QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
     let proposal_modules = gov_state.proposal_modules;
 
@@ -434,6 +544,9 @@ fn test_propose_with_messages() {
         },
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
         max_voting_period: cw_utils::Duration::Height(20),
         only_members_execute: false,
         allow_revoting: false,
@@ -464,12 +577,12 @@ fn test_propose_with_messages() {
     app.execute_contract(
         Addr::unchecked("whale"),
         govmod.clone(),
-        &ExecuteMsg::Propose {
+        &ExecuteMsg::Propose(MultipleChoiceProposeMsg {
             title: "A simple text proposal".to_string(),
             description: "This is a simple text proposal".to_string(),
             choices: mc_options,
             proposer: None,
-        },
+        }),
         &[],
     )
     .unwrap();
@@ -480,6 +593,7 @@ fn test_propose_with_messages() {
         &ExecuteMsg::Vote {
             proposal_id: 1,
             vote: MultipleChoiceVote { option_id: 0 },
+            rationale: None,
         },
         &[],
     )
@@ -519,6 +633,9 @@ fn test_min_duration_units_missmatch() {
         only_members_execute: true,
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
     };
     instantiate_with_staked_balances_governance(
@@ -551,6 +668,9 @@ fn test_min_duration_larger_than_proposal_duration() {
         only_members_execute: true,
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
     };
     instantiate_with_staked_balances_governance(
@@ -582,6 +702,9 @@ fn test_min_duration_same_as_proposal_duration() {
         only_members_execute: true,
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
     };
 
@@ -602,7 +725,13 @@ fn test_min_duration_same_as_proposal_duration() {
 
     let gov_state: dao_core::query::DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(core_addr, &dao_core::msg::QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr,
+            &dao_core::msg::QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
     let proposal_modules = gov_state.proposal_modules;
 
@@ -627,12 +756,12 @@ fn test_min_duration_same_as_proposal_duration() {
     app.execute_contract(
         Addr::unchecked("whale"),
         govmod.clone(),
-        &ExecuteMsg::Propose {
+        &ExecuteMsg::Propose(MultipleChoiceProposeMsg {
             title: "A simple text proposal".to_string(),
             description: "This is a simple text proposal".to_string(),
             choices: mc_options,
             proposer: None,
-        },
+        }),
         &[],
     )
     .unwrap();
@@ -645,6 +774,7 @@ fn test_min_duration_same_as_proposal_duration() {
         &ExecuteMsg::Vote {
             proposal_id: 1,
             vote: MultipleChoiceVote { option_id: 0 },
+            rationale: None,
         },
         &[],
     )
@@ -661,6 +791,7 @@ fn test_min_duration_same_as_proposal_duration() {
         &ExecuteMsg::Vote {
             proposal_id: 1,
             vote: MultipleChoiceVote { option_id: 2 },
+            rationale: None,
         },
         &[],
     )
@@ -688,6 +819,9 @@ fn test_voting_module_token_proposal_deposit_instantiate() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -698,6 +832,8 @@ fn test_voting_module_token_proposal_deposit_instantiate() {
                 denom: DepositToken::VotingModuleToken {},
                 amount: Uint128::new(1),
                 refund_policy: DepositRefundPolicy::OnlyPassed,
+                additional: vec![],
+                refund_on_cancel: false,
             }),
             false,
         ),
@@ -707,7 +843,13 @@ fn test_voting_module_token_proposal_deposit_instantiate() {
 
     let gov_state: dao_core::query::DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(core_addr.clone(), &dao_core::msg::QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr.clone(),
+            &dao_core::msg::QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
     let governance_modules = gov_state.proposal_modules;
 
@@ -722,7 +864,9 @@ fn test_voting_module_token_proposal_deposit_instantiate() {
         Some(CheckedDepositInfo {
             denom: CheckedDenom::Cw20(token),
             amount: Uint128::new(1),
-            refund_policy: DepositRefundPolicy::OnlyPassed
+            refund_policy: DepositRefundPolicy::OnlyPassed,
+            additional: vec![],
+            refund_on_cancel: false,
         })
     )
 }
@@ -758,6 +902,9 @@ fn test_different_token_proposal_deposit() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -770,6 +917,8 @@ fn test_different_token_proposal_deposit() {
                 },
                 amount: Uint128::new(1),
                 refund_policy: DepositRefundPolicy::OnlyPassed,
+                additional: vec![],
+                refund_on_cancel: false,
             }),
             false,
         ),
@@ -819,6 +968,9 @@ fn test_bad_token_proposal_deposit() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -831,6 +983,8 @@ fn test_bad_token_proposal_deposit() {
                 },
                 amount: Uint128::new(1),
                 refund_policy: DepositRefundPolicy::OnlyPassed,
+                additional: vec![],
+                refund_on_cancel: false,
             }),
             false,
         ),
@@ -851,6 +1005,9 @@ fn test_take_proposal_deposit() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -861,6 +1018,8 @@ fn test_take_proposal_deposit() {
                 denom: DepositToken::VotingModuleToken {},
                 amount: Uint128::new(1),
                 refund_policy: DepositRefundPolicy::OnlyPassed,
+                additional: vec![],
+                refund_on_cancel: false,
             }),
             false,
         ),
@@ -877,7 +1036,13 @@ fn test_take_proposal_deposit() {
 
     let gov_state: dao_core::query::DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(core_addr, &dao_core::msg::QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr,
+            &dao_core::msg::QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
     let governance_modules = gov_state.proposal_modules;
 
@@ -958,6 +1123,9 @@ fn test_native_proposal_deposit() {
         only_members_execute: false,
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
         pre_propose_info: get_pre_propose_info(
             &mut app,
             Some(UncheckedDepositInfo {
@@ -966,6 +1134,8 @@ fn test_native_proposal_deposit() {
                 },
                 amount: Uint128::new(1),
                 refund_policy: DepositRefundPolicy::Always,
+                additional: vec![],
+                refund_on_cancel: false,
             }),
             false,
         ),
@@ -982,7 +1152,13 @@ fn test_native_proposal_deposit() {
 
     let gov_state: dao_core::query::DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(core_addr, &dao_core::msg::QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr,
+            &dao_core::msg::QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
     let governance_modules = gov_state.proposal_modules;
 
@@ -1057,6 +1233,7 @@ fn test_native_proposal_deposit() {
             &ExecuteMsg::Vote {
                 proposal_id: 1,
                 vote: MultipleChoiceVote { option_id: 1 },
+                rationale: None,
             },
             &[],
         );
@@ -1105,13 +1282,21 @@ fn test_deposit_return_on_execute() {
             denom: DepositToken::VotingModuleToken {},
             amount: Uint128::new(1),
             refund_policy: DepositRefundPolicy::OnlyPassed,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         true,
     );
 
     let gov_state: dao_core::query::DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(core_addr, &dao_core::msg::QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr,
+            &dao_core::msg::QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
     let governance_modules = gov_state.proposal_modules;
 
@@ -1168,7 +1353,13 @@ fn test_deposit_return_zero() {
 
     let gov_state: dao_core::query::DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(core_addr.clone(), &dao_core::msg::QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr.clone(),
+            &dao_core::msg::QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
     let governance_modules = gov_state.proposal_modules;
 
@@ -1220,7 +1411,13 @@ fn test_query_list_votes() {
 
     let gov_state: dao_core::query::DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(core_addr, &dao_core::msg::QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr,
+            &dao_core::msg::QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
     let governance_modules = gov_state.proposal_modules;
 
@@ -1230,11 +1427,12 @@ fn test_query_list_votes() {
     let list_votes: VoteListResponse = app
         .wrap()
         .query_wasm_smart(
-            govmod,
+            govmod.clone(),
             &QueryMsg::ListVotes {
                 proposal_id: 1,
                 start_after: None,
                 limit: None,
+                order: None,
             },
         )
         .unwrap();
@@ -1244,15 +1442,300 @@ fn test_query_list_votes() {
             voter: Addr::unchecked("blue"),
             vote: MultipleChoiceVote { option_id: 0 },
             power: Uint128::new(10),
+            rationale: None,
         },
         VoteInfo {
             voter: Addr::unchecked("note"),
             vote: MultipleChoiceVote { option_id: 1 },
             power: Uint128::new(20),
+            rationale: None,
         },
     ];
 
-    assert_eq!(list_votes.votes, expected)
+    assert_eq!(list_votes.votes, expected);
+
+    let list_votes = |start_after: Option<&str>, order: VoteListOrder| -> Vec<Addr> {
+        let response: VoteListResponse = app
+            .wrap()
+            .query_wasm_smart(
+                govmod.clone(),
+                &QueryMsg::ListVotes {
+                    proposal_id: 1,
+                    start_after: start_after.map(str::to_string),
+                    limit: None,
+                    order: Some(order),
+                },
+            )
+            .unwrap();
+        response.votes.into_iter().map(|vote| vote.voter).collect()
+    };
+
+    assert_eq!(
+        list_votes(None, VoteListOrder::VoterDescending),
+        vec![Addr::unchecked("note"), Addr::unchecked("blue")]
+    );
+    assert_eq!(
+        list_votes(Some("note"), VoteListOrder::VoterDescending),
+        vec![Addr::unchecked("blue")]
+    );
+    assert_eq!(
+        list_votes(None, VoteListOrder::PowerDescending),
+        vec![Addr::unchecked("note"), Addr::unchecked("blue")]
+    );
+    assert_eq!(
+        list_votes(Some("blue"), VoteListOrder::PowerAscending),
+        vec![Addr::unchecked("note")]
+    );
+    assert_eq!(
+        list_votes(Some("note"), VoteListOrder::PowerAscending),
+        Vec::<Addr>::new()
+    );
+}
+
+#[test]
+fn test_verify_tally() {
+    let (app, core_addr) = do_test_votes_cw20_balances(
+        vec![
+            TestMultipleChoiceVote {
+                voter: "blue".to_string(),
+                position: MultipleChoiceVote { option_id: 0 },
+                weight: Uint128::new(10),
+                should_execute: ShouldExecute::Yes,
+            },
+            TestMultipleChoiceVote {
+                voter: "note".to_string(),
+                position: MultipleChoiceVote { option_id: 1 },
+                weight: Uint128::new(20),
+                should_execute: ShouldExecute::Yes,
+            },
+        ],
+        VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        Status::Passed,
+        None,
+        None,
+        true,
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let tally: VerifyTallyResponse = app
+        .wrap()
+        .query_wasm_smart(&govmod, &QueryMsg::VerifyTally { proposal_id: 1 })
+        .unwrap();
+
+    let expected = MultipleChoiceVotes {
+        vote_weights: vec![Uint128::new(10), Uint128::new(20), Uint128::zero()],
+    };
+    assert_eq!(
+        tally,
+        VerifyTallyResponse {
+            cached: expected.clone(),
+            recounted: expected,
+            ballots: 2,
+            consistent: true,
+        }
+    );
+
+    // Proposals that do not exist can not be verified.
+    app.wrap()
+        .query_wasm_smart::<VerifyTallyResponse>(&govmod, &QueryMsg::VerifyTally { proposal_id: 2 })
+        .unwrap_err();
+}
+
+#[test]
+fn test_list_votes_by_voter() {
+    let (app, core_addr) = do_test_votes_cw20_balances(
+        vec![
+            TestMultipleChoiceVote {
+                voter: "blue".to_string(),
+                position: MultipleChoiceVote { option_id: 0 },
+                weight: Uint128::new(10),
+                should_execute: ShouldExecute::Yes,
+            },
+            TestMultipleChoiceVote {
+                voter: "note".to_string(),
+                position: MultipleChoiceVote { option_id: 1 },
+                weight: Uint128::new(20),
+                should_execute: ShouldExecute::Yes,
+            },
+        ],
+        VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        Status::Passed,
+        None,
+        None,
+        true,
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let ballots: BallotListResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &govmod,
+            &QueryMsg::ListVotesByVoter {
+                voter: "note".to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        ballots.ballots,
+        vec![BallotInfo {
+            proposal_id: 1,
+            vote: MultipleChoiceVote { option_id: 1 },
+            power: Uint128::new(20),
+            rationale: None,
+        }]
+    );
+
+    let ballots: BallotListResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &govmod,
+            &QueryMsg::ListVotesByVoter {
+                voter: "note".to_string(),
+                start_after: Some(1),
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(ballots.ballots, vec![]);
+}
+
+#[test]
+fn test_prune_proposals() {
+    let (mut app, core_addr) = do_test_votes_cw20_balances(
+        vec![TestMultipleChoiceVote {
+            voter: "blue".to_string(),
+            position: MultipleChoiceVote { option_id: 0 },
+            weight: Uint128::new(10),
+            should_execute: ShouldExecute::Yes,
+        }],
+        VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        Status::Passed,
+        None,
+        None,
+        true,
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+    app.execute_contract(
+        Addr::unchecked("blue"),
+        govmod.clone(),
+        &ExecuteMsg::Execute { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+
+    let older_than = app.block_info().height + 1;
+    let prune = |statuses| ExecuteMsg::Prune {
+        older_than,
+        statuses,
+        limit: None,
+    };
+
+    // Only the DAO may prune proposals.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("blue"),
+            govmod.clone(),
+            &prune(vec![Status::Executed]),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    // Passed proposals may still be executed, so may not be pruned.
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            govmod.clone(),
+            &prune(vec![Status::Passed]),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::VotingError(VotingError::InvalidPruneStatus {
+            status: Status::Passed
+        })
+    ));
+
+    app.execute_contract(
+        core_addr,
+        govmod.clone(),
+        &prune(vec![Status::Executed]),
+        &[],
+    )
+    .unwrap();
+
+    app.wrap()
+        .query_wasm_smart::<ProposalResponse>(&govmod, &QueryMsg::Proposal { proposal_id: 1 })
+        .unwrap_err();
+    let pruned: Option<PrunedProposal> = app
+        .wrap()
+        .query_wasm_smart(&govmod, &QueryMsg::PrunedProposal { proposal_id: 1 })
+        .unwrap();
+    assert_eq!(pruned.unwrap().status, Status::Executed);
+
+    let ballots: BallotListResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &govmod,
+            &QueryMsg::ListVotesByVoter {
+                voter: "blue".to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(ballots.ballots, vec![]);
+}
+
+#[test]
+fn test_generic_proposal_info() {
+    let (app, core_addr) = do_test_votes_cw20_balances(
+        vec![TestMultipleChoiceVote {
+            voter: "blue".to_string(),
+            position: MultipleChoiceVote { option_id: 0 },
+            weight: Uint128::new(10),
+            should_execute: ShouldExecute::Yes,
+        }],
+        VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        Status::Passed,
+        None,
+        None,
+        true,
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(&govmod, &QueryMsg::Proposal { proposal_id: 1 })
+        .unwrap();
+    let info: GenericProposalInfoResponse = app
+        .wrap()
+        .query_wasm_smart(&govmod, &QueryMsg::GenericProposalInfo { proposal_id: 1 })
+        .unwrap();
+    assert_eq!(
+        info,
+        GenericProposalInfoResponse {
+            proposer: proposal.proposal.proposer,
+            status: "passed".to_string(),
+            expiration: proposal.proposal.expiration,
+            created: proposal.proposal.start_height,
+        }
+    );
 }
 
 #[test]
@@ -1296,7 +1779,13 @@ fn test_cant_vote_executed_or_closed() {
 
     let gov_state: dao_core::query::DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(core_addr, &dao_core::msg::QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr,
+            &dao_core::msg::QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
     let governance_modules = gov_state.proposal_modules;
 
@@ -1319,6 +1808,7 @@ fn test_cant_vote_executed_or_closed() {
         &ExecuteMsg::Vote {
             proposal_id: 1,
             vote: MultipleChoiceVote { option_id: 0 },
+            rationale: None,
         },
         &[],
     )
@@ -1357,6 +1847,7 @@ fn test_cant_vote_executed_or_closed() {
         &ExecuteMsg::Vote {
             proposal_id: 1,
             vote: MultipleChoiceVote { option_id: 0 },
+            rationale: None,
         },
         &[],
     )
@@ -1373,6 +1864,9 @@ fn test_cant_propose_zero_power() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -1383,6 +1877,8 @@ fn test_cant_propose_zero_power() {
                 denom: DepositToken::VotingModuleToken {},
                 amount: Uint128::new(1),
                 refund_policy: DepositRefundPolicy::Always,
+                additional: vec![],
+                refund_on_cancel: false,
             }),
             false,
         ),
@@ -1405,7 +1901,13 @@ fn test_cant_propose_zero_power() {
 
     let gov_state: dao_core::query::DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(core_addr, &dao_core::msg::QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr,
+            &dao_core::msg::QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
     let proposal_modules = gov_state.proposal_modules;
 
@@ -1498,13 +2000,21 @@ fn test_cant_vote_not_registered() {
             denom: DepositToken::VotingModuleToken {},
             amount: Uint128::new(1),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false,
     );
 
     let gov_state: dao_core::query::DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(core_addr, &dao_core::msg::QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr,
+            &dao_core::msg::QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
     let governance_modules = gov_state.proposal_modules;
 
@@ -1519,6 +2029,7 @@ fn test_cant_vote_not_registered() {
             &ExecuteMsg::Vote {
                 proposal_id: 1,
                 vote: MultipleChoiceVote { option_id: 0 },
+                rationale: None,
             },
             &[],
         )
@@ -1544,6 +2055,9 @@ fn test_cant_execute_not_member() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
         max_voting_period,
         only_members_execute: true,
         allow_revoting: false,
@@ -1580,12 +2094,12 @@ fn test_cant_execute_not_member() {
     app.execute_contract(
         Addr::unchecked("blue"),
         govmod.clone(),
-        &ExecuteMsg::Propose {
+        &ExecuteMsg::Propose(MultipleChoiceProposeMsg {
             title: "A simple text proposal".to_string(),
             description: "A simple text proposal".to_string(),
             choices: mc_options,
             proposer: None,
-        },
+        }),
         &[],
     )
     .unwrap();
@@ -1597,6 +2111,7 @@ fn test_cant_execute_not_member() {
         &ExecuteMsg::Vote {
             proposal_id: 1,
             vote: MultipleChoiceVote { option_id: 0 },
+            rationale: None,
         },
         &[],
     )
@@ -1635,6 +2150,9 @@ fn test_open_proposal_submission() {
         only_members_execute: false,
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
         pre_propose_info: get_pre_propose_info(&mut app, None, true),
     };
     let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
@@ -1728,13 +2246,21 @@ fn test_close_open_proposal() {
             denom: DepositToken::VotingModuleToken {},
             amount: Uint128::new(1),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false,
     );
 
     let gov_state: dao_core::query::DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(core_addr, &dao_core::msg::QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr,
+            &dao_core::msg::QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
     let governance_modules = gov_state.proposal_modules;
 
@@ -1778,6 +2304,98 @@ fn test_close_open_proposal() {
     };
 }
 
+#[test]
+fn test_cancel_proposal() {
+    let (mut app, core_addr) = do_test_votes_cw20_balances(
+        vec![TestMultipleChoiceVote {
+            voter: "blue".to_string(),
+            position: MultipleChoiceVote { option_id: 2 },
+            weight: Uint128::new(10),
+            should_execute: ShouldExecute::Yes,
+        }],
+        VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        Status::Open,
+        Some(Uint128::new(100)),
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::VotingModuleToken {},
+            amount: Uint128::new(1),
+            refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
+        }),
+        false,
+    );
+
+    let gov_state: dao_core::query::DumpStateResponse = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr,
+            &dao_core::msg::QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let govmod = gov_state
+        .proposal_modules
+        .into_iter()
+        .next()
+        .unwrap()
+        .address;
+
+    // Only the proposer may cancel the proposal.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("red"),
+            govmod.clone(),
+            &ExecuteMsg::Cancel { proposal_id: 1 },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    app.execute_contract(
+        Addr::unchecked("blue"),
+        govmod.clone(),
+        &ExecuteMsg::Cancel { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+    let proposal = query_proposal(&app, &govmod, 1);
+    assert_eq!(proposal.proposal.status, Status::Cancelled);
+
+    // Cancelled proposals may not be cancelled again.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("blue"),
+            govmod.clone(),
+            &ExecuteMsg::Cancel { proposal_id: 1 },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::NotOpen { id: 1 }));
+
+    // Deposits are not refunded on cancellation unless configured
+    // to be, even under the `Always` refund policy.
+    let (deposit_config, _) = query_deposit_config_and_pre_propose_module(&app, &govmod);
+    if let CheckedDepositInfo {
+        denom: CheckedDenom::Cw20(ref token),
+        ..
+    } = deposit_config.deposit_info.unwrap()
+    {
+        let balance = query_balance_cw20(&app, token, "blue".to_string());
+        assert_eq!(balance, Uint128::new(9));
+    } else {
+        panic!()
+    };
+}
+
 #[test]
 fn test_no_refund_failed_proposal() {
     let (mut app, core_addr) = do_test_votes_cw20_balances(
@@ -1796,13 +2414,21 @@ fn test_no_refund_failed_proposal() {
             denom: DepositToken::VotingModuleToken {},
             amount: Uint128::new(1),
             refund_policy: DepositRefundPolicy::OnlyPassed,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false,
     );
 
     let gov_state: dao_core::query::DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(core_addr, &dao_core::msg::QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr,
+            &dao_core::msg::QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
     let governance_modules = gov_state.proposal_modules;
 
@@ -1874,12 +2500,20 @@ fn test_deposit_return_on_close() {
             denom: DepositToken::VotingModuleToken {},
             amount: Uint128::new(1),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false,
     );
     let gov_state: dao_core::query::DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(core_addr, &dao_core::msg::QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr,
+            &dao_core::msg::QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
     let governance_modules = gov_state.proposal_modules;
 
@@ -1924,6 +2558,9 @@ fn test_execute_expired_proposal() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -1948,7 +2585,13 @@ fn test_execute_expired_proposal() {
 
     let gov_state: dao_core::query::DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(core_addr, &dao_core::msg::QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr,
+            &dao_core::msg::QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
     let proposal_modules = gov_state.proposal_modules;
 
@@ -1973,12 +2616,12 @@ fn test_execute_expired_proposal() {
     app.execute_contract(
         Addr::unchecked("blue"),
         govmod.clone(),
-        &ExecuteMsg::Propose {
+        &ExecuteMsg::Propose(MultipleChoiceProposeMsg {
             title: "A simple text proposal".to_string(),
             description: "A simple text proposal".to_string(),
             choices: mc_options,
             proposer: None,
-        },
+        }),
         &[],
     )
     .unwrap();
@@ -1989,6 +2632,7 @@ fn test_execute_expired_proposal() {
         &ExecuteMsg::Vote {
             proposal_id: 1,
             vote: MultipleChoiceVote { option_id: 0 },
+            rationale: None,
         },
         &[],
     )
@@ -2057,7 +2701,13 @@ fn test_update_config() {
 
     let gov_state: dao_core::query::DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(core_addr, &dao_core::msg::QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr,
+            &dao_core::msg::QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
     let governance_modules = gov_state.proposal_modules;
 
@@ -2086,6 +2736,9 @@ fn test_update_config() {
             },
             min_voting_period: None,
             close_proposal_on_execution_failure: true,
+            max_proposal_size: None,
+            max_open_proposals: None,
+            pre_propose_fallback: None,
             max_voting_period: cw_utils::Duration::Height(10),
             only_members_execute: false,
             allow_revoting: false,
@@ -2105,6 +2758,9 @@ fn test_update_config() {
             },
             min_voting_period: None,
             close_proposal_on_execution_failure: true,
+            max_proposal_size: Some(50_000),
+            max_open_proposals: None,
+            pre_propose_fallback: Some(PreProposeFallback::Frozen {}),
             max_voting_period: cw_utils::Duration::Height(10),
             only_members_execute: false,
             allow_revoting: false,
@@ -2122,6 +2778,9 @@ fn test_update_config() {
         },
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        max_proposal_size: 50_000,
+        max_open_proposals: None,
+        pre_propose_fallback: PreProposeFallback::Frozen {},
         max_voting_period: cw_utils::Duration::Height(10),
         only_members_execute: false,
         allow_revoting: false,
@@ -2129,6 +2788,29 @@ fn test_update_config() {
     };
     assert_eq!(govmod_config, expected);
 
+    // Omitting the pre-propose fallback keeps the current one.
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        govmod.clone(),
+        &ExecuteMsg::UpdateConfig {
+            voting_strategy: VotingStrategy::SingleChoice {
+                quorum: PercentageThreshold::Majority {},
+            },
+            min_voting_period: None,
+            close_proposal_on_execution_failure: true,
+            max_proposal_size: Some(50_000),
+            max_open_proposals: None,
+            pre_propose_fallback: None,
+            max_voting_period: cw_utils::Duration::Height(10),
+            only_members_execute: false,
+            allow_revoting: false,
+            dao: Addr::unchecked(CREATOR_ADDR).to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(query_proposal_config(&app, &govmod), expected);
+
     // As we have changed the DAO address updating the config using
     // the original one should now fail.
     app.execute_contract(
@@ -2140,6 +2822,9 @@ fn test_update_config() {
             },
             min_voting_period: None,
             close_proposal_on_execution_failure: true,
+            max_proposal_size: None,
+            max_open_proposals: None,
+            pre_propose_fallback: None,
             max_voting_period: cw_utils::Duration::Height(10),
             only_members_execute: false,
             allow_revoting: false,
@@ -2168,12 +2853,20 @@ fn test_no_return_if_no_refunds() {
             denom: DepositToken::VotingModuleToken {},
             amount: Uint128::new(1),
             refund_policy: DepositRefundPolicy::OnlyPassed,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         true,
     );
     let gov_state: dao_core::query::DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(core_addr, &dao_core::msg::QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr,
+            &dao_core::msg::QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
     let governance_modules = gov_state.proposal_modules;
 
@@ -2214,6 +2907,9 @@ fn test_query_list_proposals() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -2262,12 +2958,12 @@ fn test_query_list_proposals() {
         app.execute_contract(
             Addr::unchecked(CREATOR_ADDR),
             govmod.clone(),
-            &ExecuteMsg::Propose {
+            &ExecuteMsg::Propose(MultipleChoiceProposeMsg {
                 title: "A simple text proposal".to_string(),
                 description: "A simple text proposal".to_string(),
                 choices: mc_options.clone(),
                 proposer: None,
-            },
+            }),
             &[],
         )
         .unwrap();
@@ -2347,6 +3043,9 @@ fn test_hooks() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -2369,6 +3068,7 @@ fn test_hooks() {
 
     let msg = ExecuteMsg::AddProposalHook {
         address: "some_addr".to_string(),
+        include_content: false,
     };
 
     // Expect error as sender is not DAO
@@ -2473,6 +3173,9 @@ fn test_active_threshold_absolute() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -2531,12 +3234,12 @@ fn test_active_threshold_absolute() {
         .execute_contract(
             Addr::unchecked(CREATOR_ADDR),
             govmod.clone(),
-            &crate::msg::ExecuteMsg::Propose {
+            &crate::msg::ExecuteMsg::Propose(MultipleChoiceProposeMsg {
                 title: "A simple text proposal".to_string(),
                 description: "This is a simple text proposal".to_string(),
                 choices: mc_options.clone(),
                 proposer: None,
-            },
+            }),
             &[],
         )
         .unwrap_err();
@@ -2556,12 +3259,12 @@ fn test_active_threshold_absolute() {
         .execute_contract(
             Addr::unchecked(CREATOR_ADDR),
             govmod.clone(),
-            &crate::msg::ExecuteMsg::Propose {
+            &crate::msg::ExecuteMsg::Propose(MultipleChoiceProposeMsg {
                 title: "A simple text proposal".to_string(),
                 description: "This is a simple text proposal".to_string(),
                 choices: mc_options.clone(),
                 proposer: None,
-            },
+            }),
             &[],
         )
         .unwrap();
@@ -2569,6 +3272,7 @@ fn test_active_threshold_absolute() {
     // Unstake some tokens to make it inactive again
     let msg = cw20_stake::msg::ExecuteMsg::Unstake {
         amount: Uint128::new(50),
+        auto_claim: false,
     };
     app.execute_contract(Addr::unchecked(CREATOR_ADDR), staking_contract, &msg, &[])
         .unwrap();
@@ -2579,12 +3283,12 @@ fn test_active_threshold_absolute() {
         .execute_contract(
             Addr::unchecked(CREATOR_ADDR),
             govmod,
-            &crate::msg::ExecuteMsg::Propose {
+            &crate::msg::ExecuteMsg::Propose(MultipleChoiceProposeMsg {
                 title: "A simple text proposal".to_string(),
                 description: "This is a simple text proposal".to_string(),
                 choices: mc_options,
                 proposer: None,
-            },
+            }),
             &[],
         )
         .unwrap_err();
@@ -2600,6 +3304,9 @@ fn test_active_threshold_percent() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -2659,12 +3366,12 @@ fn test_active_threshold_percent() {
         .execute_contract(
             Addr::unchecked(CREATOR_ADDR),
             govmod.clone(),
-            &ExecuteMsg::Propose {
+            &ExecuteMsg::Propose(MultipleChoiceProposeMsg {
                 title: "A simple text proposal".to_string(),
                 description: "A simple text proposal".to_string(),
                 choices: mc_options.clone(),
                 proposer: None,
-            },
+            }),
             &[],
         )
         .unwrap_err();
@@ -2684,12 +3391,12 @@ fn test_active_threshold_percent() {
         .execute_contract(
             Addr::unchecked(CREATOR_ADDR),
             govmod.clone(),
-            &ExecuteMsg::Propose {
+            &ExecuteMsg::Propose(MultipleChoiceProposeMsg {
                 title: "A simple text proposal".to_string(),
                 description: "A simple text proposal".to_string(),
                 choices: mc_options.clone(),
                 proposer: None,
-            },
+            }),
             &[],
         )
         .unwrap();
@@ -2697,6 +3404,7 @@ fn test_active_threshold_percent() {
     // Unstake some tokens to make it inactive again
     let msg = cw20_stake::msg::ExecuteMsg::Unstake {
         amount: Uint128::new(1000),
+        auto_claim: false,
     };
     app.execute_contract(Addr::unchecked(CREATOR_ADDR), staking_contract, &msg, &[])
         .unwrap();
@@ -2707,12 +3415,12 @@ fn test_active_threshold_percent() {
         .execute_contract(
             Addr::unchecked(CREATOR_ADDR),
             govmod,
-            &ExecuteMsg::Propose {
+            &ExecuteMsg::Propose(MultipleChoiceProposeMsg {
                 title: "A simple text proposal".to_string(),
                 description: "A simple text proposal".to_string(),
                 choices: mc_options,
                 proposer: None,
-            },
+            }),
             &[],
         )
         .unwrap_err();
@@ -2728,6 +3436,9 @@ fn test_active_threshold_none() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -2790,12 +3501,12 @@ fn test_active_threshold_none() {
         .execute_contract(
             Addr::unchecked(CREATOR_ADDR),
             govmod,
-            &ExecuteMsg::Propose {
+            &ExecuteMsg::Propose(MultipleChoiceProposeMsg {
                 title: "A simple text proposal".to_string(),
                 description: "A simple text proposal".to_string(),
                 choices: mc_options.clone(),
                 proposer: None,
-            },
+            }),
             &[],
         )
         .unwrap();
@@ -2815,12 +3526,12 @@ fn test_active_threshold_none() {
         .execute_contract(
             Addr::unchecked(CREATOR_ADDR),
             govmod,
-            &ExecuteMsg::Propose {
+            &ExecuteMsg::Propose(MultipleChoiceProposeMsg {
                 title: "A simple text proposal".to_string(),
                 description: "A simple text proposal".to_string(),
                 choices: mc_options,
                 proposer: None,
-            },
+            }),
             &[],
         )
         .unwrap();
@@ -2842,6 +3553,9 @@ fn test_revoting() {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            max_proposal_size: None,
+            max_open_proposals: None,
+            pre_propose_fallback: None,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         },
         Some(vec![
@@ -2876,12 +3590,12 @@ fn test_revoting() {
     app.execute_contract(
         Addr::unchecked("a-1"),
         govmod.clone(),
-        &ExecuteMsg::Propose {
+        &ExecuteMsg::Propose(MultipleChoiceProposeMsg {
             title: "A simple text proposal".to_string(),
             description: "A simple text proposal".to_string(),
             choices: mc_options,
             proposer: None,
-        },
+        }),
         &[],
     )
     .unwrap();
@@ -2893,6 +3607,7 @@ fn test_revoting() {
         &ExecuteMsg::Vote {
             proposal_id: 1,
             vote: MultipleChoiceVote { option_id: 0 },
+            rationale: None,
         },
         &[],
     )
@@ -2905,6 +3620,7 @@ fn test_revoting() {
         &ExecuteMsg::Vote {
             proposal_id: 1,
             vote: MultipleChoiceVote { option_id: 1 },
+            rationale: None,
         },
         &[],
     )
@@ -2936,6 +3652,7 @@ fn test_revoting() {
         &ExecuteMsg::Vote {
             proposal_id: 1,
             vote: MultipleChoiceVote { option_id: 0 },
+            rationale: None,
         },
         &[],
     )
@@ -2971,6 +3688,9 @@ fn test_allow_revoting_config_changes() {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            max_proposal_size: None,
+            max_open_proposals: None,
+            pre_propose_fallback: None,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         },
         Some(vec![
@@ -3005,12 +3725,12 @@ fn test_allow_revoting_config_changes() {
     app.execute_contract(
         Addr::unchecked("a-1"),
         proposal_module.clone(),
-        &ExecuteMsg::Propose {
+        &ExecuteMsg::Propose(MultipleChoiceProposeMsg {
             title: "A simple text proposal".to_string(),
             description: "A simple text proposal".to_string(),
             choices: mc_options.clone(),
             proposer: None,
-        },
+        }),
         &[],
     )
     .unwrap();
@@ -3029,6 +3749,9 @@ fn test_allow_revoting_config_changes() {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            max_proposal_size: None,
+            max_open_proposals: None,
+            pre_propose_fallback: None,
         },
         &[],
     )
@@ -3044,6 +3767,7 @@ fn test_allow_revoting_config_changes() {
         &ExecuteMsg::Vote {
             proposal_id: 1,
             vote: MultipleChoiceVote { option_id: 0 },
+            rationale: None,
         },
         &[],
     )
@@ -3054,6 +3778,7 @@ fn test_allow_revoting_config_changes() {
         &ExecuteMsg::Vote {
             proposal_id: 1,
             vote: MultipleChoiceVote { option_id: 1 },
+            rationale: None,
         },
         &[],
     )
@@ -3063,12 +3788,12 @@ fn test_allow_revoting_config_changes() {
     app.execute_contract(
         Addr::unchecked("a-2"),
         proposal_module.clone(),
-        &ExecuteMsg::Propose {
+        &ExecuteMsg::Propose(MultipleChoiceProposeMsg {
             title: "A very complex text proposal".to_string(),
             description: "A very complex text proposal".to_string(),
             choices: mc_options,
             proposer: None,
-        },
+        }),
         &[],
     )
     .unwrap();
@@ -3079,6 +3804,7 @@ fn test_allow_revoting_config_changes() {
         &ExecuteMsg::Vote {
             proposal_id: 2,
             vote: MultipleChoiceVote { option_id: 0 },
+            rationale: None,
         },
         &[],
     )
@@ -3091,6 +3817,7 @@ fn test_allow_revoting_config_changes() {
             &ExecuteMsg::Vote {
                 proposal_id: 2,
                 vote: MultipleChoiceVote { option_id: 1 },
+                rationale: None,
             },
             &[],
         )
@@ -3118,6 +3845,9 @@ fn test_revoting_same_vote_twice() {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            max_proposal_size: None,
+            max_open_proposals: None,
+            pre_propose_fallback: None,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         },
         Some(vec![
@@ -3152,12 +3882,12 @@ fn test_revoting_same_vote_twice() {
     app.execute_contract(
         Addr::unchecked("a-1"),
         proprosal_module.clone(),
-        &ExecuteMsg::Propose {
+        &ExecuteMsg::Propose(MultipleChoiceProposeMsg {
             title: "A simple text proposal".to_string(),
             description: "A simple text proposal".to_string(),
             choices: mc_options,
             proposer: None,
-        },
+        }),
         &[],
     )
     .unwrap();
@@ -3169,6 +3899,7 @@ fn test_revoting_same_vote_twice() {
         &ExecuteMsg::Vote {
             proposal_id: 1,
             vote: MultipleChoiceVote { option_id: 0 },
+            rationale: None,
         },
         &[],
     )
@@ -3182,6 +3913,7 @@ fn test_revoting_same_vote_twice() {
             &ExecuteMsg::Vote {
                 proposal_id: 1,
                 vote: MultipleChoiceVote { option_id: 0 },
+                rationale: None,
             },
             &[],
         )
@@ -3210,6 +3942,9 @@ fn test_invalid_revote_does_not_invalidate_initial_vote() {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            max_proposal_size: None,
+            max_open_proposals: None,
+            pre_propose_fallback: None,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         },
         Some(vec![
@@ -3244,12 +3979,12 @@ fn test_invalid_revote_does_not_invalidate_initial_vote() {
     app.execute_contract(
         Addr::unchecked("a-1"),
         proposal_module.clone(),
-        &ExecuteMsg::Propose {
+        &ExecuteMsg::Propose(MultipleChoiceProposeMsg {
             title: "A simple text proposal".to_string(),
             description: "A simple text proposal".to_string(),
             choices: mc_options,
             proposer: None,
-        },
+        }),
         &[],
     )
     .unwrap();
@@ -3261,6 +3996,7 @@ fn test_invalid_revote_does_not_invalidate_initial_vote() {
         &ExecuteMsg::Vote {
             proposal_id: 1,
             vote: MultipleChoiceVote { option_id: 0 },
+            rationale: None,
         },
         &[],
     )
@@ -3273,6 +4009,7 @@ fn test_invalid_revote_does_not_invalidate_initial_vote() {
         &ExecuteMsg::Vote {
             proposal_id: 1,
             vote: MultipleChoiceVote { option_id: 1 },
+            rationale: None,
         },
         &[],
     )
@@ -3304,6 +4041,7 @@ fn test_invalid_revote_does_not_invalidate_initial_vote() {
             &ExecuteMsg::Vote {
                 proposal_id: 1,
                 vote: MultipleChoiceVote { option_id: 99 },
+                rationale: None,
             },
             &[],
         )
@@ -3340,13 +4078,21 @@ fn test_return_deposit_to_dao_on_proposal_failure() {
             denom: DepositToken::VotingModuleToken {},
             amount: Uint128::new(1),
             refund_policy: DepositRefundPolicy::OnlyPassed,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false,
     );
 
     let core_state: dao_core::query::DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(core_addr.clone(), &dao_core::msg::QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr.clone(),
+            &dao_core::msg::QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
     let proposal_modules = core_state.proposal_modules;
 
@@ -3395,6 +4141,9 @@ fn test_close_failed_proposal() {
         only_members_execute: false,
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
     };
 
@@ -3466,12 +4215,12 @@ fn test_close_failed_proposal() {
     app.execute_contract(
         Addr::unchecked(CREATOR_ADDR),
         govmod.clone(),
-        &ExecuteMsg::Propose {
+        &ExecuteMsg::Propose(MultipleChoiceProposeMsg {
             title: "A simple burn tokens proposal".to_string(),
             description: "Burning more tokens, than dao treasury have".to_string(),
             choices: mc_options.clone(),
             proposer: None,
-        },
+        }),
         &[],
     )
     .unwrap();
@@ -3483,6 +4232,7 @@ fn test_close_failed_proposal() {
         &ExecuteMsg::Vote {
             proposal_id: 1,
             vote: MultipleChoiceVote { option_id: 0 },
+            rationale: None,
         },
         &[],
     )
@@ -3512,7 +4262,7 @@ fn test_close_failed_proposal() {
         app.execute_contract(
             Addr::unchecked(CREATOR_ADDR),
             govmod.clone(),
-            &ExecuteMsg::Propose {
+            &ExecuteMsg::Propose(MultipleChoiceProposeMsg {
                 title: "Disable closing failed proposals".to_string(),
                 description: "We want to re-execute failed proposals".to_string(),
                 choices: MultipleChoiceOptions {
@@ -3529,6 +4279,9 @@ fn test_close_failed_proposal() {
                                     allow_revoting: false,
                                     dao: original.dao.to_string(),
                                     close_proposal_on_execution_failure: false,
+                                    max_proposal_size: None,
+                                    max_open_proposals: None,
+                                    pre_propose_fallback: None,
                                 })
                                 .unwrap(),
                                 funds: vec![],
@@ -3544,7 +4297,7 @@ fn test_close_failed_proposal() {
                     ],
                 },
                 proposer: None,
-            },
+            }),
             &[],
         )
         .unwrap();
@@ -3556,6 +4309,7 @@ fn test_close_failed_proposal() {
             &ExecuteMsg::Vote {
                 proposal_id: 2,
                 vote: MultipleChoiceVote { option_id: 0 },
+                rationale: None,
             },
             &[],
         )
@@ -3575,12 +4329,12 @@ fn test_close_failed_proposal() {
     app.execute_contract(
         Addr::unchecked(CREATOR_ADDR),
         govmod.clone(),
-        &ExecuteMsg::Propose {
+        &ExecuteMsg::Propose(MultipleChoiceProposeMsg {
             title: "A simple burn tokens proposal".to_string(),
             description: "Burning more tokens, than dao treasury have".to_string(),
             choices: mc_options,
             proposer: None,
-        },
+        }),
         &[],
     )
     .unwrap();
@@ -3592,6 +4346,7 @@ fn test_close_failed_proposal() {
         &ExecuteMsg::Vote {
             proposal_id: 3,
             vote: MultipleChoiceVote { option_id: 0 },
+            rationale: None,
         },
         &[],
     )
@@ -3629,6 +4384,9 @@ fn test_no_double_refund_on_execute_fail_and_close() {
         only_members_execute: false,
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
         pre_propose_info: get_pre_propose_info(
             &mut app,
             Some(UncheckedDepositInfo {
@@ -3638,6 +4396,8 @@ fn test_no_double_refund_on_execute_fail_and_close() {
                 // that we don't get a second refund on close. Refunds on
                 // close only happen if this is true.
                 refund_policy: DepositRefundPolicy::Always,
+                additional: vec![],
+                refund_on_cancel: false,
             }),
             false,
         ),
@@ -3758,6 +4518,7 @@ fn test_no_double_refund_on_execute_fail_and_close() {
         &ExecuteMsg::Vote {
             proposal_id: 1,
             vote: MultipleChoiceVote { option_id: 0 },
+            rationale: None,
         },
         &[],
     )
@@ -3817,6 +4578,9 @@ pub fn test_not_allow_voting_on_expired_proposal() {
         },
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
     };
     let core_addr = instantiate_with_staked_balances_governance(
@@ -3854,12 +4618,12 @@ pub fn test_not_allow_voting_on_expired_proposal() {
     app.execute_contract(
         Addr::unchecked("a-1"),
         proposal_module.clone(),
-        &ExecuteMsg::Propose {
+        &ExecuteMsg::Propose(MultipleChoiceProposeMsg {
             title: "A simple text proposal".to_string(),
             description: "A simple text proposal".to_string(),
             choices: mc_options,
             proposer: None,
-        },
+        }),
         &[],
     )
     .unwrap();
@@ -3878,6 +4642,7 @@ pub fn test_not_allow_voting_on_expired_proposal() {
             &ExecuteMsg::Vote {
                 proposal_id: 1,
                 vote: MultipleChoiceVote { option_id: 0 },
+                rationale: None,
             },
             &[],
         )
@@ -3907,6 +4672,9 @@ fn test_next_proposal_id() {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            max_proposal_size: None,
+            max_open_proposals: None,
+            pre_propose_fallback: None,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         },
         Some(vec![
@@ -3947,12 +4715,12 @@ fn test_next_proposal_id() {
     app.execute_contract(
         Addr::unchecked("a-1"),
         proposal_module.clone(),
-        &ExecuteMsg::Propose {
+        &ExecuteMsg::Propose(MultipleChoiceProposeMsg {
             title: "A simple text proposal".to_string(),
             description: "A simple text proposal".to_string(),
             choices: mc_options,
             proposer: None,
-        },
+        }),
         &[],
     )
     .unwrap();
@@ -3963,3 +4731,139 @@ fn test_next_proposal_id() {
         .unwrap();
     assert_eq!(next_proposal_id, 2);
 }
+
+#[test]
+fn test_vote_rationale() {
+    let mut app = App::default();
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        InstantiateMsg {
+            min_voting_period: None,
+            max_voting_period: Duration::Height(6),
+            only_members_execute: false,
+            allow_revoting: true,
+            voting_strategy: VotingStrategy::SingleChoice {
+                quorum: PercentageThreshold::Majority {},
+            },
+            close_proposal_on_execution_failure: false,
+            max_proposal_size: None,
+            max_open_proposals: None,
+            pre_propose_fallback: None,
+            pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        },
+        Some(vec![
+            Cw20Coin {
+                address: "a-1".to_string(),
+                amount: Uint128::new(100_000_000),
+            },
+            Cw20Coin {
+                address: "a-2".to_string(),
+                amount: Uint128::new(100_000_000),
+            },
+        ]),
+    );
+    let proposal_module = query_multiple_proposal_module(&app, &core_addr);
+
+    let options = vec![
+        MultipleChoiceOption {
+            description: "multiple choice option 1".to_string(),
+            msgs: vec![],
+            title: "title".to_string(),
+        },
+        MultipleChoiceOption {
+            description: "multiple choice option 2".to_string(),
+            msgs: vec![],
+            title: "title".to_string(),
+        },
+    ];
+    let mc_options = MultipleChoiceOptions { options };
+    let proposal_id = make_proposal(&mut app, &proposal_module, "a-1", mc_options);
+
+    // Updating a rationale without having voted fails.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("a-1"),
+            proposal_module.clone(),
+            &ExecuteMsg::UpdateRationale {
+                proposal_id,
+                rationale: Some("thoughts".to_string()),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::NoSuchVote { id: 1, .. }));
+
+    let rationale = Some("option 1 is cheaper".to_string());
+    app.execute_contract(
+        Addr::unchecked("a-1"),
+        proposal_module.clone(),
+        &ExecuteMsg::Vote {
+            proposal_id,
+            vote: MultipleChoiceVote { option_id: 0 },
+            rationale: rationale.clone(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let list_votes = |app: &App| -> Vec<Option<String>> {
+        let response: VoteListResponse = app
+            .wrap()
+            .query_wasm_smart(
+                proposal_module.clone(),
+                &QueryMsg::ListVotes {
+                    proposal_id,
+                    start_after: None,
+                    limit: None,
+                    order: None,
+                },
+            )
+            .unwrap();
+        response
+            .votes
+            .into_iter()
+            .map(|vote| vote.rationale)
+            .collect()
+    };
+    assert_eq!(list_votes(&app), vec![rationale]);
+
+    let rationale = Some("option 1 is cheaper, but slower".to_string());
+    app.execute_contract(
+        Addr::unchecked("a-1"),
+        proposal_module.clone(),
+        &ExecuteMsg::UpdateRationale {
+            proposal_id,
+            rationale: rationale.clone(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let vote: VoteResponse = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_module.clone(),
+            &QueryMsg::GetVote {
+                proposal_id,
+                voter: "a-1".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(vote.vote.unwrap().rationale, rationale);
+
+    // Revoting without a rationale clears the previous one.
+    app.execute_contract(
+        Addr::unchecked("a-1"),
+        proposal_module.clone(),
+        &ExecuteMsg::Vote {
+            proposal_id,
+            vote: MultipleChoiceVote { option_id: 1 },
+            rationale: None,
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(list_votes(&app), vec![None]);
+}
@@ -0,0 +1,357 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo,
+    Order, Response, StdResult, WasmMsg,
+};
+use cw2::set_contract_version;
+use cw_paginate::keyset_bounds;
+use cw_utils::{must_pay, Duration};
+use dao_interface::{
+    proposal::GenericProposalInfoResponse,
+    querier::{CoreQuerier, ProposalModuleQuerier},
+};
+use dao_proposal_single::{
+    msg::{ExecuteMsg as ConfirmationExecuteMsg, QueryMsg as ConfirmationQueryMsg},
+    query::ProposalResponse as ConfirmationResponse,
+};
+use dao_voting::{proposal::SingleChoiceProposeMsg, status::Status};
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::proposal::{Challenge, OptimisticProposal};
+use crate::state::{Config, CONFIG, PROPOSALS, PROPOSAL_COUNT};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-proposal-optimistic";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub const DEFAULT_LIMIT: u32 = 30;
+pub const MAX_LIMIT: u32 = 100;
+
+fn validate_config(
+    deps: Deps,
+    dao: Addr,
+    confirmation_module: String,
+    challenge_period: Duration,
+    bond: Coin,
+) -> Result<Config, ContractError> {
+    if bond.amount.is_zero() {
+        return Err(ContractError::ZeroBond {});
+    }
+    Ok(Config {
+        dao,
+        confirmation_module: deps.api.addr_validate(&confirmation_module)?,
+        challenge_period,
+        bond,
+    })
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let config = validate_config(
+        deps.as_ref(),
+        info.sender,
+        msg.confirmation_module,
+        msg.challenge_period,
+        msg.bond,
+    )?;
+    CONFIG.save(deps.storage, &config)?;
+    PROPOSAL_COUNT.save(deps.storage, &0)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("dao", config.dao)
+        .add_attribute("confirmation_module", config.confirmation_module))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Propose {
+            title,
+            description,
+            msgs,
+        } => execute_propose(deps, env, info, title, description, msgs),
+        ExecuteMsg::Challenge { proposal_id } => execute_challenge(deps, env, info, proposal_id),
+        ExecuteMsg::Resolve { proposal_id } => execute_resolve(deps, env, proposal_id),
+        ExecuteMsg::UpdateConfig {
+            confirmation_module,
+            challenge_period,
+            bond,
+        } => execute_update_config(deps, info, confirmation_module, challenge_period, bond),
+    }
+}
+
+/// Checks that exactly `bond` was sent with a message.
+fn check_bond(info: &MessageInfo, bond: &Coin) -> Result<(), ContractError> {
+    if must_pay(info, &bond.denom)? != bond.amount {
+        return Err(ContractError::WrongBond {
+            expected: bond.clone(),
+        });
+    }
+    Ok(())
+}
+
+fn send_bond(to: &Addr, bond: Coin) -> CosmosMsg {
+    BankMsg::Send {
+        to_address: to.to_string(),
+        amount: vec![bond],
+    }
+    .into()
+}
+
+pub fn execute_propose(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    title: String,
+    description: String,
+    msgs: Vec<CosmosMsg<Empty>>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let power = CoreQuerier::new(deps.querier, config.dao.clone())
+        .voting_power_at_height(&info.sender, None)?;
+    if power.power.is_zero() {
+        return Err(ContractError::NotVoter {});
+    }
+    check_bond(&info, &config.bond)?;
+
+    let id = PROPOSAL_COUNT.load(deps.storage)? + 1;
+    PROPOSAL_COUNT.save(deps.storage, &id)?;
+    PROPOSALS.save(
+        deps.storage,
+        id,
+        &OptimisticProposal {
+            title,
+            description,
+            proposer: info.sender.clone(),
+            start_height: env.block.height,
+            expiration: config.challenge_period.after(&env.block),
+            msgs,
+            bond: config.bond,
+            challenge: None,
+            status: Status::Open,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "propose")
+        .add_attribute("proposal_id", id.to_string())
+        .add_attribute("proposer", info.sender))
+}
+
+pub fn execute_challenge(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    let mut proposal = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+    if !proposal.is_challengeable(&env.block) {
+        return Err(ContractError::NotChallengeable { id: proposal_id });
+    }
+    check_bond(&info, &proposal.bond)?;
+
+    let confirmation_module = CONFIG.load(deps.storage)?.confirmation_module;
+    let confirmation_id =
+        ProposalModuleQuerier::new(deps.querier, confirmation_module.clone()).next_proposal_id()?;
+    let description = format!(
+        "A yes vote confirms proposal {proposal_id} of {}, challenged by {}.\n\n{}",
+        env.contract.address, info.sender, proposal.description
+    );
+    let confirm = WasmMsg::Execute {
+        contract_addr: confirmation_module.to_string(),
+        msg: to_binary(&ConfirmationExecuteMsg::Propose(SingleChoiceProposeMsg {
+            title: format!("Confirm: {}", proposal.title),
+            description,
+            msgs: vec![],
+            proposer: None,
+            description_hash: None,
+            content_cid: None,
+            disable_revoting: false,
+        }))?,
+        funds: vec![],
+    };
+
+    proposal.challenge = Some(Challenge {
+        challenger: info.sender.clone(),
+        confirmation_module,
+        confirmation_id,
+    });
+    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "challenge")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("challenger", info.sender)
+        .add_attribute("confirmation_id", confirmation_id.to_string())
+        .add_message(confirm))
+}
+
+pub fn execute_resolve(
+    deps: DepsMut,
+    env: Env,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut proposal = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+
+    // Whether the proposal is executed, and the bond forfeited to
+    // the DAO, if any.
+    let (execute, forfeited) = match (proposal.current_status(&env.block), &proposal.challenge) {
+        (Status::Passed, None) => (true, None),
+        (Status::Open, None) => return Err(ContractError::ChallengeWindowOpen { id: proposal_id }),
+        (Status::Open, Some(challenge)) => {
+            let confirmation: ConfirmationResponse = deps.querier.query_wasm_smart(
+                &challenge.confirmation_module,
+                &ConfirmationQueryMsg::Proposal {
+                    proposal_id: challenge.confirmation_id,
+                },
+            )?;
+            match confirmation.proposal.status {
+                Status::Passed | Status::Executed | Status::ExecutionFailed => {
+                    (true, Some(&challenge.challenger))
+                }
+                Status::Rejected | Status::Closed | Status::Cancelled => {
+                    (false, Some(&proposal.proposer))
+                }
+                Status::Open => return Err(ContractError::ConfirmationPending { id: proposal_id }),
+            }
+        }
+        _ => return Err(ContractError::AlreadyResolved { id: proposal_id }),
+    };
+
+    let mut messages = vec![];
+    if execute && !proposal.msgs.is_empty() {
+        messages.push(
+            WasmMsg::Execute {
+                contract_addr: config.dao.to_string(),
+                msg: to_binary(&dao_core::msg::ExecuteMsg::ExecuteProposalHook {
+                    msgs: proposal.msgs.clone(),
+                })?,
+                funds: vec![],
+            }
+            .into(),
+        );
+    }
+    // The proposer is refunded unless they lost a challenge, and
+    // the challenger unless they lost theirs.
+    let mut refunded = vec![&proposal.proposer];
+    if let Some(challenge) = &proposal.challenge {
+        refunded.push(&challenge.challenger);
+    }
+    for addr in refunded {
+        let to = if Some(addr) == forfeited {
+            &config.dao
+        } else {
+            addr
+        };
+        messages.push(send_bond(to, proposal.bond.clone()));
+    }
+
+    proposal.status = if execute {
+        Status::Executed
+    } else {
+        Status::Closed
+    };
+    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "resolve")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("status", proposal.status.to_string())
+        .add_messages(messages))
+}
+
+pub fn execute_update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    confirmation_module: String,
+    challenge_period: Duration,
+    bond: Coin,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.dao {
+        return Err(ContractError::Unauthorized {});
+    }
+    let config = validate_config(
+        deps.as_ref(),
+        config.dao,
+        confirmation_module,
+        challenge_period,
+        bond,
+    )?;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_config")
+        .add_attribute("confirmation_module", config.confirmation_module))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::Dao {} => to_binary(&CONFIG.load(deps.storage)?.dao),
+        QueryMsg::Info {} => {
+            let info = cw2::get_contract_version(deps.storage)?;
+            to_binary(&dao_interface::voting::InfoResponse { info })
+        }
+        QueryMsg::NextProposalId {} => to_binary(&(PROPOSAL_COUNT.load(deps.storage)? + 1)),
+        QueryMsg::Proposal { proposal_id } => {
+            let proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+            to_binary(&proposal.into_response(&env.block, proposal_id))
+        }
+        QueryMsg::ListProposals { start_after, limit } => {
+            query_list_proposals(deps, env, start_after, limit)
+        }
+        QueryMsg::GenericProposalInfo { proposal_id } => {
+            let proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+            to_binary(&GenericProposalInfoResponse {
+                status: proposal.current_status(&env.block).to_string(),
+                proposer: proposal.proposer,
+                expiration: proposal.expiration,
+                created: proposal.start_height,
+            })
+        }
+    }
+}
+
+pub fn query_list_proposals(
+    deps: Deps,
+    env: Env,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = cw_paginate::clamp_limit(limit, DEFAULT_LIMIT, MAX_LIMIT);
+    let (min, max) = keyset_bounds(start_after, Order::Ascending);
+    let proposals = PROPOSALS
+        .range(deps.storage, min, max, Order::Ascending)
+        .take(limit as usize)
+        .map(|item| item.map(|(id, proposal)| proposal.into_response(&env.block, id)))
+        .collect::<StdResult<Vec<_>>>()?;
+    to_binary(&proposals)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    msg.check_stored_version(deps.storage, CONTRACT_VERSION)?;
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::default())
+}
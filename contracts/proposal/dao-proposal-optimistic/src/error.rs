@@ -0,0 +1,43 @@
+use cosmwasm_std::{Coin, StdError};
+use cw_utils::PaymentError;
+use dao_interface::migrate::MigrationError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    MigrationError(#[from] MigrationError),
+
+    #[error("{0}")]
+    PaymentError(#[from] PaymentError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Only addresses with voting power may create proposals")]
+    NotVoter {},
+
+    #[error("The challenge bond must be non-zero")]
+    ZeroBond {},
+
+    #[error("A bond of exactly {expected} must be posted")]
+    WrongBond { expected: Coin },
+
+    #[error("No such proposal ({id})")]
+    NoSuchProposal { id: u64 },
+
+    #[error("Proposal ({id}) may only be challenged while its challenge window is open")]
+    NotChallengeable { id: u64 },
+
+    #[error("Proposal ({id}) may not be resolved until its challenge window closes")]
+    ChallengeWindowOpen { id: u64 },
+
+    #[error("Proposal ({id}) may not be resolved until its confirmation vote completes")]
+    ConfirmationPending { id: u64 },
+
+    #[error("Proposal ({id}) has already been resolved")]
+    AlreadyResolved { id: u64 },
+}
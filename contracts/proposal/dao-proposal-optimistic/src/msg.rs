@@ -0,0 +1,62 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Coin, CosmosMsg, Empty};
+use cw_utils::Duration;
+use dao_macros::proposal_module_query;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The `dao-proposal-single` module challenged proposals are sent
+    /// to for a confirmation vote. It must allow anyone to propose.
+    pub confirmation_module: String,
+    /// How long after creation a proposal may be challenged.
+    pub challenge_period: Duration,
+    /// The bond proposers and challengers must post. Must be
+    /// non-zero.
+    pub bond: Coin,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Creates a proposal. The sender must have voting power and
+    /// attach the bond.
+    Propose {
+        title: String,
+        description: String,
+        msgs: Vec<CosmosMsg<Empty>>,
+    },
+    /// Challenges a proposal during its challenge window, creating a
+    /// confirmation vote. The sender must attach the proposal's bond.
+    Challenge { proposal_id: u64 },
+    /// Executes an unchallenged proposal whose challenge window has
+    /// closed, or settles a challenged proposal once its confirmation
+    /// vote has completed. Bonds are returned or forfeited to the
+    /// DAO.
+    Resolve { proposal_id: u64 },
+    /// Updates the module's config. Only the DAO may call this.
+    /// Proposals which are already open are unaffected.
+    UpdateConfig {
+        confirmation_module: String,
+        challenge_period: Duration,
+        bond: Coin,
+    },
+}
+
+#[proposal_module_query]
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Gets the module's config.
+    #[returns(crate::state::Config)]
+    Config {},
+    /// Gets information about a proposal.
+    #[returns(crate::proposal::ProposalResponse)]
+    Proposal { proposal_id: u64 },
+    /// Lists proposals in ascending order of ID.
+    #[returns(Vec<crate::proposal::ProposalResponse>)]
+    ListProposals {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+}
+
+pub use dao_interface::migrate::MigrateMsg;
@@ -0,0 +1,75 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, BlockInfo, Coin, CosmosMsg, Empty};
+use cw_utils::Expiration;
+use dao_voting::status::Status;
+
+/// A challenge made against a proposal during its challenge window.
+#[cw_serde]
+pub struct Challenge {
+    /// The address that challenged the proposal.
+    pub challenger: Addr,
+    /// The proposal module the confirmation vote was created in.
+    pub confirmation_module: Addr,
+    /// The ID of the confirmation vote's proposal.
+    pub confirmation_id: u64,
+}
+
+#[cw_serde]
+pub struct OptimisticProposal {
+    pub title: String,
+    pub description: String,
+    /// The address that created this proposal.
+    pub proposer: Addr,
+    /// The block height at which this proposal was created.
+    pub start_height: u64,
+    /// The end of the challenge window. Unchallenged proposals may be
+    /// executed after this.
+    pub expiration: Expiration,
+    /// The messages that will be executed should this proposal be
+    /// executed.
+    pub msgs: Vec<CosmosMsg<Empty>>,
+    /// The bond posted by the proposer. Challengers post the same
+    /// bond.
+    pub bond: Coin,
+    /// The challenge made against this proposal, if any.
+    pub challenge: Option<Challenge>,
+    /// `Open` until the proposal is resolved, after which it is either
+    /// `Executed` or `Closed`.
+    pub status: Status,
+}
+
+#[cw_serde]
+pub struct ProposalResponse {
+    pub id: u64,
+    pub proposal: OptimisticProposal,
+}
+
+impl OptimisticProposal {
+    /// Gets the current status of the proposal. Unchallenged
+    /// proposals whose challenge window has closed are `Passed`.
+    pub fn current_status(&self, block: &BlockInfo) -> Status {
+        if self.status == Status::Open
+            && self.challenge.is_none()
+            && self.expiration.is_expired(block)
+        {
+            Status::Passed
+        } else {
+            self.status
+        }
+    }
+
+    /// Whether this proposal may currently be challenged.
+    pub fn is_challengeable(&self, block: &BlockInfo) -> bool {
+        self.status == Status::Open
+            && self.challenge.is_none()
+            && !self.expiration.is_expired(block)
+    }
+
+    /// Consumes the proposal and returns a version which may be used
+    /// in a query response, with its status updated to the current
+    /// block.
+    pub fn into_response(mut self, block: &BlockInfo, id: u64) -> ProposalResponse {
+        self.status = self.current_status(block);
+        ProposalResponse { id, proposal: self }
+    }
+}
@@ -0,0 +1,27 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin};
+use cw_storage_plus::{Item, Map};
+use cw_utils::Duration;
+
+use crate::proposal::OptimisticProposal;
+
+/// The proposal module's configuration.
+#[cw_serde]
+pub struct Config {
+    /// The address of the DAO that this proposal module is associated
+    /// with.
+    pub dao: Addr,
+    /// The `dao-proposal-single` module challenged proposals are sent
+    /// to for a confirmation vote. It must allow anyone to propose.
+    pub confirmation_module: Addr,
+    /// How long after creation a proposal may be challenged.
+    pub challenge_period: Duration,
+    /// The bond proposers and challengers must post.
+    pub bond: Coin,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+/// The number of proposals that have been created.
+pub const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
+/// Proposals keyed by ID.
+pub const PROPOSALS: Map<u64, OptimisticProposal> = Map::new("proposals");
@@ -0,0 +1,365 @@
+use cosmwasm_std::testing::{
+    mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+};
+use cosmwasm_std::{
+    coin, coins, from_binary, from_slice, to_binary, Addr, BankMsg, ContractResult, CosmosMsg,
+    Empty, Env, OwnedDeps, SystemResult, Uint128, WasmMsg, WasmQuery,
+};
+use cw_utils::{Duration, Expiration};
+use dao_interface::{
+    migrate::MigrationError, proposal::GenericProposalInfoResponse,
+    voting::VotingPowerAtHeightResponse,
+};
+use dao_proposal_single::{
+    msg::{ExecuteMsg as ConfirmationExecuteMsg, QueryMsg as ConfirmationQueryMsg},
+    proposal::SingleChoiceProposal,
+    query::ProposalResponse as ConfirmationResponse,
+};
+use dao_voting::{
+    proposal::SingleChoiceProposeMsg,
+    status::Status,
+    threshold::{AbstainCounting, PercentageThreshold, Threshold},
+    voting::Votes,
+};
+
+use crate::contract::{execute, instantiate, migrate, query, CONTRACT_NAME, CONTRACT_VERSION};
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::proposal::{Challenge, ProposalResponse};
+use crate::ContractError;
+
+const DAO: &str = "dao";
+const CONFIRMATION_MODULE: &str = "confirmation";
+const MEMBER: &str = "member";
+const CHALLENGER: &str = "challenger";
+const DENOM: &str = "ujuno";
+const BOND: u128 = 100;
+
+fn mock_querier(deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>, status: Status) {
+    deps.querier.update_wasm(move |query| match query {
+        WasmQuery::Smart { contract_addr, msg } if contract_addr == DAO => {
+            let power = match from_slice(msg).unwrap() {
+                dao_core::msg::QueryMsg::VotingPowerAtHeight { address, .. }
+                    if address == MEMBER =>
+                {
+                    Uint128::new(10)
+                }
+                _ => Uint128::zero(),
+            };
+            SystemResult::Ok(ContractResult::Ok(
+                to_binary(&VotingPowerAtHeightResponse { power, height: 0 }).unwrap(),
+            ))
+        }
+        WasmQuery::Smart { contract_addr, msg } if contract_addr == CONFIRMATION_MODULE => {
+            let response = match from_slice(msg).unwrap() {
+                ConfirmationQueryMsg::NextProposalId {} => to_binary(&7u64),
+                ConfirmationQueryMsg::Proposal { proposal_id } => {
+                    to_binary(&ConfirmationResponse {
+                        id: proposal_id,
+                        proposal: SingleChoiceProposal {
+                            title: "title".to_string(),
+                            description: "description".to_string(),
+                            proposer: Addr::unchecked("cosmos2contract"),
+                            start_height: 0,
+                            min_voting_period: None,
+                            expiration: Expiration::AtHeight(100),
+                            threshold: Threshold::AbsolutePercentage {
+                                percentage: PercentageThreshold::Majority {},
+                            },
+                            total_power: Uint128::new(10),
+                            msgs: vec![],
+                            status,
+                            votes: Votes::zero(),
+                            allow_revoting: false,
+                            abstain_counting: AbstainCounting::default(),
+                            execution_delay: None,
+                            earliest_execution: None,
+                            vote_extension: None,
+                            max_expiration: None,
+                            description_hash: None,
+                            content_cid: None,
+                        },
+                    })
+                }
+                _ => panic!("unexpected query"),
+            };
+            SystemResult::Ok(ContractResult::Ok(response.unwrap()))
+        }
+        _ => panic!("unexpected query"),
+    });
+}
+
+fn setup() -> OwnedDeps<MockStorage, MockApi, MockQuerier, Empty> {
+    let mut deps = mock_dependencies();
+    mock_querier(&mut deps, Status::Open);
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        InstantiateMsg {
+            confirmation_module: CONFIRMATION_MODULE.to_string(),
+            challenge_period: Duration::Height(10),
+            bond: coin(BOND, DENOM),
+        },
+    )
+    .unwrap();
+    deps
+}
+
+fn propose(
+    deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>,
+    sender: &str,
+    bond: u128,
+) -> Result<(), ContractError> {
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(sender, &coins(bond, DENOM)),
+        ExecuteMsg::Propose {
+            title: "title".to_string(),
+            description: "description".to_string(),
+            msgs: vec![BankMsg::Burn { amount: vec![] }.into()],
+        },
+    )
+    .map(|_| ())
+}
+
+fn after_challenge_window() -> Env {
+    let mut env = mock_env();
+    env.block.height += 10;
+    env
+}
+
+fn resolve(
+    deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>,
+    env: Env,
+) -> Result<Vec<CosmosMsg>, ContractError> {
+    execute(
+        deps.as_mut(),
+        env,
+        mock_info(CHALLENGER, &[]),
+        ExecuteMsg::Resolve { proposal_id: 1 },
+    )
+    .map(|res| res.messages.into_iter().map(|msg| msg.msg).collect())
+}
+
+fn query_proposal(
+    deps: &OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>,
+    env: Env,
+) -> ProposalResponse {
+    from_binary(&query(deps.as_ref(), env, QueryMsg::Proposal { proposal_id: 1 }).unwrap()).unwrap()
+}
+
+fn execute_hook() -> CosmosMsg {
+    WasmMsg::Execute {
+        contract_addr: DAO.to_string(),
+        msg: to_binary(&dao_core::msg::ExecuteMsg::ExecuteProposalHook {
+            msgs: vec![BankMsg::Burn { amount: vec![] }.into()],
+        })
+        .unwrap(),
+        funds: vec![],
+    }
+    .into()
+}
+
+fn bond_to(addr: &str) -> CosmosMsg {
+    BankMsg::Send {
+        to_address: addr.to_string(),
+        amount: coins(BOND, DENOM),
+    }
+    .into()
+}
+
+#[test]
+fn test_instantiate_zero_bond() {
+    let mut deps = mock_dependencies();
+    let err = instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        InstantiateMsg {
+            confirmation_module: CONFIRMATION_MODULE.to_string(),
+            challenge_period: Duration::Height(10),
+            bond: coin(0, DENOM),
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::ZeroBond {}));
+}
+
+#[test]
+fn test_propose() {
+    let mut deps = setup();
+
+    let err = propose(&mut deps, CHALLENGER, BOND).unwrap_err();
+    assert!(matches!(err, ContractError::NotVoter {}));
+    let err = propose(&mut deps, MEMBER, BOND - 1).unwrap_err();
+    assert!(matches!(err, ContractError::WrongBond { .. }));
+
+    propose(&mut deps, MEMBER, BOND).unwrap();
+    let next: u64 =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::NextProposalId {}).unwrap())
+            .unwrap();
+    assert_eq!(next, 2);
+
+    let info: GenericProposalInfoResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            after_challenge_window(),
+            QueryMsg::GenericProposalInfo { proposal_id: 1 },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(info.status, "passed");
+    assert_eq!(info.proposer, Addr::unchecked(MEMBER));
+}
+
+#[test]
+fn test_unchallenged() {
+    let mut deps = setup();
+    propose(&mut deps, MEMBER, BOND).unwrap();
+
+    let err = resolve(&mut deps, mock_env()).unwrap_err();
+    assert!(matches!(err, ContractError::ChallengeWindowOpen { id: 1 }));
+
+    let msgs = resolve(&mut deps, after_challenge_window()).unwrap();
+    assert_eq!(msgs, vec![execute_hook(), bond_to(MEMBER)]);
+    assert_eq!(
+        query_proposal(&deps, after_challenge_window())
+            .proposal
+            .status,
+        Status::Executed
+    );
+
+    let err = resolve(&mut deps, after_challenge_window()).unwrap_err();
+    assert!(matches!(err, ContractError::AlreadyResolved { id: 1 }));
+    let err = execute(
+        deps.as_mut(),
+        after_challenge_window(),
+        mock_info(CHALLENGER, &coins(BOND, DENOM)),
+        ExecuteMsg::Challenge { proposal_id: 1 },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::NotChallengeable { id: 1 }));
+}
+
+fn challenge(deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>) {
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(CHALLENGER, &coins(BOND + 1, DENOM)),
+        ExecuteMsg::Challenge { proposal_id: 1 },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::WrongBond { .. }));
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(CHALLENGER, &coins(BOND, DENOM)),
+        ExecuteMsg::Challenge { proposal_id: 1 },
+    )
+    .unwrap();
+    match &res.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr, msg, ..
+        }) => {
+            assert_eq!(contract_addr, CONFIRMATION_MODULE);
+            let msg: ConfirmationExecuteMsg = from_binary(msg).unwrap();
+            match msg {
+                ConfirmationExecuteMsg::Propose(SingleChoiceProposeMsg {
+                    msgs, proposer, ..
+                }) => {
+                    assert!(msgs.is_empty());
+                    assert_eq!(proposer, None);
+                }
+                _ => panic!("expected a proposal"),
+            }
+        }
+        _ => panic!("expected a wasm message"),
+    }
+
+    let proposal = query_proposal(deps, after_challenge_window()).proposal;
+    assert_eq!(
+        proposal.challenge,
+        Some(Challenge {
+            challenger: Addr::unchecked(CHALLENGER),
+            confirmation_module: Addr::unchecked(CONFIRMATION_MODULE),
+            confirmation_id: 7,
+        })
+    );
+    // Challenged proposals stay open until their confirmation vote
+    // completes.
+    assert_eq!(proposal.status, Status::Open);
+    let err = resolve(deps, after_challenge_window()).unwrap_err();
+    assert!(matches!(err, ContractError::ConfirmationPending { id: 1 }));
+}
+
+#[test]
+fn test_challenge_confirmed() {
+    let mut deps = setup();
+    propose(&mut deps, MEMBER, BOND).unwrap();
+    challenge(&mut deps);
+
+    mock_querier(&mut deps, Status::Passed);
+    let msgs = resolve(&mut deps, after_challenge_window()).unwrap();
+    assert_eq!(msgs, vec![execute_hook(), bond_to(MEMBER), bond_to(DAO)]);
+    assert_eq!(
+        query_proposal(&deps, after_challenge_window())
+            .proposal
+            .status,
+        Status::Executed
+    );
+}
+
+#[test]
+fn test_challenge_rejected() {
+    let mut deps = setup();
+    propose(&mut deps, MEMBER, BOND).unwrap();
+    challenge(&mut deps);
+
+    mock_querier(&mut deps, Status::Rejected);
+    let msgs = resolve(&mut deps, after_challenge_window()).unwrap();
+    assert_eq!(msgs, vec![bond_to(DAO), bond_to(CHALLENGER)]);
+    assert_eq!(
+        query_proposal(&deps, after_challenge_window())
+            .proposal
+            .status,
+        Status::Closed
+    );
+}
+
+#[test]
+fn test_update_config() {
+    let mut deps = setup();
+    let update = ExecuteMsg::UpdateConfig {
+        confirmation_module: "other".to_string(),
+        challenge_period: Duration::Height(5),
+        bond: coin(BOND, DENOM),
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(MEMBER, &[]),
+        update.clone(),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    execute(deps.as_mut(), mock_env(), mock_info(DAO, &[]), update).unwrap();
+    let config: crate::state::Config =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap()).unwrap();
+    assert_eq!(config.confirmation_module, Addr::unchecked("other"));
+    assert_eq!(config.challenge_period, Duration::Height(5));
+}
+
+#[test]
+fn test_migrate_checks_stored_version() {
+    let mut deps = mock_dependencies();
+    cw2::set_contract_version(&mut deps.storage, CONTRACT_NAME, CONTRACT_VERSION).unwrap();
+    let err = migrate(deps.as_mut(), mock_env(), MigrateMsg::FromV1(Empty {})).unwrap_err();
+    assert!(matches!(
+        err,
+        ContractError::MigrationError(MigrationError::NotPreviousMajor { .. })
+    ));
+    migrate(deps.as_mut(), mock_env(), MigrateMsg::FromCompatible {}).unwrap();
+}
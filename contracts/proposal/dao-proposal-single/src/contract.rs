@@ -1,31 +1,50 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Order, Reply,
-    Response, StdResult, Storage, SubMsg, WasmMsg,
+    from_binary, to_binary, Addr, Binary, BlockInfo, CosmosMsg, Decimal, Deps, DepsMut, Empty, Env,
+    MessageInfo, Order, Reply, Response, StdError, StdResult, Storage, SubMsg, SubMsgResult,
+    Timestamp, Uint128, WasmMsg,
 };
 use cw2::set_contract_version;
-use cw_hooks::Hooks;
+use cw_hooks::{FailureOutcome, HookError, Hooks, HooksResponse};
+use cw_paginate::{clamp_limit, keyset_bounds};
 use cw_proposal_single_v1 as v1;
 use cw_storage_plus::Bound;
-use cw_utils::{parse_reply_instantiate_data, Duration};
-use dao_interface::voting::IsActiveResponse;
-use dao_proposal_hooks::{new_proposal_hooks, proposal_status_changed_hooks};
-use dao_vote_hooks::new_vote_hooks;
-use dao_voting::pre_propose::{PreProposeInfo, ProposalCreationPolicy};
+use cw_utils::{parse_reply_instantiate_data, Duration, Expiration};
+use dao_events::EventSource;
+use dao_interface::{proposal::GenericProposalInfoResponse, querier::CoreQuerier};
+use dao_proposal_hooks::{
+    new_proposal_hooks, proposal_status_changed_hooks, proposal_updated_hooks, ProposalContent,
+};
+use dao_vote_hooks::{NewVote, VoteHookDispatch, VoteTally};
+use dao_voting::pre_propose::{
+    PreProposeFailure, PreProposeFallback, PreProposeInfo, ProposalCreationPolicy,
+};
 use dao_voting::proposal::{
-    SingleChoiceProposeMsg as ProposeMsg, DEFAULT_LIMIT, MAX_PROPOSAL_SIZE,
+    expiration_index_bounds, expiration_index_key, validate_content_cid, validate_description,
+    validate_max_proposal_size, validate_prune_statuses, DescriptionHash, MaxOpenProposals,
+    PrunedProposal, RebuildIndexesCursor, SingleChoiceProposeMsg as ProposeMsg, DEFAULT_LIMIT,
+    MAX_LIMIT, MAX_PROPOSAL_SIZE,
 };
 use dao_voting::reply::{
-    failed_pre_propose_module_hook_id, mask_proposal_execution_proposal_id, TaggedReplyId,
+    failed_pre_propose_module_hook_id, mask_proposal_execution_proposal_id, HookFailureRecord,
+    HookKind, TaggedReplyId, MAX_HOOK_FAILURE_HISTORY,
 };
+use dao_voting::signed_vote::{verify_signed_vote, SignedVote};
+use dao_voting::simulation::{simulate_execution, SimulationResponse};
 use dao_voting::status::Status;
-use dao_voting::threshold::Threshold;
-use dao_voting::voting::{get_total_power, get_voting_power, validate_voting_period, Vote, Votes};
+use dao_voting::threshold::{AbstainCounting, Threshold};
+use dao_voting::voting::{
+    does_vote_count_pass, get_voting_power, validate_execution_delay, validate_voting_period, Vote,
+    VoteListOrder, Votes,
+};
 
-use crate::msg::MigrateMsg;
+use crate::msg::{FromV1Msg, MigrateMsg};
 use crate::proposal::{next_proposal_id, SingleChoiceProposal};
-use crate::state::{Config, CREATION_POLICY};
+use crate::state::{
+    Config, VoteExtension, CREATION_POLICY, GOVERNANCE_STATS, HOOK_FAILURE_HISTORY, PAUSED,
+    POWER_CACHE, PRE_PROPOSE_FAILURE,
+};
 
 use crate::v1_state::{
     v1_duration_to_v2, v1_expiration_to_v2, v1_status_to_v2, v1_threshold_to_v2, v1_votes_to_v2,
@@ -35,13 +54,26 @@ use crate::{
     msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
     proposal::advance_proposal_id,
     query::ProposalListResponse,
-    query::{ProposalResponse, VoteInfo, VoteListResponse, VoteResponse},
-    state::{Ballot, BALLOTS, CONFIG, PROPOSALS, PROPOSAL_COUNT, PROPOSAL_HOOKS, VOTE_HOOKS},
+    query::{
+        BallotInfo, BallotListResponse, ExportStateResponse, ProposalResponse, QuorumProgress,
+        TallySummaryResponse, VerifyTallyResponse, VoteInfo, VoteListResponse, VotePercentages,
+        VoteResponse,
+    },
+    state::{
+        ballots, proposals, Ballot, CONFIG, IMPORTED_VOTES, IMPORTING, PROPOSAL_COUNT,
+        PROPOSAL_HOOKS, PROPOSAL_HOOK_CONTENT, PRUNED_PROPOSALS, VOTE_HOOKS, VOTE_HOOK_BUFFER,
+    },
 };
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-proposal-single";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+const EVENTS: EventSource = EventSource::new(CONTRACT_NAME, CONTRACT_VERSION);
+
+/// The number of times a proposal or vote hook may fail before it is
+/// removed.
+pub const HOOK_RETRIES: u32 = 3;
+
 /// Message type used for firing hooks to this module's pre-propose
 /// module, if one is installed.
 type PreProposeHookMsg = dao_pre_propose_base::msg::ExecuteMsg<Empty, Empty>;
@@ -56,11 +88,20 @@ pub fn instantiate(
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     msg.threshold.validate()?;
+    let threshold_summary = msg.threshold.describe().summary;
 
     let dao = info.sender;
 
     let (min_voting_period, max_voting_period) =
         validate_voting_period(msg.min_voting_period, msg.max_voting_period)?;
+    let execution_delay = validate_execution_delay(msg.execution_delay, max_voting_period)?;
+    if let Some(vote_extension) = &msg.vote_extension {
+        vote_extension.validate(max_voting_period)?;
+    }
+
+    if let Some(max_open_proposals) = &msg.max_open_proposals {
+        max_open_proposals.validate()?;
+    }
 
     let (initial_policy, pre_propose_messages) = msg
         .pre_propose_info
@@ -74,17 +115,26 @@ pub fn instantiate(
         dao: dao.clone(),
         allow_revoting: msg.allow_revoting,
         close_proposal_on_execution_failure: msg.close_proposal_on_execution_failure,
+        max_proposal_size: validate_max_proposal_size(msg.max_proposal_size)?,
+        max_open_proposals: msg.max_open_proposals,
+        pre_propose_fallback: msg.pre_propose_fallback.unwrap_or_default(),
+        require_content_cid: msg.require_content_cid.unwrap_or_default(),
+        abstain_counting: msg.abstain_counting.unwrap_or_default(),
+        execution_delay,
+        vote_extension: msg.vote_extension,
     };
 
     // Initialize proposal count to zero so that queries return zero
     // instead of None.
     PROPOSAL_COUNT.save(deps.storage, &0)?;
+    IMPORTING.save(deps.storage, &true)?;
     CONFIG.save(deps.storage, &config)?;
     CREATION_POLICY.save(deps.storage, &initial_policy)?;
 
     Ok(Response::default()
         .add_submessages(pre_propose_messages)
         .add_attribute("action", "instantiate")
+        .add_attribute("threshold", threshold_summary)
         .add_attribute("dao", dao))
 }
 
@@ -95,24 +145,58 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
+    if !matches!(msg, ExecuteMsg::Pause { .. } | ExecuteMsg::Unpause {}) {
+        PAUSED.assert_not_paused(deps.storage, &env.block)?;
+    }
+
     match msg {
         ExecuteMsg::Propose(ProposeMsg {
             title,
             description,
             msgs,
             proposer,
-        }) => execute_propose(deps, env, info.sender, title, description, msgs, proposer),
+            description_hash,
+            content_cid,
+            disable_revoting,
+        }) => execute_propose(
+            deps,
+            env,
+            info.sender,
+            title,
+            description,
+            msgs,
+            proposer,
+            description_hash,
+            content_cid,
+            disable_revoting,
+        ),
         ExecuteMsg::Vote {
             proposal_id,
             vote,
             rationale,
-        } => execute_vote(deps, env, info, proposal_id, vote, rationale),
+        } => execute_vote(deps, env, info.sender, proposal_id, vote, rationale),
+        ExecuteMsg::SubmitSignedVotes { votes } => {
+            execute_submit_signed_votes(deps, env, info, votes)
+        }
+        ExecuteMsg::VoteFor {
+            voter,
+            proposal_id,
+            vote,
+            rationale,
+        } => execute_vote_for(deps, env, info, voter, proposal_id, vote, rationale),
         ExecuteMsg::UpdateRationale {
             proposal_id,
             rationale,
         } => execute_update_rationale(deps, info, proposal_id, rationale),
+        ExecuteMsg::UpdateProposal {
+            proposal_id,
+            title,
+            description,
+            msgs,
+        } => execute_update_proposal(deps, env, info, proposal_id, title, description, msgs),
         ExecuteMsg::Execute { proposal_id } => execute_execute(deps, env, info, proposal_id),
         ExecuteMsg::Close { proposal_id } => execute_close(deps, env, info, proposal_id),
+        ExecuteMsg::Cancel { proposal_id } => execute_cancel(deps, env, info, proposal_id),
         ExecuteMsg::UpdateConfig {
             threshold,
             max_voting_period,
@@ -121,6 +205,13 @@ pub fn execute(
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
+            max_proposal_size,
+            max_open_proposals,
+            pre_propose_fallback,
+            require_content_cid,
+            abstain_counting,
+            execution_delay,
+            vote_extension,
         } => execute_update_config(
             deps,
             info,
@@ -131,13 +222,21 @@ pub fn execute(
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
+            max_proposal_size,
+            max_open_proposals,
+            pre_propose_fallback,
+            require_content_cid,
+            abstain_counting,
+            execution_delay,
+            vote_extension,
         ),
         ExecuteMsg::UpdatePreProposeInfo { info: new_info } => {
             execute_update_proposal_creation_policy(deps, info, new_info)
         }
-        ExecuteMsg::AddProposalHook { address } => {
-            execute_add_proposal_hook(deps, env, info, address)
-        }
+        ExecuteMsg::AddProposalHook {
+            address,
+            include_content,
+        } => execute_add_proposal_hook(deps, env, info, address, include_content),
         ExecuteMsg::RemoveProposalHook { address } => {
             execute_remove_proposal_hook(deps, env, info, address)
         }
@@ -145,19 +244,52 @@ pub fn execute(
         ExecuteMsg::RemoveVoteHook { address } => {
             execute_remove_vote_hook(deps, env, info, address)
         }
+        ExecuteMsg::Prune {
+            older_than,
+            statuses,
+            limit,
+        } => execute_prune(deps, info, older_than, statuses, limit),
+        ExecuteMsg::RebuildIndexes { start_after, limit } => {
+            execute_rebuild_indexes(deps, info, start_after, limit)
+        }
+        ExecuteMsg::UpdateVoteHookDispatch { dispatch } => {
+            execute_update_vote_hook_dispatch(deps, info, dispatch)
+        }
+        ExecuteMsg::FlushVoteHooks {} => execute_flush_vote_hooks(deps, info),
+        ExecuteMsg::ImportModuleState { module } => execute_import_module_state(deps, info, module),
+        ExecuteMsg::ImportProposals { proposals } => {
+            execute_import_proposals(deps, info, proposals)
+        }
+        ExecuteMsg::ImportVotes { proposal_id, votes } => {
+            execute_import_votes(deps, info, proposal_id, votes)
+        }
+        ExecuteMsg::Pause { duration, reason } => execute_pause(deps, env, info, duration, reason),
+        ExecuteMsg::Unpause {} => execute_unpause(deps, info),
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn execute_propose(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     sender: Addr,
     title: String,
     description: String,
     msgs: Vec<CosmosMsg<Empty>>,
     proposer: Option<String>,
+    description_hash: Option<DescriptionHash>,
+    content_cid: Option<String>,
+    disable_revoting: bool,
 ) -> Result<Response, ContractError> {
+    validate_description(&description, description_hash.as_ref())?;
     let config = CONFIG.load(deps.storage)?;
+    match &content_cid {
+        Some(cid) => validate_content_cid(cid)?,
+        None if config.require_content_cid => {
+            return Err(dao_voting::error::VotingError::ContentCidRequired {}.into())
+        }
+        None => (),
+    }
     let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
 
     // Check that the sender is permitted to create proposals.
@@ -169,7 +301,9 @@ pub fn execute_propose(
     // pre-propose module, it must be specified. Otherwise, the
     // proposer should not be specified.
     let proposer = match (proposer, &proposal_creation_policy) {
-        (None, ProposalCreationPolicy::Anyone {}) => sender.clone(),
+        (None, ProposalCreationPolicy::Anyone {} | ProposalCreationPolicy::Dao { .. }) => {
+            sender.clone()
+        }
         // `is_permitted` above checks that an allowed module is
         // actually sending the propose message.
         (Some(proposer), ProposalCreationPolicy::Module { .. }) => {
@@ -178,31 +312,40 @@ pub fn execute_propose(
         _ => return Err(ContractError::InvalidProposer {}),
     };
 
-    let voting_module: Addr = deps.querier.query_wasm_smart(
-        config.dao.clone(),
-        &dao_core::msg::QueryMsg::VotingModule {},
-    )?;
+    // Open proposals have not expired, so only proposals which expire
+    // after this block are read rather than every proposal last saved
+    // as open.
+    if let Some(max_open_proposals) = &config.max_open_proposals {
+        let open = unexpired_proposals(deps.storage, &env.block)?.filter_map(|item| {
+            item.map(|(_, proposal)| {
+                (proposal.current_status(&env.block) == Status::Open).then_some(proposal.proposer)
+            })
+            .transpose()
+        });
+        max_open_proposals.check(&proposer, open)?;
+    }
 
     // Voting modules are not required to implement this
     // query. Lacking an implementation they are active by default.
-    let active_resp: IsActiveResponse = deps
-        .querier
-        .query_wasm_smart(voting_module, &dao_interface::voting::Query::IsActive {})
-        .unwrap_or(IsActiveResponse { active: true });
+    let active = CoreQuerier::new(deps.querier, config.dao.clone())
+        .voting_module_querier()?
+        .is_active();
 
-    if !active_resp.active {
+    if !active {
         return Err(ContractError::InactiveDao {});
     }
 
     let expiration = config.max_voting_period.after(&env.block);
 
-    let total_power = get_total_power(deps.as_ref(), config.dao, Some(env.block.height))?;
+    let total_power = POWER_CACHE.total_power(deps.branch(), &config.dao, env.block.height)?;
 
     let proposal = {
         // Limit mutability to this block.
         let mut proposal = SingleChoiceProposal {
             title,
             description,
+            description_hash,
+            content_cid,
             proposer: proposer.clone(),
             start_height: env.block.height,
             min_voting_period: config.min_voting_period.map(|min| min.after(&env.block)),
@@ -212,7 +355,15 @@ pub fn execute_propose(
             msgs,
             status: Status::Open,
             votes: Votes::zero(),
-            allow_revoting: config.allow_revoting,
+            // Proposers may opt out of revoting, but not into it.
+            allow_revoting: config.allow_revoting && !disable_revoting,
+            abstain_counting: config.abstain_counting,
+            execution_delay: config.execution_delay,
+            earliest_execution: None,
+            vote_extension: config.vote_extension,
+            max_expiration: config
+                .vote_extension
+                .map(|extension| extension.max_expiration(expiration)),
         };
         // Update the proposal's status. Addresses case where proposal
         // expires on the same block as it is created.
@@ -220,6 +371,7 @@ pub fn execute_propose(
         proposal
     };
     let id = advance_proposal_id(deps.storage)?;
+    IMPORTING.remove(deps.storage);
 
     // Limit the size of proposals.
     //
@@ -229,30 +381,55 @@ pub fn execute_propose(
     // this check it is possible to create a proposal that can not be
     // queried.
     //
-    // The size selected was determined by uploading versions of this
+    // The default size was determined by uploading versions of this
     // contract to the Juno mainnet until queries worked within a
-    // reasonable margin of error.
+    // reasonable margin of error. DAOs on chains with other limits
+    // may configure their own.
     //
     // `to_vec` is the method used by cosmwasm to convert a struct
     // into it's byte representation in storage.
     let proposal_size = cosmwasm_std::to_vec(&proposal)?.len() as u64;
-    if proposal_size > MAX_PROPOSAL_SIZE {
+    if proposal_size > config.max_proposal_size {
         return Err(ContractError::ProposalTooLarge {
             size: proposal_size,
-            max: MAX_PROPOSAL_SIZE,
+            max: config.max_proposal_size,
         });
     }
 
-    PROPOSALS.save(deps.storage, id, &proposal)?;
+    proposals().save(deps.storage, id, &proposal)?;
+    GOVERNANCE_STATS.proposal_created(deps.storage)?;
 
-    let hooks = new_proposal_hooks(PROPOSAL_HOOKS, deps.storage, id, proposer.as_str())?;
+    let hooks = new_proposal_hooks(
+        PROPOSAL_HOOKS,
+        deps.storage,
+        id,
+        proposer.as_str(),
+        ProposalContent {
+            title: proposal.title,
+            description: proposal.description,
+            expiration: proposal.expiration,
+            content_cid: proposal.content_cid.clone(),
+        },
+        |hook| PROPOSAL_HOOK_CONTENT.has(deps.storage, hook.clone()),
+    )?;
 
-    Ok(Response::default()
+    let mut event = EVENTS
+        .event("propose")
+        .proposal_id(id)
+        .address("proposer", &proposer)
+        .attribute("status", proposal.status.to_string());
+    let mut response = Response::default()
         .add_submessages(hooks)
         .add_attribute("action", "propose")
         .add_attribute("sender", sender)
         .add_attribute("proposal_id", id.to_string())
-        .add_attribute("status", proposal.status.to_string()))
+        .add_attribute("status", proposal.status.to_string());
+    if let Some(cid) = proposal.content_cid {
+        event = event.attribute("content_cid", &cid);
+        response = response.add_attribute("content_cid", cid);
+    }
+
+    Ok(response.add_event(event.into()))
 }
 
 pub fn execute_execute(
@@ -269,7 +446,7 @@ pub fn execute_execute(
         }
     }
 
-    let mut prop = PROPOSALS
+    let mut prop = proposals()
         .may_load(deps.storage, proposal_id)?
         .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
 
@@ -281,10 +458,21 @@ pub fn execute_execute(
     if prop.status != Status::Passed {
         return Err(ContractError::NotPassed {});
     }
+    if let Some(earliest) = prop.earliest_execution {
+        if !earliest.is_expired(&env.block) {
+            return Err(ContractError::ExecutionDelayed { earliest });
+        }
+    }
 
     prop.status = Status::Executed;
 
-    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+    proposals().save(deps.storage, proposal_id, &prop)?;
+    GOVERNANCE_STATS.proposal_completed(
+        deps.storage,
+        true,
+        prop.votes.total(),
+        prop.total_power,
+    )?;
 
     let response = {
         if !prop.msgs.is_empty() {
@@ -319,7 +507,9 @@ pub fn execute_execute(
     // Add prepropose / deposit module hook which will handle deposit refunds.
     let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
     let hooks = match proposal_creation_policy {
-        ProposalCreationPolicy::Anyone {} => hooks,
+        ProposalCreationPolicy::Anyone {}
+        | ProposalCreationPolicy::Dao { .. }
+        | ProposalCreationPolicy::Frozen {} => hooks,
         ProposalCreationPolicy::Module { addr } => {
             let msg = to_binary(&PreProposeHookMsg::ProposalCompletedHook {
                 proposal_id,
@@ -341,21 +531,29 @@ pub fn execute_execute(
     Ok(response
         .add_submessages(hooks)
         .add_attribute("action", "execute")
-        .add_attribute("sender", info.sender)
+        .add_attribute("sender", info.sender.clone())
         .add_attribute("proposal_id", proposal_id.to_string())
-        .add_attribute("dao", config.dao))
+        .add_attribute("dao", config.dao)
+        .add_event(
+            EVENTS
+                .event("execute")
+                .proposal_id(proposal_id)
+                .address("sender", &info.sender)
+                .attribute("status", prop.status.to_string())
+                .into(),
+        ))
 }
 
 pub fn execute_vote(
     deps: DepsMut,
     env: Env,
-    info: MessageInfo,
+    sender: Addr,
     proposal_id: u64,
     vote: Vote,
     rationale: Option<String>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    let mut prop = PROPOSALS
+    let mut prop = proposals()
         .may_load(deps.storage, proposal_id)?
         .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
 
@@ -369,18 +567,27 @@ pub fn execute_vote(
     if prop.expiration.is_expired(&env.block) {
         return Err(ContractError::Expired { id: proposal_id });
     }
+    // Cancelled proposals are withdrawn and no longer tally opinions.
+    if prop.status == Status::Cancelled {
+        return Err(ContractError::NotOpen { id: proposal_id });
+    }
 
     let vote_power = get_voting_power(
         deps.as_ref(),
-        info.sender.clone(),
-        config.dao,
+        sender.clone(),
+        config.dao.clone(),
         Some(prop.start_height),
     )?;
     if vote_power.is_zero() {
         return Err(ContractError::NotRegistered {});
     }
 
-    BALLOTS.update(deps.storage, (proposal_id, &info.sender), |bal| match bal {
+    // Whether the proposal would pass were it to expire before this
+    // vote. Used to check if the vote changes the outcome.
+    let passing = prop.passes_at_expiration();
+
+    let first_vote = !ballots().has(deps.storage, (proposal_id, &sender));
+    ballots().update(deps.storage, (proposal_id, &sender), |bal| match bal {
         Some(current_ballot) => {
             if prop.allow_revoting {
                 if current_ballot.vote == vote {
@@ -415,9 +622,18 @@ pub fn execute_vote(
     let old_status = prop.status;
 
     prop.votes.add_vote(vote, vote_power);
-    prop.update_status(&env.block);
+    prop.update_status_after_vote(&env.block);
+
+    // Give voters time to respond to late votes which change the
+    // outcome of an undecided proposal.
+    let extended = prop.status == Status::Open
+        && prop.passes_at_expiration() != passing
+        && prop.extend_expiration(&env.block);
 
-    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+    proposals().save(deps.storage, proposal_id, &prop)?;
+    if first_vote {
+        GOVERNANCE_STATS.vote_cast(deps.storage, &sender)?;
+    }
 
     let new_status = prop.status;
     let change_hooks = proposal_status_changed_hooks(
@@ -428,23 +644,94 @@ pub fn execute_vote(
         new_status.to_string(),
     )?;
 
-    let vote_hooks = new_vote_hooks(
+    let vote_hooks = VOTE_HOOK_BUFFER.new_vote(
         VOTE_HOOKS,
         deps.storage,
-        proposal_id,
-        info.sender.to_string(),
-        vote.to_string(),
+        NewVote {
+            proposal_id,
+            voter: sender.to_string(),
+            vote: vote.to_string(),
+            power: vote_power,
+            height: prop.start_height,
+            tally: VoteTally::SingleChoice(prop.votes),
+        },
     )?;
 
+    let mut event = EVENTS
+        .event("vote")
+        .proposal_id(proposal_id)
+        .address("voter", &sender)
+        .attribute("position", vote.to_string())
+        .attribute("power", vote_power.to_string())
+        .attribute("status", prop.status.to_string());
+    if extended {
+        event = event.attribute("expiration", prop.expiration.to_string());
+    }
+
     Ok(Response::default()
         .add_submessages(change_hooks)
         .add_submessages(vote_hooks)
         .add_attribute("action", "vote")
-        .add_attribute("sender", info.sender)
+        .add_attribute("sender", sender.clone())
         .add_attribute("proposal_id", proposal_id.to_string())
         .add_attribute("position", vote.to_string())
         .add_attribute("rationale", rationale.as_deref().unwrap_or("_none"))
-        .add_attribute("status", prop.status.to_string()))
+        .add_attribute("status", prop.status.to_string())
+        .add_event(event.into()))
+}
+
+/// Records votes signed off-chain and submitted by a relayer. The
+/// batch fails if any vote is invalid.
+pub fn execute_submit_signed_votes(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    votes: Vec<SignedVote>,
+) -> Result<Response, ContractError> {
+    let mut response = Response::default()
+        .add_attribute("action", "submit_signed_votes")
+        .add_attribute("relayer", info.sender)
+        .add_attribute("count", votes.len().to_string());
+    for signed in votes {
+        let voter = verify_signed_vote(deps.api, &env.contract.address, &signed)?;
+        // Signed votes may only create ballots. Were they allowed to
+        // change them, an old signature could be replayed to undo a
+        // later revote.
+        if ballots().has(deps.storage, (signed.proposal_id, &voter)) {
+            return Err(ContractError::AlreadyVoted {});
+        }
+        let vote = execute_vote(
+            deps.branch(),
+            env.clone(),
+            voter.clone(),
+            signed.proposal_id,
+            signed.vote,
+            None,
+        )?;
+        response = response
+            .add_submessages(vote.messages)
+            .add_events(vote.events)
+            .add_attribute("voter", voter);
+    }
+    Ok(response)
+}
+
+pub fn execute_vote_for(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    voter: String,
+    proposal_id: u64,
+    vote: Vote,
+    rationale: Option<String>,
+) -> Result<Response, ContractError> {
+    match CREATION_POLICY.load(deps.storage)? {
+        ProposalCreationPolicy::Module { addr } if addr == info.sender => (),
+        _ => return Err(ContractError::Unauthorized {}),
+    }
+    let voter = deps.api.addr_validate(&voter)?;
+    let response = execute_vote(deps, env, voter, proposal_id, vote, rationale)?;
+    Ok(response.add_attribute("relayer", info.sender))
 }
 
 pub fn execute_update_rationale(
@@ -453,7 +740,7 @@ pub fn execute_update_rationale(
     proposal_id: u64,
     rationale: Option<String>,
 ) -> Result<Response, ContractError> {
-    BALLOTS.update(
+    ballots().update(
         deps.storage,
         // info.sender can't be forged so we implicitly access control
         // with the key.
@@ -477,13 +764,95 @@ pub fn execute_update_rationale(
         .add_attribute("rationale", rationale.as_deref().unwrap_or("_none")))
 }
 
+pub fn execute_update_proposal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    title: String,
+    description: String,
+    msgs: Vec<CosmosMsg<Empty>>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut prop = proposals()
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+
+    // When a pre-propose module is in place it may gate proposals
+    // (for example, behind an approver), so only it may revise
+    // them. Otherwise a proposer could rewrite an approved proposal.
+    let updater = match CREATION_POLICY.load(deps.storage)? {
+        ProposalCreationPolicy::Module { addr } => addr,
+        ProposalCreationPolicy::Anyone {}
+        | ProposalCreationPolicy::Dao { .. }
+        | ProposalCreationPolicy::Frozen {} => prop.proposer.clone(),
+    };
+    if info.sender != updater {
+        return Err(ContractError::Unauthorized {});
+    }
+    if prop.current_status(&env.block) != Status::Open {
+        return Err(ContractError::NotOpen { id: proposal_id });
+    }
+    // Votes must have power, so a proposal has been voted on iff
+    // its tally is non-zero.
+    if !prop.votes.total().is_zero() {
+        return Err(ContractError::ProposalHasVotes { id: proposal_id });
+    }
+    // A description hash pins the off-chain description, so a
+    // proposal created with one keeps it and must be revised with an
+    // empty description.
+    validate_description(&description, prop.description_hash.as_ref())?;
+
+    prop.title = title;
+    prop.description = description;
+    prop.msgs = msgs;
+
+    // Revisions are held to the same size limit as new proposals so
+    // that they remain queryable.
+    let proposal_size = cosmwasm_std::to_vec(&prop)?.len() as u64;
+    if proposal_size > config.max_proposal_size {
+        return Err(ContractError::ProposalTooLarge {
+            size: proposal_size,
+            max: config.max_proposal_size,
+        });
+    }
+
+    proposals().save(deps.storage, proposal_id, &prop)?;
+
+    let hooks = proposal_updated_hooks(
+        PROPOSAL_HOOKS,
+        deps.storage,
+        proposal_id,
+        ProposalContent {
+            title: prop.title,
+            description: prop.description,
+            expiration: prop.expiration,
+            content_cid: prop.content_cid,
+        },
+        |hook| PROPOSAL_HOOK_CONTENT.has(deps.storage, hook.clone()),
+    )?;
+
+    Ok(Response::default()
+        .add_submessages(hooks)
+        .add_attribute("action", "update_proposal")
+        .add_attribute("sender", info.sender.clone())
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_event(
+            EVENTS
+                .event("update_proposal")
+                .proposal_id(proposal_id)
+                .address("proposer", &info.sender)
+                .into(),
+        ))
+}
+
 pub fn execute_close(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     proposal_id: u64,
 ) -> Result<Response, ContractError> {
-    let mut prop = PROPOSALS.load(deps.storage, proposal_id)?;
+    let mut prop = proposals().load(deps.storage, proposal_id)?;
 
     // Update status to ensure that proposals which were open and have
     // expired are moved to "rejected."
@@ -495,7 +864,13 @@ pub fn execute_close(
     let old_status = prop.status;
 
     prop.status = Status::Closed;
-    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+    proposals().save(deps.storage, proposal_id, &prop)?;
+    GOVERNANCE_STATS.proposal_completed(
+        deps.storage,
+        false,
+        prop.votes.total(),
+        prop.total_power,
+    )?;
 
     let hooks = proposal_status_changed_hooks(
         PROPOSAL_HOOKS,
@@ -508,7 +883,9 @@ pub fn execute_close(
     // Add prepropose / deposit module hook which will handle deposit refunds.
     let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
     let hooks = match proposal_creation_policy {
-        ProposalCreationPolicy::Anyone {} => hooks,
+        ProposalCreationPolicy::Anyone {}
+        | ProposalCreationPolicy::Dao { .. }
+        | ProposalCreationPolicy::Frozen {} => hooks,
         ProposalCreationPolicy::Module { addr } => {
             let msg = to_binary(&PreProposeHookMsg::ProposalCompletedHook {
                 proposal_id,
@@ -530,8 +907,92 @@ pub fn execute_close(
     Ok(Response::default()
         .add_submessages(hooks)
         .add_attribute("action", "close")
-        .add_attribute("sender", info.sender)
-        .add_attribute("proposal_id", proposal_id.to_string()))
+        .add_attribute("sender", info.sender.clone())
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_event(
+            EVENTS
+                .event("close")
+                .proposal_id(proposal_id)
+                .address("sender", &info.sender)
+                .into(),
+        ))
+}
+
+pub fn execute_cancel(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    let mut prop = proposals()
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+
+    if info.sender != prop.proposer {
+        return Err(ContractError::Unauthorized {});
+    }
+    // Update status to ensure that proposals which have since passed
+    // or been rejected may not be cancelled.
+    prop.update_status(&env.block);
+    if prop.status != Status::Open {
+        return Err(ContractError::NotOpen { id: proposal_id });
+    }
+
+    let old_status = prop.status;
+
+    prop.status = Status::Cancelled;
+    proposals().save(deps.storage, proposal_id, &prop)?;
+    GOVERNANCE_STATS.proposal_completed(
+        deps.storage,
+        false,
+        prop.votes.total(),
+        prop.total_power,
+    )?;
+
+    let hooks = proposal_status_changed_hooks(
+        PROPOSAL_HOOKS,
+        deps.storage,
+        proposal_id,
+        old_status.to_string(),
+        prop.status.to_string(),
+    )?;
+
+    // Add prepropose / deposit module hook which will handle deposit refunds.
+    let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
+    let hooks = match proposal_creation_policy {
+        ProposalCreationPolicy::Anyone {}
+        | ProposalCreationPolicy::Dao { .. }
+        | ProposalCreationPolicy::Frozen {} => hooks,
+        ProposalCreationPolicy::Module { addr } => {
+            let msg = to_binary(&PreProposeHookMsg::ProposalCompletedHook {
+                proposal_id,
+                new_status: prop.status,
+            })?;
+            let mut hooks = hooks;
+            hooks.push(SubMsg::reply_on_error(
+                WasmMsg::Execute {
+                    contract_addr: addr.into_string(),
+                    msg,
+                    funds: vec![],
+                },
+                failed_pre_propose_module_hook_id(),
+            ));
+            hooks
+        }
+    };
+
+    Ok(Response::default()
+        .add_submessages(hooks)
+        .add_attribute("action", "cancel")
+        .add_attribute("sender", info.sender.clone())
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_event(
+            EVENTS
+                .event("cancel")
+                .proposal_id(proposal_id)
+                .address("proposer", &info.sender)
+                .into(),
+        ))
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -545,6 +1006,13 @@ pub fn execute_update_config(
     allow_revoting: bool,
     dao: String,
     close_proposal_on_execution_failure: bool,
+    max_proposal_size: Option<u64>,
+    max_open_proposals: Option<MaxOpenProposals>,
+    pre_propose_fallback: Option<PreProposeFallback>,
+    require_content_cid: Option<bool>,
+    abstain_counting: Option<AbstainCounting>,
+    execution_delay: Option<Duration>,
+    vote_extension: Option<VoteExtension>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
@@ -553,10 +1021,19 @@ pub fn execute_update_config(
         return Err(ContractError::Unauthorized {});
     }
     threshold.validate()?;
+    let threshold_summary = threshold.describe().summary;
     let dao = deps.api.addr_validate(&dao)?;
 
     let (min_voting_period, max_voting_period) =
         validate_voting_period(min_voting_period, max_voting_period)?;
+    let execution_delay = validate_execution_delay(execution_delay, max_voting_period)?;
+    if let Some(vote_extension) = &vote_extension {
+        vote_extension.validate(max_voting_period)?;
+    }
+    let max_proposal_size = validate_max_proposal_size(max_proposal_size)?;
+    if let Some(max_open_proposals) = &max_open_proposals {
+        max_open_proposals.validate()?;
+    }
 
     CONFIG.save(
         deps.storage,
@@ -568,12 +1045,26 @@ pub fn execute_update_config(
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
+            max_proposal_size,
+            max_open_proposals,
+            pre_propose_fallback: pre_propose_fallback.unwrap_or(config.pre_propose_fallback),
+            require_content_cid: require_content_cid.unwrap_or(config.require_content_cid),
+            abstain_counting: abstain_counting.unwrap_or(config.abstain_counting),
+            execution_delay,
+            vote_extension,
         },
     )?;
 
     Ok(Response::default()
         .add_attribute("action", "update_config")
-        .add_attribute("sender", info.sender))
+        .add_attribute("sender", info.sender)
+        .add_attribute("threshold", threshold_summary.clone())
+        .add_event(
+            EVENTS
+                .event("update_config")
+                .attribute("threshold", threshold_summary)
+                .into(),
+        ))
 }
 
 pub fn execute_update_proposal_creation_policy(
@@ -618,11 +1109,59 @@ pub fn remove_hook(
     Ok(())
 }
 
+pub fn execute_pause(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    duration: Duration,
+    reason: Option<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.dao {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let until = PAUSED.pause(
+        deps.storage,
+        &env.block,
+        duration,
+        info.sender.clone(),
+        reason.clone(),
+    )?;
+
+    let mut event = EVENTS
+        .event("pause")
+        .attribute("until", until.to_string())
+        .address("paused_by", &info.sender);
+    if let Some(reason) = reason {
+        event = event.attribute("reason", reason);
+    }
+
+    Ok(Response::default()
+        .add_attribute("action", "pause")
+        .add_attribute("until", until.to_string())
+        .add_event(event.into()))
+}
+
+pub fn execute_unpause(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.dao {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    PAUSED.unpause(deps.storage);
+
+    Ok(Response::default()
+        .add_attribute("action", "unpause")
+        .add_event(EVENTS.event("unpause").into()))
+}
+
 pub fn execute_add_proposal_hook(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
     address: String,
+    include_content: bool,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
     if config.dao != info.sender {
@@ -632,7 +1171,10 @@ pub fn execute_add_proposal_hook(
 
     let validated_address = deps.api.addr_validate(&address)?;
 
-    add_hook(PROPOSAL_HOOKS, deps.storage, validated_address)?;
+    add_hook(PROPOSAL_HOOKS, deps.storage, validated_address.clone())?;
+    if include_content {
+        PROPOSAL_HOOK_CONTENT.save(deps.storage, validated_address, &Empty {})?;
+    }
 
     Ok(Response::default()
         .add_attribute("action", "add_proposal_hook")
@@ -653,7 +1195,8 @@ pub fn execute_remove_proposal_hook(
 
     let validated_address = deps.api.addr_validate(&address)?;
 
-    remove_hook(PROPOSAL_HOOKS, deps.storage, validated_address)?;
+    remove_hook(PROPOSAL_HOOKS, deps.storage, validated_address.clone())?;
+    PROPOSAL_HOOK_CONTENT.remove(deps.storage, validated_address);
 
     Ok(Response::default()
         .add_attribute("action", "remove_proposal_hook")
@@ -702,31 +1245,474 @@ pub fn execute_remove_vote_hook(
         .add_attribute("address", address))
 }
 
+pub fn execute_prune(
+    deps: DepsMut,
+    info: MessageInfo,
+    older_than: u64,
+    statuses: Vec<Status>,
+    limit: Option<u64>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.dao {
+        return Err(ContractError::Unauthorized {});
+    }
+    validate_prune_statuses(&statuses)?;
+
+    let limit = clamp_limit(limit, DEFAULT_LIMIT, MAX_LIMIT) as usize;
+    let mut pruned = vec![];
+    for status in statuses {
+        // Proposal IDs are assigned in the order proposals are
+        // created, so once a proposal that is too new is found all
+        // following proposals are as well.
+        let matching = proposals()
+            .idx
+            .status
+            .prefix(status as u8)
+            .range(deps.storage, None, None, Order::Ascending)
+            .take_while(|item| {
+                item.as_ref()
+                    .map_or(true, |(_, proposal)| proposal.start_height < older_than)
+            })
+            .take(limit - pruned.len())
+            .collect::<StdResult<Vec<_>>>()?;
+        for (id, proposal) in matching {
+            prune_proposal(deps.storage, id, proposal)?;
+            pruned.push(id.to_string());
+        }
+    }
+
+    Ok(Response::default()
+        .add_attribute("action", "prune")
+        .add_attribute("pruned", pruned.len().to_string())
+        .add_event(
+            EVENTS
+                .event("prune")
+                .address("sender", &info.sender)
+                .attribute("proposal_ids", pruned.join(","))
+                .into(),
+        ))
+}
+
+/// Iterates over the proposals which expire after BLOCK. Every open
+/// proposal is among them.
+fn unexpired_proposals<'a>(
+    storage: &'a dyn Storage,
+    block: &BlockInfo,
+) -> StdResult<impl Iterator<Item = StdResult<(u64, SingleChoiceProposal)>> + 'a> {
+    let index = proposals().idx.expiration;
+    let (heights, after_height, _) =
+        expiration_index_bounds(Some(Expiration::AtHeight(block.height)), None)?;
+    let (times, after_time, _) =
+        expiration_index_bounds(Some(Expiration::AtTime(block.time)), None)?;
+    let (never, _) = expiration_index_key(&Expiration::Never {});
+    Ok(index
+        .sub_prefix(heights)
+        .range(storage, after_height, None, Order::Ascending)
+        .chain(
+            index
+                .sub_prefix(times)
+                .range(storage, after_time, None, Order::Ascending),
+        )
+        .chain(
+            index
+                .sub_prefix(never)
+                .range(storage, None, None, Order::Ascending),
+        ))
+}
+
+pub fn execute_rebuild_indexes(
+    deps: DepsMut,
+    info: MessageInfo,
+    start_after: Option<RebuildIndexesCursor>,
+    limit: Option<u64>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.dao {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let limit = clamp_limit(limit, DEFAULT_LIMIT, MAX_LIMIT) as usize;
+    let (mut saved, mut next, ballots_after) = match start_after {
+        Some(RebuildIndexesCursor::Ballot { proposal_id, voter }) => (
+            0,
+            None,
+            Some((proposal_id, deps.api.addr_validate(&voter)?)),
+        ),
+        Some(RebuildIndexesCursor::Proposal { id }) => {
+            let (saved, next) = rebuild_proposals(deps.storage, Some(id), limit)?;
+            (saved, next, None)
+        }
+        None => {
+            let (saved, next) = rebuild_proposals(deps.storage, None, limit)?;
+            (saved, next, None)
+        }
+    };
+    // Ballots are rebuilt once every proposal has been.
+    if saved < limit {
+        let page = ballots()
+            .range(
+                deps.storage,
+                ballots_after
+                    .as_ref()
+                    .map(|(id, voter)| Bound::exclusive((*id, voter))),
+                None,
+                Order::Ascending,
+            )
+            .take(limit - saved)
+            .collect::<StdResult<Vec<_>>>()?;
+        let done = page.len() < limit - saved;
+        saved += page.len();
+        next = None;
+        for ((proposal_id, voter), ballot) in page {
+            ballots().save(deps.storage, (proposal_id, &voter), &ballot)?;
+            if !done {
+                next = Some(RebuildIndexesCursor::Ballot {
+                    proposal_id,
+                    voter: voter.into_string(),
+                });
+            }
+        }
+    }
+
+    Ok(Response::default()
+        .set_data(to_binary(&next)?)
+        .add_attribute("action", "rebuild_indexes")
+        .add_attribute("saved", saved.to_string())
+        .add_attribute("complete", next.is_none().to_string()))
+}
+
+/// Saves up to LIMIT proposals after START_AFTER again, returning how
+/// many were saved and where to continue from.
+fn rebuild_proposals(
+    storage: &mut dyn Storage,
+    start_after: Option<u64>,
+    limit: usize,
+) -> StdResult<(usize, Option<RebuildIndexesCursor>)> {
+    let page = proposals()
+        .range(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+    let next = page
+        .last()
+        .map(|(id, _)| RebuildIndexesCursor::Proposal { id: *id });
+    for (id, proposal) in &page {
+        proposals().save(storage, *id, proposal)?;
+    }
+    Ok((page.len(), next))
+}
+
+pub fn execute_update_vote_hook_dispatch(
+    deps: DepsMut,
+    info: MessageInfo,
+    dispatch: VoteHookDispatch,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.dao {
+        return Err(ContractError::Unauthorized {});
+    }
+    VOTE_HOOK_BUFFER.set_dispatch(deps.storage, &dispatch)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "update_vote_hook_dispatch")
+        .add_attribute("sender", info.sender))
+}
+
+pub fn execute_flush_vote_hooks(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let buffered = VOTE_HOOK_BUFFER.len(deps.storage)?;
+    let vote_hooks = VOTE_HOOK_BUFFER.flush(VOTE_HOOKS, deps.storage)?;
+
+    Ok(Response::default()
+        .add_submessages(vote_hooks)
+        .add_attribute("action", "flush_vote_hooks")
+        .add_attribute("sender", info.sender)
+        .add_attribute("votes", buffered.to_string()))
+}
+
+pub fn execute_import_module_state(
+    deps: DepsMut,
+    info: MessageInfo,
+    module: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.dao {
+        return Err(ContractError::Unauthorized {});
+    }
+    // Proposal IDs continue from those of the replaced module, so
+    // this module must not have assigned any yet.
+    if PROPOSAL_COUNT.may_load(deps.storage)?.unwrap_or_default() != 0 {
+        return Err(ContractError::ProposalsExist {});
+    }
+    let module = deps.api.addr_validate(&module)?;
+
+    let next_id: u64 = deps
+        .querier
+        .query_wasm_smart(&module, &QueryMsg::NextProposalId {})?;
+    PROPOSAL_COUNT.save(deps.storage, &next_id.saturating_sub(1))?;
+
+    let policy: ProposalCreationPolicy = deps
+        .querier
+        .query_wasm_smart(&module, &QueryMsg::ProposalCreationPolicy {})?;
+    CREATION_POLICY.save(deps.storage, &policy)?;
+
+    let proposal_hooks: HooksResponse = deps
+        .querier
+        .query_wasm_smart(&module, &QueryMsg::ProposalHooks {})?;
+    for hook in proposal_hooks.hooks {
+        import_hook(PROPOSAL_HOOKS, deps.storage, deps.api.addr_validate(&hook)?)?;
+    }
+    let vote_hooks: HooksResponse = deps
+        .querier
+        .query_wasm_smart(&module, &QueryMsg::VoteHooks {})?;
+    for hook in vote_hooks.hooks {
+        import_hook(VOTE_HOOKS, deps.storage, deps.api.addr_validate(&hook)?)?;
+    }
+
+    Ok(Response::default()
+        .add_attribute("action", "import_module_state")
+        .add_attribute("next_proposal_id", next_id.to_string())
+        .add_event(
+            EVENTS
+                .event("import_module_state")
+                .address("module", &module)
+                .attribute("next_proposal_id", next_id.to_string())
+                .into(),
+        ))
+}
+
+pub fn execute_import_proposals(
+    deps: DepsMut,
+    info: MessageInfo,
+    imported: Vec<ProposalResponse>,
+) -> Result<Response, ContractError> {
+    check_importing(deps.as_ref(), &info.sender)?;
+
+    let mut count = PROPOSAL_COUNT.load(deps.storage)?;
+    let imported_count = imported.len();
+    for ProposalResponse { id, mut proposal } in imported {
+        if proposals().has(deps.storage, id) || PRUNED_PROPOSALS.has(deps.storage, id) {
+            return Err(ContractError::ProposalExists { id });
+        }
+        proposal.proposer = deps.api.addr_validate(proposal.proposer.as_str())?;
+        proposals().save(deps.storage, id, &proposal)?;
+        record_imported_proposal(deps.storage, &proposal)?;
+        count = count.max(id);
+    }
+    PROPOSAL_COUNT.save(deps.storage, &count)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "import_proposals")
+        .add_attribute("imported", imported_count.to_string())
+        .add_attribute("proposal_count", count.to_string()))
+}
+
+pub fn execute_import_votes(
+    deps: DepsMut,
+    info: MessageInfo,
+    proposal_id: u64,
+    votes: Vec<VoteInfo>,
+) -> Result<Response, ContractError> {
+    check_importing(deps.as_ref(), &info.sender)?;
+    let proposal = proposals()
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+    let mut tally = IMPORTED_VOTES
+        .may_load(deps.storage, proposal_id)?
+        .unwrap_or_else(Votes::zero);
+
+    let imported_count = votes.len();
+    for VoteInfo {
+        voter,
+        vote,
+        power,
+        rationale,
+    } in votes
+    {
+        let voter = deps.api.addr_validate(voter.as_str())?;
+        if ballots()
+            .may_load(deps.storage, (proposal_id, &voter))?
+            .is_some()
+        {
+            return Err(ContractError::AlreadyVoted {});
+        }
+        tally.add_vote(vote, power);
+        ballots().save(
+            deps.storage,
+            (proposal_id, &voter),
+            &Ballot {
+                power,
+                vote,
+                rationale,
+            },
+        )?;
+        GOVERNANCE_STATS.vote_cast(deps.storage, &voter)?;
+    }
+    // Ballots may be imported over several messages, so the ballots
+    // imported so far may only fall short of the proposal's tally.
+    // The complete import may be checked with the `VerifyTally`
+    // query.
+    if tally.yes > proposal.votes.yes
+        || tally.no > proposal.votes.no
+        || tally.abstain > proposal.votes.abstain
+    {
+        return Err(ContractError::ImportedVotesExceedTally { id: proposal_id });
+    }
+    IMPORTED_VOTES.save(deps.storage, proposal_id, &tally)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "import_votes")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("imported", imported_count.to_string()))
+}
+
+/// Checks that SENDER is the DAO and that this module has not yet
+/// created a proposal.
+fn check_importing(deps: Deps, sender: &Addr) -> Result<(), ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if *sender != config.dao {
+        return Err(ContractError::Unauthorized {});
+    }
+    if !IMPORTING.may_load(deps.storage)?.unwrap_or_default() {
+        return Err(ContractError::ImportClosed {});
+    }
+    Ok(())
+}
+
+/// Counts an imported proposal in the governance statistics as if it
+/// had been created and, if completed, completed by this module.
+fn record_imported_proposal(
+    storage: &mut dyn Storage,
+    proposal: &SingleChoiceProposal,
+) -> StdResult<()> {
+    GOVERNANCE_STATS.proposal_created(storage)?;
+    let executed = match proposal.status {
+        Status::Executed | Status::ExecutionFailed => true,
+        Status::Closed | Status::Cancelled => false,
+        Status::Open | Status::Rejected | Status::Passed => return Ok(()),
+    };
+    GOVERNANCE_STATS.proposal_completed(
+        storage,
+        executed,
+        proposal.votes.total(),
+        proposal.total_power,
+    )?;
+    if proposal.status == Status::ExecutionFailed {
+        GOVERNANCE_STATS.execution_failed(storage)?;
+    }
+    Ok(())
+}
+
+/// Registers HOOK with HOOKS unless it is already registered.
+fn import_hook(hooks: Hooks, storage: &mut dyn Storage, hook: Addr) -> Result<(), ContractError> {
+    match hooks.add_hook(storage, hook) {
+        Ok(()) | Err(HookError::HookAlreadyRegistered {}) => Ok(()),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Deletes a proposal and its ballots, keeping a summary of it in
+/// `PRUNED_PROPOSALS`.
+fn prune_proposal(
+    storage: &mut dyn Storage,
+    id: u64,
+    proposal: SingleChoiceProposal,
+) -> StdResult<()> {
+    let voters = ballots()
+        .prefix(id)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<Addr>>>()?;
+    for voter in voters {
+        ballots().remove(storage, (id, &voter))?;
+    }
+    proposals().remove(storage, id)?;
+    PRUNED_PROPOSALS.save(
+        storage,
+        id,
+        &PrunedProposal {
+            title: proposal.title,
+            proposer: proposal.proposer,
+            start_height: proposal.start_height,
+            status: proposal.status,
+        },
+    )
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => query_config(deps),
         QueryMsg::Dao {} => query_dao(deps),
+        QueryMsg::PauseInfo {} => to_binary(&PAUSED.query_pause_info(deps.storage, &env.block)?),
         QueryMsg::Proposal { proposal_id } => query_proposal(deps, env, proposal_id),
-        QueryMsg::ListProposals { start_after, limit } => {
-            query_list_proposals(deps, env, start_after, limit)
-        }
+        QueryMsg::ListProposals {
+            start_after,
+            limit,
+            status,
+        } => query_list_proposals(deps, env, start_after, limit, status),
         QueryMsg::NextProposalId {} => query_next_proposal_id(deps),
+        QueryMsg::GenericProposalInfo { proposal_id } => {
+            query_generic_proposal_info(deps, env, proposal_id)
+        }
+        QueryMsg::ListVotesByVoter {
+            voter,
+            start_after,
+            limit,
+        } => query_list_votes_by_voter(deps, voter, start_after, limit),
+        QueryMsg::PendingVotes {
+            voter,
+            start_after,
+            limit,
+        } => query_pending_votes(deps, env, voter, start_after, limit),
         QueryMsg::ProposalCount {} => query_proposal_count(deps),
         QueryMsg::GetVote { proposal_id, voter } => query_vote(deps, proposal_id, voter),
         QueryMsg::ListVotes {
             proposal_id,
             start_after,
             limit,
-        } => query_list_votes(deps, proposal_id, start_after, limit),
+            order,
+        } => query_list_votes(deps, proposal_id, start_after, limit, order),
         QueryMsg::Info {} => query_info(deps),
         QueryMsg::ReverseProposals {
             start_before,
             limit,
-        } => query_reverse_proposals(deps, env, start_before, limit),
+            status,
+        } => query_reverse_proposals(deps, env, start_before, limit, status),
+        QueryMsg::ListProposalsByExpiration {
+            after,
+            before,
+            limit,
+        } => query_list_proposals_by_expiration(deps, env, after, before, limit),
         QueryMsg::ProposalCreationPolicy {} => query_creation_policy(deps),
+        QueryMsg::PreProposeFailure {} => to_binary(&PRE_PROPOSE_FAILURE.may_load(deps.storage)?),
         QueryMsg::ProposalHooks {} => to_binary(&PROPOSAL_HOOKS.query_hooks(deps)?),
         QueryMsg::VoteHooks {} => to_binary(&VOTE_HOOKS.query_hooks(deps)?),
+        QueryMsg::ProposalHookFailures {} => to_binary(&PROPOSAL_HOOKS.query_failures(deps)?),
+        QueryMsg::VoteHookFailures {} => to_binary(&VOTE_HOOKS.query_failures(deps)?),
+        QueryMsg::HookFailures {} => to_binary(
+            &HOOK_FAILURE_HISTORY
+                .may_load(deps.storage)?
+                .unwrap_or_default(),
+        ),
+        QueryMsg::GovernanceStats {} => to_binary(&GOVERNANCE_STATS.query(deps.storage)?),
+        QueryMsg::SimulateExecution { proposal_id } => {
+            to_binary(&query_simulate_execution(deps, env, proposal_id)?)
+        }
+        QueryMsg::VoteHookDispatch {} => to_binary(&VOTE_HOOK_BUFFER.query(deps.storage)?),
+        QueryMsg::VerifyTally { proposal_id } => query_verify_tally(deps, proposal_id),
+        QueryMsg::TallySummary { proposal_id } => query_tally_summary(deps, env, proposal_id),
+        QueryMsg::PrunedProposal { proposal_id } => {
+            to_binary(&PRUNED_PROPOSALS.may_load(deps.storage, proposal_id)?)
+        }
+        QueryMsg::ExportState { start_after, limit } => {
+            query_export_state(deps, start_after, limit)
+        }
     }
 }
 
@@ -741,30 +1727,76 @@ pub fn query_dao(deps: Deps) -> StdResult<Binary> {
 }
 
 pub fn query_proposal(deps: Deps, env: Env, id: u64) -> StdResult<Binary> {
-    let proposal = PROPOSALS.load(deps.storage, id)?;
+    let proposal = proposals().load(deps.storage, id)?;
     to_binary(&proposal.into_response(&env.block, id))
 }
 
+pub fn query_simulate_execution(deps: Deps, env: Env, id: u64) -> StdResult<SimulationResponse> {
+    let proposal = proposals().load(deps.storage, id)?;
+    let dao = CONFIG.load(deps.storage)?.dao;
+    simulate_execution(deps, &dao, &proposal.msgs, |contract, msg| {
+        if contract == &dao {
+            from_binary::<dao_core::msg::ExecuteMsg>(msg).err()
+        } else if contract == &env.contract.address {
+            from_binary::<ExecuteMsg>(msg).err()
+        } else {
+            None
+        }
+    })
+}
+
+pub fn query_generic_proposal_info(deps: Deps, env: Env, id: u64) -> StdResult<Binary> {
+    let proposal = proposals().load(deps.storage, id)?;
+    to_binary(&GenericProposalInfoResponse {
+        status: proposal.current_status(&env.block).to_string(),
+        proposer: proposal.proposer,
+        expiration: proposal.expiration,
+        created: proposal.start_height,
+    })
+}
+
 pub fn query_creation_policy(deps: Deps) -> StdResult<Binary> {
     let policy = CREATION_POLICY.load(deps.storage)?;
     to_binary(&policy)
 }
 
+pub fn query_export_state(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let limit = clamp_limit(limit, DEFAULT_LIMIT, MAX_LIMIT);
+    let (min, max) = keyset_bounds(start_after, Order::Ascending);
+    let proposals = proposals()
+        .range(deps.storage, min, max, Order::Ascending)
+        .take(limit as usize)
+        .map(|item| item.map(|(id, proposal)| ProposalResponse { id, proposal }))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    to_binary(&ExportStateResponse {
+        config: CONFIG.load(deps.storage)?,
+        creation_policy: CREATION_POLICY.load(deps.storage)?,
+        proposal_count: PROPOSAL_COUNT.load(deps.storage)?,
+        proposals,
+    })
+}
+
 pub fn query_list_proposals(
     deps: Deps,
     env: Env,
     start_after: Option<u64>,
     limit: Option<u64>,
+    status: Option<Status>,
 ) -> StdResult<Binary> {
-    let min = start_after.map(Bound::exclusive);
-    let limit = limit.unwrap_or(DEFAULT_LIMIT);
-    let props: Vec<ProposalResponse> = PROPOSALS
-        .range(deps.storage, min, None, cosmwasm_std::Order::Ascending)
-        .take(limit as usize)
-        .collect::<Result<Vec<(u64, SingleChoiceProposal)>, _>>()?
-        .into_iter()
-        .map(|(id, proposal)| proposal.into_response(&env.block, id))
-        .collect();
+    let limit = clamp_limit(limit, DEFAULT_LIMIT, MAX_LIMIT);
+    let props = list_proposals(
+        deps,
+        &env.block,
+        start_after,
+        limit,
+        status,
+        Order::Ascending,
+    )?;
 
     to_binary(&ProposalListResponse { proposals: props })
 }
@@ -774,32 +1806,113 @@ pub fn query_reverse_proposals(
     env: Env,
     start_before: Option<u64>,
     limit: Option<u64>,
+    status: Option<Status>,
 ) -> StdResult<Binary> {
-    let limit = limit.unwrap_or(DEFAULT_LIMIT);
-    let max = start_before.map(Bound::exclusive);
-    let props: Vec<ProposalResponse> = PROPOSALS
-        .range(deps.storage, None, max, cosmwasm_std::Order::Descending)
-        .take(limit as usize)
-        .collect::<Result<Vec<(u64, SingleChoiceProposal)>, _>>()?
-        .into_iter()
-        .map(|(id, proposal)| proposal.into_response(&env.block, id))
-        .collect();
+    let limit = clamp_limit(limit, DEFAULT_LIMIT, MAX_LIMIT);
+    let props = list_proposals(
+        deps,
+        &env.block,
+        start_before,
+        limit,
+        status,
+        Order::Descending,
+    )?;
 
     to_binary(&ProposalListResponse { proposals: props })
 }
 
+/// Loads up to LIMIT proposals after START_AFTER in ORDER. If STATUS
+/// is set, only proposals whose current status is STATUS are loaded.
+fn list_proposals(
+    deps: Deps,
+    block: &BlockInfo,
+    start_after: Option<u64>,
+    limit: u64,
+    status: Option<Status>,
+    order: Order,
+) -> StdResult<Vec<ProposalResponse>> {
+    let limit = limit as usize;
+    let status = match status {
+        Some(status) => status,
+        None => {
+            let (min, max) = keyset_bounds(start_after, order);
+            return proposals()
+                .range(deps.storage, min, max, order)
+                .take(limit)
+                .map(|item| item.map(|(id, proposal)| proposal.into_response(block, id)))
+                .collect();
+        }
+    };
+
+    // Proposals are indexed by the status they were last saved
+    // with. Open proposals may have since passed or been rejected, so
+    // they are checked as well when looking for those statuses.
+    let mut stored = vec![status];
+    if matches!(status, Status::Passed | Status::Rejected) {
+        stored.push(Status::Open);
+    }
+
+    let mut props = vec![];
+    for stored_status in stored {
+        let (min, max) = keyset_bounds(start_after, order);
+        let matching = proposals()
+            .idx
+            .status
+            .prefix(stored_status as u8)
+            .range(deps.storage, min, max, order)
+            .map(|item| item.map(|(id, proposal)| proposal.into_response(block, id)))
+            .filter(|item| {
+                item.as_ref()
+                    .map_or(true, |response| response.proposal.status == status)
+            })
+            .take(limit)
+            .collect::<StdResult<Vec<_>>>()?;
+        props.extend(matching);
+    }
+
+    // Each list is in order, so the first LIMIT of the merged lists
+    // are the first LIMIT matching proposals.
+    props.sort_by_key(|response| response.id);
+    if matches!(order, Order::Descending) {
+        props.reverse();
+    }
+    props.truncate(limit);
+    Ok(props)
+}
+
 pub fn query_proposal_count(deps: Deps) -> StdResult<Binary> {
     let proposal_count = PROPOSAL_COUNT.load(deps.storage)?;
     to_binary(&proposal_count)
 }
 
+pub fn query_list_proposals_by_expiration(
+    deps: Deps,
+    env: Env,
+    after: Option<Expiration>,
+    before: Option<Expiration>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let limit = clamp_limit(limit, DEFAULT_LIMIT, MAX_LIMIT);
+    let (prefix, min, max) = expiration_index_bounds(after, before)?;
+    let props = proposals()
+        .idx
+        .expiration
+        .sub_prefix(prefix)
+        .range(deps.storage, min, max, Order::Ascending)
+        .take(limit as usize)
+        .map(|item| item.map(|(id, proposal)| proposal.into_response(&env.block, id)))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    to_binary(&ProposalListResponse { proposals: props })
+}
+
 pub fn query_next_proposal_id(deps: Deps) -> StdResult<Binary> {
     to_binary(&next_proposal_id(deps.storage)?)
 }
 
 pub fn query_vote(deps: Deps, proposal_id: u64, voter: String) -> StdResult<Binary> {
     let voter = deps.api.addr_validate(&voter)?;
-    let ballot = BALLOTS.may_load(deps.storage, (proposal_id, &voter))?;
+    let ballot = ballots().may_load(deps.storage, (proposal_id, &voter))?;
     let vote = ballot.map(|ballot| VoteInfo {
         voter,
         vote: ballot.vote,
@@ -814,21 +1927,75 @@ pub fn query_list_votes(
     proposal_id: u64,
     start_after: Option<String>,
     limit: Option<u64>,
+    order: Option<VoteListOrder>,
 ) -> StdResult<Binary> {
-    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let limit = clamp_limit(limit, DEFAULT_LIMIT, MAX_LIMIT);
+    let order = order.unwrap_or(VoteListOrder::VoterAscending);
     let start_after = start_after
         .map(|addr| deps.api.addr_validate(&addr))
         .transpose()?;
-    let min = start_after.as_ref().map(Bound::<&Addr>::exclusive);
 
-    let votes = BALLOTS
-        .prefix(proposal_id)
-        .range(deps.storage, min, None, cosmwasm_std::Order::Ascending)
+    let listed = if order.by_power() {
+        // Power is part of the index key, so paginating by voter
+        // requires knowing the power of their ballot.
+        let start_after = start_after
+            .as_ref()
+            .map(|voter| -> StdResult<_> {
+                let ballot = ballots().load(deps.storage, (proposal_id, voter))?;
+                Ok((ballot.power.u128(), (proposal_id, voter)))
+            })
+            .transpose()?;
+        let (min, max) = keyset_bounds(start_after, order.storage_order());
+        ballots()
+            .idx
+            .power
+            .sub_prefix(proposal_id)
+            .range(deps.storage, min, max, order.storage_order())
+            .take(limit as usize)
+            .map(|item| item.map(|((_, voter), ballot)| (voter, ballot)))
+            .collect::<StdResult<Vec<_>>>()?
+    } else {
+        let (min, max) = keyset_bounds(start_after.as_ref(), order.storage_order());
+        ballots()
+            .prefix(proposal_id)
+            .range(deps.storage, min, max, order.storage_order())
+            .take(limit as usize)
+            .collect::<StdResult<Vec<_>>>()?
+    };
+
+    let votes = listed
+        .into_iter()
+        .map(|(voter, ballot)| VoteInfo {
+            voter,
+            vote: ballot.vote,
+            power: ballot.power,
+            rationale: ballot.rationale,
+        })
+        .collect();
+
+    to_binary(&VoteListResponse { votes })
+}
+
+pub fn query_list_votes_by_voter(
+    deps: Deps,
+    voter: String,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let limit = clamp_limit(limit, DEFAULT_LIMIT, MAX_LIMIT);
+    let voter = deps.api.addr_validate(&voter)?;
+    let min = start_after.map(|proposal_id| Bound::exclusive((proposal_id, &voter)));
+
+    let votes = ballots()
+        .idx
+        .voter
+        .prefix(voter.clone())
+        .range(deps.storage, min, None, Order::Ascending)
         .take(limit as usize)
         .map(|item| {
-            let (voter, ballot) = item?;
-            Ok(VoteInfo {
-                voter,
+            let ((proposal_id, _), ballot) = item?;
+            Ok(BallotInfo {
+                proposal_id,
                 vote: ballot.vote,
                 power: ballot.power,
                 rationale: ballot.rationale,
@@ -836,7 +2003,131 @@ pub fn query_list_votes(
         })
         .collect::<StdResult<Vec<_>>>()?;
 
-    to_binary(&VoteListResponse { votes })
+    to_binary(&BallotListResponse { ballots: votes })
+}
+
+pub fn query_pending_votes(
+    deps: Deps,
+    env: Env,
+    voter: String,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let limit = clamp_limit(limit, DEFAULT_LIMIT, MAX_LIMIT) as usize;
+    let voter = deps.api.addr_validate(&voter)?;
+    let dao = CONFIG.load(deps.storage)?.dao;
+    let (min, max) = keyset_bounds(start_after, Order::Ascending);
+
+    // Proposals are indexed by the status they were last saved with,
+    // so proposals saved as open may have since passed or been
+    // rejected.
+    let mut pending = vec![];
+    for item in proposals().idx.status.prefix(Status::Open as u8).range(
+        deps.storage,
+        min,
+        max,
+        Order::Ascending,
+    ) {
+        if pending.len() == limit {
+            break;
+        }
+        let (id, proposal) = item?;
+        let response = proposal.into_response(&env.block, id);
+        if response.proposal.status != Status::Open || ballots().has(deps.storage, (id, &voter)) {
+            continue;
+        }
+        let power = get_voting_power(
+            deps,
+            voter.clone(),
+            dao.clone(),
+            Some(response.proposal.start_height),
+        )?;
+        if !power.is_zero() {
+            pending.push(response);
+        }
+    }
+
+    to_binary(&ProposalListResponse { proposals: pending })
+}
+
+pub fn query_verify_tally(deps: Deps, proposal_id: u64) -> StdResult<Binary> {
+    let proposal = proposals().load(deps.storage, proposal_id)?;
+    let mut recounted = Votes::zero();
+    let mut counted = 0u64;
+    for item in ballots()
+        .prefix(proposal_id)
+        .range(deps.storage, None, None, Order::Ascending)
+    {
+        let (_, ballot) = item?;
+        recounted.add_vote(ballot.vote, ballot.power);
+        counted += 1;
+    }
+
+    to_binary(&VerifyTallyResponse {
+        consistent: proposal.votes == recounted,
+        cached: proposal.votes,
+        recounted,
+        ballots: counted,
+    })
+}
+
+pub fn query_tally_summary(deps: Deps, env: Env, proposal_id: u64) -> StdResult<Binary> {
+    let proposal = proposals()
+        .may_load(deps.storage, proposal_id)?
+        .ok_or_else(|| StdError::not_found("proposal"))?;
+    let status = proposal.current_status(&env.block);
+    let votes = proposal.votes.clone();
+    let total_power = proposal.total_power;
+
+    let quorum = match proposal.threshold {
+        Threshold::ThresholdQuorum { quorum, .. } => Some(QuorumProgress {
+            quorum,
+            turnout: percentage(votes.total(), total_power),
+            met: does_vote_count_pass(votes.total(), total_power, quorum),
+        }),
+        Threshold::AbsolutePercentage { .. } | Threshold::AbsoluteCount { .. } => None,
+    };
+    let passed = matches!(
+        status,
+        Status::Passed | Status::Executed | Status::ExecutionFailed
+    );
+    let passing = if status == Status::Open {
+        // A block after which every expiration and minimum voting
+        // period has elapsed.
+        let closed = BlockInfo {
+            height: u64::MAX,
+            time: Timestamp::from_nanos(u64::MAX),
+            chain_id: env.block.chain_id,
+        };
+        proposal.is_passed(&closed)
+    } else {
+        passed
+    };
+
+    to_binary(&TallySummaryResponse {
+        status,
+        of_cast: vote_percentages(&votes, votes.total()),
+        of_total_power: vote_percentages(&votes, total_power),
+        quorum,
+        passed,
+        rejected: matches!(status, Status::Rejected | Status::Closed),
+        passing,
+        votes,
+        total_power,
+    })
+}
+
+/// Computes PART as a share of TOTAL, or zero if TOTAL is zero.
+fn percentage(part: Uint128, total: Uint128) -> Decimal {
+    Decimal::checked_from_ratio(part, total).unwrap_or_default()
+}
+
+fn vote_percentages(votes: &Votes, total: Uint128) -> VotePercentages {
+    VotePercentages {
+        yes: percentage(votes.yes, total),
+        no: percentage(votes.no, total),
+        abstain: percentage(votes.abstain, total),
+    }
 }
 
 pub fn query_info(deps: Deps) -> StdResult<Binary> {
@@ -844,16 +2135,46 @@ pub fn query_info(deps: Deps) -> StdResult<Binary> {
     to_binary(&dao_interface::voting::InfoResponse { info })
 }
 
+/// Adds a hook's failure to the hook failure history, dropping the
+/// oldest failure if the history is full.
+fn record_hook_failure(
+    storage: &mut dyn Storage,
+    env: &Env,
+    reply: &Reply,
+    kind: HookKind,
+    hook: Addr,
+    removed: bool,
+) -> StdResult<()> {
+    let reason = match &reply.result {
+        SubMsgResult::Err(err) => err.clone(),
+        SubMsgResult::Ok(_) => String::new(),
+    };
+    let mut history = HOOK_FAILURE_HISTORY.may_load(storage)?.unwrap_or_default();
+    history.push(HookFailureRecord {
+        kind,
+        hook,
+        reason,
+        height: env.block.height,
+        reply_id: reply.id,
+        removed,
+    });
+    if history.len() > MAX_HOOK_FAILURE_HISTORY {
+        history.remove(0);
+    }
+    HOOK_FAILURE_HISTORY.save(storage, &history)
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    msg.check_stored_version(deps.storage, CONTRACT_VERSION)?;
     // Set contract to version to latest
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     match msg {
-        MigrateMsg::FromV1 {
+        MigrateMsg::FromV1(FromV1Msg {
             close_proposal_on_execution_failure,
             pre_propose_info,
-        } => {
+        }) => {
             // Update the stored config to have the new
             // `close_proposal_on_execution_falure` field.
             let current_config = v1::state::CONFIG.load(deps.storage)?;
@@ -867,6 +2188,13 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, Co
                     allow_revoting: current_config.allow_revoting,
                     dao: current_config.dao.clone(),
                     close_proposal_on_execution_failure,
+                    max_proposal_size: MAX_PROPOSAL_SIZE,
+                    max_open_proposals: None,
+                    pre_propose_fallback: PreProposeFallback::default(),
+                    require_content_cid: false,
+                    abstain_counting: AbstainCounting::default(),
+                    execution_delay: None,
+                    vote_extension: None,
                 },
             )?;
 
@@ -901,6 +2229,8 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, Co
                     let migrated_proposal = SingleChoiceProposal {
                         title: prop.title,
                         description: prop.description,
+                        description_hash: None,
+                        content_cid: None,
                         proposer: prop.proposer,
                         start_height: prop.start_height,
                         min_voting_period: prop.min_voting_period.map(v1_expiration_to_v2),
@@ -911,9 +2241,14 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, Co
                         status: v1_status_to_v2(prop.status),
                         votes: v1_votes_to_v2(prop.votes),
                         allow_revoting: prop.allow_revoting,
+                        abstain_counting: AbstainCounting::default(),
+                        execution_delay: None,
+                        earliest_execution: None,
+                        vote_extension: None,
+                        max_expiration: None,
                     };
 
-                    PROPOSALS
+                    proposals()
                         .save(deps.storage, id, &migrated_proposal)
                         .map_err(|e| e.into())
                 })?;
@@ -931,11 +2266,11 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, Co
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
     let repl = TaggedReplyId::new(msg.id)?;
     match repl {
         TaggedReplyId::FailedProposalExecution(proposal_id) => {
-            PROPOSALS.update(deps.storage, proposal_id, |prop| match prop {
+            proposals().update(deps.storage, proposal_id, |prop| match prop {
                 Some(mut prop) => {
                     prop.status = Status::ExecutionFailed;
 
@@ -943,16 +2278,49 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
                 }
                 None => Err(ContractError::NoSuchProposal { id: proposal_id }),
             })?;
+            GOVERNANCE_STATS.execution_failed(deps.storage)?;
 
             Ok(Response::new().add_attribute("proposal_execution_failed", proposal_id.to_string()))
         }
         TaggedReplyId::FailedProposalHook(idx) => {
-            let addr = PROPOSAL_HOOKS.remove_hook_by_index(deps.storage, idx)?;
-            Ok(Response::new().add_attribute("removed_proposal_hook", format!("{addr}:{idx}")))
+            let outcome = PROPOSAL_HOOKS.record_failure(deps.storage, idx, HOOK_RETRIES)?;
+            record_hook_failure(
+                deps.storage,
+                &env,
+                &msg,
+                HookKind::Proposal,
+                outcome.hook().clone(),
+                outcome.is_removed(),
+            )?;
+            match outcome {
+                FailureOutcome::Removed { hook } => {
+                    PROPOSAL_HOOK_CONTENT.remove(deps.storage, hook.clone());
+                    Ok(Response::new()
+                        .add_attribute("removed_proposal_hook", format!("{hook}:{idx}")))
+                }
+                FailureOutcome::Retained { hook, failures } => Ok(Response::new()
+                    .add_attribute("failed_proposal_hook", format!("{hook}:{idx}"))
+                    .add_attribute("failures", failures.to_string())),
+            }
         }
         TaggedReplyId::FailedVoteHook(idx) => {
-            let addr = VOTE_HOOKS.remove_hook_by_index(deps.storage, idx)?;
-            Ok(Response::new().add_attribute("removed_vote_hook", format!("{addr}:{idx}")))
+            let outcome = VOTE_HOOKS.record_failure(deps.storage, idx, HOOK_RETRIES)?;
+            record_hook_failure(
+                deps.storage,
+                &env,
+                &msg,
+                HookKind::Vote,
+                outcome.hook().clone(),
+                outcome.is_removed(),
+            )?;
+            match outcome {
+                FailureOutcome::Removed { hook } => {
+                    Ok(Response::new().add_attribute("removed_vote_hook", format!("{hook}:{idx}")))
+                }
+                FailureOutcome::Retained { hook, failures } => Ok(Response::new()
+                    .add_attribute("failed_vote_hook", format!("{hook}:{idx}"))
+                    .add_attribute("failures", failures.to_string())),
+            }
         }
         TaggedReplyId::PreProposeModuleInstantiation => {
             let res = parse_reply_instantiate_data(msg)?;
@@ -973,24 +2341,51 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
         }
         TaggedReplyId::FailedPreProposeModuleHook => {
             let addr = match CREATION_POLICY.load(deps.storage)? {
-                ProposalCreationPolicy::Anyone {} => {
-                    // Something is off if we're getting this
-                    // reply and we don't have a pre-propose
-                    // module installed. This should be
-                    // unreachable.
+                ProposalCreationPolicy::Module { addr } => addr,
+                // Something is off if we're getting this reply and
+                // we don't have a pre-propose module installed. This
+                // should be unreachable.
+                ProposalCreationPolicy::Anyone {}
+                | ProposalCreationPolicy::Dao { .. }
+                | ProposalCreationPolicy::Frozen {} => {
                     return Err(ContractError::InvalidReplyID {
                         id: failed_pre_propose_module_hook_id(),
                     });
                 }
-                ProposalCreationPolicy::Module { addr } => {
-                    // If we are here, our pre-propose module has
-                    // errored while receiving a proposal
-                    // hook. Rest in peace pre-propose module.
-                    CREATION_POLICY.save(deps.storage, &ProposalCreationPolicy::Anyone {})?;
-                    addr
-                }
             };
-            Ok(Response::new().add_attribute("failed_prepropose_hook", format!("{addr}")))
+
+            // If we are here, our pre-propose module has errored
+            // while receiving a proposal hook. Rest in peace
+            // pre-propose module.
+            let config = CONFIG.load(deps.storage)?;
+            let fallback = config.pre_propose_fallback;
+            CREATION_POLICY.save(deps.storage, &fallback.clone().into_policy(config.dao))?;
+            PRE_PROPOSE_FAILURE.save(
+                deps.storage,
+                &PreProposeFailure {
+                    module: addr.clone(),
+                    height: env.block.height,
+                    fallback: fallback.clone(),
+                },
+            )?;
+            record_hook_failure(
+                deps.storage,
+                &env,
+                &msg,
+                HookKind::PreProposeModule,
+                addr.clone(),
+                true,
+            )?;
+
+            Ok(Response::new()
+                .add_attribute("failed_prepropose_hook", format!("{addr}"))
+                .add_event(
+                    EVENTS
+                        .event("pre_propose_fallback")
+                        .address("module", &addr)
+                        .attribute("fallback", fallback.to_string())
+                        .into(),
+                ))
         }
     }
 }
@@ -2,7 +2,8 @@ use std::u64;
 
 use cosmwasm_std::StdError;
 use cw_hooks::HookError;
-use cw_utils::ParseReplyError;
+use cw_utils::{Expiration, ParseReplyError};
+use dao_interface::migrate::MigrationError;
 use dao_voting::reply::error::TagError;
 use thiserror::Error;
 
@@ -11,6 +12,12 @@ pub enum ContractError {
     #[error(transparent)]
     Std(#[from] StdError),
 
+    #[error(transparent)]
+    MigrationError(#[from] MigrationError),
+
+    #[error(transparent)]
+    PauseError(#[from] dao_pausable::PauseError),
+
     #[error(transparent)]
     ParseReplyError(#[from] ParseReplyError),
 
@@ -26,6 +33,9 @@ pub enum ContractError {
     #[error(transparent)]
     VotingError(#[from] dao_voting::error::VotingError),
 
+    #[error(transparent)]
+    SignedVoteError(#[from] dao_voting::signed_vote::SignedVoteError),
+
     #[error("no such proposal ({id})")]
     NoSuchProposal { id: u64 },
 
@@ -38,6 +48,12 @@ pub enum ContractError {
     #[error("Proposal ({id}) is expired")]
     Expired { id: u64 },
 
+    #[error("proposal ({id}) is not open")]
+    NotOpen { id: u64 },
+
+    #[error("proposal ({id}) has been voted on and may no longer be updated")]
+    ProposalHasVotes { id: u64 },
+
     #[error("not registered to vote (no voting power) at time of proposal creation")]
     NotRegistered {},
 
@@ -50,6 +66,9 @@ pub enum ContractError {
     #[error("proposal is not in 'passed' state")]
     NotPassed {},
 
+    #[error("proposal may not be executed until ({earliest})")]
+    ExecutionDelayed { earliest: Expiration },
+
     #[error("proposal has already been executed")]
     AlreadyExecuted {},
 
@@ -68,6 +87,11 @@ pub enum ContractError {
     #[error("min voting period must be less than or equal to max voting period")]
     InvalidMinVotingPeriod {},
 
+    #[error(
+        "vote extension durations must be non-zero and have the same units as max_voting_period"
+    )]
+    InvalidVoteExtension {},
+
     #[error(
         "pre-propose modules must specify a proposer. lacking one, no proposer should be specified"
     )]
@@ -86,4 +110,16 @@ pub enum ContractError {
 
     #[error("received a reply failure with an invalid ID: ({id})")]
     InvalidReplyID { id: u64 },
+
+    #[error("module state may only be imported before any proposals have been created")]
+    ProposalsExist {},
+
+    #[error("proposals may only be imported before this module creates a proposal")]
+    ImportClosed {},
+
+    #[error("proposal ({id}) already exists")]
+    ProposalExists { id: u64 },
+
+    #[error("imported ballots on proposal ({id}) add up to more than its tally")]
+    ImportedVotesExceedTally { id: u64 },
 }
@@ -1,9 +1,20 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cw_utils::Duration;
-use dao_macros::proposal_module_query;
+use cosmwasm_std::{CosmosMsg, Empty};
+use cw_utils::{Duration, Expiration};
+use dao_macros::{pausable, pausable_query, proposal_module_query, TestQuerier};
+use dao_vote_hooks::VoteHookDispatch;
 use dao_voting::{
-    pre_propose::PreProposeInfo, proposal::SingleChoiceProposeMsg, threshold::Threshold,
-    voting::Vote,
+    pre_propose::{PreProposeFallback, PreProposeInfo},
+    proposal::{MaxOpenProposals, RebuildIndexesCursor, SingleChoiceProposeMsg},
+    signed_vote::SignedVote,
+    status::Status,
+    threshold::{AbstainCounting, Threshold},
+    voting::{Vote, VoteListOrder},
+};
+
+use crate::{
+    query::{ProposalResponse, VoteInfo},
+    state::VoteExtension,
 };
 
 #[cw_serde]
@@ -38,8 +49,36 @@ pub struct InstantiateMsg {
     /// remain open until the DAO's treasury was large enough for it to be
     /// executed.
     pub close_proposal_on_execution_failure: bool,
+    /// The maximum size of a proposal in bytes. Defaults to
+    /// `MAX_PROPOSAL_SIZE` and may not exceed
+    /// `MAX_PROPOSAL_SIZE_CEILING`. Chains with a larger query size
+    /// limit than Juno may raise this.
+    pub max_proposal_size: Option<u64>,
+    /// Limits on the number of proposals which may be open at
+    /// once. Proposals created through a pre-propose module count
+    /// towards the limits of their proposer. None for no limits.
+    pub max_open_proposals: Option<MaxOpenProposals>,
+    /// Who may create proposals if the pre-propose module errors
+    /// while handling a hook. Defaults to anyone.
+    pub pre_propose_fallback: Option<PreProposeFallback>,
+    /// If true, proposals must be created with a content CID
+    /// pointing to their full content on IPFS. Defaults to false.
+    pub require_content_cid: Option<bool>,
+    /// How abstain votes are counted when checking proposals' votes
+    /// against the threshold. Defaults to counting them towards
+    /// quorum only.
+    pub abstain_counting: Option<AbstainCounting>,
+    /// The amount of time that must pass after a proposal passes
+    /// before it may be executed. Must have the same units as
+    /// `max_voting_period`. None for no delay.
+    pub execution_delay: Option<Duration>,
+    /// Extends the expiration of proposals receiving late votes
+    /// which change their outcome. Durations must have the same units
+    /// as `max_voting_period`. None to never extend proposals.
+    pub vote_extension: Option<VoteExtension>,
 }
 
+#[pausable]
 #[cw_serde]
 pub enum ExecuteMsg {
     /// Creates a proposal in the module.
@@ -56,12 +95,44 @@ pub enum ExecuteMsg {
         /// the vote.
         rationale: Option<String>,
     },
+    /// Records votes signed off-chain with ADR-036 signatures,
+    /// allowing a relayer to pay for voting. Signed votes may not
+    /// change an existing ballot. See `dao_voting::signed_vote`.
+    SubmitSignedVotes { votes: Vec<SignedVote> },
+    /// Votes on a proposal on VOTER's behalf, with VOTER's voting
+    /// power. Only callable by the proposal creation policy's
+    /// pre-propose module, which is trusted to have checked that
+    /// VOTER sent the vote, as it is trusted to set the proposer of
+    /// the proposals it creates.
+    VoteFor {
+        voter: String,
+        proposal_id: u64,
+        vote: Vote,
+        rationale: Option<String>,
+    },
     /// Updates the sender's rationale for their vote on the specified
     /// proposal. Errors if no vote vote has been cast.
     UpdateRationale {
         proposal_id: u64,
         rationale: Option<String>,
     },
+    /// Revises an open proposal which has not been voted on. Only
+    /// the proposal's proposer may call this method, unless the
+    /// proposal creation policy is a pre-propose module, in which
+    /// case only that module may, on the proposer's behalf.
+    /// Proposals created with a description hash keep it and must be
+    /// revised with an empty description.
+    UpdateProposal {
+        /// The ID of the proposal to update.
+        proposal_id: u64,
+        /// The proposal's new title.
+        title: String,
+        /// The proposal's new description.
+        description: String,
+        /// The messages that will be executed should the proposal
+        /// pass.
+        msgs: Vec<CosmosMsg<Empty>>,
+    },
     /// Causes the messages associated with a passed proposal to be
     /// executed by the DAO.
     Execute {
@@ -75,6 +146,13 @@ pub enum ExecuteMsg {
         /// The ID of the proposal to close.
         proposal_id: u64,
     },
+    /// Withdraws an open proposal. Only the proposal's proposer may
+    /// call this method. If applicable, the proposal deposit is
+    /// refunded according to its `refund_on_cancel` policy.
+    Cancel {
+        /// The ID of the proposal to cancel.
+        proposal_id: u64,
+    },
     /// Updates the governance module's config.
     UpdateConfig {
         /// The new proposal passing threshold. This will only apply
@@ -110,6 +188,35 @@ pub enum ExecuteMsg {
         /// remain open until the DAO's treasury was large enough for it to be
         /// executed.
         close_proposal_on_execution_failure: bool,
+        /// The maximum size of a proposal in bytes. Defaults to
+        /// `MAX_PROPOSAL_SIZE` if not set. Only applies to proposals
+        /// created after the config update.
+        max_proposal_size: Option<u64>,
+        /// Limits on the number of proposals which may be open at
+        /// once. None for no limits.
+        max_open_proposals: Option<MaxOpenProposals>,
+        /// Who may create proposals if the pre-propose module errors
+        /// while handling a hook. If not set, the current fallback is
+        /// kept.
+        pre_propose_fallback: Option<PreProposeFallback>,
+        /// If true, proposals must be created with a content
+        /// CID. If not set, the current setting is kept. Only applies
+        /// to proposals created after the config update.
+        require_content_cid: Option<bool>,
+        /// How abstain votes are counted when checking proposals'
+        /// votes against the threshold. If not set, the current
+        /// setting is kept. Only applies to proposals created after
+        /// the config update.
+        abstain_counting: Option<AbstainCounting>,
+        /// The amount of time that must pass after a proposal passes
+        /// before it may be executed. None for no delay. Only applies
+        /// to proposals created after the config update.
+        execution_delay: Option<Duration>,
+        /// Extends the expiration of proposals receiving late votes
+        /// which change their outcome. None to never extend
+        /// proposals. Only applies to proposals created after the
+        /// config update.
+        vote_extension: Option<VoteExtension>,
     },
     /// Update's the proposal creation policy used for this
     /// module. Only the DAO may call this method.
@@ -119,7 +226,13 @@ pub enum ExecuteMsg {
     /// the status of a proposal changes or a proposal is created. If
     /// a consumer contract errors when handling a hook message it
     /// will be removed from the list of consumers.
-    AddProposalHook { address: String },
+    AddProposalHook {
+        address: String,
+        /// If true, new proposal hook messages sent to this consumer
+        /// include the proposal's title, description, and expiration.
+        #[serde(default)]
+        include_content: bool,
+    },
     /// Removes a consumer of proposal hooks.
     RemoveProposalHook { address: String },
     /// Adds an address as a consumer of vote hooks. Consumers of vote
@@ -129,11 +242,61 @@ pub enum ExecuteMsg {
     AddVoteHook { address: String },
     /// Removed a consumer of vote hooks.
     RemoveVoteHook { address: String },
+    /// Sets whether vote hooks are sent as votes are cast or
+    /// buffered and sent in batches. Only callable by the DAO.
+    UpdateVoteHookDispatch { dispatch: VoteHookDispatch },
+    /// Sends buffered votes to vote hook consumers. Callable by
+    /// anyone, for example by a crank once per block.
+    FlushVoteHooks {},
+    /// Copies the proposal and vote hook consumers, proposal
+    /// creation policy, and proposal count of MODULE, a proposal
+    /// module this one is replacing. Sent by the DAO when replacing
+    /// a proposal module and only callable by the DAO before any
+    /// proposals have been created.
+    ImportModuleState { module: String },
+    /// Imports proposals exported from another proposal module with
+    /// `ExportState`, for example when re-instantiating a DAO on a
+    /// new chain. Proposals keep their IDs and subsequent proposals
+    /// are numbered after the largest imported ID. Only callable by
+    /// the DAO before this module has created a proposal.
+    ImportProposals { proposals: Vec<ProposalResponse> },
+    /// Imports the votes cast on an imported proposal, as listed by
+    /// `ListVotes`. The proposal's tally is not changed, and errors
+    /// if a voter's ballot has already been imported or if the
+    /// imported ballots add up to more than the tally. Only callable
+    /// by the DAO before this module has created a proposal.
+    ImportVotes {
+        proposal_id: u64,
+        votes: Vec<VoteInfo>,
+    },
+    /// Deletes proposals with one of STATUSES which were created
+    /// before the block height OLDER_THAN, along with their
+    /// ballots. A summary of each pruned proposal is kept and may be
+    /// queried with `QueryMsg::PrunedProposal`. Only executed,
+    /// closed, and failed proposals may be pruned. At most LIMIT
+    /// proposals are pruned per call. Only callable by the DAO.
+    Prune {
+        older_than: u64,
+        statuses: Vec<Status>,
+        limit: Option<u64>,
+    },
+    /// Saves proposals and ballots again so that those saved before
+    /// an index was added to this module are added to it. Migrating
+    /// does not do this, so the DAO should call this after a
+    /// migration that adds an index until the response's data, the
+    /// cursor to pass as START_AFTER next, is `None`. At most LIMIT
+    /// proposals and ballots are saved per call. Only callable by the
+    /// DAO.
+    RebuildIndexes {
+        start_after: Option<RebuildIndexesCursor>,
+        limit: Option<u64>,
+    },
 }
 
+#[pausable_query]
 #[proposal_module_query]
 #[cw_serde]
-#[derive(QueryResponses)]
+#[derive(QueryResponses, TestQuerier)]
 pub enum QueryMsg {
     /// Gets the proposal module's config.
     #[returns(crate::state::Config)]
@@ -153,6 +316,8 @@ pub enum QueryMsg {
         /// query. If no limit is set a max of 30 proposals will be
         /// returned.
         limit: Option<u64>,
+        /// If set, only proposals with this status are returned.
+        status: Option<Status>,
     },
     /// Lists all of the proposals that have been cast in this module
     /// in decending order of proposal ID.
@@ -166,6 +331,23 @@ pub enum QueryMsg {
         /// query. If no limit is set a max of 30 proposals will be
         /// returned.
         limit: Option<u64>,
+        /// If set, only proposals with this status are returned.
+        status: Option<Status>,
+    },
+    /// Lists proposals which expire after `after` and before
+    /// `before` in ascending order of expiration. Both bounds are
+    /// exclusive. At least one bound must be set, and if both are
+    /// set they must both be heights or both be times. For example,
+    /// proposals closing in the next hour may be found by setting
+    /// `after` to the current time and `before` to an hour from now.
+    #[returns(crate::query::ProposalListResponse)]
+    ListProposalsByExpiration {
+        after: Option<Expiration>,
+        before: Option<Expiration>,
+        /// The maximum number of proposals to return as part of this
+        /// query. If no limit is set a max of 30 proposals will be
+        /// returned.
+        limit: Option<u64>,
     },
     /// Returns a voters position on a propsal.
     #[returns(crate::query::VoteResponse)]
@@ -176,12 +358,40 @@ pub enum QueryMsg {
     ListVotes {
         /// The proposal to list the votes of.
         proposal_id: u64,
-        /// The voter to start listing votes after. Ordering is done
-        /// alphabetically.
+        /// The voter to start listing votes after.
         start_after: Option<String>,
         /// The maximum number of votes to return in response to this
         /// query. If no limit is specified a max of 30 are returned.
         limit: Option<u64>,
+        /// The order to list votes in. Defaults to ascending by
+        /// voter address.
+        order: Option<VoteListOrder>,
+    },
+    /// Lists the votes an address has cast in this module in
+    /// ascending order of proposal ID.
+    #[returns(crate::query::BallotListResponse)]
+    ListVotesByVoter {
+        /// The address to list the votes of.
+        voter: String,
+        /// The proposal ID to start listing votes after.
+        start_after: Option<u64>,
+        /// The maximum number of votes to return in response to this
+        /// query. If no limit is specified a max of 30 are returned.
+        limit: Option<u64>,
+    },
+    /// Lists open proposals which `voter` had voting power for when
+    /// they were created but has not yet voted on, in ascending order
+    /// of proposal ID.
+    #[returns(crate::query::ProposalListResponse)]
+    PendingVotes {
+        /// The address to list the pending votes of.
+        voter: String,
+        /// The proposal ID to start listing proposals after.
+        start_after: Option<u64>,
+        /// The maximum number of proposals to return in response to
+        /// this query. If no limit is specified a max of 30 are
+        /// returned.
+        limit: Option<u64>,
     },
     /// Returns the number of proposals that have been created in this module.
     #[returns(::std::primitive::u64)]
@@ -189,36 +399,95 @@ pub enum QueryMsg {
     /// Gets the current proposal creation policy for this module.
     #[returns(::dao_voting::pre_propose::ProposalCreationPolicy)]
     ProposalCreationPolicy {},
+    /// Gets the most recent failure of a pre-propose module, or None
+    /// if a pre-propose module has never failed.
+    #[returns(Option<::dao_voting::pre_propose::PreProposeFailure>)]
+    PreProposeFailure {},
     /// Lists all of the consumers of proposal hooks for this module.
     #[returns(::cw_hooks::HooksResponse)]
     ProposalHooks {},
     /// Lists all of the consumers of vote hooks for this module.
     #[returns(::cw_hooks::HooksResponse)]
     VoteHooks {},
+    /// Lists the number of times each proposal hook consumer has
+    /// failed. Consumers are removed after failing more than
+    /// `HOOK_RETRIES` times.
+    #[returns(::cw_hooks::HookFailuresResponse)]
+    ProposalHookFailures {},
+    /// Lists the number of times each vote hook consumer has
+    /// failed. Consumers are removed after failing more than
+    /// `HOOK_RETRIES` times.
+    #[returns(::cw_hooks::HookFailuresResponse)]
+    VoteHookFailures {},
+    /// Lists the most recent failures of proposal, vote, and
+    /// pre-propose module hooks, oldest first, including hooks that
+    /// were removed because they failed.
+    #[returns(Vec<::dao_voting::reply::HookFailureRecord>)]
+    HookFailures {},
+    /// Gets totals of the proposals created, executed, and closed in
+    /// this module, the votes cast on them, and their average
+    /// turnout and pass rate.
+    #[returns(::dao_voting::stats::GovernanceStatsResponse)]
+    GovernanceStats {},
+    /// Statically checks the messages of a proposal for problems
+    /// that would prevent it from executing: invalid addresses,
+    /// messages to the DAO or this module that do not deserialize,
+    /// and native token spends larger than the DAO's balance.
+    #[returns(::dao_voting::simulation::SimulationResponse)]
+    SimulateExecution { proposal_id: u64 },
+    /// Gets how vote hooks are dispatched and the number of buffered
+    /// votes.
+    #[returns(::dao_vote_hooks::VoteHookDispatchResponse)]
+    VoteHookDispatch {},
+    /// Recounts the ballots cast on a proposal and compares the
+    /// result against the vote totals stored on the proposal. This
+    /// iterates over every ballot, so may run out of gas for
+    /// proposals with a very large number of voters.
+    #[returns(crate::query::VerifyTallyResponse)]
+    VerifyTally { proposal_id: u64 },
+    /// Summarizes the votes cast on a proposal as percentages, along
+    /// with its progress towards quorum and whether it meets its
+    /// threshold.
+    #[returns(crate::query::TallySummaryResponse)]
+    TallySummary { proposal_id: u64 },
+    /// Gets the summary of a proposal which has been pruned, or None
+    /// if the proposal has not been pruned.
+    #[returns(Option<::dao_voting::proposal::PrunedProposal>)]
+    PrunedProposal { proposal_id: u64 },
+    /// Gets this module's config, proposal creation policy, and
+    /// proposal count, along with its proposals in ascending order of
+    /// ID. Proposals are returned as stored, without their status
+    /// updated to the current block. Votes may be exported with
+    /// `ListVotes`.
+    #[returns(crate::query::ExportStateResponse)]
+    ExportState {
+        start_after: Option<u64>,
+        limit: Option<u64>,
+    },
 }
 
+/// Information needed to migrate from DAO DAO v1.
 #[cw_serde]
-pub enum MigrateMsg {
-    FromV1 {
-        /// This field was not present in DAO DAO v1. To migrate, a
-        /// value must be specified.
-        ///
-        /// If set to true proposals will be closed if their execution
-        /// fails. Otherwise, proposals will remain open after execution
-        /// failure. For example, with this enabled a proposal to send 5
-        /// tokens out of a DAO's treasury with 4 tokens would be closed when
-        /// it is executed. With this disabled, that same proposal would
-        /// remain open until the DAO's treasury was large enough for it to be
-        /// executed.
-        close_proposal_on_execution_failure: bool,
-        /// This field was not present in DAO DAO v1. To migrate, a
-        /// value must be specified.
-        ///
-        /// This contains information about how a pre-propose module may be configured.
-        /// If set to "AnyoneMayPropose", there will be no pre-propose module and consequently,
-        /// no deposit or membership checks when submitting a proposal. The "ModuleMayPropose"
-        /// option allows for instantiating a prepropose module which will handle deposit verification and return logic.
-        pre_propose_info: PreProposeInfo,
-    },
-    FromCompatible {},
+pub struct FromV1Msg {
+    /// This field was not present in DAO DAO v1. To migrate, a
+    /// value must be specified.
+    ///
+    /// If set to true proposals will be closed if their execution
+    /// fails. Otherwise, proposals will remain open after execution
+    /// failure. For example, with this enabled a proposal to send 5
+    /// tokens out of a DAO's treasury with 4 tokens would be closed when
+    /// it is executed. With this disabled, that same proposal would
+    /// remain open until the DAO's treasury was large enough for it to be
+    /// executed.
+    pub close_proposal_on_execution_failure: bool,
+    /// This field was not present in DAO DAO v1. To migrate, a
+    /// value must be specified.
+    ///
+    /// This contains information about how a pre-propose module may be configured.
+    /// If set to "AnyoneMayPropose", there will be no pre-propose module and consequently,
+    /// no deposit or membership checks when submitting a proposal. The "ModuleMayPropose"
+    /// option allows for instantiating a prepropose module which will handle deposit verification and return logic.
+    pub pre_propose_info: PreProposeInfo,
 }
+
+pub type MigrateMsg = dao_interface::migrate::MigrateMsg<FromV1Msg>;
@@ -1,16 +1,24 @@
 use crate::query::ProposalResponse;
-use crate::state::PROPOSAL_COUNT;
+use crate::state::{VoteExtension, PROPOSAL_COUNT};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, BlockInfo, CosmosMsg, Decimal, Empty, StdResult, Storage, Uint128};
-use cw_utils::Expiration;
+use cw_utils::{Duration, Expiration};
+use dao_voting::proposal::DescriptionHash;
 use dao_voting::status::Status;
-use dao_voting::threshold::{PercentageThreshold, Threshold};
+use dao_voting::threshold::{AbstainCounting, PercentageThreshold, Threshold};
 use dao_voting::voting::{does_vote_count_fail, does_vote_count_pass, Votes};
 
 #[cw_serde]
 pub struct SingleChoiceProposal {
     pub title: String,
     pub description: String,
+    /// A hash of the description and where it may be retrieved from,
+    /// if the description is stored off chain. The description is
+    /// empty if this is set.
+    pub description_hash: Option<DescriptionHash>,
+    /// The IPFS CID of a document holding the proposal's full
+    /// content, if one was provided.
+    pub content_cid: Option<String>,
     /// The address that created this proposal.
     pub proposer: Addr,
     /// The block height at which this proposal was created. Voting
@@ -34,6 +42,28 @@ pub struct SingleChoiceProposal {
     pub status: Status,
     pub votes: Votes,
     pub allow_revoting: bool,
+    /// How abstain votes are counted when checking this proposal's
+    /// votes against its threshold. Proposals created before this was
+    /// configurable count abstentions towards quorum only.
+    #[serde(default)]
+    pub abstain_counting: AbstainCounting,
+    /// The amount of time that must pass after this proposal passes
+    /// before it may be executed. None if it may be executed as soon
+    /// as it passes.
+    #[serde(default)]
+    pub execution_delay: Option<Duration>,
+    /// The earliest time at which this proposal may be executed. Set
+    /// when a proposal with an execution delay passes.
+    #[serde(default)]
+    pub earliest_execution: Option<Expiration>,
+    /// Extends this proposal's expiration when it receives late votes
+    /// which change its outcome.
+    #[serde(default)]
+    pub vote_extension: Option<VoteExtension>,
+    /// The latest this proposal's expiration may be extended to. Set
+    /// if this proposal has a vote extension.
+    #[serde(default)]
+    pub max_expiration: Option<Expiration>,
 }
 
 pub fn next_proposal_id(store: &dyn Storage) -> StdResult<u64> {
@@ -74,10 +104,102 @@ impl SingleChoiceProposal {
 
     /// Sets a proposals status to its current status.
     pub fn update_status(&mut self, block: &BlockInfo) {
+        self.set_status(block, false)
+    }
+
+    /// Sets a proposals status to its current status after a vote has
+    /// been cast on it. If this causes the proposal to pass, it is
+    /// the vote that passed it and any execution delay starts now.
+    pub fn update_status_after_vote(&mut self, block: &BlockInfo) {
+        self.set_status(block, true)
+    }
+
+    fn set_status(&mut self, block: &BlockInfo, voted: bool) {
         let new_status = self.current_status(block);
+        if self.status == Status::Open && new_status == Status::Passed {
+            self.earliest_execution = self.execution_delay.map(|delay| {
+                let passed_at = if voted {
+                    None
+                } else {
+                    self.passed_without_vote_at(block)
+                };
+                match (passed_at, delay) {
+                    (Some(Expiration::AtHeight(height)), Duration::Height(blocks)) => {
+                        Expiration::AtHeight(height + blocks)
+                    }
+                    (Some(Expiration::AtTime(time)), Duration::Time(seconds)) => {
+                        Expiration::AtTime(time.plus_seconds(seconds))
+                    }
+                    // The delay's units are validated to match the
+                    // voting period's, so this is only reached when
+                    // the proposal passed at `block`.
+                    _ => delay.after(block),
+                }
+            });
+        }
         self.status = new_status
     }
 
+    /// Gets when this proposal passed, given that it passed without a
+    /// vote being cast. Votes are fixed between when the last vote
+    /// was cast and now, so the proposal passed either when its
+    /// minimum voting period ended or when it expired. None if it
+    /// passed when it was created.
+    fn passed_without_vote_at(&self, block: &BlockInfo) -> Option<Expiration> {
+        if !self.expiration.is_expired(block) {
+            // Only the minimum voting period ending can have passed
+            // an unexpired proposal.
+            return self.min_voting_period;
+        }
+        match self.min_voting_period {
+            Some(min) if !self.allow_revoting && self.is_threshold_met(false) => Some(min),
+            _ => Some(self.expiration),
+        }
+    }
+
+    /// Returns true if this proposal would pass were it to expire
+    /// with its current votes.
+    pub fn passes_at_expiration(&self) -> bool {
+        self.is_threshold_met(true)
+    }
+
+    /// Extends this proposal's expiration by its vote extension if
+    /// it is within the extension's window of expiring at BLOCK. The
+    /// expiration is not extended past `max_expiration`. Returns true
+    /// if the expiration changed.
+    pub fn extend_expiration(&mut self, block: &BlockInfo) -> bool {
+        let (extension, max) = match (self.vote_extension, self.max_expiration) {
+            (Some(extension), Some(max)) => (extension, max),
+            _ => return false,
+        };
+        let extended = match (self.expiration, extension.window, extension.extension, max) {
+            (
+                Expiration::AtHeight(height),
+                Duration::Height(window),
+                Duration::Height(blocks),
+                Expiration::AtHeight(max),
+            ) => (height.saturating_sub(block.height) <= window)
+                .then(|| Expiration::AtHeight((height + blocks).min(max))),
+            (
+                Expiration::AtTime(time),
+                Duration::Time(window),
+                Duration::Time(seconds),
+                Expiration::AtTime(max),
+            ) => (time.seconds().saturating_sub(block.time.seconds()) <= window)
+                .then(|| Expiration::AtTime(time.plus_seconds(seconds).min(max))),
+            // The extension's units are validated to match the voting
+            // period's, so proposals with one expire in those units.
+            _ => None,
+        };
+        match extended {
+            Some(expiration) if expiration != self.expiration => {
+                self.expiration = expiration;
+                true
+            }
+            _ => false,
+        }
+    }
+
     /// Returns true iff this proposal is sure to pass (even before
     /// expiration if no future sequence of possible votes can cause
     /// it to fail).
@@ -98,25 +220,38 @@ impl SingleChoiceProposal {
             }
         }
 
+        self.is_threshold_met(self.expiration.is_expired(block))
+    }
+
+    /// Returns true if this proposal's votes meet its threshold,
+    /// ignoring when voting may complete. `expired` selects whether
+    /// the votes are compared against those cast or against the total
+    /// voting power.
+    fn is_threshold_met(&self, expired: bool) -> bool {
+        let counting = self.abstain_counting;
         match self.threshold {
             Threshold::AbsolutePercentage { percentage } => {
-                let options = self.total_power - self.votes.abstain;
+                let options = counting.threshold_options(self.total_power, &self.votes);
                 does_vote_count_pass(self.votes.yes, options, percentage)
             }
             Threshold::ThresholdQuorum { threshold, quorum } => {
-                if !does_vote_count_pass(self.votes.total(), self.total_power, quorum) {
+                if !does_vote_count_pass(
+                    counting.quorum_votes(&self.votes),
+                    self.total_power,
+                    quorum,
+                ) {
                     return false;
                 }
 
-                if self.expiration.is_expired(block) {
+                if expired {
                     // If the quorum is met and the proposal is
                     // expired the number of votes needed to pass a
                     // proposal is compared to the number of votes on
                     // the proposal.
-                    let options = self.votes.total() - self.votes.abstain;
+                    let options = counting.threshold_options(self.votes.total(), &self.votes);
                     does_vote_count_pass(self.votes.yes, options, threshold)
                 } else {
-                    let options = self.total_power - self.votes.abstain;
+                    let options = counting.threshold_options(self.total_power, &self.votes);
                     does_vote_count_pass(self.votes.yes, options, threshold)
                 }
             }
@@ -133,11 +268,13 @@ impl SingleChoiceProposal {
             return false;
         }
 
+        let counting = self.abstain_counting;
+        let against = counting.votes_against(&self.votes);
         match self.threshold {
             Threshold::AbsolutePercentage {
                 percentage: percentage_needed,
             } => {
-                let options = self.total_power - self.votes.abstain;
+                let options = counting.threshold_options(self.total_power, &self.votes);
 
                 // If there is a 100% passing threshold..
                 if percentage_needed == PercentageThreshold::Percent(Decimal::percent(100)) {
@@ -155,22 +292,26 @@ impl SingleChoiceProposal {
                         // threshold`) we get a 0% requirement for no
                         // votes. Zero no votes do indeed meet a 0%
                         // threshold.
-                        return self.votes.no >= Uint128::new(1);
+                        return against >= Uint128::new(1);
                     }
                 }
 
-                does_vote_count_fail(self.votes.no, options, percentage_needed)
+                does_vote_count_fail(against, options, percentage_needed)
             }
             Threshold::ThresholdQuorum { threshold, quorum } => {
                 match (
-                    does_vote_count_pass(self.votes.total(), self.total_power, quorum),
+                    does_vote_count_pass(
+                        counting.quorum_votes(&self.votes),
+                        self.total_power,
+                        quorum,
+                    ),
                     self.expiration.is_expired(block),
                 ) {
                     // Has met quorum and is expired.
                     (true, true) => {
                         // => consider only votes cast and see if no
                         //    votes meet threshold.
-                        let options = self.votes.total() - self.votes.abstain;
+                        let options = counting.threshold_options(self.votes.total(), &self.votes);
 
                         // If there is a 100% passing threshold..
                         if threshold == PercentageThreshold::Percent(Decimal::percent(100)) {
@@ -189,17 +330,17 @@ impl SingleChoiceProposal {
                                 // threshold`) we get a 0% requirement
                                 // for no votes. Zero no votes do
                                 // indeed meet a 0% threshold.
-                                return self.votes.no >= Uint128::new(1);
+                                return against >= Uint128::new(1);
                             }
                         }
-                        does_vote_count_fail(self.votes.no, options, threshold)
+                        does_vote_count_fail(against, options, threshold)
                     }
                     // Has met quorum and is not expired.
                     // | Hasn't met quorum and is not expired.
                     (true, false) | (false, false) => {
                         // => consider all possible votes and see if
                         //    no votes meet threshold.
-                        let options = self.total_power - self.votes.abstain;
+                        let options = counting.threshold_options(self.total_power, &self.votes);
 
                         // If there is a 100% passing threshold..
                         if threshold == PercentageThreshold::Percent(Decimal::percent(100)) {
@@ -218,11 +359,11 @@ impl SingleChoiceProposal {
                                 // get a 0% requirement for no
                                 // votes. Zero no votes do indeed meet
                                 // a 0% threshold.
-                                return self.votes.no >= Uint128::new(1);
+                                return against >= Uint128::new(1);
                             }
                         }
 
-                        does_vote_count_fail(self.votes.no, options, threshold)
+                        does_vote_count_fail(against, options, threshold)
                     }
                     // Hasn't met quorum requirement and voting has closed => rejected.
                     (false, true) => true,
@@ -272,11 +413,18 @@ mod test {
             expiration,
             min_voting_period: Some(min_voting_period),
             allow_revoting,
+            abstain_counting: AbstainCounting::default(),
             msgs: vec![],
             status: Status::Open,
             threshold,
             total_power,
             votes,
+            description_hash: None,
+            content_cid: None,
+            execution_delay: None,
+            earliest_execution: None,
+            vote_extension: None,
+            max_expiration: None,
         };
         (prop, block)
     }
@@ -770,6 +918,74 @@ mod test {
         ));
     }
 
+    #[test]
+    fn test_abstain_counting() {
+        let threshold = Threshold::ThresholdQuorum {
+            threshold: PercentageThreshold::Majority {},
+            quorum: PercentageThreshold::Percent(Decimal::percent(60)),
+        };
+        let votes = Votes {
+            yes: Uint128::new(5),
+            no: Uint128::new(3),
+            abstain: Uint128::new(6),
+        };
+        let (mut prop, block) = setup_prop(threshold, votes, Uint128::new(15), true, true, false);
+
+        // 14 of 15 voted, meeting quorum, and 5 of the 8 non-abstaining
+        // votes are yes.
+        assert!(prop.is_passed(&block));
+
+        // Abstentions count against the proposal: 5 of 14 votes are
+        // yes.
+        prop.abstain_counting = AbstainCounting::QuorumAndThreshold {};
+        assert!(!prop.is_passed(&block));
+        assert!(prop.is_rejected(&block));
+
+        // Only 8 of 15 voted yes or no, below quorum.
+        prop.abstain_counting = AbstainCounting::Neither {};
+        assert!(!prop.is_passed(&block));
+        assert!(prop.is_rejected(&block));
+    }
+
+    #[test]
+    fn test_earliest_execution() {
+        let threshold = Threshold::AbsolutePercentage {
+            percentage: PercentageThreshold::Majority {},
+        };
+        let votes = Votes {
+            yes: Uint128::new(3),
+            no: Uint128::zero(),
+            abstain: Uint128::zero(),
+        };
+        let (mut prop, block) = setup_prop(threshold, votes, Uint128::new(4), false, true, false);
+        prop.execution_delay = Some(Duration::Height(10));
+
+        // Passing because a vote was cast starts the delay now.
+        let mut voted = prop.clone();
+        voted.update_status_after_vote(&block);
+        assert_eq!(voted.status, Status::Passed);
+        assert_eq!(
+            voted.earliest_execution,
+            Some(Expiration::AtHeight(block.height + 10))
+        );
+
+        // Otherwise, the proposal passed when its minimum voting
+        // period ended.
+        prop.update_status(&block);
+        assert_eq!(prop.status, Status::Passed);
+        assert_eq!(
+            prop.earliest_execution,
+            Some(Expiration::AtHeight(block.height - 5 + 10))
+        );
+
+        // Already passed proposals keep their delay.
+        prop.update_status(&block);
+        assert_eq!(
+            prop.earliest_execution,
+            Some(Expiration::AtHeight(block.height + 5))
+        );
+    }
+
     #[test]
     fn proposal_passed_quorum() {
         let quorum = Threshold::ThresholdQuorum {
@@ -1,7 +1,12 @@
-use crate::proposal::SingleChoiceProposal;
+use crate::{proposal::SingleChoiceProposal, state::Config};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
-use dao_voting::voting::Vote;
+use cosmwasm_std::{Addr, Decimal, Uint128};
+use dao_voting::{
+    pre_propose::ProposalCreationPolicy,
+    status::Status,
+    threshold::PercentageThreshold,
+    voting::{Vote, Votes},
+};
 
 /// Information about a proposal returned by proposal queries.
 #[cw_serde]
@@ -37,9 +42,95 @@ pub struct VoteListResponse {
     pub votes: Vec<VoteInfo>,
 }
 
+/// Information about a vote cast by an address, returned by
+/// `ListVotesByVoter`.
+#[cw_serde]
+pub struct BallotInfo {
+    /// The proposal that was voted on.
+    pub proposal_id: u64,
+    /// Position on the vote.
+    pub vote: Vote,
+    /// The voting power behind the vote.
+    pub power: Uint128,
+    /// Address-specified rationale for the vote.
+    pub rationale: Option<String>,
+}
+
+/// The votes cast by an address.
+#[cw_serde]
+pub struct BallotListResponse {
+    pub ballots: Vec<BallotInfo>,
+}
+
 /// A list of proposals returned by `ListProposals` and
 /// `ReverseProposals`.
 #[cw_serde]
 pub struct ProposalListResponse {
     pub proposals: Vec<ProposalResponse>,
 }
+
+/// The result of recounting the ballots cast on a proposal, returned
+/// by `VerifyTally`.
+#[cw_serde]
+pub struct VerifyTallyResponse {
+    /// The vote totals stored on the proposal.
+    pub cached: Votes,
+    /// The vote totals computed by summing the proposal's ballots.
+    pub recounted: Votes,
+    /// The number of ballots that were summed.
+    pub ballots: u64,
+    /// True if `cached` and `recounted` are equal.
+    pub consistent: bool,
+}
+
+/// A page of a proposal module's state, returned by `ExportState`.
+#[cw_serde]
+pub struct ExportStateResponse {
+    pub config: Config,
+    pub creation_policy: ProposalCreationPolicy,
+    /// The number of proposals that have been created. The next
+    /// proposal will have this plus one as its ID.
+    pub proposal_count: u64,
+    pub proposals: Vec<ProposalResponse>,
+}
+
+/// The share of some amount of voting power held by each position.
+#[cw_serde]
+pub struct VotePercentages {
+    pub yes: Decimal,
+    pub no: Decimal,
+    pub abstain: Decimal,
+}
+
+/// A proposal's progress towards its quorum.
+#[cw_serde]
+pub struct QuorumProgress {
+    pub quorum: PercentageThreshold,
+    /// The share of total voting power which has voted.
+    pub turnout: Decimal,
+    /// True if the quorum has been reached.
+    pub met: bool,
+}
+
+/// A summary of the votes cast on a proposal, returned by
+/// `TallySummary`.
+#[cw_serde]
+pub struct TallySummaryResponse {
+    /// The proposal's status as of the current block.
+    pub status: Status,
+    pub votes: Votes,
+    pub total_power: Uint128,
+    /// Each position's share of the votes cast.
+    pub of_cast: VotePercentages,
+    /// Each position's share of the total voting power.
+    pub of_total_power: VotePercentages,
+    /// Progress towards quorum, or None if the proposal's threshold
+    /// does not have one.
+    pub quorum: Option<QuorumProgress>,
+    /// True if the proposal has passed or is certain to pass.
+    pub passed: bool,
+    /// True if the proposal has been rejected or is certain to be.
+    pub rejected: bool,
+    /// True if the proposal would pass were voting to close now.
+    pub passing: bool,
+}
@@ -1,11 +1,20 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Empty, Uint128};
 use cw_hooks::Hooks;
-use cw_storage_plus::{Item, Map};
-use cw_utils::Duration;
-use dao_voting::{pre_propose::ProposalCreationPolicy, threshold::Threshold, voting::Vote};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, KeyDeserialize, Map, MultiIndex};
+use cw_utils::{Duration, Expiration};
+use dao_pausable::Pausable;
+use dao_vote_hooks::VoteHookBuffer;
+use dao_voting::{
+    pre_propose::{PreProposeFailure, PreProposeFallback, ProposalCreationPolicy},
+    proposal::{expiration_index_key, MaxOpenProposals, PrunedProposal, MAX_PROPOSAL_SIZE},
+    reply::HookFailureRecord,
+    stats::GovernanceStats,
+    threshold::{AbstainCounting, Threshold},
+    voting::{PowerCache, Vote, Votes},
+};
 
-use crate::proposal::SingleChoiceProposal;
+use crate::{proposal::SingleChoiceProposal, ContractError};
 
 /// A vote cast for a proposal.
 #[cw_serde]
@@ -55,6 +64,93 @@ pub struct Config {
     /// remain open until the DAO's treasury was large enough for it to be
     /// executed.
     pub close_proposal_on_execution_failure: bool,
+    /// The maximum size of a proposal in bytes. Proposals which are
+    /// larger than this may not be created. Configs saved before this
+    /// was configurable use `MAX_PROPOSAL_SIZE`.
+    #[serde(default = "default_max_proposal_size")]
+    pub max_proposal_size: u64,
+    /// Limits on the number of proposals which may be open at
+    /// once. None for no limits.
+    pub max_open_proposals: Option<MaxOpenProposals>,
+    /// The creation policy to fall back to if the pre-propose module
+    /// errors while handling a hook. Configs saved before this was
+    /// configurable fall back to allowing anyone to propose.
+    #[serde(default)]
+    pub pre_propose_fallback: PreProposeFallback,
+    /// If true, proposals must be created with a content CID. Configs
+    /// saved before this was configurable do not require one.
+    #[serde(default)]
+    pub require_content_cid: bool,
+    /// How abstain votes are counted when checking proposals' votes
+    /// against the threshold. Configs saved before this was
+    /// configurable count abstentions towards quorum only.
+    #[serde(default)]
+    pub abstain_counting: AbstainCounting,
+    /// The amount of time that must pass after a proposal passes
+    /// before it may be executed. None for no delay.
+    #[serde(default)]
+    pub execution_delay: Option<Duration>,
+    /// Extends the expiration of proposals receiving late votes
+    /// which change their outcome. None to never extend proposals.
+    #[serde(default)]
+    pub vote_extension: Option<VoteExtension>,
+}
+
+/// Configures when a proposal's expiration is extended. A vote cast
+/// within `window` of a proposal's expiration which changes whether
+/// the proposal would pass were it to expire extends the expiration
+/// by `extension`. This gives voters time to respond to last-second
+/// votes.
+#[cw_serde]
+#[derive(Copy)]
+pub struct VoteExtension {
+    /// How close to its expiration a vote must be cast for the
+    /// proposal to be extended.
+    pub window: Duration,
+    /// How far the proposal's expiration is extended by each late
+    /// vote.
+    pub extension: Duration,
+    /// The most a proposal's expiration may be extended by in total.
+    pub max_extension: Duration,
+}
+
+impl VoteExtension {
+    /// Validates that the extension's durations are non-zero and have
+    /// the same units as the max voting period.
+    pub fn validate(&self, max_voting_period: Duration) -> Result<(), ContractError> {
+        let valid = [self.window, self.extension, self.max_extension]
+            .iter()
+            .all(|duration| match (duration, max_voting_period) {
+                (Duration::Height(n), Duration::Height(_))
+                | (Duration::Time(n), Duration::Time(_)) => *n > 0,
+                _ => false,
+            });
+        if valid {
+            Ok(())
+        } else {
+            Err(ContractError::InvalidVoteExtension {})
+        }
+    }
+
+    /// Gets the latest a proposal expiring at EXPIRATION may be
+    /// extended to.
+    pub fn max_expiration(&self, expiration: Expiration) -> Expiration {
+        match (expiration, self.max_extension) {
+            (Expiration::AtHeight(height), Duration::Height(blocks)) => {
+                Expiration::AtHeight(height + blocks)
+            }
+            (Expiration::AtTime(time), Duration::Time(seconds)) => {
+                Expiration::AtTime(time.plus_seconds(seconds))
+            }
+            // Validated to have the same units as the voting period,
+            // so proposals expire in the same units.
+            _ => expiration,
+        }
+    }
+}
+
+fn default_max_proposal_size() -> u64 {
+    MAX_PROPOSAL_SIZE
 }
 
 /// The current top level config for the module.  The "config" key was
@@ -62,12 +158,115 @@ pub struct Config {
 pub const CONFIG: Item<Config> = Item::new("config_v2");
 /// The number of proposals that have been created.
 pub const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
-pub const PROPOSALS: Map<u64, SingleChoiceProposal> = Map::new("proposals_v2");
-pub const BALLOTS: Map<(u64, &Addr), Ballot> = Map::new("ballots");
+
+pub struct ProposalIndexes<'a> {
+    /// Indexes proposals by the status they were last saved
+    /// with. Open proposals may have since passed or been rejected.
+    pub status: MultiIndex<'a, u8, SingleChoiceProposal, u64>,
+    /// Indexes proposals by when they expire. See
+    /// `expiration_index_key` for the index's key.
+    pub expiration: MultiIndex<'a, (u8, u64), SingleChoiceProposal, u64>,
+}
+
+impl<'a> IndexList<SingleChoiceProposal> for ProposalIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<SingleChoiceProposal>> + '_> {
+        let v: Vec<&dyn Index<SingleChoiceProposal>> = vec![&self.status, &self.expiration];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Proposals keyed by ID.
+pub fn proposals<'a>() -> IndexedMap<'a, u64, SingleChoiceProposal, ProposalIndexes<'a>> {
+    IndexedMap::new(
+        "proposals_v2",
+        ProposalIndexes {
+            status: MultiIndex::new(
+                |_, proposal| proposal.status as u8,
+                "proposals_v2",
+                "proposals_v2__status",
+            ),
+            expiration: MultiIndex::new(
+                |_, proposal| expiration_index_key(&proposal.expiration),
+                "proposals_v2",
+                "proposals_v2__expiration",
+            ),
+        },
+    )
+}
+
+pub struct BallotIndexes<'a> {
+    /// Indexes ballots by voter so that the votes an address has
+    /// cast may be listed without scanning every proposal.
+    pub voter: MultiIndex<'a, Addr, Ballot, (u64, &'a Addr)>,
+    /// Indexes ballots by proposal ID and voting power so that the
+    /// votes on a proposal may be listed by power.
+    pub power: MultiIndex<'a, (u64, u128), Ballot, (u64, &'a Addr)>,
+}
+
+impl<'a> IndexList<Ballot> for BallotIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Ballot>> + '_> {
+        let v: Vec<&dyn Index<Ballot>> = vec![&self.voter, &self.power];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Ballots keyed by proposal ID and voter.
+pub fn ballots<'a>() -> IndexedMap<'a, (u64, &'a Addr), Ballot, BallotIndexes<'a>> {
+    IndexedMap::new(
+        "ballots",
+        BallotIndexes {
+            voter: MultiIndex::new(|pk, _| ballot_key(pk).1, "ballots", "ballots__voter"),
+            power: MultiIndex::new(
+                |pk, ballot| (ballot_key(pk).0, ballot.power.u128()),
+                "ballots",
+                "ballots__power",
+            ),
+        },
+    )
+}
+
+fn ballot_key(pk: &[u8]) -> (u64, Addr) {
+    // Ballots are only saved under valid keys.
+    <(u64, Addr)>::from_slice(pk).expect("invalid ballot key")
+}
+
 /// Consumers of proposal state change hooks.
 pub const PROPOSAL_HOOKS: Hooks = Hooks::new("proposal_hooks");
+/// Consumers of proposal hooks that are sent the content of new
+/// proposals.
+pub const PROPOSAL_HOOK_CONTENT: Map<Addr, Empty> = Map::new("proposal_hook_content");
 /// Consumers of vote hooks.
 pub const VOTE_HOOKS: Hooks = Hooks::new("vote_hooks");
+/// How vote hooks are dispatched, and votes buffered for batched
+/// dispatch.
+pub const VOTE_HOOK_BUFFER: VoteHookBuffer =
+    VoteHookBuffer::new("vote_hook_dispatch", "vote_hook_buffer");
 /// The address of the pre-propose module associated with this
 /// proposal module (if any).
 pub const CREATION_POLICY: Item<ProposalCreationPolicy> = Item::new("creation_policy");
+/// The most recent failure of a pre-propose module, if any.
+pub const PRE_PROPOSE_FAILURE: Item<PreProposeFailure> = Item::new("pre_propose_failure");
+/// The most recent hook failures, oldest first. At most
+/// `MAX_HOOK_FAILURE_HISTORY` failures are kept.
+pub const HOOK_FAILURE_HISTORY: Item<Vec<HookFailureRecord>> = Item::new("hook_failure_history");
+/// Totals of proposal and voting activity, maintained as proposals
+/// are created, voted on, and completed.
+pub const GOVERNANCE_STATS: GovernanceStats =
+    GovernanceStats::new("governance_stats", "governance_stats_voters");
+/// Total voting power queried from the DAO, keyed by voting module
+/// and height. Proposals created in the same block share a total
+/// power query.
+pub const POWER_CACHE: PowerCache = PowerCache::new("total_power_cache");
+/// Set while proposals and votes exported from another module may be
+/// imported. Set on instantiation and removed once this module
+/// creates its first proposal.
+pub const IMPORTING: Item<bool> = Item::new("importing");
+/// The sum of the ballots imported on each imported proposal, which
+/// may not exceed the proposal's tally.
+pub const IMPORTED_VOTES: Map<u64, Votes> = Map::new("imported_votes");
+/// Summaries of proposals which have been pruned, keyed by proposal
+/// ID.
+pub const PRUNED_PROPOSALS: Map<u64, PrunedProposal> = Map::new("pruned_proposals");
+/// While paused, only the DAO may act on this module, and only to
+/// unpause it.
+pub const PAUSED: Pausable = Pausable::new("paused", "pause_metadata");
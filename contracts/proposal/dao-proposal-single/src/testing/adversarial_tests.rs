@@ -173,10 +173,19 @@ pub fn test_executed_prop_state_remains_after_vote_swing() {
                 denom: dao_voting::deposit::DepositToken::VotingModuleToken {},
                 amount: Uint128::new(10_000_000),
                 refund_policy: DepositRefundPolicy::OnlyPassed,
+                additional: vec![],
+                refund_on_cancel: false,
             }),
             false,
         ),
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
+        require_content_cid: None,
+        abstain_counting: None,
+        execution_delay: None,
+        vote_extension: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -269,10 +278,19 @@ pub fn test_passed_prop_state_remains_after_vote_swing() {
                 denom: dao_voting::deposit::DepositToken::VotingModuleToken {},
                 amount: Uint128::new(10_000_000),
                 refund_policy: DepositRefundPolicy::OnlyPassed,
+                additional: vec![],
+                refund_on_cancel: false,
             }),
             false,
         ),
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
+        require_content_cid: None,
+        abstain_counting: None,
+        execution_delay: None,
+        vote_extension: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
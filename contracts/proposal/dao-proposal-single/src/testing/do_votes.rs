@@ -127,6 +127,13 @@ where
         only_members_execute: false,
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
+        require_content_cid: None,
+        abstain_counting: None,
+        execution_delay: None,
+        vote_extension: None,
         pre_propose_info,
     };
 
@@ -193,6 +200,9 @@ where
                 title: "A simple text proposal".to_string(),
                 description: "This is a simple text proposal".to_string(),
                 msgs: vec![],
+                description_hash: None,
+                content_cid: None,
+                disable_revoting: false,
             },
         },
         &funds,
@@ -34,7 +34,9 @@ pub(crate) fn make_proposal(
 
     // Collect the funding.
     let funds = match proposal_creation_policy {
-        ProposalCreationPolicy::Anyone {} => vec![],
+        ProposalCreationPolicy::Anyone {}
+        | ProposalCreationPolicy::Dao { .. }
+        | ProposalCreationPolicy::Frozen {} => vec![],
         ProposalCreationPolicy::Module {
             addr: ref pre_propose,
         } => {
@@ -44,6 +46,8 @@ pub(crate) fn make_proposal(
                     denom,
                     amount,
                     refund_policy: _,
+                    additional: _,
+                    refund_on_cancel: _,
                 }) => match denom {
                     CheckedDenom::Native(denom) => coins(amount.u128(), denom),
                     CheckedDenom::Cw20(addr) => {
@@ -69,7 +73,9 @@ pub(crate) fn make_proposal(
 
     // Make the proposal.
     match proposal_creation_policy {
-        ProposalCreationPolicy::Anyone {} => app
+        ProposalCreationPolicy::Anyone {}
+        | ProposalCreationPolicy::Dao { .. }
+        | ProposalCreationPolicy::Frozen {} => app
             .execute_contract(
                 Addr::unchecked(proposer),
                 proposal_single.clone(),
@@ -78,6 +84,9 @@ pub(crate) fn make_proposal(
                     description: "description".to_string(),
                     msgs: msgs.clone(),
                     proposer: None,
+                    description_hash: None,
+                    content_cid: None,
+                    disable_revoting: false,
                 }),
                 &[],
             )
@@ -91,6 +100,9 @@ pub(crate) fn make_proposal(
                         title: "title".to_string(),
                         description: "description".to_string(),
                         msgs: msgs.clone(),
+                        description_hash: None,
+                        content_cid: None,
+                        disable_revoting: false,
                     },
                 },
                 &funds,
@@ -213,6 +225,50 @@ pub(crate) fn update_rationale(
     .unwrap();
 }
 
+pub(crate) fn update_proposal(
+    app: &mut App,
+    proposal_single: &Addr,
+    sender: &str,
+    proposal_id: u64,
+    title: &str,
+    msgs: Vec<CosmosMsg>,
+) {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        proposal_single.clone(),
+        &ExecuteMsg::UpdateProposal {
+            proposal_id,
+            title: title.to_string(),
+            description: "updated description".to_string(),
+            msgs,
+        },
+        &[],
+    )
+    .unwrap();
+}
+
+pub(crate) fn update_proposal_should_fail(
+    app: &mut App,
+    proposal_single: &Addr,
+    sender: &str,
+    proposal_id: u64,
+) -> ContractError {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        proposal_single.clone(),
+        &ExecuteMsg::UpdateProposal {
+            proposal_id,
+            title: "title".to_string(),
+            description: "description".to_string(),
+            msgs: vec![],
+        },
+        &[],
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap()
+}
+
 pub(crate) fn execute_proposal(
     app: &mut App,
     proposal_single: &Addr,
@@ -260,6 +316,38 @@ pub(crate) fn close_proposal(
     .unwrap();
 }
 
+pub(crate) fn cancel_proposal_should_fail(
+    app: &mut App,
+    proposal_single: &Addr,
+    sender: &str,
+    proposal_id: u64,
+) -> ContractError {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        proposal_single.clone(),
+        &ExecuteMsg::Cancel { proposal_id },
+        &[],
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap()
+}
+
+pub(crate) fn cancel_proposal(
+    app: &mut App,
+    proposal_single: &Addr,
+    sender: &str,
+    proposal_id: u64,
+) {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        proposal_single.clone(),
+        &ExecuteMsg::Cancel { proposal_id },
+        &[],
+    )
+    .unwrap();
+}
+
 pub(crate) fn mint_natives(app: &mut App, receiver: &str, amount: Vec<Coin>) {
     app.sudo(cw_multi_test::SudoMsg::Bank(BankSudo::Mint {
         to_address: receiver.to_string(),
@@ -322,6 +410,7 @@ pub(crate) fn add_proposal_hook(
         proposal_module.clone(),
         &ExecuteMsg::AddProposalHook {
             address: hook_addr.to_string(),
+            include_content: false,
         },
         &[],
     )
@@ -339,6 +428,7 @@ pub(crate) fn add_proposal_hook_should_fail(
         proposal_module.clone(),
         &ExecuteMsg::AddProposalHook {
             address: hook_addr.to_string(),
+            include_content: false,
         },
         &[],
     )
@@ -37,6 +37,7 @@ pub(crate) fn get_pre_propose_info(
             msg: to_binary(&cppbps::InstantiateMsg {
                 deposit_info,
                 open_proposal_submission,
+                nft_deposit_info: None,
                 extension: Empty::default(),
             })
             .unwrap(),
@@ -62,10 +63,19 @@ pub(crate) fn get_default_token_dao_proposal_module_instantiate(app: &mut App) -
                 denom: dao_voting::deposit::DepositToken::VotingModuleToken {},
                 amount: Uint128::new(10_000_000),
                 refund_policy: DepositRefundPolicy::OnlyPassed,
+                additional: vec![],
+                refund_on_cancel: false,
             }),
             false,
         ),
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
+        require_content_cid: None,
+        abstain_counting: None,
+        execution_delay: None,
+        vote_extension: None,
     }
 }
 
@@ -84,6 +94,13 @@ pub(crate) fn get_default_non_token_dao_proposal_module_instantiate(
         allow_revoting: false,
         pre_propose_info: get_pre_propose_info(app, None, false),
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
+        require_content_cid: None,
+        abstain_counting: None,
+        execution_delay: None,
+        vote_extension: None,
     }
 }
 
@@ -149,6 +166,8 @@ pub(crate) fn instantiate_with_staked_cw721_governance(
                 owner: Some(Admin::CoreModule {}),
                 unstaking_duration: None,
                 nft_address: nft_address.to_string(),
+                ics721: None,
+                active_threshold: None,
             })
             .unwrap(),
             admin: None,
@@ -176,7 +195,13 @@ pub(crate) fn instantiate_with_staked_cw721_governance(
 
     let core_state: dao_core::query::DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(core_addr.clone(), &dao_core::msg::QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr.clone(),
+            &dao_core::msg::QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
     let staking_addr = core_state.voting_module;
 
@@ -291,7 +316,13 @@ pub(crate) fn instantiate_with_native_staked_balances_governance(
 
     let gov_state: dao_core::query::DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(core_addr.clone(), &dao_core::msg::QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr.clone(),
+            &dao_core::msg::QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
     let native_staking_addr = gov_state.voting_module;
 
@@ -407,7 +438,13 @@ pub(crate) fn instantiate_with_staked_balances_governance(
 
     let gov_state: dao_core::query::DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(core_addr.clone(), &dao_core::msg::QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr.clone(),
+            &dao_core::msg::QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
     let voting_module = gov_state.voting_module;
 
@@ -1,14 +1,22 @@
 use cosmwasm_std::{Addr, Uint128};
 use cw_multi_test::App;
+use cw_utils::Expiration;
 use dao_core::state::{ProposalModule, ProposalModuleStatus};
 
 use cw_hooks::HooksResponse;
+use dao_interface::proposal::GenericProposalInfoResponse;
 use dao_pre_propose_single as cppbps;
-use dao_voting::pre_propose::ProposalCreationPolicy;
+use dao_voting::{
+    pre_propose::ProposalCreationPolicy, simulation::SimulationResponse,
+    stats::GovernanceStatsResponse, voting::VoteListOrder,
+};
 
 use crate::{
-    msg::QueryMsg,
-    query::{ProposalListResponse, ProposalResponse, VoteListResponse, VoteResponse},
+    msg::QueryMsgQuerier,
+    query::{
+        BallotListResponse, ExportStateResponse, ProposalListResponse, ProposalResponse,
+        TallySummaryResponse, VerifyTallyResponse, VoteListResponse, VoteResponse,
+    },
     state::Config,
 };
 
@@ -27,15 +35,17 @@ pub(crate) fn query_deposit_config_and_pre_propose_module(
     }
 }
 
+fn querier<'a>(app: &'a App, proposal_single: &Addr) -> QueryMsgQuerier<'a> {
+    QueryMsgQuerier::new(app.wrap(), proposal_single)
+}
+
 pub(crate) fn query_proposal_config(app: &App, proposal_single: &Addr) -> Config {
-    app.wrap()
-        .query_wasm_smart(proposal_single, &QueryMsg::Config {})
-        .unwrap()
+    querier(app, proposal_single).config().unwrap()
 }
 
 pub(crate) fn query_creation_policy(app: &App, proposal_single: &Addr) -> ProposalCreationPolicy {
-    app.wrap()
-        .query_wasm_smart(proposal_single, &QueryMsg::ProposalCreationPolicy {})
+    querier(app, proposal_single)
+        .proposal_creation_policy()
         .unwrap()
 }
 
@@ -45,11 +55,51 @@ pub(crate) fn query_list_proposals(
     start_after: Option<u64>,
     limit: Option<u64>,
 ) -> ProposalListResponse {
-    app.wrap()
-        .query_wasm_smart(
-            proposal_single,
-            &QueryMsg::ListProposals { start_after, limit },
-        )
+    querier(app, proposal_single)
+        .list_proposals(start_after, limit, None)
+        .unwrap()
+}
+
+pub(crate) fn query_list_proposals_by_expiration(
+    app: &App,
+    proposal_single: &Addr,
+    after: Option<Expiration>,
+    before: Option<Expiration>,
+    limit: Option<u64>,
+) -> Vec<u64> {
+    querier(app, proposal_single)
+        .list_proposals_by_expiration(after, before, limit)
+        .unwrap()
+        .proposals
+        .into_iter()
+        .map(|p| p.id)
+        .collect()
+}
+
+pub(crate) fn query_pending_votes(
+    app: &App,
+    proposal_single: &Addr,
+    voter: &str,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> Vec<u64> {
+    querier(app, proposal_single)
+        .pending_votes(voter.to_string(), start_after, limit)
+        .unwrap()
+        .proposals
+        .into_iter()
+        .map(|p| p.id)
+        .collect()
+}
+
+pub(crate) fn query_export_state(
+    app: &App,
+    proposal_single: &Addr,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> ExportStateResponse {
+    querier(app, proposal_single)
+        .export_state(start_after, limit)
         .unwrap()
 }
 
@@ -59,16 +109,22 @@ pub(crate) fn query_list_votes(
     proposal_id: u64,
     start_after: Option<String>,
     limit: Option<u64>,
+    order: Option<VoteListOrder>,
 ) -> VoteListResponse {
-    app.wrap()
-        .query_wasm_smart(
-            proposal_single,
-            &QueryMsg::ListVotes {
-                proposal_id,
-                start_after,
-                limit,
-            },
-        )
+    querier(app, proposal_single)
+        .list_votes(proposal_id, start_after, limit, order)
+        .unwrap()
+}
+
+pub(crate) fn query_list_votes_by_voter(
+    app: &App,
+    proposal_single: &Addr,
+    voter: &str,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> BallotListResponse {
+    querier(app, proposal_single)
+        .list_votes_by_voter(voter.to_string(), start_after, limit)
         .unwrap()
 }
 
@@ -78,27 +134,17 @@ pub(crate) fn query_vote(
     who: &str,
     proposal_id: u64,
 ) -> VoteResponse {
-    app.wrap()
-        .query_wasm_smart(
-            proposal_module,
-            &QueryMsg::GetVote {
-                proposal_id,
-                voter: who.to_string(),
-            },
-        )
+    querier(app, proposal_module)
+        .get_vote(proposal_id, who.to_string())
         .unwrap()
 }
 
 pub(crate) fn query_proposal_hooks(app: &App, proposal_single: &Addr) -> HooksResponse {
-    app.wrap()
-        .query_wasm_smart(proposal_single, &QueryMsg::ProposalHooks {})
-        .unwrap()
+    querier(app, proposal_single).proposal_hooks().unwrap()
 }
 
 pub(crate) fn query_vote_hooks(app: &App, proposal_single: &Addr) -> HooksResponse {
-    app.wrap()
-        .query_wasm_smart(proposal_single, &QueryMsg::VoteHooks {})
-        .unwrap()
+    querier(app, proposal_single).vote_hooks().unwrap()
 }
 
 pub(crate) fn query_list_proposals_reverse(
@@ -107,14 +153,8 @@ pub(crate) fn query_list_proposals_reverse(
     start_before: Option<u64>,
     limit: Option<u64>,
 ) -> ProposalListResponse {
-    app.wrap()
-        .query_wasm_smart(
-            proposal_single,
-            &QueryMsg::ReverseProposals {
-                start_before,
-                limit,
-            },
-        )
+    querier(app, proposal_single)
+        .reverse_proposals(start_before, limit, None)
         .unwrap()
 }
 
@@ -196,13 +236,53 @@ pub(crate) fn query_balance_native(app: &App, who: &str, denom: &str) -> Uint128
 }
 
 pub(crate) fn query_proposal(app: &App, proposal_single: &Addr, id: u64) -> ProposalResponse {
-    app.wrap()
-        .query_wasm_smart(proposal_single, &QueryMsg::Proposal { proposal_id: id })
+    querier(app, proposal_single).proposal(id).unwrap()
+}
+
+pub(crate) fn query_governance_stats(app: &App, proposal_single: &Addr) -> GovernanceStatsResponse {
+    querier(app, proposal_single).governance_stats().unwrap()
+}
+
+pub(crate) fn query_simulate_execution(
+    app: &App,
+    proposal_single: &Addr,
+    proposal_id: u64,
+) -> SimulationResponse {
+    querier(app, proposal_single)
+        .simulate_execution(proposal_id)
         .unwrap()
 }
 
-pub(crate) fn query_next_proposal_id(app: &App, proposal_single: &Addr) -> u64 {
-    app.wrap()
-        .query_wasm_smart(proposal_single, &QueryMsg::NextProposalId {})
+pub(crate) fn query_tally_summary(
+    app: &App,
+    proposal_single: &Addr,
+    proposal_id: u64,
+) -> TallySummaryResponse {
+    querier(app, proposal_single)
+        .tally_summary(proposal_id)
+        .unwrap()
+}
+
+pub(crate) fn query_verify_tally(
+    app: &App,
+    proposal_single: &Addr,
+    proposal_id: u64,
+) -> VerifyTallyResponse {
+    querier(app, proposal_single)
+        .verify_tally(proposal_id)
+        .unwrap()
+}
+
+pub(crate) fn query_generic_proposal_info(
+    app: &App,
+    proposal_single: &Addr,
+    proposal_id: u64,
+) -> GenericProposalInfoResponse {
+    querier(app, proposal_single)
+        .generic_proposal_info(proposal_id)
         .unwrap()
 }
+
+pub(crate) fn query_next_proposal_id(app: &App, proposal_single: &Addr) -> u64 {
+    querier(app, proposal_single).next_proposal_id().unwrap()
+}
@@ -1,5 +1,5 @@
 use cosmwasm_std::{
-    coins,
+    coins, from_binary,
     testing::{mock_dependencies, mock_env},
     to_binary, Addr, Attribute, BankMsg, Binary, ContractInfoResponse, CosmosMsg, Decimal, Empty,
     Reply, StdError, SubMsgResult, Uint128, WasmMsg, WasmQuery,
@@ -9,29 +9,40 @@ use cw20::Cw20Coin;
 use cw_denom::CheckedDenom;
 use cw_hooks::{HookError, HooksResponse};
 use cw_multi_test::{next_block, App, Executor};
-use cw_utils::Duration;
-use dao_interface::{voting::InfoResponse, Admin, ModuleInstantiateInfo};
+use cw_utils::{Duration, Expiration};
+use dao_interface::{
+    proposal::GenericProposalInfoResponse, voting::InfoResponse, Admin, ModuleInstantiateInfo,
+};
 use dao_testing::{ShouldExecute, TestSingleChoiceVote};
 use dao_voting::{
     deposit::{CheckedDepositInfo, UncheckedDepositInfo},
-    pre_propose::{PreProposeInfo, ProposalCreationPolicy},
-    proposal::{SingleChoiceProposeMsg as ProposeMsg, MAX_PROPOSAL_SIZE},
+    error::VotingError,
+    pre_propose::{PreProposeFailure, PreProposeFallback, PreProposeInfo, ProposalCreationPolicy},
+    proposal::{
+        DescriptionHash, MaxOpenProposals, PrunedProposal, RebuildIndexesCursor,
+        SingleChoiceProposeMsg as ProposeMsg, MAX_PROPOSAL_SIZE, MAX_PROPOSAL_SIZE_CEILING,
+    },
     reply::{
         failed_pre_propose_module_hook_id, mask_proposal_execution_proposal_id,
         mask_proposal_hook_index, mask_vote_hook_index,
     },
+    simulation::{ExecutionWarning, SimulationResponse},
+    stats::GovernanceTotals,
     status::Status,
-    threshold::{PercentageThreshold, Threshold},
-    voting::{Vote, Votes},
+    threshold::{AbstainCounting, PercentageThreshold, Threshold},
+    voting::{Vote, VoteListOrder, Votes},
 };
 use dao_voting_cw20_staked::msg::ActiveThreshold;
 
 use crate::{
     contract::{migrate, CONTRACT_NAME, CONTRACT_VERSION},
-    msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
+    msg::{ExecuteMsg, FromV1Msg, InstantiateMsg, MigrateMsg, QueryMsg, QueryMsgQuerier},
     proposal::SingleChoiceProposal,
-    query::{ProposalResponse, VoteInfo},
-    state::Config,
+    query::{
+        BallotInfo, ProposalListResponse, ProposalResponse, QuorumProgress, VerifyTallyResponse,
+        VoteInfo, VotePercentages,
+    },
+    state::{Config, VoteExtension},
     testing::{
         contracts::{
             cw20_base_contract, cw20_stake_contract, cw20_staked_balances_voting_contract,
@@ -40,10 +51,11 @@ use crate::{
         },
         execute::{
             add_proposal_hook, add_proposal_hook_should_fail, add_vote_hook,
-            add_vote_hook_should_fail, close_proposal, close_proposal_should_fail,
-            execute_proposal, execute_proposal_should_fail, instantiate_cw20_base_default,
-            make_proposal, mint_cw20s, mint_natives, remove_proposal_hook,
-            remove_proposal_hook_should_fail, remove_vote_hook, remove_vote_hook_should_fail,
+            add_vote_hook_should_fail, cancel_proposal, cancel_proposal_should_fail,
+            close_proposal, close_proposal_should_fail, execute_proposal,
+            execute_proposal_should_fail, instantiate_cw20_base_default, make_proposal, mint_cw20s,
+            mint_natives, remove_proposal_hook, remove_proposal_hook_should_fail, remove_vote_hook,
+            remove_vote_hook_should_fail, update_proposal, update_proposal_should_fail,
             update_rationale, vote_on_proposal, vote_on_proposal_should_fail,
         },
         instantiate::{
@@ -54,11 +66,13 @@ use crate::{
         },
         queries::{
             query_balance_cw20, query_balance_native, query_creation_policy, query_dao_token,
-            query_deposit_config_and_pre_propose_module, query_list_proposals,
-            query_list_proposals_reverse, query_list_votes, query_pre_proposal_single_config,
+            query_deposit_config_and_pre_propose_module, query_export_state,
+            query_generic_proposal_info, query_governance_stats, query_list_proposals,
+            query_list_proposals_by_expiration, query_list_proposals_reverse, query_list_votes,
+            query_pending_votes, query_pre_proposal_single_config,
             query_pre_proposal_single_deposit_info, query_proposal, query_proposal_config,
-            query_proposal_hooks, query_single_proposal_module, query_vote_hooks,
-            query_voting_module,
+            query_proposal_hooks, query_simulate_execution, query_single_proposal_module,
+            query_tally_summary, query_verify_tally, query_vote_hooks, query_voting_module,
         },
     },
     ContractError,
@@ -67,7 +81,7 @@ use crate::{
 use super::{
     do_votes::do_votes_staked_balances,
     execute::vote_on_proposal_with_rationale,
-    queries::{query_next_proposal_id, query_vote},
+    queries::{query_list_votes_by_voter, query_next_proposal_id, query_vote},
     CREATOR_ADDR,
 };
 
@@ -125,10 +139,17 @@ fn test_simple_propose_staked_balances() {
             threshold: PercentageThreshold::Majority {},
         },
         allow_revoting: false,
+        abstain_counting: AbstainCounting::default(),
+        execution_delay: None,
+        earliest_execution: None,
+        vote_extension: None,
+        max_expiration: None,
         total_power: Uint128::new(100_000_000),
         msgs: vec![],
         status: Status::Open,
         votes: Votes::zero(),
+        description_hash: None,
+        content_cid: None,
     };
 
     assert_eq!(created.proposal, expected);
@@ -144,7 +165,9 @@ fn test_simple_propose_staked_balances() {
         Some(CheckedDepositInfo {
             denom: cw_denom::CheckedDenom::Cw20(gov_token),
             amount: Uint128::new(10_000_000),
-            refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed
+            refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed,
+            additional: vec![],
+            refund_on_cancel: false,
         })
     );
 }
@@ -174,10 +197,17 @@ fn test_simple_proposal_cw4_voting() {
             quorum: PercentageThreshold::Majority {},
         },
         allow_revoting: false,
+        abstain_counting: AbstainCounting::default(),
+        execution_delay: None,
+        earliest_execution: None,
+        vote_extension: None,
+        max_expiration: None,
         total_power: Uint128::new(1),
         msgs: vec![],
         status: Status::Open,
         votes: Votes::zero(),
+        description_hash: None,
+        content_cid: None,
     };
 
     assert_eq!(created.proposal, expected);
@@ -253,6 +283,8 @@ fn test_instantiate_with_non_voting_module_cw20_deposit() {
             },
             amount: Uint128::new(10_000_000),
             refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed,
+            additional: vec![],
+            refund_on_cancel: false,
         }),
         false,
     );
@@ -278,10 +310,17 @@ fn test_instantiate_with_non_voting_module_cw20_deposit() {
             quorum: PercentageThreshold::Majority {},
         },
         allow_revoting: false,
+        abstain_counting: AbstainCounting::default(),
+        execution_delay: None,
+        earliest_execution: None,
+        vote_extension: None,
+        max_expiration: None,
         total_power: Uint128::new(1),
         msgs: vec![],
         status: Status::Open,
         votes: Votes::zero(),
+        description_hash: None,
+        content_cid: None,
     };
 
     assert_eq!(created.proposal, expected);
@@ -297,7 +336,9 @@ fn test_instantiate_with_non_voting_module_cw20_deposit() {
         Some(CheckedDepositInfo {
             denom: cw_denom::CheckedDenom::Cw20(alt_cw20),
             amount: Uint128::new(10_000_000),
-            refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed
+            refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed,
+            additional: vec![],
+            refund_on_cancel: false,
         })
     );
 }
@@ -532,6 +573,13 @@ fn test_update_config() {
                 allow_revoting: false,
                 dao: core_addr.to_string(),
                 close_proposal_on_execution_failure: false,
+                max_proposal_size: None,
+                max_open_proposals: None,
+                pre_propose_fallback: None,
+                require_content_cid: None,
+                abstain_counting: None,
+                execution_delay: None,
+                vote_extension: None,
             })
             .unwrap(),
             funds: vec![],
@@ -560,6 +608,13 @@ fn test_update_config() {
             allow_revoting: false,
             dao: core_addr.clone(),
             close_proposal_on_execution_failure: false,
+            max_proposal_size: MAX_PROPOSAL_SIZE,
+            max_open_proposals: None,
+            pre_propose_fallback: PreProposeFallback::Anyone {},
+            require_content_cid: false,
+            abstain_counting: AbstainCounting::default(),
+            execution_delay: None,
+            vote_extension: None,
         }
     );
 
@@ -578,6 +633,13 @@ fn test_update_config() {
                 allow_revoting: false,
                 dao: core_addr.to_string(),
                 close_proposal_on_execution_failure: false,
+                max_proposal_size: None,
+                max_open_proposals: None,
+                pre_propose_fallback: None,
+                require_content_cid: None,
+                abstain_counting: None,
+                execution_delay: None,
+                vote_extension: None,
             },
             &[],
         )
@@ -587,6 +649,141 @@ fn test_update_config() {
     assert!(matches!(err, ContractError::Unauthorized {}))
 }
 
+#[test]
+fn test_update_config_keeps_pre_propose_fallback() {
+    let mut app = App::default();
+    let instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let update_config = |pre_propose_fallback| ExecuteMsg::UpdateConfig {
+        threshold: Threshold::AbsoluteCount {
+            threshold: Uint128::new(10_000),
+        },
+        max_voting_period: Duration::Height(6),
+        min_voting_period: None,
+        only_members_execute: true,
+        allow_revoting: false,
+        dao: core_addr.to_string(),
+        close_proposal_on_execution_failure: false,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback,
+        require_content_cid: None,
+        abstain_counting: None,
+        execution_delay: None,
+        vote_extension: None,
+    };
+
+    app.execute_contract(
+        core_addr.clone(),
+        proposal_module.clone(),
+        &update_config(Some(PreProposeFallback::Frozen {})),
+        &[],
+    )
+    .unwrap();
+    // Omitting the fallback keeps the current one.
+    app.execute_contract(
+        core_addr.clone(),
+        proposal_module.clone(),
+        &update_config(None),
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        query_proposal_config(&app, &proposal_module).pre_propose_fallback,
+        PreProposeFallback::Frozen {}
+    );
+}
+
+#[test]
+fn test_update_config_keeps_require_content_cid() {
+    let mut app = App::default();
+    let instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let update_config = |require_content_cid| ExecuteMsg::UpdateConfig {
+        threshold: Threshold::AbsoluteCount {
+            threshold: Uint128::new(10_000),
+        },
+        max_voting_period: Duration::Height(6),
+        min_voting_period: None,
+        only_members_execute: true,
+        allow_revoting: false,
+        dao: core_addr.to_string(),
+        close_proposal_on_execution_failure: false,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
+        require_content_cid,
+        abstain_counting: None,
+        execution_delay: None,
+        vote_extension: None,
+    };
+
+    app.execute_contract(
+        core_addr.clone(),
+        proposal_module.clone(),
+        &update_config(Some(true)),
+        &[],
+    )
+    .unwrap();
+    // Omitting the setting keeps the current one.
+    app.execute_contract(
+        core_addr.clone(),
+        proposal_module.clone(),
+        &update_config(None),
+        &[],
+    )
+    .unwrap();
+    assert!(query_proposal_config(&app, &proposal_module).require_content_cid);
+}
+
+#[test]
+fn test_update_config_keeps_abstain_counting() {
+    let mut app = App::default();
+    let instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let update_config = |abstain_counting| ExecuteMsg::UpdateConfig {
+        threshold: Threshold::AbsoluteCount {
+            threshold: Uint128::new(10_000),
+        },
+        max_voting_period: Duration::Height(6),
+        min_voting_period: None,
+        only_members_execute: true,
+        allow_revoting: false,
+        dao: core_addr.to_string(),
+        close_proposal_on_execution_failure: false,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
+        require_content_cid: None,
+        abstain_counting,
+        execution_delay: None,
+        vote_extension: None,
+    };
+
+    app.execute_contract(
+        core_addr.clone(),
+        proposal_module.clone(),
+        &update_config(Some(AbstainCounting::Neither {})),
+        &[],
+    )
+    .unwrap();
+    // Omitting the setting keeps the current one.
+    app.execute_contract(
+        core_addr.clone(),
+        proposal_module.clone(),
+        &update_config(None),
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        query_proposal_config(&app, &proposal_module).abstain_counting,
+        AbstainCounting::Neither {}
+    );
+}
+
 #[test]
 fn test_anyone_may_propose_and_proposal_listing() {
     let mut app = App::default();
@@ -655,6 +852,11 @@ fn test_anyone_may_propose_and_proposal_listing() {
                     threshold: PercentageThreshold::Majority {},
                 },
                 allow_revoting: false,
+                abstain_counting: AbstainCounting::default(),
+                execution_delay: None,
+                earliest_execution: None,
+                vote_extension: None,
+                max_expiration: None,
                 total_power: Uint128::new(100_000_000),
                 msgs: vec![],
                 status: Status::Executed,
@@ -663,6 +865,8 @@ fn test_anyone_may_propose_and_proposal_listing() {
                     no: Uint128::zero(),
                     abstain: Uint128::zero()
                 },
+                description_hash: None,
+                content_cid: None,
             }
         }
     )
@@ -823,6 +1027,9 @@ fn test_active_threshold_absolute() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: None,
+                description_hash: None,
+                content_cid: None,
+                disable_revoting: false,
             }),
             &[],
         )
@@ -847,6 +1054,7 @@ fn test_active_threshold_absolute() {
     // Unstake some tokens to make it inactive again.
     let msg = cw20_stake::msg::ExecuteMsg::Unstake {
         amount: Uint128::new(50),
+        auto_claim: false,
     };
     app.execute_contract(Addr::unchecked(CREATOR_ADDR), staking_contract, &msg, &[])
         .unwrap();
@@ -861,6 +1069,9 @@ fn test_active_threshold_absolute() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: None,
+                description_hash: None,
+                content_cid: None,
+                disable_revoting: false,
             }),
             &[],
         )
@@ -904,6 +1115,9 @@ fn test_active_threshold_percent() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: None,
+                description_hash: None,
+                content_cid: None,
+                disable_revoting: false,
             }),
             &[],
         )
@@ -928,7 +1142,8 @@ fn test_active_threshold_percent() {
     // Unstake some tokens to make it inactive again.
     let msg = cw20_stake::msg::ExecuteMsg::Unstake {
         amount: Uint128::new(1), // Only one is needed as we're right
-                                 // on the edge. :)
+        // on the edge. :)
+        auto_claim: false,
     };
     app.execute_contract(Addr::unchecked(CREATOR_ADDR), staking_contract, &msg, &[])
         .unwrap();
@@ -943,6 +1158,9 @@ fn test_active_threshold_percent() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: None,
+                description_hash: None,
+                content_cid: None,
+                disable_revoting: false,
             }),
             &[],
         )
@@ -1140,6 +1358,13 @@ fn test_allow_revoting_config_changes() {
             allow_revoting: false,
             dao: core_addr.to_string(),
             close_proposal_on_execution_failure: false,
+            max_proposal_size: None,
+            max_open_proposals: None,
+            pre_propose_fallback: None,
+            require_content_cid: None,
+            abstain_counting: None,
+            execution_delay: None,
+            vote_extension: None,
         },
         &[],
     )
@@ -1222,7 +1447,13 @@ fn test_three_of_five_multisig() {
 
     let core_state: dao_core::query::DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(core_addr, &dao_core::msg::QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr,
+            &dao_core::msg::QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
     let proposal_module = core_state
         .proposal_modules
@@ -1266,6 +1497,196 @@ fn test_three_of_five_multisig() {
     assert_eq!(proposal.proposal.status, Status::Closed);
 }
 
+#[test]
+fn test_disable_revoting_on_proposal() {
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    instantiate.allow_revoting = true;
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        proposal_module.clone(),
+        &ExecuteMsg::Propose(ProposeMsg {
+            title: "title".to_string(),
+            description: "description".to_string(),
+            msgs: vec![],
+            proposer: None,
+            description_hash: None,
+            content_cid: None,
+            disable_revoting: true,
+        }),
+        &[],
+    )
+    .unwrap();
+
+    // Revoting is enabled in the config but was disabled by the
+    // proposer.
+    let proposal = query_proposal(&app, &proposal_module, 1).proposal;
+    assert!(!proposal.allow_revoting);
+
+    vote_on_proposal(&mut app, &proposal_module, CREATOR_ADDR, 1, Vote::Yes);
+    let err = vote_on_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, 1, Vote::No);
+    assert!(matches!(err, ContractError::AlreadyVoted {}));
+}
+
+#[test]
+fn test_execution_delay() {
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.execution_delay = Some(Duration::Time(100));
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let gov_token = query_dao_token(&app, &core_addr);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 10_000_000);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.status, Status::Passed);
+    let earliest = Expiration::AtTime(app.block_info().time.plus_seconds(100));
+    assert_eq!(proposal.earliest_execution, Some(earliest));
+
+    let err = execute_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert!(matches!(err, ContractError::ExecutionDelayed { earliest: e } if e == earliest));
+
+    app.update_block(|mut b| b.time = b.time.plus_seconds(100));
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.status, Status::Executed);
+}
+
+#[test]
+fn test_vote_extension() {
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.allow_revoting = true;
+    instantiate.vote_extension = Some(VoteExtension {
+        window: Duration::Time(3600),
+        extension: Duration::Time(3600),
+        max_extension: Duration::Time(5400),
+    });
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let gov_token = query_dao_token(&app, &core_addr);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 10_000_000);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    let start = app.block_info().time;
+    let expiration = Expiration::AtTime(start.plus_seconds(604800));
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.expiration, expiration);
+    assert_eq!(
+        proposal.max_expiration,
+        Some(Expiration::AtTime(start.plus_seconds(604800 + 5400)))
+    );
+
+    // Votes cast outside of the window do not extend the proposal.
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.expiration, expiration);
+
+    // A late vote which changes the outcome extends the proposal.
+    app.update_block(|mut b| b.time = start.plus_seconds(604800 - 1800));
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::No,
+    );
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(
+        proposal.expiration,
+        Expiration::AtTime(start.plus_seconds(604800 + 3600))
+    );
+
+    // Late votes which do not change the outcome do not extend the
+    // proposal.
+    app.update_block(|mut b| b.time = start.plus_seconds(604800 + 1800));
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Abstain,
+    );
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(
+        proposal.expiration,
+        Expiration::AtTime(start.plus_seconds(604800 + 3600))
+    );
+
+    // Extensions are bounded by the max extension.
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.expiration, proposal.max_expiration.unwrap());
+
+    app.update_block(|mut b| b.time = start.plus_seconds(604800 + 5400));
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.status, Status::Passed);
+}
+
+#[test]
+fn test_execution_delay_units_conflict() {
+    let mut app = App::default();
+    let instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let config = query_proposal_config(&app, &proposal_module);
+
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            proposal_module,
+            &ExecuteMsg::UpdateConfig {
+                threshold: config.threshold,
+                max_voting_period: config.max_voting_period,
+                min_voting_period: None,
+                only_members_execute: true,
+                allow_revoting: false,
+                dao: core_addr.to_string(),
+                close_proposal_on_execution_failure: true,
+                max_proposal_size: None,
+                max_open_proposals: None,
+                pre_propose_fallback: None,
+                require_content_cid: None,
+                abstain_counting: None,
+                execution_delay: Some(Duration::Height(10)),
+                vote_extension: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::VotingError(VotingError::ExecutionDelayUnitsConflict {})
+    ));
+}
+
 #[test]
 fn test_three_of_five_multisig_revoting() {
     let mut app = App::default();
@@ -1304,7 +1725,13 @@ fn test_three_of_five_multisig_revoting() {
 
     let core_state: dao_core::query::DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(core_addr, &dao_core::msg::QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr,
+            &dao_core::msg::QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
     let proposal_module = core_state
         .proposal_modules
@@ -1442,6 +1869,13 @@ fn test_proposal_count_initialized_to_zero() {
             allow_revoting: false,
             pre_propose_info,
             close_proposal_on_execution_failure: true,
+            max_proposal_size: None,
+            max_open_proposals: None,
+            pre_propose_fallback: None,
+            require_content_cid: None,
+            abstain_counting: None,
+            execution_delay: None,
+            vote_extension: None,
         },
         Some(vec![
             Cw20Coin {
@@ -1457,7 +1891,13 @@ fn test_proposal_count_initialized_to_zero() {
 
     let core_state: dao_core::query::DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(core_addr, &dao_core::msg::QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr,
+            &dao_core::msg::QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
     let proposal_modules = core_state.proposal_modules;
 
@@ -1594,7 +2034,13 @@ fn test_migrate_from_v1() {
 
     let core_state: dao_core::query::DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(core_addr.clone(), &dao_core::msg::QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr.clone(),
+            &dao_core::msg::QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
     let voting_module = core_state.voting_module;
 
@@ -1664,7 +2110,7 @@ fn test_migrate_from_v1() {
 
     // Attempt to migrate. This will fail as there is a pending
     // proposal.
-    let migrate_msg = MigrateMsg::FromV1 {
+    let migrate_msg = MigrateMsg::FromV1(FromV1Msg {
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::ModuleMayPropose {
             info: ModuleInstantiateInfo {
@@ -1674,8 +2120,11 @@ fn test_migrate_from_v1() {
                         denom: dao_voting::deposit::DepositToken::VotingModuleToken {},
                         amount: Uint128::new(1),
                         refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed,
+                        additional: vec![],
+                        refund_on_cancel: false,
                     }),
                     open_proposal_submission: false,
+                    nft_deposit_info: None,
                     extension: Empty::default(),
                 })
                 .unwrap(),
@@ -1683,7 +2132,7 @@ fn test_migrate_from_v1() {
                 label: "DAO DAO pre-propose".to_string(),
             },
         },
-    };
+    });
     let err: ContractError = app
         .execute(
             core_addr.clone(),
@@ -1726,6 +2175,13 @@ fn test_migrate_from_v1() {
             allow_revoting: false,
             dao: core_addr.clone(),
             close_proposal_on_execution_failure: true,
+            max_proposal_size: MAX_PROPOSAL_SIZE,
+            max_open_proposals: None,
+            pre_propose_fallback: PreProposeFallback::Anyone {},
+            require_content_cid: false,
+            abstain_counting: AbstainCounting::default(),
+            execution_delay: None,
+            vote_extension: None,
         }
     );
 
@@ -1742,22 +2198,89 @@ fn test_migrate_from_v1() {
         }
     );
 
+    // Ballots cast before the migration are not indexed by voter
+    // until the DAO rebuilds the indexes.
+    let ballots = query_list_votes_by_voter(&app, &proposal_module, CREATOR_ADDR, None, None);
+    assert!(ballots.ballots.is_empty());
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::RebuildIndexes {
+                start_after: None,
+                limit: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    // Rebuild one entry at a time: the proposal, then its ballot,
+    // then an empty page.
+    let mut start_after = None;
+    let mut cursors = vec![];
+    loop {
+        let res = app
+            .execute_contract(
+                core_addr.clone(),
+                proposal_module.clone(),
+                &ExecuteMsg::RebuildIndexes {
+                    start_after,
+                    limit: Some(1),
+                },
+                &[],
+            )
+            .unwrap();
+        start_after = from_binary(&res.data.unwrap()).unwrap();
+        match &start_after {
+            Some(cursor) => cursors.push(cursor.clone()),
+            None => break,
+        }
+    }
+    assert_eq!(
+        cursors,
+        vec![
+            RebuildIndexesCursor::Proposal { id: 1 },
+            RebuildIndexesCursor::Ballot {
+                proposal_id: 1,
+                voter: CREATOR_ADDR.to_string()
+            }
+        ]
+    );
+
+    let ballots = query_list_votes_by_voter(&app, &proposal_module, CREATOR_ADDR, None, None);
+    assert_eq!(
+        ballots.ballots,
+        vec![BallotInfo {
+            proposal_id: 1,
+            vote: Vote::No,
+            power: Uint128::new(100),
+            rationale: None,
+        }]
+    );
+
     let proposal_creation_policy = query_creation_policy(&app, &proposal_module);
 
     // Check that a new creation policy has been birthed.
     let pre_propose = match proposal_creation_policy {
-        ProposalCreationPolicy::Anyone {} => panic!("expected a pre-propose module"),
         ProposalCreationPolicy::Module { addr } => addr,
+        _ => panic!("expected a pre-propose module"),
     };
     let pre_propose_config = query_pre_proposal_single_config(&app, &pre_propose);
     assert_eq!(
         pre_propose_config,
         cppbps::Config {
             open_proposal_submission: false,
+            nft_deposit_info: None,
             deposit_info: Some(CheckedDepositInfo {
                 denom: CheckedDenom::Cw20(token_contract.clone()),
                 amount: Uint128::new(1),
                 refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed,
+                additional: vec![],
+                refund_on_cancel: false,
             })
         }
     );
@@ -1844,6 +2367,13 @@ fn test_execution_failed() {
             dao: config.dao.into_string(),
             // Disable.
             close_proposal_on_execution_failure: false,
+            max_proposal_size: None,
+            max_open_proposals: None,
+            pre_propose_fallback: None,
+            require_content_cid: None,
+            abstain_counting: None,
+            execution_delay: None,
+            vote_extension: None,
         },
         &[],
     )
@@ -1883,13 +2413,13 @@ fn test_execution_failed() {
 #[test]
 fn test_reply_proposal_mock() {
     use crate::contract::reply;
-    use crate::state::PROPOSALS;
+    use crate::state::proposals;
 
     let mut deps = mock_dependencies();
     let env = mock_env();
 
     let m_proposal_id = mask_proposal_execution_proposal_id(1);
-    PROPOSALS
+    proposals()
         .save(
             deps.as_mut().storage,
             1,
@@ -1904,10 +2434,17 @@ fn test_reply_proposal_mock() {
                     percentage: PercentageThreshold::Majority {},
                 },
                 allow_revoting: false,
+                abstain_counting: AbstainCounting::default(),
+                execution_delay: None,
+                earliest_execution: None,
+                vote_extension: None,
+                max_expiration: None,
                 total_power: Uint128::new(100_000_000),
                 msgs: vec![],
                 status: Status::Open,
                 votes: Votes::zero(),
+                description_hash: None,
+                content_cid: None,
             },
         )
         .unwrap();
@@ -1926,7 +2463,7 @@ fn test_reply_proposal_mock() {
         }
     );
 
-    let prop = PROPOSALS.load(deps.as_mut().storage, 1).unwrap();
+    let prop = proposals().load(deps.as_mut().storage, 1).unwrap();
     assert_eq!(prop.status, Status::ExecutionFailed);
 }
 
@@ -1947,6 +2484,9 @@ fn test_proposal_too_large() {
                 description: "a".repeat(MAX_PROPOSAL_SIZE as usize),
                 msgs: vec![],
                 proposer: None,
+                description_hash: None,
+                content_cid: None,
+                disable_revoting: false,
             }),
             &[],
         )
@@ -1964,18 +2504,272 @@ fn test_proposal_too_large() {
 }
 
 #[test]
-fn test_vote_not_registered() {
-    let CommonTest {
-        mut app,
-        core_addr: _,
-        proposal_module,
-        gov_token: _,
-        proposal_id,
-    } = setup_test(vec![]);
-
-    let err =
-        vote_on_proposal_should_fail(&mut app, &proposal_module, "ekez", proposal_id, Vote::Yes);
-    assert!(matches!(err, ContractError::NotRegistered {}))
+fn test_proposal_description_hash() {
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    // A description far larger than the maximum proposal size may be
+    // committed to.
+    let description = "a".repeat(MAX_PROPOSAL_SIZE as usize * 2);
+    let description_hash = DescriptionHash::new(&description, "ipfs://description");
+
+    // The description may not be stored on chain as well.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Propose(ProposeMsg {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs: vec![],
+                proposer: None,
+                description_hash: Some(description_hash.clone()),
+                content_cid: None,
+                disable_revoting: false,
+            }),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::VotingError(VotingError::DescriptionWithHash {})
+    ));
+
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        proposal_module.clone(),
+        &ExecuteMsg::Propose(ProposeMsg {
+            title: "title".to_string(),
+            description: "".to_string(),
+            msgs: vec![],
+            proposer: None,
+            description_hash: Some(description_hash.clone()),
+            content_cid: None,
+            disable_revoting: false,
+        }),
+        &[],
+    )
+    .unwrap();
+
+    let proposal = query_proposal(&app, &proposal_module, 1).proposal;
+    assert_eq!(proposal.description, "");
+    assert_eq!(proposal.description_hash, Some(description_hash));
+    assert!(proposal.description_hash.unwrap().verify(&description));
+}
+
+#[test]
+fn test_proposal_content_cid() {
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    instantiate.require_content_cid = Some(true);
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let propose = |app: &mut App, content_cid: Option<&str>| {
+        app.execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Propose(ProposeMsg {
+                title: "title".to_string(),
+                description: "summary".to_string(),
+                msgs: vec![],
+                proposer: None,
+                description_hash: None,
+                content_cid: content_cid.map(str::to_string),
+                disable_revoting: false,
+            }),
+            &[],
+        )
+    };
+
+    let err: ContractError = propose(&mut app, None).unwrap_err().downcast().unwrap();
+    assert!(matches!(
+        err,
+        ContractError::VotingError(VotingError::ContentCidRequired {})
+    ));
+    let err: ContractError = propose(&mut app, Some("ipfs://not-a-cid"))
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::VotingError(VotingError::InvalidContentCid { .. })
+    ));
+
+    let cid = "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi";
+    let res = propose(&mut app, Some(cid)).unwrap();
+    assert!(res.events.iter().any(|e| e
+        .attributes
+        .iter()
+        .any(|a| a.key == "content_cid" && a.value == cid)));
+    let proposal = query_proposal(&app, &proposal_module, 1).proposal;
+    assert_eq!(proposal.content_cid.as_deref(), Some(cid));
+}
+
+#[test]
+fn test_max_open_proposals() {
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    instantiate.max_open_proposals = Some(MaxOpenProposals {
+        total: Some(2),
+        per_proposer: Some(1),
+    });
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let propose = |app: &mut App, proposer: &str| {
+        app.execute_contract(
+            Addr::unchecked(proposer),
+            proposal_module.clone(),
+            &ExecuteMsg::Propose(ProposeMsg {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs: vec![],
+                proposer: None,
+                description_hash: None,
+                content_cid: None,
+                disable_revoting: false,
+            }),
+            &[],
+        )
+    };
+
+    propose(&mut app, "ekez").unwrap();
+    let err: ContractError = propose(&mut app, "ekez").unwrap_err().downcast().unwrap();
+    assert!(matches!(
+        err,
+        ContractError::VotingError(VotingError::TooManyOpenProposalsByProposer { max: 1 })
+    ));
+
+    propose(&mut app, "keze").unwrap();
+    let err: ContractError = propose(&mut app, "zeke").unwrap_err().downcast().unwrap();
+    assert!(matches!(
+        err,
+        ContractError::VotingError(VotingError::TooManyOpenProposals { max: 2 })
+    ));
+
+    // Once the open proposals are rejected, which happens lazily when
+    // they expire, new proposals may be created.
+    app.update_block(|mut b| b.time = b.time.plus_seconds(604800));
+    propose(&mut app, "ekez").unwrap();
+    propose(&mut app, "zeke").unwrap();
+}
+
+#[test]
+fn test_configured_max_proposal_size() {
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    instantiate.max_proposal_size = Some(1_000);
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let config = query_proposal_config(&app, &proposal_module);
+    assert_eq!(config.max_proposal_size, 1_000);
+
+    let propose = ExecuteMsg::Propose(ProposeMsg {
+        title: "".to_string(),
+        description: "a".repeat(2_000),
+        msgs: vec![],
+        proposer: None,
+        description_hash: None,
+        content_cid: None,
+        disable_revoting: false,
+    });
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &propose,
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::ProposalTooLarge {
+            size: _,
+            max: 1_000
+        }
+    ));
+
+    let update_config = |max_proposal_size| ExecuteMsg::UpdateConfig {
+        threshold: config.threshold.clone(),
+        max_voting_period: config.max_voting_period,
+        min_voting_period: config.min_voting_period,
+        only_members_execute: config.only_members_execute,
+        allow_revoting: config.allow_revoting,
+        dao: config.dao.to_string(),
+        close_proposal_on_execution_failure: config.close_proposal_on_execution_failure,
+        max_proposal_size,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
+        require_content_cid: None,
+        abstain_counting: None,
+        execution_delay: None,
+        vote_extension: None,
+    };
+
+    // The size may not exceed the ceiling.
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            proposal_module.clone(),
+            &update_config(Some(MAX_PROPOSAL_SIZE_CEILING + 1)),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::VotingError(VotingError::InvalidMaxProposalSize {
+            ceiling: MAX_PROPOSAL_SIZE_CEILING
+        })
+    ));
+
+    // Not setting a size restores the default.
+    app.execute_contract(
+        core_addr,
+        proposal_module.clone(),
+        &update_config(None),
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        query_proposal_config(&app, &proposal_module).max_proposal_size,
+        MAX_PROPOSAL_SIZE
+    );
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        proposal_module,
+        &propose,
+        &[],
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_vote_not_registered() {
+    let CommonTest {
+        mut app,
+        core_addr: _,
+        proposal_module,
+        gov_token: _,
+        proposal_id,
+    } = setup_test(vec![]);
+
+    let err =
+        vote_on_proposal_should_fail(&mut app, &proposal_module, "ekez", proposal_id, Vote::Yes);
+    assert!(matches!(err, ContractError::NotRegistered {}))
 }
 
 #[test]
@@ -1998,6 +2792,9 @@ fn test_proposal_creation_permissions() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: None,
+                description_hash: None,
+                content_cid: None,
+                disable_revoting: false,
             }),
             &[],
         )
@@ -2008,8 +2805,8 @@ fn test_proposal_creation_permissions() {
 
     let proposal_creation_policy = query_creation_policy(&app, &proposal_module);
     let pre_propose = match proposal_creation_policy {
-        ProposalCreationPolicy::Anyone {} => panic!("expected a pre-propose module"),
         ProposalCreationPolicy::Module { addr } => addr,
+        _ => panic!("expected a pre-propose module"),
     };
 
     // Proposer may not be none when a pre-propose module is making
@@ -2023,6 +2820,9 @@ fn test_proposal_creation_permissions() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: None,
+                description_hash: None,
+                content_cid: None,
+                disable_revoting: false,
             }),
             &[],
         )
@@ -2053,6 +2853,9 @@ fn test_proposal_creation_permissions() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: Some("ekez".to_string()),
+                description_hash: None,
+                content_cid: None,
+                disable_revoting: false,
             }),
             &[],
         )
@@ -2077,13 +2880,15 @@ fn test_proposal_creation_permissions() {
 
 #[test]
 fn test_reply_hooks_mock() {
-    use crate::contract::reply;
-    use crate::state::{CREATION_POLICY, PROPOSAL_HOOKS, VOTE_HOOKS};
+    use crate::contract::{query, reply, HOOK_RETRIES};
+    use crate::state::{CONFIG, CREATION_POLICY, PROPOSAL_HOOKS, VOTE_HOOKS};
+    use cosmwasm_std::from_binary;
+    use dao_voting::reply::{HookFailureRecord, HookKind};
 
     let mut deps = mock_dependencies();
     let env = mock_env();
 
-    // Add a proposal hook and remove it
+    // Add a proposal hook and fail it until it is removed.
     let m_proposal_hook_idx = mask_proposal_hook_index(0);
     PROPOSAL_HOOKS
         .add_hook(deps.as_mut().storage, Addr::unchecked(CREATOR_ADDR))
@@ -2094,6 +2899,19 @@ fn test_reply_hooks_mock() {
         result: SubMsgResult::Err("error_msg".to_string()),
     };
 
+    for failures in 1..=HOOK_RETRIES {
+        let res = reply(deps.as_mut(), env.clone(), reply_msg.clone()).unwrap();
+        assert_eq!(
+            res.attributes,
+            vec![
+                Attribute::new("failed_proposal_hook", format!("{CREATOR_ADDR}:0")),
+                Attribute::new("failures", failures.to_string()),
+            ]
+        );
+    }
+    let failures = PROPOSAL_HOOKS.query_failures(deps.as_ref()).unwrap();
+    assert_eq!(failures.failures[0].failures, HOOK_RETRIES);
+
     let res = reply(deps.as_mut(), env.clone(), reply_msg).unwrap();
     assert_eq!(
         res.attributes[0],
@@ -2103,7 +2921,30 @@ fn test_reply_hooks_mock() {
         }
     );
 
-    // Reply needs a creation policy in state.
+    // Reply needs a config and creation policy in state.
+    CONFIG
+        .save(
+            deps.as_mut().storage,
+            &Config {
+                threshold: Threshold::AbsoluteCount {
+                    threshold: Uint128::new(1),
+                },
+                max_voting_period: Duration::Height(6),
+                min_voting_period: None,
+                only_members_execute: false,
+                allow_revoting: false,
+                dao: Addr::unchecked(CREATOR_ADDR),
+                close_proposal_on_execution_failure: false,
+                max_proposal_size: MAX_PROPOSAL_SIZE,
+                max_open_proposals: None,
+                pre_propose_fallback: PreProposeFallback::Anyone {},
+                require_content_cid: false,
+                abstain_counting: AbstainCounting::default(),
+                execution_delay: None,
+                vote_extension: None,
+            },
+        )
+        .unwrap();
     CREATION_POLICY
         .save(
             deps.as_mut().storage,
@@ -2147,7 +2988,11 @@ fn test_reply_hooks_mock() {
         id: m_vote_hook_idx,
         result: SubMsgResult::Err("error_msg".to_string()),
     };
-    let res = reply(deps.as_mut(), env, reply_msg).unwrap();
+    for _ in 0..HOOK_RETRIES {
+        let res = reply(deps.as_mut(), env.clone(), reply_msg.clone()).unwrap();
+        assert_eq!(res.attributes[0].key, "failed_vote_hook");
+    }
+    let res = reply(deps.as_mut(), env.clone(), reply_msg).unwrap();
     assert_eq!(
         res.attributes[0],
         Attribute {
@@ -2155,6 +3000,114 @@ fn test_reply_hooks_mock() {
             value: format! {"{CREATOR_ADDR}:{}", 0}
         }
     );
+
+    // Every failure, including those that removed a hook, is in the
+    // hook failure history.
+    let history: Vec<HookFailureRecord> =
+        from_binary(&query(deps.as_ref(), env.clone(), QueryMsg::HookFailures {}).unwrap())
+            .unwrap();
+    let retries = HOOK_RETRIES as usize;
+    assert_eq!(history.len(), 2 * (retries + 1) + 1);
+    assert_eq!(
+        history[retries],
+        HookFailureRecord {
+            kind: HookKind::Proposal,
+            hook: Addr::unchecked(CREATOR_ADDR),
+            reason: "error_msg".to_string(),
+            height: env.block.height,
+            reply_id: m_proposal_hook_idx,
+            removed: true,
+        }
+    );
+    assert!(!history[0].removed);
+    assert_eq!(history[retries + 1].kind, HookKind::PreProposeModule);
+    assert_eq!(history[retries + 1].hook, Addr::unchecked("ekez"));
+    assert_eq!(history.last().unwrap().kind, HookKind::Vote);
+    assert!(history.last().unwrap().removed);
+}
+
+#[test]
+fn test_pre_propose_fallback_mock() {
+    use crate::contract::reply;
+    use crate::state::{CONFIG, CREATION_POLICY, PRE_PROPOSE_FAILURE};
+
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let dao = Addr::unchecked("dao");
+    let module = Addr::unchecked("pre_propose");
+
+    let reply_msg = Reply {
+        id: failed_pre_propose_module_hook_id(),
+        result: SubMsgResult::Err("error_msg".to_string()),
+    };
+
+    for (fallback, expected) in [
+        (
+            PreProposeFallback::Anyone {},
+            ProposalCreationPolicy::Anyone {},
+        ),
+        (
+            PreProposeFallback::DaoOnly {},
+            ProposalCreationPolicy::Dao { addr: dao.clone() },
+        ),
+        (
+            PreProposeFallback::Frozen {},
+            ProposalCreationPolicy::Frozen {},
+        ),
+    ] {
+        CONFIG
+            .save(
+                deps.as_mut().storage,
+                &Config {
+                    threshold: Threshold::AbsoluteCount {
+                        threshold: Uint128::new(1),
+                    },
+                    max_voting_period: Duration::Height(6),
+                    min_voting_period: None,
+                    only_members_execute: false,
+                    allow_revoting: false,
+                    dao: dao.clone(),
+                    close_proposal_on_execution_failure: false,
+                    max_proposal_size: MAX_PROPOSAL_SIZE,
+                    max_open_proposals: None,
+                    pre_propose_fallback: fallback.clone(),
+                    require_content_cid: false,
+                    abstain_counting: AbstainCounting::default(),
+                    execution_delay: None,
+                    vote_extension: None,
+                },
+            )
+            .unwrap();
+        CREATION_POLICY
+            .save(
+                deps.as_mut().storage,
+                &ProposalCreationPolicy::Module {
+                    addr: module.clone(),
+                },
+            )
+            .unwrap();
+
+        let res = reply(deps.as_mut(), env.clone(), reply_msg.clone()).unwrap();
+        assert_eq!(
+            res.events[0].attributes.last().unwrap(),
+            &Attribute::new("fallback", fallback.to_string())
+        );
+
+        let policy = CREATION_POLICY.load(deps.as_ref().storage).unwrap();
+        assert_eq!(policy, expected);
+        assert_eq!(
+            PRE_PROPOSE_FAILURE.load(deps.as_ref().storage).unwrap(),
+            PreProposeFailure {
+                module: module.clone(),
+                height: env.block.height,
+                fallback,
+            }
+        );
+    }
+
+    // Once fallen back there is no pre-propose module to fail.
+    let err = reply(deps.as_mut(), env, reply_msg).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidReplyID { .. }));
 }
 
 #[test]
@@ -2220,7 +3173,7 @@ fn test_query_list_votes() {
     let proposal_module = query_single_proposal_module(&app, &core_addr);
     let proposal_id = make_proposal(&mut app, &proposal_module, "one", vec![]);
 
-    let votes = query_list_votes(&app, &proposal_module, proposal_id, None, None);
+    let votes = query_list_votes(&app, &proposal_module, proposal_id, None, None, None);
     assert_eq!(votes.votes, vec![]);
 
     vote_on_proposal(&mut app, &proposal_module, "two", proposal_id, Vote::No);
@@ -2229,7 +3182,7 @@ fn test_query_list_votes() {
     vote_on_proposal(&mut app, &proposal_module, "four", proposal_id, Vote::Yes);
     vote_on_proposal(&mut app, &proposal_module, "five", proposal_id, Vote::Yes);
 
-    let votes = query_list_votes(&app, &proposal_module, proposal_id, None, None);
+    let votes = query_list_votes(&app, &proposal_module, proposal_id, None, None, None);
     assert_eq!(
         votes.votes,
         vec![
@@ -2272,6 +3225,7 @@ fn test_query_list_votes() {
         proposal_id,
         Some("four".to_string()),
         Some(2),
+        None,
     );
     assert_eq!(
         votes.votes,
@@ -2293,135 +3247,1468 @@ fn test_query_list_votes() {
 }
 
 #[test]
-fn test_update_pre_propose_module() {
-    let CommonTest {
-        mut app,
-        core_addr,
-        proposal_module,
-        gov_token,
-        proposal_id: pre_update_proposal_id,
-    } = setup_test(vec![]);
-
-    // Store the address of the pre-propose module that we start with
-    // so we can execute withdraw on it later.
-    let proposal_creation_policy = query_creation_policy(&app, &proposal_module);
-    let pre_propose_start = match proposal_creation_policy {
-        ProposalCreationPolicy::Anyone {} => panic!("expected a pre-propose module"),
-        ProposalCreationPolicy::Module { addr } => addr,
+fn test_query_list_votes_ordered() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.threshold = Threshold::AbsoluteCount {
+        threshold: Uint128::new(100),
     };
-
-    let pre_propose_id = app.store_code(pre_propose_single_contract());
-
-    // Make a proposal to switch to a new pre-propose moudle.
-    mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 10_000_000);
-    let proposal_id = make_proposal(
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(
         &mut app,
-        &proposal_module,
-        CREATOR_ADDR,
-        vec![WasmMsg::Execute {
-            contract_addr: proposal_module.to_string(),
-            msg: to_binary(&ExecuteMsg::UpdatePreProposeInfo {
-                info: PreProposeInfo::ModuleMayPropose {
-                    info: ModuleInstantiateInfo {
-                        code_id: pre_propose_id,
-                        msg: to_binary(&dao_pre_propose_single::InstantiateMsg {
-                            deposit_info: Some(UncheckedDepositInfo {
-                                denom: dao_voting::deposit::DepositToken::VotingModuleToken {},
-                                amount: Uint128::new(1),
-                                refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed,
-                            }),
-                            open_proposal_submission: false,
-                            extension: Empty::default(),
-                        })
-                        .unwrap(),
-                        admin: Some(Admin::CoreModule {}),
-                        label: "new pre-propose module".to_string(),
-                    },
-                },
-            })
-            .unwrap(),
-            funds: vec![],
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: "a".to_string(),
+                amount: Uint128::new(3),
+            },
+            Cw20Coin {
+                address: "b".to_string(),
+                amount: Uint128::new(1),
+            },
+            Cw20Coin {
+                address: "c".to_string(),
+                amount: Uint128::new(3),
+            },
+            Cw20Coin {
+                address: "d".to_string(),
+                amount: Uint128::new(2),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let proposal_id = make_proposal(&mut app, &proposal_module, "a", vec![]);
+    for voter in ["a", "b", "c", "d"] {
+        vote_on_proposal(&mut app, &proposal_module, voter, proposal_id, Vote::Yes);
+    }
+
+    let voters = |order: VoteListOrder, start_after: Option<&str>, limit: Option<u64>| {
+        query_list_votes(
+            &app,
+            &proposal_module,
+            proposal_id,
+            start_after.map(str::to_string),
+            limit,
+            Some(order),
+        )
+        .votes
+        .into_iter()
+        .map(|vote| vote.voter.into_string())
+        .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        voters(VoteListOrder::VoterAscending, None, None),
+        ["a", "b", "c", "d"]
+    );
+    assert_eq!(
+        voters(VoteListOrder::VoterDescending, None, None),
+        ["d", "c", "b", "a"]
+    );
+    assert_eq!(
+        voters(VoteListOrder::VoterDescending, Some("c"), None),
+        ["b", "a"]
+    );
+    assert_eq!(
+        voters(VoteListOrder::PowerAscending, None, None),
+        ["b", "d", "a", "c"]
+    );
+    assert_eq!(
+        voters(VoteListOrder::PowerDescending, None, None),
+        ["c", "a", "d", "b"]
+    );
+
+    // Paginating by power continues from the ballot of the last
+    // voter, including voters with the same power.
+    assert_eq!(voters(VoteListOrder::PowerDescending, None, Some(1)), ["c"]);
+    assert_eq!(
+        voters(VoteListOrder::PowerDescending, Some("c"), Some(2)),
+        ["a", "d"]
+    );
+    assert_eq!(
+        voters(VoteListOrder::PowerAscending, Some("a"), None),
+        ["c"]
+    );
+
+    // Paginating by power after an address that has not voted fails.
+    let err = QueryMsgQuerier::new(app.wrap(), &proposal_module)
+        .list_votes(
+            proposal_id,
+            Some("e".to_string()),
+            None,
+            Some(VoteListOrder::PowerAscending),
+        )
+        .unwrap_err();
+    assert!(matches!(err, StdError::GenericErr { .. }));
+}
+
+#[test]
+fn test_list_proposals_by_status() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.threshold = Threshold::AbsoluteCount {
+        threshold: Uint128::new(2),
+    };
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: "one".to_string(),
+                amount: Uint128::new(1),
+            },
+            Cw20Coin {
+                address: "two".to_string(),
+                amount: Uint128::new(1),
+            },
+            Cw20Coin {
+                address: "three".to_string(),
+                amount: Uint128::new(1),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    // 1 is executed, 2 and 4 are open, and 3 is rejected.
+    let executed = make_proposal(&mut app, &proposal_module, "one", vec![]);
+    vote_on_proposal(&mut app, &proposal_module, "one", executed, Vote::Yes);
+    vote_on_proposal(&mut app, &proposal_module, "two", executed, Vote::Yes);
+    execute_proposal(&mut app, &proposal_module, "one", executed);
+    let open = make_proposal(&mut app, &proposal_module, "one", vec![]);
+    let rejected = make_proposal(&mut app, &proposal_module, "one", vec![]);
+    vote_on_proposal(&mut app, &proposal_module, "one", rejected, Vote::No);
+    vote_on_proposal(&mut app, &proposal_module, "two", rejected, Vote::No);
+    let also_open = make_proposal(&mut app, &proposal_module, "one", vec![]);
+
+    let list = |app: &App, start_after, limit, status| -> Vec<u64> {
+        QueryMsgQuerier::new(app.wrap(), &proposal_module)
+            .list_proposals(start_after, limit, Some(status))
+            .unwrap()
+            .proposals
+            .into_iter()
+            .map(|response| response.id)
+            .collect()
+    };
+    let reverse = |app: &App, start_before, limit, status| -> Vec<u64> {
+        QueryMsgQuerier::new(app.wrap(), &proposal_module)
+            .reverse_proposals(start_before, limit, Some(status))
+            .unwrap()
+            .proposals
+            .into_iter()
+            .map(|response| response.id)
+            .collect()
+    };
+
+    assert_eq!(list(&app, None, None, Status::Open), vec![open, also_open]);
+    assert_eq!(list(&app, None, None, Status::Executed), vec![executed]);
+    assert_eq!(list(&app, None, None, Status::Rejected), vec![rejected]);
+    assert_eq!(list(&app, None, None, Status::Passed), Vec::<u64>::new());
+
+    // Once the open proposals expire they are rejected, though their
+    // stored status is still open.
+    app.update_block(|block| block.time = block.time.plus_seconds(604800));
+    assert_eq!(list(&app, None, None, Status::Open), Vec::<u64>::new());
+    assert_eq!(
+        list(&app, None, None, Status::Rejected),
+        vec![open, rejected, also_open]
+    );
+    assert_eq!(
+        list(&app, Some(open), None, Status::Rejected),
+        vec![rejected, also_open]
+    );
+    assert_eq!(
+        list(&app, None, Some(2), Status::Rejected),
+        vec![open, rejected]
+    );
+    assert_eq!(
+        reverse(&app, None, Some(2), Status::Rejected),
+        vec![also_open, rejected]
+    );
+    assert_eq!(
+        reverse(&app, Some(rejected), None, Status::Rejected),
+        vec![open]
+    );
+}
+
+#[test]
+fn test_query_list_votes_by_voter() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.threshold = Threshold::AbsoluteCount {
+        threshold: Uint128::new(3),
+    };
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: "one".to_string(),
+                amount: Uint128::new(1),
+            },
+            Cw20Coin {
+                address: "two".to_string(),
+                amount: Uint128::new(1),
+            },
+            Cw20Coin {
+                address: "three".to_string(),
+                amount: Uint128::new(1),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let first = make_proposal(&mut app, &proposal_module, "one", vec![]);
+    let second = make_proposal(&mut app, &proposal_module, "one", vec![]);
+    let third = make_proposal(&mut app, &proposal_module, "one", vec![]);
+
+    let ballots = query_list_votes_by_voter(&app, &proposal_module, "one", None, None);
+    assert_eq!(ballots.ballots, vec![]);
+
+    vote_on_proposal(&mut app, &proposal_module, "one", third, Vote::No);
+    vote_on_proposal(&mut app, &proposal_module, "two", second, Vote::Yes);
+    vote_on_proposal(&mut app, &proposal_module, "one", first, Vote::Yes);
+
+    let ballot = |proposal_id, vote| BallotInfo {
+        proposal_id,
+        vote,
+        power: Uint128::new(1),
+        rationale: None,
+    };
+
+    let ballots = query_list_votes_by_voter(&app, &proposal_module, "one", None, None);
+    assert_eq!(
+        ballots.ballots,
+        vec![ballot(first, Vote::Yes), ballot(third, Vote::No)]
+    );
+    let ballots = query_list_votes_by_voter(&app, &proposal_module, "one", None, Some(1));
+    assert_eq!(ballots.ballots, vec![ballot(first, Vote::Yes)]);
+    let ballots = query_list_votes_by_voter(&app, &proposal_module, "one", Some(first), None);
+    assert_eq!(ballots.ballots, vec![ballot(third, Vote::No)]);
+
+    let ballots = query_list_votes_by_voter(&app, &proposal_module, "two", None, None);
+    assert_eq!(ballots.ballots, vec![ballot(second, Vote::Yes)]);
+    let ballots = query_list_votes_by_voter(&app, &proposal_module, "three", None, None);
+    assert_eq!(ballots.ballots, vec![]);
+}
+
+#[test]
+fn test_verify_tally() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    // Revoting keeps the proposal open so that everyone may vote.
+    instantiate.allow_revoting = true;
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: "one".to_string(),
+                amount: Uint128::new(1),
+            },
+            Cw20Coin {
+                address: "two".to_string(),
+                amount: Uint128::new(2),
+            },
+            Cw20Coin {
+                address: "three".to_string(),
+                amount: Uint128::new(3),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let proposal_id = make_proposal(&mut app, &proposal_module, "one", vec![]);
+
+    let tally = query_verify_tally(&app, &proposal_module, proposal_id);
+    assert_eq!(
+        tally,
+        VerifyTallyResponse {
+            cached: Votes::zero(),
+            recounted: Votes::zero(),
+            ballots: 0,
+            consistent: true,
         }
-        .into()],
     );
 
+    vote_on_proposal(&mut app, &proposal_module, "one", proposal_id, Vote::Yes);
+    vote_on_proposal(&mut app, &proposal_module, "two", proposal_id, Vote::No);
+    vote_on_proposal(&mut app, &proposal_module, "three", proposal_id, Vote::Yes);
     vote_on_proposal(
         &mut app,
         &proposal_module,
-        CREATOR_ADDR,
+        "two",
         proposal_id,
-        Vote::Yes,
+        Vote::Abstain,
     );
-    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    vote_on_proposal(&mut app, &proposal_module, "one", proposal_id, Vote::No);
 
-    // Check that a new creation policy has been birthed.
-    let proposal_creation_policy = query_creation_policy(&app, &proposal_module);
-    let pre_propose = match proposal_creation_policy {
-        ProposalCreationPolicy::Anyone {} => panic!("expected a pre-propose module"),
-        ProposalCreationPolicy::Module { addr } => addr,
+    let expected = Votes {
+        yes: Uint128::new(3),
+        no: Uint128::new(1),
+        abstain: Uint128::new(2),
     };
+    let tally = query_verify_tally(&app, &proposal_module, proposal_id);
+    assert_eq!(
+        tally,
+        VerifyTallyResponse {
+            cached: expected.clone(),
+            recounted: expected,
+            ballots: 3,
+            consistent: true,
+        }
+    );
 
-    // Check that the admin has been set to the DAO properly.
-    let info: ContractInfoResponse = app
-        .wrap()
-        .query(&cosmwasm_std::QueryRequest::Wasm(WasmQuery::ContractInfo {
-            contract_addr: pre_propose.to_string(),
-        }))
+    // Proposals that do not exist can not be verified.
+    let err = QueryMsgQuerier::new(app.wrap(), &proposal_module)
+        .verify_tally(proposal_id + 1)
+        .unwrap_err();
+    assert!(matches!(err, StdError::GenericErr { .. }));
+}
+
+#[test]
+fn test_verify_tally_mismatch() {
+    use crate::contract::query_verify_tally;
+    use crate::state::{ballots, proposals, Ballot};
+
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    proposals()
+        .save(
+            deps.as_mut().storage,
+            1,
+            &SingleChoiceProposal {
+                title: "A simple text proposal".to_string(),
+                description: "This is a simple text proposal".to_string(),
+                proposer: Addr::unchecked(CREATOR_ADDR),
+                start_height: env.block.height,
+                expiration: cw_utils::Duration::Height(6).after(&env.block),
+                min_voting_period: None,
+                threshold: Threshold::AbsolutePercentage {
+                    percentage: PercentageThreshold::Majority {},
+                },
+                allow_revoting: false,
+                abstain_counting: AbstainCounting::default(),
+                execution_delay: None,
+                earliest_execution: None,
+                vote_extension: None,
+                max_expiration: None,
+                total_power: Uint128::new(100),
+                msgs: vec![],
+                status: Status::Open,
+                votes: Votes::with_yes(Uint128::new(10)),
+                description_hash: None,
+                content_cid: None,
+            },
+        )
+        .unwrap();
+    ballots()
+        .save(
+            deps.as_mut().storage,
+            (1, &Addr::unchecked(CREATOR_ADDR)),
+            &Ballot {
+                power: Uint128::new(5),
+                vote: Vote::Yes,
+                rationale: None,
+            },
+        )
         .unwrap();
-    assert_eq!(info.admin, Some(core_addr.to_string()));
 
-    let pre_propose_config = query_pre_proposal_single_config(&app, &pre_propose);
+    let tally: VerifyTallyResponse =
+        cosmwasm_std::from_binary(&query_verify_tally(deps.as_ref(), 1).unwrap()).unwrap();
     assert_eq!(
-        pre_propose_config,
-        dao_pre_propose_single::Config {
-            deposit_info: Some(CheckedDepositInfo {
-                denom: CheckedDenom::Cw20(gov_token.clone()),
-                amount: Uint128::new(1),
-                refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed,
-            }),
-            open_proposal_submission: false,
+        tally,
+        VerifyTallyResponse {
+            cached: Votes::with_yes(Uint128::new(10)),
+            recounted: Votes::with_yes(Uint128::new(5)),
+            ballots: 1,
+            consistent: false,
         }
     );
+}
 
-    // Make a new proposal with this new module installed.
-    make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
-    // Check that the deposit was withdrawn.
-    let balance = query_balance_cw20(&app, gov_token.as_str(), CREATOR_ADDR);
-    assert_eq!(balance, Uint128::new(9_999_999));
+#[test]
+fn test_generic_proposal_info() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(vec![Cw20Coin {
+            address: "one".to_string(),
+            amount: Uint128::new(1),
+        }]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let proposal_id = make_proposal(&mut app, &proposal_module, "one", vec![]);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+
+    let info = query_generic_proposal_info(&app, &proposal_module, proposal_id);
+    assert_eq!(
+        info,
+        GenericProposalInfoResponse {
+            proposer: Addr::unchecked("one"),
+            status: "open".to_string(),
+            expiration: proposal.expiration,
+            created: proposal.start_height,
+        }
+    );
+
+    // The status is the proposal's current status and may be read
+    // back as a `Status`.
+    vote_on_proposal(&mut app, &proposal_module, "one", proposal_id, Vote::Yes);
+    let info = query_generic_proposal_info(&app, &proposal_module, proposal_id);
+    let status: Status =
+        cosmwasm_std::from_slice(format!("\"{}\"", info.status).as_bytes()).unwrap();
+    assert_eq!(status, Status::Passed);
+}
+
+#[test]
+fn test_update_pre_propose_module() {
+    let CommonTest {
+        mut app,
+        core_addr,
+        proposal_module,
+        gov_token,
+        proposal_id: pre_update_proposal_id,
+    } = setup_test(vec![]);
+
+    // Store the address of the pre-propose module that we start with
+    // so we can execute withdraw on it later.
+    let proposal_creation_policy = query_creation_policy(&app, &proposal_module);
+    let pre_propose_start = match proposal_creation_policy {
+        ProposalCreationPolicy::Module { addr } => addr,
+        _ => panic!("expected a pre-propose module"),
+    };
+
+    let pre_propose_id = app.store_code(pre_propose_single_contract());
+
+    // Make a proposal to switch to a new pre-propose moudle.
+    mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 10_000_000);
+    let proposal_id = make_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        vec![WasmMsg::Execute {
+            contract_addr: proposal_module.to_string(),
+            msg: to_binary(&ExecuteMsg::UpdatePreProposeInfo {
+                info: PreProposeInfo::ModuleMayPropose {
+                    info: ModuleInstantiateInfo {
+                        code_id: pre_propose_id,
+                        msg: to_binary(&dao_pre_propose_single::InstantiateMsg {
+                            deposit_info: Some(UncheckedDepositInfo {
+                                denom: dao_voting::deposit::DepositToken::VotingModuleToken {},
+                                amount: Uint128::new(1),
+                                refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed,
+                                additional: vec![],
+                                refund_on_cancel: false,
+                            }),
+                            open_proposal_submission: false,
+                            nft_deposit_info: None,
+                            extension: Empty::default(),
+                        })
+                        .unwrap(),
+                        admin: Some(Admin::CoreModule {}),
+                        label: "new pre-propose module".to_string(),
+                    },
+                },
+            })
+            .unwrap(),
+            funds: vec![],
+        }
+        .into()],
+    );
+
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+
+    // Check that a new creation policy has been birthed.
+    let proposal_creation_policy = query_creation_policy(&app, &proposal_module);
+    let pre_propose = match proposal_creation_policy {
+        ProposalCreationPolicy::Module { addr } => addr,
+        _ => panic!("expected a pre-propose module"),
+    };
+
+    // Check that the admin has been set to the DAO properly.
+    let info: ContractInfoResponse = app
+        .wrap()
+        .query(&cosmwasm_std::QueryRequest::Wasm(WasmQuery::ContractInfo {
+            contract_addr: pre_propose.to_string(),
+        }))
+        .unwrap();
+    assert_eq!(info.admin, Some(core_addr.to_string()));
+
+    let pre_propose_config = query_pre_proposal_single_config(&app, &pre_propose);
+    assert_eq!(
+        pre_propose_config,
+        dao_pre_propose_single::Config {
+            deposit_info: Some(CheckedDepositInfo {
+                denom: CheckedDenom::Cw20(gov_token.clone()),
+                amount: Uint128::new(1),
+                refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed,
+                additional: vec![],
+                refund_on_cancel: false,
+            }),
+            open_proposal_submission: false,
+            nft_deposit_info: None,
+        }
+    );
+
+    // Make a new proposal with this new module installed.
+    make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    // Check that the deposit was withdrawn.
+    let balance = query_balance_cw20(&app, gov_token.as_str(), CREATOR_ADDR);
+    assert_eq!(balance, Uint128::new(9_999_999));
+
+    // Vote on and execute the proposal created with the old
+    // module. This should work fine, but the deposit will not be
+    // returned as that module is no longer receiving hook messages.
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        pre_update_proposal_id,
+        Vote::Yes,
+    );
+    execute_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        pre_update_proposal_id,
+    );
+
+    // Deposit should not have been returned.
+    let balance = query_balance_cw20(&app, gov_token.as_str(), CREATOR_ADDR);
+    assert_eq!(balance, Uint128::new(9_999_999));
+
+    // Withdraw from the old pre-propose module.
+    let proposal_id = make_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        vec![WasmMsg::Execute {
+            contract_addr: pre_propose_start.into_string(),
+            msg: to_binary(&dao_pre_propose_single::ExecuteMsg::Withdraw { denom: None }).unwrap(),
+            funds: vec![],
+        }
+        .into()],
+    );
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+
+    // Make sure the left over deposit was returned to the DAO.
+    let balance = query_balance_cw20(&app, gov_token.as_str(), core_addr.as_str());
+    assert_eq!(balance, Uint128::new(10_000_000));
+}
+
+/// DAO should be admin of the pre-propose contract despite the fact
+/// that the proposal module instantiates it.
+#[test]
+fn test_pre_propose_admin_is_dao() {
+    let CommonTest {
+        app,
+        core_addr,
+        proposal_module,
+        gov_token: _,
+        proposal_id: _,
+    } = setup_test(vec![]);
+
+    let proposal_creation_policy = query_creation_policy(&app, &proposal_module);
+
+    // Check that a new creation policy has been birthed.
+    let pre_propose = match proposal_creation_policy {
+        ProposalCreationPolicy::Module { addr } => addr,
+        _ => panic!("expected a pre-propose module"),
+    };
+
+    let info: ContractInfoResponse = app
+        .wrap()
+        .query(&cosmwasm_std::QueryRequest::Wasm(WasmQuery::ContractInfo {
+            contract_addr: pre_propose.into_string(),
+        }))
+        .unwrap();
+    assert_eq!(info.admin, Some(core_addr.into_string()));
+}
+
+// I can add a rationale to my vote. My rational is queryable when
+// listing votes. I can later change my rationale.
+#[test]
+fn test_rationale() {
+    let CommonTest {
+        mut app,
+        proposal_module,
+        proposal_id,
+        ..
+    } = setup_test(vec![]);
+
+    let rationale = Some("i support dog charities".to_string());
+
+    vote_on_proposal_with_rationale(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+        rationale.clone(),
+    );
+
+    let vote = query_vote(&app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert_eq!(vote.vote.unwrap().rationale, rationale);
+
+    let rationale =
+        Some("i did not realize that dog charity was gambling with customer funds".to_string());
+
+    update_rationale(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        rationale.clone(),
+    );
+
+    let vote = query_vote(&app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert_eq!(vote.vote.unwrap().rationale, rationale);
+}
+
+// Revoting should override any previous rationale. If no new
+// rationalle is provided, the old one will be wiped regardless.
+#[test]
+fn test_rational_clobbered_on_revote() {
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.allow_revoting = true;
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let gov_token = query_dao_token(&app, &core_addr);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 10_000_000);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+
+    let rationale = Some("to_string".to_string());
+
+    vote_on_proposal_with_rationale(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+        rationale.clone(),
+    );
+
+    let vote = query_vote(&app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert_eq!(vote.vote.unwrap().rationale, rationale);
+
+    let rationale = None;
+
+    // revote and clobber.
+    vote_on_proposal_with_rationale(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::No,
+        None,
+    );
+
+    let vote = query_vote(&app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert_eq!(vote.vote.unwrap().rationale, rationale);
+}
+
+// Casting votes is only allowed within the proposal expiration timeframe
+#[test]
+pub fn test_not_allow_voting_on_expired_proposal() {
+    let CommonTest {
+        mut app,
+        core_addr: _,
+        proposal_module,
+        gov_token: _,
+        proposal_id,
+    } = setup_test(vec![]);
+
+    // expire the proposal
+    app.update_block(|mut b| b.time = b.time.plus_seconds(604800));
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Rejected);
+    assert_eq!(proposal.proposal.votes.yes, Uint128::zero());
+
+    // attempt to vote past the expiration date
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id,
+                vote: Vote::Yes,
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+
+    // assert the vote got rejected and did not count
+    // towards the votes
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Rejected);
+    assert_eq!(proposal.proposal.votes.yes, Uint128::zero());
+    assert!(matches!(err, ContractError::Expired { id: _proposal_id }));
+}
+
+#[test]
+fn test_proposal_count_goes_up() {
+    let CommonTest {
+        mut app,
+        proposal_module,
+        gov_token,
+        core_addr,
+        ..
+    } = setup_test(vec![]);
+
+    let next = query_next_proposal_id(&app, &proposal_module);
+    assert_eq!(next, 2);
+
+    mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 10_000_000);
+    make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+
+    let next = query_next_proposal_id(&app, &proposal_module);
+    assert_eq!(next, 3);
+}
+
+#[test]
+fn test_prune_proposals() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.threshold = Threshold::AbsoluteCount {
+        threshold: Uint128::new(2),
+    };
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: "one".to_string(),
+                amount: Uint128::new(1),
+            },
+            Cw20Coin {
+                address: "two".to_string(),
+                amount: Uint128::new(1),
+            },
+            Cw20Coin {
+                address: "three".to_string(),
+                amount: Uint128::new(1),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    // 1 is executed, 2 is rejected and closed, and 3 is executed
+    // later on.
+    let executed = make_proposal(&mut app, &proposal_module, "one", vec![]);
+    vote_on_proposal(&mut app, &proposal_module, "one", executed, Vote::Yes);
+    vote_on_proposal(&mut app, &proposal_module, "two", executed, Vote::Yes);
+    execute_proposal(&mut app, &proposal_module, "one", executed);
+    let closed = make_proposal(&mut app, &proposal_module, "one", vec![]);
+    vote_on_proposal(&mut app, &proposal_module, "one", closed, Vote::No);
+    vote_on_proposal(&mut app, &proposal_module, "two", closed, Vote::No);
+    close_proposal(&mut app, &proposal_module, "one", closed);
+
+    app.update_block(next_block);
+    let cutoff = app.block_info().height;
+
+    let recent = make_proposal(&mut app, &proposal_module, "one", vec![]);
+    vote_on_proposal(&mut app, &proposal_module, "one", recent, Vote::Yes);
+    vote_on_proposal(&mut app, &proposal_module, "two", recent, Vote::Yes);
+    execute_proposal(&mut app, &proposal_module, "one", recent);
+
+    let prune = |older_than, statuses, limit| ExecuteMsg::Prune {
+        older_than,
+        statuses,
+        limit,
+    };
+
+    // Only the DAO may prune proposals.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("one"),
+            proposal_module.clone(),
+            &prune(cutoff, vec![Status::Executed], None),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    // Proposals whose status may still change may not be pruned.
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            proposal_module.clone(),
+            &prune(cutoff, vec![Status::Executed, Status::Rejected], None),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::VotingError(VotingError::InvalidPruneStatus {
+            status: Status::Rejected
+        })
+    ));
+
+    app.execute_contract(
+        core_addr.clone(),
+        proposal_module.clone(),
+        &prune(cutoff, vec![Status::Executed, Status::Closed], None),
+        &[],
+    )
+    .unwrap();
+
+    let querier = QueryMsgQuerier::new(app.wrap(), &proposal_module);
+    querier.proposal(executed).unwrap_err();
+    querier.proposal(closed).unwrap_err();
+    assert_eq!(
+        querier.pruned_proposal(executed).unwrap(),
+        Some(PrunedProposal {
+            title: "title".to_string(),
+            proposer: Addr::unchecked("one"),
+            start_height: cutoff - 1,
+            status: Status::Executed,
+        })
+    );
+    assert_eq!(
+        querier.pruned_proposal(closed).unwrap().unwrap().status,
+        Status::Closed
+    );
+
+    // Ballots on pruned proposals are removed.
+    assert_eq!(query_vote(&app, &proposal_module, "two", closed).vote, None);
+    let ballots = query_list_votes_by_voter(&app, &proposal_module, "two", None, None);
+    assert_eq!(
+        ballots
+            .ballots
+            .into_iter()
+            .map(|ballot| ballot.proposal_id)
+            .collect::<Vec<_>>(),
+        vec![recent]
+    );
+
+    // Newer proposals are kept.
+    assert_eq!(querier.pruned_proposal(recent).unwrap(), None);
+    assert_eq!(
+        query_proposal(&app, &proposal_module, recent)
+            .proposal
+            .status,
+        Status::Executed
+    );
+
+    app.execute_contract(
+        core_addr,
+        proposal_module.clone(),
+        &prune(u64::MAX, vec![Status::Executed], Some(1)),
+        &[],
+    )
+    .unwrap();
+    let querier = QueryMsgQuerier::new(app.wrap(), &proposal_module);
+    assert!(querier.pruned_proposal(recent).unwrap().is_some());
+    let ballots = query_list_votes_by_voter(&app, &proposal_module, "two", None, None);
+    assert_eq!(ballots.ballots, vec![]);
+}
+
+#[test]
+fn test_replace_proposal_module() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = get_pre_propose_info(&mut app, None, false);
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let old_module = query_single_proposal_module(&app, &core_addr);
+    let pre_propose = match query_creation_policy(&app, &old_module) {
+        ProposalCreationPolicy::Module { addr } => addr,
+        _ => panic!("expected a pre-propose module"),
+    };
+    make_proposal(&mut app, &old_module, CREATOR_ADDR, vec![]);
+    make_proposal(&mut app, &old_module, CREATOR_ADDR, vec![]);
+    add_proposal_hook(&mut app, &old_module, core_addr.as_str(), "proposal_hook");
+    add_vote_hook(&mut app, &old_module, core_addr.as_str(), "vote_hook");
+
+    // Only the DAO may start a replacement.
+    let proposal_single_id = app.store_code(proposal_single_contract());
+    let replace = dao_core::msg::ExecuteMsg::ReplaceProposalModule {
+        address: old_module.to_string(),
+        replacement: ModuleInstantiateInfo {
+            code_id: proposal_single_id,
+            msg: to_binary(&InstantiateMsg {
+                pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+                ..get_default_non_token_dao_proposal_module_instantiate(&mut app)
+            })
+            .unwrap(),
+            admin: Some(Admin::CoreModule {}),
+            label: "replacement".to_string(),
+        },
+    };
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        core_addr.clone(),
+        &replace,
+        &[],
+    )
+    .unwrap_err();
+    app.execute_contract(core_addr.clone(), core_addr.clone(), &replace, &[])
+        .unwrap();
+
+    let active: Vec<dao_core::state::ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            &core_addr,
+            &dao_core::msg::QueryMsg::ActiveProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(active.len(), 1);
+    let new_module = active.into_iter().next().unwrap().address;
+    assert_ne!(new_module, old_module);
+
+    // Hooks, the pre-propose module, and proposal IDs are carried
+    // over.
+    assert_eq!(
+        query_proposal_hooks(&app, &new_module).hooks,
+        vec!["proposal_hook".to_string()]
+    );
+    assert_eq!(
+        query_vote_hooks(&app, &new_module).hooks,
+        vec!["vote_hook".to_string()]
+    );
+    assert_eq!(
+        query_creation_policy(&app, &new_module),
+        ProposalCreationPolicy::Module {
+            addr: pre_propose.clone()
+        }
+    );
+    let bound: Addr = app
+        .wrap()
+        .query_wasm_smart(
+            &pre_propose,
+            &dao_pre_propose_single::QueryMsg::ProposalModule {},
+        )
+        .unwrap();
+    assert_eq!(bound, new_module);
+    assert_eq!(query_next_proposal_id(&app, &new_module), 3);
+    let id = make_proposal(&mut app, &new_module, CREATOR_ADDR, vec![]);
+    assert_eq!(id, 3);
+
+    // State may not be imported once proposals exist.
+    let err: ContractError = app
+        .execute_contract(
+            core_addr,
+            new_module,
+            &ExecuteMsg::ImportModuleState {
+                module: old_module.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::ProposalsExist {}));
+}
+
+#[test]
+fn test_export_and_import_state() {
+    let mut app = App::default();
+    let instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let old_module = query_single_proposal_module(&app, &core_addr);
+    let id = make_proposal(&mut app, &old_module, CREATOR_ADDR, vec![]);
+    vote_on_proposal(&mut app, &old_module, CREATOR_ADDR, id, Vote::Yes);
+    execute_proposal(&mut app, &old_module, CREATOR_ADDR, id);
+    let open_id = make_proposal(&mut app, &old_module, CREATOR_ADDR, vec![]);
+
+    let instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    let new_core = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let new_module = query_single_proposal_module(&app, &new_core);
+
+    let export = query_export_state(&app, &old_module, None, Some(1));
+    assert_eq!(export.proposal_count, 2);
+    assert_eq!(export.proposals.len(), 1);
+    let rest = query_export_state(&app, &old_module, Some(1), None);
+    let exported: Vec<ProposalResponse> =
+        export.proposals.into_iter().chain(rest.proposals).collect();
+    let votes = query_list_votes(&app, &old_module, id, None, None, None).votes;
+
+    // Only the DAO may import state.
+    let import = ExecuteMsg::ImportProposals {
+        proposals: exported.clone(),
+    };
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            new_module.clone(),
+            &import,
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    app.execute_contract(new_core.clone(), new_module.clone(), &import, &[])
+        .unwrap();
+    app.execute_contract(
+        new_core.clone(),
+        new_module.clone(),
+        &ExecuteMsg::ImportVotes {
+            proposal_id: id,
+            votes: votes.clone(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let imported = query_export_state(&app, &new_module, None, None);
+    assert_eq!(imported.proposal_count, 2);
+    assert_eq!(imported.proposals, exported);
+    assert_eq!(
+        query_list_votes(&app, &new_module, id, None, None, None).votes,
+        votes
+    );
+    assert_eq!(
+        query_proposal(&app, &new_module, id).proposal.status,
+        Status::Executed
+    );
+    // Imported proposals and votes are counted in the governance
+    // statistics.
+    assert_eq!(
+        query_governance_stats(&app, &new_module),
+        query_governance_stats(&app, &old_module)
+    );
+
+    // Ballots may only be imported once, and may not add up to more
+    // than the proposal's tally.
+    let err: ContractError = app
+        .execute_contract(
+            new_core.clone(),
+            new_module.clone(),
+            &ExecuteMsg::ImportVotes {
+                proposal_id: id,
+                votes: votes.clone(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::AlreadyVoted {}));
+    let err: ContractError = app
+        .execute_contract(
+            new_core.clone(),
+            new_module.clone(),
+            &ExecuteMsg::ImportVotes {
+                proposal_id: open_id,
+                votes,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::ImportedVotesExceedTally { id: 2 }
+    ));
+
+    // Imported proposals may not be overwritten.
+    let err: ContractError = app
+        .execute_contract(new_core.clone(), new_module.clone(), &import, &[])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::ProposalExists { id: 1 }));
+
+    // New proposals are numbered after imported ones, and close the
+    // import.
+    let id = make_proposal(&mut app, &new_module, CREATOR_ADDR, vec![]);
+    assert_eq!(id, 3);
+    let err: ContractError = app
+        .execute_contract(new_core, new_module, &import, &[])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::ImportClosed {}));
+}
+
+#[test]
+fn test_tally_summary() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let weights = [("ekez", 60), ("keze", 30), ("zeke", 10)];
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(
+            weights
+                .into_iter()
+                .map(|(address, amount)| Cw20Coin {
+                    address: address.to_string(),
+                    amount: Uint128::new(amount),
+                })
+                .collect(),
+        ),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let id = make_proposal(&mut app, &proposal_module, "ekez", vec![]);
+
+    vote_on_proposal(&mut app, &proposal_module, "keze", id, Vote::No);
+    let summary = query_tally_summary(&app, &proposal_module, id);
+    assert_eq!(summary.status, Status::Open);
+    assert_eq!(summary.total_power, Uint128::new(100));
+    assert_eq!(
+        summary.of_cast,
+        VotePercentages {
+            yes: Decimal::zero(),
+            no: Decimal::one(),
+            abstain: Decimal::zero(),
+        }
+    );
+    assert_eq!(summary.of_total_power.no, Decimal::percent(30));
+    assert_eq!(
+        summary.quorum,
+        Some(QuorumProgress {
+            quorum: PercentageThreshold::Majority {},
+            turnout: Decimal::percent(30),
+            met: false,
+        })
+    );
+    assert!(!summary.passed);
+    assert!(!summary.rejected);
+    // Voting closing now would leave the proposal short of quorum.
+    assert!(!summary.passing);
+
+    vote_on_proposal(&mut app, &proposal_module, "zeke", id, Vote::Yes);
+    let summary = query_tally_summary(&app, &proposal_module, id);
+    // A quarter of the votes cast are yes, which meets the 15%
+    // threshold, but only 40% of the power has voted.
+    assert!(!summary.quorum.unwrap().met);
+    assert!(!summary.passing);
+
+    vote_on_proposal(&mut app, &proposal_module, "ekez", id, Vote::Abstain);
+    let summary = query_tally_summary(&app, &proposal_module, id);
+    assert_eq!(summary.status, Status::Passed);
+    assert_eq!(
+        summary.of_cast,
+        VotePercentages {
+            yes: Decimal::percent(10),
+            no: Decimal::percent(30),
+            abstain: Decimal::percent(60),
+        }
+    );
+    assert_eq!(summary.of_cast, summary.of_total_power);
+    assert!(summary.quorum.unwrap().met);
+    assert!(summary.passed);
+    assert!(summary.passing);
+    assert!(!summary.rejected);
+}
+
+#[test]
+fn test_list_proposals_by_expiration() {
+    let CommonTest {
+        mut app,
+        core_addr: _,
+        proposal_module,
+        gov_token: _,
+        proposal_id: _,
+    } = setup_test(vec![]);
+    let start = app.block_info().time;
+    let week = 604800;
+
+    // Proposals expire a week after they are created.
+    for _ in 0..2 {
+        app.update_block(|mut b| b.time = b.time.plus_seconds(3600));
+        make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    }
+
+    let at = |seconds: u64| Some(Expiration::AtTime(start.plus_seconds(seconds)));
+    let list = |after, before, limit| {
+        query_list_proposals_by_expiration(&app, &proposal_module, after, before, limit)
+    };
+
+    assert_eq!(list(at(0), None, None), vec![1, 2, 3]);
+    assert_eq!(list(at(0), None, Some(2)), vec![1, 2]);
+    // Both bounds are exclusive.
+    assert_eq!(list(at(week), None, None), vec![2, 3]);
+    assert_eq!(list(None, at(week + 3600), None), vec![1]);
+    assert_eq!(list(at(week), at(week + 7200), None), vec![2]);
+    assert!(list(at(week + 7200), None, None).is_empty());
+
+    // Proposals expiring at a time are not listed when searching by
+    // height.
+    assert!(list(Some(Expiration::AtHeight(0)), None, None).is_empty());
+
+    // Heights and times can not be mixed.
+    app.wrap()
+        .query_wasm_smart::<ProposalListResponse>(
+            &proposal_module,
+            &QueryMsg::ListProposalsByExpiration {
+                after: Some(Expiration::AtHeight(0)),
+                before: at(week),
+                limit: None,
+            },
+        )
+        .unwrap_err();
+}
+
+#[test]
+fn test_pending_votes() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(60),
+            },
+            Cw20Coin {
+                address: "keze".to_string(),
+                amount: Uint128::new(30),
+            },
+            Cw20Coin {
+                address: "zeke".to_string(),
+                amount: Uint128::new(10),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    for _ in 0..3 {
+        make_proposal(&mut app, &proposal_module, "ekez", vec![]);
+    }
+
+    vote_on_proposal(&mut app, &proposal_module, "keze", 1, Vote::No);
+    assert_eq!(
+        query_pending_votes(&app, &proposal_module, "keze", None, None),
+        vec![2, 3]
+    );
+    assert_eq!(
+        query_pending_votes(&app, &proposal_module, "zeke", None, None),
+        vec![1, 2, 3]
+    );
+    assert_eq!(
+        query_pending_votes(&app, &proposal_module, "zeke", None, Some(1)),
+        vec![1]
+    );
+    assert_eq!(
+        query_pending_votes(&app, &proposal_module, "zeke", Some(1), None),
+        vec![2, 3]
+    );
+    // Addresses without voting power have nothing to vote on.
+    assert!(query_pending_votes(&app, &proposal_module, "nobody", None, None).is_empty());
+
+    // Proposals which are no longer open are not pending.
+    vote_on_proposal(&mut app, &proposal_module, "ekez", 2, Vote::Yes);
+    assert_eq!(
+        query_pending_votes(&app, &proposal_module, "zeke", None, None),
+        vec![1, 3]
+    );
+}
+
+#[test]
+fn test_governance_stats() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(60),
+            },
+            Cw20Coin {
+                address: "keze".to_string(),
+                amount: Uint128::new(30),
+            },
+            Cw20Coin {
+                address: "zeke".to_string(),
+                amount: Uint128::new(10),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    for _ in 0..3 {
+        make_proposal(&mut app, &proposal_module, "ekez", vec![]);
+    }
+
+    vote_on_proposal(&mut app, &proposal_module, "ekez", 1, Vote::Yes);
+    vote_on_proposal(&mut app, &proposal_module, "ekez", 2, Vote::No);
+    vote_on_proposal(&mut app, &proposal_module, "zeke", 2, Vote::No);
+    vote_on_proposal(&mut app, &proposal_module, "zeke", 3, Vote::Yes);
+    execute_proposal(&mut app, &proposal_module, "ekez", 1);
+    close_proposal(&mut app, &proposal_module, "ekez", 2);
+
+    let stats = query_governance_stats(&app, &proposal_module);
+    assert_eq!(
+        stats.totals,
+        GovernanceTotals {
+            proposals: 3,
+            executed: 1,
+            execution_failed: 0,
+            closed: 1,
+            votes: 4,
+            unique_voters: 2,
+            turnout_sum: Decimal::percent(130),
+        }
+    );
+    assert_eq!(stats.pending, 1);
+    assert_eq!(stats.average_turnout, Decimal::percent(65));
+    assert_eq!(stats.pass_rate, Decimal::percent(50));
+}
+
+#[test]
+fn test_simulate_execution() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    mint_natives(&mut app, core_addr.as_str(), coins(10, "ujuno"));
+
+    let id = make_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        vec![
+            BankMsg::Send {
+                to_address: CREATOR_ADDR.to_string(),
+                amount: coins(10, "ujuno"),
+            }
+            .into(),
+            WasmMsg::Execute {
+                contract_addr: core_addr.to_string(),
+                msg: to_binary(&dao_core::msg::ExecuteMsg::UpdateCw20List {
+                    to_add: vec![],
+                    to_remove: vec![],
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into(),
+        ],
+    );
+    assert_eq!(
+        query_simulate_execution(&app, &proposal_module, id),
+        SimulationResponse { warnings: vec![] }
+    );
 
-    // Vote on and execute the proposal created with the old
-    // module. This should work fine, but the deposit will not be
-    // returned as that module is no longer receiving hook messages.
-    vote_on_proposal(
+    let id = make_proposal(
         &mut app,
         &proposal_module,
         CREATOR_ADDR,
-        pre_update_proposal_id,
-        Vote::Yes,
+        vec![
+            BankMsg::Send {
+                to_address: "A".to_string(),
+                amount: coins(11, "ujuno"),
+            }
+            .into(),
+            WasmMsg::Execute {
+                contract_addr: proposal_module.to_string(),
+                msg: to_binary(&dao_core::msg::ExecuteMsg::UpdateCw20List {
+                    to_add: vec![],
+                    to_remove: vec![],
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into(),
+        ],
     );
-    execute_proposal(
-        &mut app,
-        &proposal_module,
-        CREATOR_ADDR,
-        pre_update_proposal_id,
+    let warnings = query_simulate_execution(&app, &proposal_module, id).warnings;
+    assert_eq!(warnings.len(), 3);
+    assert_eq!(
+        warnings[0],
+        ExecutionWarning::InvalidAddress {
+            msg_index: 0,
+            address: "A".to_string(),
+        }
+    );
+    match &warnings[1] {
+        ExecutionWarning::InvalidExecuteMsg {
+            msg_index,
+            contract,
+            ..
+        } => {
+            assert_eq!(*msg_index, 1);
+            assert_eq!(contract, &proposal_module);
+        }
+        _ => panic!("expected an invalid execute message warning"),
+    }
+    assert_eq!(
+        warnings[2],
+        ExecutionWarning::InsufficientBalance {
+            denom: "ujuno".to_string(),
+            required: Uint128::new(11),
+            balance: Uint128::new(10),
+        }
     );
+}
 
-    // Deposit should not have been returned.
-    let balance = query_balance_cw20(&app, gov_token.as_str(), CREATOR_ADDR);
-    assert_eq!(balance, Uint128::new(9_999_999));
+#[test]
+fn test_update_proposal() {
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.allow_revoting = true;
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let gov_token = query_dao_token(&app, &core_addr);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
 
-    // Withdraw from the old pre-propose module.
-    let proposal_id = make_proposal(
+    mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 10_000_000);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+
+    // Only the proposer may update the proposal.
+    let err = update_proposal_should_fail(&mut app, &proposal_module, "ekez", proposal_id);
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    let msgs = vec![BankMsg::Send {
+        to_address: CREATOR_ADDR.to_string(),
+        amount: coins(10, "ujuno"),
+    }
+    .into()];
+    update_proposal(
         &mut app,
         &proposal_module,
         CREATOR_ADDR,
-        vec![WasmMsg::Execute {
-            contract_addr: pre_propose_start.into_string(),
-            msg: to_binary(&dao_pre_propose_single::ExecuteMsg::Withdraw { denom: None }).unwrap(),
-            funds: vec![],
-        }
-        .into()],
+        proposal_id,
+        "updated title",
+        msgs.clone(),
     );
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.title, "updated title");
+    assert_eq!(proposal.description, "updated description");
+    assert_eq!(proposal.msgs, msgs);
+
+    // Proposals may not be updated once voted on.
     vote_on_proposal(
         &mut app,
         &proposal_module,
@@ -2429,129 +4716,166 @@ fn test_update_pre_propose_module() {
         proposal_id,
         Vote::Yes,
     );
-    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    let err = update_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert!(matches!(err, ContractError::ProposalHasVotes { id } if id == proposal_id));
 
-    // Make sure the left over deposit was returned to the DAO.
-    let balance = query_balance_cw20(&app, gov_token.as_str(), core_addr.as_str());
-    assert_eq!(balance, Uint128::new(10_000_000));
+    // Nor once they have closed.
+    mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 10_000_000);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    app.update_block(|mut b| b.time = b.time.plus_seconds(604800));
+    let err = update_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert!(matches!(err, ContractError::NotOpen { id } if id == proposal_id));
+
+    let err = update_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, 10);
+    assert!(matches!(err, ContractError::NoSuchProposal { id: 10 }));
 }
 
-/// DAO should be admin of the pre-propose contract despite the fact
-/// that the proposal module instantiates it.
 #[test]
-fn test_pre_propose_admin_is_dao() {
-    let CommonTest {
-        app,
-        core_addr,
-        proposal_module,
-        gov_token: _,
-        proposal_id: _,
-    } = setup_test(vec![]);
-
-    let proposal_creation_policy = query_creation_policy(&app, &proposal_module);
-
-    // Check that a new creation policy has been birthed.
-    let pre_propose = match proposal_creation_policy {
-        ProposalCreationPolicy::Anyone {} => panic!("expected a pre-propose module"),
-        ProposalCreationPolicy::Module { addr } => addr,
+fn test_pause() {
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    let pause = ExecuteMsg::Pause {
+        duration: Duration::Height(10),
+        reason: Some("bad proposal".to_string()),
     };
 
-    let info: ContractInfoResponse = app
-        .wrap()
-        .query(&cosmwasm_std::QueryRequest::Wasm(WasmQuery::ContractInfo {
-            contract_addr: pre_propose.into_string(),
-        }))
+    // Only the DAO may pause the module.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &pause,
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
         .unwrap();
-    assert_eq!(info.admin, Some(core_addr.into_string()));
-}
-
-// I can add a rationale to my vote. My rational is queryable when
-// listing votes. I can later change my rationale.
-#[test]
-fn test_rationale() {
-    let CommonTest {
-        mut app,
-        proposal_module,
-        proposal_id,
-        ..
-    } = setup_test(vec![]);
+    assert!(matches!(err, ContractError::Unauthorized {}));
 
-    let rationale = Some("i support dog charities".to_string());
+    app.execute_contract(core_addr.clone(), proposal_module.clone(), &pause, &[])
+        .unwrap();
+    let info: dao_pausable::PauseInfoResponse = app
+        .wrap()
+        .query_wasm_smart(proposal_module.clone(), &QueryMsg::PauseInfo {})
+        .unwrap();
+    assert!(matches!(
+        info,
+        dao_pausable::PauseInfoResponse::Paused { reason: Some(ref reason), .. } if reason == "bad proposal"
+    ));
 
-    vote_on_proposal_with_rationale(
+    // Proposals may not be voted on while the module is paused.
+    let err = vote_on_proposal_should_fail(
         &mut app,
         &proposal_module,
         CREATOR_ADDR,
         proposal_id,
         Vote::Yes,
-        rationale.clone(),
     );
+    assert!(matches!(
+        err,
+        ContractError::PauseError(dao_pausable::PauseError::Paused { .. })
+    ));
 
-    let vote = query_vote(&app, &proposal_module, CREATOR_ADDR, proposal_id);
-    assert_eq!(vote.vote.unwrap().rationale, rationale);
-
-    let rationale =
-        Some("i did not realize that dog charity was gambling with customer funds".to_string());
-
-    update_rationale(
+    app.execute_contract(
+        core_addr,
+        proposal_module.clone(),
+        &ExecuteMsg::Unpause {},
+        &[],
+    )
+    .unwrap();
+    vote_on_proposal(
         &mut app,
         &proposal_module,
         CREATOR_ADDR,
         proposal_id,
-        rationale.clone(),
+        Vote::Yes,
     );
+}
 
-    let vote = query_vote(&app, &proposal_module, CREATOR_ADDR, proposal_id);
-    assert_eq!(vote.vote.unwrap().rationale, rationale);
+#[test]
+fn test_update_proposal_pre_propose_module() {
+    let mut app = App::default();
+    let instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let gov_token = query_dao_token(&app, &core_addr);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 10_000_000);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+
+    // Proposals made through a pre-propose module may only be
+    // updated by that module, not by their proposer.
+    let err = update_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert!(matches!(err, ContractError::Unauthorized {}));
 }
 
-// Revoting should override any previous rationale. If no new
-// rationalle is provided, the old one will be wiped regardless.
 #[test]
-fn test_rational_clobbered_on_revote() {
+fn test_cancel_proposal() {
     let mut app = App::default();
     let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
-    instantiate.allow_revoting = true;
+    instantiate.pre_propose_info = get_pre_propose_info(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: dao_voting::deposit::DepositToken::VotingModuleToken {},
+            amount: Uint128::new(10_000_000),
+            refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed,
+            additional: vec![],
+            refund_on_cancel: true,
+        }),
+        false,
+    );
     let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
     let gov_token = query_dao_token(&app, &core_addr);
     let proposal_module = query_single_proposal_module(&app, &core_addr);
 
     mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 10_000_000);
     let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    let balance = query_balance_cw20(&app, &gov_token, CREATOR_ADDR);
+    assert_eq!(balance, Uint128::zero());
 
-    let rationale = Some("to_string".to_string());
+    // Only the proposer may cancel the proposal.
+    let err = cancel_proposal_should_fail(&mut app, &proposal_module, "ekez", proposal_id);
+    assert!(matches!(err, ContractError::Unauthorized {}));
 
-    vote_on_proposal_with_rationale(
+    cancel_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.status, Status::Cancelled);
+
+    // The deposit is refunded per the `refund_on_cancel` policy.
+    let balance = query_balance_cw20(&app, &gov_token, CREATOR_ADDR);
+    assert_eq!(balance, Uint128::new(10_000_000));
+
+    // Cancelled proposals may not be voted on or cancelled again.
+    let err = vote_on_proposal_should_fail(
         &mut app,
         &proposal_module,
         CREATOR_ADDR,
         proposal_id,
         Vote::Yes,
-        rationale.clone(),
     );
+    assert!(matches!(err, ContractError::NotOpen { id } if id == proposal_id));
+    let err = cancel_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert!(matches!(err, ContractError::NotOpen { id } if id == proposal_id));
 
-    let vote = query_vote(&app, &proposal_module, CREATOR_ADDR, proposal_id);
-    assert_eq!(vote.vote.unwrap().rationale, rationale);
-
-    let rationale = None;
-
-    // revote and clobber.
-    vote_on_proposal_with_rationale(
+    // Proposals which have passed may not be cancelled.
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    vote_on_proposal(
         &mut app,
         &proposal_module,
         CREATOR_ADDR,
         proposal_id,
-        Vote::No,
-        None,
+        Vote::Yes,
     );
-
-    let vote = query_vote(&app, &proposal_module, CREATOR_ADDR, proposal_id);
-    assert_eq!(vote.vote.unwrap().rationale, rationale);
+    let err = cancel_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert!(matches!(err, ContractError::NotOpen { id } if id == proposal_id));
 }
 
-// Casting votes is only allowed within the proposal expiration timeframe
 #[test]
-pub fn test_not_allow_voting_on_expired_proposal() {
+fn test_vote_for() {
     let CommonTest {
         mut app,
         core_addr: _,
@@ -2559,53 +4883,42 @@ pub fn test_not_allow_voting_on_expired_proposal() {
         gov_token: _,
         proposal_id,
     } = setup_test(vec![]);
+    let pre_propose = match query_creation_policy(&app, &proposal_module) {
+        ProposalCreationPolicy::Module { addr } => addr,
+        _ => panic!("expected a pre-propose module"),
+    };
+    let vote_for = ExecuteMsg::VoteFor {
+        voter: CREATOR_ADDR.to_string(),
+        proposal_id,
+        vote: Vote::Yes,
+        rationale: None,
+    };
 
-    // expire the proposal
-    app.update_block(|mut b| b.time = b.time.plus_seconds(604800));
-    let proposal = query_proposal(&app, &proposal_module, proposal_id);
-    assert_eq!(proposal.proposal.status, Status::Rejected);
-    assert_eq!(proposal.proposal.votes.yes, Uint128::zero());
-
-    // attempt to vote past the expiration date
+    // Only the pre-propose module may vote on a voter's behalf.
     let err: ContractError = app
         .execute_contract(
-            Addr::unchecked(CREATOR_ADDR),
+            Addr::unchecked("ekez"),
             proposal_module.clone(),
-            &ExecuteMsg::Vote {
-                proposal_id,
-                vote: Vote::Yes,
-                rationale: None,
-            },
+            &vote_for,
             &[],
         )
         .unwrap_err()
         .downcast()
         .unwrap();
+    assert!(matches!(err, ContractError::Unauthorized {}));
 
-    // assert the vote got rejected and did not count
-    // towards the votes
+    app.execute_contract(pre_propose, proposal_module.clone(), &vote_for, &[])
+        .unwrap();
+    let vote = query_vote(&app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert_eq!(
+        vote.vote.unwrap(),
+        VoteInfo {
+            voter: Addr::unchecked(CREATOR_ADDR),
+            vote: Vote::Yes,
+            power: Uint128::new(100_000_000),
+            rationale: None,
+        }
+    );
     let proposal = query_proposal(&app, &proposal_module, proposal_id);
-    assert_eq!(proposal.proposal.status, Status::Rejected);
-    assert_eq!(proposal.proposal.votes.yes, Uint128::zero());
-    assert!(matches!(err, ContractError::Expired { id: _proposal_id }));
-}
-
-#[test]
-fn test_proposal_count_goes_up() {
-    let CommonTest {
-        mut app,
-        proposal_module,
-        gov_token,
-        core_addr,
-        ..
-    } = setup_test(vec![]);
-
-    let next = query_next_proposal_id(&app, &proposal_module);
-    assert_eq!(next, 2);
-
-    mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 10_000_000);
-    make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
-
-    let next = query_next_proposal_id(&app, &proposal_module);
-    assert_eq!(next, 3);
+    assert_eq!(proposal.proposal.status, Status::Passed);
 }
@@ -1,8 +1,8 @@
-use cosmwasm_schema::write_api;
+use dao_schema::write_schema;
 use stake_cw20_external_rewards::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
 
 fn main() {
-    write_api! {
+    write_schema! {
         instantiate: InstantiateMsg,
         query: QueryMsg,
         execute: ExecuteMsg,
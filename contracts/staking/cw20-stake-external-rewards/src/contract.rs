@@ -626,6 +626,7 @@ mod tests {
     fn unstake_tokens(app: &mut App, staking_addr: &Addr, address: &str, amount: u128) {
         let msg = cw20_stake::msg::ExecuteMsg::Unstake {
             amount: Uint128::new(amount),
+            auto_claim: false,
         };
         app.execute_contract(Addr::unchecked(address), staking_addr.clone(), &msg, &[])
             .unwrap();
@@ -4,7 +4,7 @@ use cosmwasm_std::entry_point;
 
 use cosmwasm_std::{
     from_binary, from_slice, to_binary, to_vec, Addr, Binary, Deps, DepsMut, Empty, Env,
-    MessageInfo, Response, StdError, StdResult, Uint128,
+    MessageInfo, Order, Response, StdError, StdResult, Storage, Uint128,
 };
 
 use cw20::Cw20ReceiveMsg;
@@ -12,12 +12,13 @@ use cw20::Cw20ReceiveMsg;
 use crate::hooks::{stake_hook_msgs, unstake_hook_msgs};
 use crate::math;
 use crate::msg::{
-    ExecuteMsg, GetHooksResponse, InstantiateMsg, ListStakersResponse, MigrateMsg, QueryMsg,
-    ReceiveMsg, StakedBalanceAtHeightResponse, StakedValueResponse, StakerBalanceResponse,
-    TotalStakedAtHeightResponse, TotalValueResponse,
+    ExecuteMsg, GetHooksResponse, InstantiateMsg, ListStakersChangedResponse, ListStakersResponse,
+    MigrateMsg, QueryMsg, ReceiveMsg, StakedBalanceAtHeightResponse, StakedValueResponse,
+    StakerBalanceResponse, StakerChangeResponse, TotalStakedAtHeightResponse, TotalValueResponse,
 };
 use crate::state::{
-    Config, BALANCE, CLAIMS, CONFIG, HOOKS, MAX_CLAIMS, STAKED_BALANCES, STAKED_TOTAL,
+    Config, BALANCE, CLAIMS, CONFIG, HOOKS, MAX_CLAIMS, STAKED_BALANCES, STAKED_BALANCE_CHANGED,
+    STAKED_TOTAL, STAKED_TOTAL_CHANGED, STAKERS_BY_CHANGE,
 };
 use crate::ContractError;
 use cw2::set_contract_version;
@@ -32,6 +33,7 @@ pub use cw20_base::contract::{
 };
 pub use cw20_base::enumerable::{query_all_accounts, query_owner_allowances};
 use cw_controllers::ClaimsResponse;
+use cw_storage_plus::{Bound, PrefixBound};
 use cw_utils::Duration;
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:cw20-stake";
@@ -86,6 +88,7 @@ pub fn instantiate(
     // against a scenerio where state is cleared by a bad actor and
     // `unwrap_or_default` carries on.
     STAKED_TOTAL.save(deps.storage, &Uint128::zero(), env.block.height)?;
+    STAKED_TOTAL_CHANGED.save(deps.storage, &env.block.height)?;
     BALANCE.save(deps.storage, &Uint128::zero())?;
 
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
@@ -102,7 +105,10 @@ pub fn execute(
 ) -> Result<Response<Empty>, ContractError> {
     match msg {
         ExecuteMsg::Receive(msg) => execute_receive(deps, env, info, msg),
-        ExecuteMsg::Unstake { amount } => execute_unstake(deps, env, info, amount),
+        ExecuteMsg::Unstake { amount, auto_claim } => {
+            execute_unstake(deps, env, info, amount, auto_claim)
+        }
+        ExecuteMsg::UnstakeAll {} => execute_unstake_all(deps, env, info),
         ExecuteMsg::Claim {} => execute_claim(deps, env, info),
         ExecuteMsg::UpdateConfig {
             owner,
@@ -205,6 +211,7 @@ pub fn execute_stake(
             Ok(total.unwrap().checked_add(amount_to_stake)?)
         },
     )?;
+    record_change(deps.storage, &sender, env.block.height)?;
     BALANCE.save(
         deps.storage,
         &balance.checked_add(amount).map_err(StdError::overflow)?,
@@ -222,6 +229,7 @@ pub fn execute_unstake(
     env: Env,
     info: MessageInfo,
     amount: Uint128,
+    auto_claim: bool,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
     let balance = BALANCE.load(deps.storage)?;
@@ -251,6 +259,7 @@ pub fn execute_unstake(
             Ok(total.unwrap().checked_sub(amount)?)
         },
     )?;
+    record_change(deps.storage, &info.sender, env.block.height)?;
     BALANCE.save(
         deps.storage,
         &balance
@@ -258,11 +267,20 @@ pub fn execute_unstake(
             .map_err(StdError::overflow)?,
     )?;
     let hook_msgs = unstake_hook_msgs(deps.storage, info.sender.clone(), amount)?;
+    // Matured claims are released before any new claim is created so
+    // that they do not count towards MAX_CLAIMS.
+    let claimed = if auto_claim {
+        CLAIMS.claim_tokens(deps.storage, &info.sender, &env.block, None)?
+    } else {
+        Uint128::zero()
+    };
     match config.unstaking_duration {
         None => {
             let cw_send_msg = cw20::Cw20ExecuteMsg::Transfer {
                 recipient: info.sender.to_string(),
-                amount: amount_to_claim,
+                amount: amount_to_claim
+                    .checked_add(claimed)
+                    .map_err(StdError::overflow)?,
             };
             let wasm_msg = cosmwasm_std::WasmMsg::Execute {
                 contract_addr: config.token_address.to_string(),
@@ -275,6 +293,7 @@ pub fn execute_unstake(
                 .add_attribute("action", "unstake")
                 .add_attribute("from", info.sender)
                 .add_attribute("amount", amount)
+                .add_attribute("claimed", claimed)
                 .add_attribute("claim_duration", "None"))
         }
         Some(duration) => {
@@ -289,16 +308,43 @@ pub fn execute_unstake(
                 amount_to_claim,
                 duration.after(&env.block),
             )?;
-            Ok(Response::new()
+            let mut response = Response::new();
+            if !claimed.is_zero() {
+                let cw_send_msg = cw20::Cw20ExecuteMsg::Transfer {
+                    recipient: info.sender.to_string(),
+                    amount: claimed,
+                };
+                response = response.add_message(cosmwasm_std::WasmMsg::Execute {
+                    contract_addr: config.token_address.to_string(),
+                    msg: to_binary(&cw_send_msg)?,
+                    funds: vec![],
+                });
+            }
+            Ok(response
                 .add_attribute("action", "unstake")
                 .add_submessages(hook_msgs)
                 .add_attribute("from", info.sender)
                 .add_attribute("amount", amount)
+                .add_attribute("claimed", claimed)
                 .add_attribute("claim_duration", format!("{duration}")))
         }
     }
 }
 
+pub fn execute_unstake_all(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let amount = STAKED_BALANCES
+        .may_load(deps.storage, &info.sender)?
+        .unwrap_or_default();
+    if amount.is_zero() {
+        return Err(ContractError::NothingStaked {});
+    }
+    execute_unstake(deps, env, info, amount, true)
+}
+
 pub fn execute_claim(
     deps: DepsMut,
     _env: Env,
@@ -391,9 +437,26 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::ListStakers { start_after, limit } => {
             query_list_stakers(deps, start_after, limit)
         }
+        QueryMsg::ListStakersChangedSince {
+            height,
+            start_after,
+            limit,
+        } => query_list_stakers_changed_since(deps, height, start_after, limit),
     }
 }
 
+/// Records that the staked balance of ADDRESS and the total staked
+/// changed at HEIGHT, so that queries for later heights need not
+/// search the changelog.
+fn record_change(storage: &mut dyn Storage, address: &Addr, height: u64) -> StdResult<()> {
+    if let Some(changed) = STAKED_BALANCE_CHANGED.may_load(storage, address)? {
+        STAKERS_BY_CHANGE.remove(storage, (changed, address));
+    }
+    STAKERS_BY_CHANGE.save(storage, (height, address), &Empty {})?;
+    STAKED_BALANCE_CHANGED.save(storage, address, &height)?;
+    STAKED_TOTAL_CHANGED.save(storage, &height)
+}
+
 pub fn query_staked_balance_at_height(
     deps: Deps,
     env: Env,
@@ -402,9 +465,13 @@ pub fn query_staked_balance_at_height(
 ) -> StdResult<StakedBalanceAtHeightResponse> {
     let address = deps.api.addr_validate(&address)?;
     let height = height.unwrap_or(env.block.height);
-    let balance = STAKED_BALANCES
-        .may_load_at_height(deps.storage, &address, height)?
-        .unwrap_or_default();
+    let balance = match STAKED_BALANCE_CHANGED.may_load(deps.storage, &address)? {
+        // The changelog records values as they were before changes
+        // at a height, so nothing changed at or after HEIGHT.
+        Some(changed) if changed < height => STAKED_BALANCES.may_load(deps.storage, &address)?,
+        _ => STAKED_BALANCES.may_load_at_height(deps.storage, &address, height)?,
+    }
+    .unwrap_or_default();
     Ok(StakedBalanceAtHeightResponse { balance, height })
 }
 
@@ -414,9 +481,11 @@ pub fn query_total_staked_at_height(
     height: Option<u64>,
 ) -> StdResult<TotalStakedAtHeightResponse> {
     let height = height.unwrap_or(_env.block.height);
-    let total = STAKED_TOTAL
-        .may_load_at_height(deps.storage, height)?
-        .unwrap_or_default();
+    let total = match STAKED_TOTAL_CHANGED.may_load(deps.storage)? {
+        Some(changed) if changed < height => STAKED_TOTAL.may_load(deps.storage)?,
+        _ => STAKED_TOTAL.may_load_at_height(deps.storage, height)?,
+    }
+    .unwrap_or_default();
     Ok(TotalStakedAtHeightResponse { total, height })
 }
 
@@ -493,6 +562,61 @@ pub fn query_list_stakers(
     to_binary(&ListStakersResponse { stakers })
 }
 
+pub fn query_list_stakers_changed_since(
+    deps: Deps,
+    height: u64,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let start_after = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    // Resume after START_AFTER if it is still where the previous page
+    // left it, otherwise start from the first change after HEIGHT.
+    let resume = match &start_after {
+        Some(addr) => STAKED_BALANCE_CHANGED
+            .may_load(deps.storage, addr)?
+            .filter(|changed| *changed > height)
+            .map(|changed| (changed, addr)),
+        None => None,
+    };
+    let changes = match resume {
+        Some(key) => STAKERS_BY_CHANGE.range(
+            deps.storage,
+            Some(Bound::exclusive(key)),
+            None,
+            Order::Ascending,
+        ),
+        None => STAKERS_BY_CHANGE.prefix_range(
+            deps.storage,
+            Some(PrefixBound::exclusive(height)),
+            None,
+            Order::Ascending,
+        ),
+    };
+    let changes = match limit {
+        Some(limit) => changes
+            .take(limit as usize)
+            .collect::<StdResult<Vec<_>>>()?,
+        None => changes.collect::<StdResult<Vec<_>>>()?,
+    };
+
+    let stakers = changes
+        .into_iter()
+        .map(|((height, address), _)| {
+            Ok(StakerChangeResponse {
+                balance: STAKED_BALANCES
+                    .may_load(deps.storage, &address)?
+                    .unwrap_or_default(),
+                address: address.into_string(),
+                height,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    to_binary(&ListStakersChangedResponse { stakers })
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
     // Set contract to version to latest
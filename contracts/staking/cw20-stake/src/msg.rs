@@ -17,18 +17,31 @@ pub struct InstantiateMsg {
 #[cw_serde]
 pub enum ExecuteMsg {
     Receive(Cw20ReceiveMsg),
+    /// Unstakes AMOUNT of the sender's staked tokens. If AUTO_CLAIM
+    /// is set, any of the sender's claims which have matured are
+    /// claimed in the same transaction.
     Unstake {
         amount: Uint128,
+        #[serde(default)]
+        auto_claim: bool,
     },
+    /// Unstakes all of the sender's staked tokens and claims any of
+    /// their claims which have matured.
+    UnstakeAll {},
     Claim {},
     UpdateConfig {
         owner: Option<String>,
         manager: Option<String>,
         duration: Option<Duration>,
     },
+    /// Adds ADDR as a consumer of stake changed hooks, which are sent
+    /// whenever tokens are staked or unstaked. Only the owner or
+    /// manager may call this.
     AddHook {
         addr: String,
     },
+    /// Removes ADDR as a consumer of stake changed hooks. Only the
+    /// owner or manager may call this.
     RemoveHook {
         addr: String,
     },
@@ -65,6 +78,17 @@ pub enum QueryMsg {
         start_after: Option<String>,
         limit: Option<u32>,
     },
+    /// Lists the stakers whose staked balance changed after HEIGHT,
+    /// ordered by the height of their most recent change. Stakers
+    /// whose balance changes while paginating move to the end of the
+    /// list. Balances which have not changed since this contract
+    /// began tracking changes are not listed.
+    #[returns(ListStakersChangedResponse)]
+    ListStakersChangedSince {
+        height: u64,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 }
 
 #[cw_serde]
@@ -110,3 +134,17 @@ pub struct StakerBalanceResponse {
     pub address: String,
     pub balance: Uint128,
 }
+
+#[cw_serde]
+pub struct ListStakersChangedResponse {
+    pub stakers: Vec<StakerChangeResponse>,
+}
+
+#[cw_serde]
+pub struct StakerChangeResponse {
+    pub address: String,
+    /// The staker's current staked balance.
+    pub balance: Uint128,
+    /// The height at which the staked balance last changed.
+    pub height: u64,
+}
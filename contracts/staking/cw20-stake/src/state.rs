@@ -1,8 +1,8 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Empty, Uint128};
 use cw_controllers::Claims;
 use cw_controllers::Hooks;
-use cw_storage_plus::{Item, SnapshotItem, SnapshotMap, Strategy};
+use cw_storage_plus::{Item, Map, SnapshotItem, SnapshotMap, Strategy};
 use cw_utils::Duration;
 
 #[cw_serde]
@@ -29,6 +29,22 @@ pub const STAKED_TOTAL: SnapshotItem<Uint128> = SnapshotItem::new(
     Strategy::EveryBlock,
 );
 
+/// The height at which each address' staked balance last
+/// changed. Balances at later heights are read directly instead of
+/// searching the changelog, which is never compacted. Missing for
+/// balances which have not changed since this was tracked, in which
+/// case the changelog is searched.
+pub const STAKED_BALANCE_CHANGED: Map<&Addr, u64> = Map::new("staked_balance__changed");
+
+/// Stakers keyed by the height at which their staked balance last
+/// changed. Used to list the stakers whose balances changed after a
+/// height.
+pub const STAKERS_BY_CHANGE: Map<(u64, &Addr), Empty> = Map::new("stakers_by_change");
+
+/// The height at which the total staked last changed. See
+/// `STAKED_BALANCE_CHANGED`.
+pub const STAKED_TOTAL_CHANGED: Item<u64> = Item::new("total_staked__changed");
+
 /// The maximum number of claims that may be outstanding.
 pub const MAX_CLAIMS: u64 = 100;
 
@@ -1,17 +1,22 @@
 use std::borrow::BorrowMut;
 
 use crate::contract::{migrate, CONTRACT_NAME, CONTRACT_VERSION};
+use crate::hooks::StakeChangedHookMsg;
 use crate::msg::{
-    ExecuteMsg, ListStakersResponse, MigrateMsg, QueryMsg, ReceiveMsg,
-    StakedBalanceAtHeightResponse, StakedValueResponse, StakerBalanceResponse,
-    TotalStakedAtHeightResponse, TotalValueResponse,
+    ExecuteMsg, GetHooksResponse, ListStakersChangedResponse, ListStakersResponse, MigrateMsg,
+    QueryMsg, ReceiveMsg, StakedBalanceAtHeightResponse, StakedValueResponse,
+    StakerBalanceResponse, StakerChangeResponse, TotalStakedAtHeightResponse, TotalValueResponse,
 };
 use crate::state::{Config, MAX_CLAIMS};
 use crate::ContractError;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
-use cosmwasm_std::{from_slice, to_binary, Addr, Empty, MessageInfo, Storage, Uint128};
+use cosmwasm_std::{
+    from_slice, to_binary, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response,
+    StdResult, Storage, Uint128,
+};
 use cw20::Cw20Coin;
+use cw_storage_plus::Item;
 use cw_utils::Duration;
 
 use cw_multi_test::{next_block, App, AppResponse, Contract, ContractWrapper, Executor};
@@ -45,6 +50,49 @@ pub fn contract_cw20() -> Box<dyn Contract<Empty>> {
     Box::new(contract)
 }
 
+#[cw_serde]
+enum HookReceiverExecuteMsg {
+    StakeChangeHook(StakeChangedHookMsg),
+}
+
+/// The stake change hooks received by the hook receiver.
+const RECEIVED_HOOKS: Item<Vec<StakeChangedHookMsg>> = Item::new("received_hooks");
+
+fn hook_receiver_instantiate(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: Empty,
+) -> StdResult<Response> {
+    Ok(Response::new())
+}
+
+fn hook_receiver_execute(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: HookReceiverExecuteMsg,
+) -> StdResult<Response> {
+    let HookReceiverExecuteMsg::StakeChangeHook(hook) = msg;
+    let mut received = RECEIVED_HOOKS.may_load(deps.storage)?.unwrap_or_default();
+    received.push(hook);
+    RECEIVED_HOOKS.save(deps.storage, &received)?;
+    Ok(Response::new())
+}
+
+fn hook_receiver_query(deps: Deps, _env: Env, _msg: Empty) -> StdResult<Binary> {
+    to_binary(&RECEIVED_HOOKS.may_load(deps.storage)?.unwrap_or_default())
+}
+
+fn contract_hook_receiver() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        hook_receiver_execute,
+        hook_receiver_instantiate,
+        hook_receiver_query,
+    );
+    Box::new(contract)
+}
+
 fn mock_app() -> App {
     App::default()
 }
@@ -123,6 +171,34 @@ fn query_staked_balance<T: Into<String>, U: Into<String>>(
     result.balance
 }
 
+fn query_staked_balance_at_height<T: Into<String>, U: Into<String>>(
+    app: &App,
+    contract_addr: T,
+    address: U,
+    height: u64,
+) -> Uint128 {
+    let msg = QueryMsg::StakedBalanceAtHeight {
+        address: address.into(),
+        height: Some(height),
+    };
+    let result: StakedBalanceAtHeightResponse =
+        app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+    result.balance
+}
+
+fn query_total_staked_at_height<T: Into<String>>(
+    app: &App,
+    contract_addr: T,
+    height: u64,
+) -> Uint128 {
+    let msg = QueryMsg::TotalStakedAtHeight {
+        height: Some(height),
+    };
+    let result: TotalStakedAtHeightResponse =
+        app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+    result.total
+}
+
 fn query_config<T: Into<String>>(app: &App, contract_addr: T) -> Config {
     let msg = QueryMsg::GetConfig {};
     app.wrap().query_wasm_smart(contract_addr, &msg).unwrap()
@@ -202,7 +278,10 @@ fn unstake_tokens(
     info: MessageInfo,
     amount: Uint128,
 ) -> AnyResult<AppResponse> {
-    let msg = ExecuteMsg::Unstake { amount };
+    let msg = ExecuteMsg::Unstake {
+        amount,
+        auto_claim: false,
+    };
     app.execute_contract(info.sender, staking_addr.clone(), &msg, &[])
 }
 
@@ -535,6 +614,196 @@ fn test_staking() {
     assert_eq!(get_balance(&app, &cw20_addr, ADDR1), Uint128::from(30u128));
 }
 
+#[test]
+fn test_staked_at_height() {
+    let mut app = mock_app();
+    let initial_balances = vec![
+        Cw20Coin {
+            address: ADDR1.to_string(),
+            amount: Uint128::new(100),
+        },
+        Cw20Coin {
+            address: ADDR2.to_string(),
+            amount: Uint128::new(100),
+        },
+    ];
+    let (staking_addr, cw20_addr) = setup_test_case(&mut app, initial_balances, None);
+    let start = app.block_info().height;
+
+    // ADDR1 stakes 10 in each of the next three blocks, and 5 more
+    // in the last one. ADDR2 stakes 7 a few blocks later.
+    let stakes = [
+        (ADDR1, 10),
+        (ADDR1, 10),
+        (ADDR1, 10),
+        (ADDR1, 5),
+        (ADDR2, 7),
+    ];
+    for (i, (staker, amount)) in stakes.into_iter().enumerate() {
+        match i {
+            1 | 2 => app.update_block(next_block),
+            4 => app.update_block(|block| block.height += 4),
+            _ => (),
+        }
+        stake_tokens(
+            &mut app,
+            &staking_addr,
+            &cw20_addr,
+            mock_info(staker, &[]),
+            Uint128::new(amount),
+        )
+        .unwrap();
+    }
+    let end = app.block_info().height;
+
+    // Changes are reflected from the block after they happen.
+    let expected = [0, 10, 20, 35, 35, 35, 35, 35, 35];
+    for (offset, expected) in expected.into_iter().enumerate() {
+        let height = start + offset as u64;
+        assert_eq!(
+            query_staked_balance_at_height(&app, &staking_addr, ADDR1, height),
+            Uint128::new(expected)
+        );
+        let total = if height > end { expected + 7 } else { expected };
+        assert_eq!(
+            query_total_staked_at_height(&app, &staking_addr, height),
+            Uint128::new(total)
+        );
+    }
+    assert_eq!(
+        query_staked_balance_at_height(&app, &staking_addr, ADDR2, end),
+        Uint128::zero()
+    );
+    assert_eq!(
+        query_staked_balance_at_height(&app, &staking_addr, ADDR2, end + 1),
+        Uint128::new(7)
+    );
+    assert_eq!(
+        query_total_staked_at_height(&app, &staking_addr, end + 1),
+        Uint128::new(42)
+    );
+}
+
+#[test]
+fn test_staked_at_height_without_last_change() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let addr = Addr::unchecked(ADDR1);
+
+    // Contracts migrated from versions which did not record when
+    // balances last changed only have the snapshots.
+    let height = env.block.height;
+    crate::state::STAKED_BALANCES
+        .save(&mut deps.storage, &addr, &Uint128::new(10), height - 10)
+        .unwrap();
+    crate::state::STAKED_BALANCES
+        .save(&mut deps.storage, &addr, &Uint128::new(25), height - 5)
+        .unwrap();
+    crate::state::STAKED_TOTAL
+        .save(&mut deps.storage, &Uint128::new(10), height - 10)
+        .unwrap();
+    crate::state::STAKED_TOTAL
+        .save(&mut deps.storage, &Uint128::new(25), height - 5)
+        .unwrap();
+
+    for (at, expected) in [
+        (height - 10, 0),
+        (height - 9, 10),
+        (height - 5, 10),
+        (height - 4, 25),
+        (height, 25),
+    ] {
+        let balance = crate::contract::query_staked_balance_at_height(
+            deps.as_ref(),
+            env.clone(),
+            ADDR1.to_string(),
+            Some(at),
+        )
+        .unwrap();
+        assert_eq!(balance.balance, Uint128::new(expected));
+        let total =
+            crate::contract::query_total_staked_at_height(deps.as_ref(), env.clone(), Some(at))
+                .unwrap();
+        assert_eq!(total.total, Uint128::new(expected));
+    }
+}
+
+#[test]
+fn test_staked_at_height_after_many_changes() {
+    let mut app = mock_app();
+    let initial_balances = vec![
+        Cw20Coin {
+            address: ADDR1.to_string(),
+            amount: Uint128::new(1000),
+        },
+        Cw20Coin {
+            address: ADDR2.to_string(),
+            amount: Uint128::new(100),
+        },
+    ];
+    let (staking_addr, cw20_addr) = setup_test_case(&mut app, initial_balances, None);
+    let start = app.block_info().height;
+
+    // ADDR2 stakes once, and ADDR1 changes its stake every block
+    // after that, alternating between staking 3 and unstaking 1.
+    stake_tokens(
+        &mut app,
+        &staking_addr,
+        &cw20_addr,
+        mock_info(ADDR2, &[]),
+        Uint128::new(7),
+    )
+    .unwrap();
+    let mut history = vec![0];
+    for i in 0..300u128 {
+        app.update_block(next_block);
+        let last = *history.last().unwrap();
+        if i % 2 == 0 {
+            stake_tokens(
+                &mut app,
+                &staking_addr,
+                &cw20_addr,
+                mock_info(ADDR1, &[]),
+                Uint128::new(3),
+            )
+            .unwrap();
+            history.push(last + 3);
+        } else {
+            unstake_tokens(
+                &mut app,
+                &staking_addr,
+                mock_info(ADDR1, &[]),
+                Uint128::new(1),
+            )
+            .unwrap();
+            history.push(last - 1);
+        }
+    }
+    app.update_block(next_block);
+
+    // HISTORY[i] is ADDR1's balance at the end of block START + i, so
+    // it is the balance queried at the height after that.
+    for (offset, balance) in history.into_iter().enumerate() {
+        let height = start + offset as u64 + 1;
+        assert_eq!(
+            query_staked_balance_at_height(&app, &staking_addr, ADDR1, height),
+            Uint128::new(balance)
+        );
+        assert_eq!(
+            query_total_staked_at_height(&app, &staking_addr, height),
+            Uint128::new(balance + 7)
+        );
+        assert_eq!(
+            query_staked_balance_at_height(&app, &staking_addr, ADDR2, height),
+            Uint128::new(7)
+        );
+    }
+    assert_eq!(
+        query_staked_balance_at_height(&app, &staking_addr, ADDR2, start),
+        Uint128::zero()
+    );
+}
+
 #[test]
 fn text_max_claims() {
     let mut app = mock_app();
@@ -1005,6 +1274,69 @@ fn test_simple_unstaking_with_duration() {
     assert_eq!(get_balance(&app, &cw20_addr, ADDR2), Uint128::from(100u128));
 }
 
+#[test]
+fn test_unstake_with_auto_claim() {
+    let mut app = mock_app();
+    let initial_balances = vec![Cw20Coin {
+        address: ADDR1.to_string(),
+        amount: Uint128::new(100),
+    }];
+    let (staking_addr, cw20_addr) =
+        setup_test_case(&mut app, initial_balances, Some(Duration::Height(1)));
+
+    let info = mock_info(ADDR1, &[]);
+    stake_tokens(&mut app, &staking_addr, &cw20_addr, info, Uint128::new(100)).unwrap();
+    app.update_block(next_block);
+    let info = mock_info(ADDR1, &[]);
+    unstake_tokens(&mut app, &staking_addr, info, Uint128::new(40)).unwrap();
+    app.update_block(next_block);
+
+    // The matured claim is paid out and a new one is created.
+    app.execute_contract(
+        Addr::unchecked(ADDR1),
+        staking_addr.clone(),
+        &ExecuteMsg::Unstake {
+            amount: Uint128::new(10),
+            auto_claim: true,
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(get_balance(&app, &cw20_addr, ADDR1), Uint128::new(40));
+    let claims = query_claims(&app, &staking_addr, ADDR1);
+    assert_eq!(claims.len(), 1);
+    assert_eq!(claims[0].amount, Uint128::new(10));
+    app.update_block(next_block);
+
+    app.execute_contract(
+        Addr::unchecked(ADDR1),
+        staking_addr.clone(),
+        &ExecuteMsg::UnstakeAll {},
+        &[],
+    )
+    .unwrap();
+    assert_eq!(get_balance(&app, &cw20_addr, ADDR1), Uint128::new(50));
+    assert_eq!(
+        query_staked_balance(&app, &staking_addr, ADDR1),
+        Uint128::zero()
+    );
+    let claims = query_claims(&app, &staking_addr, ADDR1);
+    assert_eq!(claims.len(), 1);
+    assert_eq!(claims[0].amount, Uint128::new(50));
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ADDR1),
+            staking_addr,
+            &ExecuteMsg::UnstakeAll {},
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NothingStaked {});
+}
+
 #[test]
 fn test_double_unstake_at_height() {
     let mut app = App::default();
@@ -1203,6 +1535,184 @@ fn test_query_list_stakers() {
     assert_eq!(stakers, test_res)
 }
 
+#[test]
+fn test_query_list_stakers_changed_since() {
+    let mut app = App::default();
+
+    let (staking_addr, cw20_addr) = setup_test_case(
+        &mut app,
+        vec![
+            Cw20Coin {
+                address: "ekez1".to_string(),
+                amount: Uint128::new(10),
+            },
+            Cw20Coin {
+                address: "ekez2".to_string(),
+                amount: Uint128::new(20),
+            },
+        ],
+        None,
+    );
+
+    let start = app.block_info().height;
+    stake_tokens(
+        &mut app,
+        &staking_addr,
+        &cw20_addr,
+        mock_info("ekez2", &[]),
+        Uint128::new(20),
+    )
+    .unwrap();
+    stake_tokens(
+        &mut app,
+        &staking_addr,
+        &cw20_addr,
+        mock_info("ekez1", &[]),
+        Uint128::new(10),
+    )
+    .unwrap();
+    app.update_block(next_block);
+    unstake_tokens(
+        &mut app,
+        &staking_addr,
+        mock_info("ekez1", &[]),
+        Uint128::new(5),
+    )
+    .unwrap();
+
+    let list = |app: &App, height: u64, start_after: Option<&str>, limit: Option<u32>| {
+        let res: ListStakersChangedResponse = app
+            .wrap()
+            .query_wasm_smart(
+                staking_addr.clone(),
+                &QueryMsg::ListStakersChangedSince {
+                    height,
+                    start_after: start_after.map(str::to_string),
+                    limit,
+                },
+            )
+            .unwrap();
+        res.stakers
+    };
+
+    let ekez1 = StakerChangeResponse {
+        address: "ekez1".to_string(),
+        balance: Uint128::new(5),
+        height: start + 1,
+    };
+    let ekez2 = StakerChangeResponse {
+        address: "ekez2".to_string(),
+        balance: Uint128::new(20),
+        height: start,
+    };
+    assert_eq!(
+        list(&app, start - 1, None, None),
+        vec![ekez2.clone(), ekez1.clone()]
+    );
+    assert_eq!(list(&app, start, None, None), vec![ekez1.clone()]);
+    assert!(list(&app, start + 1, None, None).is_empty());
+
+    assert_eq!(list(&app, start - 1, None, Some(1)), vec![ekez2]);
+    assert_eq!(list(&app, start - 1, Some("ekez2"), None), vec![ekez1]);
+}
+
+#[test]
+fn test_stake_changed_hooks() {
+    let mut app = mock_app();
+    let initial_balances = vec![Cw20Coin {
+        address: ADDR1.to_string(),
+        amount: Uint128::new(100),
+    }];
+    let (staking_addr, cw20_addr) =
+        setup_test_case(&mut app, initial_balances, Some(Duration::Height(5)));
+    let receiver_id = app.store_code(contract_hook_receiver());
+    let receiver = app
+        .instantiate_contract(
+            receiver_id,
+            Addr::unchecked(ADDR1),
+            &Empty {},
+            &[],
+            "receiver",
+            None,
+        )
+        .unwrap();
+    let received = |app: &App| -> Vec<StakeChangedHookMsg> {
+        app.wrap().query_wasm_smart(&receiver, &Empty {}).unwrap()
+    };
+
+    // Only the owner and manager may add and remove hooks.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ADDR1),
+            staking_addr.clone(),
+            &ExecuteMsg::AddHook {
+                addr: receiver.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+    app.execute_contract(
+        Addr::unchecked("owner"),
+        staking_addr.clone(),
+        &ExecuteMsg::AddHook {
+            addr: receiver.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    let hooks: GetHooksResponse = app
+        .wrap()
+        .query_wasm_smart(&staking_addr, &QueryMsg::GetHooks {})
+        .unwrap();
+    assert_eq!(hooks.hooks, vec![receiver.to_string()]);
+
+    let info = mock_info(ADDR1, &[]);
+    stake_tokens(
+        &mut app,
+        &staking_addr,
+        &cw20_addr,
+        info.clone(),
+        Uint128::new(50),
+    )
+    .unwrap();
+    app.update_block(next_block);
+    unstake_tokens(&mut app, &staking_addr, info.clone(), Uint128::new(20)).unwrap();
+    assert_eq!(
+        received(&app),
+        vec![
+            StakeChangedHookMsg::Stake {
+                addr: Addr::unchecked(ADDR1),
+                amount: Uint128::new(50),
+            },
+            StakeChangedHookMsg::Unstake {
+                addr: Addr::unchecked(ADDR1),
+                amount: Uint128::new(20),
+            },
+        ]
+    );
+
+    // Claiming unstaked tokens once the unstaking duration has passed
+    // does not change the stake, so no hook is sent.
+    app.update_block(|b| b.height += 5);
+    claim_tokens(&mut app, &staking_addr, info.clone()).unwrap();
+    assert_eq!(received(&app).len(), 2);
+
+    app.execute_contract(
+        Addr::unchecked("manager"),
+        staking_addr.clone(),
+        &ExecuteMsg::RemoveHook {
+            addr: receiver.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    stake_tokens(&mut app, &staking_addr, &cw20_addr, info, Uint128::new(10)).unwrap();
+    assert_eq!(received(&app).len(), 2);
+}
+
 #[test]
 pub fn test_migrate_update_version() {
     let mut deps = mock_dependencies();
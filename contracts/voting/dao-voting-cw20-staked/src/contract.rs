@@ -7,6 +7,7 @@ use cosmwasm_std::{
 use cw2::set_contract_version;
 use cw20::{Cw20Coin, TokenInfoResponse};
 use cw_utils::parse_reply_instantiate_data;
+use dao_events::EventSource;
 use dao_interface::voting::IsActiveResponse;
 use std::convert::TryInto;
 
@@ -23,6 +24,8 @@ use crate::state::{
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-voting-cw20-staked";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+const EVENTS: EventSource = EventSource::new(CONTRACT_NAME, CONTRACT_VERSION);
+
 const INSTANTIATE_TOKEN_REPLY_ID: u64 = 0;
 const INSTANTIATE_STAKING_REPLY_ID: u64 = 1;
 
@@ -224,7 +227,9 @@ pub fn execute_update_active_threshold(
         ACTIVE_THRESHOLD.remove(deps.storage);
     }
 
-    Ok(Response::new().add_attribute("action", "update_active_threshold"))
+    Ok(Response::new()
+        .add_attribute("action", "update_active_threshold")
+        .add_event(EVENTS.event("update_active_threshold").into()))
 }
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
@@ -370,7 +375,8 @@ pub fn query_active_threshold(deps: Deps) -> StdResult<Binary> {
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    msg.check_stored_version(deps.storage, CONTRACT_VERSION)?;
     // Set contract to version to latest
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     Ok(Response::default())
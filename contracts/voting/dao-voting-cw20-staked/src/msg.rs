@@ -101,5 +101,4 @@ pub struct ActiveThresholdResponse {
     pub active_threshold: Option<ActiveThreshold>,
 }
 
-#[cw_serde]
-pub struct MigrateMsg {}
+pub use dao_interface::migrate::MigrateMsg;
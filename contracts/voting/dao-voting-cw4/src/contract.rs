@@ -5,15 +5,18 @@ use cosmwasm_std::{
     SubMsg, Uint128, WasmMsg,
 };
 use cw2::set_contract_version;
-use cw_utils::parse_reply_instantiate_data;
+use cw_utils::{parse_reply_instantiate_data, Duration};
+use dao_events::EventSource;
 
 use crate::error::ContractError;
 use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
-use crate::state::{DAO, GROUP_CONTRACT, TOTAL_WEIGHT, USER_WEIGHTS};
+use crate::state::{DAO, GROUP_CONTRACT, PAUSED, TOTAL_WEIGHT, USER_WEIGHTS};
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-voting-cw4";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+const EVENTS: EventSource = EventSource::new(CONTRACT_NAME, CONTRACT_VERSION);
+
 const INSTANTIATE_GROUP_REPLY_ID: u64 = 0;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -82,11 +85,62 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
+    if !matches!(msg, ExecuteMsg::Pause { .. } | ExecuteMsg::Unpause {}) {
+        PAUSED.assert_not_paused(deps.storage, &env.block)?;
+    }
+
     match msg {
         ExecuteMsg::MemberChangedHook { diffs } => {
             execute_member_changed_hook(deps, env, info, diffs)
         }
+        ExecuteMsg::Pause { duration, reason } => execute_pause(deps, env, info, duration, reason),
+        ExecuteMsg::Unpause {} => execute_unpause(deps, info),
+    }
+}
+
+pub fn execute_pause(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    duration: Duration,
+    reason: Option<String>,
+) -> Result<Response, ContractError> {
+    if info.sender != DAO.load(deps.storage)? {
+        return Err(ContractError::Unauthorized {});
     }
+
+    let until = PAUSED.pause(
+        deps.storage,
+        &env.block,
+        duration,
+        info.sender.clone(),
+        reason.clone(),
+    )?;
+
+    let mut event = EVENTS
+        .event("pause")
+        .attribute("until", until.to_string())
+        .address("paused_by", &info.sender);
+    if let Some(reason) = reason {
+        event = event.attribute("reason", reason);
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "pause")
+        .add_attribute("until", until.to_string())
+        .add_event(event.into()))
+}
+
+pub fn execute_unpause(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    if info.sender != DAO.load(deps.storage)? {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    PAUSED.unpause(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute("action", "unpause")
+        .add_event(EVENTS.event("unpause").into()))
 }
 
 pub fn execute_member_changed_hook(
@@ -105,8 +159,10 @@ pub fn execute_member_changed_hook(
     // In seperate counters to apply at once and prevent underflow
     let mut positive_difference: Uint128 = Uint128::zero();
     let mut negative_difference: Uint128 = Uint128::zero();
+    let mut event = EVENTS.event("update_members");
     for diff in diffs {
         let user_address = deps.api.addr_validate(&diff.key)?;
+        event = event.address("member", &user_address);
         let weight = diff.new.unwrap_or_default();
         let old = diff.old.unwrap_or_default();
         // Do we need to add to positive difference or negative difference
@@ -141,7 +197,12 @@ pub fn execute_member_changed_hook(
 
     Ok(Response::new()
         .add_attribute("action", "member_changed_hook")
-        .add_attribute("total_weight", new_total_weight.to_string()))
+        .add_attribute("total_weight", new_total_weight.to_string())
+        .add_event(
+            event
+                .attribute("total_weight", new_total_weight.to_string())
+                .into(),
+        ))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -154,6 +215,7 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::Info {} => query_info(deps),
         QueryMsg::GroupContract {} => to_binary(&GROUP_CONTRACT.load(deps.storage)?),
         QueryMsg::Dao {} => to_binary(&DAO.load(deps.storage)?),
+        QueryMsg::PauseInfo {} => to_binary(&PAUSED.query_pause_info(deps.storage, &env.block)?),
     }
 }
 
@@ -186,7 +248,8 @@ pub fn query_info(deps: Deps) -> StdResult<Binary> {
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    msg.check_stored_version(deps.storage, CONTRACT_VERSION)?;
     // Set contract to version to latest
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     Ok(Response::default())
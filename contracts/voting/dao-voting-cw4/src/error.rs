@@ -1,4 +1,5 @@
 use cosmwasm_std::StdError;
+use dao_interface::migrate::MigrationError;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -6,6 +7,12 @@ pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
+    #[error(transparent)]
+    MigrationError(#[from] MigrationError),
+
+    #[error(transparent)]
+    PauseError(#[from] dao_pausable::PauseError),
+
     #[error("Unauthorized")]
     Unauthorized {},
 
@@ -1,5 +1,5 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use dao_macros::voting_module_query;
+use dao_macros::{pausable, pausable_query, voting_module_query};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -7,11 +7,13 @@ pub struct InstantiateMsg {
     pub initial_members: Vec<cw4::Member>,
 }
 
+#[pausable]
 #[cw_serde]
 pub enum ExecuteMsg {
     MemberChangedHook { diffs: Vec<cw4::MemberDiff> },
 }
 
+#[pausable_query]
 #[voting_module_query]
 #[cw_serde]
 #[derive(QueryResponses)]
@@ -20,5 +22,4 @@ pub enum QueryMsg {
     GroupContract {},
 }
 
-#[cw_serde]
-pub struct MigrateMsg {}
+pub use dao_interface::migrate::MigrateMsg;
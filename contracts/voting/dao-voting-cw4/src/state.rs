@@ -1,5 +1,6 @@
 use cosmwasm_std::{Addr, Uint128};
 use cw_storage_plus::{Item, SnapshotItem, SnapshotMap, Strategy};
+use dao_pausable::Pausable;
 
 pub const USER_WEIGHTS: SnapshotMap<&Addr, Uint128> = SnapshotMap::new(
     "user_weights",
@@ -17,3 +18,5 @@ pub const TOTAL_WEIGHT: SnapshotItem<Uint128> = SnapshotItem::new(
 
 pub const GROUP_CONTRACT: Item<Addr> = Item::new("group_contract");
 pub const DAO: Item<Addr> = Item::new("dao_address");
+/// While paused, voting power can not change.
+pub const PAUSED: Pausable = Pausable::new("paused", "pause_metadata");
@@ -4,9 +4,11 @@ use cosmwasm_std::{
 };
 use cw2::ContractVersion;
 use cw_multi_test::{next_block, App, Contract, ContractWrapper, Executor};
+use cw_utils::Duration;
 use dao_interface::voting::{
     InfoResponse, TotalPowerAtHeightResponse, VotingPowerAtHeightResponse,
 };
+use dao_pausable::{PauseError, PauseInfoResponse};
 
 use crate::{
     contract::{migrate, CONTRACT_NAME, CONTRACT_VERSION},
@@ -202,6 +204,93 @@ fn test_permissions() {
     assert!(matches!(err, ContractError::Unauthorized {}));
 }
 
+#[test]
+fn test_pause() {
+    let mut app = App::default();
+    let voting_addr = setup_test_case(&mut app);
+    let cw4_addr: Addr = app
+        .wrap()
+        .query_wasm_smart(voting_addr.clone(), &QueryMsg::GroupContract {})
+        .unwrap();
+    let pause = ExecuteMsg::Pause {
+        duration: Duration::Height(10),
+        reason: Some("compromised member".to_string()),
+    };
+    let update_members = |app: &mut App, weight: u64| {
+        app.execute_contract(
+            Addr::unchecked(DAO_ADDR),
+            cw4_addr.clone(),
+            &cw4_group::msg::ExecuteMsg::UpdateMembers {
+                remove: vec![],
+                add: vec![cw4::Member {
+                    addr: ADDR1.to_string(),
+                    weight,
+                }],
+            },
+            &[],
+        )
+    };
+
+    // Only the DAO may pause the contract.
+    let err: ContractError = app
+        .execute_contract(Addr::unchecked(ADDR1), voting_addr.clone(), &pause, &[])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    app.execute_contract(Addr::unchecked(DAO_ADDR), voting_addr.clone(), &pause, &[])
+        .unwrap();
+    let info: PauseInfoResponse = app
+        .wrap()
+        .query_wasm_smart(voting_addr.clone(), &QueryMsg::PauseInfo {})
+        .unwrap();
+    assert!(matches!(
+        info,
+        PauseInfoResponse::Paused { reason: Some(ref reason), .. } if reason == "compromised member"
+    ));
+
+    // Voting power can not change while paused.
+    let err: ContractError = update_members(&mut app, 2).unwrap_err().downcast().unwrap();
+    assert!(matches!(
+        err,
+        ContractError::PauseError(PauseError::Paused { .. })
+    ));
+
+    // Only the DAO may unpause the contract.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ADDR1),
+            voting_addr.clone(),
+            &ExecuteMsg::Unpause {},
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    app.execute_contract(
+        Addr::unchecked(DAO_ADDR),
+        voting_addr.clone(),
+        &ExecuteMsg::Unpause {},
+        &[],
+    )
+    .unwrap();
+    update_members(&mut app, 2).unwrap();
+
+    // Pauses expire.
+    app.execute_contract(Addr::unchecked(DAO_ADDR), voting_addr.clone(), &pause, &[])
+        .unwrap();
+    update_members(&mut app, 3).unwrap_err();
+    app.update_block(|block| block.height += 10);
+    update_members(&mut app, 3).unwrap();
+    let info: PauseInfoResponse = app
+        .wrap()
+        .query_wasm_smart(voting_addr, &QueryMsg::PauseInfo {})
+        .unwrap();
+    assert_eq!(info, PauseInfoResponse::Unpaused {});
+}
+
 #[test]
 fn test_power_at_height() {
     let mut app = App::default();
@@ -532,7 +621,7 @@ fn test_migrate() {
         CosmosMsg::Wasm(WasmMsg::Migrate {
             contract_addr: voting_addr.to_string(),
             new_code_id: voting_id,
-            msg: to_binary(&MigrateMsg {}).unwrap(),
+            msg: to_binary(&MigrateMsg::FromCompatible {}).unwrap(),
         }),
     )
     .unwrap();
@@ -673,7 +762,7 @@ fn test_zero_voting_power() {
 pub fn test_migrate_update_version() {
     let mut deps = mock_dependencies();
     cw2::set_contract_version(&mut deps.storage, "my-contract", "old-version").unwrap();
-    migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+    migrate(deps.as_mut(), mock_env(), MigrateMsg::FromCompatible {}).unwrap();
     let version = cw2::get_contract_version(&deps.storage).unwrap();
     assert_eq!(version.version, CONTRACT_VERSION);
     assert_eq!(version.contract, CONTRACT_NAME);
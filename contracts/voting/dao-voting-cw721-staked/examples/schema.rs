@@ -1,8 +1,8 @@
-use cosmwasm_schema::write_api;
+use dao_schema::write_schema;
 use dao_voting_cw721_staked::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
 
 fn main() {
-    write_api! {
+    write_schema! {
         instantiate: InstantiateMsg,
         query: QueryMsg,
         execute: ExecuteMsg,
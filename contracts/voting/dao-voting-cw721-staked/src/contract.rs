@@ -1,24 +1,33 @@
 use crate::hooks::{stake_hook_msgs, unstake_hook_msgs};
 #[cfg(not(feature = "library"))]
-use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::msg::{
+    ActiveThreshold, ActiveThresholdResponse, ExecuteMsg, Ics721QueryMsg, InstantiateMsg,
+    ListStakersChangedResponse, QueryMsg, StakerChangeResponse,
+};
 use crate::state::{
-    register_staked_nft, register_unstaked_nft, Config, CONFIG, DAO, HOOKS, MAX_CLAIMS,
-    NFT_BALANCES, NFT_CLAIMS, STAKED_NFTS_PER_OWNER, TOTAL_STAKED_NFTS,
+    register_staked_nft, register_unstaked_nft, Config, Ics721Collection, ACTIVE_THRESHOLD, CONFIG,
+    DAO, HOOKS, ICS721_COLLECTION, MAX_CLAIMS, NFT_BALANCES, NFT_BALANCE_CHANGED, NFT_CLAIMS,
+    STAKED_NFTS_PER_OWNER, STAKERS_BY_CHANGE, TOTAL_STAKED_NFTS,
 };
 use crate::ContractError;
 use cosmwasm_std::{
-    entry_point, to_binary, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Response,
-    StdResult, Uint128, WasmMsg,
+    entry_point, to_binary, Addr, Binary, CosmosMsg, Decimal, Deps, DepsMut, Empty, Env,
+    MessageInfo, Response, StdResult, Uint128, WasmMsg,
 };
 use cw2::set_contract_version;
 use cw721::Cw721ReceiveMsg;
-use cw_storage_plus::Bound;
+use cw_paginate::keyset_bounds;
+use cw_storage_plus::{Bound, PrefixBound};
 use cw_utils::Duration;
+use dao_events::EventSource;
+use dao_interface::voting::IsActiveResponse;
 use dao_interface::Admin;
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-voting-cw721-staked";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+const EVENTS: EventSource = EventSource::new(CONTRACT_NAME, CONTRACT_VERSION);
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -46,6 +55,35 @@ pub fn instantiate(
     };
     CONFIG.save(deps.storage, &config)?;
 
+    if let Some(ics721) = msg.ics721 {
+        let bridge = deps.api.addr_validate(&ics721.bridge)?;
+        let bridged: Option<Addr> = deps.querier.query_wasm_smart(
+            &bridge,
+            &Ics721QueryMsg::NftContract {
+                class_id: ics721.class_id.clone(),
+            },
+        )?;
+        if bridged.as_ref() != Some(&config.nft_address) {
+            return Err(ContractError::Ics721ClassMismatch {
+                class_id: ics721.class_id,
+                bridged,
+                expected: config.nft_address,
+            });
+        }
+        ICS721_COLLECTION.save(
+            deps.storage,
+            &Ics721Collection {
+                bridge,
+                class_id: ics721.class_id,
+            },
+        )?;
+    }
+
+    if let Some(active_threshold) = msg.active_threshold {
+        assert_valid_active_threshold(deps.as_ref(), &config.nft_address, &active_threshold)?;
+        ACTIVE_THRESHOLD.save(deps.storage, &active_threshold)?;
+    }
+
     TOTAL_STAKED_NFTS.save(deps.storage, &Uint128::zero(), env.block.height)?;
 
     Ok(Response::default()
@@ -75,9 +113,40 @@ pub fn execute(
         }
         ExecuteMsg::AddHook { addr } => execute_add_hook(deps, info, addr),
         ExecuteMsg::RemoveHook { addr } => execute_remove_hook(deps, info, addr),
+        ExecuteMsg::UpdateActiveThreshold { new_threshold } => {
+            execute_update_active_threshold(deps, info, new_threshold)
+        }
     }
 }
 
+/// Gets the number of NFTs in the staked collection.
+fn query_nft_supply(deps: Deps, nft_address: &Addr) -> StdResult<Uint128> {
+    let supply: cw721::NumTokensResponse = deps
+        .querier
+        .query_wasm_smart(nft_address, &cw721::Cw721QueryMsg::NumTokens {})?;
+    Ok(Uint128::from(supply.count))
+}
+
+pub fn assert_valid_active_threshold(
+    deps: Deps,
+    nft_address: &Addr,
+    active_threshold: &ActiveThreshold,
+) -> Result<(), ContractError> {
+    match active_threshold {
+        ActiveThreshold::Percentage { percent } => {
+            if *percent > Decimal::percent(100) || percent.is_zero() {
+                return Err(ContractError::InvalidActivePercentage {});
+            }
+        }
+        ActiveThreshold::AbsoluteCount { count } => {
+            if *count > query_nft_supply(deps, nft_address)? {
+                return Err(ContractError::InvalidAbsoluteCount {});
+            }
+        }
+    }
+    Ok(())
+}
+
 pub fn execute_stake(
     deps: DepsMut,
     env: Env,
@@ -94,11 +163,16 @@ pub fn execute_stake(
     let staker = deps.api.addr_validate(&wrapper.sender)?;
     register_staked_nft(deps.storage, env.block.height, &staker, &wrapper.token_id)?;
     let hook_msgs = stake_hook_msgs(deps.storage, staker.clone(), wrapper.token_id.clone())?;
+    let event = EVENTS
+        .event("stake")
+        .address("staker", &staker)
+        .id("token_id", &wrapper.token_id);
     Ok(Response::default()
         .add_submessages(hook_msgs)
         .add_attribute("action", "stake")
         .add_attribute("from", staker)
-        .add_attribute("token_id", wrapper.token_id))
+        .add_attribute("token_id", wrapper.token_id)
+        .add_event(event.into()))
 }
 
 pub fn execute_unstake(
@@ -114,6 +188,10 @@ pub fn execute_unstake(
     register_unstaked_nft(deps.storage, env.block.height, &info.sender, &token_ids)?;
 
     let hook_msgs = unstake_hook_msgs(deps.storage, info.sender.clone(), token_ids.clone())?;
+    let event = token_ids.iter().fold(
+        EVENTS.event("unstake").address("staker", &info.sender),
+        |event, token_id| event.id("token_id", token_id),
+    );
 
     let config = CONFIG.load(deps.storage)?;
     match config.unstaking_duration {
@@ -137,7 +215,8 @@ pub fn execute_unstake(
                 .add_submessages(hook_msgs)
                 .add_attribute("action", "unstake")
                 .add_attribute("from", info.sender)
-                .add_attribute("claim_duration", "None"))
+                .add_attribute("claim_duration", "None")
+                .add_event(event.attribute("claim_duration", "None").into()))
         }
 
         Some(duration) => {
@@ -161,7 +240,12 @@ pub fn execute_unstake(
                 .add_attribute("action", "unstake")
                 .add_submessages(hook_msgs)
                 .add_attribute("from", info.sender)
-                .add_attribute("claim_duration", format!("{duration}")))
+                .add_attribute("claim_duration", format!("{duration}"))
+                .add_event(
+                    event
+                        .attribute("claim_duration", format!("{duration}"))
+                        .into(),
+                ))
         }
     }
 }
@@ -178,6 +262,10 @@ pub fn execute_claim_nfts(
 
     let config = CONFIG.load(deps.storage)?;
 
+    let event = nfts.iter().fold(
+        EVENTS.event("claim").address("staker", &info.sender),
+        |event, token_id| event.id("token_id", token_id),
+    );
     let msgs = nfts
         .into_iter()
         .map(|nft| -> StdResult<CosmosMsg> {
@@ -196,7 +284,8 @@ pub fn execute_claim_nfts(
     Ok(Response::default()
         .add_messages(msgs)
         .add_attribute("action", "claim_nfts")
-        .add_attribute("from", info.sender))
+        .add_attribute("from", info.sender)
+        .add_event(event.into()))
 }
 
 pub fn execute_update_config(
@@ -219,14 +308,18 @@ pub fn execute_update_config(
     config.unstaking_duration = duration;
     CONFIG.save(deps.storage, &config)?;
 
+    let owner = config
+        .owner
+        .map(|a| a.to_string())
+        .unwrap_or_else(|| "None".to_string());
     Ok(Response::default()
         .add_attribute("action", "update_config")
-        .add_attribute(
-            "owner",
-            config
-                .owner
-                .map(|a| a.to_string())
-                .unwrap_or_else(|| "None".to_string()),
+        .add_attribute("owner", owner.clone())
+        .add_event(
+            EVENTS
+                .event("update_config")
+                .attribute("owner", owner)
+                .into(),
         ))
 }
 
@@ -266,6 +359,29 @@ pub fn execute_remove_hook(
         .add_attribute("hook", addr))
 }
 
+pub fn execute_update_active_threshold(
+    deps: DepsMut,
+    info: MessageInfo,
+    new_active_threshold: Option<ActiveThreshold>,
+) -> Result<Response, ContractError> {
+    let dao = DAO.load(deps.storage)?;
+    if info.sender != dao {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if let Some(active_threshold) = new_active_threshold {
+        let config = CONFIG.load(deps.storage)?;
+        assert_valid_active_threshold(deps.as_ref(), &config.nft_address, &active_threshold)?;
+        ACTIVE_THRESHOLD.save(deps.storage, &active_threshold)?;
+    } else {
+        ACTIVE_THRESHOLD.remove(deps.storage);
+    }
+
+    Ok(Response::default()
+        .add_attribute("action", "update_active_threshold")
+        .add_event(EVENTS.event("update_active_threshold").into()))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
@@ -273,6 +389,7 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::Dao {} => query_dao(deps),
         QueryMsg::NftClaims { address } => query_nft_claims(deps, address),
         QueryMsg::Hooks {} => query_hooks(deps),
+        QueryMsg::Ics721Collection {} => query_ics721_collection(deps),
         QueryMsg::VotingPowerAtHeight { address, height } => {
             query_voting_power_at_height(deps, env, address, height)
         }
@@ -283,6 +400,13 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             start_after,
             limit,
         } => query_staked_nfts(deps, address, start_after, limit),
+        QueryMsg::ListStakersChangedSince {
+            height,
+            start_after,
+            limit,
+        } => query_list_stakers_changed_since(deps, height, start_after, limit),
+        QueryMsg::IsActive {} => query_is_active(deps),
+        QueryMsg::ActiveThreshold {} => query_active_threshold(deps),
     }
 }
 
@@ -326,6 +450,41 @@ pub fn query_hooks(deps: Deps) -> StdResult<Binary> {
     to_binary(&HOOKS.query_hooks(deps)?)
 }
 
+pub fn query_ics721_collection(deps: Deps) -> StdResult<Binary> {
+    to_binary(&ICS721_COLLECTION.may_load(deps.storage)?)
+}
+
+pub fn query_is_active(deps: Deps) -> StdResult<Binary> {
+    let active = match ACTIVE_THRESHOLD.may_load(deps.storage)? {
+        Some(threshold) => {
+            let staked = TOTAL_STAKED_NFTS.load(deps.storage)?;
+            let count = match threshold {
+                ActiveThreshold::AbsoluteCount { count } => count,
+                ActiveThreshold::Percentage { percent } => {
+                    // The number of NFTs in a collection is a u64
+                    // and percent's atomics are at most 10^18, so
+                    // their product fits in a u128. Rounds up so
+                    // that the module is active only once at least
+                    // PERCENT of the collection is staked.
+                    let config = CONFIG.load(deps.storage)?;
+                    let supply = query_nft_supply(deps, &config.nft_address)?;
+                    let scale = Uint128::from(10u64).pow(percent.decimal_places());
+                    (supply * percent.atomics() + scale - Uint128::one()) / scale
+                }
+            };
+            staked >= count
+        }
+        None => true,
+    };
+    to_binary(&IsActiveResponse { active })
+}
+
+pub fn query_active_threshold(deps: Deps) -> StdResult<Binary> {
+    to_binary(&ActiveThresholdResponse {
+        active_threshold: ACTIVE_THRESHOLD.may_load(deps.storage)?,
+    })
+}
+
 pub fn query_info(deps: Deps) -> StdResult<Binary> {
     let info = cw2::get_contract_version(deps.storage)?;
     to_binary(&dao_interface::voting::InfoResponse { info })
@@ -340,16 +499,64 @@ pub fn query_staked_nfts(
     let prefix = deps.api.addr_validate(&address)?;
     let prefix = STAKED_NFTS_PER_OWNER.prefix(&prefix);
 
-    let start_after = start_after.as_deref().map(Bound::exclusive);
-    let range = prefix.keys(
-        deps.storage,
-        start_after,
-        None,
-        cosmwasm_std::Order::Ascending,
-    );
+    let (min, max) = keyset_bounds(start_after.as_deref(), cosmwasm_std::Order::Ascending);
+    let range = prefix.keys(deps.storage, min, max, cosmwasm_std::Order::Ascending);
     let range: StdResult<Vec<String>> = match limit {
         Some(l) => range.take(l as usize).collect(),
         None => range.collect(),
     };
     to_binary(&range?)
 }
+
+pub fn query_list_stakers_changed_since(
+    deps: Deps,
+    height: u64,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let start_after = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    // Resume after START_AFTER if it is still where the previous page
+    // left it, otherwise start from the first change after HEIGHT.
+    let resume = match &start_after {
+        Some(addr) => NFT_BALANCE_CHANGED
+            .may_load(deps.storage, addr)?
+            .filter(|changed| *changed > height)
+            .map(|changed| (changed, addr)),
+        None => None,
+    };
+    let changes = match resume {
+        Some(key) => STAKERS_BY_CHANGE.range(
+            deps.storage,
+            Some(Bound::exclusive(key)),
+            None,
+            cosmwasm_std::Order::Ascending,
+        ),
+        None => STAKERS_BY_CHANGE.prefix_range(
+            deps.storage,
+            Some(PrefixBound::exclusive(height)),
+            None,
+            cosmwasm_std::Order::Ascending,
+        ),
+    };
+    let changes: StdResult<Vec<_>> = match limit {
+        Some(l) => changes.take(l as usize).collect(),
+        None => changes.collect(),
+    };
+
+    let stakers = changes?
+        .into_iter()
+        .map(|((height, address), _)| {
+            Ok(StakerChangeResponse {
+                balance: NFT_BALANCES
+                    .may_load(deps.storage, &address)?
+                    .unwrap_or_default(),
+                address: address.into_string(),
+                height,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    to_binary(&ListStakersChangedResponse { stakers })
+}
@@ -15,6 +15,9 @@ pub enum ContractError {
     #[error("Only the owner of this contract my execute this message")]
     NotOwner {},
 
+    #[error("Unauthorized")]
+    Unauthorized {},
+
     #[error("Can not unstake that which you have not staked (unstaking {token_id})")]
     NotStaked { token_id: String },
 
@@ -29,4 +32,19 @@ pub enum ContractError {
 
     #[error("Can't unstake zero NFTs.")]
     ZeroUnstake {},
+
+    #[error("Active threshold percentage must be greater than 0 and less than 1")]
+    InvalidActivePercentage {},
+
+    #[error(
+        "Absolute count threshold cannot be greater than the number of NFTs in the collection"
+    )]
+    InvalidAbsoluteCount {},
+
+    #[error("ICS-721 bridge maps class ID ({class_id}) to ({bridged:?}), not the provided NFT contract ({expected})")]
+    Ics721ClassMismatch {
+        class_id: String,
+        bridged: Option<Addr>,
+        expected: Addr,
+    },
 }
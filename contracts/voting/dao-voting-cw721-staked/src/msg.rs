@@ -1,8 +1,9 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Decimal, Uint128};
 use cw721::Cw721ReceiveMsg;
 use cw_utils::Duration;
 use dao_interface::Admin;
-use dao_macros::voting_module_query;
+use dao_macros::{active_query, voting_module_query};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -13,6 +14,38 @@ pub struct InstantiateMsg {
     /// Amount of time between unstaking and tokens being
     /// avaliable. To unstake with no delay, leave as `None`.
     pub unstaking_duration: Option<Duration>,
+    /// Set if `nft_address` is a collection bridged to this chain
+    /// over ICS-721. On instantiation the bridge is queried to check
+    /// that it maps `class_id` to `nft_address`.
+    pub ics721: Option<Ics721Info>,
+    /// The number of NFTs which must be staked before this module is
+    /// active. If unset, the module is always active.
+    pub active_threshold: Option<ActiveThreshold>,
+}
+
+/// The threshold of NFTs that must be staked in order for this
+/// voting module to be active. If this is not reached, this module
+/// will response to `is_active` queries with false and proposal
+/// modules which respect active thresholds will not allow the
+/// creation of proposals.
+#[cw_serde]
+pub enum ActiveThreshold {
+    /// The absolute number of NFTs that must be staked for the
+    /// module to be active.
+    AbsoluteCount { count: Uint128 },
+    /// The percentage of NFTs that must be staked for the module to
+    /// be active. Computed as `staked / total_supply` where
+    /// `total_supply` is the number of NFTs in the collection.
+    Percentage { percent: Decimal },
+}
+
+#[cw_serde]
+pub struct Ics721Info {
+    /// Address of the ICS-721 bridge contract on this chain.
+    pub bridge: String,
+    /// The class ID of the bridged collection, e.g.
+    /// `wasm.stars1.../channel-0/stars1...`.
+    pub class_id: String,
 }
 
 #[cw_serde]
@@ -38,9 +71,16 @@ pub enum ExecuteMsg {
     RemoveHook {
         addr: String,
     },
+    /// Sets the active threshold to a new value. Only the
+    /// instantiator of this contract (a DAO most likely) may call
+    /// this method.
+    UpdateActiveThreshold {
+        new_threshold: Option<ActiveThreshold>,
+    },
 }
 
 #[voting_module_query]
+#[active_query]
 #[cw_serde]
 #[derive(QueryResponses)]
 pub enum QueryMsg {
@@ -50,6 +90,10 @@ pub enum QueryMsg {
     NftClaims { address: String },
     #[returns(::cw_controllers::HooksResponse)]
     Hooks {},
+    /// Returns the ICS-721 bridge and class ID of the staked
+    /// collection if it was bridged to this chain.
+    #[returns(Option<crate::state::Ics721Collection>)]
+    Ics721Collection {},
     // List the staked NFTs for a given address.
     #[returns(Vec<String>)]
     StakedNfts {
@@ -57,4 +101,45 @@ pub enum QueryMsg {
         start_after: Option<String>,
         limit: Option<u32>,
     },
+    /// Lists the stakers whose number of staked NFTs changed after
+    /// HEIGHT, ordered by the height of their most recent
+    /// change. Stakers whose balance changes while paginating move to
+    /// the end of the list. Balances which have not changed since
+    /// this contract began tracking changes are not listed.
+    #[returns(ListStakersChangedResponse)]
+    ListStakersChangedSince {
+        height: u64,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(ActiveThresholdResponse)]
+    ActiveThreshold {},
+}
+
+#[cw_serde]
+pub struct ActiveThresholdResponse {
+    pub active_threshold: Option<ActiveThreshold>,
+}
+
+#[cw_serde]
+pub struct ListStakersChangedResponse {
+    pub stakers: Vec<StakerChangeResponse>,
+}
+
+#[cw_serde]
+pub struct StakerChangeResponse {
+    pub address: String,
+    /// The number of NFTs the staker currently has staked.
+    pub balance: Uint128,
+    /// The height at which the number of staked NFTs last changed.
+    pub height: u64,
+}
+
+/// The subset of the ICS-721 bridge's query messages used by this
+/// contract.
+#[cw_serde]
+pub enum Ics721QueryMsg {
+    /// Returns the address of the cw721 contract the bridge
+    /// instantiated for CLASS_ID as an `Option<Addr>`.
+    NftContract { class_id: String },
 }
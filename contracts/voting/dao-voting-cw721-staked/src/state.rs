@@ -5,6 +5,7 @@ use cw_controllers::Hooks;
 use cw_storage_plus::{Item, Map, SnapshotItem, SnapshotMap, Strategy};
 use cw_utils::Duration;
 
+use crate::msg::ActiveThreshold;
 use crate::ContractError;
 
 #[cw_serde]
@@ -14,8 +15,21 @@ pub struct Config {
     pub unstaking_duration: Option<Duration>,
 }
 
+/// The origin of a collection that was bridged to this chain over
+/// ICS-721.
+#[cw_serde]
+pub struct Ics721Collection {
+    pub bridge: Addr,
+    pub class_id: String,
+}
+
 pub const CONFIG: Item<Config> = Item::new("config");
 pub const DAO: Item<Addr> = Item::new("dao");
+/// The number of NFTs which must be staked for this module to be
+/// active. If unset, the module is always active.
+pub const ACTIVE_THRESHOLD: Item<ActiveThreshold> = Item::new("active_threshold");
+/// Set if the staked NFT contract is a bridged ICS-721 collection.
+pub const ICS721_COLLECTION: Item<Ics721Collection> = Item::new("ics721_collection");
 
 /// The set of NFTs currently staked by each address. The existence of
 /// an `(address, token_id)` pair implies that `address` has staked
@@ -29,6 +43,13 @@ pub const NFT_BALANCES: SnapshotMap<&Addr, Uint128> = SnapshotMap::new(
     "nb__changelog",
     Strategy::EveryBlock,
 );
+/// The height at which the number of NFTs staked by each address
+/// last changed.
+pub const NFT_BALANCE_CHANGED: Map<&Addr, u64> = Map::new("nb__changed");
+/// Stakers keyed by the height at which the number of NFTs they have
+/// staked last changed. Used to list the stakers whose balances
+/// changed after a height.
+pub const STAKERS_BY_CHANGE: Map<(u64, &Addr), Empty> = Map::new("stakers_by_change");
 /// The number of NFTs staked with this contract as a function of
 /// block height.
 pub const TOTAL_STAKED_NFTS: SnapshotItem<Uint128> = SnapshotItem::new(
@@ -60,6 +81,7 @@ pub fn register_staked_nft(
 
     STAKED_NFTS_PER_OWNER.save(storage, (staker, token_id), &Empty::default())?;
     NFT_BALANCES.update(storage, staker, height, add_one)?;
+    record_staker_change(storage, staker, height)?;
     TOTAL_STAKED_NFTS
         .update(storage, height, add_one)
         .map(|_| ())
@@ -95,5 +117,14 @@ pub fn register_unstaked_nft(
     let sub_n = subtractor(token_ids.len() as u128);
     TOTAL_STAKED_NFTS.update(storage, height, sub_n)?;
     NFT_BALANCES.update(storage, staker, height, sub_n)?;
+    record_staker_change(storage, staker, height)?;
     Ok(())
 }
+
+fn record_staker_change(storage: &mut dyn Storage, staker: &Addr, height: u64) -> StdResult<()> {
+    if let Some(changed) = NFT_BALANCE_CHANGED.may_load(storage, staker)? {
+        STAKERS_BY_CHANGE.remove(storage, (changed, staker));
+    }
+    NFT_BALANCE_CHANGED.save(storage, staker, &height)?;
+    STAKERS_BY_CHANGE.save(storage, (height, staker), &Empty::default())
+}
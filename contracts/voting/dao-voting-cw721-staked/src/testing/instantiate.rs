@@ -1,7 +1,12 @@
-use cosmwasm_std::Addr;
-use cw_multi_test::{App, Executor};
+use cosmwasm_std::{
+    to_binary, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult,
+};
+use cw_multi_test::{App, Contract, ContractWrapper, Executor};
+use cw_storage_plus::Item;
 use dao_testing::contracts::cw721_base_contract;
 
+use crate::msg::Ics721QueryMsg;
+
 pub fn instantiate_cw721_base(app: &mut App, sender: &str, minter: &str) -> Addr {
     let cw721_id = app.store_code(cw721_base_contract());
 
@@ -19,3 +24,61 @@ pub fn instantiate_cw721_base(app: &mut App, sender: &str, minter: &str) -> Addr
     )
     .unwrap()
 }
+
+/// A mock ICS-721 bridge that maps a single class ID to a single NFT
+/// contract.
+mod mock_ics721_bridge {
+    use super::*;
+
+    pub const CLASS: Item<(String, Addr)> = Item::new("class");
+
+    pub fn instantiate(
+        deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        msg: (String, Addr),
+    ) -> StdResult<Response> {
+        CLASS.save(deps.storage, &msg)?;
+        Ok(Response::default())
+    }
+
+    pub fn execute(
+        _deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        _msg: Empty,
+    ) -> StdResult<Response> {
+        Ok(Response::default())
+    }
+
+    pub fn query(deps: Deps, _env: Env, msg: Ics721QueryMsg) -> StdResult<Binary> {
+        let (class_id, nft) = CLASS.load(deps.storage)?;
+        match msg {
+            Ics721QueryMsg::NftContract { class_id: query } => {
+                to_binary(&(query == class_id).then_some(nft))
+            }
+        }
+    }
+}
+
+fn mock_ics721_bridge_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new(
+        mock_ics721_bridge::execute,
+        mock_ics721_bridge::instantiate,
+        mock_ics721_bridge::query,
+    ))
+}
+
+/// Instantiates a mock ICS-721 bridge that maps CLASS_ID to NFT.
+pub fn instantiate_ics721_bridge(app: &mut App, class_id: &str, nft: &Addr) -> Addr {
+    let bridge_id = app.store_code(mock_ics721_bridge_contract());
+    app.instantiate_contract(
+        bridge_id,
+        Addr::unchecked("bridge_admin"),
+        &(class_id.to_string(), nft.clone()),
+        &[],
+        "ics721",
+        None,
+    )
+    .unwrap()
+}
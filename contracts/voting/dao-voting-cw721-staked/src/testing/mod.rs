@@ -37,6 +37,8 @@ pub(crate) fn setup_test(owner: Option<Admin>, unstaking_duration: Option<Durati
                 owner,
                 nft_address: nft.to_string(),
                 unstaking_duration,
+                ics721: None,
+                active_threshold: None,
             },
             &[],
             "cw721_voting",
@@ -6,7 +6,10 @@ use dao_interface::voting::{
     InfoResponse, TotalPowerAtHeightResponse, VotingPowerAtHeightResponse,
 };
 
-use crate::{msg::QueryMsg, state::Config};
+use crate::{
+    msg::{ListStakersChangedResponse, QueryMsg, StakerChangeResponse},
+    state::Config,
+};
 
 pub fn query_config(app: &App, module: &Addr) -> StdResult<Config> {
     let config = app.wrap().query_wasm_smart(module, &QueryMsg::Config {})?;
@@ -46,6 +49,24 @@ pub fn query_staked_nfts(
     Ok(nfts)
 }
 
+pub fn query_stakers_changed_since(
+    app: &App,
+    module: &Addr,
+    height: u64,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<StakerChangeResponse>> {
+    let res: ListStakersChangedResponse = app.wrap().query_wasm_smart(
+        module,
+        &QueryMsg::ListStakersChangedSince {
+            height,
+            start_after,
+            limit,
+        },
+    )?;
+    Ok(res.stakers)
+}
+
 pub fn query_voting_power(
     app: &App,
     module: &Addr,
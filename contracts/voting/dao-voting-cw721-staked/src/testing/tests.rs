@@ -1,15 +1,21 @@
-use cosmwasm_std::Uint128;
+use cosmwasm_std::{Addr, Decimal, Uint128};
 use cw721_controllers::{NftClaim, NftClaimsResponse};
-use cw_multi_test::next_block;
+use cw_multi_test::{next_block, App, Executor};
 use cw_utils::Duration;
-use dao_interface::Admin;
+use dao_interface::{voting::IsActiveResponse, Admin};
+use dao_testing::contracts::voting_cw721_staked_contract;
 
 use crate::{
-    state::{Config, MAX_CLAIMS},
+    msg::{
+        ActiveThreshold, ActiveThresholdResponse, ExecuteMsg, Ics721Info, InstantiateMsg, QueryMsg,
+        StakerChangeResponse,
+    },
+    state::{Config, Ics721Collection, MAX_CLAIMS},
     testing::{
         execute::{
             claim_nfts, mint_and_stake_nft, mint_nft, stake_nft, unstake_nfts, update_config,
         },
+        instantiate::{instantiate_cw721_base, instantiate_ics721_bridge},
         queries::{query_config, query_hooks, query_nft_owner, query_total_and_voting_power},
     },
 };
@@ -17,7 +23,10 @@ use crate::{
 use super::{
     execute::{add_hook, remove_hook},
     is_error,
-    queries::{query_claims, query_info, query_staked_nfts, query_total_power, query_voting_power},
+    queries::{
+        query_claims, query_info, query_staked_nfts, query_stakers_changed_since,
+        query_total_power, query_voting_power,
+    },
     setup_test, CommonTest, CREATOR_ADDR,
 };
 
@@ -350,6 +359,63 @@ fn test_list_staked_nfts() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_list_stakers_changed_since() -> anyhow::Result<()> {
+    let CommonTest {
+        mut app,
+        module,
+        nft,
+    } = setup_test(None, None);
+
+    let start = app.block_info().height;
+    let deardrie = "deardrie";
+    mint_and_stake_nft(&mut app, &nft, &module, CREATOR_ADDR, "1")?;
+    mint_and_stake_nft(&mut app, &nft, &module, deardrie, "2")?;
+    app.update_block(next_block);
+    mint_and_stake_nft(&mut app, &nft, &module, CREATOR_ADDR, "3")?;
+
+    let creator = StakerChangeResponse {
+        address: CREATOR_ADDR.to_string(),
+        balance: Uint128::new(2),
+        height: start + 1,
+    };
+    let deardrie = StakerChangeResponse {
+        address: deardrie.to_string(),
+        balance: Uint128::new(1),
+        height: start,
+    };
+    assert_eq!(
+        query_stakers_changed_since(&app, &module, start - 1, None, None)?,
+        vec![deardrie.clone(), creator.clone()]
+    );
+    assert_eq!(
+        query_stakers_changed_since(&app, &module, start, None, None)?,
+        vec![creator.clone()]
+    );
+    assert_eq!(
+        query_stakers_changed_since(&app, &module, start - 1, None, Some(1))?,
+        vec![deardrie.clone()]
+    );
+    assert_eq!(
+        query_stakers_changed_since(&app, &module, start - 1, Some(deardrie.address), None)?,
+        vec![creator]
+    );
+
+    unstake_nfts(&mut app, &module, CREATOR_ADDR, &["1", "3"])?;
+    app.update_block(next_block);
+    let stakers = query_stakers_changed_since(&app, &module, start, None, None)?;
+    assert_eq!(
+        stakers,
+        vec![StakerChangeResponse {
+            address: CREATOR_ADDR.to_string(),
+            balance: Uint128::zero(),
+            height: start + 1,
+        }]
+    );
+
+    Ok(())
+}
+
 #[test]
 fn test_info_query_works() -> anyhow::Result<()> {
     let CommonTest { app, module, .. } = setup_test(None, None);
@@ -389,3 +455,170 @@ fn test_add_remove_hooks() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+// A bridged ICS-721 collection may be used if the bridge maps the
+// provided class ID to the NFT contract.
+#[test]
+fn test_ics721_collection() -> anyhow::Result<()> {
+    let mut app = App::default();
+    let module_id = app.store_code(voting_cw721_staked_contract());
+    let nft = instantiate_cw721_base(&mut app, CREATOR_ADDR, CREATOR_ADDR);
+    let other_nft = instantiate_cw721_base(&mut app, CREATOR_ADDR, CREATOR_ADDR);
+    let class_id = "wasm.stars1bridge/channel-0/stars1collection";
+    let bridge = instantiate_ics721_bridge(&mut app, class_id, &nft);
+
+    let instantiate = |app: &mut App, nft: &Addr, class_id: &str| {
+        app.instantiate_contract(
+            module_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &InstantiateMsg {
+                owner: None,
+                nft_address: nft.to_string(),
+                unstaking_duration: None,
+                ics721: Some(Ics721Info {
+                    bridge: bridge.to_string(),
+                    class_id: class_id.to_string(),
+                }),
+                active_threshold: None,
+            },
+            &[],
+            "cw721_voting",
+            None,
+        )
+    };
+
+    // Wrong NFT contract for the class ID.
+    let res = instantiate(&mut app, &other_nft, class_id);
+    is_error!(res => "not the provided NFT contract");
+    // Class ID unknown to the bridge.
+    let res = instantiate(
+        &mut app,
+        &nft,
+        "wasm.stars1bridge/channel-1/stars1collection",
+    );
+    is_error!(res => "not the provided NFT contract");
+
+    let module = instantiate(&mut app, &nft, class_id)?;
+    let collection: Option<Ics721Collection> = app
+        .wrap()
+        .query_wasm_smart(&module, &QueryMsg::Ics721Collection {})?;
+    assert_eq!(
+        collection,
+        Some(Ics721Collection {
+            bridge,
+            class_id: class_id.to_string(),
+        })
+    );
+
+    // Bridged NFTs are staked like any other.
+    mint_and_stake_nft(&mut app, &nft, &module, CREATOR_ADDR, "1")?;
+    app.update_block(next_block);
+    let voting_power = query_voting_power(&app, &module, CREATOR_ADDR, None)?;
+    assert_eq!(voting_power.power, Uint128::new(1));
+
+    Ok(())
+}
+
+// Only the DAO may update the active threshold. The module is active
+// once the threshold of staked NFTs is reached.
+#[test]
+fn test_active_threshold() -> anyhow::Result<()> {
+    let CommonTest {
+        mut app,
+        module,
+        nft,
+    } = setup_test(None, None);
+
+    let is_active = |app: &App| -> anyhow::Result<bool> {
+        let res: IsActiveResponse = app
+            .wrap()
+            .query_wasm_smart(&module, &QueryMsg::IsActive {})?;
+        Ok(res.active)
+    };
+    let update_active_threshold =
+        |app: &mut App, sender: &str, threshold: Option<ActiveThreshold>| {
+            app.execute_contract(
+                Addr::unchecked(sender),
+                module.clone(),
+                &ExecuteMsg::UpdateActiveThreshold {
+                    new_threshold: threshold,
+                },
+                &[],
+            )
+        };
+
+    // No threshold, so always active.
+    assert!(is_active(&app)?);
+
+    mint_nft(&mut app, &nft, CREATOR_ADDR, CREATOR_ADDR, "1")?;
+    mint_nft(&mut app, &nft, CREATOR_ADDR, CREATOR_ADDR, "2")?;
+    mint_nft(&mut app, &nft, CREATOR_ADDR, CREATOR_ADDR, "3")?;
+
+    let res = update_active_threshold(
+        &mut app,
+        "ekez",
+        Some(ActiveThreshold::AbsoluteCount {
+            count: Uint128::new(2),
+        }),
+    );
+    is_error!(res => "Unauthorized");
+
+    // Can't require more NFTs than exist.
+    let res = update_active_threshold(
+        &mut app,
+        CREATOR_ADDR,
+        Some(ActiveThreshold::AbsoluteCount {
+            count: Uint128::new(4),
+        }),
+    );
+    is_error!(res => "Absolute count threshold cannot be greater");
+    let res = update_active_threshold(
+        &mut app,
+        CREATOR_ADDR,
+        Some(ActiveThreshold::Percentage {
+            percent: Decimal::zero(),
+        }),
+    );
+    is_error!(res => "Active threshold percentage must be greater than 0");
+
+    update_active_threshold(
+        &mut app,
+        CREATOR_ADDR,
+        Some(ActiveThreshold::AbsoluteCount {
+            count: Uint128::new(2),
+        }),
+    )?;
+    let threshold: ActiveThresholdResponse = app
+        .wrap()
+        .query_wasm_smart(&module, &QueryMsg::ActiveThreshold {})?;
+    assert_eq!(
+        threshold.active_threshold,
+        Some(ActiveThreshold::AbsoluteCount {
+            count: Uint128::new(2)
+        })
+    );
+    assert!(!is_active(&app)?);
+
+    stake_nft(&mut app, &nft, &module, CREATOR_ADDR, "1")?;
+    assert!(!is_active(&app)?);
+    stake_nft(&mut app, &nft, &module, CREATOR_ADDR, "2")?;
+    assert!(is_active(&app)?);
+
+    // 50% of 3 NFTs rounds up to 2.
+    update_active_threshold(
+        &mut app,
+        CREATOR_ADDR,
+        Some(ActiveThreshold::Percentage {
+            percent: Decimal::percent(50),
+        }),
+    )?;
+    assert!(is_active(&app)?);
+    unstake_nfts(&mut app, &module, CREATOR_ADDR, &["2"])?;
+    assert!(!is_active(&app)?);
+
+    // Removing the threshold makes the module active.
+    update_active_threshold(&mut app, CREATOR_ADDR, None)?;
+    assert!(is_active(&app)?);
+
+    Ok(())
+}
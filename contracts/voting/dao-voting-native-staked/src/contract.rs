@@ -1,24 +1,33 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    coins, to_binary, BankMsg, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Response,
-    StdResult, Uint128,
+    coins, to_binary, BankMsg, Binary, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo, Order,
+    Response, StdResult, Uint128,
 };
 use cw2::set_contract_version;
 use cw_controllers::ClaimsResponse;
+use cw_storage_plus::{Bound, PrefixBound};
 use cw_utils::{must_pay, Duration};
+use dao_events::EventSource;
 use dao_interface::voting::{TotalPowerAtHeightResponse, VotingPowerAtHeightResponse};
 use dao_interface::Admin;
 
 use crate::error::ContractError;
 use crate::msg::{
-    ExecuteMsg, InstantiateMsg, ListStakersResponse, MigrateMsg, QueryMsg, StakerBalanceResponse,
+    ExecuteMsg, InstantiateMsg, ListStakersChangedResponse, ListStakersResponse, MigrateMsg,
+    PendingRewardsResponse, QueryMsg, StakerBalanceResponse, StakerChangeResponse,
+};
+use crate::state::{
+    record_staker_change, update_rewards, Config, Rewards, StakerRewards, CLAIMS, CONFIG, DAO,
+    MAX_CLAIMS, REWARDS, STAKED_BALANCES, STAKED_BALANCE_CHANGED, STAKED_TOTAL, STAKERS_BY_CHANGE,
+    STAKER_REWARDS,
 };
-use crate::state::{Config, CLAIMS, CONFIG, DAO, MAX_CLAIMS, STAKED_BALANCES, STAKED_TOTAL};
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-voting-native-staked";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+const EVENTS: EventSource = EventSource::new(CONTRACT_NAME, CONTRACT_VERSION);
+
 fn validate_duration(duration: Option<Duration>) -> Result<(), ContractError> {
     if let Some(unstaking_duration) = duration {
         match unstaking_duration {
@@ -105,6 +114,12 @@ pub fn execute(
             duration,
         } => execute_update_config(deps, info, owner, manager, duration),
         ExecuteMsg::Claim {} => execute_claim(deps, env, info),
+        ExecuteMsg::UpdateRewards {
+            denom,
+            emission_rate,
+        } => execute_update_rewards(deps, env, info, denom, emission_rate),
+        ExecuteMsg::FundRewards {} => execute_fund_rewards(deps, env, info),
+        ExecuteMsg::ClaimRewards {} => execute_claim_rewards(deps, env, info),
     }
 }
 
@@ -116,6 +131,7 @@ pub fn execute_stake(
     let config = CONFIG.load(deps.storage)?;
     let amount = must_pay(&info, &config.denom)?;
 
+    update_rewards(deps.storage, &info.sender, env.block.height)?;
     STAKED_BALANCES.update(
         deps.storage,
         &info.sender,
@@ -127,11 +143,19 @@ pub fn execute_stake(
         env.block.height,
         |total| -> StdResult<Uint128> { Ok(total.unwrap_or_default().checked_add(amount)?) },
     )?;
+    record_staker_change(deps.storage, &info.sender, env.block.height)?;
 
     Ok(Response::new()
         .add_attribute("action", "stake")
         .add_attribute("amount", amount.to_string())
-        .add_attribute("from", info.sender))
+        .add_attribute("from", info.sender.clone())
+        .add_event(
+            EVENTS
+                .event("stake")
+                .address("staker", &info.sender)
+                .attribute("amount", amount.to_string())
+                .into(),
+        ))
 }
 
 pub fn execute_unstake(
@@ -142,6 +166,7 @@ pub fn execute_unstake(
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
+    update_rewards(deps.storage, &info.sender, env.block.height)?;
     STAKED_BALANCES.update(
         deps.storage,
         &info.sender,
@@ -163,6 +188,7 @@ pub fn execute_unstake(
                 .map_err(|_e| ContractError::InvalidUnstakeAmount {})
         },
     )?;
+    record_staker_change(deps.storage, &info.sender, env.block.height)?;
 
     match config.unstaking_duration {
         None => {
@@ -173,9 +199,17 @@ pub fn execute_unstake(
             Ok(Response::new()
                 .add_message(msg)
                 .add_attribute("action", "unstake")
-                .add_attribute("from", info.sender)
+                .add_attribute("from", info.sender.clone())
                 .add_attribute("amount", amount)
-                .add_attribute("claim_duration", "None"))
+                .add_attribute("claim_duration", "None")
+                .add_event(
+                    EVENTS
+                        .event("unstake")
+                        .address("staker", &info.sender)
+                        .attribute("amount", amount.to_string())
+                        .attribute("claim_duration", "None")
+                        .into(),
+                ))
         }
         Some(duration) => {
             let outstanding_claims = CLAIMS.query_claims(deps.as_ref(), &info.sender)?.claims;
@@ -191,9 +225,17 @@ pub fn execute_unstake(
             )?;
             Ok(Response::new()
                 .add_attribute("action", "unstake")
-                .add_attribute("from", info.sender)
+                .add_attribute("from", info.sender.clone())
                 .add_attribute("amount", amount)
-                .add_attribute("claim_duration", format!("{duration}")))
+                .add_attribute("claim_duration", format!("{duration}"))
+                .add_event(
+                    EVENTS
+                        .event("unstake")
+                        .address("staker", &info.sender)
+                        .attribute("amount", amount.to_string())
+                        .attribute("claim_duration", format!("{duration}"))
+                        .into(),
+                ))
         }
     }
 }
@@ -229,21 +271,24 @@ pub fn execute_update_config(
     config.unstaking_duration = duration;
 
     CONFIG.save(deps.storage, &config)?;
+    let owner = config
+        .owner
+        .map(|a| a.to_string())
+        .unwrap_or_else(|| "None".to_string());
+    let manager = config
+        .manager
+        .map(|a| a.to_string())
+        .unwrap_or_else(|| "None".to_string());
     Ok(Response::new()
         .add_attribute("action", "update_config")
-        .add_attribute(
-            "owner",
-            config
-                .owner
-                .map(|a| a.to_string())
-                .unwrap_or_else(|| "None".to_string()),
-        )
-        .add_attribute(
-            "manager",
-            config
-                .manager
-                .map(|a| a.to_string())
-                .unwrap_or_else(|| "None".to_string()),
+        .add_attribute("owner", owner.clone())
+        .add_attribute("manager", manager.clone())
+        .add_event(
+            EVENTS
+                .event("update_config")
+                .attribute("owner", owner)
+                .attribute("manager", manager)
+                .into(),
         ))
 }
 
@@ -266,8 +311,130 @@ pub fn execute_claim(
     Ok(Response::new()
         .add_message(msg)
         .add_attribute("action", "claim")
-        .add_attribute("from", info.sender)
-        .add_attribute("amount", release))
+        .add_attribute("from", info.sender.clone())
+        .add_attribute("amount", release)
+        .add_event(
+            EVENTS
+                .event("claim")
+                .address("staker", &info.sender)
+                .attribute("amount", release.to_string())
+                .into(),
+        ))
+}
+
+pub fn execute_update_rewards(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denom: String,
+    emission_rate: Uint128,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if Some(info.sender.clone()) != config.owner && Some(info.sender) != config.manager {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let rewards = match REWARDS.may_load(deps.storage)? {
+        Some(mut rewards) => {
+            if rewards.denom != denom {
+                return Err(ContractError::RewardDenomChanged {});
+            }
+            // Emit rewards at the old rate up to this block.
+            let total_staked = STAKED_TOTAL.may_load(deps.storage)?.unwrap_or_default();
+            rewards.update(total_staked, env.block.height)?;
+            rewards.emission_rate = emission_rate;
+            rewards
+        }
+        None => Rewards {
+            denom,
+            emission_rate,
+            pool: Uint128::zero(),
+            index: Decimal::zero(),
+            last_updated: env.block.height,
+        },
+    };
+    REWARDS.save(deps.storage, &rewards)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_rewards")
+        .add_attribute("denom", rewards.denom.clone())
+        .add_attribute("emission_rate", rewards.emission_rate)
+        .add_event(
+            EVENTS
+                .event("update_rewards")
+                .attribute("denom", rewards.denom)
+                .attribute("emission_rate", rewards.emission_rate.to_string())
+                .into(),
+        ))
+}
+
+pub fn execute_fund_rewards(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let mut rewards = REWARDS
+        .may_load(deps.storage)?
+        .ok_or(ContractError::RewardsNotConfigured {})?;
+    let amount = must_pay(&info, &rewards.denom)?;
+
+    // Rewards emitted before this block came from the old pool.
+    let total_staked = STAKED_TOTAL.may_load(deps.storage)?.unwrap_or_default();
+    rewards.update(total_staked, env.block.height)?;
+    rewards.pool = rewards.pool.checked_add(amount)?;
+    REWARDS.save(deps.storage, &rewards)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "fund_rewards")
+        .add_attribute("from", info.sender.clone())
+        .add_attribute("amount", amount)
+        .add_event(
+            EVENTS
+                .event("fund_rewards")
+                .address("funder", &info.sender)
+                .attribute("amount", amount.to_string())
+                .into(),
+        ))
+}
+
+pub fn execute_claim_rewards(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let staker_rewards = update_rewards(deps.storage, &info.sender, env.block.height)?
+        .ok_or(ContractError::RewardsNotConfigured {})?;
+    let amount = staker_rewards.pending;
+    if amount.is_zero() {
+        return Err(ContractError::NothingToClaim {});
+    }
+    STAKER_REWARDS.save(
+        deps.storage,
+        &info.sender,
+        &StakerRewards {
+            pending: Uint128::zero(),
+            ..staker_rewards
+        },
+    )?;
+
+    let denom = REWARDS.load(deps.storage)?.denom;
+    let msg = CosmosMsg::Bank(BankMsg::Send {
+        to_address: info.sender.to_string(),
+        amount: coins(amount.u128(), denom),
+    });
+
+    Ok(Response::new()
+        .add_message(msg)
+        .add_attribute("action", "claim_rewards")
+        .add_attribute("from", info.sender.clone())
+        .add_attribute("amount", amount)
+        .add_event(
+            EVENTS
+                .event("claim_rewards")
+                .address("staker", &info.sender)
+                .attribute("amount", amount.to_string())
+                .into(),
+        ))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -286,6 +453,15 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::ListStakers { start_after, limit } => {
             query_list_stakers(deps, start_after, limit)
         }
+        QueryMsg::ListStakersChangedSince {
+            height,
+            start_after,
+            limit,
+        } => query_list_stakers_changed_since(deps, height, start_after, limit),
+        QueryMsg::Rewards {} => to_binary(&query_rewards(deps, env)?),
+        QueryMsg::PendingRewards { address } => {
+            to_binary(&query_pending_rewards(deps, env, address)?)
+        }
     }
 }
 
@@ -329,6 +505,40 @@ pub fn query_claims(deps: Deps, address: String) -> StdResult<ClaimsResponse> {
     CLAIMS.query_claims(deps, &deps.api.addr_validate(&address)?)
 }
 
+pub fn query_rewards(deps: Deps, env: Env) -> StdResult<Option<Rewards>> {
+    // Report the rewards as they would be if updated at this block.
+    REWARDS
+        .may_load(deps.storage)?
+        .map(|mut rewards| {
+            let total_staked = STAKED_TOTAL.may_load(deps.storage)?.unwrap_or_default();
+            rewards.update(total_staked, env.block.height)?;
+            Ok(rewards)
+        })
+        .transpose()
+}
+
+pub fn query_pending_rewards(
+    deps: Deps,
+    env: Env,
+    address: String,
+) -> StdResult<PendingRewardsResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let pending = match query_rewards(deps, env)? {
+        Some(rewards) => {
+            let balance = STAKED_BALANCES
+                .may_load(deps.storage, &address)?
+                .unwrap_or_default();
+            let mut staker_rewards = STAKER_REWARDS
+                .may_load(deps.storage, &address)?
+                .unwrap_or_default();
+            staker_rewards.update(balance, rewards.index)?;
+            staker_rewards.pending
+        }
+        None => Uint128::zero(),
+    };
+    Ok(PendingRewardsResponse { pending })
+}
+
 pub fn query_list_stakers(
     deps: Deps,
     start_after: Option<String>,
@@ -357,8 +567,64 @@ pub fn query_list_stakers(
     to_binary(&ListStakersResponse { stakers })
 }
 
+pub fn query_list_stakers_changed_since(
+    deps: Deps,
+    height: u64,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let start_after = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    // Resume after START_AFTER if it is still where the previous page
+    // left it, otherwise start from the first change after HEIGHT.
+    let resume = match &start_after {
+        Some(addr) => STAKED_BALANCE_CHANGED
+            .may_load(deps.storage, addr)?
+            .filter(|changed| *changed > height)
+            .map(|changed| (changed, addr)),
+        None => None,
+    };
+    let changes = match resume {
+        Some(key) => STAKERS_BY_CHANGE.range(
+            deps.storage,
+            Some(Bound::exclusive(key)),
+            None,
+            Order::Ascending,
+        ),
+        None => STAKERS_BY_CHANGE.prefix_range(
+            deps.storage,
+            Some(PrefixBound::exclusive(height)),
+            None,
+            Order::Ascending,
+        ),
+    };
+    let changes = match limit {
+        Some(limit) => changes
+            .take(limit as usize)
+            .collect::<StdResult<Vec<_>>>()?,
+        None => changes.collect::<StdResult<Vec<_>>>()?,
+    };
+
+    let stakers = changes
+        .into_iter()
+        .map(|((height, address), _)| {
+            Ok(StakerChangeResponse {
+                balance: STAKED_BALANCES
+                    .may_load(deps.storage, &address)?
+                    .unwrap_or_default(),
+                address: address.into_string(),
+                height,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    to_binary(&ListStakersChangedResponse { stakers })
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    msg.check_stored_version(deps.storage, CONTRACT_VERSION)?;
     // Set contract to version to latest
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     Ok(Response::default())
@@ -1,5 +1,6 @@
 use cosmwasm_std::StdError;
 use cw_utils::PaymentError;
+use dao_interface::migrate::MigrationError;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -7,6 +8,9 @@ pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
+    #[error(transparent)]
+    MigrationError(#[from] MigrationError),
+
     #[error("{0}")]
     PaymentError(#[from] PaymentError),
 
@@ -27,4 +31,10 @@ pub enum ContractError {
 
     #[error("Can only unstake less than or equal to the amount you have staked")]
     InvalidUnstakeAmount {},
+
+    #[error("Staking rewards have not been configured")]
+    RewardsNotConfigured {},
+
+    #[error("The reward denom may not be changed once set")]
+    RewardDenomChanged {},
 }
@@ -28,6 +28,18 @@ pub enum ExecuteMsg {
         duration: Option<Duration>,
     },
     Claim {},
+    /// Sets the native denom staking rewards are paid in and the
+    /// amount emitted each block. Only the owner or manager may call
+    /// this. The denom may not be changed once set.
+    UpdateRewards {
+        denom: String,
+        emission_rate: Uint128,
+    },
+    /// Adds the reward denom sent with this message to the reward
+    /// pool.
+    FundRewards {},
+    /// Sends the sender's pending rewards to them.
+    ClaimRewards {},
 }
 
 #[voting_module_query]
@@ -43,10 +55,27 @@ pub enum QueryMsg {
         start_after: Option<String>,
         limit: Option<u32>,
     },
+    /// Lists the stakers whose staked balance changed after HEIGHT,
+    /// ordered by the height of their most recent change. Stakers
+    /// whose balance changes while paginating move to the end of the
+    /// list. Balances which have not changed since this contract
+    /// began tracking changes are not listed.
+    #[returns(ListStakersChangedResponse)]
+    ListStakersChangedSince {
+        height: u64,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Gets the staking rewards configuration and pool, or None if
+    /// rewards have not been configured.
+    #[returns(Option<crate::state::Rewards>)]
+    Rewards {},
+    /// Gets the rewards ADDRESS may claim.
+    #[returns(PendingRewardsResponse)]
+    PendingRewards { address: String },
 }
 
-#[cw_serde]
-pub struct MigrateMsg {}
+pub use dao_interface::migrate::MigrateMsg;
 
 #[cw_serde]
 pub struct ListStakersResponse {
@@ -58,3 +87,24 @@ pub struct StakerBalanceResponse {
     pub address: String,
     pub balance: Uint128,
 }
+
+#[cw_serde]
+pub struct ListStakersChangedResponse {
+    pub stakers: Vec<StakerChangeResponse>,
+}
+
+#[cw_serde]
+pub struct StakerChangeResponse {
+    pub address: String,
+    /// The staker's current staked balance.
+    pub balance: Uint128,
+    /// The height at which the staked balance last changed.
+    pub height: u64,
+}
+
+#[cw_serde]
+pub struct PendingRewardsResponse {
+    /// The rewards which may be claimed. Zero if rewards have not
+    /// been configured.
+    pub pending: Uint128,
+}
@@ -1,7 +1,7 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Decimal, Empty, StdResult, Storage, Uint128};
 use cw_controllers::Claims;
-use cw_storage_plus::{Item, SnapshotItem, SnapshotMap, Strategy};
+use cw_storage_plus::{Item, Map, SnapshotItem, SnapshotMap, Strategy};
 use cw_utils::Duration;
 
 #[cw_serde]
@@ -12,6 +12,66 @@ pub struct Config {
     pub unstaking_duration: Option<Duration>,
 }
 
+/// Rewards paid to stakers in proportion to their stake. Rewards are
+/// emitted from a pool funded by the DAO at a fixed rate per block.
+#[cw_serde]
+pub struct Rewards {
+    /// The native denom rewards are paid in.
+    pub denom: String,
+    /// The amount of rewards emitted each block. Emission stops when
+    /// the pool is empty.
+    pub emission_rate: Uint128,
+    /// The rewards which have been funded but not yet emitted.
+    pub pool: Uint128,
+    /// The rewards emitted per staked token since rewards were
+    /// configured.
+    pub index: Decimal,
+    /// The height at which rewards were last emitted.
+    pub last_updated: u64,
+}
+
+impl Rewards {
+    /// Emits rewards for the blocks between the last update and
+    /// HEIGHT, sharing them between TOTAL_STAKED tokens. Nothing is
+    /// emitted while nothing is staked.
+    pub fn update(&mut self, total_staked: Uint128, height: u64) -> StdResult<()> {
+        let blocks = height.saturating_sub(self.last_updated);
+        self.last_updated = height;
+        if total_staked.is_zero() {
+            return Ok(());
+        }
+        let emitted = self
+            .emission_rate
+            .checked_mul(Uint128::from(blocks))?
+            .min(self.pool);
+        self.pool -= emitted;
+        self.index = self
+            .index
+            .checked_add(Decimal::from_ratio(emitted, total_staked))?;
+        Ok(())
+    }
+}
+
+/// The rewards accrued by a staker.
+#[cw_serde]
+#[derive(Default)]
+pub struct StakerRewards {
+    /// The value of `Rewards::index` when this staker's rewards were
+    /// last updated.
+    pub index: Decimal,
+    /// Rewards which have accrued but not been claimed.
+    pub pending: Uint128,
+}
+
+impl StakerRewards {
+    /// Accrues rewards on BALANCE staked tokens up to INDEX.
+    pub fn update(&mut self, balance: Uint128, index: Decimal) -> StdResult<()> {
+        self.pending = self.pending.checked_add(balance * (index - self.index))?;
+        self.index = index;
+        Ok(())
+    }
+}
+
 pub const CONFIG: Item<Config> = Item::new("config");
 pub const DAO: Item<Addr> = Item::new("dao");
 pub const STAKED_BALANCES: SnapshotMap<&Addr, Uint128> = SnapshotMap::new(
@@ -28,7 +88,61 @@ pub const STAKED_TOTAL: SnapshotItem<Uint128> = SnapshotItem::new(
     Strategy::EveryBlock,
 );
 
+/// The height at which each address' staked balance last changed.
+pub const STAKED_BALANCE_CHANGED: Map<&Addr, u64> = Map::new("staked_balance__changed");
+
+/// Stakers keyed by the height at which their staked balance last
+/// changed. Used to list the stakers whose balances changed after a
+/// height.
+pub const STAKERS_BY_CHANGE: Map<(u64, &Addr), Empty> = Map::new("stakers_by_change");
+
 /// The maximum number of claims that may be outstanding.
 pub const MAX_CLAIMS: u64 = 100;
 
 pub const CLAIMS: Claims = Claims::new("claims");
+
+/// The staking rewards, if they have been configured.
+pub const REWARDS: Item<Rewards> = Item::new("rewards");
+/// The rewards accrued by each staker.
+pub const STAKER_REWARDS: Map<&Addr, StakerRewards> = Map::new("staker_rewards");
+
+/// Records that the staked balance of STAKER changed at HEIGHT.
+pub fn record_staker_change(
+    storage: &mut dyn Storage,
+    staker: &Addr,
+    height: u64,
+) -> StdResult<()> {
+    if let Some(changed) = STAKED_BALANCE_CHANGED.may_load(storage, staker)? {
+        STAKERS_BY_CHANGE.remove(storage, (changed, staker));
+    }
+    STAKED_BALANCE_CHANGED.save(storage, staker, &height)?;
+    STAKERS_BY_CHANGE.save(storage, (height, staker), &Empty {})
+}
+
+/// Emits rewards up to HEIGHT and accrues STAKER's share of them.
+/// Must be called before STAKER's staked balance or the total staked
+/// balance changes. Returns STAKER's updated rewards, or None if
+/// rewards have not been configured.
+pub fn update_rewards(
+    storage: &mut dyn Storage,
+    staker: &Addr,
+    height: u64,
+) -> StdResult<Option<StakerRewards>> {
+    let mut rewards = match REWARDS.may_load(storage)? {
+        Some(rewards) => rewards,
+        None => return Ok(None),
+    };
+    let total_staked = STAKED_TOTAL.may_load(storage)?.unwrap_or_default();
+    rewards.update(total_staked, height)?;
+    REWARDS.save(storage, &rewards)?;
+
+    let balance = STAKED_BALANCES
+        .may_load(storage, staker)?
+        .unwrap_or_default();
+    let mut staker_rewards = STAKER_REWARDS
+        .may_load(storage, staker)?
+        .unwrap_or_default();
+    staker_rewards.update(balance, rewards.index)?;
+    STAKER_REWARDS.save(storage, staker, &staker_rewards)?;
+    Ok(Some(staker_rewards))
+}
@@ -1,10 +1,12 @@
 use crate::contract::{migrate, CONTRACT_NAME, CONTRACT_VERSION};
 use crate::msg::{
-    ExecuteMsg, InstantiateMsg, ListStakersResponse, MigrateMsg, QueryMsg, StakerBalanceResponse,
+    ExecuteMsg, InstantiateMsg, ListStakersChangedResponse, ListStakersResponse, MigrateMsg,
+    PendingRewardsResponse, QueryMsg, StakerBalanceResponse, StakerChangeResponse,
 };
-use crate::state::Config;
+use crate::state::{Config, Rewards};
+use crate::ContractError;
 use cosmwasm_std::testing::{mock_dependencies, mock_env};
-use cosmwasm_std::{coins, Addr, Coin, Empty, Uint128};
+use cosmwasm_std::{coins, Addr, Coin, Decimal, Empty, Uint128};
 use cw_controllers::ClaimsResponse;
 use cw_multi_test::{
     custom_app, next_block, App, AppResponse, Contract, ContractWrapper, Executor,
@@ -20,6 +22,7 @@ const ADDR1: &str = "addr1";
 const ADDR2: &str = "addr2";
 const DENOM: &str = "ujuno";
 const INVALID_DENOM: &str = "uinvalid";
+const REWARD_DENOM: &str = "ureward";
 
 fn staking_contract() -> Box<dyn Contract<Empty>> {
     let contract = ContractWrapper::new(
@@ -45,6 +48,10 @@ fn mock_app() -> App {
                         denom: INVALID_DENOM.to_string(),
                         amount: Uint128::new(10000),
                     },
+                    Coin {
+                        denom: REWARD_DENOM.to_string(),
+                        amount: Uint128::new(10000),
+                    },
                 ],
             )
             .unwrap();
@@ -155,6 +162,61 @@ fn update_config(
     )
 }
 
+fn update_rewards(
+    app: &mut App,
+    staking_addr: Addr,
+    sender: &str,
+    denom: &str,
+    emission_rate: u128,
+) -> anyhow::Result<AppResponse> {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        staking_addr,
+        &ExecuteMsg::UpdateRewards {
+            denom: denom.to_string(),
+            emission_rate: Uint128::new(emission_rate),
+        },
+        &[],
+    )
+}
+
+fn fund_rewards(app: &mut App, staking_addr: Addr, amount: u128) -> anyhow::Result<AppResponse> {
+    app.execute_contract(
+        Addr::unchecked(DAO_ADDR),
+        staking_addr,
+        &ExecuteMsg::FundRewards {},
+        &coins(amount, REWARD_DENOM),
+    )
+}
+
+fn claim_rewards(app: &mut App, staking_addr: Addr, sender: &str) -> anyhow::Result<AppResponse> {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        staking_addr,
+        &ExecuteMsg::ClaimRewards {},
+        &[],
+    )
+}
+
+fn get_pending_rewards(app: &mut App, staking_addr: Addr, address: &str) -> Uint128 {
+    let response: PendingRewardsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            staking_addr,
+            &QueryMsg::PendingRewards {
+                address: address.to_string(),
+            },
+        )
+        .unwrap();
+    response.pending
+}
+
+fn get_rewards(app: &mut App, staking_addr: Addr) -> Option<Rewards> {
+    app.wrap()
+        .query_wasm_smart(staking_addr, &QueryMsg::Rewards {})
+        .unwrap()
+}
+
 fn get_voting_power_at_height(
     app: &mut App,
     staking_addr: Addr,
@@ -191,6 +253,27 @@ fn get_claims(app: &mut App, staking_addr: Addr, address: String) -> ClaimsRespo
         .unwrap()
 }
 
+fn list_stakers_changed_since(
+    app: &mut App,
+    staking_addr: Addr,
+    height: u64,
+    start_after: Option<&str>,
+    limit: Option<u32>,
+) -> Vec<StakerChangeResponse> {
+    let res: ListStakersChangedResponse = app
+        .wrap()
+        .query_wasm_smart(
+            staking_addr,
+            &QueryMsg::ListStakersChangedSince {
+                height,
+                start_after: start_after.map(str::to_string),
+                limit,
+            },
+        )
+        .unwrap();
+    res.stakers
+}
+
 fn get_balance(app: &mut App, address: &str, denom: &str) -> Uint128 {
     app.wrap().query_balance(address, denom).unwrap().amount
 }
@@ -975,11 +1058,207 @@ fn test_query_list_stakers() {
     assert_eq!(stakers, ListStakersResponse { stakers: vec![] });
 }
 
+#[test]
+fn test_query_list_stakers_changed_since() {
+    let mut app = mock_app();
+    let staking_id = app.store_code(staking_contract());
+    let addr = instantiate_staking(
+        &mut app,
+        staking_id,
+        InstantiateMsg {
+            owner: Some(Admin::CoreModule {}),
+            manager: Some(ADDR1.to_string()),
+            denom: DENOM.to_string(),
+            unstaking_duration: Some(Duration::Height(5)),
+        },
+    );
+
+    let start = app.block_info().height;
+    stake_tokens(&mut app, addr.clone(), ADDR1, 100, DENOM).unwrap();
+    app.update_block(next_block);
+    stake_tokens(&mut app, addr.clone(), ADDR2, 50, DENOM).unwrap();
+    app.update_block(next_block);
+    // ADDR1's change moves after ADDR2's.
+    unstake_tokens(&mut app, addr.clone(), ADDR1, 10).unwrap();
+
+    let addr1 = StakerChangeResponse {
+        address: ADDR1.to_string(),
+        balance: Uint128::new(90),
+        height: start + 2,
+    };
+    let addr2 = StakerChangeResponse {
+        address: ADDR2.to_string(),
+        balance: Uint128::new(50),
+        height: start + 1,
+    };
+    assert_eq!(
+        list_stakers_changed_since(&mut app, addr.clone(), start, None, None),
+        vec![addr2.clone(), addr1.clone()]
+    );
+    assert_eq!(
+        list_stakers_changed_since(&mut app, addr.clone(), start + 1, None, None),
+        vec![addr1.clone()]
+    );
+    assert!(list_stakers_changed_since(&mut app, addr.clone(), start + 2, None, None).is_empty());
+
+    assert_eq!(
+        list_stakers_changed_since(&mut app, addr.clone(), start, None, Some(1)),
+        vec![addr2]
+    );
+    assert_eq!(
+        list_stakers_changed_since(&mut app, addr, start, Some(ADDR2), None),
+        vec![addr1]
+    );
+}
+
+#[test]
+fn test_rewards() {
+    let mut app = mock_app();
+    let staking_id = app.store_code(staking_contract());
+    let addr = instantiate_staking(
+        &mut app,
+        staking_id,
+        InstantiateMsg {
+            owner: Some(Admin::CoreModule {}),
+            manager: None,
+            denom: DENOM.to_string(),
+            unstaking_duration: None,
+        },
+    );
+
+    assert_eq!(get_rewards(&mut app, addr.clone()), None);
+    let err: ContractError = fund_rewards(&mut app, addr.clone(), 1000)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::RewardsNotConfigured {}));
+    let err: ContractError = update_rewards(&mut app, addr.clone(), ADDR1, REWARD_DENOM, 10)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    update_rewards(&mut app, addr.clone(), DAO_ADDR, REWARD_DENOM, 10).unwrap();
+    fund_rewards(&mut app, addr.clone(), 1000).unwrap();
+    stake_tokens(&mut app, addr.clone(), ADDR1, 100, DENOM).unwrap();
+
+    // ADDR1 is the only staker for a block.
+    app.update_block(next_block);
+    assert_eq!(
+        get_pending_rewards(&mut app, addr.clone(), ADDR1),
+        Uint128::new(10)
+    );
+    stake_tokens(&mut app, addr.clone(), ADDR2, 300, DENOM).unwrap();
+
+    // The next 20 rewards are shared 1:3.
+    app.update_block(|b| b.height += 2);
+    assert_eq!(
+        get_pending_rewards(&mut app, addr.clone(), ADDR1),
+        Uint128::new(15)
+    );
+    assert_eq!(
+        get_pending_rewards(&mut app, addr.clone(), ADDR2),
+        Uint128::new(15)
+    );
+    assert_eq!(
+        get_rewards(&mut app, addr.clone()),
+        Some(Rewards {
+            denom: REWARD_DENOM.to_string(),
+            emission_rate: Uint128::new(10),
+            pool: Uint128::new(970),
+            index: Decimal::from_ratio(15u128, 100u128),
+            last_updated: app.block_info().height,
+        })
+    );
+
+    claim_rewards(&mut app, addr.clone(), ADDR1).unwrap();
+    assert_eq!(
+        app.wrap()
+            .query_balance(ADDR1, REWARD_DENOM)
+            .unwrap()
+            .amount,
+        Uint128::new(15)
+    );
+    assert_eq!(
+        get_pending_rewards(&mut app, addr.clone(), ADDR1),
+        Uint128::zero()
+    );
+    let err: ContractError = claim_rewards(&mut app, addr.clone(), ADDR1)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::NothingToClaim {}));
+
+    // Rewards accrued before unstaking may still be claimed.
+    unstake_tokens(&mut app, addr.clone(), ADDR2, 300).unwrap();
+    app.update_block(next_block);
+    assert_eq!(
+        get_pending_rewards(&mut app, addr.clone(), ADDR1),
+        Uint128::new(10)
+    );
+    assert_eq!(
+        get_pending_rewards(&mut app, addr.clone(), ADDR2),
+        Uint128::new(15)
+    );
+
+    let err: ContractError = update_rewards(&mut app, addr.clone(), DAO_ADDR, DENOM, 10)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::RewardDenomChanged {}));
+}
+
+#[test]
+fn test_rewards_pool_exhausted() {
+    let mut app = mock_app();
+    let staking_id = app.store_code(staking_contract());
+    let addr = instantiate_staking(
+        &mut app,
+        staking_id,
+        InstantiateMsg {
+            owner: Some(Admin::CoreModule {}),
+            manager: None,
+            denom: DENOM.to_string(),
+            unstaking_duration: None,
+        },
+    );
+
+    update_rewards(&mut app, addr.clone(), DAO_ADDR, REWARD_DENOM, 10).unwrap();
+    fund_rewards(&mut app, addr.clone(), 25).unwrap();
+    stake_tokens(&mut app, addr.clone(), ADDR1, 100, DENOM).unwrap();
+
+    app.update_block(|b| b.height += 5);
+    assert_eq!(
+        get_pending_rewards(&mut app, addr.clone(), ADDR1),
+        Uint128::new(25)
+    );
+    assert_eq!(
+        get_rewards(&mut app, addr.clone()).unwrap().pool,
+        Uint128::zero()
+    );
+
+    // Emission resumes once the pool is refunded.
+    fund_rewards(&mut app, addr.clone(), 100).unwrap();
+    app.update_block(next_block);
+    assert_eq!(
+        get_pending_rewards(&mut app, addr.clone(), ADDR1),
+        Uint128::new(35)
+    );
+    claim_rewards(&mut app, addr, ADDR1).unwrap();
+    assert_eq!(
+        app.wrap()
+            .query_balance(ADDR1, REWARD_DENOM)
+            .unwrap()
+            .amount,
+        Uint128::new(35)
+    );
+}
+
 #[test]
 pub fn test_migrate_update_version() {
     let mut deps = mock_dependencies();
     cw2::set_contract_version(&mut deps.storage, "my-contract", "old-version").unwrap();
-    migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+    migrate(deps.as_mut(), mock_env(), MigrateMsg::FromCompatible {}).unwrap();
     let version = cw2::get_contract_version(&deps.storage).unwrap();
     assert_eq!(version.version, CONTRACT_VERSION);
     assert_eq!(version.contract, CONTRACT_NAME);
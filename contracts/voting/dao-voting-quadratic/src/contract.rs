@@ -0,0 +1,130 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Uint128,
+};
+use cw2::set_contract_version;
+use dao_interface::voting::{TotalPowerAtHeightResponse, VotingPowerAtHeightResponse};
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::state::{Config, CONFIG, DAO};
+
+const CONTRACT_NAME: &str = "crates.io:dao-voting-quadratic";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let root = msg.root.unwrap_or(2);
+    if root == 0 {
+        return Err(ContractError::ZeroRoot {});
+    }
+    let voting_module = deps.api.addr_validate(&msg.voting_module)?;
+
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            voting_module: voting_module.clone(),
+            root,
+        },
+    )?;
+    DAO.save(deps.storage, &info.sender)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("voting_module", voting_module)
+        .add_attribute("root", root.to_string()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {}
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::VotingPowerAtHeight { address, height } => {
+            to_binary(&query_voting_power_at_height(deps, address, height)?)
+        }
+        QueryMsg::TotalPowerAtHeight { height } => {
+            to_binary(&query_total_power_at_height(deps, height)?)
+        }
+        QueryMsg::Info {} => query_info(deps),
+        QueryMsg::Dao {} => to_binary(&DAO.load(deps.storage)?),
+        QueryMsg::Config {} => to_binary(&CONFIG.load(deps.storage)?),
+    }
+}
+
+pub fn query_voting_power_at_height(
+    deps: Deps,
+    address: String,
+    height: Option<u64>,
+) -> StdResult<VotingPowerAtHeightResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let response: VotingPowerAtHeightResponse = deps.querier.query_wasm_smart(
+        config.voting_module,
+        &dao_interface::voting::Query::VotingPowerAtHeight { address, height },
+    )?;
+    Ok(VotingPowerAtHeightResponse {
+        power: nth_root(response.power, config.root),
+        height: response.height,
+    })
+}
+
+pub fn query_total_power_at_height(
+    deps: Deps,
+    height: Option<u64>,
+) -> StdResult<TotalPowerAtHeightResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let response: TotalPowerAtHeightResponse = deps.querier.query_wasm_smart(
+        config.voting_module,
+        &dao_interface::voting::Query::TotalPowerAtHeight { height },
+    )?;
+    Ok(TotalPowerAtHeightResponse {
+        power: nth_root(response.power, config.root),
+        height: response.height,
+    })
+}
+
+pub fn query_info(deps: Deps) -> StdResult<Binary> {
+    let info = cw2::get_contract_version(deps.storage)?;
+    to_binary(&dao_interface::voting::InfoResponse { info })
+}
+
+/// Computes the ROOTth root of VALUE, rounded down. ROOT must be
+/// greater than zero.
+pub fn nth_root(value: Uint128, root: u32) -> Uint128 {
+    let value = value.u128();
+    // Binary search for the largest number whose ROOTth power is at
+    // most VALUE. The root of a number is never larger than it.
+    let (mut low, mut high) = (0u128, value);
+    while low < high {
+        // Rounds up so that `low = mid` always makes progress.
+        let mid = low + (high - low) / 2 + 1;
+        match mid.checked_pow(root) {
+            Some(power) if power <= value => low = mid,
+            _ => high = mid - 1,
+        }
+    }
+    Uint128::new(low)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    msg.check_stored_version(deps.storage, CONTRACT_VERSION)?;
+    // Don't do any state migrations.
+    Ok(Response::default())
+}
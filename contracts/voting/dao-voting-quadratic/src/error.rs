@@ -0,0 +1,15 @@
+use cosmwasm_std::StdError;
+use dao_interface::migrate::MigrationError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    MigrationError(#[from] MigrationError),
+
+    #[error("root must be greater than zero")]
+    ZeroRoot {},
+}
@@ -0,0 +1,26 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use dao_macros::voting_module_query;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The voting module whose voting power will be dampened.
+    pub voting_module: String,
+    /// Voting power is the ROOTth root of the wrapped module's
+    /// voting power, rounded down. Defaults to two, or quadratic
+    /// voting.
+    pub root: Option<u32>,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {}
+
+#[voting_module_query]
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Gets the wrapped voting module and root.
+    #[returns(crate::state::Config)]
+    Config {},
+}
+
+pub use dao_interface::migrate::MigrateMsg;
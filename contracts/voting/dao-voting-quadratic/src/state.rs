@@ -0,0 +1,14 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Addr;
+use cw_storage_plus::Item;
+
+#[cw_serde]
+pub struct Config {
+    /// The voting module whose voting power is dampened.
+    pub voting_module: Addr,
+    /// Voting power is the ROOTth root of the voting module's.
+    pub root: u32,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const DAO: Item<Addr> = Item::new("dao");
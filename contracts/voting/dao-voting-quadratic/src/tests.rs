@@ -0,0 +1,162 @@
+use cosmwasm_std::{Addr, Empty, Uint128};
+use cw_multi_test::{next_block, App, Contract, ContractWrapper, Executor};
+use dao_interface::voting::{
+    InfoResponse, TotalPowerAtHeightResponse, VotingPowerAtHeightResponse,
+};
+use dao_testing::contracts::{cw4_group_contract, dao_voting_cw4_contract};
+
+use crate::{
+    contract::nth_root,
+    msg::{InstantiateMsg, QueryMsg},
+    state::Config,
+    ContractError,
+};
+
+const DAO_ADDR: &str = "dao";
+const ADDR1: &str = "addr1";
+const ADDR2: &str = "addr2";
+const ADDR3: &str = "addr3";
+
+fn quadratic_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        crate::contract::execute,
+        crate::contract::instantiate,
+        crate::contract::query,
+    );
+    Box::new(contract)
+}
+
+/// Instantiates a cw4 voting module with members ADDR1, ADDR2, and
+/// ADDR3 with weights of 1, 16, and 100, and wraps it in a quadratic
+/// voting module with ROOT.
+fn setup_test_case(app: &mut App, root: Option<u32>) -> anyhow::Result<Addr> {
+    let cw4_id = app.store_code(cw4_group_contract());
+    let cw4_voting_id = app.store_code(dao_voting_cw4_contract());
+    let quadratic_id = app.store_code(quadratic_contract());
+
+    let cw4_voting = app.instantiate_contract(
+        cw4_voting_id,
+        Addr::unchecked(DAO_ADDR),
+        &dao_voting_cw4::msg::InstantiateMsg {
+            cw4_group_code_id: cw4_id,
+            initial_members: vec![
+                cw4::Member {
+                    addr: ADDR1.to_string(),
+                    weight: 1,
+                },
+                cw4::Member {
+                    addr: ADDR2.to_string(),
+                    weight: 16,
+                },
+                cw4::Member {
+                    addr: ADDR3.to_string(),
+                    weight: 100,
+                },
+            ],
+        },
+        &[],
+        "cw4 voting",
+        None,
+    )?;
+    let quadratic = app.instantiate_contract(
+        quadratic_id,
+        Addr::unchecked(DAO_ADDR),
+        &InstantiateMsg {
+            voting_module: cw4_voting.to_string(),
+            root,
+        },
+        &[],
+        "quadratic voting",
+        None,
+    )?;
+    app.update_block(next_block);
+    Ok(quadratic)
+}
+
+fn query_voting_power(app: &App, voting: &Addr, address: &str) -> Uint128 {
+    let response: VotingPowerAtHeightResponse = app
+        .wrap()
+        .query_wasm_smart(
+            voting,
+            &QueryMsg::VotingPowerAtHeight {
+                address: address.to_string(),
+                height: None,
+            },
+        )
+        .unwrap();
+    response.power
+}
+
+fn query_total_power(app: &App, voting: &Addr) -> Uint128 {
+    let response: TotalPowerAtHeightResponse = app
+        .wrap()
+        .query_wasm_smart(voting, &QueryMsg::TotalPowerAtHeight { height: None })
+        .unwrap();
+    response.power
+}
+
+#[test]
+fn test_instantiate() {
+    let mut app = App::default();
+    let voting = setup_test_case(&mut app, None).unwrap();
+
+    let config: Config = app
+        .wrap()
+        .query_wasm_smart(&voting, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config.root, 2);
+
+    let dao: Addr = app
+        .wrap()
+        .query_wasm_smart(&voting, &QueryMsg::Dao {})
+        .unwrap();
+    assert_eq!(dao, Addr::unchecked(DAO_ADDR));
+
+    let info: InfoResponse = app
+        .wrap()
+        .query_wasm_smart(&voting, &QueryMsg::Info {})
+        .unwrap();
+    assert_eq!(info.info.contract, "crates.io:dao-voting-quadratic");
+
+    let err: ContractError = setup_test_case(&mut app, Some(0))
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::ZeroRoot {}));
+}
+
+#[test]
+fn test_quadratic_power() {
+    let mut app = App::default();
+    let voting = setup_test_case(&mut app, None).unwrap();
+
+    assert_eq!(query_voting_power(&app, &voting, ADDR1), Uint128::new(1));
+    assert_eq!(query_voting_power(&app, &voting, ADDR2), Uint128::new(4));
+    assert_eq!(query_voting_power(&app, &voting, ADDR3), Uint128::new(10));
+    assert_eq!(query_voting_power(&app, &voting, "nobody"), Uint128::zero());
+    // sqrt(117) rounded down.
+    assert_eq!(query_total_power(&app, &voting), Uint128::new(10));
+}
+
+#[test]
+fn test_cube_root_power() {
+    let mut app = App::default();
+    let voting = setup_test_case(&mut app, Some(3)).unwrap();
+
+    assert_eq!(query_voting_power(&app, &voting, ADDR1), Uint128::new(1));
+    assert_eq!(query_voting_power(&app, &voting, ADDR2), Uint128::new(2));
+    assert_eq!(query_voting_power(&app, &voting, ADDR3), Uint128::new(4));
+    assert_eq!(query_total_power(&app, &voting), Uint128::new(4));
+}
+
+#[test]
+fn test_nth_root() {
+    assert_eq!(nth_root(Uint128::zero(), 2), Uint128::zero());
+    assert_eq!(nth_root(Uint128::new(99), 2), Uint128::new(9));
+    assert_eq!(nth_root(Uint128::new(100), 2), Uint128::new(10));
+    assert_eq!(nth_root(Uint128::new(26), 3), Uint128::new(2));
+    assert_eq!(nth_root(Uint128::new(27), 3), Uint128::new(3));
+    assert_eq!(nth_root(Uint128::new(12345), 1), Uint128::new(12345));
+    assert_eq!(nth_root(Uint128::MAX, 2), Uint128::new(u64::MAX as u128));
+    assert_eq!(nth_root(Uint128::MAX, 128), Uint128::new(1));
+}
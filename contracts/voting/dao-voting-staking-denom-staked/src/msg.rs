@@ -20,5 +20,4 @@ pub enum QueryMsg {
     StakingModule {},
 }
 
-#[cw_serde]
-pub struct MigrateMsg {}
+pub use dao_interface::migrate::MigrateMsg;
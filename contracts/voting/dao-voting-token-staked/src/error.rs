@@ -0,0 +1,46 @@
+use cosmwasm_std::StdError;
+use cw_utils::PaymentError;
+use dao_interface::migrate::MigrationError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    MigrationError(#[from] MigrationError),
+
+    #[error("{0}")]
+    PaymentError(#[from] PaymentError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Invalid unstaking duration, unstaking duration cannot be 0")]
+    InvalidUnstakingDuration {},
+
+    #[error("Nothing to claim")]
+    NothingToClaim {},
+
+    #[error("Too many outstanding claims. Claim some tokens before unstaking more.")]
+    TooManyClaims {},
+
+    #[error("Only owner can change owner")]
+    OnlyOwnerCanChangeOwner {},
+
+    #[error("Can only unstake less than or equal to the amount you have staked")]
+    InvalidUnstakeAmount {},
+
+    #[error("Initial governance token balances must not be empty")]
+    InitialBalancesError {},
+
+    #[error("Error instantiating token issuer")]
+    TokenIssuerInstantiateError {},
+
+    #[error("Can not change the contract's token issuer after it has been set")]
+    DuplicateTokenIssuer {},
+
+    #[error("Got a submessage reply with unknown id: {id}")]
+    UnknownReplyId { id: u64 },
+}
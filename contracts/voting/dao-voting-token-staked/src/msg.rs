@@ -0,0 +1,136 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Uint128;
+use cw_utils::Duration;
+use dao_interface::Admin;
+use dao_macros::voting_module_query;
+
+#[cw_serde]
+pub struct InitialBalance {
+    pub address: String,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct NewTokenInfo {
+    /// Code ID of the cw-tokenfactory-issuer contract which will
+    /// create and administer the new denom.
+    pub token_issuer_code_id: u64,
+    /// The subdenom of the new denom. The full denom is
+    /// `factory/{issuer address}/{subdenom}`.
+    pub subdenom: String,
+    /// Balances minted when the denom is created. May not be empty,
+    /// as a DAO with no voting power can never do anything.
+    pub initial_balances: Vec<InitialBalance>,
+    /// An amount minted to the DAO's treasury when the denom is
+    /// created.
+    pub initial_dao_balance: Option<Uint128>,
+}
+
+#[cw_serde]
+pub enum TokenInfo {
+    /// Stakes an existing native denom, e.g. ujuno or an IBC denom.
+    Existing { denom: String },
+    /// Creates a new tokenfactory denom and stakes it.
+    New(NewTokenInfo),
+}
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    // Owner can update all configs including changing the owner. This will generally be a DAO.
+    pub owner: Option<Admin>,
+    // Manager can update all configs except changing the owner. This will generally be an operations multisig for a DAO.
+    pub manager: Option<String>,
+    /// The denom to stake, or how to create it.
+    pub token_info: TokenInfo,
+    // How long until the tokens become liquid again
+    pub unstaking_duration: Option<Duration>,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    Stake {},
+    Unstake {
+        amount: Uint128,
+    },
+    UpdateConfig {
+        owner: Option<String>,
+        manager: Option<String>,
+        duration: Option<Duration>,
+    },
+    Claim {},
+}
+
+#[voting_module_query]
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(crate::state::Config)]
+    GetConfig {},
+    /// Gets the denom which is staked.
+    #[returns(DenomResponse)]
+    Denom {},
+    /// Gets the cw-tokenfactory-issuer contract which created the
+    /// staked denom, or None if an existing denom is staked.
+    #[returns(Option<cosmwasm_std::Addr>)]
+    TokenIssuerContract {},
+    #[returns(cw_controllers::ClaimsResponse)]
+    Claims { address: String },
+    #[returns(ListStakersResponse)]
+    ListStakers {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+
+pub use dao_interface::migrate::MigrateMsg;
+
+#[cw_serde]
+pub struct DenomResponse {
+    pub denom: String,
+}
+
+#[cw_serde]
+pub struct ListStakersResponse {
+    pub stakers: Vec<StakerBalanceResponse>,
+}
+
+#[cw_serde]
+pub struct StakerBalanceResponse {
+    pub address: String,
+    pub balance: Uint128,
+}
+
+/// The subset of cw-tokenfactory-issuer's instantiate messages used
+/// by this contract.
+#[cw_serde]
+pub enum IssuerInstantiateMsg {
+    /// Creates the denom `factory/{issuer address}/{subdenom}`,
+    /// owned by the instantiator.
+    NewToken { subdenom: String },
+}
+
+/// The subset of cw-tokenfactory-issuer's execute messages used by
+/// this contract.
+#[cw_serde]
+pub enum IssuerExecuteMsg {
+    /// Allows ADDRESS to mint up to ALLOWANCE tokens.
+    SetMinterAllowance {
+        address: String,
+        allowance: Uint128,
+    },
+    Mint {
+        to_address: String,
+        amount: Uint128,
+    },
+    ChangeContractOwner {
+        new_owner: String,
+    },
+}
+
+/// The subset of cw-tokenfactory-issuer's query messages used by this
+/// contract.
+#[cw_serde]
+pub enum IssuerQueryMsg {
+    /// Returns the denom the issuer administers as a `DenomResponse`.
+    Denom {},
+}
@@ -0,0 +1,385 @@
+use cosmwasm_std::{
+    coins, to_binary, Addr, Binary, Coin, Deps, DepsMut, Empty, Env, MessageInfo, Response,
+    StdError, StdResult, Uint128,
+};
+use cw_multi_test::{
+    custom_app, next_block, App, AppResponse, BankSudo, Contract, ContractWrapper, Executor,
+    SudoMsg,
+};
+use cw_utils::Duration;
+use dao_interface::voting::{TotalPowerAtHeightResponse, VotingPowerAtHeightResponse};
+use dao_interface::Admin;
+
+use crate::msg::{
+    DenomResponse, ExecuteMsg, InitialBalance, InstantiateMsg, NewTokenInfo, QueryMsg, TokenInfo,
+};
+use crate::ContractError;
+
+const DAO_ADDR: &str = "dao";
+const ADDR1: &str = "addr1";
+const ADDR2: &str = "addr2";
+const DENOM: &str = "ujuno";
+
+/// A mock cw-tokenfactory-issuer which records the tokens it is asked
+/// to mint instead of minting them.
+mod mock_issuer {
+    use cosmwasm_schema::cw_serde;
+    use cw_storage_plus::Item;
+
+    use super::*;
+    use crate::msg::{IssuerExecuteMsg, IssuerInstantiateMsg};
+
+    pub const DENOM: Item<String> = Item::new("denom");
+    pub const OWNER: Item<Addr> = Item::new("owner");
+    pub const ALLOWANCE: Item<(Addr, Uint128)> = Item::new("allowance");
+    pub const MINTED: Item<Vec<(String, Uint128)>> = Item::new("minted");
+
+    #[cw_serde]
+    pub enum QueryMsg {
+        Denom {},
+        Owner {},
+        Minted {},
+    }
+
+    pub fn instantiate(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: IssuerInstantiateMsg,
+    ) -> StdResult<Response> {
+        let IssuerInstantiateMsg::NewToken { subdenom } = msg;
+        DENOM.save(
+            deps.storage,
+            &format!("factory/{}/{}", env.contract.address, subdenom),
+        )?;
+        OWNER.save(deps.storage, &info.sender)?;
+        MINTED.save(deps.storage, &vec![])?;
+        Ok(Response::default())
+    }
+
+    pub fn execute(
+        deps: DepsMut,
+        _env: Env,
+        info: MessageInfo,
+        msg: IssuerExecuteMsg,
+    ) -> StdResult<Response> {
+        let owner = OWNER.load(deps.storage)?;
+        match msg {
+            IssuerExecuteMsg::SetMinterAllowance { address, allowance } => {
+                if info.sender != owner {
+                    return Err(StdError::generic_err("not owner"));
+                }
+                ALLOWANCE.save(deps.storage, &(Addr::unchecked(address), allowance))?;
+            }
+            IssuerExecuteMsg::Mint { to_address, amount } => {
+                let (minter, allowance) = ALLOWANCE.load(deps.storage)?;
+                if info.sender != minter {
+                    return Err(StdError::generic_err("not minter"));
+                }
+                ALLOWANCE.save(deps.storage, &(minter, allowance.checked_sub(amount)?))?;
+                MINTED.update(deps.storage, |mut minted| -> StdResult<_> {
+                    minted.push((to_address, amount));
+                    Ok(minted)
+                })?;
+            }
+            IssuerExecuteMsg::ChangeContractOwner { new_owner } => {
+                if info.sender != owner {
+                    return Err(StdError::generic_err("not owner"));
+                }
+                OWNER.save(deps.storage, &Addr::unchecked(new_owner))?;
+            }
+        }
+        Ok(Response::default())
+    }
+
+    pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+        match msg {
+            QueryMsg::Denom {} => to_binary(&DenomResponse {
+                denom: DENOM.load(deps.storage)?,
+            }),
+            QueryMsg::Owner {} => to_binary(&OWNER.load(deps.storage)?),
+            QueryMsg::Minted {} => to_binary(&MINTED.load(deps.storage)?),
+        }
+    }
+}
+
+fn staking_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        crate::contract::execute,
+        crate::contract::instantiate,
+        crate::contract::query,
+    )
+    .with_reply(crate::contract::reply);
+    Box::new(contract)
+}
+
+fn issuer_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        mock_issuer::execute,
+        mock_issuer::instantiate,
+        mock_issuer::query,
+    );
+    Box::new(contract)
+}
+
+fn mock_app() -> App {
+    custom_app(|r, _a, s| {
+        r.bank
+            .init_balance(
+                s,
+                &Addr::unchecked(ADDR1),
+                vec![Coin {
+                    denom: DENOM.to_string(),
+                    amount: Uint128::new(10000),
+                }],
+            )
+            .unwrap();
+    })
+}
+
+fn instantiate_staking(app: &mut App, token_info: TokenInfo) -> anyhow::Result<Addr> {
+    let staking_id = app.store_code(staking_contract());
+    app.instantiate_contract(
+        staking_id,
+        Addr::unchecked(DAO_ADDR),
+        &InstantiateMsg {
+            owner: Some(Admin::CoreModule {}),
+            manager: None,
+            token_info,
+            unstaking_duration: None,
+        },
+        &[],
+        "Staking",
+        None,
+    )
+}
+
+fn new_token_info(app: &mut App, initial_balances: Vec<InitialBalance>) -> TokenInfo {
+    TokenInfo::New(NewTokenInfo {
+        token_issuer_code_id: app.store_code(issuer_contract()),
+        subdenom: "ugov".to_string(),
+        initial_balances,
+        initial_dao_balance: Some(Uint128::new(1000)),
+    })
+}
+
+fn stake_tokens(
+    app: &mut App,
+    staking_addr: &Addr,
+    sender: &str,
+    amount: u128,
+    denom: &str,
+) -> anyhow::Result<AppResponse> {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        staking_addr.clone(),
+        &ExecuteMsg::Stake {},
+        &coins(amount, denom),
+    )
+}
+
+fn get_voting_power_at_height(
+    app: &App,
+    staking_addr: &Addr,
+    address: &str,
+    height: Option<u64>,
+) -> VotingPowerAtHeightResponse {
+    app.wrap()
+        .query_wasm_smart(
+            staking_addr,
+            &QueryMsg::VotingPowerAtHeight {
+                address: address.to_string(),
+                height,
+            },
+        )
+        .unwrap()
+}
+
+fn get_total_power_at_height(
+    app: &App,
+    staking_addr: &Addr,
+    height: Option<u64>,
+) -> TotalPowerAtHeightResponse {
+    app.wrap()
+        .query_wasm_smart(staking_addr, &QueryMsg::TotalPowerAtHeight { height })
+        .unwrap()
+}
+
+fn get_denom(app: &App, staking_addr: &Addr) -> String {
+    let res: DenomResponse = app
+        .wrap()
+        .query_wasm_smart(staking_addr, &QueryMsg::Denom {})
+        .unwrap();
+    res.denom
+}
+
+#[test]
+fn test_new_token() {
+    let mut app = mock_app();
+    let token_info = new_token_info(
+        &mut app,
+        vec![
+            InitialBalance {
+                address: ADDR1.to_string(),
+                amount: Uint128::new(100),
+            },
+            InitialBalance {
+                address: ADDR2.to_string(),
+                amount: Uint128::new(50),
+            },
+        ],
+    );
+    let staking_addr = instantiate_staking(&mut app, token_info).unwrap();
+
+    let issuer: Option<Addr> = app
+        .wrap()
+        .query_wasm_smart(&staking_addr, &QueryMsg::TokenIssuerContract {})
+        .unwrap();
+    let issuer = issuer.unwrap();
+    assert_eq!(
+        get_denom(&app, &staking_addr),
+        format!("factory/{issuer}/ugov")
+    );
+
+    let minted: Vec<(String, Uint128)> = app
+        .wrap()
+        .query_wasm_smart(&issuer, &mock_issuer::QueryMsg::Minted {})
+        .unwrap();
+    assert_eq!(
+        minted,
+        vec![
+            (ADDR1.to_string(), Uint128::new(100)),
+            (ADDR2.to_string(), Uint128::new(50)),
+            (DAO_ADDR.to_string(), Uint128::new(1000)),
+        ]
+    );
+
+    // The DAO controls the issuer once the initial balances have
+    // been minted.
+    let owner: Addr = app
+        .wrap()
+        .query_wasm_smart(&issuer, &mock_issuer::QueryMsg::Owner {})
+        .unwrap();
+    assert_eq!(owner, Addr::unchecked(DAO_ADDR));
+}
+
+#[test]
+fn test_new_token_requires_initial_balances() {
+    let mut app = mock_app();
+    let token_info = new_token_info(
+        &mut app,
+        vec![InitialBalance {
+            address: ADDR1.to_string(),
+            amount: Uint128::zero(),
+        }],
+    );
+    let err: ContractError = instantiate_staking(&mut app, token_info)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::InitialBalancesError {}));
+}
+
+#[test]
+fn test_stake_new_token() {
+    let mut app = mock_app();
+    let token_info = new_token_info(
+        &mut app,
+        vec![InitialBalance {
+            address: ADDR1.to_string(),
+            amount: Uint128::new(100),
+        }],
+    );
+    let staking_addr = instantiate_staking(&mut app, token_info).unwrap();
+    let denom = get_denom(&app, &staking_addr);
+
+    // The mock issuer does not mint, so mint the initial balance
+    // here.
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: ADDR1.to_string(),
+        amount: coins(100, &denom),
+    }))
+    .unwrap();
+
+    // Can't stake other denoms.
+    let err: ContractError = stake_tokens(&mut app, &staking_addr, ADDR1, 100, DENOM)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::PaymentError(_)));
+
+    stake_tokens(&mut app, &staking_addr, ADDR1, 60, &denom).unwrap();
+    app.update_block(next_block);
+
+    let resp = get_voting_power_at_height(&app, &staking_addr, ADDR1, None);
+    assert_eq!(resp.power, Uint128::new(60));
+    let resp = get_total_power_at_height(&app, &staking_addr, None);
+    assert_eq!(resp.power, Uint128::new(60));
+}
+
+#[test]
+fn test_existing_token() {
+    let mut app = mock_app();
+    let staking_addr = instantiate_staking(
+        &mut app,
+        TokenInfo::Existing {
+            denom: DENOM.to_string(),
+        },
+    )
+    .unwrap();
+    assert_eq!(get_denom(&app, &staking_addr), DENOM);
+
+    let issuer: Option<Addr> = app
+        .wrap()
+        .query_wasm_smart(&staking_addr, &QueryMsg::TokenIssuerContract {})
+        .unwrap();
+    assert_eq!(issuer, None);
+
+    stake_tokens(&mut app, &staking_addr, ADDR1, 100, DENOM).unwrap();
+    app.update_block(next_block);
+    let resp = get_voting_power_at_height(&app, &staking_addr, ADDR1, None);
+    assert_eq!(resp.power, Uint128::new(100));
+
+    // Unstaking with no unstaking duration returns tokens
+    // immediately.
+    app.execute_contract(
+        Addr::unchecked(ADDR1),
+        staking_addr.clone(),
+        &ExecuteMsg::Unstake {
+            amount: Uint128::new(40),
+        },
+        &[],
+    )
+    .unwrap();
+    app.update_block(next_block);
+    let resp = get_voting_power_at_height(&app, &staking_addr, ADDR1, None);
+    assert_eq!(resp.power, Uint128::new(60));
+    let balance = app.wrap().query_balance(ADDR1, DENOM).unwrap();
+    assert_eq!(balance.amount, Uint128::new(9940));
+}
+
+#[test]
+fn test_invalid_unstaking_duration() {
+    let mut app = mock_app();
+    let staking_id = app.store_code(staking_contract());
+    let err: ContractError = app
+        .instantiate_contract(
+            staking_id,
+            Addr::unchecked(DAO_ADDR),
+            &InstantiateMsg {
+                owner: None,
+                manager: None,
+                token_info: TokenInfo::Existing {
+                    denom: DENOM.to_string(),
+                },
+                unstaking_duration: Some(Duration::Height(0)),
+            },
+            &[],
+            "Staking",
+            None,
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::InvalidUnstakingDuration {}));
+}
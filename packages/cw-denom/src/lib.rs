@@ -7,9 +7,10 @@ use std::fmt::{self};
 
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    to_binary, Addr, BankMsg, Coin, CosmosMsg, CustomQuery, Deps, QuerierWrapper, StdError,
-    StdResult, Uint128, WasmMsg,
+    to_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, CustomQuery, Deps, QuerierWrapper,
+    QueryRequest, StdError, StdResult, Uint128, WasmMsg,
 };
+use serde::{Deserialize, Serialize};
 
 use thiserror::Error;
 
@@ -29,6 +30,17 @@ pub enum DenomError {
 
     #[error("invalid character ({c}) in native denom")]
     InvalidCharacter { c: char },
+
+    #[error("invalid token factory denom ({denom}). expected `factory/{{creator}}/{{subdenom}}`")]
+    InvalidFactoryDenom { denom: String },
+
+    #[error(
+        "invalid IBC denom ({denom}). expected `ibc/{{hash}}` where hash is 64 hex characters"
+    )]
+    InvalidIbcDenom { denom: String },
+
+    #[error("native denom ({denom}) does not exist: {err}")]
+    UnknownDenom { denom: String, err: StdError },
 }
 
 /// A denom that has been checked to point to a valid asset. This enum
@@ -62,9 +74,21 @@ impl UncheckedDenom {
     /// `cw20::TokenInfoResponse`.
     ///
     /// [default SDK rules]: https://github.com/cosmos/cosmos-sdk/blob/7728516abfab950dc7a9120caad4870f1f962df5/types/coin.go#L865-L867
+    ///
+    /// Token factory (`factory/{creator}/{subdenom}`) and IBC
+    /// (`ibc/{hash}`) denominations are additionally checked to be
+    /// well formed and to exist on chain. Token factory denoms must
+    /// have denom metadata, and IBC denoms must have a denom trace.
     pub fn into_checked(self, deps: Deps) -> Result<CheckedDenom, DenomError> {
         match self {
-            Self::Native(denom) => validate_native_denom(denom),
+            Self::Native(denom) => {
+                if denom.starts_with("factory/") {
+                    validate_factory_denom(deps, &denom)?;
+                } else if denom.starts_with("ibc/") {
+                    validate_ibc_denom(deps, &denom)?;
+                }
+                validate_native_denom(denom)
+            }
             Self::Cw20(addr) => {
                 let addr = deps.api.addr_validate(&addr)?;
                 let _info: cw20::TokenInfoResponse = deps
@@ -150,6 +174,131 @@ pub fn validate_native_denom(denom: String) -> Result<CheckedDenom, DenomError>
     Ok(CheckedDenom::Native(denom))
 }
 
+/// Checks that DENOM is of the form `factory/{creator}/{subdenom}`,
+/// that creator is a valid address, and that the denom has metadata
+/// registered with the bank module (token factory sets this on
+/// creation).
+fn validate_factory_denom(deps: Deps, denom: &str) -> Result<(), DenomError> {
+    let invalid = || DenomError::InvalidFactoryDenom {
+        denom: denom.to_string(),
+    };
+    let mut parts = denom.splitn(3, '/').skip(1);
+    let creator = parts.next().ok_or_else(invalid)?;
+    let subdenom = parts.next().ok_or_else(invalid)?;
+    if subdenom.is_empty() {
+        return Err(invalid());
+    }
+    deps.api.addr_validate(creator).map_err(|_| invalid())?;
+
+    query_denom_metadata(&deps.querier, denom).map_err(|err| DenomError::UnknownDenom {
+        denom: denom.to_string(),
+        err,
+    })?;
+    Ok(())
+}
+
+/// Checks that DENOM is of the form `ibc/{hash}` where hash is a hex
+/// encoded SHA-256 hash, and that the transfer module has a denom
+/// trace for that hash.
+fn validate_ibc_denom(deps: Deps, denom: &str) -> Result<(), DenomError> {
+    let hash = &denom["ibc/".len()..];
+    if hash.len() != 64 || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(DenomError::InvalidIbcDenom {
+            denom: denom.to_string(),
+        });
+    }
+
+    query_denom_trace(&deps.querier, hash).map_err(|err| DenomError::UnknownDenom {
+        denom: denom.to_string(),
+        err,
+    })?;
+    Ok(())
+}
+
+/// A unit of a native denomination, as registered in the bank
+/// module's denom metadata.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(default)]
+pub struct DenomUnit {
+    pub denom: String,
+    pub exponent: u32,
+    pub aliases: Vec<String>,
+}
+
+/// Metadata for a native denomination, as returned by the bank
+/// module's `DenomMetadata` query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(default)]
+pub struct DenomMetadata {
+    pub description: String,
+    pub denom_units: Vec<DenomUnit>,
+    pub base: String,
+    pub display: String,
+    pub name: String,
+    pub symbol: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct DenomMetadataResponse {
+    metadata: DenomMetadata,
+}
+
+/// The path an IBC denomination took to arrive on this chain, as
+/// returned by the transfer module's `DenomTrace` query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(default)]
+pub struct DenomTrace {
+    pub path: String,
+    pub base_denom: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct DenomTraceResponse {
+    denom_trace: DenomTrace,
+}
+
+/// Encodes a protobuf message whose only field is a string with field
+/// number one. This is the shape of both the `DenomMetadata` and
+/// `DenomTrace` query requests.
+fn encode_string_request(value: &str) -> Binary {
+    let mut len = value.len();
+    let mut buf = vec![0x0a];
+    while len >= 0x80 {
+        buf.push((len as u8 & 0x7f) | 0x80);
+        len >>= 7;
+    }
+    buf.push(len as u8);
+    buf.extend_from_slice(value.as_bytes());
+    Binary(buf)
+}
+
+/// Queries the bank module for DENOM's metadata. Errors if the denom
+/// has no metadata.
+pub fn query_denom_metadata<C: CustomQuery>(
+    querier: &QuerierWrapper<C>,
+    denom: &str,
+) -> StdResult<DenomMetadata> {
+    let res: DenomMetadataResponse = querier.query(&QueryRequest::Stargate {
+        path: "/cosmos.bank.v1beta1.Query/DenomMetadata".to_string(),
+        data: encode_string_request(denom),
+    })?;
+    Ok(res.metadata)
+}
+
+/// Queries the transfer module for the denom trace of the IBC denom
+/// with HASH (the part of the denom after `ibc/`). Errors if no such
+/// denom trace exists.
+pub fn query_denom_trace<C: CustomQuery>(
+    querier: &QuerierWrapper<C>,
+    hash: &str,
+) -> StdResult<DenomTrace> {
+    let res: DenomTraceResponse = querier.query(&QueryRequest::Stargate {
+        path: "/ibc.applications.transfer.v1.Query/DenomTrace".to_string(),
+        data: encode_string_request(hash),
+    })?;
+    Ok(res.denom_trace)
+}
+
 // Useful for returning these in response objects when updating the
 // config or doing a withdrawal.
 impl fmt::Display for CheckedDenom {
@@ -165,7 +314,8 @@ impl fmt::Display for CheckedDenom {
 mod tests {
     use cosmwasm_std::{
         testing::{mock_dependencies, MockQuerier},
-        to_binary, Addr, ContractResult, QuerierResult, StdError, SystemError, Uint128, WasmQuery,
+        to_binary, Addr, ContractResult, Querier, QuerierResult, StdError, SystemError, Uint128,
+        WasmQuery,
     };
 
     use super::*;
@@ -312,6 +462,119 @@ mod tests {
         }
     }
 
+    /// Responds to `DenomMetadata` and `DenomTrace` stargate queries
+    /// with RESPONSE, and to everything else like the mock querier.
+    struct StargateQuerier {
+        response: Binary,
+    }
+
+    impl Querier for StargateQuerier {
+        fn raw_query(&self, bin_request: &[u8]) -> QuerierResult {
+            let request: QueryRequest<cosmwasm_std::Empty> =
+                cosmwasm_std::from_slice(bin_request).unwrap();
+            match request {
+                QueryRequest::Stargate { .. } => {
+                    QuerierResult::Ok(ContractResult::Ok(self.response.clone()))
+                }
+                _ => MockQuerier::default().raw_query(bin_request),
+            }
+        }
+    }
+
+    const IBC_DENOM: &str = "ibc/A59A9C955F1AB8B76671B00C1A0482C64A6590352944BB5880E5122358F7E1CE";
+
+    #[test]
+    fn test_into_checked_factory_and_ibc_invalid() {
+        let deps = mock_dependencies();
+
+        for invalid in [
+            "factory/creator",
+            "factory/creator/",
+            "factory/HasCapitalsSoShouldNotValidate/subdenom",
+        ] {
+            assert_eq!(
+                UncheckedDenom::Native(invalid.to_string()).into_checked(deps.as_ref()),
+                Err(DenomError::InvalidFactoryDenom {
+                    denom: invalid.to_string()
+                })
+            );
+        }
+        for invalid in [
+            "ibc/abc",
+            "ibc/zz9A9C955F1AB8B76671B00C1A0482C64A6590352944BB5880E5122358F7E1CE",
+        ] {
+            assert_eq!(
+                UncheckedDenom::Native(invalid.to_string()).into_checked(deps.as_ref()),
+                Err(DenomError::InvalidIbcDenom {
+                    denom: invalid.to_string()
+                })
+            );
+        }
+
+        // Well formed, but the default mock querier doesn't know
+        // about the denom.
+        let err = UncheckedDenom::Native(IBC_DENOM.to_string())
+            .into_checked(deps.as_ref())
+            .unwrap_err();
+        assert!(matches!(err, DenomError::UnknownDenom { .. }));
+    }
+
+    #[test]
+    fn test_into_checked_factory_and_ibc_valid() {
+        let deps = mock_dependencies();
+        let metadata = DenomMetadata {
+            base: "factory/creator/subdenom".to_string(),
+            display: "subdenom".to_string(),
+            ..Default::default()
+        };
+        let mut deps = cosmwasm_std::OwnedDeps {
+            storage: deps.storage,
+            api: deps.api,
+            querier: StargateQuerier {
+                response: to_binary(&DenomMetadataResponse {
+                    metadata: metadata.clone(),
+                })
+                .unwrap(),
+            },
+            custom_query_type: std::marker::PhantomData::<cosmwasm_std::Empty>,
+        };
+        let checked = UncheckedDenom::Native("factory/creator/subdenom".to_string())
+            .into_checked(deps.as_ref())
+            .unwrap();
+        assert_eq!(
+            checked,
+            CheckedDenom::Native("factory/creator/subdenom".to_string())
+        );
+        assert_eq!(
+            query_denom_metadata(&deps.as_ref().querier, "factory/creator/subdenom").unwrap(),
+            metadata
+        );
+
+        deps.querier.response = to_binary(&DenomTraceResponse {
+            denom_trace: DenomTrace {
+                path: "transfer/channel-0".to_string(),
+                base_denom: "uatom".to_string(),
+            },
+        })
+        .unwrap();
+        UncheckedDenom::Native(IBC_DENOM.to_string())
+            .into_checked(deps.as_ref())
+            .unwrap();
+    }
+
+    #[test]
+    fn test_encode_string_request() {
+        assert_eq!(
+            encode_string_request("abc"),
+            Binary(vec![0x0a, 3, b'a', b'b', b'c'])
+        );
+        let long = "a".repeat(200);
+        let encoded = encode_string_request(&long);
+        // 200 = 0b1_1001000 -> [0xc8, 0x01]
+        assert_eq!(&encoded.as_slice()[..3], &[0x0a, 0xc8, 0x01]);
+        assert_eq!(encoded.len(), 203);
+    }
+
     #[test]
     fn test_display() {
         let denom = CheckedDenom::Native("hello".to_string());
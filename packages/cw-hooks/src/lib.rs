@@ -4,13 +4,50 @@ use thiserror::Error;
 
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, CustomQuery, Deps, StdError, StdResult, Storage, SubMsg};
-use cw_storage_plus::Item;
+use cw_storage_plus::{Item, Map};
 
 #[cw_serde]
 pub struct HooksResponse {
     pub hooks: Vec<String>,
 }
 
+#[cw_serde]
+pub struct HookFailure {
+    pub hook: String,
+    /// The number of times calling this hook has failed since it was
+    /// added.
+    pub failures: u32,
+}
+
+#[cw_serde]
+pub struct HookFailuresResponse {
+    pub failures: Vec<HookFailure>,
+}
+
+/// The result of recording a failed hook call.
+#[derive(Debug, PartialEq)]
+pub enum FailureOutcome {
+    /// The hook has failed no more than the allowed number of retries
+    /// and remains registered.
+    Retained { hook: Addr, failures: u32 },
+    /// The hook has exhausted its retries and was removed.
+    Removed { hook: Addr },
+}
+
+impl FailureOutcome {
+    /// The hook which failed.
+    pub fn hook(&self) -> &Addr {
+        match self {
+            FailureOutcome::Retained { hook, .. } | FailureOutcome::Removed { hook } => hook,
+        }
+    }
+
+    /// True if the hook was removed.
+    pub fn is_removed(&self) -> bool {
+        matches!(self, FailureOutcome::Removed { .. })
+    }
+}
+
 #[derive(Error, Debug, PartialEq)]
 pub enum HookError {
     #[error("{0}")]
@@ -24,31 +61,41 @@ pub enum HookError {
 }
 
 // store all hook addresses in one item. We cannot have many of them before the contract becomes unusable anyway.
-pub struct Hooks<'a>(Item<'a, Vec<Addr>>);
+pub struct Hooks<'a> {
+    hooks: Item<'a, Vec<Addr>>,
+    // Failure counts share a storage key with the hook list. Map keys
+    // are length prefixed so they can not collide with the item.
+    failures: Map<'a, Addr, u32>,
+}
 
 impl<'a> Hooks<'a> {
     pub const fn new(storage_key: &'a str) -> Self {
-        Hooks(Item::new(storage_key))
+        Hooks {
+            hooks: Item::new(storage_key),
+            failures: Map::new(storage_key),
+        }
     }
 
     pub fn add_hook(&self, storage: &mut dyn Storage, addr: Addr) -> Result<(), HookError> {
-        let mut hooks = self.0.may_load(storage)?.unwrap_or_default();
+        let mut hooks = self.hooks.may_load(storage)?.unwrap_or_default();
         if !hooks.iter().any(|h| h == &addr) {
+            self.failures.remove(storage, addr.clone());
             hooks.push(addr);
         } else {
             return Err(HookError::HookAlreadyRegistered {});
         }
-        Ok(self.0.save(storage, &hooks)?)
+        Ok(self.hooks.save(storage, &hooks)?)
     }
 
     pub fn remove_hook(&self, storage: &mut dyn Storage, addr: Addr) -> Result<(), HookError> {
-        let mut hooks = self.0.load(storage)?;
+        let mut hooks = self.hooks.load(storage)?;
         if let Some(p) = hooks.iter().position(|x| x == &addr) {
             hooks.remove(p);
         } else {
             return Err(HookError::HookNotRegistered {});
         }
-        Ok(self.0.save(storage, &hooks)?)
+        self.failures.remove(storage, addr);
+        Ok(self.hooks.save(storage, &hooks)?)
     }
 
     pub fn remove_hook_by_index(
@@ -56,18 +103,52 @@ impl<'a> Hooks<'a> {
         storage: &mut dyn Storage,
         index: u64,
     ) -> Result<Addr, HookError> {
-        let mut hooks = self.0.load(storage)?;
+        let mut hooks = self.hooks.load(storage)?;
+        if index as usize >= hooks.len() {
+            return Err(HookError::HookNotRegistered {});
+        }
         let hook = hooks.remove(index as usize);
-        self.0.save(storage, &hooks)?;
+        self.failures.remove(storage, hook.clone());
+        self.hooks.save(storage, &hooks)?;
         Ok(hook)
     }
 
+    /// Records a failed call to the hook at INDEX. A hook is removed
+    /// once it has failed more than RETRIES times, so a RETRIES of
+    /// zero removes a hook on its first failure. Failure counts are
+    /// reset when a hook is removed or added.
+    pub fn record_failure(
+        &self,
+        storage: &mut dyn Storage,
+        index: u64,
+        retries: u32,
+    ) -> Result<FailureOutcome, HookError> {
+        let hook = self
+            .hooks
+            .load(storage)?
+            .get(index as usize)
+            .cloned()
+            .ok_or(HookError::HookNotRegistered {})?;
+        let failures = self
+            .failures
+            .may_load(storage, hook.clone())?
+            .unwrap_or_default()
+            + 1;
+        if failures > retries {
+            self.remove_hook_by_index(storage, index)?;
+            Ok(FailureOutcome::Removed { hook })
+        } else {
+            self.failures.save(storage, hook.clone(), &failures)?;
+            Ok(FailureOutcome::Retained { hook, failures })
+        }
+    }
+
     pub fn prepare_hooks<F: FnMut(Addr) -> StdResult<SubMsg>>(
         &self,
         storage: &dyn Storage,
         prep: F,
     ) -> StdResult<Vec<SubMsg>> {
-        self.0
+        self.hooks
             .may_load(storage)?
             .unwrap_or_default()
             .into_iter()
@@ -81,14 +162,35 @@ impl<'a> Hooks<'a> {
         // <https://webassembly.github.io/spec/core/syntax/types.html#syntax-limits>. We
         // can safely return a u32 here as that's the biggest size in
         // the WASM VM.
-        Ok(self.0.may_load(storage)?.unwrap_or_default().len() as u32)
+        Ok(self.hooks.may_load(storage)?.unwrap_or_default().len() as u32)
     }
 
     pub fn query_hooks<Q: CustomQuery>(&self, deps: Deps<Q>) -> StdResult<HooksResponse> {
-        let hooks = self.0.may_load(deps.storage)?.unwrap_or_default();
+        let hooks = self.hooks.may_load(deps.storage)?.unwrap_or_default();
         let hooks = hooks.into_iter().map(String::from).collect();
         Ok(HooksResponse { hooks })
     }
+
+    /// Lists the number of times each registered hook has failed.
+    pub fn query_failures<Q: CustomQuery>(&self, deps: Deps<Q>) -> StdResult<HookFailuresResponse> {
+        let failures = self
+            .hooks
+            .may_load(deps.storage)?
+            .unwrap_or_default()
+            .into_iter()
+            .map(|hook| {
+                let failures = self
+                    .failures
+                    .may_load(deps.storage, hook.clone())?
+                    .unwrap_or_default();
+                Ok(HookFailure {
+                    hook: hook.into_string(),
+                    failures,
+                })
+            })
+            .collect::<StdResult<_>>()?;
+        Ok(HookFailuresResponse { failures })
+    }
 }
 
 #[cfg(test)]
@@ -142,4 +244,75 @@ mod tests {
 
         assert_eq!(the_hooks, vec![addr!("meow")]);
     }
+
+    #[test]
+    fn test_hook_failures() {
+        let mut deps = mock_dependencies();
+        let hooks = Hooks::new("hooks");
+        hooks.add_hook(&mut deps.storage, addr!("ekez")).unwrap();
+        hooks.add_hook(&mut deps.storage, addr!("meow")).unwrap();
+
+        // Hooks get two retries before removal.
+        for failures in 1..=2 {
+            assert_eq!(
+                hooks.record_failure(&mut deps.storage, 1, 2).unwrap(),
+                FailureOutcome::Retained {
+                    hook: addr!("meow"),
+                    failures
+                }
+            );
+        }
+        assert_eq!(
+            hooks.query_failures(deps.as_ref()).unwrap(),
+            HookFailuresResponse {
+                failures: vec![
+                    HookFailure {
+                        hook: "ekez".to_string(),
+                        failures: 0
+                    },
+                    HookFailure {
+                        hook: "meow".to_string(),
+                        failures: 2
+                    }
+                ]
+            }
+        );
+        assert_eq!(
+            hooks.record_failure(&mut deps.storage, 1, 2).unwrap(),
+            FailureOutcome::Removed {
+                hook: addr!("meow")
+            }
+        );
+        assert_eq!(hooks.hook_count(&deps.storage).unwrap(), 1);
+
+        // Re-adding a hook resets its failure count.
+        hooks.add_hook(&mut deps.storage, addr!("meow")).unwrap();
+        assert_eq!(
+            hooks.record_failure(&mut deps.storage, 1, 2).unwrap(),
+            FailureOutcome::Retained {
+                hook: addr!("meow"),
+                failures: 1
+            }
+        );
+
+        // With no retries hooks are removed on their first failure.
+        assert_eq!(
+            hooks.record_failure(&mut deps.storage, 0, 0).unwrap(),
+            FailureOutcome::Removed {
+                hook: addr!("ekez")
+            }
+        );
+
+        // Indexes that are out of bounds are not registered hooks.
+        assert_eq!(
+            hooks.record_failure(&mut deps.storage, 1, 2).unwrap_err(),
+            HookError::HookNotRegistered {}
+        );
+        assert_eq!(
+            hooks
+                .remove_hook_by_index(&mut deps.storage, 1)
+                .unwrap_err(),
+            HookError::HookNotRegistered {}
+        );
+    }
 }
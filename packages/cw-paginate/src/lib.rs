@@ -1,9 +1,115 @@
 #![doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"))]
 
+use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Deps, Order, StdResult};
 
 #[allow(unused_imports)]
-use cw_storage_plus::{Bound, Bounder, KeyDeserialize, Map, SnapshotMap, Strategy};
+use cw_storage_plus::{
+    Bound, Bounder, IndexList, IndexedMap, KeyDeserialize, Map, PrimaryKey, SnapshotMap, Strategy,
+};
+
+/// A page of (K, V) pairs along with where the next page starts.
+#[cw_serde]
+pub struct Page<K, V> {
+    pub items: Vec<(K, V)>,
+    /// True if there are more items after this page.
+    pub has_more: bool,
+    /// The key to use as `start_after` when loading the next
+    /// page. `None` if this is the last page.
+    pub next_key: Option<K>,
+}
+
+/// The order in which a query lists items. Queries take this in
+/// place of `cosmwasm_std::Order`, which may not be serialized.
+#[cw_serde]
+#[derive(Copy)]
+pub enum PageOrder {
+    Ascending,
+    Descending,
+}
+
+impl From<PageOrder> for Order {
+    fn from(order: PageOrder) -> Self {
+        match order {
+            PageOrder::Ascending => Order::Ascending,
+            PageOrder::Descending => Order::Descending,
+        }
+    }
+}
+
+/// Resolves the order a query should list items in. `default` is
+/// used if no order is provided.
+pub fn resolve_order(order: Option<PageOrder>, default: Order) -> Order {
+    order.map_or(default, Order::from)
+}
+
+/// Collects up to `limit` items, or all of the items if no limit is
+/// provided.
+pub fn take_limit<T>(
+    items: impl Iterator<Item = StdResult<T>>,
+    limit: Option<u32>,
+) -> StdResult<Vec<T>> {
+    match limit {
+        Some(limit) => items.take(limit as usize).collect(),
+        None => items.collect(),
+    }
+}
+
+/// Counts the keys in a map. This visits every key, so should only
+/// be used in queries.
+pub fn count_map_keys<'a, K, V>(deps: Deps, map: &Map<'a, K, V>) -> u64
+where
+    K: PrimaryKey<'a>,
+    V: serde::de::DeserializeOwned + serde::Serialize,
+{
+    map.keys_raw(deps.storage, None, None, Order::Ascending)
+        .count() as u64
+}
+
+/// Computes range bounds for the keys after `start_after` when
+/// iterating in `order`. For descending order these are the keys
+/// before `start_after`.
+pub fn keyset_bounds<'a, K: Bounder<'a>>(
+    start_after: Option<K>,
+    order: Order,
+) -> (Option<Bound<'a, K>>, Option<Bound<'a, K>>) {
+    match order {
+        Order::Ascending => (start_after.map(Bound::exclusive), None),
+        Order::Descending => (None, start_after.map(Bound::exclusive)),
+    }
+}
+
+/// Resolves the number of items a query should return. `default` is
+/// used if no limit is provided, and limits above `max` are lowered
+/// to `max`.
+pub fn clamp_limit<T: Ord>(limit: Option<T>, default: T, max: T) -> T {
+    limit.unwrap_or(default).min(max)
+}
+
+/// Collects up to `limit` items into a `Page`. One item past the
+/// limit is loaded to determine if there are more pages.
+pub fn page<K, V>(
+    items: impl Iterator<Item = StdResult<(K, V)>>,
+    limit: u32,
+) -> StdResult<Page<K, V>>
+where
+    K: Clone,
+{
+    let limit = limit as usize;
+    let mut items = items.take(limit + 1).collect::<StdResult<Vec<_>>>()?;
+    let has_more = items.len() > limit;
+    items.truncate(limit);
+    let next_key = if has_more {
+        items.last().map(|(k, _)| k.clone())
+    } else {
+        None
+    };
+    Ok(Page {
+        items,
+        has_more,
+        next_key,
+    })
+}
 
 /// Generic function for paginating a list of (K, V) pairs in a
 /// CosmWasm Map.
@@ -18,10 +124,7 @@ where
     K: Bounder<'a> + KeyDeserialize<Output = R> + 'b,
     V: serde::de::DeserializeOwned + serde::Serialize,
 {
-    let (range_min, range_max) = match order {
-        Order::Ascending => (start_after.map(Bound::exclusive), None),
-        Order::Descending => (None, start_after.map(Bound::exclusive)),
-    };
+    let (range_min, range_max) = keyset_bounds(start_after, order);
 
     let items = map.range(deps.storage, range_min, range_max, order);
     match limit {
@@ -44,10 +147,7 @@ where
     K: Bounder<'a> + KeyDeserialize<Output = R> + 'b,
     V: serde::de::DeserializeOwned + serde::Serialize,
 {
-    let (range_min, range_max) = match order {
-        Order::Ascending => (start_after.map(Bound::exclusive), None),
-        Order::Descending => (None, start_after.map(Bound::exclusive)),
-    };
+    let (range_min, range_max) = keyset_bounds(start_after, order);
 
     let items = map.keys(deps.storage, range_min, range_max, order);
     match limit {
@@ -70,10 +170,7 @@ where
     K: Bounder<'a> + KeyDeserialize<Output = R> + 'b,
     V: serde::de::DeserializeOwned + serde::Serialize,
 {
-    let (range_min, range_max) = match order {
-        Order::Ascending => (start_after.map(Bound::exclusive), None),
-        Order::Descending => (None, start_after.map(Bound::exclusive)),
-    };
+    let (range_min, range_max) = keyset_bounds(start_after, order);
 
     let items = map.range(deps.storage, range_min, range_max, order);
     match limit {
@@ -96,10 +193,7 @@ where
     K: Bounder<'a> + KeyDeserialize<Output = K> + 'static,
     V: serde::de::DeserializeOwned + serde::Serialize,
 {
-    let (range_min, range_max) = match order {
-        Order::Ascending => (start_after.map(Bound::exclusive), None),
-        Order::Descending => (None, start_after.map(Bound::exclusive)),
-    };
+    let (range_min, range_max) = keyset_bounds(start_after, order);
 
     let items = map
         .range(deps.storage, range_min, range_max, order)
@@ -126,10 +220,7 @@ where
     K: Bounder<'a> + KeyDeserialize<Output = R> + 'b,
     V: serde::de::DeserializeOwned + serde::Serialize,
 {
-    let (range_min, range_max) = match order {
-        Order::Ascending => (start_after.map(Bound::exclusive), None),
-        Order::Descending => (None, start_after.map(Bound::exclusive)),
-    };
+    let (range_min, range_max) = keyset_bounds(start_after, order);
 
     let items = map.keys(deps.storage, range_min, range_max, order);
     match limit {
@@ -140,6 +231,83 @@ where
     }
 }
 
+/// Same as `paginate_map` but for use with `IndexedMap`.
+pub fn paginate_indexed_map<'a, 'b, K, V, I, R: 'static>(
+    deps: Deps,
+    map: &IndexedMap<'a, K, V, I>,
+    start_after: Option<K>,
+    limit: Option<u32>,
+    order: Order,
+) -> StdResult<Vec<(R, V)>>
+where
+    K: Bounder<'a> + KeyDeserialize<Output = R> + 'b,
+    V: serde::de::DeserializeOwned + serde::Serialize + Clone,
+    I: IndexList<V>,
+{
+    let (range_min, range_max) = keyset_bounds(start_after, order);
+
+    let items = map.range(deps.storage, range_min, range_max, order);
+    match limit {
+        Some(limit) => Ok(items
+            .take(limit.try_into().unwrap())
+            .collect::<StdResult<_>>()?),
+        None => Ok(items.collect::<StdResult<_>>()?),
+    }
+}
+
+/// Same as `paginate_map` but returns a `Page` with information
+/// about where the next page starts.
+pub fn page_map<'a, 'b, K, V, R>(
+    deps: Deps,
+    map: &Map<'a, K, V>,
+    start_after: Option<K>,
+    limit: u32,
+    order: Order,
+) -> StdResult<Page<R, V>>
+where
+    K: Bounder<'a> + KeyDeserialize<Output = R> + 'b,
+    V: serde::de::DeserializeOwned + serde::Serialize,
+    R: Clone + 'static,
+{
+    let (range_min, range_max) = keyset_bounds(start_after, order);
+    page(map.range(deps.storage, range_min, range_max, order), limit)
+}
+
+/// Same as `page_map` but for use with `SnapshotMap`.
+pub fn page_snapshot_map<'a, 'b, K, V, R>(
+    deps: Deps,
+    map: &SnapshotMap<'a, K, V>,
+    start_after: Option<K>,
+    limit: u32,
+    order: Order,
+) -> StdResult<Page<R, V>>
+where
+    K: Bounder<'a> + KeyDeserialize<Output = R> + 'b,
+    V: serde::de::DeserializeOwned + serde::Serialize,
+    R: Clone + 'static,
+{
+    let (range_min, range_max) = keyset_bounds(start_after, order);
+    page(map.range(deps.storage, range_min, range_max, order), limit)
+}
+
+/// Same as `page_map` but for use with `IndexedMap`.
+pub fn page_indexed_map<'a, 'b, K, V, I, R>(
+    deps: Deps,
+    map: &IndexedMap<'a, K, V, I>,
+    start_after: Option<K>,
+    limit: u32,
+    order: Order,
+) -> StdResult<Page<R, V>>
+where
+    K: Bounder<'a> + KeyDeserialize<Output = R> + 'b,
+    V: serde::de::DeserializeOwned + serde::Serialize + Clone,
+    I: IndexList<V>,
+    R: Clone + 'static,
+{
+    let (range_min, range_max) = keyset_bounds(start_after, order);
+    page(map.range(deps.storage, range_min, range_max, order), limit)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -534,4 +702,115 @@ mod tests {
         ];
         assert_eq!(items, test_vec);
     }
+
+    #[test]
+    fn test_clamp_limit() {
+        assert_eq!(clamp_limit(None, 10, 30), 10);
+        assert_eq!(clamp_limit(Some(5), 10, 30), 5);
+        assert_eq!(clamp_limit(Some(50), 10, 30), 30);
+    }
+
+    #[test]
+    fn page_pagination() {
+        let mut deps = mock_dependencies();
+        let map: Map<u32, String> = Map::new("items");
+
+        for num in 1u32..=5 {
+            map.save(&mut deps.storage, num, &(num * 2).to_string())
+                .unwrap();
+        }
+
+        let page = page_map(deps.as_ref(), &map, None, 2, Order::Ascending).unwrap();
+        assert_eq!(
+            page,
+            Page {
+                items: vec![(1, "2".to_string()), (2, "4".to_string())],
+                has_more: true,
+                next_key: Some(2),
+            }
+        );
+
+        let page = page_map(deps.as_ref(), &map, page.next_key, 2, Order::Ascending).unwrap();
+        assert_eq!(page.items, vec![(3, "6".to_string()), (4, "8".to_string())]);
+        assert!(page.has_more);
+
+        // The last page is exactly full.
+        let page = page_map(deps.as_ref(), &map, Some(3), 2, Order::Ascending).unwrap();
+        assert_eq!(
+            page.items,
+            vec![(4, "8".to_string()), (5, "10".to_string())]
+        );
+        assert!(!page.has_more);
+        assert_eq!(page.next_key, None);
+
+        let page = page_map(deps.as_ref(), &map, None, 3, Order::Descending).unwrap();
+        assert_eq!(page.next_key, Some(3));
+        let page = page_map(deps.as_ref(), &map, page.next_key, 3, Order::Descending).unwrap();
+        assert_eq!(page.items, vec![(2, "4".to_string()), (1, "2".to_string())]);
+        assert!(!page.has_more);
+    }
+
+    #[test]
+    fn indexed_map_pagination() {
+        use cw_storage_plus::{Index, MultiIndex};
+
+        struct Indexes<'a> {
+            parity: MultiIndex<'a, u32, u32, u32>,
+        }
+
+        impl<'a> IndexList<u32> for Indexes<'a> {
+            fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<u32>> + '_> {
+                let v: Vec<&dyn Index<u32>> = vec![&self.parity];
+                Box::new(v.into_iter())
+            }
+        }
+
+        let mut deps = mock_dependencies();
+        let map = IndexedMap::new(
+            "items",
+            Indexes {
+                parity: MultiIndex::new(|_, v: &u32| v % 2, "items", "items__parity"),
+            },
+        );
+
+        for num in 1u32..=5 {
+            map.save(&mut deps.storage, num, &(num * 2)).unwrap();
+        }
+
+        let items =
+            paginate_indexed_map(deps.as_ref(), &map, Some(2), Some(2), Order::Ascending).unwrap();
+        assert_eq!(items, vec![(3, 6), (4, 8)]);
+
+        let page = page_indexed_map(deps.as_ref(), &map, Some(4), 2, Order::Descending).unwrap();
+        assert_eq!(
+            page,
+            Page {
+                items: vec![(3, 6), (2, 4)],
+                has_more: true,
+                next_key: Some(2),
+            }
+        );
+    }
+
+    #[test]
+    fn order_limit_and_count() {
+        let mut deps = mock_dependencies();
+        let map: Map<u32, u32> = Map::new("items");
+        assert_eq!(count_map_keys(deps.as_ref(), &map), 0);
+
+        for num in 1u32..=3 {
+            map.save(&mut deps.storage, num, &(num * 2)).unwrap();
+        }
+        assert_eq!(count_map_keys(deps.as_ref(), &map), 3);
+
+        let order = resolve_order(None, Order::Descending);
+        assert_eq!(order, Order::Descending);
+        let order = resolve_order(Some(PageOrder::Ascending), Order::Descending);
+        assert_eq!(order, Order::Ascending);
+
+        let keys = take_limit(map.keys(&deps.storage, None, None, order), Some(2)).unwrap();
+        assert_eq!(keys, vec![1, 2]);
+        let keys = take_limit(map.keys(&deps.storage, None, None, order), None).unwrap();
+        assert_eq!(keys, vec![1, 2, 3]);
+    }
 }
@@ -0,0 +1,151 @@
+#![doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"))]
+
+use cosmwasm_std::{Addr, Attribute, Event};
+
+/// The type of events built by this package. The chain prefixes this
+/// with `wasm-`.
+pub const EVENT_TYPE: &str = "dao";
+
+/// The version of the event schema. Incremented when the meaning of
+/// an existing key changes or a key is removed.
+pub const EVENT_SCHEMA_VERSION: &str = "1";
+
+/// The contract emitting events. Contracts typically declare one
+/// from their cw2 name and version:
+///
+/// ```
+/// use dao_events::EventSource;
+///
+/// const CONTRACT_NAME: &str = "crates.io:dao-core";
+/// const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+///
+/// const EVENTS: EventSource = EventSource::new(CONTRACT_NAME, CONTRACT_VERSION);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EventSource {
+    module: &'static str,
+    version: &'static str,
+}
+
+impl EventSource {
+    pub const fn new(module: &'static str, version: &'static str) -> Self {
+        Self { module, version }
+    }
+
+    /// Starts building an event for ACTION.
+    pub fn event(&self, action: impl Into<String>) -> DaoEvent {
+        DaoEvent::new(self.module, self.version, action)
+    }
+}
+
+/// A standardized DAO event. Converts into a cosmwasm `Event` which
+/// may be added to a response with `Response::add_event`:
+///
+/// ```
+/// # use cosmwasm_std::Response;
+/// # use dao_events::EventSource;
+/// # const EVENTS: EventSource = EventSource::new("crates.io:dao-core", "2.0.0");
+/// let response: Response = Response::new().add_event(EVENTS.event("pause").into());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaoEvent {
+    action: String,
+    module: String,
+    version: String,
+    ids: Vec<Attribute>,
+    attributes: Vec<Attribute>,
+}
+
+impl DaoEvent {
+    /// Starts building an event for ACTION emitted by MODULE at
+    /// VERSION. Contracts whose name is known at compile time should
+    /// prefer `EventSource`.
+    pub fn new(
+        module: impl Into<String>,
+        version: impl Into<String>,
+        action: impl Into<String>,
+    ) -> Self {
+        Self {
+            action: action.into(),
+            module: module.into(),
+            version: version.into(),
+            ids: vec![],
+            attributes: vec![],
+        }
+    }
+
+    /// Adds the ID of the proposal this event concerns.
+    pub fn proposal_id(self, proposal_id: u64) -> Self {
+        self.id("proposal_id", proposal_id)
+    }
+
+    /// Adds an ID of something this event concerns. IDs are emitted
+    /// before other attributes.
+    pub fn id(mut self, key: impl Into<String>, value: impl ToString) -> Self {
+        self.ids.push(Attribute::new(key, value.to_string()));
+        self
+    }
+
+    /// Adds an address this event concerns, for example the sender
+    /// or a module that was added.
+    pub fn address(self, key: impl Into<String>, address: &Addr) -> Self {
+        self.id(key, address)
+    }
+
+    /// Adds any other attribute.
+    pub fn attribute(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.attributes.push(Attribute::new(key, value));
+        self
+    }
+}
+
+impl From<DaoEvent> for Event {
+    fn from(event: DaoEvent) -> Self {
+        Event::new(EVENT_TYPE)
+            .add_attribute("schema_version", EVENT_SCHEMA_VERSION)
+            .add_attribute("action", event.action)
+            .add_attribute("module", event.module)
+            .add_attribute("module_version", event.version)
+            .add_attributes(event.ids)
+            .add_attributes(event.attributes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{Addr, Event, Response};
+
+    use crate::EventSource;
+
+    const EVENTS: EventSource = EventSource::new("crates.io:dao-core", "2.0.0");
+
+    #[test]
+    fn test_event_attributes() {
+        let event: Event = EVENTS
+            .event("vote")
+            .attribute("position", "yes")
+            .proposal_id(1)
+            .address("voter", &Addr::unchecked("ekez"))
+            .into();
+
+        assert_eq!(
+            event,
+            Event::new("dao")
+                .add_attribute("schema_version", "1")
+                .add_attribute("action", "vote")
+                .add_attribute("module", "crates.io:dao-core")
+                .add_attribute("module_version", "2.0.0")
+                .add_attribute("proposal_id", "1")
+                .add_attribute("voter", "ekez")
+                .add_attribute("position", "yes")
+        );
+    }
+
+    #[test]
+    fn test_add_to_response() {
+        let response: Response = Response::new().add_event(EVENTS.event("pause").into());
+        assert_eq!(response.events.len(), 1);
+        assert_eq!(response.events[0].ty, "dao");
+        assert_eq!(response.events[0].attributes[1].value, "pause");
+    }
+}
@@ -3,7 +3,10 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Binary, CosmosMsg, Empty, WasmMsg};
 
+pub mod message_filter;
+pub mod migrate;
 pub mod proposal;
+pub mod querier;
 pub mod voting;
 
 /// The cw-core interface.
@@ -0,0 +1,23 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{CosmosMsg, Empty};
+
+/// Sent by a DAO to each of its message filters before it executes
+/// the messages of a passed proposal, messages sent by its admin, or
+/// a transfer made with a spend limit. A filter rejects the messages
+/// by returning an error, which causes the DAO to not execute them.
+#[cw_serde]
+pub enum MessageFilterMsg {
+    FilterMessages {
+        /// The proposal module whose proposal is being executed, the
+        /// DAO's admin, or the address spending from its spend limit.
+        proposal_module: String,
+        /// The messages the DAO will execute if no filter errors.
+        msgs: Vec<CosmosMsg<Empty>>,
+    },
+}
+
+// This is just a helper to properly serialize the above message.
+#[cw_serde]
+pub enum MessageFilterExecuteMsg {
+    MessageFilterHook(MessageFilterMsg),
+}
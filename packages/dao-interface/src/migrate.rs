@@ -0,0 +1,167 @@
+//! A shared migration message for DAO modules, and helpers for
+//! checking that the version of a contract being migrated matches
+//! the migration requested.
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Empty, StdError, Storage};
+use cw2::CONTRACT;
+use thiserror::Error;
+
+/// The migration message used by DAO modules. `T` holds any
+/// information needed to migrate from the previous major version,
+/// for example values for config fields that did not exist in v1.
+#[cw_serde]
+pub enum MigrateMsg<T = Empty> {
+    /// Migrates a contract from the previous major version.
+    FromV1(T),
+    /// Migrates a contract from a version with the same major
+    /// version. State from previous major versions is not migrated,
+    /// though contracts may convert state whose format changed within
+    /// the major version.
+    FromCompatible {},
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum MigrationError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("can not migrate from version ({stored}) with `from_v1`. this message is for migrating from a previous major version")]
+    NotPreviousMajor { stored: String },
+
+    #[error("can not migrate from version ({stored}) with `from_compatible`. migrating across major versions requires a version specific message")]
+    NotCompatible { stored: String },
+}
+
+/// Returns the major version of a version string, or `None` if the
+/// string does not start with a major version. For example,
+/// `"2.0.0-beta"` has major version 2.
+pub fn major_version(version: &str) -> Option<u64> {
+    version.split('.').next()?.parse().ok()
+}
+
+/// Returns the major version of the contract version stored by cw2,
+/// or `None` if no version is stored or the stored version has no
+/// major version.
+pub fn stored_major_version(storage: &dyn Storage) -> Result<Option<u64>, MigrationError> {
+    Ok(CONTRACT
+        .may_load(storage)?
+        .and_then(|info| major_version(&info.version)))
+}
+
+impl<T> MigrateMsg<T> {
+    /// Checks that the contract version stored by cw2 can be migrated
+    /// with this message to `new_version`. `FromV1` requires that the
+    /// stored major version be less than that of `new_version`, and
+    /// `FromCompatible` requires that it be the same. Contracts without
+    /// a stored version, or whose stored version has no major version,
+    /// are permitted as they can not be checked.
+    ///
+    /// This must be called before the new version is written to
+    /// storage.
+    pub fn check_stored_version(
+        &self,
+        storage: &dyn Storage,
+        new_version: &str,
+    ) -> Result<(), MigrationError> {
+        let stored = match CONTRACT.may_load(storage)? {
+            Some(info) => info.version,
+            None => return Ok(()),
+        };
+        let (stored_major, new_major) = match (major_version(&stored), major_version(new_version)) {
+            (Some(stored_major), Some(new_major)) => (stored_major, new_major),
+            _ => return Ok(()),
+        };
+        match self {
+            MigrateMsg::FromV1(_) if stored_major >= new_major => {
+                Err(MigrationError::NotPreviousMajor { stored })
+            }
+            MigrateMsg::FromCompatible {} if stored_major != new_major => {
+                Err(MigrationError::NotCompatible { stored })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{testing::MockStorage, to_binary, Binary};
+    use cw2::set_contract_version;
+
+    use super::*;
+
+    #[test]
+    fn test_major_version() {
+        assert_eq!(major_version("2.0.0-beta"), Some(2));
+        assert_eq!(major_version("0.1.0"), Some(0));
+        assert_eq!(major_version("10"), Some(10));
+        assert_eq!(major_version("old-version"), None);
+        assert_eq!(major_version(""), None);
+    }
+
+    #[test]
+    fn test_stored_major_version() {
+        let mut storage = MockStorage::new();
+        assert_eq!(stored_major_version(&storage).unwrap(), None);
+
+        set_contract_version(&mut storage, "crates.io:cw-core", "0.1.0").unwrap();
+        assert_eq!(stored_major_version(&storage).unwrap(), Some(0));
+    }
+
+    #[test]
+    fn test_check_stored_version() {
+        let mut storage = MockStorage::new();
+        let from_v1: MigrateMsg = MigrateMsg::FromV1(Empty {});
+        let compatible: MigrateMsg = MigrateMsg::FromCompatible {};
+
+        // Contracts without a stored version can not be checked.
+        from_v1.check_stored_version(&storage, "2.0.0").unwrap();
+        compatible.check_stored_version(&storage, "2.0.0").unwrap();
+
+        // DAO DAO v1 contracts report version 0.1.0.
+        set_contract_version(&mut storage, "crates.io:cw-core", "0.1.0").unwrap();
+        from_v1.check_stored_version(&storage, "2.0.0").unwrap();
+        assert_eq!(
+            compatible.check_stored_version(&storage, "2.0.0"),
+            Err(MigrationError::NotCompatible {
+                stored: "0.1.0".to_string()
+            })
+        );
+
+        set_contract_version(&mut storage, "crates.io:dao-core", "2.0.0-beta").unwrap();
+        compatible.check_stored_version(&storage, "2.1.0").unwrap();
+        assert_eq!(
+            from_v1.check_stored_version(&storage, "2.1.0"),
+            Err(MigrationError::NotPreviousMajor {
+                stored: "2.0.0-beta".to_string()
+            })
+        );
+
+        // Versions that can not be parsed are not checked.
+        set_contract_version(&mut storage, "crates.io:dao-core", "old-version").unwrap();
+        compatible.check_stored_version(&storage, "2.0.0").unwrap();
+        from_v1.check_stored_version(&storage, "2.0.0").unwrap();
+    }
+
+    #[test]
+    fn test_migrate_msg_serialization() {
+        // `FromV1` serializes the same as a struct variant with the
+        // fields of its contents.
+        #[cw_serde]
+        struct V1Info {
+            dao_uri: Option<String>,
+        }
+        assert_eq!(
+            to_binary(&MigrateMsg::FromV1(V1Info {
+                dao_uri: Some("uri".to_string())
+            }))
+            .unwrap(),
+            Binary::from(br#"{"from_v1":{"dao_uri":"uri"}}"#)
+        );
+        assert_eq!(
+            to_binary(&MigrateMsg::<Empty>::FromCompatible {}).unwrap(),
+            Binary::from(br#"{"from_compatible":{}}"#)
+        );
+    }
+}
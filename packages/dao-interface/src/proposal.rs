@@ -1,4 +1,6 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Addr;
+use cw_utils::Expiration;
 use dao_macros::proposal_module_query;
 
 #[proposal_module_query]
@@ -6,6 +8,77 @@ use dao_macros::proposal_module_query;
 #[derive(QueryResponses)]
 pub enum Query {}
 
+/// Information about a proposal that every proposal module tracks,
+/// regardless of its proposal type. Lets contracts like veto gates
+/// and dashboards read proposals without knowing the response types
+/// of each proposal module.
+#[cw_serde]
+pub struct GenericProposalInfoResponse {
+    /// The address that created the proposal.
+    pub proposer: Addr,
+    /// The proposal's status in snake case, for example `open` or
+    /// `execution_failed`. This is how `dao_voting::status::Status`
+    /// serializes, so it may be deserialized as one.
+    pub status: String,
+    /// When voting on the proposal ends.
+    pub expiration: Expiration,
+    /// The block height at which the proposal was created.
+    pub created: u64,
+}
+
+#[cw_serde]
+pub enum ProposalCreationPolicy {
+    /// Anyone may create a proposal, free of charge.
+    Anyone {},
+    /// Only ADDR may create proposals. It is expected that ADDR is a
+    /// pre-propose module, though we only require that it is a valid
+    /// address.
+    Module { addr: Addr },
+    /// Only the DAO at ADDR may create proposals. Proposal modules
+    /// may be configured to fall back to this policy if their
+    /// pre-propose module fails.
+    Dao { addr: Addr },
+    /// No one may create proposals. Proposal modules may be
+    /// configured to fall back to this policy if their pre-propose
+    /// module fails.
+    Frozen {},
+}
+
+impl ProposalCreationPolicy {
+    /// Determines if CREATOR is permitted to create a
+    /// proposal. Returns true if so and false otherwise.
+    pub fn is_permitted(&self, creator: &Addr) -> bool {
+        match self {
+            Self::Anyone {} => true,
+            Self::Module { addr } | Self::Dao { addr } => creator == addr,
+            Self::Frozen {} => false,
+        }
+    }
+}
+
+/// Messages sent by the DAO while replacing a proposal module with
+/// `ReplaceProposalModule`.
+#[cw_serde]
+pub enum ReplacementMsg {
+    /// Sent to the new proposal module. Copies the proposal and vote
+    /// hook consumers, the proposal creation policy, and the proposal
+    /// count of the replaced MODULE so that proposal IDs are not
+    /// reused.
+    ImportModuleState { module: String },
+    /// Sent to the pre-propose module of the replaced proposal
+    /// module, binding it to the proposal module at ADDRESS.
+    UpdateProposalModule { address: String },
+}
+
+/// Queried by the DAO to find the pre-propose module of a proposal
+/// module it is replacing.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum CreationPolicyQuery {
+    #[returns(ProposalCreationPolicy)]
+    ProposalCreationPolicy {},
+}
+
 mod tests {
     /// Make sure the enum has all of the fields we expect. This will
     /// fail to compile if not.
@@ -19,6 +92,7 @@ mod tests {
             Query::Dao {} => (),
             Query::Info {} => (),
             Query::NextProposalId {} => (),
+            Query::GenericProposalInfo { .. } => (),
         }
     }
 }
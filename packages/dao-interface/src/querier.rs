@@ -0,0 +1,363 @@
+//! Typed wrappers around `QuerierWrapper` for querying DAO core,
+//! voting, and proposal modules. These save callers from building
+//! query messages and naming response types by hand.
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_schema::serde::de::DeserializeOwned;
+use cosmwasm_std::{
+    from_slice, Addr, Api, CustomQuery, Empty, QuerierWrapper, StdError, StdResult,
+};
+
+use crate::proposal::{self, ProposalCreationPolicy};
+use crate::voting::{self, InfoResponse, TotalPowerAtHeightResponse, VotingPowerAtHeightResponse};
+
+/// The subset of the core module's query interface used by
+/// `CoreQuerier`. The core module's message types can not be used
+/// directly as the core module depends on this package.
+#[cw_serde]
+enum CoreQuery {
+    Admin {},
+    GetItem {
+        key: String,
+    },
+    Info {},
+    VotingModule {},
+    VotingPowerAtHeight {
+        address: String,
+        height: Option<u64>,
+    },
+    TotalPowerAtHeight {
+        height: Option<u64>,
+    },
+}
+
+#[cw_serde]
+struct GetItemResponse {
+    item: Option<String>,
+}
+
+/// Queries a DAO's core module.
+pub struct CoreQuerier<'a, C: CustomQuery = Empty> {
+    querier: QuerierWrapper<'a, C>,
+    addr: Addr,
+}
+
+impl<'a, C: CustomQuery> CoreQuerier<'a, C> {
+    pub fn new(querier: QuerierWrapper<'a, C>, addr: Addr) -> Self {
+        Self { querier, addr }
+    }
+
+    /// The address of the DAO's admin.
+    pub fn admin(&self) -> StdResult<Addr> {
+        self.querier
+            .query_wasm_smart(&self.addr, &CoreQuery::Admin {})
+    }
+
+    /// The item stored under KEY, or `None` if no such item exists.
+    pub fn item(&self, key: impl Into<String>) -> StdResult<Option<String>> {
+        let resp: GetItemResponse = self
+            .querier
+            .query_wasm_smart(&self.addr, &CoreQuery::GetItem { key: key.into() })?;
+        Ok(resp.item)
+    }
+
+    /// The item stored under KEY parsed as a `u64`, or `None` if no
+    /// such item exists. Errors if the item is not a `u64`.
+    pub fn item_u64(&self, key: impl Into<String>) -> StdResult<Option<u64>> {
+        self.item(key)?
+            .map(|item| {
+                item.parse()
+                    .map_err(|e: std::num::ParseIntError| StdError::parse_err("u64", e))
+            })
+            .transpose()
+    }
+
+    /// The item stored under KEY parsed as a `bool`, or `None` if no
+    /// such item exists. Errors if the item is not `true` or `false`.
+    pub fn item_bool(&self, key: impl Into<String>) -> StdResult<Option<bool>> {
+        self.item(key)?
+            .map(|item| {
+                item.parse()
+                    .map_err(|e: std::str::ParseBoolError| StdError::parse_err("bool", e))
+            })
+            .transpose()
+    }
+
+    /// The item stored under KEY validated as an address, or `None`
+    /// if no such item exists.
+    pub fn item_addr(&self, api: &dyn Api, key: impl Into<String>) -> StdResult<Option<Addr>> {
+        self.item(key)?
+            .map(|item| api.addr_validate(&item))
+            .transpose()
+    }
+
+    /// The item stored under KEY deserialized from JSON, or `None` if
+    /// no such item exists.
+    pub fn item_json<T: DeserializeOwned>(&self, key: impl Into<String>) -> StdResult<Option<T>> {
+        self.item(key)?
+            .map(|item| from_slice(item.as_bytes()))
+            .transpose()
+    }
+
+    pub fn info(&self) -> StdResult<InfoResponse> {
+        self.querier
+            .query_wasm_smart(&self.addr, &CoreQuery::Info {})
+    }
+
+    /// The address of the DAO's voting module.
+    pub fn voting_module(&self) -> StdResult<Addr> {
+        self.querier
+            .query_wasm_smart(&self.addr, &CoreQuery::VotingModule {})
+    }
+
+    /// A querier for the DAO's voting module.
+    pub fn voting_module_querier(&self) -> StdResult<VotingModuleQuerier<'a, C>> {
+        Ok(VotingModuleQuerier::new(
+            self.querier,
+            self.voting_module()?,
+        ))
+    }
+
+    pub fn voting_power_at_height(
+        &self,
+        address: impl Into<String>,
+        height: Option<u64>,
+    ) -> StdResult<VotingPowerAtHeightResponse> {
+        self.querier.query_wasm_smart(
+            &self.addr,
+            &CoreQuery::VotingPowerAtHeight {
+                address: address.into(),
+                height,
+            },
+        )
+    }
+
+    pub fn total_power_at_height(
+        &self,
+        height: Option<u64>,
+    ) -> StdResult<TotalPowerAtHeightResponse> {
+        self.querier
+            .query_wasm_smart(&self.addr, &CoreQuery::TotalPowerAtHeight { height })
+    }
+}
+
+/// Queries a voting module.
+pub struct VotingModuleQuerier<'a, C: CustomQuery = Empty> {
+    querier: QuerierWrapper<'a, C>,
+    addr: Addr,
+}
+
+impl<'a, C: CustomQuery> VotingModuleQuerier<'a, C> {
+    pub fn new(querier: QuerierWrapper<'a, C>, addr: Addr) -> Self {
+        Self { querier, addr }
+    }
+
+    /// The address of the DAO this voting module belongs to.
+    pub fn dao(&self) -> StdResult<Addr> {
+        self.querier
+            .query_wasm_smart(&self.addr, &voting::Query::Dao {})
+    }
+
+    pub fn info(&self) -> StdResult<InfoResponse> {
+        self.querier
+            .query_wasm_smart(&self.addr, &voting::Query::Info {})
+    }
+
+    pub fn voting_power_at_height(
+        &self,
+        address: impl Into<String>,
+        height: Option<u64>,
+    ) -> StdResult<VotingPowerAtHeightResponse> {
+        self.querier.query_wasm_smart(
+            &self.addr,
+            &voting::Query::VotingPowerAtHeight {
+                address: address.into(),
+                height,
+            },
+        )
+    }
+
+    pub fn total_power_at_height(
+        &self,
+        height: Option<u64>,
+    ) -> StdResult<TotalPowerAtHeightResponse> {
+        self.querier
+            .query_wasm_smart(&self.addr, &voting::Query::TotalPowerAtHeight { height })
+    }
+
+    /// The token contract associated with this voting module. Only
+    /// implemented by token based voting modules.
+    pub fn token_contract(&self) -> StdResult<Addr> {
+        self.querier
+            .query_wasm_smart(&self.addr, &voting::Query::TokenContract {})
+    }
+
+    /// Whether the voting module is active. Voting modules are not
+    /// required to implement this query, and lacking an
+    /// implementation they are active.
+    pub fn is_active(&self) -> bool {
+        self.querier
+            .query_wasm_smart(&self.addr, &voting::Query::IsActive {})
+            .map(|resp: voting::IsActiveResponse| resp.active)
+            .unwrap_or(true)
+    }
+}
+
+/// The subset of the proposal module interface not covered by
+/// `proposal::Query`.
+#[cw_serde]
+enum ProposalModuleQuery {
+    ProposalCreationPolicy {},
+}
+
+/// Queries a proposal module.
+pub struct ProposalModuleQuerier<'a, C: CustomQuery = Empty> {
+    querier: QuerierWrapper<'a, C>,
+    addr: Addr,
+}
+
+impl<'a, C: CustomQuery> ProposalModuleQuerier<'a, C> {
+    pub fn new(querier: QuerierWrapper<'a, C>, addr: Addr) -> Self {
+        Self { querier, addr }
+    }
+
+    /// The address of the DAO this proposal module belongs to.
+    pub fn dao(&self) -> StdResult<Addr> {
+        self.querier
+            .query_wasm_smart(&self.addr, &proposal::Query::Dao {})
+    }
+
+    pub fn info(&self) -> StdResult<InfoResponse> {
+        self.querier
+            .query_wasm_smart(&self.addr, &proposal::Query::Info {})
+    }
+
+    /// The ID that will be assigned to the next proposal created.
+    pub fn next_proposal_id(&self) -> StdResult<u64> {
+        self.querier
+            .query_wasm_smart(&self.addr, &proposal::Query::NextProposalId {})
+    }
+
+    pub fn proposal_creation_policy(&self) -> StdResult<ProposalCreationPolicy> {
+        self.querier
+            .query_wasm_smart(&self.addr, &ProposalModuleQuery::ProposalCreationPolicy {})
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{
+        from_binary,
+        testing::{mock_dependencies, MockQuerier},
+        to_binary, ContractResult, SystemResult, Uint128, WasmQuery,
+    };
+
+    use super::*;
+
+    fn mock_querier() -> MockQuerier {
+        let mut querier = MockQuerier::new(&[]);
+        querier.update_wasm(|query| match query {
+            WasmQuery::Smart { contract_addr, msg } if contract_addr == "dao" => {
+                match from_binary::<CoreQuery>(msg).unwrap() {
+                    CoreQuery::VotingModule {} => SystemResult::Ok(ContractResult::Ok(
+                        to_binary(&Addr::unchecked("voting")).unwrap(),
+                    )),
+                    CoreQuery::GetItem { key } => SystemResult::Ok(ContractResult::Ok(
+                        to_binary(&GetItemResponse {
+                            item: match key.as_str() {
+                                "meme" => Some("doge".to_string()),
+                                "count" => Some("42".to_string()),
+                                "enabled" => Some("true".to_string()),
+                                "treasurer" => Some("ekez".to_string()),
+                                "limits" => Some(r#"{"min":1,"max":10}"#.to_string()),
+                                _ => None,
+                            },
+                        })
+                        .unwrap(),
+                    )),
+                    _ => unimplemented!(),
+                }
+            }
+            WasmQuery::Smart { contract_addr, msg } if contract_addr == "voting" => {
+                match from_binary::<voting::Query>(msg).unwrap() {
+                    voting::Query::VotingPowerAtHeight { address, height } => {
+                        SystemResult::Ok(ContractResult::Ok(
+                            to_binary(&VotingPowerAtHeightResponse {
+                                power: Uint128::new(address.len() as u128),
+                                height: height.unwrap_or(12345),
+                            })
+                            .unwrap(),
+                        ))
+                    }
+                    _ => SystemResult::Ok(ContractResult::Err("unknown query".to_string())),
+                }
+            }
+            _ => SystemResult::Ok(ContractResult::Err("no such contract".to_string())),
+        });
+        querier
+    }
+
+    #[test]
+    fn test_core_querier() {
+        let mock = mock_querier();
+        let core = CoreQuerier::new(QuerierWrapper::new(&mock), Addr::unchecked("dao"));
+
+        assert_eq!(core.voting_module().unwrap(), Addr::unchecked("voting"));
+        assert_eq!(core.item("meme").unwrap(), Some("doge".to_string()));
+        assert_eq!(core.item("cat").unwrap(), None);
+
+        let voting = core.voting_module_querier().unwrap();
+        assert_eq!(
+            voting.voting_power_at_height("ekez", Some(10)).unwrap(),
+            VotingPowerAtHeightResponse {
+                power: Uint128::new(4),
+                height: 10
+            }
+        );
+        // Voting modules that do not implement `IsActive` are
+        // active.
+        assert!(voting.is_active());
+    }
+
+    #[test]
+    fn test_typed_items() {
+        #[cw_serde]
+        struct Limits {
+            min: u64,
+            max: u64,
+        }
+
+        let deps = mock_dependencies();
+        let mock = mock_querier();
+        let core = CoreQuerier::new(QuerierWrapper::new(&mock), Addr::unchecked("dao"));
+
+        assert_eq!(core.item_u64("count").unwrap(), Some(42));
+        assert_eq!(core.item_u64("cat").unwrap().unwrap_or(7), 7);
+        core.item_u64("meme").unwrap_err();
+
+        assert_eq!(core.item_bool("enabled").unwrap(), Some(true));
+        assert_eq!(core.item_bool("cat").unwrap(), None);
+        core.item_bool("count").unwrap_err();
+
+        assert_eq!(
+            core.item_addr(&deps.api, "treasurer").unwrap(),
+            Some(Addr::unchecked("ekez"))
+        );
+        assert_eq!(core.item_addr(&deps.api, "cat").unwrap(), None);
+
+        assert_eq!(
+            core.item_json::<Limits>("limits").unwrap(),
+            Some(Limits { min: 1, max: 10 })
+        );
+        core.item_json::<Limits>("meme").unwrap_err();
+    }
+
+    #[test]
+    fn test_proposal_module_querier_error() {
+        let mock = mock_querier();
+        let proposal =
+            ProposalModuleQuerier::new(QuerierWrapper::new(&mock), Addr::unchecked("proposal"));
+        proposal.proposal_creation_policy().unwrap_err();
+        proposal.next_proposal_id().unwrap_err();
+    }
+}
@@ -288,8 +288,8 @@ pub fn active_query(metadata: TokenStream, input: TokenStream) -> TokenStream {
 /// enum QueryMsg {
 ///     Dao {},
 ///     Info {},
-///     ProposalCreationPolicy {},
-///     ProposalHooks {},
+///     NextProposalId {},
+///     GenericProposalInfo { proposal_id: u64 },
 /// }
 /// ```
 ///
@@ -317,6 +317,7 @@ pub fn active_query(metadata: TokenStream, input: TokenStream) -> TokenStream {
 #[proc_macro_attribute]
 pub fn proposal_module_query(metadata: TokenStream, input: TokenStream) -> TokenStream {
     let i = dao_interface_path("voting::InfoResponse");
+    let g = dao_interface_path("proposal::GenericProposalInfoResponse");
 
     merge_variants(
         metadata,
@@ -333,12 +334,501 @@ pub fn proposal_module_query(metadata: TokenStream, input: TokenStream) -> Token
             /// next proposal created.
             #[returns(::std::primitive::u64)]
             NextProposalId {},
+            /// Returns information about a proposal that every
+            /// proposal module tracks, regardless of its proposal
+            /// type.
+            #[returns(#g)]
+            GenericProposalInfo { proposal_id: u64 },
         }
         }
         .into(),
     )
 }
 
+/// Adds `Pause` and `Unpause` variants to an execute message enum so
+/// that a contract may be paused. See the `dao-pausable` package for
+/// storage and checks to go along with these messages.
+///
+/// For example:
+///
+/// ```
+/// use dao_macros::pausable;
+/// use cosmwasm_schema::cw_serde;
+///
+/// #[pausable]
+/// #[cw_serde]
+/// enum ExecuteMsg {}
+/// ```
+///
+/// Will transform the enum to:
+///
+/// ```
+/// enum ExecuteMsg {
+///     Pause {
+///         duration: cw_utils::Duration,
+///         reason: Option<String>,
+///     },
+///     Unpause {},
+/// }
+/// ```
+///
+/// Note that other derive macro invocations must occur after this
+/// procedural macro as they may depend on the new fields. For
+/// example, the following will fail becase the `Clone` derivation
+/// occurs before the addition of the field.
+///
+/// ```compile_fail
+/// use dao_macros::pausable;
+///
+/// #[derive(Clone)]
+/// #[pausable]
+/// #[allow(dead_code)]
+/// enum Test {
+///     Foo,
+///     Bar(u64),
+///     Baz { foo: u64 },
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn pausable(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    merge_variants(
+        metadata,
+        input,
+        quote! {
+        enum Right {
+            /// Pauses the contract for `duration`.
+            Pause {
+                duration: ::cw_utils::Duration,
+                /// An optional explanation of why the contract was
+                /// paused, returned from `PauseInfo` queries while
+                /// paused.
+                reason: ::std::option::Option<::std::string::String>,
+            },
+            /// Unpauses the contract.
+            Unpause {}
+        }
+        }
+        .into(),
+    )
+}
+
+/// Adds a `ProposalHook` variant to an execute message enum so that
+/// the contract may be added as a consumer of a proposal module's
+/// proposal hooks. The variant wraps
+/// `dao_proposal_hooks::ProposalHookMsg`, so it matches the messages
+/// proposal modules send.
+///
+/// For example:
+///
+/// ```
+/// use dao_macros::proposal_hook_consumer;
+/// use cosmwasm_schema::cw_serde;
+///
+/// #[proposal_hook_consumer]
+/// #[cw_serde]
+/// enum ExecuteMsg {}
+/// ```
+///
+/// Will transform the enum to:
+///
+/// ```
+/// # use dao_proposal_hooks::ProposalHookMsg;
+/// enum ExecuteMsg {
+///     ProposalHook(ProposalHookMsg),
+/// }
+/// ```
+///
+/// Which a contract may then handle with:
+///
+/// ```ignore
+/// match msg {
+///     ExecuteMsg::ProposalHook(ProposalHookMsg::NewProposal { id, proposer, .. }) => todo!(),
+///     ExecuteMsg::ProposalHook(ProposalHookMsg::ProposalStatusChanged {
+///         id,
+///         old_status,
+///         new_status,
+///     }) => todo!(),
+/// }
+/// ```
+///
+/// Note that other derive macro invocations must occur after this
+/// procedural macro as they may depend on the new fields. For
+/// example, the following will fail becase the `Clone` derivation
+/// occurs before the addition of the field.
+///
+/// ```compile_fail
+/// use dao_macros::proposal_hook_consumer;
+///
+/// #[derive(Clone)]
+/// #[proposal_hook_consumer]
+/// #[allow(dead_code)]
+/// enum Test {
+///     Foo,
+///     Bar(u64),
+///     Baz { foo: u64 },
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn proposal_hook_consumer(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    merge_variants(
+        metadata,
+        input,
+        quote! {
+        enum Right {
+            /// Called by a proposal module when a proposal is created
+            /// or its status changes.
+            ProposalHook(::dao_proposal_hooks::ProposalHookMsg),
+        }
+        }
+        .into(),
+    )
+}
+
+/// Adds a `VoteHook` variant to an execute message enum so that the
+/// contract may be added as a consumer of a proposal module's vote
+/// hooks. The variant wraps `dao_vote_hooks::VoteHookMsg`, so it
+/// matches the messages proposal modules send.
+///
+/// For example:
+///
+/// ```
+/// use dao_macros::vote_hook_consumer;
+/// use cosmwasm_schema::cw_serde;
+///
+/// #[vote_hook_consumer]
+/// #[cw_serde]
+/// enum ExecuteMsg {}
+/// ```
+///
+/// Will transform the enum to:
+///
+/// ```
+/// # use dao_vote_hooks::VoteHookMsg;
+/// enum ExecuteMsg {
+///     VoteHook(VoteHookMsg),
+/// }
+/// ```
+///
+/// Which a contract may then handle with:
+///
+/// ```ignore
+/// match msg {
+///     ExecuteMsg::VoteHook(VoteHookMsg::NewVote {
+///         proposal_id,
+///         voter,
+///         vote,
+///         ..
+///     }) => todo!(),
+/// }
+/// ```
+///
+/// Note that other derive macro invocations must occur after this
+/// procedural macro as they may depend on the new fields. For
+/// example, the following will fail becase the `Clone` derivation
+/// occurs before the addition of the field.
+///
+/// ```compile_fail
+/// use dao_macros::vote_hook_consumer;
+///
+/// #[derive(Clone)]
+/// #[vote_hook_consumer]
+/// #[allow(dead_code)]
+/// enum Test {
+///     Foo,
+///     Bar(u64),
+///     Baz { foo: u64 },
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn vote_hook_consumer(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    merge_variants(
+        metadata,
+        input,
+        quote! {
+        enum Right {
+            /// Called by a proposal module when a vote is cast.
+            VoteHook(::dao_vote_hooks::VoteHookMsg),
+        }
+        }
+        .into(),
+    )
+}
+
+/// Adds a `PauseInfo` query to a query message enum, returning a
+/// `dao_pausable::PauseInfoResponse`.
+///
+/// For example:
+///
+/// ```
+/// use dao_macros::pausable_query;
+/// use cosmwasm_schema::{cw_serde, QueryResponses};
+///
+/// #[pausable_query]
+/// #[cw_serde]
+/// #[derive(QueryResponses)]
+/// enum QueryMsg {}
+/// ```
+///
+/// Will transform the enum to:
+///
+/// ```
+/// enum QueryMsg {
+///     PauseInfo {},
+/// }
+/// ```
+///
+/// Note that other derive macro invocations must occur after this
+/// procedural macro as they may depend on the new fields. For
+/// example, the following will fail becase the `Clone` derivation
+/// occurs before the addition of the field.
+///
+/// ```compile_fail
+/// use dao_macros::pausable_query;
+///
+/// #[derive(Clone)]
+/// #[pausable_query]
+/// #[allow(dead_code)]
+/// enum Test {
+///     Foo,
+///     Bar(u64),
+///     Baz { foo: u64 },
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn pausable_query(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    merge_variants(
+        metadata,
+        input,
+        quote! {
+        enum Right {
+            /// Returns information about if the contract is currently
+            /// paused.
+            #[returns(::dao_pausable::PauseInfoResponse)]
+            PauseInfo {}
+        }
+        }
+        .into(),
+    )
+}
+
+/// Adds an `UpdateOwnership` variant to an execute message enum for
+/// transferring, accepting, and renouncing ownership of a
+/// contract. See the `dao-ownable` package for storage and checks to
+/// go along with this message.
+///
+/// For example:
+///
+/// ```
+/// use dao_macros::ownable_execute;
+/// use cosmwasm_schema::cw_serde;
+///
+/// #[ownable_execute]
+/// #[cw_serde]
+/// enum ExecuteMsg {}
+/// ```
+///
+/// Will transform the enum to:
+///
+/// ```
+/// enum ExecuteMsg {
+///     UpdateOwnership(dao_ownable::OwnershipAction),
+/// }
+/// ```
+///
+/// Note that other derive macro invocations must occur after this
+/// procedural macro as they may depend on the new fields. For
+/// example, the following will fail becase the `Clone` derivation
+/// occurs before the addition of the field.
+///
+/// ```compile_fail
+/// use dao_macros::ownable_execute;
+///
+/// #[derive(Clone)]
+/// #[ownable_execute]
+/// #[allow(dead_code)]
+/// enum Test {
+///     Foo,
+///     Bar(u64),
+///     Baz { foo: u64 },
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn ownable_execute(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    merge_variants(
+        metadata,
+        input,
+        quote! {
+        enum Right {
+            /// Updates the contract's ownership. See
+            /// `dao_ownable::OwnershipAction`.
+            UpdateOwnership(::dao_ownable::OwnershipAction)
+        }
+        }
+        .into(),
+    )
+}
+
+/// Adds an `Ownership` query to a query message enum, returning a
+/// `dao_ownable::Ownership`.
+///
+/// For example:
+///
+/// ```
+/// use dao_macros::ownable_query;
+/// use cosmwasm_schema::{cw_serde, QueryResponses};
+///
+/// #[ownable_query]
+/// #[cw_serde]
+/// #[derive(QueryResponses)]
+/// enum QueryMsg {}
+/// ```
+///
+/// Will transform the enum to:
+///
+/// ```
+/// enum QueryMsg {
+///     Ownership {},
+/// }
+/// ```
+///
+/// Note that other derive macro invocations must occur after this
+/// procedural macro as they may depend on the new fields. For
+/// example, the following will fail becase the `Clone` derivation
+/// occurs before the addition of the field.
+///
+/// ```compile_fail
+/// use dao_macros::ownable_query;
+///
+/// #[derive(Clone)]
+/// #[ownable_query]
+/// #[allow(dead_code)]
+/// enum Test {
+///     Foo,
+///     Bar(u64),
+///     Baz { foo: u64 },
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn ownable_query(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    merge_variants(
+        metadata,
+        input,
+        quote! {
+        enum Right {
+            /// Returns the contract's owner and pending owner.
+            #[returns(::dao_ownable::Ownership)]
+            Ownership {}
+        }
+        }
+        .into(),
+    )
+}
+
+/// Bounds on the number of variants an enum may have, parsed from the
+/// arguments to `limit_variant_count`.
+#[derive(Default)]
+struct VariantLimits {
+    min: Option<usize>,
+    max: Option<usize>,
+    /// If set, only unit variants (ex. `Foo`, but not `Foo {}`) are
+    /// counted.
+    unit_only: bool,
+}
+
+impl VariantLimits {
+    fn parse(args: AttributeArgs) -> syn::Result<Self> {
+        use syn::{Lit, Meta, MetaNameValue, NestedMeta};
+
+        const USAGE: &str = "expected an integer literal or `exact = N`, `min = N`, `max = N`, and `unit_only` arguments. ex: `#[limit_variant_count(min = 2, max = 8)]`";
+
+        let parse_int = |lit: &Lit| match lit {
+            Lit::Int(int) => int.base10_parse::<usize>(),
+            _ => Err(syn::Error::new_spanned(lit, "expected an integer literal")),
+        };
+
+        // The original form, `#[limit_variant_count(4)]`, sets a
+        // maximum.
+        if let [NestedMeta::Lit(lit)] = args.as_slice() {
+            return Ok(Self {
+                max: Some(parse_int(lit)?),
+                ..Default::default()
+            });
+        }
+        if args.is_empty() {
+            return Err(syn::Error::new(proc_macro2::Span::call_site(), USAGE));
+        }
+
+        let mut limits = Self::default();
+        let mut exact = false;
+        for arg in args.iter() {
+            let (set, value) = match arg {
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("unit_only") => {
+                    if limits.unit_only {
+                        return Err(syn::Error::new_spanned(arg, "duplicate argument"));
+                    }
+                    limits.unit_only = true;
+                    continue;
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue { path, lit, .. })) => {
+                    let value = parse_int(lit)?;
+                    if path.is_ident("exact") {
+                        exact = true;
+                        (vec![&mut limits.min, &mut limits.max], value)
+                    } else if path.is_ident("min") {
+                        (vec![&mut limits.min], value)
+                    } else if path.is_ident("max") {
+                        (vec![&mut limits.max], value)
+                    } else {
+                        return Err(syn::Error::new_spanned(path, USAGE));
+                    }
+                }
+                _ => return Err(syn::Error::new_spanned(arg, USAGE)),
+            };
+            for bound in set {
+                if bound.replace(value).is_some() {
+                    return Err(syn::Error::new_spanned(
+                        arg,
+                        if exact {
+                            "`exact` may not be combined with `min` or `max`"
+                        } else {
+                            "duplicate argument"
+                        },
+                    ));
+                }
+            }
+        }
+
+        if let (Some(min), Some(max)) = (limits.min, limits.max) {
+            if min > max {
+                return Err(syn::Error::new(
+                    proc_macro2::Span::call_site(),
+                    format!("min ({min}) may not be greater than max ({max})"),
+                ));
+            }
+        }
+        Ok(limits)
+    }
+
+    fn describe(&self) -> String {
+        let kind = if self.unit_only {
+            "unit variant"
+        } else {
+            "variant"
+        };
+        match (self.min, self.max) {
+            (Some(min), Some(max)) if min == max => {
+                format!("this enum's {kind} count must be exactly {min}")
+            }
+            (Some(min), Some(max)) => {
+                format!("this enum's {kind} count must be between {min} and {max}")
+            }
+            (Some(min), None) => format!("this enum's {kind} count must be at least {min}"),
+            (None, Some(max)) => format!("this enum's {kind} count is limited to {max}"),
+            (None, None) => unreachable!("at least one bound is required"),
+        }
+    }
+}
+
 /// Limits the number of variants allowed on an enum at compile
 /// time. For example, the following will not compile:
 ///
@@ -351,35 +841,79 @@ pub fn proposal_module_query(metadata: TokenStream, input: TokenStream) -> Token
 ///     Two {},
 /// }
 /// ```
+///
+/// Exact counts, minimums, and maximums may also be specified:
+///
+/// ```
+/// use dao_macros::limit_variant_count;
+///
+/// #[limit_variant_count(min = 2, max = 8)]
+/// enum Two {
+///     One {},
+///     Two {},
+/// }
+///
+/// #[limit_variant_count(exact = 2)]
+/// enum AlsoTwo {
+///     One {},
+///     Two {},
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use dao_macros::limit_variant_count;
+///
+/// #[limit_variant_count(min = 3)]
+/// enum Two {
+///     One {},
+///     Two {},
+/// }
+/// ```
+///
+/// With `unit_only`, only unit variants are counted. The following
+/// will not compile as it has two unit variants:
+///
+/// ```compile_fail
+/// use dao_macros::limit_variant_count;
+///
+/// #[limit_variant_count(max = 1, unit_only)]
+/// enum Three {
+///     One,
+///     Two,
+///     Three {},
+/// }
+/// ```
+///
+/// When an enum has too many variants, the error points at the first
+/// variant over the limit.
 #[proc_macro_attribute]
 pub fn limit_variant_count(metadata: TokenStream, input: TokenStream) -> TokenStream {
     let args = parse_macro_input!(metadata as AttributeArgs);
-    if args.len() != 1 {
-        panic!("macro takes one argument. ex: `#[limit_variant_count(4)]`")
-    }
-
-    let limit: usize = if let syn::NestedMeta::Lit(syn::Lit::Int(unparsed)) = args.first().unwrap()
-    {
-        match unparsed.base10_parse() {
-            Ok(limit) => limit,
-            Err(e) => return e.to_compile_error().into(),
-        }
-    } else {
-        return syn::Error::new_spanned(args[0].clone(), "argument should be an integer literal")
-            .to_compile_error()
-            .into();
+    let limits = match VariantLimits::parse(args) {
+        Ok(limits) => limits,
+        Err(e) => return e.to_compile_error().into(),
     };
 
     let ast: DeriveInput = parse_macro_input!(input);
     match ast.data {
         syn::Data::Enum(DataEnum { ref variants, .. }) => {
-            if variants.len() > limit {
-                return syn::Error::new_spanned(
-                    variants[limit].clone(),
-                    format!("this enum's variant count is limited to {limit}"),
-                )
-                .to_compile_error()
-                .into();
+            let counted: Vec<_> = variants
+                .iter()
+                .filter(|v| !limits.unit_only || matches!(v.fields, syn::Fields::Unit))
+                .collect();
+            if let Some(max) = limits.max {
+                if counted.len() > max {
+                    return syn::Error::new_spanned(counted[max], limits.describe())
+                        .to_compile_error()
+                        .into();
+                }
+            }
+            if let Some(min) = limits.min {
+                if counted.len() < min {
+                    return syn::Error::new(ast.ident.span(), limits.describe())
+                        .to_compile_error()
+                        .into();
+                }
             }
         }
         _ => {
@@ -397,3 +931,169 @@ pub fn limit_variant_count(metadata: TokenStream, input: TokenStream) -> TokenSt
     }
     .into()
 }
+
+// Converts a variant name like `ListProposals` into a method name
+// like `list_proposals`.
+fn to_snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut out = String::with_capacity(name.len() + 4);
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i != 0 {
+            let prev = chars[i - 1];
+            let next_is_lower = matches!(chars.get(i + 1), Some(n) if n.is_lowercase());
+            if prev.is_lowercase() || prev.is_numeric() || (prev.is_uppercase() && next_is_lower) {
+                out.push('_');
+            }
+        }
+        out.extend(c.to_lowercase());
+    }
+    out
+}
+
+/// Derives a typed query client for a query message enum. For an
+/// enum named `QueryMsg`, a struct named `QueryMsgQuerier` is
+/// generated with one method per variant annotated with
+/// `#[returns(..)]`. Each method takes the variant's fields as
+/// arguments and returns the annotated type. This is intended to
+/// replace hand-written `query_wasm_smart` wrappers in tests, where a
+/// querier may be constructed with `cw_multi_test::App::wrap`.
+///
+/// For example:
+///
+/// ```
+/// use cosmwasm_schema::{cw_serde, QueryResponses};
+/// use cosmwasm_std::testing::MockQuerier;
+/// use cosmwasm_std::{Addr, QuerierWrapper, StdResult};
+/// use dao_macros::TestQuerier;
+///
+/// #[cw_serde]
+/// #[derive(QueryResponses, TestQuerier)]
+/// enum QueryMsg {
+///     #[returns(Addr)]
+///     Dao {},
+///     #[returns(Vec<String>)]
+///     ListVoters { start_after: Option<String>, limit: Option<u32> },
+/// }
+///
+/// fn list_voters(querier: &QueryMsgQuerier) -> StdResult<Vec<String>> {
+///     querier.list_voters(None, Some(10))
+/// }
+///
+/// let mock: MockQuerier = MockQuerier::new(&[]);
+/// let querier = QueryMsgQuerier::new(QuerierWrapper::new(&mock), "contract");
+/// assert!(list_voters(&querier).is_err());
+/// ```
+///
+/// Variants without a `#[returns(..)]` annotation do not get a
+/// method. Note that this derive must occur after any procedural
+/// macros that add variants, for example `#[proposal_module_query]`,
+/// for methods to be generated for those variants.
+#[proc_macro_derive(TestQuerier, attributes(returns))]
+pub fn derive_test_querier(input: TokenStream) -> TokenStream {
+    let ast: DeriveInput = parse_macro_input!(input);
+    let variants = match &ast.data {
+        syn::Data::Enum(DataEnum { variants, .. }) => variants,
+        _ => {
+            return syn::Error::new(
+                ast.ident.span(),
+                "TestQuerier may only be derived for enums",
+            )
+            .to_compile_error()
+            .into()
+        }
+    };
+
+    let vis = &ast.vis;
+    let msg = &ast.ident;
+    let querier = quote::format_ident!("{}Querier", msg);
+    let is_generic = !ast.generics.params.is_empty();
+    let (_, msg_generics, _) = ast.generics.split_for_impl();
+    let msg_turbofish = msg_generics.as_turbofish();
+
+    let mut generics = ast.generics.clone();
+    generics.params.insert(0, syn::parse_quote!('__querier));
+    if is_generic {
+        generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse_quote!(#msg #msg_generics: ::cosmwasm_schema::serde::Serialize));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut methods = Vec::with_capacity(variants.len());
+    for variant in variants {
+        let returns = match variant.attrs.iter().find(|a| a.path.is_ident("returns")) {
+            Some(attr) => match attr.parse_args::<syn::Type>() {
+                Ok(ty) => ty,
+                Err(e) => return e.to_compile_error().into(),
+            },
+            None => continue,
+        };
+        let docs = variant.attrs.iter().filter(|a| a.path.is_ident("doc"));
+        let name = &variant.ident;
+        let method = quote::format_ident!("{}", to_snake_case(&name.to_string()));
+
+        let (params, construct) = match &variant.fields {
+            syn::Fields::Named(fields) => {
+                let idents: Vec<_> = fields.named.iter().map(|f| &f.ident).collect();
+                let types = fields.named.iter().map(|f| &f.ty);
+                (
+                    quote! { #(#idents: #types),* },
+                    quote! { #msg #msg_turbofish::#name { #(#idents),* } },
+                )
+            }
+            syn::Fields::Unnamed(fields) => {
+                let idents: Vec<_> = (0..fields.unnamed.len())
+                    .map(|i| quote::format_ident!("arg{}", i))
+                    .collect();
+                let types = fields.unnamed.iter().map(|f| &f.ty);
+                (
+                    quote! { #(#idents: #types),* },
+                    quote! { #msg #msg_turbofish::#name ( #(#idents),* ) },
+                )
+            }
+            syn::Fields::Unit => (quote! {}, quote! { #msg #msg_turbofish::#name }),
+        };
+        let bound = if is_generic {
+            quote! { where #returns: ::cosmwasm_schema::serde::de::DeserializeOwned }
+        } else {
+            quote! {}
+        };
+
+        methods.push(quote! {
+            #(#docs)*
+            pub fn #method(&self, #params) -> ::cosmwasm_std::StdResult<#returns> #bound {
+                self.querier.query_wasm_smart(&self.contract, &#construct)
+            }
+        });
+    }
+
+    let doc = format!("Typed query client for [`{msg}`]. Generated by `#[derive(TestQuerier)]`.");
+    quote! {
+        #[doc = #doc]
+        #[allow(dead_code)]
+        #vis struct #querier #generics #where_clause {
+            querier: ::cosmwasm_std::QuerierWrapper<'__querier>,
+            contract: ::std::string::String,
+            msg: ::std::marker::PhantomData<fn() -> #msg #msg_generics>,
+        }
+
+        #[allow(dead_code)]
+        impl #impl_generics #querier #ty_generics #where_clause {
+            /// Creates a new querier for the contract at `contract`.
+            pub fn new(
+                querier: ::cosmwasm_std::QuerierWrapper<'__querier>,
+                contract: impl ::std::convert::Into<::std::string::String>,
+            ) -> Self {
+                Self {
+                    querier,
+                    contract: contract.into(),
+                    msg: ::std::marker::PhantomData,
+                }
+            }
+
+            #(#methods)*
+        }
+    }
+    .into()
+}
@@ -0,0 +1,58 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{from_slice, to_vec, Uint128};
+use dao_proposal_hooks::{ProposalHookExecuteMsg, ProposalHookMsg};
+use dao_vote_hooks::{VoteHookExecuteMsg, VoteHookMsg, VoteTally};
+use dao_voting::voting::Votes;
+
+use dao_macros::{proposal_hook_consumer, vote_hook_consumer};
+
+#[vote_hook_consumer]
+#[proposal_hook_consumer]
+#[allow(dead_code)]
+#[cw_serde]
+enum Execute {
+    Foo,
+    Bar(u64),
+    Baz { waldo: u64 },
+}
+
+#[test]
+fn hook_consumer_derive() {
+    let test = Execute::Foo;
+
+    // If this compiles we have won.
+    match test {
+        Execute::Foo | Execute::Bar(_) | Execute::Baz { .. } => "yay",
+        Execute::ProposalHook(_) | Execute::VoteHook(_) => "yay",
+    };
+}
+
+#[test]
+fn hook_consumer_receives_hooks() {
+    // Consumers must be able to parse the messages proposal modules
+    // send.
+    let hook = ProposalHookMsg::ProposalStatusChanged {
+        id: 1,
+        old_status: "open".to_string(),
+        new_status: "passed".to_string(),
+    };
+    let sent = to_vec(&ProposalHookExecuteMsg::ProposalHook(hook.clone())).unwrap();
+    assert_eq!(
+        from_slice::<Execute>(&sent).unwrap(),
+        Execute::ProposalHook(hook)
+    );
+
+    let hook = VoteHookMsg::NewVote {
+        proposal_id: 1,
+        voter: "ekez".to_string(),
+        vote: "yes".to_string(),
+        power: Uint128::new(1),
+        height: 10,
+        tally: VoteTally::SingleChoice(Votes::with_yes(Uint128::new(1))),
+    };
+    let sent = to_vec(&VoteHookExecuteMsg::VoteHook(hook.clone())).unwrap();
+    assert_eq!(
+        from_slice::<Execute>(&sent).unwrap(),
+        Execute::VoteHook(hook)
+    );
+}
@@ -0,0 +1,43 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use dao_ownable::OwnershipAction;
+
+use dao_macros::{ownable_execute, ownable_query};
+
+#[ownable_execute]
+#[allow(dead_code)]
+#[cw_serde]
+enum Execute {
+    Foo,
+    Bar(u64),
+    Baz { waldo: u64 },
+}
+
+#[ownable_query]
+#[allow(dead_code)]
+#[cw_serde]
+#[derive(QueryResponses)]
+enum Query {
+    #[returns(String)]
+    Foo,
+}
+
+#[test]
+fn ownable_execute_derive() {
+    let test = Execute::UpdateOwnership(OwnershipAction::AcceptOwnership {});
+
+    // If this compiles we have won.
+    match test {
+        Execute::Foo | Execute::Bar(_) | Execute::Baz { .. } => "yay",
+        Execute::UpdateOwnership(_) => "yay",
+    };
+}
+
+#[test]
+fn ownable_query_derive() {
+    let test = Query::Ownership {};
+
+    // If this compiles we have won.
+    match test {
+        Query::Foo | Query::Ownership {} => "yay",
+    };
+}
@@ -0,0 +1,46 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cw_utils::Duration;
+
+use dao_macros::{pausable, pausable_query};
+
+#[pausable]
+#[allow(dead_code)]
+#[cw_serde]
+enum Execute {
+    Foo,
+    Bar(u64),
+    Baz { waldo: u64 },
+}
+
+#[pausable_query]
+#[allow(dead_code)]
+#[cw_serde]
+#[derive(QueryResponses)]
+enum Query {
+    #[returns(String)]
+    Foo,
+}
+
+#[test]
+fn pausable_derive() {
+    let test = Execute::Pause {
+        duration: Duration::Height(10),
+        reason: Some("under attack".to_string()),
+    };
+
+    // If this compiles we have won.
+    match test {
+        Execute::Foo | Execute::Bar(_) | Execute::Baz { .. } => "yay",
+        Execute::Pause { .. } | Execute::Unpause {} => "yay",
+    };
+}
+
+#[test]
+fn pausable_query_derive() {
+    let test = Query::PauseInfo {};
+
+    // If this compiles we have won.
+    match test {
+        Query::Foo | Query::PauseInfo {} => "yay",
+    };
+}
@@ -0,0 +1,88 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{
+    to_binary, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult,
+};
+use cw_multi_test::{App, ContractWrapper, Executor};
+
+use dao_macros::{proposal_module_query, TestQuerier};
+
+#[proposal_module_query]
+#[allow(dead_code)]
+#[cw_serde]
+#[derive(QueryResponses, TestQuerier)]
+enum Query {
+    #[returns(String)]
+    Echo { message: String },
+    #[returns(u64)]
+    Sum(u64, u64),
+    #[returns(Vec<u64>)]
+    ListNumbers {},
+}
+
+#[cw_serde]
+#[derive(QueryResponses, TestQuerier)]
+enum GenericQuery<T> {
+    #[returns(Option<String>)]
+    Extension { msg: T },
+}
+
+fn instantiate(_: DepsMut, _: Env, _: MessageInfo, _: Empty) -> StdResult<Response> {
+    Ok(Response::default())
+}
+
+fn execute(_: DepsMut, _: Env, _: MessageInfo, _: Empty) -> StdResult<Response> {
+    Ok(Response::default())
+}
+
+fn query(_: Deps, env: Env, msg: Query) -> StdResult<Binary> {
+    match msg {
+        Query::Echo { message } => to_binary(&message),
+        Query::Sum(a, b) => to_binary(&(a + b)),
+        Query::ListNumbers {} => to_binary(&vec![1u64, 2, 3]),
+        Query::Dao {} => to_binary(&env.contract.address),
+        Query::Info {} | Query::NextProposalId {} | Query::GenericProposalInfo { .. } => {
+            unimplemented!()
+        }
+    }
+}
+
+fn setup_app() -> (App, Addr) {
+    let mut app = App::default();
+    let code_id = app.store_code(Box::new(ContractWrapper::new(execute, instantiate, query)));
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("ekez"),
+            &Empty {},
+            &[],
+            "test",
+            None,
+        )
+        .unwrap();
+    (app, addr)
+}
+
+#[test]
+fn test_querier_derive() {
+    let (app, addr) = setup_app();
+    let querier = QueryQuerier::new(app.wrap(), &addr);
+
+    assert_eq!(querier.echo("hello".to_string()).unwrap(), "hello");
+    assert_eq!(querier.sum(1, 2).unwrap(), 3);
+    assert_eq!(querier.list_numbers().unwrap(), vec![1, 2, 3]);
+    // Variants added by other macros get methods as well.
+    assert_eq!(querier.dao().unwrap(), addr);
+}
+
+#[test]
+fn test_querier_generic_derive() {
+    let (app, addr) = setup_app();
+    let querier = GenericQueryQuerier::<Empty>::new(app.wrap(), "nobody");
+
+    // No contract exists at this address so the query fails.
+    querier.extension(Empty {}).unwrap_err();
+
+    let querier = GenericQueryQuerier::<Empty>::new(app.wrap(), addr);
+    // The contract does not understand the extension message.
+    querier.extension(Empty {}).unwrap_err();
+}
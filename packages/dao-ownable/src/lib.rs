@@ -0,0 +1,193 @@
+#![doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"))]
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, DepsMut, StdError, StdResult, Storage};
+use cw_storage_plus::Item;
+use thiserror::Error;
+
+/// The ownership of a contract. Returned by the `Ownership {}`
+/// query.
+#[cw_serde]
+#[derive(Default)]
+pub struct Ownership {
+    /// The contract's owner. None if ownership has been renounced.
+    pub owner: Option<Addr>,
+    /// The address nominated to become the owner, if any.
+    pub pending_owner: Option<Addr>,
+}
+
+/// Actions that may be taken with `UpdateOwnership`.
+#[cw_serde]
+pub enum OwnershipAction {
+    /// Callable by the owner. Nominates a new owner who must accept
+    /// the nomination to become the owner. Replaces any pending
+    /// nomination.
+    TransferOwnership { new_owner: String },
+    /// Callable by the pending owner. Makes the sender the owner.
+    AcceptOwnership {},
+    /// Callable by the owner. Removes the owner and any pending
+    /// owner. This can not be undone.
+    RenounceOwnership {},
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum OwnershipError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Caller is not the contract's owner")]
+    NotOwner {},
+
+    #[error("Caller is not the contract's pending owner")]
+    NotPendingOwner {},
+
+    #[error("Ownership has been renounced")]
+    NoOwner {},
+}
+
+pub struct Ownable<'a>(Item<'a, Ownership>);
+
+impl<'a> Ownable<'a> {
+    pub const fn new(storage_key: &'a str) -> Self {
+        Ownable(Item::new(storage_key))
+    }
+
+    /// Sets the initial owner. Usually called during instantiation.
+    pub fn initialize(&self, storage: &mut dyn Storage, owner: Option<Addr>) -> StdResult<()> {
+        self.0.save(
+            storage,
+            &Ownership {
+                owner,
+                pending_owner: None,
+            },
+        )
+    }
+
+    pub fn get_ownership(&self, storage: &dyn Storage) -> StdResult<Ownership> {
+        Ok(self.0.may_load(storage)?.unwrap_or_default())
+    }
+
+    /// Errors if `sender` is not the owner.
+    pub fn assert_owner(&self, storage: &dyn Storage, sender: &Addr) -> Result<(), OwnershipError> {
+        match self.get_ownership(storage)?.owner {
+            Some(owner) if owner == *sender => Ok(()),
+            Some(_) => Err(OwnershipError::NotOwner {}),
+            None => Err(OwnershipError::NoOwner {}),
+        }
+    }
+
+    /// Applies `action` on behalf of `sender`, returning the new
+    /// ownership.
+    pub fn update_ownership(
+        &self,
+        deps: DepsMut,
+        sender: &Addr,
+        action: OwnershipAction,
+    ) -> Result<Ownership, OwnershipError> {
+        let ownership = match action {
+            OwnershipAction::TransferOwnership { new_owner } => {
+                self.assert_owner(deps.storage, sender)?;
+                Ownership {
+                    owner: Some(sender.clone()),
+                    pending_owner: Some(deps.api.addr_validate(&new_owner)?),
+                }
+            }
+            OwnershipAction::AcceptOwnership {} => {
+                let ownership = self.get_ownership(deps.storage)?;
+                if ownership.pending_owner.as_ref() != Some(sender) {
+                    return Err(OwnershipError::NotPendingOwner {});
+                }
+                Ownership {
+                    owner: Some(sender.clone()),
+                    pending_owner: None,
+                }
+            }
+            OwnershipAction::RenounceOwnership {} => {
+                self.assert_owner(deps.storage, sender)?;
+                Ownership::default()
+            }
+        };
+        self.0.save(deps.storage, &ownership)?;
+        Ok(ownership)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_dependencies;
+
+    use super::*;
+
+    const OWNERSHIP: Ownable = Ownable::new("ownership");
+
+    #[test]
+    fn test_transfer_ownership() {
+        let mut deps = mock_dependencies();
+        let owner = Addr::unchecked("owner");
+        let new_owner = Addr::unchecked("new_owner");
+        OWNERSHIP
+            .initialize(deps.as_mut().storage, Some(owner.clone()))
+            .unwrap();
+
+        let transfer = OwnershipAction::TransferOwnership {
+            new_owner: new_owner.to_string(),
+        };
+        let err = OWNERSHIP
+            .update_ownership(deps.as_mut(), &new_owner, transfer.clone())
+            .unwrap_err();
+        assert_eq!(err, OwnershipError::NotOwner {});
+        OWNERSHIP
+            .update_ownership(deps.as_mut(), &owner, transfer)
+            .unwrap();
+        assert_eq!(
+            OWNERSHIP.get_ownership(deps.as_ref().storage).unwrap(),
+            Ownership {
+                owner: Some(owner.clone()),
+                pending_owner: Some(new_owner.clone()),
+            }
+        );
+
+        let err = OWNERSHIP
+            .update_ownership(deps.as_mut(), &owner, OwnershipAction::AcceptOwnership {})
+            .unwrap_err();
+        assert_eq!(err, OwnershipError::NotPendingOwner {});
+        OWNERSHIP
+            .update_ownership(
+                deps.as_mut(),
+                &new_owner,
+                OwnershipAction::AcceptOwnership {},
+            )
+            .unwrap();
+        OWNERSHIP
+            .assert_owner(deps.as_ref().storage, &new_owner)
+            .unwrap();
+        assert_eq!(
+            OWNERSHIP
+                .assert_owner(deps.as_ref().storage, &owner)
+                .unwrap_err(),
+            OwnershipError::NotOwner {}
+        );
+    }
+
+    #[test]
+    fn test_renounce_ownership() {
+        let mut deps = mock_dependencies();
+        let owner = Addr::unchecked("owner");
+        OWNERSHIP
+            .initialize(deps.as_mut().storage, Some(owner.clone()))
+            .unwrap();
+        OWNERSHIP
+            .update_ownership(deps.as_mut(), &owner, OwnershipAction::RenounceOwnership {})
+            .unwrap();
+        assert_eq!(
+            OWNERSHIP
+                .assert_owner(deps.as_ref().storage, &owner)
+                .unwrap_err(),
+            OwnershipError::NoOwner {}
+        );
+        assert_eq!(
+            OWNERSHIP.get_ownership(deps.as_ref().storage).unwrap(),
+            Ownership::default()
+        );
+    }
+}
@@ -0,0 +1,268 @@
+#![doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"))]
+
+use std::cmp::Ordering;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, BlockInfo, StdError, StdResult, Storage};
+use cw_storage_plus::Item;
+use cw_utils::{Duration, Expiration};
+use thiserror::Error;
+
+/// Information about if the contract is currently paused.
+#[cw_serde]
+pub enum PauseInfoResponse {
+    Paused {
+        expiration: Expiration,
+        /// The address which paused the contract. None for pauses
+        /// made before this was recorded.
+        paused_by: Option<Addr>,
+        /// Why the contract was paused, if a reason was given.
+        reason: Option<String>,
+        /// The duration the contract was paused for. None for pauses
+        /// made before this was recorded.
+        duration: Option<Duration>,
+    },
+    Unpaused {},
+}
+
+/// Information recorded when a contract is paused.
+#[cw_serde]
+pub struct PauseMetadata {
+    pub paused_by: Addr,
+    pub reason: Option<String>,
+    pub duration: Duration,
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum PauseError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Contract is paused until ({expiration})")]
+    Paused { expiration: Expiration },
+
+    #[error("Contract is not paused")]
+    NotPaused {},
+
+    #[error("Pauses may only be extended to after their current expiration ({expiration})")]
+    InvalidExtension { expiration: Expiration },
+}
+
+/// Stores when a paused contract will unpause, along with who paused
+/// it and why. A contract is unpaused once the stored expiration has
+/// expired.
+pub struct Pausable<'a> {
+    expiration: Item<'a, Expiration>,
+    metadata: Item<'a, PauseMetadata>,
+}
+
+impl<'a> Pausable<'a> {
+    pub const fn new(storage_key: &'a str, metadata_key: &'a str) -> Self {
+        Pausable {
+            expiration: Item::new(storage_key),
+            metadata: Item::new(metadata_key),
+        }
+    }
+
+    /// Pauses the contract for `duration`, returning when the
+    /// contract will unpause. Pausing an already paused contract
+    /// replaces the existing pause.
+    pub fn pause(
+        &self,
+        storage: &mut dyn Storage,
+        block: &BlockInfo,
+        duration: Duration,
+        paused_by: Addr,
+        reason: Option<String>,
+    ) -> StdResult<Expiration> {
+        let until = duration.after(block);
+        self.expiration.save(storage, &until)?;
+        self.metadata.save(
+            storage,
+            &PauseMetadata {
+                paused_by,
+                reason,
+                duration,
+            },
+        )?;
+        Ok(until)
+    }
+
+    /// Moves the expiration of the current pause to `expiration`,
+    /// keeping who paused the contract and why. Errors if the
+    /// contract is not paused or `expiration` is not after the
+    /// current expiration.
+    pub fn extend(
+        &self,
+        storage: &mut dyn Storage,
+        block: &BlockInfo,
+        expiration: Expiration,
+    ) -> Result<(), PauseError> {
+        match self.query_pause_info(storage, block)? {
+            PauseInfoResponse::Paused {
+                expiration: current,
+                ..
+            } => {
+                if expiration.partial_cmp(&current) != Some(Ordering::Greater) {
+                    return Err(PauseError::InvalidExtension {
+                        expiration: current,
+                    });
+                }
+                self.expiration.save(storage, &expiration)?;
+                Ok(())
+            }
+            PauseInfoResponse::Unpaused {} => Err(PauseError::NotPaused {}),
+        }
+    }
+
+    pub fn unpause(&self, storage: &mut dyn Storage) {
+        self.expiration.remove(storage);
+        self.metadata.remove(storage);
+    }
+
+    pub fn query_pause_info(
+        &self,
+        storage: &dyn Storage,
+        block: &BlockInfo,
+    ) -> StdResult<PauseInfoResponse> {
+        Ok(match self.expiration.may_load(storage)? {
+            Some(expiration) if !expiration.is_expired(block) => {
+                let metadata = self.metadata.may_load(storage)?;
+                PauseInfoResponse::Paused {
+                    expiration,
+                    paused_by: metadata.as_ref().map(|m| m.paused_by.clone()),
+                    reason: metadata.as_ref().and_then(|m| m.reason.clone()),
+                    duration: metadata.map(|m| m.duration),
+                }
+            }
+            _ => PauseInfoResponse::Unpaused {},
+        })
+    }
+
+    /// Errors if the contract is paused.
+    pub fn assert_not_paused(
+        &self,
+        storage: &dyn Storage,
+        block: &BlockInfo,
+    ) -> Result<(), PauseError> {
+        match self.query_pause_info(storage, block)? {
+            PauseInfoResponse::Paused { expiration, .. } => Err(PauseError::Paused { expiration }),
+            PauseInfoResponse::Unpaused {} => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+
+    use super::*;
+
+    const PAUSED: Pausable = Pausable::new("paused", "pause_metadata");
+
+    #[test]
+    fn test_pause_and_unpause() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+
+        assert_eq!(
+            PAUSED
+                .query_pause_info(deps.as_ref().storage, &env.block)
+                .unwrap(),
+            PauseInfoResponse::Unpaused {}
+        );
+        PAUSED
+            .assert_not_paused(deps.as_ref().storage, &env.block)
+            .unwrap();
+
+        let expiration = PAUSED
+            .pause(
+                deps.as_mut().storage,
+                &env.block,
+                Duration::Height(10),
+                Addr::unchecked("dao"),
+                Some("under attack".to_string()),
+            )
+            .unwrap();
+        assert_eq!(expiration, Expiration::AtHeight(env.block.height + 10));
+        assert_eq!(
+            PAUSED
+                .query_pause_info(deps.as_ref().storage, &env.block)
+                .unwrap(),
+            PauseInfoResponse::Paused {
+                expiration,
+                paused_by: Some(Addr::unchecked("dao")),
+                reason: Some("under attack".to_string()),
+                duration: Some(Duration::Height(10)),
+            }
+        );
+        assert_eq!(
+            PAUSED
+                .assert_not_paused(deps.as_ref().storage, &env.block)
+                .unwrap_err(),
+            PauseError::Paused { expiration }
+        );
+
+        // Pauses may only be extended to a later expiration with the
+        // same units.
+        for invalid in [
+            Expiration::AtHeight(env.block.height + 10),
+            Expiration::AtTime(env.block.time.plus_seconds(60)),
+        ] {
+            assert_eq!(
+                PAUSED
+                    .extend(deps.as_mut().storage, &env.block, invalid)
+                    .unwrap_err(),
+                PauseError::InvalidExtension { expiration }
+            );
+        }
+        PAUSED
+            .extend(
+                deps.as_mut().storage,
+                &env.block,
+                Expiration::AtHeight(env.block.height + 20),
+            )
+            .unwrap();
+        env.block.height += 10;
+        assert_eq!(
+            PAUSED
+                .query_pause_info(deps.as_ref().storage, &env.block)
+                .unwrap(),
+            PauseInfoResponse::Paused {
+                expiration: Expiration::AtHeight(env.block.height + 10),
+                paused_by: Some(Addr::unchecked("dao")),
+                reason: Some("under attack".to_string()),
+                duration: Some(Duration::Height(10)),
+            }
+        );
+
+        // Pauses expire.
+        env.block.height += 10;
+        assert_eq!(
+            PAUSED
+                .extend(deps.as_mut().storage, &env.block, Expiration::Never {})
+                .unwrap_err(),
+            PauseError::NotPaused {}
+        );
+        PAUSED
+            .assert_not_paused(deps.as_ref().storage, &env.block)
+            .unwrap();
+
+        PAUSED
+            .pause(
+                deps.as_mut().storage,
+                &env.block,
+                Duration::Time(60),
+                Addr::unchecked("dao"),
+                None,
+            )
+            .unwrap();
+        PAUSED.unpause(deps.as_mut().storage);
+        assert_eq!(
+            PAUSED
+                .query_pause_info(deps.as_ref().storage, &env.block)
+                .unwrap(),
+            PauseInfoResponse::Unpaused {}
+        );
+    }
+}
@@ -38,15 +38,42 @@ pub enum PreProposeError {
     #[error("Nothing to withdraw")]
     NothingToWithdraw {},
 
-    #[error("Proposal status ({status}) not closed or executed")]
+    #[error("Proposal status ({status}) not closed, executed, or cancelled")]
     NotClosedOrExecuted { status: Status },
 
     #[error("Proposal not found")]
     ProposalNotFound {},
 
+    #[error("Only the proposer of proposal ({proposal_id}) may update it")]
+    NotProposer { proposal_id: u64 },
+
+    #[error("Approved proposals may not be updated")]
+    ProposalApproved {},
+
+    #[error("A deposit already exists for proposal ({proposal_id})")]
+    DepositExists { proposal_id: u64 },
+
     #[error("Unauthorized")]
     Unauthorized {},
 
     #[error("An unknown reply ID was received.")]
     UnknownReplyID {},
+
+    #[error("Invalid cw721 collection for NFT deposits: {err}")]
+    InvalidNftCollection { err: StdError },
+
+    #[error("This module does not accept NFT deposits")]
+    NftDepositNotAccepted {},
+
+    #[error("The NFT does not match the required NFT deposit")]
+    WrongNft {},
+
+    #[error("An NFT deposit is already awaiting use. Return it before depositing another")]
+    NftDepositPending {},
+
+    #[error("An NFT must be deposited with `SendNft` before creating a proposal")]
+    NftDepositRequired {},
+
+    #[error("No NFT deposit to return")]
+    NoNftDeposit {},
 }
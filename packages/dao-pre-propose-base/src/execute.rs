@@ -1,27 +1,75 @@
-use cosmwasm_schema::schemars::JsonSchema;
+use cosmwasm_schema::{cw_serde, schemars::JsonSchema};
 use cosmwasm_std::{
-    to_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult, SubMsg, WasmMsg,
+    to_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Order, Response,
+    StdResult, Storage, SubMsg, WasmMsg,
 };
 
 use cw2::set_contract_version;
+use cw_storage_plus::Bound;
 
-use cw_denom::UncheckedDenom;
+use cw721::Cw721ReceiveMsg;
+use cw_denom::{CheckedDenom, UncheckedDenom};
+use dao_events::DaoEvent;
 use dao_interface::voting::{Query as CwCoreQuery, VotingPowerAtHeightResponse};
 use dao_voting::{
-    deposit::{DepositRefundPolicy, UncheckedDepositInfo},
+    deposit::{CheckedDepositInfo, DepositRefundPolicy, UncheckedDepositInfo},
     status::Status,
 };
 use serde::Serialize;
 
 use crate::{
     error::PreProposeError,
-    msg::{DepositInfoResponse, ExecuteMsg, InstantiateMsg, QueryMsg},
-    state::{Config, PreProposeContract},
+    msg::{
+        DepositInfoResponse, DepositTotalResponse, ExecuteMsg, ExportedDeposit, InstantiateMsg,
+        NftDepositResponse, QueryMsg, UncheckedNftDepositInfo,
+    },
+    state::{Config, NftDeposit, NftDepositInfo, PreProposeContract, ProposalNftDeposit},
 };
 
 const CONTRACT_NAME: &str = "crates.io::dao-pre-propose-base";
+
+const DEFAULT_EXPORT_LIMIT: u32 = 30;
+const MAX_EXPORT_LIMIT: u32 = 100;
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// The messages this module sends to its proposal module, other than
+/// the proposal creation message it is configured with.
+#[cw_serde]
+enum ProposalModuleExecuteMsg {
+    UpdateProposal {
+        proposal_id: u64,
+        title: String,
+        description: String,
+        msgs: Vec<CosmosMsg<Empty>>,
+    },
+}
+
+/// Starts building an event for ACTION. Pre-propose modules set their
+/// own contract version after instantiating this package, so the
+/// module emitting the event is read from storage.
+fn event(storage: &dyn Storage, action: &str) -> StdResult<DaoEvent> {
+    Ok(match cw2::CONTRACT.may_load(storage)? {
+        Some(version) => DaoEvent::new(version.contract, version.version, action),
+        None => DaoEvent::new(CONTRACT_NAME, CONTRACT_VERSION, action),
+    })
+}
+
+/// Whether a deposit with REFUND_POLICY is returned to the proposer
+/// of a proposal completed with NEW_STATUS. Otherwise, it goes to the
+/// DAO. Cancelled proposals are treated as closed ones.
+fn refunds_to_proposer(new_status: Status, refund_policy: &DepositRefundPolicy) -> bool {
+    (matches!(new_status, Status::Closed | Status::Cancelled)
+        && *refund_policy == DepositRefundPolicy::Always)
+        || (new_status == Status::Executed && *refund_policy != DepositRefundPolicy::Never)
+}
+
+fn denom_key(denom: &CheckedDenom) -> String {
+    match denom {
+        CheckedDenom::Native(denom) => format!("native:{denom}"),
+        CheckedDenom::Cw20(address) => format!("cw20:{address}"),
+    }
+}
+
 impl<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage>
     PreProposeContract<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage>
 where
@@ -54,10 +102,15 @@ where
             .deposit_info
             .map(|info| info.into_checked(deps.as_ref(), dao.clone()))
             .transpose()?;
+        let nft_deposit_info = msg
+            .nft_deposit_info
+            .map(|info| info.into_checked(deps.as_ref()))
+            .transpose()?;
 
         let config = Config {
             deposit_info,
             open_proposal_submission: msg.open_proposal_submission,
+            nft_deposit_info,
         };
 
         self.config.save(deps.storage, &config)?;
@@ -82,10 +135,23 @@ where
     ) -> Result<Response, PreProposeError> {
         match msg {
             ExecuteMsg::Propose { msg } => self.execute_propose(deps, env, info, msg),
+            ExecuteMsg::UpdateProposal {
+                proposal_id,
+                title,
+                description,
+                msgs,
+            } => self.execute_update_proposal(deps, info, proposal_id, title, description, msgs),
             ExecuteMsg::UpdateConfig {
                 deposit_info,
                 open_proposal_submission,
-            } => self.execute_update_config(deps, info, deposit_info, open_proposal_submission),
+                nft_deposit_info,
+            } => self.execute_update_config(
+                deps,
+                info,
+                deposit_info,
+                open_proposal_submission,
+                nft_deposit_info,
+            ),
             ExecuteMsg::Withdraw { denom } => {
                 self.execute_withdraw(deps.as_ref(), env, info, denom)
             }
@@ -98,7 +164,15 @@ where
             ExecuteMsg::ProposalCompletedHook {
                 proposal_id,
                 new_status,
-            } => self.execute_proposal_completed_hook(deps.as_ref(), info, proposal_id, new_status),
+            } => self.execute_proposal_completed_hook(deps, info, proposal_id, new_status),
+            ExecuteMsg::UpdateProposalModule { address } => {
+                self.execute_update_proposal_module(deps, info, address)
+            }
+            ExecuteMsg::ImportDeposits { deposits } => {
+                self.execute_import_deposits(deps, info, deposits)
+            }
+            ExecuteMsg::ReceiveNft(msg) => self.execute_receive_nft(deps, info, msg),
+            ExecuteMsg::ReturnNftDeposit {} => self.execute_return_nft_deposit(deps, info),
 
             ExecuteMsg::Extension { .. } => Ok(Response::default()),
         }
@@ -121,6 +195,8 @@ where
         } else {
             vec![]
         };
+        let nft_deposit =
+            self.take_nft_deposit(deps.storage, &config.nft_deposit_info, &info.sender)?;
 
         let proposal_module = self.proposal_module.load(deps.storage)?;
 
@@ -130,6 +206,12 @@ where
             &proposal_module,
             &dao_interface::proposal::Query::NextProposalId {},
         )?;
+        if let Some(ref deposit_info) = config.deposit_info {
+            self.add_deposit_totals(deps.storage, deposit_info)?;
+        }
+        if let Some(ref nft_deposit) = nft_deposit {
+            self.nft_deposits.save(deps.storage, next_id, nft_deposit)?;
+        }
         self.deposits.save(
             deps.storage,
             next_id,
@@ -153,9 +235,14 @@ where
                 Ok(SubMsg::new(execute))
             })?;
 
+        let event = event(deps.storage, "propose")?
+            .proposal_id(next_id)
+            .address("proposer", &info.sender);
+
         Ok(Response::default()
             .add_attribute("method", "execute_propose")
             .add_attribute("sender", info.sender)
+            .add_event(event.into())
             // It's important that the propose message is
             // first. Otherwise, a hook receiver could create a
             // proposal before us and invalidate our `NextProposalId
@@ -165,12 +252,56 @@ where
             .add_messages(deposit_messages))
     }
 
+    pub fn execute_update_proposal(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        proposal_id: u64,
+        title: String,
+        description: String,
+        msgs: Vec<CosmosMsg<Empty>>,
+    ) -> Result<Response, PreProposeError> {
+        // Proposers are recorded alongside deposits for every
+        // proposal created through this module.
+        let proposer = self
+            .deposits
+            .may_load(deps.storage, proposal_id)?
+            .map(|(_, proposer)| proposer);
+        if proposer.as_ref() != Some(&info.sender) {
+            return Err(PreProposeError::NotProposer { proposal_id });
+        }
+
+        let proposal_module = self.proposal_module.load(deps.storage)?;
+        let update_message = WasmMsg::Execute {
+            contract_addr: proposal_module.into_string(),
+            msg: to_binary(&ProposalModuleExecuteMsg::UpdateProposal {
+                proposal_id,
+                title,
+                description,
+                msgs,
+            })?,
+            funds: vec![],
+        };
+
+        let event = event(deps.storage, "update_proposal")?
+            .proposal_id(proposal_id)
+            .address("proposer", &info.sender);
+
+        Ok(Response::default()
+            .add_attribute("method", "execute_update_proposal")
+            .add_attribute("sender", info.sender)
+            .add_attribute("proposal_id", proposal_id.to_string())
+            .add_event(event.into())
+            .add_message(update_message))
+    }
+
     pub fn execute_update_config(
         &self,
         deps: DepsMut,
         info: MessageInfo,
         deposit_info: Option<UncheckedDepositInfo>,
         open_proposal_submission: bool,
+        nft_deposit_info: Option<UncheckedNftDepositInfo>,
     ) -> Result<Response, PreProposeError> {
         let dao = self.dao.load(deps.storage)?;
         if info.sender != dao {
@@ -179,17 +310,22 @@ where
             let deposit_info = deposit_info
                 .map(|d| d.into_checked(deps.as_ref(), dao))
                 .transpose()?;
+            let nft_deposit_info = nft_deposit_info
+                .map(|d| d.into_checked(deps.as_ref()))
+                .transpose()?;
             self.config.save(
                 deps.storage,
                 &Config {
                     deposit_info,
                     open_proposal_submission,
+                    nft_deposit_info,
                 },
             )?;
 
             Ok(Response::default()
                 .add_attribute("method", "update_config")
-                .add_attribute("sender", info.sender))
+                .add_attribute("sender", info.sender)
+                .add_event(event(deps.storage, "update_config")?.into()))
         }
     }
 
@@ -219,11 +355,16 @@ where
                         Err(PreProposeError::NothingToWithdraw {})
                     } else {
                         let withdraw_message = denom.get_transfer_to_message(&dao, balance)?;
+                        let event = event(deps.storage, "withdraw")?
+                            .address("receiver", &dao)
+                            .attribute("denom", denom.to_string())
+                            .attribute("amount", balance.to_string());
                         Ok(Response::default()
                             .add_message(withdraw_message)
                             .add_attribute("method", "withdraw")
                             .add_attribute("receiver", &dao)
-                            .add_attribute("denom", denom.to_string()))
+                            .add_attribute("denom", denom.to_string())
+                            .add_event(event.into()))
                     }
                 }
             }
@@ -268,15 +409,173 @@ where
         Ok(Response::default())
     }
 
+    pub fn execute_update_proposal_module(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        address: String,
+    ) -> Result<Response, PreProposeError> {
+        let dao = self.dao.load(deps.storage)?;
+        if info.sender != dao {
+            return Err(PreProposeError::NotDao {});
+        }
+
+        let address = deps.api.addr_validate(&address)?;
+        let previous = self.proposal_module.load(deps.storage)?;
+        self.previous_proposal_modules
+            .save(deps.storage, previous.clone(), &Empty {})?;
+        self.proposal_module.save(deps.storage, &address)?;
+
+        Ok(Response::default()
+            .add_attribute("method", "update_proposal_module")
+            .add_attribute("previous", previous)
+            .add_attribute("proposal_module", address))
+    }
+
+    pub fn execute_import_deposits(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        deposits: Vec<ExportedDeposit>,
+    ) -> Result<Response, PreProposeError> {
+        let dao = self.dao.load(deps.storage)?;
+        if info.sender != dao {
+            return Err(PreProposeError::NotDao {});
+        }
+
+        let count = deposits.len();
+        for ExportedDeposit {
+            proposal_id,
+            deposit_info,
+            proposer,
+        } in deposits
+        {
+            if self.deposits.has(deps.storage, proposal_id) {
+                return Err(PreProposeError::DepositExists { proposal_id });
+            }
+            let proposer = deps.api.addr_validate(proposer.as_str())?;
+            if let Some(ref deposit_info) = deposit_info {
+                self.add_deposit_totals(deps.storage, deposit_info)?;
+            }
+            self.deposits
+                .save(deps.storage, proposal_id, &(deposit_info, proposer))?;
+        }
+
+        Ok(Response::default()
+            .add_attribute("method", "import_deposits")
+            .add_attribute("count", count.to_string()))
+    }
+
+    pub fn execute_receive_nft(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        msg: Cw721ReceiveMsg,
+    ) -> Result<Response, PreProposeError> {
+        let nft_deposit_info = self
+            .config
+            .load(deps.storage)?
+            .nft_deposit_info
+            .ok_or(PreProposeError::NftDepositNotAccepted {})?;
+        // The collection is the sender of the receive message, so
+        // NFTs from other contracts may not pose as the deposit.
+        let deposit = NftDeposit {
+            collection: info.sender,
+            token_id: msg.token_id,
+        };
+        if !nft_deposit_info.accepts(&deposit) {
+            return Err(PreProposeError::WrongNft {});
+        }
+        let depositor = deps.api.addr_validate(&msg.sender)?;
+        if self
+            .pending_nft_deposits
+            .has(deps.storage, depositor.clone())
+        {
+            return Err(PreProposeError::NftDepositPending {});
+        }
+        self.pending_nft_deposits
+            .save(deps.storage, depositor.clone(), &deposit)?;
+
+        let event = event(deps.storage, "receive_nft")?
+            .address("depositor", &depositor)
+            .address("collection", &deposit.collection)
+            .attribute("token_id", deposit.token_id.clone());
+        Ok(Response::default()
+            .add_attribute("method", "receive_nft")
+            .add_attribute("depositor", depositor)
+            .add_attribute("collection", deposit.collection)
+            .add_attribute("token_id", deposit.token_id)
+            .add_event(event.into()))
+    }
+
+    pub fn execute_return_nft_deposit(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+    ) -> Result<Response, PreProposeError> {
+        let deposit = self
+            .pending_nft_deposits
+            .may_load(deps.storage, info.sender.clone())?
+            .ok_or(PreProposeError::NoNftDeposit {})?;
+        self.pending_nft_deposits
+            .remove(deps.storage, info.sender.clone());
+
+        let event = event(deps.storage, "return_nft_deposit")?
+            .address("depositor", &info.sender)
+            .attribute("token_id", deposit.token_id.clone());
+        Ok(Response::default()
+            .add_message(deposit.get_transfer_to_message(&info.sender)?)
+            .add_attribute("method", "return_nft_deposit")
+            .add_attribute("depositor", info.sender)
+            .add_attribute("token_id", deposit.token_id)
+            .add_event(event.into()))
+    }
+
+    /// Takes the NFT DEPOSITOR deposited for use as the deposit for a
+    /// new proposal, along with how the deposit is refunded. Call
+    /// this when a proposal is created. Returns `None` if no NFT
+    /// deposit is required.
+    pub fn take_nft_deposit(
+        &self,
+        storage: &mut dyn Storage,
+        nft_deposit_info: &Option<NftDepositInfo>,
+        depositor: &Addr,
+    ) -> Result<Option<ProposalNftDeposit>, PreProposeError> {
+        match nft_deposit_info {
+            Some(nft_deposit_info) => {
+                let deposit = self
+                    .pending_nft_deposits
+                    .may_load(storage, depositor.clone())?
+                    .ok_or(PreProposeError::NftDepositRequired {})?;
+                // The NFT deposit requirements may have changed since
+                // the NFT was deposited.
+                if !nft_deposit_info.accepts(&deposit) {
+                    return Err(PreProposeError::WrongNft {});
+                }
+                self.pending_nft_deposits.remove(storage, depositor.clone());
+                Ok(Some(ProposalNftDeposit {
+                    deposit,
+                    refund_policy: nft_deposit_info.refund_policy.clone(),
+                    refund_on_cancel: nft_deposit_info.refund_on_cancel,
+                }))
+            }
+            None => Ok(None),
+        }
+    }
+
     pub fn execute_proposal_completed_hook(
         &self,
-        deps: Deps,
+        deps: DepsMut,
         info: MessageInfo,
         id: u64,
         new_status: Status,
     ) -> Result<Response, PreProposeError> {
         let proposal_module = self.proposal_module.load(deps.storage)?;
-        if info.sender != proposal_module {
+        if info.sender != proposal_module
+            && !self
+                .previous_proposal_modules
+                .has(deps.storage, info.sender.clone())
+        {
             return Err(PreProposeError::NotModule {});
         }
 
@@ -285,36 +584,65 @@ where
         // bizare has happened. In that event, this message errors
         // which ought to cause the proposal module to remove this
         // module and open proposal submission to anyone.
-        if new_status != Status::Closed && new_status != Status::Executed {
+        if !matches!(
+            new_status,
+            Status::Closed | Status::Executed | Status::Cancelled
+        ) {
             return Err(PreProposeError::NotClosedOrExecuted { status: new_status });
         }
 
+        let event = event(deps.storage, "proposal_completed")?
+            .proposal_id(id)
+            .attribute("status", new_status.to_string());
+
         match self.deposits.may_load(deps.storage, id)? {
             Some((deposit_info, proposer)) => {
-                let messages = if let Some(ref deposit_info) = deposit_info {
+                let dao = self.dao.load(deps.storage)?;
+                let (mut messages, mut event) = if let Some(ref deposit_info) = deposit_info {
+                    self.remove_deposit_totals(deps.storage, deposit_info)?;
+
                     // Refund can be issued if proposal if it is going to
-                    // closed or executed.
-                    let should_refund_to_proposer = (new_status == Status::Closed
-                        && deposit_info.refund_policy == DepositRefundPolicy::Always)
-                        || (new_status == Status::Executed
-                            && deposit_info.refund_policy != DepositRefundPolicy::Never);
-
-                    if should_refund_to_proposer {
-                        deposit_info.get_return_deposit_message(&proposer)?
+                    // closed or executed. Whether cancelled proposals
+                    // are refunded is configured separately.
+                    let refund = if new_status == Status::Cancelled {
+                        deposit_info.refund_on_cancel
+                    } else {
+                        refunds_to_proposer(new_status, &deposit_info.refund_policy)
+                    };
+                    let recipient = if refund {
+                        &proposer
                     } else {
                         // If the proposer doesn't get the deposit, the DAO does.
-                        let dao = self.dao.load(deps.storage)?;
-                        deposit_info.get_return_deposit_message(&dao)?
-                    }
+                        &dao
+                    };
+                    (
+                        deposit_info.get_return_deposit_message(recipient)?,
+                        event.address("deposit_recipient", recipient),
+                    )
                 } else {
                     // No deposit info for this proposal. Nothing to do.
-                    vec![]
+                    (vec![], event)
                 };
 
+                if let Some(nft_deposit) = self.nft_deposits.may_load(deps.storage, id)? {
+                    self.nft_deposits.remove(deps.storage, id);
+                    // NFT deposits are refunded by the same rules as
+                    // other deposits.
+                    let refund = if new_status == Status::Cancelled {
+                        nft_deposit.refund_on_cancel
+                    } else {
+                        refunds_to_proposer(new_status, &nft_deposit.refund_policy)
+                    };
+                    let recipient = if refund { &proposer } else { &dao };
+                    messages.push(nft_deposit.deposit.get_transfer_to_message(recipient)?);
+                    event = event.address("nft_deposit_recipient", recipient);
+                }
+
                 Ok(Response::default()
                     .add_attribute("method", "execute_proposal_completed_hook")
                     .add_attribute("proposal", id.to_string())
                     .add_attribute("deposit_info", to_binary(&deposit_info)?.to_string())
+                    .add_event(event.into())
                     .add_messages(messages))
             }
 
@@ -324,10 +652,55 @@ where
             // do nothing.
             None => Ok(Response::default()
                 .add_attribute("method", "execute_proposal_completed_hook")
-                .add_attribute("proposal", id.to_string())),
+                .add_attribute("proposal", id.to_string())
+                .add_event(event.into())),
         }
     }
 
+    /// Adds the deposits in DEPOSIT_INFO to the totals held by this
+    /// module. Call this when deposits are taken.
+    pub fn add_deposit_totals(
+        &self,
+        storage: &mut dyn Storage,
+        deposit_info: &CheckedDepositInfo,
+    ) -> StdResult<()> {
+        for (denom, amount) in deposit_info.deposits() {
+            self.deposit_totals
+                .update(storage, denom_key(denom), |total| -> StdResult<_> {
+                    let held = total.map(|(_, held)| held).unwrap_or_default();
+                    Ok((denom.clone(), held.checked_add(amount)?))
+                })?;
+        }
+        Ok(())
+    }
+
+    /// Removes the deposits in DEPOSIT_INFO from the totals held by
+    /// this module. Call this when deposits are returned to their
+    /// proposer or sent to the DAO.
+    pub fn remove_deposit_totals(
+        &self,
+        storage: &mut dyn Storage,
+        deposit_info: &CheckedDepositInfo,
+    ) -> StdResult<()> {
+        for (denom, amount) in deposit_info.deposits() {
+            let key = denom_key(denom);
+            // Deposits paid before totals were tracked are not
+            // counted, so the total may be less than the deposit.
+            let held = self
+                .deposit_totals
+                .may_load(storage, key.clone())?
+                .map(|(_, held)| held.saturating_sub(amount))
+                .unwrap_or_default();
+            if held.is_zero() {
+                self.deposit_totals.remove(storage, key);
+            } else {
+                self.deposit_totals
+                    .save(storage, key, &(denom.clone(), held))?;
+            }
+        }
+        Ok(())
+    }
+
     pub fn check_can_submit(&self, deps: Deps, who: Addr) -> Result<(), PreProposeError> {
         let config = self.config.load(deps.storage)?;
 
@@ -347,7 +720,7 @@ where
         Ok(())
     }
 
-    pub fn query(&self, deps: Deps, _env: Env, msg: QueryMsg<QueryExt>) -> StdResult<Binary> {
+    pub fn query(&self, deps: Deps, env: Env, msg: QueryMsg<QueryExt>) -> StdResult<Binary> {
         match msg {
             QueryMsg::ProposalModule {} => to_binary(&self.proposal_module.load(deps.storage)?),
             QueryMsg::Dao {} => to_binary(&self.dao.load(deps.storage)?),
@@ -359,6 +732,62 @@ where
                     proposer,
                 })
             }
+            QueryMsg::DepositTotals {} => {
+                let totals = self
+                    .deposit_totals
+                    .range(deps.storage, None, None, Order::Ascending)
+                    .map(|item| {
+                        let (_, (denom, outstanding)) = item?;
+                        let balance = denom.query_balance(&deps.querier, &env.contract.address)?;
+                        Ok(DepositTotalResponse {
+                            shortfall: outstanding.saturating_sub(balance),
+                            denom,
+                            outstanding,
+                            balance,
+                        })
+                    })
+                    .collect::<StdResult<Vec<_>>>()?;
+                to_binary(&totals)
+            }
+            QueryMsg::ExportDeposits { start_after, limit } => {
+                let limit = limit.unwrap_or(DEFAULT_EXPORT_LIMIT).min(MAX_EXPORT_LIMIT) as usize;
+                let deposits = self
+                    .deposits
+                    .range(
+                        deps.storage,
+                        start_after.map(Bound::exclusive),
+                        None,
+                        Order::Ascending,
+                    )
+                    .take(limit)
+                    .map(|item| {
+                        let (proposal_id, (deposit_info, proposer)) = item?;
+                        Ok(ExportedDeposit {
+                            proposal_id,
+                            deposit_info,
+                            proposer,
+                        })
+                    })
+                    .collect::<StdResult<Vec<_>>>()?;
+                to_binary(&deposits)
+            }
+            QueryMsg::NftDeposit { proposal_id } => {
+                to_binary(&self.nft_deposits.may_load(deps.storage, proposal_id)?.map(
+                    |ProposalNftDeposit {
+                         deposit,
+                         refund_policy,
+                         refund_on_cancel,
+                     }| NftDepositResponse {
+                        deposit,
+                        refund_policy,
+                        refund_on_cancel,
+                    },
+                ))
+            }
+            QueryMsg::PendingNftDeposit { address } => {
+                let address = deps.api.addr_validate(&address)?;
+                to_binary(&self.pending_nft_deposits.may_load(deps.storage, address)?)
+            }
             QueryMsg::ProposalSubmittedHooks {} => {
                 to_binary(&self.proposal_submitted_hooks.query_hooks(deps)?)
             }
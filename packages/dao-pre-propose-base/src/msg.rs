@@ -1,10 +1,17 @@
 use cosmwasm_schema::{cw_serde, schemars::JsonSchema, QueryResponses};
-use cw_denom::UncheckedDenom;
+use cosmwasm_std::{CosmosMsg, Deps, Empty, Uint128};
+use cw721::Cw721ReceiveMsg;
+use cw_denom::{CheckedDenom, UncheckedDenom};
 use dao_voting::{
-    deposit::{CheckedDepositInfo, UncheckedDepositInfo},
+    deposit::{CheckedDepositInfo, DepositRefundPolicy, UncheckedDepositInfo},
     status::Status,
 };
 
+use crate::{
+    error::PreProposeError,
+    state::{NftDeposit, NftDepositInfo},
+};
+
 #[cw_serde]
 pub struct InstantiateMsg<InstantiateExt> {
     /// Information about the deposit requirements for this
@@ -14,17 +21,69 @@ pub struct InstantiateMsg<InstantiateExt> {
     /// proposals in the DAO. Otherwise, any address may create a
     /// proposal so long as they pay the deposit.
     pub open_proposal_submission: bool,
+    /// Information about the NFT deposit requirements for this
+    /// module. None if no NFT deposit.
+    #[serde(default)]
+    pub nft_deposit_info: Option<UncheckedNftDepositInfo>,
     /// Extension for instantiation. The default implementation will
     /// do nothing with this data.
     pub extension: InstantiateExt,
 }
 
+/// Information about the NFT which must be deposited to create a
+/// proposal.
+#[cw_serde]
+pub struct UncheckedNftDepositInfo {
+    /// The address of the cw721 collection the deposited NFT must
+    /// belong to.
+    pub collection: String,
+    /// The token which must be deposited. If `None`, any token from
+    /// the collection may be deposited.
+    pub token_id: Option<String>,
+    /// The policy used for refunding NFT deposits.
+    pub refund_policy: DepositRefundPolicy,
+    /// If true, NFT deposits are refunded when a proposal is
+    /// cancelled by its proposer. Otherwise, they are sent to the DAO.
+    #[serde(default)]
+    pub refund_on_cancel: bool,
+}
+
+impl UncheckedNftDepositInfo {
+    /// Converts NFT deposit info into checked NFT deposit info,
+    /// checking that the collection is a cw721 contract.
+    pub fn into_checked(self, deps: Deps) -> Result<NftDepositInfo, PreProposeError> {
+        let collection = deps.api.addr_validate(&self.collection)?;
+        let _info: cw721::ContractInfoResponse = deps
+            .querier
+            .query_wasm_smart(&collection, &cw721::Cw721QueryMsg::ContractInfo {})
+            .map_err(|err| PreProposeError::InvalidNftCollection { err })?;
+        Ok(NftDepositInfo {
+            collection,
+            token_id: self.token_id,
+            refund_policy: self.refund_policy,
+            refund_on_cancel: self.refund_on_cancel,
+        })
+    }
+}
+
 #[cw_serde]
 pub enum ExecuteMsg<ProposalMessage, ExecuteExt> {
     /// Creates a new proposal in the pre-propose module. MSG will be
     /// serialized and used as the proposal creation message.
     Propose { msg: ProposalMessage },
 
+    /// Revises an open proposal created through this module which
+    /// has not been voted on, by forwarding `UpdateProposal` to the
+    /// proposal module. Only the proposal's proposer may call this
+    /// method, and only proposal modules which support revising
+    /// proposals, such as `dao-proposal-single`, will accept it.
+    UpdateProposal {
+        proposal_id: u64,
+        title: String,
+        description: String,
+        msgs: Vec<CosmosMsg<Empty>>,
+    },
+
     /// Updates the configuration of this module. This will completely
     /// override the existing configuration. This new configuration
     /// will only apply to proposals created after the config is
@@ -32,6 +91,8 @@ pub enum ExecuteMsg<ProposalMessage, ExecuteExt> {
     UpdateConfig {
         deposit_info: Option<UncheckedDepositInfo>,
         open_proposal_submission: bool,
+        #[serde(default)]
+        nft_deposit_info: Option<UncheckedNftDepositInfo>,
     },
 
     /// Withdraws funds inside of this contract to the message
@@ -86,6 +147,32 @@ pub enum ExecuteMsg<ProposalMessage, ExecuteExt> {
         proposal_id: u64,
         new_status: Status,
     },
+
+    /// Associates this module with the proposal module at ADDRESS,
+    /// which is replacing its current proposal module. Completion
+    /// hooks are still accepted from the replaced proposal module so
+    /// that deposits for its proposals are returned. Only the DAO may
+    /// call this method.
+    UpdateProposalModule { address: String },
+
+    /// Imports deposits exported from another pre-propose module with
+    /// `ExportDeposits`, for example when re-instantiating a DAO on a
+    /// new chain. The deposited funds must be sent to this module
+    /// separately. Deposits may not be imported for proposals which
+    /// already have one. Only the DAO may call this method.
+    ImportDeposits { deposits: Vec<ExportedDeposit> },
+
+    /// Receives an NFT to be used as the deposit for the sender's
+    /// next proposal. This is sent by the NFT's collection when the
+    /// NFT is sent to this module with `SendNft`, and the NFT must
+    /// match the configured NFT deposit. The message sent along with
+    /// the NFT is ignored. An address may only have one NFT awaiting
+    /// use as a deposit at a time.
+    ReceiveNft(Cw721ReceiveMsg),
+
+    /// Returns the NFT the sender deposited which has not yet been
+    /// used to create a proposal.
+    ReturnNftDeposit {},
 }
 
 #[cw_serde]
@@ -109,6 +196,27 @@ where
     /// PROPOSAL_ID.
     #[returns(DepositInfoResponse)]
     DepositInfo { proposal_id: u64 },
+    /// Gets the sum of deposits held for proposals which have not
+    /// completed for each denom, along with the module's balance of
+    /// that denom. Returns `Vec<DepositTotalResponse>`.
+    #[returns(Vec<DepositTotalResponse>)]
+    DepositTotals {},
+    /// Lists the deposits held by this module in ascending order of
+    /// proposal ID. The result may be imported into another module
+    /// with `ImportDeposits`.
+    #[returns(Vec<ExportedDeposit>)]
+    ExportDeposits {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Gets the NFT deposited for the proposal identified by
+    /// PROPOSAL_ID, if any.
+    #[returns(Option<NftDepositResponse>)]
+    NftDeposit { proposal_id: u64 },
+    /// Gets the NFT ADDRESS deposited which has not yet been used to
+    /// create a proposal, if any.
+    #[returns(Option<crate::state::NftDeposit>)]
+    PendingNftDeposit { address: String },
     /// Returns list of proposal submitted hooks.
     #[returns(cw_hooks::HooksResponse)]
     ProposalSubmittedHooks {},
@@ -125,3 +233,37 @@ pub struct DepositInfoResponse {
     /// The address that created the proposal.
     pub proposer: cosmwasm_std::Addr,
 }
+
+#[cw_serde]
+pub struct NftDepositResponse {
+    /// The NFT deposited for the proposal.
+    pub deposit: NftDeposit,
+    /// The policy used for refunding the deposit.
+    pub refund_policy: DepositRefundPolicy,
+    /// Whether the deposit is refunded if the proposal is cancelled.
+    pub refund_on_cancel: bool,
+}
+
+/// A deposit exported by `ExportDeposits`.
+#[cw_serde]
+pub struct ExportedDeposit {
+    pub proposal_id: u64,
+    /// The deposit that has been paid for the proposal.
+    pub deposit_info: Option<CheckedDepositInfo>,
+    /// The address that created the proposal.
+    pub proposer: cosmwasm_std::Addr,
+}
+
+#[cw_serde]
+pub struct DepositTotalResponse {
+    pub denom: CheckedDenom,
+    /// The sum of deposits held for proposals which have not
+    /// completed.
+    pub outstanding: Uint128,
+    /// The module's balance of DENOM.
+    pub balance: Uint128,
+    /// The amount by which the module's balance falls short of the
+    /// outstanding deposits. If this is not zero, some deposits will
+    /// fail to be returned.
+    pub shortfall: Uint128,
+}
@@ -1,11 +1,12 @@
 use std::marker::PhantomData;
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Addr;
+use cosmwasm_std::{to_binary, Addr, CosmosMsg, Empty, StdResult, Uint128, WasmMsg};
 use cw_hooks::Hooks;
 use cw_storage_plus::{Item, Map};
 
-use dao_voting::deposit::CheckedDepositInfo;
+use cw_denom::CheckedDenom;
+use dao_voting::deposit::{CheckedDepositInfo, DepositRefundPolicy};
 
 #[cw_serde]
 pub struct Config {
@@ -16,11 +17,83 @@ pub struct Config {
     /// proposals in the DAO. Otherwise, any address may create a
     /// proposal so long as they pay the deposit.
     pub open_proposal_submission: bool,
+    /// Information about the NFT deposit required to create a
+    /// proposal. If `None`, no NFT deposit is required.
+    #[serde(default)]
+    pub nft_deposit_info: Option<NftDepositInfo>,
+}
+
+/// Counterpart to `UncheckedNftDepositInfo` which has been
+/// processed. Built by calling `into_checked` on an
+/// `UncheckedNftDepositInfo`.
+#[cw_serde]
+pub struct NftDepositInfo {
+    /// The cw721 collection the deposited NFT must belong to.
+    pub collection: Addr,
+    /// The token which must be deposited. If `None`, any token from
+    /// the collection may be deposited.
+    pub token_id: Option<String>,
+    /// The policy used for refunding NFT deposits.
+    pub refund_policy: DepositRefundPolicy,
+    /// If true, NFT deposits are refunded when a proposal is
+    /// cancelled by its proposer. Otherwise, they are sent to the DAO.
+    #[serde(default)]
+    pub refund_on_cancel: bool,
+}
+
+impl NftDepositInfo {
+    /// Whether DEPOSIT may be used to create a proposal.
+    pub fn accepts(&self, deposit: &NftDeposit) -> bool {
+        deposit.collection == self.collection
+            && self
+                .token_id
+                .as_ref()
+                .map_or(true, |token_id| *token_id == deposit.token_id)
+    }
+}
+
+/// An NFT deposited with this module.
+#[cw_serde]
+pub struct NftDeposit {
+    pub collection: Addr,
+    pub token_id: String,
+}
+
+impl NftDeposit {
+    /// Gets the message transferring this NFT to RECIPIENT.
+    pub fn get_transfer_to_message(&self, recipient: &Addr) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr: self.collection.to_string(),
+            msg: to_binary(&cw721::Cw721ExecuteMsg::TransferNft {
+                recipient: recipient.to_string(),
+                token_id: self.token_id.clone(),
+            })?,
+            funds: vec![],
+        }
+        .into())
+    }
+}
+
+/// An NFT deposited for a proposal, along with how it is refunded
+/// once the proposal completes.
+#[cw_serde]
+pub struct ProposalNftDeposit {
+    /// The NFT deposited for the proposal.
+    pub deposit: NftDeposit,
+    /// The refund policy in effect when the NFT was deposited.
+    pub refund_policy: DepositRefundPolicy,
+    /// Whether the NFT is refunded if the proposal is cancelled.
+    #[serde(default)]
+    pub refund_on_cancel: bool,
 }
 
 pub struct PreProposeContract<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage> {
     /// The proposal module that this module is associated with.
     pub proposal_module: Item<'static, Addr>,
+    /// Proposal modules this module was associated with before being
+    /// moved to a replacement. Completion hooks are still accepted
+    /// from these so deposits for their proposals may be returned.
+    pub previous_proposal_modules: Map<'static, Addr, Empty>,
     /// The DAO (cw-dao-core module) that this module is associated
     /// with.
     pub dao: Item<'static, Addr>,
@@ -28,8 +101,18 @@ pub struct PreProposeContract<InstantiateExt, ExecuteExt, QueryExt, ProposalMess
     pub config: Item<'static, Config>,
     /// Map between proposal IDs and (deposit, proposer) pairs.
     pub deposits: Map<'static, u64, (Option<CheckedDepositInfo>, Addr)>,
+    /// The sum of deposits held for proposals which have not
+    /// completed, keyed by denom. Deposits paid before these were
+    /// tracked are not counted.
+    pub deposit_totals: Map<'static, String, (CheckedDenom, Uint128)>,
     /// Consumers of proposal submitted hooks.
     pub proposal_submitted_hooks: Hooks<'static>,
+    /// NFTs which have been deposited but not yet used to create a
+    /// proposal, keyed by depositor.
+    pub pending_nft_deposits: Map<'static, Addr, NftDeposit>,
+    /// Map between proposal IDs and the NFT deposited for them, along
+    /// with the refund policy in effect when they were deposited.
+    pub nft_deposits: Map<'static, u64, ProposalNftDeposit>,
 
     // These types are used in associated functions, but not
     // assocaited data. To stop the compiler complaining about unused
@@ -48,14 +131,22 @@ impl<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage>
         dao_key: &'static str,
         config_key: &'static str,
         deposits_key: &'static str,
+        deposit_totals_key: &'static str,
         proposal_submitted_hooks_key: &'static str,
+        previous_proposal_modules_key: &'static str,
+        pending_nft_deposits_key: &'static str,
+        nft_deposits_key: &'static str,
     ) -> Self {
         Self {
             proposal_module: Item::new(proposal_key),
             dao: Item::new(dao_key),
             config: Item::new(config_key),
             deposits: Map::new(deposits_key),
+            deposit_totals: Map::new(deposit_totals_key),
             proposal_submitted_hooks: Hooks::new(proposal_submitted_hooks_key),
+            previous_proposal_modules: Map::new(previous_proposal_modules_key),
+            pending_nft_deposits: Map::new(pending_nft_deposits_key),
+            nft_deposits: Map::new(nft_deposits_key),
             execute_type: PhantomData,
             instantiate_type: PhantomData,
             query_type: PhantomData,
@@ -76,7 +167,11 @@ impl<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage> Default
             "dao",
             "config",
             "deposits",
+            "deposit_totals",
             "proposal_submitted_hooks",
+            "previous_proposal_modules",
+            "pending_nft_deposits",
+            "nft_deposits",
         )
     }
 }
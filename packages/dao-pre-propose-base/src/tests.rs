@@ -1,15 +1,20 @@
 use cosmwasm_std::{
-    from_binary,
+    coins, from_binary,
     testing::{mock_dependencies, mock_env, mock_info},
-    to_binary, Addr, Binary, ContractResult, Empty, Response, SubMsg, WasmMsg,
+    to_binary, Addr, Binary, ContractResult, Empty, Response, SubMsg, Uint128, WasmMsg,
 };
+use cw721::{Cw721ExecuteMsg, Cw721ReceiveMsg};
+use cw_denom::CheckedDenom;
 use cw_hooks::HooksResponse;
-use dao_voting::status::Status;
+use dao_voting::{
+    deposit::{CheckedDepositInfo, DepositRefundPolicy},
+    status::Status,
+};
 
 use crate::{
     error::PreProposeError,
-    msg::{ExecuteMsg, QueryMsg},
-    state::{Config, PreProposeContract},
+    msg::{DepositTotalResponse, ExecuteMsg, ExportedDeposit, NftDepositResponse, QueryMsg},
+    state::{Config, NftDeposit, NftDepositInfo, PreProposeContract},
 };
 
 type Contract = PreProposeContract<Empty, Empty, Empty, Empty>;
@@ -68,6 +73,158 @@ fn test_completed_hook_auth() {
     assert_eq!(res.unwrap_err(), PreProposeError::NotModule {});
 }
 
+#[test]
+fn test_update_proposal_module() {
+    let mut deps = mock_dependencies();
+    let module = Contract::default();
+
+    module
+        .dao
+        .save(&mut deps.storage, &Addr::unchecked("d"))
+        .unwrap();
+    module
+        .proposal_module
+        .save(&mut deps.storage, &Addr::unchecked("pm"))
+        .unwrap();
+
+    let update = ExecuteMsg::UpdateProposalModule {
+        address: "new".to_string(),
+    };
+    let err = module
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("pm", &[]),
+            update.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, PreProposeError::NotDao {});
+    module
+        .execute(deps.as_mut(), mock_env(), mock_info("d", &[]), update)
+        .unwrap();
+    assert_eq!(
+        module.proposal_module.load(&deps.storage).unwrap(),
+        Addr::unchecked("new")
+    );
+
+    // Both the new and the replaced proposal module may complete
+    // proposals.
+    for sender in ["pm", "new"] {
+        let res = module.execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(sender, &[]),
+            ExecuteMsg::ProposalCompletedHook {
+                proposal_id: 1,
+                new_status: Status::Passed,
+            },
+        );
+        assert_eq!(
+            res.unwrap_err(),
+            PreProposeError::NotClosedOrExecuted {
+                status: Status::Passed
+            }
+        );
+    }
+}
+
+#[test]
+fn test_import_deposits() {
+    let mut deps = mock_dependencies();
+    let module = Contract::default();
+
+    module
+        .dao
+        .save(&mut deps.storage, &Addr::unchecked("d"))
+        .unwrap();
+
+    let deposits = vec![
+        ExportedDeposit {
+            proposal_id: 1,
+            deposit_info: None,
+            proposer: Addr::unchecked("ekez"),
+        },
+        ExportedDeposit {
+            proposal_id: 3,
+            deposit_info: Some(CheckedDepositInfo {
+                denom: CheckedDenom::Native("ujuno".to_string()),
+                amount: Uint128::new(10),
+                refund_policy: DepositRefundPolicy::Always,
+                additional: vec![],
+                refund_on_cancel: false,
+            }),
+            proposer: Addr::unchecked("keze"),
+        },
+    ];
+    let import = ExecuteMsg::ImportDeposits {
+        deposits: deposits.clone(),
+    };
+
+    let err = module
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("ekez", &[]),
+            import.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, PreProposeError::NotDao {});
+
+    module
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("d", &[]),
+            import.clone(),
+        )
+        .unwrap();
+
+    let exported: Vec<ExportedDeposit> = from_binary(
+        &module
+            .query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::ExportDeposits {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(exported, deposits);
+
+    let exported: Vec<ExportedDeposit> = from_binary(
+        &module
+            .query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::ExportDeposits {
+                    start_after: Some(1),
+                    limit: Some(1),
+                },
+            )
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(exported, deposits[1..].to_vec());
+
+    // Imported deposits are counted as held by the module.
+    let totals: Vec<DepositTotalResponse> = from_binary(
+        &module
+            .query(deps.as_ref(), mock_env(), QueryMsg::DepositTotals {})
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(totals[0].outstanding, Uint128::new(10));
+
+    // Deposits may not be overwritten.
+    let err = module
+        .execute(deps.as_mut(), mock_env(), mock_info("d", &[]), import)
+        .unwrap_err();
+    assert_eq!(err, PreProposeError::DepositExists { proposal_id: 1 });
+}
+
 #[test]
 fn test_proposal_submitted_hooks() {
     let mut deps = mock_dependencies();
@@ -88,6 +245,7 @@ fn test_proposal_submitted_hooks() {
             &Config {
                 deposit_info: None,
                 open_proposal_submission: true,
+                nft_deposit_info: None,
             },
         )
         .unwrap();
@@ -166,6 +324,373 @@ fn test_proposal_submitted_hooks() {
     assert!(hooks.hooks.is_empty());
 }
 
+#[test]
+fn test_deposit_totals() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let module = Contract::default();
+
+    module
+        .dao
+        .save(&mut deps.storage, &Addr::unchecked("d"))
+        .unwrap();
+    module
+        .proposal_module
+        .save(&mut deps.storage, &Addr::unchecked("pm"))
+        .unwrap();
+    module
+        .config
+        .save(
+            &mut deps.storage,
+            &Config {
+                deposit_info: Some(CheckedDepositInfo {
+                    denom: CheckedDenom::Native("ujuno".to_string()),
+                    amount: Uint128::new(10),
+                    refund_policy: DepositRefundPolicy::Always,
+                    additional: vec![],
+                    refund_on_cancel: false,
+                }),
+                open_proposal_submission: true,
+                nft_deposit_info: None,
+            },
+        )
+        .unwrap();
+    deps.querier.update_wasm(|_| {
+        // for responding to the next proposal ID query that gets fired by propose.
+        cosmwasm_std::SystemResult::Ok(ContractResult::Ok(to_binary(&1u64).unwrap()))
+    });
+
+    module
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("a", &coins(10, "ujuno")),
+            ExecuteMsg::Propose {
+                msg: Empty::default(),
+            },
+        )
+        .unwrap();
+    deps.querier
+        .update_balance(env.contract.address.clone(), coins(10, "ujuno"));
+
+    let query_totals = |deps: cosmwasm_std::Deps| -> Vec<DepositTotalResponse> {
+        from_binary(
+            &module
+                .query(deps, mock_env(), QueryMsg::DepositTotals {})
+                .unwrap(),
+        )
+        .unwrap()
+    };
+    assert_eq!(
+        query_totals(deps.as_ref()),
+        vec![DepositTotalResponse {
+            denom: CheckedDenom::Native("ujuno".to_string()),
+            outstanding: Uint128::new(10),
+            balance: Uint128::new(10),
+            shortfall: Uint128::zero(),
+        }]
+    );
+
+    // Funds leaving the module, for example via `Withdraw`, leave
+    // refunds underfunded.
+    deps.querier
+        .update_balance(env.contract.address.clone(), coins(4, "ujuno"));
+    assert_eq!(query_totals(deps.as_ref())[0].shortfall, Uint128::new(6));
+
+    // Completed proposals no longer have deposits outstanding.
+    module
+        .execute(
+            deps.as_mut(),
+            env,
+            mock_info("pm", &[]),
+            ExecuteMsg::ProposalCompletedHook {
+                proposal_id: 1,
+                new_status: Status::Executed,
+            },
+        )
+        .unwrap();
+    assert!(query_totals(deps.as_ref()).is_empty());
+}
+
+#[test]
+fn test_nft_deposit() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let module = Contract::default();
+
+    module
+        .dao
+        .save(&mut deps.storage, &Addr::unchecked("d"))
+        .unwrap();
+    module
+        .proposal_module
+        .save(&mut deps.storage, &Addr::unchecked("pm"))
+        .unwrap();
+    module
+        .config
+        .save(
+            &mut deps.storage,
+            &Config {
+                deposit_info: None,
+                open_proposal_submission: true,
+                nft_deposit_info: Some(NftDepositInfo {
+                    collection: Addr::unchecked("nft"),
+                    token_id: None,
+                    refund_policy: DepositRefundPolicy::OnlyPassed,
+                    refund_on_cancel: false,
+                }),
+            },
+        )
+        .unwrap();
+    deps.querier.update_wasm(|_| {
+        // for responding to the next proposal ID query that gets fired by propose.
+        cosmwasm_std::SystemResult::Ok(ContractResult::Ok(to_binary(&1u64).unwrap()))
+    });
+
+    let receive = |sender: &str, token_id: &str| {
+        ExecuteMsg::ReceiveNft(Cw721ReceiveMsg {
+            sender: sender.to_string(),
+            token_id: token_id.to_string(),
+            msg: Binary::default(),
+        })
+    };
+    let propose = ExecuteMsg::Propose {
+        msg: Empty::default(),
+    };
+
+    // Proposals may not be created without a deposit.
+    let err = module
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("a", &[]),
+            propose.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, PreProposeError::NftDepositRequired {});
+
+    // NFTs from other collections are not accepted.
+    let err = module
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("other", &[]),
+            receive("a", "1"),
+        )
+        .unwrap_err();
+    assert_eq!(err, PreProposeError::WrongNft {});
+
+    module
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("nft", &[]),
+            receive("a", "1"),
+        )
+        .unwrap();
+    let err = module
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("nft", &[]),
+            receive("a", "2"),
+        )
+        .unwrap_err();
+    assert_eq!(err, PreProposeError::NftDepositPending {});
+
+    let deposit = NftDeposit {
+        collection: Addr::unchecked("nft"),
+        token_id: "1".to_string(),
+    };
+    let pending: Option<NftDeposit> = from_binary(
+        &module
+            .query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::PendingNftDeposit {
+                    address: "a".to_string(),
+                },
+            )
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(pending, Some(deposit.clone()));
+
+    // Proposing takes the pending deposit.
+    module
+        .execute(deps.as_mut(), env.clone(), mock_info("a", &[]), propose)
+        .unwrap();
+    let nft_deposit: Option<NftDepositResponse> = from_binary(
+        &module
+            .query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::NftDeposit { proposal_id: 1 },
+            )
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        nft_deposit,
+        Some(NftDepositResponse {
+            deposit,
+            refund_policy: DepositRefundPolicy::OnlyPassed,
+            refund_on_cancel: false,
+        })
+    );
+    let err = module
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("a", &[]),
+            ExecuteMsg::ReturnNftDeposit {},
+        )
+        .unwrap_err();
+    assert_eq!(err, PreProposeError::NoNftDeposit {});
+
+    // The proposal was closed, so the deposit goes to the DAO.
+    let res = module
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("pm", &[]),
+            ExecuteMsg::ProposalCompletedHook {
+                proposal_id: 1,
+                new_status: Status::Closed,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(WasmMsg::Execute {
+            contract_addr: "nft".to_string(),
+            msg: to_binary(&Cw721ExecuteMsg::TransferNft {
+                recipient: "d".to_string(),
+                token_id: "1".to_string(),
+            })
+            .unwrap(),
+            funds: vec![],
+        })]
+    );
+
+    // Pending deposits may be returned.
+    module
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("nft", &[]),
+            receive("a", "2"),
+        )
+        .unwrap();
+    let res = module
+        .execute(
+            deps.as_mut(),
+            env,
+            mock_info("a", &[]),
+            ExecuteMsg::ReturnNftDeposit {},
+        )
+        .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(WasmMsg::Execute {
+            contract_addr: "nft".to_string(),
+            msg: to_binary(&Cw721ExecuteMsg::TransferNft {
+                recipient: "a".to_string(),
+                token_id: "2".to_string(),
+            })
+            .unwrap(),
+            funds: vec![],
+        })]
+    );
+}
+
+#[test]
+fn test_nft_deposit_refund_on_cancel() {
+    for refund_on_cancel in [false, true] {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let module = Contract::default();
+
+        module
+            .dao
+            .save(&mut deps.storage, &Addr::unchecked("d"))
+            .unwrap();
+        module
+            .proposal_module
+            .save(&mut deps.storage, &Addr::unchecked("pm"))
+            .unwrap();
+        module
+            .config
+            .save(
+                &mut deps.storage,
+                &Config {
+                    deposit_info: None,
+                    open_proposal_submission: true,
+                    nft_deposit_info: Some(NftDepositInfo {
+                        collection: Addr::unchecked("nft"),
+                        token_id: None,
+                        refund_policy: DepositRefundPolicy::Always,
+                        refund_on_cancel,
+                    }),
+                },
+            )
+            .unwrap();
+        deps.querier.update_wasm(|_| {
+            cosmwasm_std::SystemResult::Ok(ContractResult::Ok(to_binary(&1u64).unwrap()))
+        });
+
+        module
+            .execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_info("nft", &[]),
+                ExecuteMsg::ReceiveNft(Cw721ReceiveMsg {
+                    sender: "a".to_string(),
+                    token_id: "1".to_string(),
+                    msg: Binary::default(),
+                }),
+            )
+            .unwrap();
+        module
+            .execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_info("a", &[]),
+                ExecuteMsg::Propose {
+                    msg: Empty::default(),
+                },
+            )
+            .unwrap();
+
+        // Cancelled proposals refund their NFT deposit only if
+        // `refund_on_cancel` is set, regardless of the refund policy.
+        let res = module
+            .execute(
+                deps.as_mut(),
+                env,
+                mock_info("pm", &[]),
+                ExecuteMsg::ProposalCompletedHook {
+                    proposal_id: 1,
+                    new_status: Status::Cancelled,
+                },
+            )
+            .unwrap();
+        let recipient = if refund_on_cancel { "a" } else { "d" };
+        assert_eq!(
+            res.messages,
+            vec![SubMsg::new(WasmMsg::Execute {
+                contract_addr: "nft".to_string(),
+                msg: to_binary(&Cw721ExecuteMsg::TransferNft {
+                    recipient: recipient.to_string(),
+                    token_id: "1".to_string(),
+                })
+                .unwrap(),
+                funds: vec![],
+            })]
+        );
+    }
+}
+
 #[test]
 fn test_query_ext_does_nothing() {
     let deps = mock_dependencies();
@@ -1,8 +1,9 @@
 #![doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"))]
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{to_binary, StdResult, Storage, SubMsg, WasmMsg};
+use cosmwasm_std::{to_binary, Addr, StdResult, Storage, SubMsg, WasmMsg};
 use cw_hooks::Hooks;
+use cw_utils::Expiration;
 use dao_voting::reply::mask_proposal_hook_index;
 
 #[cw_serde]
@@ -10,12 +11,34 @@ pub enum ProposalHookMsg {
     NewProposal {
         id: u64,
         proposer: String,
+        /// The content of the new proposal. Only sent to consumers
+        /// that asked for it when they were added, so that existing
+        /// consumers continue to receive the same message.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        content: Option<ProposalContent>,
     },
     ProposalStatusChanged {
         id: u64,
         old_status: String,
         new_status: String,
     },
+    /// Sent when a proposal's proposer revises it before it receives
+    /// any votes. Only sent to consumers that asked for proposal
+    /// content when they were added.
+    ProposalUpdated { id: u64, content: ProposalContent },
+}
+
+/// The content of a new proposal, as included in `NewProposal` hook
+/// messages.
+#[cw_serde]
+pub struct ProposalContent {
+    pub title: String,
+    pub description: String,
+    pub expiration: Expiration,
+    /// The IPFS CID of the proposal's full content, if the proposal
+    /// module supports and was given one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_cid: Option<String>,
 }
 
 // This is just a helper to properly serialize the above message
@@ -27,24 +50,42 @@ pub enum ProposalHookExecuteMsg {
 /// Prepares new proposal hook messages. These messages reply on error
 /// and have even reply IDs.
 /// IDs are set to even numbers to then be interleaved with the vote hooks.
+///
+/// Hooks for which `include_content` returns true are sent the
+/// proposal's `content` along with its ID and proposer.
 pub fn new_proposal_hooks(
     hooks: Hooks,
     storage: &dyn Storage,
     id: u64,
     proposer: &str,
+    content: ProposalContent,
+    include_content: impl Fn(&Addr) -> bool,
 ) -> StdResult<Vec<SubMsg>> {
     let msg = to_binary(&ProposalHookExecuteMsg::ProposalHook(
         ProposalHookMsg::NewProposal {
             id,
             proposer: proposer.to_string(),
+            content: None,
+        },
+    ))?;
+    let msg_with_content = to_binary(&ProposalHookExecuteMsg::ProposalHook(
+        ProposalHookMsg::NewProposal {
+            id,
+            proposer: proposer.to_string(),
+            content: Some(content),
         },
     ))?;
 
     let mut index: u64 = 0;
     let messages = hooks.prepare_hooks(storage, |a| {
+        let msg = if include_content(&a) {
+            msg_with_content.clone()
+        } else {
+            msg.clone()
+        };
         let execute = WasmMsg::Execute {
             contract_addr: a.to_string(),
-            msg: msg.clone(),
+            msg,
             funds: vec![],
         };
         let masked_index = mask_proposal_hook_index(index);
@@ -92,3 +133,151 @@ pub fn proposal_status_changed_hooks(
 
     Ok(messages)
 }
+
+/// Prepares proposal updated hook messages. These messages reply on
+/// error and have even reply IDs.
+///
+/// Only hooks for which `include_content` returns true are sent the
+/// message. Reply IDs are those of the hooks' positions among all
+/// hooks so that failures are attributed to the correct hook.
+pub fn proposal_updated_hooks(
+    hooks: Hooks,
+    storage: &dyn Storage,
+    id: u64,
+    content: ProposalContent,
+    include_content: impl Fn(&Addr) -> bool,
+) -> StdResult<Vec<SubMsg>> {
+    let msg = to_binary(&ProposalHookExecuteMsg::ProposalHook(
+        ProposalHookMsg::ProposalUpdated { id, content },
+    ))?;
+
+    let mut index: u64 = 0;
+    let mut included = vec![];
+    let messages = hooks.prepare_hooks(storage, |a| {
+        included.push(include_content(&a));
+        let execute = WasmMsg::Execute {
+            contract_addr: a.to_string(),
+            msg: msg.clone(),
+            funds: vec![],
+        };
+        let masked_index = mask_proposal_hook_index(index);
+        let tmp = SubMsg::reply_on_error(execute, masked_index);
+        index += 1;
+        Ok(tmp)
+    })?;
+
+    Ok(messages
+        .into_iter()
+        .zip(included)
+        .filter_map(|(message, included)| included.then_some(message))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{from_binary, testing::MockStorage, Binary, CosmosMsg};
+
+    use super::*;
+
+    #[test]
+    fn test_new_proposal_hooks_content() {
+        let mut storage = MockStorage::new();
+        let hooks = Hooks::new("hooks");
+        hooks
+            .add_hook(&mut storage, Addr::unchecked("plain"))
+            .unwrap();
+        hooks
+            .add_hook(&mut storage, Addr::unchecked("content"))
+            .unwrap();
+
+        let content = ProposalContent {
+            title: "title".to_string(),
+            description: "description".to_string(),
+            expiration: Expiration::AtHeight(10),
+            content_cid: None,
+        };
+        let messages = new_proposal_hooks(hooks, &storage, 1, "ekez", content.clone(), |a| {
+            a.as_str() == "content"
+        })
+        .unwrap();
+
+        let sent: Vec<ProposalHookExecuteMsg> = messages
+            .into_iter()
+            .map(|m| match m.msg {
+                CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => from_binary(&msg).unwrap(),
+                _ => panic!("expected a wasm execute message"),
+            })
+            .collect();
+        assert_eq!(
+            sent,
+            vec![
+                ProposalHookExecuteMsg::ProposalHook(ProposalHookMsg::NewProposal {
+                    id: 1,
+                    proposer: "ekez".to_string(),
+                    content: None,
+                }),
+                ProposalHookExecuteMsg::ProposalHook(ProposalHookMsg::NewProposal {
+                    id: 1,
+                    proposer: "ekez".to_string(),
+                    content: Some(content),
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_proposal_updated_hooks_content_only() {
+        let mut storage = MockStorage::new();
+        let hooks = Hooks::new("hooks");
+        hooks
+            .add_hook(&mut storage, Addr::unchecked("plain"))
+            .unwrap();
+        hooks
+            .add_hook(&mut storage, Addr::unchecked("content"))
+            .unwrap();
+
+        let content = ProposalContent {
+            title: "title".to_string(),
+            description: "description".to_string(),
+            expiration: Expiration::AtHeight(10),
+            content_cid: None,
+        };
+        let messages = proposal_updated_hooks(hooks, &storage, 1, content.clone(), |a| {
+            a.as_str() == "content"
+        })
+        .unwrap();
+
+        // The remaining hook keeps the reply ID of its position.
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].id, mask_proposal_hook_index(1));
+        match &messages[0].msg {
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr, msg, ..
+            }) => {
+                assert_eq!(contract_addr, "content");
+                assert_eq!(
+                    from_binary::<ProposalHookExecuteMsg>(msg).unwrap(),
+                    ProposalHookExecuteMsg::ProposalHook(ProposalHookMsg::ProposalUpdated {
+                        id: 1,
+                        content,
+                    })
+                );
+            }
+            _ => panic!("expected a wasm execute message"),
+        }
+    }
+
+    #[test]
+    fn test_new_proposal_hook_without_content_is_unchanged() {
+        let msg = to_binary(&ProposalHookMsg::NewProposal {
+            id: 1,
+            proposer: "ekez".to_string(),
+            content: None,
+        })
+        .unwrap();
+        assert_eq!(
+            msg,
+            Binary::from(br#"{"new_proposal":{"id":1,"proposer":"ekez"}}"#)
+        );
+    }
+}
@@ -0,0 +1,21 @@
+#![doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"))]
+
+#[doc(hidden)]
+pub use cosmwasm_schema;
+
+/// Writes the schema of the contract being compiled to its `schema`
+/// directory. Takes the same input as `cosmwasm_schema::write_api!`.
+/// The calling crate must depend on cosmwasm-schema.
+#[macro_export]
+macro_rules! write_schema {
+    ($($input:tt)*) => {{
+        let contract_dir = ::std::path::Path::new(env!("CARGO_MANIFEST_DIR"));
+        let out_dir = contract_dir.join("schema");
+        ::std::fs::create_dir_all(&out_dir).unwrap();
+        $crate::cosmwasm_schema::remove_schemas(&out_dir).unwrap();
+
+        // `write_api!` writes relative to the current directory.
+        ::std::env::set_current_dir(contract_dir).unwrap();
+        $crate::cosmwasm_schema::write_api! { $($input)* }
+    }};
+}
@@ -0,0 +1,358 @@
+use cosmwasm_std::{to_binary, Addr, Empty, Uint128};
+use cw20::Cw20Coin;
+use cw_multi_test::{App, Executor};
+use cw_utils::Duration;
+use dao_interface::{querier::CoreQuerier, Admin, ModuleInstantiateInfo};
+use dao_voting::{
+    deposit::UncheckedDepositInfo,
+    pre_propose::{PreProposeInfo, ProposalCreationPolicy},
+    threshold::Threshold,
+};
+
+use crate::contracts::{
+    cw20_base_contract, cw20_stake_contract, cw20_staked_balances_voting_contract,
+    cw4_group_contract, dao_core_contract, dao_voting_cw4_contract, pre_propose_single_contract,
+    proposal_single_contract,
+};
+
+const CREATOR_ADDR: &str = "creator";
+
+enum Voting {
+    Cw4 { members: Vec<cw4::Member> },
+    Cw20Staked { initial_balances: Vec<Cw20Coin> },
+}
+
+struct PreProposeSingle {
+    deposit_info: Option<UncheckedDepositInfo>,
+    open_proposal_submission: bool,
+}
+
+/// Assembles a DAO with a voting module, a single choice proposal
+/// module, and optionally a pre-propose module.
+///
+/// ```ignore
+/// let mut app = App::default();
+/// let dao = DaoTestBuilder::new()
+///     .with_cw4_voting(vec![("ekez", 9), ("keze", 8)])
+///     .with_proposal_single(Threshold::majority())
+///     .with_pre_propose_deposit(deposit_info)
+///     .build(&mut app);
+/// ```
+///
+/// By default the DAO uses cw4 voting with `creator` as its only
+/// member and a proposal module which passes proposals with a
+/// majority.
+pub struct DaoTestBuilder {
+    voting: Voting,
+    threshold: Threshold,
+    max_voting_period: Duration,
+    pre_propose: Option<PreProposeSingle>,
+}
+
+/// The addresses of a DAO assembled by `DaoTestBuilder`.
+#[derive(Debug, Clone)]
+pub struct TestDao {
+    pub core: Addr,
+    pub voting: Addr,
+    pub proposal_single: Addr,
+    /// The proposal module's pre-propose module, if one was added.
+    pub pre_propose: Option<Addr>,
+    /// The cw4 group backing the voting module, if cw4 voting is
+    /// used.
+    pub cw4_group: Option<Addr>,
+    /// The governance token, if token based voting is used.
+    pub cw20: Option<Addr>,
+    /// The staking contract for the governance token, if token based
+    /// voting is used.
+    pub cw20_stake: Option<Addr>,
+}
+
+impl Default for DaoTestBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DaoTestBuilder {
+    pub fn new() -> Self {
+        Self {
+            voting: Voting::Cw4 {
+                members: vec![cw4::Member {
+                    addr: CREATOR_ADDR.to_string(),
+                    weight: 1,
+                }],
+            },
+            threshold: Threshold::majority(),
+            max_voting_period: Duration::Time(86400),
+            pre_propose: None,
+        }
+    }
+
+    /// Uses a cw4 group for voting, with the given (address, weight)
+    /// members.
+    pub fn with_cw4_voting(mut self, members: Vec<(&str, u64)>) -> Self {
+        self.voting = Voting::Cw4 {
+            members: members
+                .into_iter()
+                .map(|(addr, weight)| cw4::Member {
+                    addr: addr.to_string(),
+                    weight,
+                })
+                .collect(),
+        };
+        self
+    }
+
+    /// Uses staked cw20 balances for voting. The initial balances are
+    /// minted and staked.
+    pub fn with_cw20_staked_voting(mut self, initial_balances: Vec<(&str, u128)>) -> Self {
+        self.voting = Voting::Cw20Staked {
+            initial_balances: initial_balances
+                .into_iter()
+                .map(|(address, amount)| Cw20Coin {
+                    address: address.to_string(),
+                    amount: Uint128::new(amount),
+                })
+                .collect(),
+        };
+        self
+    }
+
+    /// Sets the threshold of the single choice proposal module.
+    pub fn with_proposal_single(mut self, threshold: Threshold) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Sets the maximum voting period of the single choice proposal
+    /// module.
+    pub fn with_max_voting_period(mut self, max_voting_period: Duration) -> Self {
+        self.max_voting_period = max_voting_period;
+        self
+    }
+
+    /// Adds a pre-propose module to the proposal module.
+    pub fn with_pre_propose_single(
+        mut self,
+        deposit_info: Option<UncheckedDepositInfo>,
+        open_proposal_submission: bool,
+    ) -> Self {
+        self.pre_propose = Some(PreProposeSingle {
+            deposit_info,
+            open_proposal_submission,
+        });
+        self
+    }
+
+    /// Adds a pre-propose module which requires DEPOSIT_INFO to
+    /// create a proposal. Only members may create proposals.
+    pub fn with_pre_propose_deposit(self, deposit_info: UncheckedDepositInfo) -> Self {
+        self.with_pre_propose_single(Some(deposit_info), false)
+    }
+
+    pub fn build(self, app: &mut App) -> TestDao {
+        let core_id = app.store_code(dao_core_contract());
+        let proposal_single_id = app.store_code(proposal_single_contract());
+
+        let voting_module_instantiate_info = match &self.voting {
+            Voting::Cw4 { members } => ModuleInstantiateInfo {
+                code_id: app.store_code(dao_voting_cw4_contract()),
+                msg: to_binary(&dao_voting_cw4::msg::InstantiateMsg {
+                    cw4_group_code_id: app.store_code(cw4_group_contract()),
+                    initial_members: members.clone(),
+                })
+                .unwrap(),
+                admin: Some(Admin::CoreModule {}),
+                label: "DAO DAO voting module".to_string(),
+            },
+            Voting::Cw20Staked { initial_balances } => ModuleInstantiateInfo {
+                code_id: app.store_code(cw20_staked_balances_voting_contract()),
+                msg: to_binary(&dao_voting_cw20_staked::msg::InstantiateMsg {
+                    active_threshold: None,
+                    token_info: dao_voting_cw20_staked::msg::TokenInfo::New {
+                        code_id: app.store_code(cw20_base_contract()),
+                        label: "DAO DAO governance token".to_string(),
+                        name: "DAO DAO".to_string(),
+                        symbol: "DAO".to_string(),
+                        decimals: 6,
+                        initial_balances: initial_balances.clone(),
+                        marketing: None,
+                        staking_code_id: app.store_code(cw20_stake_contract()),
+                        unstaking_duration: Some(Duration::Height(6)),
+                        initial_dao_balance: None,
+                    },
+                })
+                .unwrap(),
+                admin: Some(Admin::CoreModule {}),
+                label: "DAO DAO voting module".to_string(),
+            },
+        };
+
+        let pre_propose_info = match self.pre_propose {
+            Some(PreProposeSingle {
+                deposit_info,
+                open_proposal_submission,
+            }) => PreProposeInfo::ModuleMayPropose {
+                info: ModuleInstantiateInfo {
+                    code_id: app.store_code(pre_propose_single_contract()),
+                    msg: to_binary(&dao_pre_propose_single::InstantiateMsg {
+                        deposit_info,
+                        open_proposal_submission,
+                        nft_deposit_info: None,
+                        extension: Empty::default(),
+                    })
+                    .unwrap(),
+                    admin: Some(Admin::CoreModule {}),
+                    label: "DAO DAO pre-propose module".to_string(),
+                },
+            },
+            None => PreProposeInfo::AnyoneMayPropose {},
+        };
+
+        let core = app
+            .instantiate_contract(
+                core_id,
+                Addr::unchecked(CREATOR_ADDR),
+                &dao_core::msg::InstantiateMsg {
+                    dao_uri: None,
+                    admin: None,
+                    name: "DAO DAO".to_string(),
+                    description: "A DAO that builds DAOs".to_string(),
+                    image_url: None,
+                    automatically_add_cw20s: true,
+                    automatically_add_cw721s: true,
+                    voting_module_instantiate_info,
+                    proposal_modules_instantiate_info: vec![ModuleInstantiateInfo {
+                        code_id: proposal_single_id,
+                        msg: to_binary(&dao_proposal_single::msg::InstantiateMsg {
+                            threshold: self.threshold,
+                            max_voting_period: self.max_voting_period,
+                            min_voting_period: None,
+                            only_members_execute: false,
+                            allow_revoting: false,
+                            pre_propose_info,
+                            close_proposal_on_execution_failure: false,
+                            max_proposal_size: None,
+                            max_open_proposals: None,
+                            pre_propose_fallback: None,
+                            require_content_cid: None,
+                            abstain_counting: None,
+                            execution_delay: None,
+                            vote_extension: None,
+                        })
+                        .unwrap(),
+                        admin: Some(Admin::CoreModule {}),
+                        label: "DAO DAO governance module".to_string(),
+                    }],
+                    initial_items: None,
+                },
+                &[],
+                "DAO DAO",
+                None,
+            )
+            .unwrap();
+
+        let voting = CoreQuerier::new(app.wrap(), core.clone())
+            .voting_module()
+            .unwrap();
+        let proposal_modules: Vec<dao_core::state::ProposalModule> = app
+            .wrap()
+            .query_wasm_smart(
+                &core,
+                &dao_core::msg::QueryMsg::ProposalModules {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        let proposal_single = proposal_modules.into_iter().next().unwrap().address;
+        let pre_propose = match app
+            .wrap()
+            .query_wasm_smart(
+                &proposal_single,
+                &dao_proposal_single::msg::QueryMsg::ProposalCreationPolicy {},
+            )
+            .unwrap()
+        {
+            ProposalCreationPolicy::Module { addr } => Some(addr),
+            _ => None,
+        };
+
+        let mut dao = TestDao {
+            core,
+            voting,
+            proposal_single,
+            pre_propose,
+            cw4_group: None,
+            cw20: None,
+            cw20_stake: None,
+        };
+
+        match self.voting {
+            Voting::Cw4 { .. } => {
+                dao.cw4_group = Some(
+                    app.wrap()
+                        .query_wasm_smart(
+                            &dao.voting,
+                            &dao_voting_cw4::msg::QueryMsg::GroupContract {},
+                        )
+                        .unwrap(),
+                );
+            }
+            Voting::Cw20Staked { initial_balances } => {
+                let token: Addr = app
+                    .wrap()
+                    .query_wasm_smart(&dao.voting, &dao_interface::voting::Query::TokenContract {})
+                    .unwrap();
+                let staking: Addr = app
+                    .wrap()
+                    .query_wasm_smart(
+                        &dao.voting,
+                        &dao_voting_cw20_staked::msg::QueryMsg::StakingContract {},
+                    )
+                    .unwrap();
+                for Cw20Coin { address, amount } in initial_balances {
+                    app.execute_contract(
+                        Addr::unchecked(address),
+                        token.clone(),
+                        &cw20::Cw20ExecuteMsg::Send {
+                            contract: staking.to_string(),
+                            amount,
+                            msg: to_binary(&cw20_stake::msg::ReceiveMsg::Stake {}).unwrap(),
+                        },
+                        &[],
+                    )
+                    .unwrap();
+                }
+                dao.cw20 = Some(token);
+                dao.cw20_stake = Some(staking);
+            }
+        }
+
+        // Update the block so that voting power appears.
+        app.update_block(|block| block.height += 1);
+
+        dao
+    }
+}
+
+impl TestDao {
+    /// The DAO's pre-propose module. Panics if the DAO does not have
+    /// one.
+    pub fn pre_propose(&self) -> Addr {
+        self.pre_propose
+            .clone()
+            .expect("DAO was built without a pre-propose module")
+    }
+
+    pub fn core_querier<'a>(&self, app: &'a App) -> CoreQuerier<'a> {
+        CoreQuerier::new(app.wrap(), self.core.clone())
+    }
+
+    pub fn proposal_single_querier<'a>(
+        &self,
+        app: &'a App,
+    ) -> dao_proposal_single::msg::QueryMsgQuerier<'a> {
+        dao_proposal_single::msg::QueryMsgQuerier::new(app.wrap(), &self.proposal_single)
+    }
+}
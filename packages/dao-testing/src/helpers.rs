@@ -170,7 +170,13 @@ pub fn instantiate_with_staked_balances_governance(
 
     let gov_state: dao_core::query::DumpStateResponse = app
         .wrap()
-        .query_wasm_smart(core_addr.clone(), &dao_core::msg::QueryMsg::DumpState {})
+        .query_wasm_smart(
+            core_addr.clone(),
+            &dao_core::msg::QueryMsg::DumpState {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
     let voting_module = gov_state.voting_module;
 
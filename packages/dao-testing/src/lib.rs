@@ -9,5 +9,8 @@ pub mod helpers;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod contracts;
 
+#[cfg(not(target_arch = "wasm32"))]
+pub mod builder;
+
 #[cfg(not(target_arch = "wasm32"))]
 pub use tests::*;
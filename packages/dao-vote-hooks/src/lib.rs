@@ -1,9 +1,12 @@
 #![doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"))]
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{to_binary, StdResult, Storage, SubMsg, WasmMsg};
+use cosmwasm_std::{to_binary, StdResult, Storage, SubMsg, Uint128, WasmMsg};
 use cw_hooks::Hooks;
-use dao_voting::reply::mask_vote_hook_index;
+use cw_storage_plus::{Deque, Item};
+use dao_voting::{
+    multiple_choice::MultipleChoiceVotes, reply::mask_vote_hook_index, voting::Votes,
+};
 
 #[cw_serde]
 pub enum VoteHookMsg {
@@ -11,7 +14,52 @@ pub enum VoteHookMsg {
         proposal_id: u64,
         voter: String,
         vote: String,
+        /// The voting power of the voter at `height`.
+        power: Uint128,
+        /// The block height at which voting power is measured for
+        /// the proposal.
+        height: u64,
+        /// The proposal's votes, including this one.
+        tally: VoteTally,
     },
+    /// Votes buffered by a proposal module which dispatches vote
+    /// hooks in batches, in the order they were cast.
+    NewVotes { votes: Vec<NewVote> },
+}
+
+/// A vote sent as part of a `VoteHookMsg::NewVotes` batch.
+#[cw_serde]
+pub struct NewVote {
+    pub proposal_id: u64,
+    pub voter: String,
+    pub vote: String,
+    /// The voting power of the voter at `height`.
+    pub power: Uint128,
+    /// The block height at which voting power is measured for the
+    /// proposal.
+    pub height: u64,
+    /// The proposal's votes, including this one.
+    pub tally: VoteTally,
+}
+
+impl From<NewVote> for VoteHookMsg {
+    fn from(vote: NewVote) -> Self {
+        VoteHookMsg::NewVote {
+            proposal_id: vote.proposal_id,
+            voter: vote.voter,
+            vote: vote.vote,
+            power: vote.power,
+            height: vote.height,
+            tally: vote.tally,
+        }
+    }
+}
+
+/// The votes cast on a proposal so far.
+#[cw_serde]
+pub enum VoteTally {
+    SingleChoice(Votes),
+    MultipleChoice(MultipleChoiceVotes),
 }
 
 // This is just a helper to properly serialize the above message
@@ -23,18 +71,37 @@ pub enum VoteHookExecuteMsg {
 /// Prepares new vote hook messages. These messages reply on error
 /// and have even reply IDs.
 /// IDs are set to odd numbers to then be interleaved with the proposal hooks.
+#[allow(clippy::too_many_arguments)]
 pub fn new_vote_hooks(
     hooks: Hooks,
     storage: &dyn Storage,
     proposal_id: u64,
     voter: String,
     vote: String,
+    power: Uint128,
+    height: u64,
+    tally: VoteTally,
+) -> StdResult<Vec<SubMsg>> {
+    prepare_vote_hooks(
+        hooks,
+        storage,
+        VoteHookMsg::NewVote {
+            proposal_id,
+            voter,
+            vote,
+            power,
+            height,
+            tally,
+        },
+    )
+}
+
+fn prepare_vote_hooks(
+    hooks: Hooks,
+    storage: &dyn Storage,
+    msg: VoteHookMsg,
 ) -> StdResult<Vec<SubMsg>> {
-    let msg = to_binary(&VoteHookExecuteMsg::VoteHook(VoteHookMsg::NewVote {
-        proposal_id,
-        voter,
-        vote,
-    }))?;
+    let msg = to_binary(&VoteHookExecuteMsg::VoteHook(msg))?;
     let mut index: u64 = 0;
     hooks.prepare_hooks(storage, |a| {
         let execute = WasmMsg::Execute {
@@ -48,3 +115,194 @@ pub fn new_vote_hooks(
         Ok(tmp)
     })
 }
+
+/// How a proposal module dispatches vote hooks.
+#[cw_serde]
+pub enum VoteHookDispatch {
+    /// Each consumer is sent a `NewVote` message as every vote is
+    /// cast.
+    Immediate {},
+    /// Votes are buffered and each consumer is sent one `NewVotes`
+    /// message when the buffer is flushed. Anyone may flush the
+    /// buffer, for example from a crank once per block, and the vote
+    /// which brings the buffer to MAX_VOTES votes flushes it.
+    Batched { max_votes: u32 },
+}
+
+impl Default for VoteHookDispatch {
+    fn default() -> Self {
+        VoteHookDispatch::Immediate {}
+    }
+}
+
+#[cw_serde]
+pub struct VoteHookDispatchResponse {
+    pub dispatch: VoteHookDispatch,
+    /// The number of votes waiting to be sent to consumers.
+    pub buffered_votes: u32,
+}
+
+/// Stores how vote hooks are dispatched along with votes buffered
+/// for batched dispatch. Buffered votes are sent to the consumers
+/// registered when the buffer is flushed.
+pub struct VoteHookBuffer<'a> {
+    dispatch: Item<'a, VoteHookDispatch>,
+    votes: Deque<'a, NewVote>,
+}
+
+impl<'a> VoteHookBuffer<'a> {
+    pub const fn new(dispatch_key: &'a str, votes_key: &'a str) -> Self {
+        Self {
+            dispatch: Item::new(dispatch_key),
+            votes: Deque::new(votes_key),
+        }
+    }
+
+    pub fn dispatch(&self, storage: &dyn Storage) -> StdResult<VoteHookDispatch> {
+        Ok(self.dispatch.may_load(storage)?.unwrap_or_default())
+    }
+
+    /// Sets how future votes are dispatched. Votes already in the
+    /// buffer remain there until it is flushed.
+    pub fn set_dispatch(
+        &self,
+        storage: &mut dyn Storage,
+        dispatch: &VoteHookDispatch,
+    ) -> StdResult<()> {
+        self.dispatch.save(storage, dispatch)
+    }
+
+    pub fn len(&self, storage: &dyn Storage) -> StdResult<u32> {
+        self.votes.len(storage)
+    }
+
+    pub fn is_empty(&self, storage: &dyn Storage) -> StdResult<bool> {
+        self.votes.is_empty(storage)
+    }
+
+    /// Prepares vote hook messages for VOTE, or buffers it if hooks
+    /// are dispatched in batches. Messages reply on error with the
+    /// same IDs as `new_vote_hooks`.
+    pub fn new_vote(
+        &self,
+        hooks: Hooks,
+        storage: &mut dyn Storage,
+        vote: NewVote,
+    ) -> StdResult<Vec<SubMsg>> {
+        match self.dispatch(storage)? {
+            VoteHookDispatch::Immediate {} => prepare_vote_hooks(hooks, storage, vote.into()),
+            VoteHookDispatch::Batched { max_votes } => {
+                self.votes.push_back(storage, &vote)?;
+                if self.votes.len(storage)? >= max_votes {
+                    self.flush(hooks, storage)
+                } else {
+                    Ok(vec![])
+                }
+            }
+        }
+    }
+
+    /// Empties the buffer, preparing one `NewVotes` message for each
+    /// consumer. Returns no messages if the buffer is empty.
+    pub fn flush(&self, hooks: Hooks, storage: &mut dyn Storage) -> StdResult<Vec<SubMsg>> {
+        let mut votes = vec![];
+        while let Some(vote) = self.votes.pop_front(storage)? {
+            votes.push(vote);
+        }
+        if votes.is_empty() {
+            Ok(vec![])
+        } else {
+            prepare_vote_hooks(hooks, storage, VoteHookMsg::NewVotes { votes })
+        }
+    }
+
+    pub fn query(&self, storage: &dyn Storage) -> StdResult<VoteHookDispatchResponse> {
+        Ok(VoteHookDispatchResponse {
+            dispatch: self.dispatch(storage)?,
+            buffered_votes: self.len(storage)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{from_binary, testing::mock_dependencies, Addr, CosmosMsg};
+    use dao_voting::voting::Votes;
+
+    use super::*;
+
+    const HOOKS: Hooks = Hooks::new("hooks");
+    const BUFFER: VoteHookBuffer = VoteHookBuffer::new("dispatch", "buffer");
+
+    fn vote(voter: &str) -> NewVote {
+        NewVote {
+            proposal_id: 1,
+            voter: voter.to_string(),
+            vote: "yes".to_string(),
+            power: Uint128::new(1),
+            height: 10,
+            tally: VoteTally::SingleChoice(Votes::zero()),
+        }
+    }
+
+    fn hook_msg(msg: &SubMsg) -> VoteHookMsg {
+        match &msg.msg {
+            CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => {
+                match from_binary::<VoteHookExecuteMsg>(msg).unwrap() {
+                    VoteHookExecuteMsg::VoteHook(msg) => msg,
+                }
+            }
+            _ => panic!("expected a wasm execute message"),
+        }
+    }
+
+    #[test]
+    fn test_batched_dispatch() {
+        let mut deps = mock_dependencies();
+        let storage = &mut deps.storage;
+        HOOKS.add_hook(storage, Addr::unchecked("ekez")).unwrap();
+        HOOKS.add_hook(storage, Addr::unchecked("meow")).unwrap();
+
+        // Votes are sent as they are cast by default.
+        let msgs = BUFFER.new_vote(HOOKS, storage, vote("a")).unwrap();
+        assert_eq!(msgs.len(), 2);
+        assert_eq!(hook_msg(&msgs[0]), vote("a").into());
+
+        BUFFER
+            .set_dispatch(storage, &VoteHookDispatch::Batched { max_votes: 3 })
+            .unwrap();
+        assert!(BUFFER
+            .new_vote(HOOKS, storage, vote("a"))
+            .unwrap()
+            .is_empty());
+        assert!(BUFFER
+            .new_vote(HOOKS, storage, vote("b"))
+            .unwrap()
+            .is_empty());
+        assert_eq!(BUFFER.len(storage).unwrap(), 2);
+
+        // The third vote fills the buffer and flushes it.
+        let msgs = BUFFER.new_vote(HOOKS, storage, vote("c")).unwrap();
+        assert_eq!(msgs.len(), 2);
+        assert_eq!(
+            hook_msg(&msgs[1]),
+            VoteHookMsg::NewVotes {
+                votes: vec![vote("a"), vote("b"), vote("c")]
+            }
+        );
+        assert_eq!(msgs[1].id, mask_vote_hook_index(1));
+        assert!(BUFFER.is_empty(storage).unwrap());
+
+        // Flushing sends whatever is buffered, and nothing once the
+        // buffer is empty.
+        BUFFER.new_vote(HOOKS, storage, vote("d")).unwrap();
+        let msgs = BUFFER.flush(HOOKS, storage).unwrap();
+        assert_eq!(
+            hook_msg(&msgs[0]),
+            VoteHookMsg::NewVotes {
+                votes: vec![vote("d")]
+            }
+        );
+        assert!(BUFFER.flush(HOOKS, storage).unwrap().is_empty());
+    }
+}
@@ -25,6 +25,9 @@ pub enum DepositError {
 
     #[error("invalid deposit amount. got ({actual}), expected ({expected})")]
     InvalidDeposit { actual: Uint128, expected: Uint128 },
+
+    #[error("more than one deposit uses ({denom}). each deposit must use a different token")]
+    DuplicateDenom { denom: String },
 }
 
 /// Information about the token to use for proposal deposits.
@@ -51,6 +54,25 @@ pub struct UncheckedDepositInfo {
     pub amount: Uint128,
     /// The policy used for refunding deposits on proposal completion.
     pub refund_policy: DepositRefundPolicy,
+    /// Deposits required in addition to the one above. For example,
+    /// a native token spam bond along with a governance token
+    /// deposit. All deposits are taken and refunded together, and
+    /// each must use a different token.
+    #[serde(default)]
+    pub additional: Vec<UncheckedDeposit>,
+    /// If true, deposits are refunded when a proposal is cancelled
+    /// by its proposer. Otherwise, they are sent to the DAO.
+    #[serde(default)]
+    pub refund_on_cancel: bool,
+}
+
+/// A deposit required in addition to the primary deposit of an
+/// `UncheckedDepositInfo`.
+#[cw_serde]
+pub struct UncheckedDeposit {
+    pub denom: DepositToken,
+    /// Must be a positive, non-zero number.
+    pub amount: Uint128,
 }
 
 #[cw_serde]
@@ -79,25 +101,26 @@ pub struct CheckedDepositInfo {
     pub amount: Uint128,
     /// The policy used for refunding proposal deposits.
     pub refund_policy: DepositRefundPolicy,
+    /// Deposits required in addition to the one above.
+    #[serde(default)]
+    pub additional: Vec<CheckedDeposit>,
+    /// If true, deposits are refunded when a proposal is cancelled
+    /// by its proposer. Otherwise, they are sent to the DAO.
+    #[serde(default)]
+    pub refund_on_cancel: bool,
 }
 
-impl UncheckedDepositInfo {
-    /// Converts deposit info into checked deposit info.
-    pub fn into_checked(self, deps: Deps, dao: Addr) -> Result<CheckedDepositInfo, DepositError> {
-        let Self {
-            denom,
-            amount,
-            refund_policy,
-        } = self;
-        // Check that the deposit is non-zero. Modules should make
-        // deposit information optional and consumers should provide
-        // `None` when they do not want to have a proposal deposit.
-        if amount.is_zero() {
-            return Err(DepositError::ZeroDeposit);
-        }
+/// Counterpart to `UncheckedDeposit` which has been processed.
+#[cw_serde]
+pub struct CheckedDeposit {
+    pub denom: CheckedDenom,
+    pub amount: Uint128,
+}
 
-        let denom = match denom {
-            DepositToken::Token { denom } => denom.into_checked(deps),
+impl DepositToken {
+    fn into_checked(self, deps: Deps, dao: &Addr) -> Result<CheckedDenom, DepositError> {
+        match self {
+            DepositToken::Token { denom } => Ok(denom.into_checked(deps)?),
             DepositToken::VotingModuleToken {} => {
                 let voting_module: Addr = deps
                     .querier
@@ -112,42 +135,129 @@ impl UncheckedDepositInfo {
                 // returned a valid token. Conversion of the unchecked
                 // denom into a checked one will do a `TokenInfo {}`
                 // query.
-                UncheckedDenom::Cw20(token_addr.into_string()).into_checked(deps)
+                Ok(UncheckedDenom::Cw20(token_addr.into_string()).into_checked(deps)?)
+            }
+        }
+    }
+}
+
+impl UncheckedDepositInfo {
+    /// Converts deposit info into checked deposit info.
+    pub fn into_checked(self, deps: Deps, dao: Addr) -> Result<CheckedDepositInfo, DepositError> {
+        let Self {
+            denom,
+            amount,
+            refund_policy,
+            additional,
+            refund_on_cancel,
+        } = self;
+        // Check that the deposit is non-zero. Modules should make
+        // deposit information optional and consumers should provide
+        // `None` when they do not want to have a proposal deposit.
+        if amount.is_zero() || additional.iter().any(|d| d.amount.is_zero()) {
+            return Err(DepositError::ZeroDeposit);
+        }
+
+        let denom = denom.into_checked(deps, &dao)?;
+        let additional = additional
+            .into_iter()
+            .map(
+                |UncheckedDeposit { denom, amount }| -> Result<_, DepositError> {
+                    Ok(CheckedDeposit {
+                        denom: denom.into_checked(deps, &dao)?,
+                        amount,
+                    })
+                },
+            )
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Two deposits of the same token could not be told apart
+        // when checking native payments, so each must be unique.
+        let mut seen = vec![&denom];
+        for CheckedDeposit { denom, .. } in additional.iter() {
+            if seen.contains(&denom) {
+                return Err(DepositError::DuplicateDenom {
+                    denom: denom.to_string(),
+                });
             }
-        }?;
+            seen.push(denom);
+        }
 
         Ok(CheckedDepositInfo {
             denom,
             amount,
             refund_policy,
+            additional,
+            refund_on_cancel,
         })
     }
 }
 
 impl CheckedDepositInfo {
+    /// Iterates over the denom and amount of every required deposit,
+    /// starting with the primary deposit.
+    pub fn deposits(&self) -> impl Iterator<Item = (&CheckedDenom, Uint128)> {
+        std::iter::once((&self.denom, self.amount)).chain(
+            self.additional
+                .iter()
+                .map(|deposit| (&deposit.denom, deposit.amount)),
+        )
+    }
+
     pub fn check_native_deposit_paid(&self, info: &MessageInfo) -> Result<(), DepositError> {
-        if let Self {
-            amount,
-            denom: CheckedDenom::Native(denom),
-            ..
-        } = self
-        {
-            // must_pay > may_pay. The method this is getting called
-            // in is accepting a deposit. It seems likely to me that
-            // if other payments are here it's a bug in a frontend and
-            // not an intentional thing.
-            let paid = must_pay(info, denom)?;
-            if paid != *amount {
-                Err(DepositError::InvalidDeposit {
-                    actual: paid,
-                    expected: *amount,
-                })
-            } else {
+        let native: Vec<(&String, Uint128)> = self
+            .deposits()
+            .filter_map(|(denom, amount)| match denom {
+                CheckedDenom::Native(denom) => Some((denom, amount)),
+                CheckedDenom::Cw20(_) => None,
+            })
+            .collect();
+
+        match native.as_slice() {
+            // Nothing to do if we're all cw20s.
+            [] => Ok(()),
+            [(denom, amount)] => {
+                // must_pay > may_pay. The method this is getting
+                // called in is accepting a deposit. It seems likely
+                // to me that if other payments are here it's a bug in
+                // a frontend and not an intentional thing.
+                let paid = must_pay(info, denom)?;
+                if paid != *amount {
+                    Err(DepositError::InvalidDeposit {
+                        actual: paid,
+                        expected: *amount,
+                    })
+                } else {
+                    Ok(())
+                }
+            }
+            _ => {
+                if let Some(extra) = info
+                    .funds
+                    .iter()
+                    .find(|coin| !native.iter().any(|(denom, _)| *denom == &coin.denom))
+                {
+                    return Err(PaymentError::ExtraDenom(extra.denom.clone()).into());
+                }
+                for (denom, amount) in native {
+                    let paid: Uint128 = info
+                        .funds
+                        .iter()
+                        .filter(|coin| &coin.denom == denom)
+                        .map(|coin| coin.amount)
+                        .sum();
+                    if paid.is_zero() {
+                        return Err(PaymentError::MissingDenom(denom.clone()).into());
+                    }
+                    if paid != amount {
+                        return Err(DepositError::InvalidDeposit {
+                            actual: paid,
+                            expected: amount,
+                        });
+                    }
+                }
                 Ok(())
             }
-        } else {
-            // Nothing to do if we're a cw20.
-            Ok(())
         }
     }
 
@@ -156,49 +266,48 @@ impl CheckedDepositInfo {
         depositor: &Addr,
         contract: &Addr,
     ) -> StdResult<Vec<CosmosMsg>> {
-        let take_deposit_msg: Vec<CosmosMsg> = if let Self {
-            amount,
-            denom: CheckedDenom::Cw20(address),
-            ..
-        } = self
-        {
-            // into_checked() makes sure this isn't the case, but just for
-            // posterity.
-            if amount.is_zero() {
-                vec![]
-            } else {
-                vec![WasmMsg::Execute {
+        self.deposits()
+            // into_checked() makes sure amounts are non-zero, but
+            // just for posterity.
+            .filter(|(_, amount)| !amount.is_zero())
+            .filter_map(|(denom, amount)| match denom {
+                CheckedDenom::Cw20(address) => Some((address, amount)),
+                // Deposits are pushed, not pulled for native
+                // deposits. See: `check_native_deposit_paid`.
+                CheckedDenom::Native(_) => None,
+            })
+            .map(|(address, amount)| {
+                Ok(WasmMsg::Execute {
                     contract_addr: address.to_string(),
                     funds: vec![],
                     msg: to_binary(&cw20::Cw20ExecuteMsg::TransferFrom {
                         owner: depositor.to_string(),
                         recipient: contract.to_string(),
-                        amount: *amount,
+                        amount,
                     })?,
                 }
-                .into()]
-            }
-        } else {
-            // Deposits are pushed, not pulled for native
-            // deposits. See: `check_native_deposit_paid`.
-            vec![]
-        };
-        Ok(take_deposit_msg)
+                .into())
+            })
+            .collect()
     }
 
     pub fn get_return_deposit_message(&self, depositor: &Addr) -> StdResult<Vec<CosmosMsg>> {
-        // Should get caught in `into_checked()`, but to be pedantic.
-        if self.amount.is_zero() {
-            return Ok(vec![]);
-        }
-        let message = self.denom.get_transfer_to_message(depositor, self.amount)?;
-        Ok(vec![message])
+        self.deposits()
+            // Should get caught in `into_checked()`, but to be
+            // pedantic.
+            .filter(|(_, amount)| !amount.is_zero())
+            .map(|(denom, amount)| denom.get_transfer_to_message(depositor, amount))
+            .collect()
     }
 }
 
 #[cfg(test)]
 pub mod tests {
-    use cosmwasm_std::{coin, coins, testing::mock_info, BankMsg};
+    use cosmwasm_std::{
+        coin, coins,
+        testing::{mock_dependencies, mock_info},
+        BankMsg,
+    };
 
     use super::*;
 
@@ -212,6 +321,8 @@ pub mod tests {
             denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         };
         deposit_info.check_native_deposit_paid(&info).unwrap();
 
@@ -234,6 +345,8 @@ pub mod tests {
             denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         };
         let err = deposit_info.check_native_deposit_paid(&info).unwrap_err();
         assert_eq!(
@@ -252,6 +365,8 @@ pub mod tests {
             denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         };
         let err = deposit_info.check_native_deposit_paid(&info).unwrap_err();
         assert_eq!(
@@ -272,6 +387,8 @@ pub mod tests {
             denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         };
 
         let err = deposit_info.check_native_deposit_paid(&info).unwrap_err();
@@ -285,6 +402,8 @@ pub mod tests {
             denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         };
         let err = deposit_info.check_native_deposit_paid(&info).unwrap_err();
         assert_eq!(err, DepositError::Payment(PaymentError::NoFunds {}));
@@ -297,6 +416,8 @@ pub mod tests {
             denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         };
         let messages = deposit_info
             .get_take_deposit_messages(&Addr::unchecked("ekez"), &Addr::unchecked(CW20))
@@ -337,6 +458,8 @@ pub mod tests {
             denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         };
         let messages = deposit_info
             .get_return_deposit_message(&Addr::unchecked("ekez"))
@@ -363,6 +486,8 @@ pub mod tests {
             denom: CheckedDenom::Cw20(Addr::unchecked(CW20)),
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            additional: vec![],
+            refund_on_cancel: false,
         };
         let messages = deposit_info
             .get_return_deposit_message(&Addr::unchecked("ekez"))
@@ -387,4 +512,130 @@ pub mod tests {
             .unwrap();
         assert_eq!(messages, vec![]);
     }
+
+    fn multi_deposit_info() -> CheckedDepositInfo {
+        CheckedDepositInfo {
+            denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+            additional: vec![
+                CheckedDeposit {
+                    denom: CheckedDenom::Native("ujuno".to_string()),
+                    amount: Uint128::new(5),
+                },
+                CheckedDeposit {
+                    denom: CheckedDenom::Cw20(Addr::unchecked(CW20)),
+                    amount: Uint128::new(20),
+                },
+            ],
+            refund_on_cancel: false,
+        }
+    }
+
+    #[test]
+    fn test_check_native_deposit_paid_multiple() {
+        let deposit_info = multi_deposit_info();
+
+        let info = mock_info("ekez", &[coin(10, NATIVE_DENOM), coin(5, "ujuno")]);
+        deposit_info.check_native_deposit_paid(&info).unwrap();
+
+        let info = mock_info("ekez", &coins(10, NATIVE_DENOM));
+        let err = deposit_info.check_native_deposit_paid(&info).unwrap_err();
+        assert_eq!(
+            err,
+            DepositError::Payment(PaymentError::MissingDenom("ujuno".to_string()))
+        );
+
+        let info = mock_info("ekez", &[coin(10, NATIVE_DENOM), coin(4, "ujuno")]);
+        let err = deposit_info.check_native_deposit_paid(&info).unwrap_err();
+        assert_eq!(
+            err,
+            DepositError::InvalidDeposit {
+                actual: Uint128::new(4),
+                expected: Uint128::new(5)
+            }
+        );
+
+        let info = mock_info(
+            "ekez",
+            &[coin(10, NATIVE_DENOM), coin(5, "ujuno"), coin(1, "uatom")],
+        );
+        let err = deposit_info.check_native_deposit_paid(&info).unwrap_err();
+        assert_eq!(
+            err,
+            DepositError::Payment(PaymentError::ExtraDenom("uatom".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_multiple_deposit_messages() {
+        let deposit_info = multi_deposit_info();
+
+        // Only the cw20 deposit needs to be pulled in.
+        let messages = deposit_info
+            .get_take_deposit_messages(&Addr::unchecked("ekez"), &Addr::unchecked("contract"))
+            .unwrap();
+        assert_eq!(
+            messages,
+            vec![CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: CW20.to_string(),
+                msg: to_binary(&cw20::Cw20ExecuteMsg::TransferFrom {
+                    owner: "ekez".to_string(),
+                    recipient: "contract".to_string(),
+                    amount: Uint128::new(20)
+                })
+                .unwrap(),
+                funds: vec![],
+            })]
+        );
+
+        // Every deposit is returned.
+        let messages = deposit_info
+            .get_return_deposit_message(&Addr::unchecked("ekez"))
+            .unwrap();
+        assert_eq!(
+            messages,
+            vec![
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "ekez".to_string(),
+                    amount: coins(10, NATIVE_DENOM)
+                }),
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "ekez".to_string(),
+                    amount: coins(5, "ujuno")
+                }),
+                CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr: CW20.to_string(),
+                    msg: to_binary(&cw20::Cw20ExecuteMsg::Transfer {
+                        recipient: "ekez".to_string(),
+                        amount: Uint128::new(20)
+                    })
+                    .unwrap(),
+                    funds: vec![]
+                })
+            ]
+        );
+    }
+
+    #[test]
+    fn test_into_checked_zero_additional_deposit() {
+        let deps = mock_dependencies();
+        let err = UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native(NATIVE_DENOM.to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+            additional: vec![UncheckedDeposit {
+                denom: DepositToken::Token {
+                    denom: UncheckedDenom::Native("ujuno".to_string()),
+                },
+                amount: Uint128::zero(),
+            }],
+            refund_on_cancel: false,
+        }
+        .into_checked(deps.as_ref(), Addr::unchecked("dao"))
+        .unwrap_err();
+        assert_eq!(err, DepositError::ZeroDeposit);
+    }
 }
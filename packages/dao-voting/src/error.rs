@@ -1,6 +1,8 @@
 use cosmwasm_std::StdError;
 use thiserror::Error;
 
+use crate::status::Status;
+
 #[derive(Error, Debug, PartialEq)]
 pub enum VotingError {
     #[error("{0}")]
@@ -11,4 +13,37 @@ pub enum VotingError {
 
     #[error("Min voting period must be less than or equal to max voting period")]
     InvalidMinVotingPeriod {},
+
+    #[error("execution_delay and max_voting_period must have the same units (height or time)")]
+    ExecutionDelayUnitsConflict {},
+
+    #[error("Max proposal size must be greater than zero and at most ({ceiling}) bytes")]
+    InvalidMaxProposalSize { ceiling: u64 },
+
+    #[error("Description hash must be 32 bytes, got ({len})")]
+    InvalidDescriptionHash { len: usize },
+
+    #[error("Description URI must be non-empty and at most ({max}) bytes")]
+    InvalidDescriptionUri { max: usize },
+
+    #[error("Description must be empty when a description hash is provided")]
+    DescriptionWithHash {},
+
+    #[error("Content CID must be a CIDv0 or base32 CIDv1 of at most ({max}) characters")]
+    InvalidContentCid { max: usize },
+
+    #[error("Proposals must include a content CID")]
+    ContentCidRequired {},
+
+    #[error("Max open proposals must be greater than zero")]
+    ZeroMaxOpenProposals {},
+
+    #[error("There may be at most ({max}) open proposals")]
+    TooManyOpenProposals { max: u64 },
+
+    #[error("A proposer may have at most ({max}) open proposals")]
+    TooManyOpenProposalsByProposer { max: u64 },
+
+    #[error("Only executed, closed, or failed proposals may be pruned, got ({status})")]
+    InvalidPruneStatus { status: Status },
 }
@@ -6,6 +6,12 @@ pub mod multiple_choice;
 pub mod pre_propose;
 pub mod proposal;
 pub mod reply;
+pub mod signed_vote;
+pub mod simulation;
+pub mod stats;
 pub mod status;
 pub mod threshold;
 pub mod voting;
+
+#[cfg(test)]
+mod proptests;
@@ -1,7 +1,9 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{CosmosMsg, Empty, StdError, StdResult, Uint128};
 
-use crate::threshold::{validate_quorum, PercentageThreshold, ThresholdError};
+use crate::threshold::{
+    validate_quorum, PassingRequirement, PercentageThreshold, ThresholdDescription, ThresholdError,
+};
 
 /// Maximum number of choices for multiple choice votes. Chosen
 /// in order to impose a bound on state / queries.
@@ -26,6 +28,20 @@ impl VotingStrategy {
             VotingStrategy::SingleChoice { quorum } => *quorum,
         }
     }
+
+    /// Explains what this strategy requires of a proposal's votes.
+    pub fn describe(&self) -> ThresholdDescription {
+        match self {
+            VotingStrategy::SingleChoice { quorum } => ThresholdDescription {
+                passing: PassingRequirement::Plurality {},
+                quorum: Some(*quorum),
+                summary: format!(
+                    "{} of voting power must vote, and the option with the most votes wins",
+                    quorum.describe()
+                ),
+            },
+        }
+    }
 }
 
 /// A multiple choice vote, picking the desired option
@@ -5,6 +5,8 @@ use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Empty, StdResult, SubMsg};
 use dao_interface::ModuleInstantiateInfo;
 
+pub use dao_interface::proposal::ProposalCreationPolicy;
+
 use crate::reply::pre_propose_module_instantiation_id;
 
 #[cw_serde]
@@ -16,27 +18,6 @@ pub enum PreProposeInfo {
     ModuleMayPropose { info: ModuleInstantiateInfo },
 }
 
-#[cw_serde]
-pub enum ProposalCreationPolicy {
-    /// Anyone may create a proposal, free of charge.
-    Anyone {},
-    /// Only ADDR may create proposals. It is expected that ADDR is a
-    /// pre-propose module, though we only require that it is a valid
-    /// address.
-    Module { addr: Addr },
-}
-
-impl ProposalCreationPolicy {
-    /// Determines if CREATOR is permitted to create a
-    /// proposal. Returns true if so and false otherwise.
-    pub fn is_permitted(&self, creator: &Addr) -> bool {
-        match self {
-            Self::Anyone {} => true,
-            Self::Module { addr } => creator == addr,
-        }
-    }
-}
-
 impl PreProposeInfo {
     pub fn into_initial_policy_and_messages(
         self,
@@ -58,6 +39,60 @@ impl PreProposeInfo {
     }
 }
 
+/// The proposal creation policy a proposal module falls back to if
+/// its pre-propose module errors while handling a hook.
+#[cw_serde]
+pub enum PreProposeFallback {
+    /// Anyone may create proposals. Before the fallback was
+    /// configurable this was always used.
+    Anyone {},
+    /// Only the DAO may create proposals.
+    DaoOnly {},
+    /// No one may create proposals until the DAO installs a new
+    /// pre-propose module.
+    Frozen {},
+}
+
+impl Default for PreProposeFallback {
+    fn default() -> Self {
+        Self::Anyone {}
+    }
+}
+
+impl PreProposeFallback {
+    /// The creation policy to use after falling back for a proposal
+    /// module belonging to DAO.
+    pub fn into_policy(self, dao: Addr) -> ProposalCreationPolicy {
+        match self {
+            Self::Anyone {} => ProposalCreationPolicy::Anyone {},
+            Self::DaoOnly {} => ProposalCreationPolicy::Dao { addr: dao },
+            Self::Frozen {} => ProposalCreationPolicy::Frozen {},
+        }
+    }
+}
+
+impl std::fmt::Display for PreProposeFallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Anyone {} => write!(f, "anyone"),
+            Self::DaoOnly {} => write!(f, "dao_only"),
+            Self::Frozen {} => write!(f, "frozen"),
+        }
+    }
+}
+
+/// A record of a pre-propose module failing and the proposal module
+/// falling back to another creation policy.
+#[cw_serde]
+pub struct PreProposeFailure {
+    /// The pre-propose module which failed.
+    pub module: Addr,
+    /// The block height at which it failed.
+    pub height: u64,
+    /// The fallback that was applied.
+    pub fallback: PreProposeFallback,
+}
+
 #[cfg(test)]
 mod tests {
     use cosmwasm_std::{to_binary, WasmMsg};
@@ -99,6 +134,22 @@ mod tests {
         assert!(policy.is_permitted(&Addr::unchecked("deposit_module")));
     }
 
+    #[test]
+    fn test_fallback_policies() {
+        let dao = Addr::unchecked("dao");
+        let anyone = PreProposeFallback::Anyone {}.into_policy(dao.clone());
+        assert!(anyone.is_permitted(&Addr::unchecked("ekez")));
+
+        let dao_only = PreProposeFallback::DaoOnly {}.into_policy(dao.clone());
+        assert!(dao_only.is_permitted(&dao));
+        assert!(!dao_only.is_permitted(&Addr::unchecked("ekez")));
+
+        let frozen = PreProposeFallback::Frozen {}.into_policy(dao.clone());
+        assert!(!frozen.is_permitted(&dao));
+
+        assert_eq!(PreProposeFallback::default(), PreProposeFallback::Anyone {});
+    }
+
     #[test]
     fn test_pre_any_conversion() {
         let info = PreProposeInfo::AnyoneMayPropose {};
@@ -1,9 +1,93 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{CosmosMsg, Empty};
+use cosmwasm_std::{Addr, Binary, CosmosMsg, Empty, StdError, StdResult};
+use cw_storage_plus::Bound;
+use cw_utils::Expiration;
+use sha2::{Digest, Sha256};
+
+use crate::{error::VotingError, multiple_choice::MultipleChoiceOptions, status::Status};
 
 /// Default limit for proposal pagination.
 pub const DEFAULT_LIMIT: u64 = 30;
+/// Maximum limit for proposal pagination.
+pub const MAX_LIMIT: u64 = 100;
+/// The default maximum size of a proposal in bytes. This was tuned
+/// for the Juno mainnet, where larger proposals may be created but
+/// can not be queried.
 pub const MAX_PROPOSAL_SIZE: u64 = 30_000;
+/// The largest maximum proposal size a proposal module may be
+/// configured with.
+pub const MAX_PROPOSAL_SIZE_CEILING: u64 = 500_000;
+
+/// The maximum length in bytes of the URI of a description stored
+/// off chain.
+pub const MAX_DESCRIPTION_URI_LENGTH: usize = 512;
+
+/// The maximum length of a proposal's content CID.
+pub const MAX_CONTENT_CID_LENGTH: usize = 128;
+
+/// Validates a configured maximum proposal size, returning
+/// `MAX_PROPOSAL_SIZE` if none is provided.
+pub fn validate_max_proposal_size(size: Option<u64>) -> Result<u64, VotingError> {
+    match size {
+        None => Ok(MAX_PROPOSAL_SIZE),
+        Some(size) if size == 0 || size > MAX_PROPOSAL_SIZE_CEILING => {
+            Err(VotingError::InvalidMaxProposalSize {
+                ceiling: MAX_PROPOSAL_SIZE_CEILING,
+            })
+        }
+        Some(size) => Ok(size),
+    }
+}
+
+/// Limits on the number of proposals which may be open at once in a
+/// proposal module. These protect a DAO from being flooded with
+/// proposals, which wastes voters' attention and the gas of hook
+/// consumers.
+#[cw_serde]
+pub struct MaxOpenProposals {
+    /// The maximum number of open proposals. None for no limit.
+    pub total: Option<u64>,
+    /// The maximum number of open proposals created by a single
+    /// proposer. None for no limit.
+    pub per_proposer: Option<u64>,
+}
+
+impl MaxOpenProposals {
+    pub fn validate(&self) -> Result<(), VotingError> {
+        if self.total == Some(0) || self.per_proposer == Some(0) {
+            return Err(VotingError::ZeroMaxOpenProposals {});
+        }
+        Ok(())
+    }
+
+    /// Checks that PROPOSER may create a proposal, given the
+    /// proposers of the proposals that are currently open. Stops
+    /// reading OPEN once a limit has been reached.
+    pub fn check(
+        &self,
+        proposer: &Addr,
+        open: impl IntoIterator<Item = StdResult<Addr>>,
+    ) -> Result<(), VotingError> {
+        if self.total.is_none() && self.per_proposer.is_none() {
+            return Ok(());
+        }
+        let mut total = 0;
+        let mut by_proposer = 0;
+        for open_proposer in open {
+            total += 1;
+            if let Some(max) = self.total.filter(|max| total >= *max) {
+                return Err(VotingError::TooManyOpenProposals { max });
+            }
+            if &open_proposer? == proposer {
+                by_proposer += 1;
+                if let Some(max) = self.per_proposer.filter(|max| by_proposer >= *max) {
+                    return Err(VotingError::TooManyOpenProposalsByProposer { max });
+                }
+            }
+        }
+        Ok(())
+    }
+}
 
 /// The contents of a message to create a proposal in the single
 /// choice proposal module.
@@ -31,4 +115,406 @@ pub struct SingleChoiceProposeMsg {
     /// pre-propose module is attached, this must be Some and will
     /// set the proposer of the proposal it creates.
     pub proposer: Option<String>,
+    /// If set, only a hash of the description and the URI it may be
+    /// retrieved from are stored, keeping large descriptions off
+    /// chain. `description` must be empty if this is set.
+    pub description_hash: Option<DescriptionHash>,
+    /// The IPFS CID of a document holding the proposal's full
+    /// content. Must be a base58btc CIDv0 (`Qm...`) or a base32
+    /// CIDv1 (`b...`).
+    pub content_cid: Option<String>,
+    /// If true, votes on this proposal may not be changed even if
+    /// the proposal module allows revoting. Revoting can not be
+    /// enabled for a proposal if the module does not allow it.
+    #[serde(default)]
+    pub disable_revoting: bool,
+}
+
+/// The propose message used by dao-proposal-multiple. Broken out
+/// of that contract's `ExecuteMsg` for the same reasons as
+/// `SingleChoiceProposeMsg`.
+#[cw_serde]
+pub struct MultipleChoiceProposeMsg {
+    /// The title of the proposal.
+    pub title: String,
+    /// A description of the proposal.
+    pub description: String,
+    /// The multiple choices.
+    pub choices: MultipleChoiceOptions,
+    /// The address creating the proposal. If no pre-propose
+    /// module is attached to this module this must always be None
+    /// as the proposer is the sender of the propose message. If a
+    /// pre-propose module is attached, this must be Some and will
+    /// set the proposer of the proposal it creates.
+    pub proposer: Option<String>,
+}
+
+/// A commitment to a proposal description stored off chain. Clients
+/// retrieve the description from `uri` and check it against
+/// `sha256` with `DescriptionHash::verify`.
+#[cw_serde]
+pub struct DescriptionHash {
+    /// The SHA-256 hash of the UTF-8 encoded description.
+    pub sha256: Binary,
+    /// Where the description may be retrieved from, for example an
+    /// `ipfs://` or `https://` URI.
+    pub uri: String,
+}
+
+impl DescriptionHash {
+    /// Commits to DESCRIPTION, which may be retrieved from URI.
+    pub fn new(description: &str, uri: impl Into<String>) -> Self {
+        Self {
+            sha256: Binary::from(Sha256::digest(description.as_bytes()).as_slice()),
+            uri: uri.into(),
+        }
+    }
+
+    /// True if DESCRIPTION is the description this commits to.
+    pub fn verify(&self, description: &str) -> bool {
+        Sha256::digest(description.as_bytes()).as_slice() == self.sha256.as_slice()
+    }
+
+    pub fn validate(&self) -> Result<(), VotingError> {
+        if self.sha256.len() != 32 {
+            return Err(VotingError::InvalidDescriptionHash {
+                len: self.sha256.len(),
+            });
+        }
+        if self.uri.is_empty() || self.uri.len() > MAX_DESCRIPTION_URI_LENGTH {
+            return Err(VotingError::InvalidDescriptionUri {
+                max: MAX_DESCRIPTION_URI_LENGTH,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Validates the description of a new proposal. If a description
+/// hash is provided, the description is stored off chain and must be
+/// empty.
+pub fn validate_description(
+    description: &str,
+    description_hash: Option<&DescriptionHash>,
+) -> Result<(), VotingError> {
+    match description_hash {
+        Some(_) if !description.is_empty() => Err(VotingError::DescriptionWithHash {}),
+        Some(hash) => hash.validate(),
+        None => Ok(()),
+    }
+}
+
+/// Reads an unsigned LEB128 varint from the front of BYTES,
+/// returning it and the remaining bytes.
+fn read_varint(bytes: &[u8]) -> Option<(u64, &[u8])> {
+    let mut value = 0u64;
+    for (i, byte) in bytes.iter().enumerate().take(9) {
+        value |= u64::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            return Some((value, &bytes[i + 1..]));
+        }
+    }
+    None
+}
+
+/// Decodes unpadded, lowercase RFC 4648 base32.
+fn decode_base32(s: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(s.len() * 5 / 8);
+    let mut buffer = 0u32;
+    let mut bits = 0u32;
+    for c in s.bytes() {
+        let value = match c {
+            b'a'..=b'z' => c - b'a',
+            b'2'..=b'7' => c - b'2' + 26,
+            _ => return None,
+        };
+        buffer = (buffer << 5) | u32::from(value);
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    Some(bytes)
+}
+
+/// Checks that CID is a well formed IPFS content identifier. CIDv0s
+/// are a base58btc encoded SHA-256 multihash. CIDv1s must use the
+/// base32 multibase and hold a multihash whose digest has the length
+/// it declares. Whether the content is retrievable is not checked.
+pub fn validate_content_cid(cid: &str) -> Result<(), VotingError> {
+    const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+    let valid = if cid.len() > MAX_CONTENT_CID_LENGTH {
+        false
+    } else if cid.starts_with("Qm") {
+        cid.len() == 46 && cid.chars().all(|c| BASE58_ALPHABET.contains(c))
+    } else if let Some(encoded) = cid.strip_prefix('b') {
+        decode_base32(encoded)
+            .as_deref()
+            .and_then(read_varint)
+            .filter(|(version, _)| *version == 1)
+            .and_then(|(_, rest)| read_varint(rest))
+            .and_then(|(_codec, rest)| read_varint(rest))
+            .and_then(|(_hash_code, rest)| read_varint(rest))
+            .map_or(false, |(len, digest)| len > 0 && digest.len() as u64 == len)
+    } else {
+        false
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(VotingError::InvalidContentCid {
+            max: MAX_CONTENT_CID_LENGTH,
+        })
+    }
+}
+
+/// What remains of a proposal after it has been pruned. Pruning
+/// deletes a proposal's messages, description, and ballots, keeping
+/// only enough to tell what the proposal was and how it ended.
+#[cw_serde]
+pub struct PrunedProposal {
+    pub title: String,
+    pub proposer: Addr,
+    /// The block height at which the proposal was created.
+    pub start_height: u64,
+    /// The status the proposal had when it was pruned.
+    pub status: Status,
+}
+
+/// A position in the rebuilding of a proposal module's indexes.
+/// Proposals are re-saved first, in order of ID, followed by ballots,
+/// in order of proposal ID and then voter.
+#[cw_serde]
+pub enum RebuildIndexesCursor {
+    /// The ID of the last proposal re-saved.
+    Proposal { id: u64 },
+    /// The last ballot re-saved.
+    Ballot { proposal_id: u64, voter: String },
+}
+
+/// Validates the statuses of proposals to be pruned. Only proposals
+/// whose status can no longer change may be pruned.
+pub fn validate_prune_statuses(statuses: &[Status]) -> Result<(), VotingError> {
+    match statuses.iter().find(|status| !status.is_final()) {
+        Some(status) => Err(VotingError::InvalidPruneStatus { status: *status }),
+        None => Ok(()),
+    }
+}
+
+/// The key proposals are indexed by in an expiration index. Heights
+/// and times can not be compared, so each kind of expiration is kept
+/// under its own prefix: 0 for heights, 1 for times, and 2 for
+/// proposals which never expire.
+pub fn expiration_index_key(expiration: &Expiration) -> (u8, u64) {
+    match expiration {
+        Expiration::AtHeight(height) => (0, *height),
+        Expiration::AtTime(time) => (1, time.nanos()),
+        Expiration::Never {} => (2, 0),
+    }
+}
+
+/// Range bounds over an expiration index prefix, whose keys are an
+/// expiration and a proposal ID.
+pub type ExpirationIndexBounds<'a> = (
+    u8,
+    Option<Bound<'a, (u64, u64)>>,
+    Option<Bound<'a, (u64, u64)>>,
+);
+
+/// Gets the prefix of an expiration index and the bounds within it
+/// that cover proposals expiring strictly after AFTER and strictly
+/// before BEFORE. At least one of the two must be set, and if both
+/// are set they must both be heights or both be times.
+pub fn expiration_index_bounds<'a>(
+    after: Option<Expiration>,
+    before: Option<Expiration>,
+) -> StdResult<ExpirationIndexBounds<'a>> {
+    let after = after.as_ref().map(expiration_index_key);
+    let before = before.as_ref().map(expiration_index_key);
+    let prefix = match (after, before) {
+        (Some((after, _)), Some((before, _))) if after != before => {
+            return Err(StdError::generic_err(
+                "after and before must both be heights or both be times",
+            ))
+        }
+        (Some((prefix, _)), _) | (None, Some((prefix, _))) => prefix,
+        (None, None) => return Err(StdError::generic_err("after or before must be set")),
+    };
+    // Proposal IDs break ties between proposals with the same
+    // expiration, so these bounds exclude every proposal expiring
+    // at AFTER or BEFORE.
+    Ok((
+        prefix,
+        after.map(|(_, after)| Bound::exclusive((after, u64::MAX))),
+        before.map(|(_, before)| Bound::exclusive((before, 0))),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_description_hash() {
+        let hash = DescriptionHash::new("a very long description", "ipfs://description");
+        hash.validate().unwrap();
+        assert!(hash.verify("a very long description"));
+        assert!(!hash.verify("a different description"));
+
+        assert_eq!(validate_description("", Some(&hash)), Ok(()));
+        assert_eq!(validate_description("summary", None), Ok(()));
+        assert_eq!(
+            validate_description("summary", Some(&hash)),
+            Err(VotingError::DescriptionWithHash {})
+        );
+    }
+
+    #[test]
+    fn test_validate_content_cid() {
+        validate_content_cid("QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG").unwrap();
+        validate_content_cid("bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi")
+            .unwrap();
+        validate_content_cid("bafkreidgvpkjawlxz6sffxzwgooowe5yt7i6wsyg236mfoks77nywkptdq")
+            .unwrap();
+
+        for invalid in [
+            "",
+            "ipfs://QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG",
+            // Too short.
+            "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbd",
+            // '0' is not in the base58 alphabet.
+            "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbd0",
+            // Uppercase base32 uses a different multibase prefix.
+            "BAFYBEIGDYRZT5SFP7UDM7HU76UH7Y26NF3EFUYLQABF3OCLGTQY55FBZDI",
+            // Truncated digest.
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55f",
+        ] {
+            assert_eq!(
+                validate_content_cid(invalid),
+                Err(VotingError::InvalidContentCid {
+                    max: MAX_CONTENT_CID_LENGTH
+                }),
+                "{invalid}"
+            );
+        }
+        assert!(validate_content_cid(&format!("b{}", "a".repeat(MAX_CONTENT_CID_LENGTH))).is_err());
+    }
+
+    #[test]
+    fn test_expiration_index_bounds() {
+        assert_eq!(expiration_index_key(&Expiration::AtHeight(10)), (0, 10));
+        assert_eq!(
+            expiration_index_key(&Expiration::AtTime(cosmwasm_std::Timestamp::from_nanos(10))),
+            (1, 10)
+        );
+        assert_eq!(expiration_index_key(&Expiration::Never {}), (2, 0));
+
+        let (prefix, min, max) =
+            expiration_index_bounds(Some(Expiration::AtHeight(1)), None).unwrap();
+        assert_eq!(prefix, 0);
+        assert!(min.is_some() && max.is_none());
+
+        let (prefix, ..) = expiration_index_bounds(
+            None,
+            Some(Expiration::AtTime(cosmwasm_std::Timestamp::from_seconds(1))),
+        )
+        .unwrap();
+        assert_eq!(prefix, 1);
+
+        expiration_index_bounds(None, None).unwrap_err();
+        expiration_index_bounds(
+            Some(Expiration::AtHeight(1)),
+            Some(Expiration::AtTime(cosmwasm_std::Timestamp::from_seconds(1))),
+        )
+        .unwrap_err();
+    }
+
+    #[test]
+    fn test_validate_prune_statuses() {
+        assert_eq!(
+            validate_prune_statuses(&[Status::Closed, Status::Executed, Status::ExecutionFailed]),
+            Ok(())
+        );
+        assert_eq!(validate_prune_statuses(&[]), Ok(()));
+        assert_eq!(
+            validate_prune_statuses(&[Status::Closed, Status::Rejected]),
+            Err(VotingError::InvalidPruneStatus {
+                status: Status::Rejected
+            })
+        );
+    }
+
+    #[test]
+    fn test_max_open_proposals() {
+        let ekez = Addr::unchecked("ekez");
+        let keze = Addr::unchecked("keze");
+        let open = |proposers: &[&Addr]| -> Vec<StdResult<Addr>> {
+            proposers.iter().map(|p| Ok((*p).clone())).collect()
+        };
+
+        let limits = MaxOpenProposals {
+            total: Some(3),
+            per_proposer: Some(2),
+        };
+        limits.validate().unwrap();
+        limits.check(&ekez, open(&[])).unwrap();
+        limits.check(&ekez, open(&[&ekez, &keze])).unwrap();
+        assert_eq!(
+            limits.check(&ekez, open(&[&keze, &ekez, &ekez])),
+            Err(VotingError::TooManyOpenProposals { max: 3 })
+        );
+        assert_eq!(
+            limits.check(&ekez, open(&[&ekez, &ekez])),
+            Err(VotingError::TooManyOpenProposalsByProposer { max: 2 })
+        );
+        limits.check(&keze, open(&[&ekez, &ekez])).unwrap();
+
+        let unlimited = MaxOpenProposals {
+            total: None,
+            per_proposer: None,
+        };
+        unlimited
+            .check(&ekez, open(&[&ekez, &ekez, &ekez, &ekez]))
+            .unwrap();
+
+        assert_eq!(
+            MaxOpenProposals {
+                total: Some(0),
+                per_proposer: None
+            }
+            .validate(),
+            Err(VotingError::ZeroMaxOpenProposals {})
+        );
+    }
+
+    #[test]
+    fn test_invalid_description_hash() {
+        let hash = DescriptionHash {
+            sha256: Binary::from(b"too short"),
+            uri: "ipfs://description".to_string(),
+        };
+        assert_eq!(
+            hash.validate(),
+            Err(VotingError::InvalidDescriptionHash { len: 9 })
+        );
+
+        let hash = DescriptionHash::new("description", "");
+        assert_eq!(
+            hash.validate(),
+            Err(VotingError::InvalidDescriptionUri {
+                max: MAX_DESCRIPTION_URI_LENGTH
+            })
+        );
+
+        let hash = DescriptionHash::new("description", "a".repeat(MAX_DESCRIPTION_URI_LENGTH + 1));
+        assert_eq!(
+            hash.validate(),
+            Err(VotingError::InvalidDescriptionUri {
+                max: MAX_DESCRIPTION_URI_LENGTH
+            })
+        );
+    }
 }
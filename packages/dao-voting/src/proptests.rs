@@ -0,0 +1,175 @@
+//! Property based tests for threshold and tally math. Every proposal
+//! module relies on these functions, so we check that they hold up
+//! for arbitrary voting power distributions and vote sequences.
+
+use std::collections::BTreeMap;
+
+use cosmwasm_std::{Decimal, Uint128};
+use proptest::prelude::*;
+
+use crate::{
+    multiple_choice::{MultipleChoiceVote, MultipleChoiceVotes},
+    threshold::PercentageThreshold,
+    voting::{does_vote_count_fail, does_vote_count_pass, Vote, Votes},
+};
+
+/// Thresholds are generated with basis point precision, which covers
+/// any threshold a DAO would configure in practice.
+fn percent() -> impl Strategy<Value = Decimal> {
+    (1u64..=10_000).prop_map(|bps| Decimal::from_ratio(bps, 10_000u64))
+}
+
+fn percentage_threshold() -> impl Strategy<Value = PercentageThreshold> {
+    prop_oneof![
+        Just(PercentageThreshold::Majority {}),
+        percent().prop_map(PercentageThreshold::Percent),
+    ]
+}
+
+fn vote() -> impl Strategy<Value = Vote> {
+    prop_oneof![Just(Vote::Yes), Just(Vote::No), Just(Vote::Abstain)]
+}
+
+/// Generates the power of some voters along with a sequence of
+/// (voter, vote) pairs. Later votes by a voter replace earlier ones.
+fn voters_and_votes() -> impl Strategy<Value = (Vec<u64>, Vec<(usize, Vote)>)> {
+    prop::collection::vec(1u64..1_000_000_000, 1..20).prop_flat_map(|powers| {
+        let voters = powers.len();
+        (
+            Just(powers),
+            prop::collection::vec((0..voters, vote()), 0..60),
+        )
+    })
+}
+
+proptest! {
+    #[test]
+    fn passing_is_monotonic_in_yes_votes(
+        options in 0u64..u64::MAX,
+        yes in 0u64..u64::MAX,
+        extra in 0u64..u64::MAX,
+        threshold in percentage_threshold(),
+    ) {
+        let yes = yes.min(options);
+        let more_yes = yes.saturating_add(extra).min(options);
+        if does_vote_count_pass(Uint128::from(yes), Uint128::from(options), threshold) {
+            prop_assert!(does_vote_count_pass(
+                Uint128::from(more_yes),
+                Uint128::from(options),
+                threshold
+            ));
+        }
+    }
+
+    #[test]
+    fn failing_is_monotonic_in_no_votes(
+        options in 0u64..u64::MAX,
+        no in 0u64..u64::MAX,
+        extra in 0u64..u64::MAX,
+        threshold in percentage_threshold(),
+    ) {
+        let no = no.min(options);
+        let more_no = no.saturating_add(extra).min(options);
+        if does_vote_count_fail(Uint128::from(no), Uint128::from(options), threshold) {
+            prop_assert!(does_vote_count_fail(
+                Uint128::from(more_no),
+                Uint128::from(options),
+                threshold
+            ));
+        }
+    }
+
+    #[test]
+    fn lower_thresholds_are_easier_to_pass(
+        options in 0u64..u64::MAX,
+        yes in 0u64..u64::MAX,
+        a in percent(),
+        b in percent(),
+    ) {
+        let yes = Uint128::from(yes.min(options));
+        let options = Uint128::from(options);
+        let (low, high) = if a <= b { (a, b) } else { (b, a) };
+        if does_vote_count_pass(yes, options, PercentageThreshold::Percent(high)) {
+            prop_assert!(does_vote_count_pass(yes, options, PercentageThreshold::Percent(low)));
+        }
+        // A majority is strictly more than 50%, so anything passing
+        // with a majority also passes with 50%.
+        if does_vote_count_pass(yes, options, PercentageThreshold::Majority {}) {
+            prop_assert!(does_vote_count_pass(
+                yes,
+                options,
+                PercentageThreshold::Percent(Decimal::percent(50))
+            ));
+        }
+    }
+
+    #[test]
+    fn never_passed_and_rejected(
+        options in 0u64..u64::MAX,
+        yes in 0u64..u64::MAX,
+        no in 0u64..u64::MAX,
+        threshold in percentage_threshold(),
+    ) {
+        let yes = yes.min(options);
+        let no = no.min(options - yes);
+        let options = Uint128::from(options);
+        prop_assert!(
+            !(does_vote_count_pass(Uint128::from(yes), options, threshold)
+                && does_vote_count_fail(Uint128::from(no), options, threshold))
+        );
+    }
+
+    #[test]
+    fn revoting_is_neutral((powers, votes) in voters_and_votes()) {
+        let mut tally = Votes::zero();
+        let mut ballots: BTreeMap<usize, Vote> = BTreeMap::new();
+        for (voter, vote) in votes {
+            let power = Uint128::from(powers[voter]);
+            if let Some(old) = ballots.insert(voter, vote) {
+                tally.remove_vote(old, power);
+            }
+            tally.add_vote(vote, power);
+        }
+
+        // The tally matches one built from only the final ballots.
+        let mut expected = Votes::zero();
+        for (voter, vote) in ballots.iter() {
+            expected.add_vote(*vote, Uint128::from(powers[*voter]));
+        }
+        prop_assert_eq!(&tally, &expected);
+
+        let voted: Uint128 = ballots.keys().map(|voter| Uint128::from(powers[*voter])).sum();
+        prop_assert_eq!(tally.total(), voted);
+    }
+
+    #[test]
+    fn multiple_choice_revoting_is_neutral(
+        (powers, votes) in voters_and_votes(),
+        choices in 2usize..10,
+    ) {
+        let mut tally = MultipleChoiceVotes::zero(choices);
+        let mut ballots: BTreeMap<usize, MultipleChoiceVote> = BTreeMap::new();
+        for (voter, vote) in votes {
+            let power = Uint128::from(powers[voter]);
+            // Reuse the generated vote to pick an option.
+            let vote = MultipleChoiceVote {
+                option_id: (vote as u32 + voter as u32) % choices as u32,
+            };
+            if let Some(old) = ballots.insert(voter, vote) {
+                tally.remove_vote(old, power).unwrap();
+            }
+            tally.add_vote(vote, power).unwrap();
+        }
+
+        let mut expected = MultipleChoiceVotes::zero(choices);
+        for (voter, vote) in ballots.iter() {
+            expected
+                .add_vote(*vote, Uint128::from(powers[*voter]))
+                .unwrap();
+        }
+        prop_assert_eq!(&tally, &expected);
+
+        let voted: Uint128 = ballots.keys().map(|voter| Uint128::from(powers[*voter])).sum();
+        prop_assert_eq!(tally.total(), voted);
+    }
+}
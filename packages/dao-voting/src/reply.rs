@@ -1,3 +1,5 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Addr;
 use dao_macros::limit_variant_count;
 
 const FAILED_PROPOSAL_EXECUTION_MASK: u64 = 0b000;
@@ -76,6 +78,34 @@ pub const fn failed_pre_propose_module_hook_id() -> u64 {
     FAILED_PRE_PROPOSE_MODULE_HOOK_ID
 }
 
+/// The number of hook failures a proposal module keeps a record
+/// of. Older records are dropped as new ones are added.
+pub const MAX_HOOK_FAILURE_HISTORY: usize = 32;
+
+/// The kinds of hooks a proposal module calls.
+#[cw_serde]
+pub enum HookKind {
+    Proposal,
+    Vote,
+    PreProposeModule,
+}
+
+/// A record of a hook erroring when called by a proposal module.
+#[cw_serde]
+pub struct HookFailureRecord {
+    pub kind: HookKind,
+    /// The address of the hook which failed.
+    pub hook: Addr,
+    /// The error the hook failed with.
+    pub reason: String,
+    /// The block height at which the hook failed.
+    pub height: u64,
+    /// The ID of the reply the failure was reported with.
+    pub reply_id: u64,
+    /// True if the hook was removed because of this failure.
+    pub removed: bool,
+}
+
 pub mod error {
     use thiserror::Error;
 
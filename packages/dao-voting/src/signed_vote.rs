@@ -0,0 +1,156 @@
+//! Verification of votes signed off-chain with [ADR-036] arbitrary
+//! message signatures, allowing a relayer to submit votes on behalf
+//! of voters.
+//!
+//! [ADR-036]: https://github.com/cosmos/cosmos-sdk/blob/main/docs/architecture/adr-036-arbitrary-signature.md
+
+use bech32::{ToBase32, Variant};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{to_vec, Addr, Api, Binary, StdError};
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::voting::Vote;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum SignedVoteError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Invalid bech32 address ({address})")]
+    InvalidAddress { address: String },
+
+    #[error("Invalid signature for vote by ({voter})")]
+    InvalidSignature { voter: String },
+}
+
+/// The data a voter signs. The proposal module is included so that
+/// a signature may not be replayed against another module.
+#[cw_serde]
+pub struct VotePayload {
+    pub proposal_module: String,
+    pub proposal_id: u64,
+    pub vote: Vote,
+}
+
+/// A vote signed off-chain.
+#[cw_serde]
+pub struct SignedVote {
+    pub proposal_id: u64,
+    pub vote: Vote,
+    /// The voter's compressed secp256k1 public key. The voter's
+    /// address is derived from this.
+    pub pubkey: Binary,
+    /// The ADR-036 signature over the `VotePayload` for this vote.
+    pub signature: Binary,
+}
+
+/// Derives the address for a secp256k1 public key using the bech32
+/// prefix of `prefix_of`.
+pub fn pubkey_to_address(prefix_of: &Addr, pubkey: &[u8]) -> Result<Addr, SignedVoteError> {
+    let invalid = || SignedVoteError::InvalidAddress {
+        address: prefix_of.to_string(),
+    };
+    let (hrp, _, _) = bech32::decode(prefix_of.as_str()).map_err(|_| invalid())?;
+    let hash = Ripemd160::digest(Sha256::digest(pubkey));
+    let address = bech32::encode(&hrp, hash.to_base32(), Variant::Bech32).map_err(|_| invalid())?;
+    Ok(Addr::unchecked(address))
+}
+
+/// The ADR-036 sign doc for `data` signed by `signer`. Keys are
+/// sorted and whitespace omitted as in amino JSON signing.
+pub fn adr36_sign_doc(signer: &Addr, data: &[u8]) -> String {
+    format!(
+        r#"{{"account_number":"0","chain_id":"","fee":{{"amount":[],"gas":"0"}},"memo":"","msgs":[{{"type":"sign/MsgSignData","value":{{"data":"{}","signer":"{}"}}}}],"sequence":"0"}}"#,
+        Binary::from(data).to_base64(),
+        signer
+    )
+}
+
+/// Verifies a signed vote cast on `proposal_module`, returning the
+/// address of the voter.
+pub fn verify_signed_vote(
+    api: &dyn Api,
+    proposal_module: &Addr,
+    vote: &SignedVote,
+) -> Result<Addr, SignedVoteError> {
+    let voter = pubkey_to_address(proposal_module, &vote.pubkey)?;
+    let payload = to_vec(&VotePayload {
+        proposal_module: proposal_module.to_string(),
+        proposal_id: vote.proposal_id,
+        vote: vote.vote,
+    })?;
+    let hash = Sha256::digest(adr36_sign_doc(&voter, &payload).as_bytes());
+    let valid = api
+        .secp256k1_verify(&hash, &vote.signature, &vote.pubkey)
+        .map_err(|e| StdError::generic_err(e.to_string()))?;
+    if !valid {
+        return Err(SignedVoteError::InvalidSignature {
+            voter: voter.into_string(),
+        });
+    }
+    Ok(voter)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_dependencies;
+
+    use super::*;
+
+    const PROPOSAL_MODULE: &str = "juno1thklh702txwafc72d2qtxv7ywt7sk0mfyex7e4";
+    const VOTER: &str = "juno1ptqd6902tgrvl5zsj2dm5q4hvvdajh35yas0ls";
+    const PUBKEY: &str = "A/lzoLhwYsOJ0SXYGZ6AO4Mrasa/eGek9s2HUGBg/ExY";
+    const SIGNATURE: &str =
+        "PmhlLm1K92s8wuyK0mQNJW2p4f/LL19CADqls2pGLMQ2jegaccrQjCy1Si9M8DG2cNqoIBO3pF0+Q/FGyCC8GA==";
+
+    fn signed_vote() -> SignedVote {
+        SignedVote {
+            proposal_id: 1,
+            vote: Vote::Yes,
+            pubkey: Binary::from_base64(PUBKEY).unwrap(),
+            signature: Binary::from_base64(SIGNATURE).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_pubkey_to_address() {
+        let pubkey = Binary::from_base64(PUBKEY).unwrap();
+        assert_eq!(
+            pubkey_to_address(&Addr::unchecked(PROPOSAL_MODULE), &pubkey).unwrap(),
+            Addr::unchecked(VOTER)
+        );
+        assert_eq!(
+            pubkey_to_address(&Addr::unchecked("contract0"), &pubkey).unwrap_err(),
+            SignedVoteError::InvalidAddress {
+                address: "contract0".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_verify_signed_vote() {
+        let deps = mock_dependencies();
+        let module = Addr::unchecked(PROPOSAL_MODULE);
+
+        let voter = verify_signed_vote(&deps.api, &module, &signed_vote()).unwrap();
+        assert_eq!(voter, Addr::unchecked(VOTER));
+
+        // The signature does not cover a different vote.
+        let mut vote = signed_vote();
+        vote.vote = Vote::No;
+        let err = verify_signed_vote(&deps.api, &module, &vote).unwrap_err();
+        assert_eq!(
+            err,
+            SignedVoteError::InvalidSignature {
+                voter: VOTER.to_string()
+            }
+        );
+
+        // Nor a different proposal.
+        let mut vote = signed_vote();
+        vote.proposal_id = 2;
+        verify_signed_vote(&deps.api, &module, &vote).unwrap_err();
+    }
+}
@@ -0,0 +1,200 @@
+use std::collections::BTreeMap;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    Addr, BankMsg, Binary, Coin, CosmosMsg, Deps, Empty, StdError, StdResult, Uint128, WasmMsg,
+};
+
+/// A problem found by statically checking a proposal's messages
+/// before they are executed.
+#[cw_serde]
+pub enum ExecutionWarning {
+    /// An address the message sends to or manages is not valid.
+    InvalidAddress { msg_index: u32, address: String },
+    /// The message executes a contract whose interface is known, but
+    /// does not deserialize as one of that contract's execute
+    /// messages.
+    InvalidExecuteMsg {
+        msg_index: u32,
+        contract: Addr,
+        error: String,
+    },
+    /// The DAO does not hold enough of a native token to cover the
+    /// tokens sent, burned, and attached as funds by the proposal's
+    /// messages.
+    InsufficientBalance {
+        denom: String,
+        required: Uint128,
+        balance: Uint128,
+    },
+}
+
+#[cw_serde]
+pub struct SimulationResponse {
+    /// Empty if no problems were found. Messages are only checked
+    /// statically, so a proposal without warnings may still fail to
+    /// execute.
+    pub warnings: Vec<ExecutionWarning>,
+}
+
+/// Statically checks messages that will be executed by the DAO.
+/// Checks that addresses are valid, that native tokens sent from the
+/// DAO's treasury are held by it, and that messages to contracts
+/// whose interface is known deserialize. `check_execute_msg` is
+/// called with the target and message of every wasm execute message
+/// with a valid target, and returns an error if the target is known
+/// and the message is not one of its execute messages.
+pub fn simulate_execution(
+    deps: Deps,
+    dao: &Addr,
+    msgs: &[CosmosMsg<Empty>],
+    check_execute_msg: impl Fn(&Addr, &Binary) -> Option<StdError>,
+) -> StdResult<SimulationResponse> {
+    let mut warnings = vec![];
+    let mut required: BTreeMap<String, Uint128> = BTreeMap::new();
+    let mut spend = |coins: &[Coin]| -> StdResult<()> {
+        for coin in coins {
+            let total = required.entry(coin.denom.clone()).or_default();
+            *total = total.checked_add(coin.amount)?;
+        }
+        Ok(())
+    };
+
+    for (msg_index, msg) in msgs.iter().enumerate() {
+        let msg_index = msg_index as u32;
+        let mut validate = |address: &str| -> Option<Addr> {
+            let addr = deps.api.addr_validate(address).ok();
+            if addr.is_none() {
+                warnings.push(ExecutionWarning::InvalidAddress {
+                    msg_index,
+                    address: address.to_string(),
+                });
+            }
+            addr
+        };
+        match msg {
+            CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+                validate(to_address);
+                spend(amount)?;
+            }
+            CosmosMsg::Bank(BankMsg::Burn { amount }) => spend(amount)?,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr,
+                msg,
+                funds,
+            }) => {
+                if let Some(contract) = validate(contract_addr) {
+                    if let Some(error) = check_execute_msg(&contract, msg) {
+                        warnings.push(ExecutionWarning::InvalidExecuteMsg {
+                            msg_index,
+                            contract,
+                            error: error.to_string(),
+                        });
+                    }
+                }
+                spend(funds)?;
+            }
+            CosmosMsg::Wasm(WasmMsg::Instantiate { admin, funds, .. }) => {
+                if let Some(admin) = admin {
+                    validate(admin);
+                }
+                spend(funds)?;
+            }
+            CosmosMsg::Wasm(WasmMsg::UpdateAdmin {
+                contract_addr,
+                admin,
+            }) => {
+                validate(contract_addr);
+                validate(admin);
+            }
+            CosmosMsg::Wasm(
+                WasmMsg::Migrate { contract_addr, .. } | WasmMsg::ClearAdmin { contract_addr },
+            ) => {
+                validate(contract_addr);
+            }
+            _ => (),
+        }
+    }
+
+    for (denom, required) in required {
+        let balance = deps.querier.query_balance(dao, &denom)?.amount;
+        if balance < required {
+            warnings.push(ExecutionWarning::InsufficientBalance {
+                denom,
+                required,
+                balance,
+            });
+        }
+    }
+
+    Ok(SimulationResponse { warnings })
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{coins, testing::mock_dependencies, to_binary};
+
+    use super::*;
+
+    #[test]
+    fn test_simulate_execution() {
+        let mut deps = mock_dependencies();
+        deps.querier.update_balance("dao", coins(100, "ujuno"));
+        let dao = Addr::unchecked("dao");
+        let msgs: Vec<CosmosMsg> = vec![
+            BankMsg::Send {
+                to_address: "A".to_string(),
+                amount: coins(60, "ujuno"),
+            }
+            .into(),
+            WasmMsg::Execute {
+                contract_addr: "dao".to_string(),
+                msg: to_binary("bad").unwrap(),
+                funds: coins(50, "ujuno"),
+            }
+            .into(),
+            WasmMsg::Execute {
+                contract_addr: "other".to_string(),
+                msg: to_binary("bad").unwrap(),
+                funds: vec![],
+            }
+            .into(),
+            BankMsg::Burn {
+                amount: coins(1, "uatom"),
+            }
+            .into(),
+        ];
+
+        let res = simulate_execution(deps.as_ref(), &dao, &msgs, |contract, _| {
+            (contract == &dao).then(|| StdError::generic_err("unknown message"))
+        })
+        .unwrap();
+        assert_eq!(
+            res.warnings,
+            vec![
+                ExecutionWarning::InvalidAddress {
+                    msg_index: 0,
+                    address: "A".to_string(),
+                },
+                ExecutionWarning::InvalidExecuteMsg {
+                    msg_index: 1,
+                    contract: dao.clone(),
+                    error: "Generic error: unknown message".to_string(),
+                },
+                ExecutionWarning::InsufficientBalance {
+                    denom: "uatom".to_string(),
+                    required: Uint128::new(1),
+                    balance: Uint128::zero(),
+                },
+                ExecutionWarning::InsufficientBalance {
+                    denom: "ujuno".to_string(),
+                    required: Uint128::new(110),
+                    balance: Uint128::new(100),
+                },
+            ]
+        );
+
+        let res = simulate_execution(deps.as_ref(), &dao, &msgs[2..3], |_, _| None).unwrap();
+        assert!(res.warnings.is_empty());
+    }
+}
@@ -0,0 +1,176 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, Empty, StdResult, Storage, Uint128};
+use cw_storage_plus::{Item, Map};
+
+/// Counts of proposal and voting activity in a proposal module.
+#[cw_serde]
+#[derive(Default)]
+pub struct GovernanceTotals {
+    /// The number of proposals created.
+    pub proposals: u64,
+    /// The number of proposals executed, including those whose
+    /// messages failed to execute.
+    pub executed: u64,
+    /// The number of executed proposals whose messages failed to
+    /// execute.
+    pub execution_failed: u64,
+    /// The number of proposals closed after being rejected.
+    pub closed: u64,
+    /// The number of ballots cast. Changed votes are not counted.
+    pub votes: u64,
+    /// The number of addresses which have voted.
+    pub unique_voters: u64,
+    /// The sum of the turnout of every executed and closed proposal.
+    pub turnout_sum: Decimal,
+}
+
+#[cw_serde]
+pub struct GovernanceStatsResponse {
+    pub totals: GovernanceTotals,
+    /// The number of proposals which have not been executed or
+    /// closed. These are open, passed and waiting to be executed, or
+    /// rejected and waiting to be closed.
+    pub pending: u64,
+    /// The average turnout of executed and closed proposals.
+    pub average_turnout: Decimal,
+    /// The portion of executed and closed proposals which were
+    /// executed.
+    pub pass_rate: Decimal,
+}
+
+/// Maintains governance statistics as proposals are created, voted
+/// on, and completed so they may be queried without iterating over
+/// every proposal. Statistics are counted from when a proposal
+/// module starts maintaining them.
+pub struct GovernanceStats<'a> {
+    totals: Item<'a, GovernanceTotals>,
+    voters: Map<'a, &'a Addr, Empty>,
+}
+
+impl<'a> GovernanceStats<'a> {
+    pub const fn new(totals_key: &'a str, voters_namespace: &'a str) -> Self {
+        Self {
+            totals: Item::new(totals_key),
+            voters: Map::new(voters_namespace),
+        }
+    }
+
+    fn update(
+        &self,
+        storage: &mut dyn Storage,
+        f: impl FnOnce(&mut GovernanceTotals),
+    ) -> StdResult<()> {
+        let mut totals = self.totals.may_load(storage)?.unwrap_or_default();
+        f(&mut totals);
+        self.totals.save(storage, &totals)
+    }
+
+    pub fn proposal_created(&self, storage: &mut dyn Storage) -> StdResult<()> {
+        self.update(storage, |totals| totals.proposals += 1)
+    }
+
+    /// Records VOTER casting a ballot. Must not be called when a
+    /// voter changes their vote.
+    pub fn vote_cast(&self, storage: &mut dyn Storage, voter: &Addr) -> StdResult<()> {
+        let first_vote = !self.voters.has(storage, voter);
+        if first_vote {
+            self.voters.save(storage, voter, &Empty {})?;
+        }
+        self.update(storage, |totals| {
+            totals.votes += 1;
+            if first_vote {
+                totals.unique_voters += 1;
+            }
+        })
+    }
+
+    /// Records a proposal being executed or closed. The proposal's
+    /// turnout is the portion of TOTAL_POWER which voted on it.
+    pub fn proposal_completed(
+        &self,
+        storage: &mut dyn Storage,
+        executed: bool,
+        votes: Uint128,
+        total_power: Uint128,
+    ) -> StdResult<()> {
+        let turnout = Decimal::checked_from_ratio(votes, total_power).unwrap_or_default();
+        self.update(storage, |totals| {
+            if executed {
+                totals.executed += 1;
+            } else {
+                totals.closed += 1;
+            }
+            totals.turnout_sum += turnout;
+        })
+    }
+
+    /// Records the messages of an executed proposal failing to
+    /// execute.
+    pub fn execution_failed(&self, storage: &mut dyn Storage) -> StdResult<()> {
+        self.update(storage, |totals| totals.execution_failed += 1)
+    }
+
+    pub fn query(&self, storage: &dyn Storage) -> StdResult<GovernanceStatsResponse> {
+        let totals = self.totals.may_load(storage)?.unwrap_or_default();
+        let completed = totals.executed + totals.closed;
+        Ok(GovernanceStatsResponse {
+            pending: totals.proposals.saturating_sub(completed),
+            average_turnout: match completed {
+                0 => Decimal::zero(),
+                completed => Decimal::new(totals.turnout_sum.atomics() / Uint128::from(completed)),
+            },
+            pass_rate: Decimal::checked_from_ratio(totals.executed, completed).unwrap_or_default(),
+            totals,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_dependencies;
+
+    use super::*;
+
+    #[test]
+    fn test_governance_stats() {
+        let mut deps = mock_dependencies();
+        let storage = &mut deps.storage;
+        let stats = GovernanceStats::new("stats", "stats_voters");
+
+        let empty = stats.query(storage).unwrap();
+        assert_eq!(empty.totals, GovernanceTotals::default());
+        assert_eq!(empty.average_turnout, Decimal::zero());
+        assert_eq!(empty.pass_rate, Decimal::zero());
+
+        for _ in 0..3 {
+            stats.proposal_created(storage).unwrap();
+        }
+        stats.vote_cast(storage, &Addr::unchecked("ekez")).unwrap();
+        stats.vote_cast(storage, &Addr::unchecked("ekez")).unwrap();
+        stats.vote_cast(storage, &Addr::unchecked("zeke")).unwrap();
+        stats
+            .proposal_completed(storage, true, Uint128::new(60), Uint128::new(100))
+            .unwrap();
+        stats
+            .proposal_completed(storage, false, Uint128::new(20), Uint128::new(100))
+            .unwrap();
+        stats.execution_failed(storage).unwrap();
+
+        let res = stats.query(storage).unwrap();
+        assert_eq!(
+            res.totals,
+            GovernanceTotals {
+                proposals: 3,
+                executed: 1,
+                execution_failed: 1,
+                closed: 1,
+                votes: 3,
+                unique_voters: 2,
+                turnout_sum: Decimal::percent(80),
+            }
+        );
+        assert_eq!(res.pending, 1);
+        assert_eq!(res.average_turnout, Decimal::percent(40));
+        assert_eq!(res.pass_rate, Decimal::percent(50));
+    }
+}
@@ -17,6 +17,22 @@ pub enum Status {
     Closed,
     /// The proposal's execution failed.
     ExecutionFailed,
+    /// The proposal was withdrawn by its proposer before
+    /// completing. A proposal deposit refund has been issued if
+    /// applicable.
+    Cancelled,
+}
+
+impl Status {
+    /// Returns true if a proposal with this status may never change
+    /// status again. Rejected proposals are not final as they may
+    /// still be closed.
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            Status::Executed | Status::Closed | Status::ExecutionFailed | Status::Cancelled
+        )
+    }
 }
 
 impl std::fmt::Display for Status {
@@ -28,6 +44,7 @@ impl std::fmt::Display for Status {
             Status::Executed => write!(f, "executed"),
             Status::Closed => write!(f, "closed"),
             Status::ExecutionFailed => write!(f, "execution_failed"),
+            Status::Cancelled => write!(f, "cancelled"),
         }
     }
 }
@@ -3,6 +3,8 @@ use cosmwasm_std::{Decimal, Uint128};
 
 use thiserror::Error;
 
+use crate::voting::Votes;
+
 #[derive(Error, Debug, PartialEq, Eq)]
 pub enum ThresholdError {
     #[error("Required threshold cannot be zero")]
@@ -68,6 +70,105 @@ pub enum Threshold {
     AbsoluteCount { threshold: Uint128 },
 }
 
+/// How abstain votes are counted when checking a proposal's votes
+/// against its threshold and quorum.
+#[cw_serde]
+#[derive(Copy)]
+pub enum AbstainCounting {
+    /// Abstain votes count towards quorum, but are excluded from the
+    /// voting power percentage thresholds are computed against.
+    Quorum {},
+    /// Abstain votes count towards quorum and are included in the
+    /// voting power percentage thresholds are computed against, so
+    /// they count against a proposal passing in the same way as no
+    /// votes.
+    QuorumAndThreshold {},
+    /// Abstain votes count towards neither quorum nor thresholds.
+    Neither {},
+}
+
+impl Default for AbstainCounting {
+    fn default() -> Self {
+        AbstainCounting::Quorum {}
+    }
+}
+
+impl AbstainCounting {
+    /// The votes which count towards a proposal's quorum.
+    pub fn quorum_votes(&self, votes: &Votes) -> Uint128 {
+        match self {
+            AbstainCounting::Neither {} => votes.yes + votes.no,
+            _ => votes.total(),
+        }
+    }
+
+    /// The voting power a percentage threshold is computed against,
+    /// where POWER is either the total voting power or the total
+    /// votes cast.
+    pub fn threshold_options(&self, power: Uint128, votes: &Votes) -> Uint128 {
+        match self {
+            AbstainCounting::QuorumAndThreshold {} => power,
+            _ => power - votes.abstain,
+        }
+    }
+
+    /// The votes which count against a proposal reaching a
+    /// percentage threshold.
+    pub fn votes_against(&self, votes: &Votes) -> Uint128 {
+        match self {
+            AbstainCounting::QuorumAndThreshold {} => votes.no + votes.abstain,
+            _ => votes.no,
+        }
+    }
+}
+
+/// A structured explanation of what a threshold requires of a
+/// proposal's votes. Returned by `Threshold::describe` and
+/// `VotingStrategy::describe`.
+#[cw_serde]
+pub struct ThresholdDescription {
+    /// The votes a proposal must receive in order to pass.
+    pub passing: PassingRequirement,
+    /// The share of total voting power that must cast a vote for
+    /// the proposal to pass, if any.
+    pub quorum: Option<PercentageThreshold>,
+    /// A human readable summary of the above. For example, "more
+    /// than 50% of non-abstaining voting power must vote yes".
+    pub summary: String,
+}
+
+#[cw_serde]
+pub enum PassingRequirement {
+    /// A share of all voting power, less abstentions, must vote yes.
+    ShareOfVotingPower { share: PercentageThreshold },
+    /// A share of the votes cast, less abstentions, must be yes once
+    /// the proposal expires. Before then, the share is of all voting
+    /// power less abstentions.
+    ShareOfVotesCast { share: PercentageThreshold },
+    /// A fixed number of yes votes.
+    Count { count: Uint128 },
+    /// The option with the most votes wins.
+    Plurality {},
+}
+
+impl PercentageThreshold {
+    /// Two thirds of votes.
+    pub fn supermajority() -> Self {
+        PercentageThreshold::Percent(Decimal::from_ratio(2u128, 3u128))
+    }
+
+    /// Describes the share of votes this threshold requires. For
+    /// example, "more than 50%".
+    pub fn describe(&self) -> String {
+        match self {
+            PercentageThreshold::Majority {} => "more than 50%".to_string(),
+            PercentageThreshold::Percent(percent) => {
+                format!("at least {}%", *percent * Decimal::percent(10_000))
+            }
+        }
+    }
+}
+
 /// Asserts that the 0.0 < percent <= 1.0
 fn validate_percentage(percent: &PercentageThreshold) -> Result<(), ThresholdError> {
     if let PercentageThreshold::Percent(percent) = percent {
@@ -98,6 +199,31 @@ pub fn validate_quorum(quorum: &PercentageThreshold) -> Result<(), ThresholdErro
 }
 
 impl Threshold {
+    /// More than half of all voting power, less abstentions, must
+    /// vote yes.
+    pub fn majority() -> Self {
+        Threshold::AbsolutePercentage {
+            percentage: PercentageThreshold::Majority {},
+        }
+    }
+
+    /// Two thirds of all voting power, less abstentions, must vote
+    /// yes.
+    pub fn supermajority() -> Self {
+        Threshold::AbsolutePercentage {
+            percentage: PercentageThreshold::supermajority(),
+        }
+    }
+
+    /// `quorum` of all voting power must vote, and more than half of
+    /// the votes cast, less abstentions, must be yes.
+    pub fn majority_with_quorum(quorum: Decimal) -> Self {
+        Threshold::ThresholdQuorum {
+            threshold: PercentageThreshold::Majority {},
+            quorum: PercentageThreshold::Percent(quorum),
+        }
+    }
+
     /// Validates the threshold.
     ///
     /// - Quorums must never be over 100%.
@@ -121,6 +247,34 @@ impl Threshold {
             }
         }
     }
+
+    /// Explains what this threshold requires of a proposal's votes.
+    pub fn describe(&self) -> ThresholdDescription {
+        match self {
+            Threshold::AbsolutePercentage { percentage } => ThresholdDescription {
+                passing: PassingRequirement::ShareOfVotingPower { share: *percentage },
+                quorum: None,
+                summary: format!(
+                    "{} of non-abstaining voting power must vote yes",
+                    percentage.describe()
+                ),
+            },
+            Threshold::ThresholdQuorum { threshold, quorum } => ThresholdDescription {
+                passing: PassingRequirement::ShareOfVotesCast { share: *threshold },
+                quorum: Some(*quorum),
+                summary: format!(
+                    "{} of voting power must vote, and {} of non-abstaining votes must be yes",
+                    quorum.describe(),
+                    threshold.describe()
+                ),
+            },
+            Threshold::AbsoluteCount { threshold } => ThresholdDescription {
+                passing: PassingRequirement::Count { count: *threshold },
+                quorum: None,
+                summary: format!("at least {} yes votes are required", threshold),
+            },
+        }
+    }
 }
 
 #[cfg(test)]
@@ -180,4 +334,54 @@ mod tests {
             ThresholdError::UnreachableThreshold {}
         );
     }
+
+    #[test]
+    fn test_presets_are_valid() {
+        Threshold::majority().validate().unwrap();
+        Threshold::supermajority().validate().unwrap();
+        Threshold::majority_with_quorum(Decimal::percent(20))
+            .validate()
+            .unwrap();
+        assert_eq!(
+            Threshold::majority_with_quorum(Decimal::percent(101))
+                .validate()
+                .unwrap_err(),
+            ThresholdError::UnreachableThreshold {}
+        );
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(
+            Threshold::majority().describe(),
+            ThresholdDescription {
+                passing: PassingRequirement::ShareOfVotingPower {
+                    share: PercentageThreshold::Majority {}
+                },
+                quorum: None,
+                summary: "more than 50% of non-abstaining voting power must vote yes".to_string(),
+            }
+        );
+
+        let t = Threshold::ThresholdQuorum {
+            threshold: p!(60),
+            quorum: p!(20),
+        };
+        assert_eq!(
+            t.describe(),
+            ThresholdDescription {
+                passing: PassingRequirement::ShareOfVotesCast { share: p!(60) },
+                quorum: Some(p!(20)),
+                summary: "at least 20% of voting power must vote, and at least 60% of non-abstaining votes must be yes".to_string(),
+            }
+        );
+
+        let t = Threshold::AbsoluteCount {
+            threshold: Uint128::new(3),
+        };
+        assert_eq!(
+            t.describe().summary,
+            "at least 3 yes votes are required".to_string()
+        );
+    }
 }
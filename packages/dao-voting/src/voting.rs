@@ -1,7 +1,11 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Decimal, Deps, StdError, StdResult, Uint128, Uint256};
+use cosmwasm_std::{Addr, Decimal, Deps, DepsMut, Order, StdError, StdResult, Uint128, Uint256};
+use cw_storage_plus::Map;
 use cw_utils::Duration;
-use dao_interface::voting;
+use dao_interface::{
+    querier::{CoreQuerier, VotingModuleQuerier},
+    voting,
+};
 
 use crate::threshold::PercentageThreshold;
 
@@ -29,6 +33,39 @@ pub enum Vote {
     Abstain,
 }
 
+/// The order in which proposal modules list the votes cast on a
+/// proposal.
+#[cw_serde]
+#[derive(Copy)]
+pub enum VoteListOrder {
+    /// Ascending by voter address.
+    VoterAscending,
+    /// Descending by voter address.
+    VoterDescending,
+    /// Smallest voting power first. Votes with the same power are
+    /// ordered by voter address.
+    PowerAscending,
+    /// Largest voting power first. Votes with the same power are
+    /// ordered by voter address, descending.
+    PowerDescending,
+}
+
+impl VoteListOrder {
+    /// The order in which storage should be iterated to list votes in
+    /// this order.
+    pub fn storage_order(&self) -> Order {
+        match self {
+            Self::VoterAscending | Self::PowerAscending => Order::Ascending,
+            Self::VoterDescending | Self::PowerDescending => Order::Descending,
+        }
+    }
+
+    /// True if votes are ordered by voting power.
+    pub fn by_power(&self) -> bool {
+        matches!(self, Self::PowerAscending | Self::PowerDescending)
+    }
+}
+
 #[cw_serde]
 pub struct MultipleChoiceVote {
     // A vote indicates which option the user has selected.
@@ -260,6 +297,40 @@ pub fn get_total_power(deps: Deps, dao: Addr, height: Option<u64>) -> StdResult<
     Ok(response.power)
 }
 
+/// Caches the total voting power of a DAO. Voting power at a height
+/// which has been reached does not change, so it is queried from the
+/// voting module once and read from storage afterwards. Power is
+/// keyed by voting module so that a DAO changing its voting module
+/// does not read power cached from the old one. Voting modules which
+/// ignore the height in power queries will have their first response
+/// for a height cached.
+pub struct PowerCache<'a> {
+    total: Map<'a, (Addr, u64), Uint128>,
+}
+
+impl<'a> PowerCache<'a> {
+    pub const fn new(namespace: &'a str) -> Self {
+        Self {
+            total: Map::new(namespace),
+        }
+    }
+
+    /// The total voting power of DAO at HEIGHT. HEIGHT must not be
+    /// greater than the current block height.
+    pub fn total_power(&self, deps: DepsMut, dao: &Addr, height: u64) -> StdResult<Uint128> {
+        let voting_module = CoreQuerier::new(deps.querier, dao.clone()).voting_module()?;
+        let key = (voting_module.clone(), height);
+        if let Some(power) = self.total.may_load(deps.storage, key.clone())? {
+            return Ok(power);
+        }
+        let power = VotingModuleQuerier::new(deps.querier, voting_module)
+            .total_power_at_height(Some(height))?
+            .power;
+        self.total.save(deps.storage, key, &power)?;
+        Ok(power)
+    }
+}
+
 /// Validates that the min voting period is less than the max voting
 /// period. Passes arguments through the function.
 pub fn validate_voting_period(
@@ -284,6 +355,21 @@ pub fn validate_voting_period(
     Ok((min, max))
 }
 
+/// Validates that the delay between a proposal passing and its
+/// execution has the same units as the max voting period. Passes the
+/// delay through the function.
+pub fn validate_execution_delay(
+    delay: Option<Duration>,
+    max_voting_period: Duration,
+) -> Result<Option<Duration>, crate::error::VotingError> {
+    match (delay, max_voting_period) {
+        (None, _)
+        | (Some(Duration::Time(_)), Duration::Time(_))
+        | (Some(Duration::Height(_)), Duration::Height(_)) => Ok(delay),
+        _ => Err(crate::error::VotingError::ExecutionDelayUnitsConflict {}),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -527,4 +613,61 @@ mod test {
 
         assert_eq!(votes, MultipleChoiceVotes::zero(2))
     }
+
+    #[test]
+    fn test_power_cache() {
+        use cosmwasm_std::{
+            testing::mock_dependencies, to_binary, ContractResult, SystemError, SystemResult,
+            WasmQuery,
+        };
+
+        // Mocks a DAO whose voting module is VOTING_MODULE and has
+        // POWER total power, or errors on power queries if None.
+        let mock = |voting_module: &'static str, power: Option<u128>| {
+            move |query: &WasmQuery| match query {
+                WasmQuery::Smart { contract_addr, .. } if contract_addr == "dao" => {
+                    SystemResult::Ok(ContractResult::Ok(
+                        to_binary(&Addr::unchecked(voting_module)).unwrap(),
+                    ))
+                }
+                WasmQuery::Smart { contract_addr, .. }
+                    if contract_addr == voting_module && power.is_some() =>
+                {
+                    SystemResult::Ok(ContractResult::Ok(
+                        to_binary(&voting::TotalPowerAtHeightResponse {
+                            power: Uint128::new(power.unwrap()),
+                            height: 1,
+                        })
+                        .unwrap(),
+                    ))
+                }
+                _ => SystemResult::Err(SystemError::Unknown {}),
+            }
+        };
+
+        let mut deps = mock_dependencies();
+        let cache = PowerCache::new("total");
+        let dao = Addr::unchecked("dao");
+
+        deps.querier.update_wasm(mock("voting", Some(10)));
+        assert_eq!(
+            cache.total_power(deps.as_mut(), &dao, 1).unwrap(),
+            Uint128::new(10)
+        );
+
+        // Once cached, power is not queried again.
+        deps.querier.update_wasm(mock("voting", None));
+        assert_eq!(
+            cache.total_power(deps.as_mut(), &dao, 1).unwrap(),
+            Uint128::new(10)
+        );
+        cache.total_power(deps.as_mut(), &dao, 2).unwrap_err();
+
+        // Power cached from a previous voting module is not used.
+        deps.querier.update_wasm(mock("new_voting", Some(20)));
+        assert_eq!(
+            cache.total_power(deps.as_mut(), &dao, 1).unwrap(),
+            Uint128::new(20)
+        );
+    }
 }
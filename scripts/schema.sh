@@ -1,3 +1,5 @@
+set -e
+
 START_DIR=$(pwd)
 
 # ${f    <-- from variable f
@@ -68,6 +68,7 @@ pub fn execute_proposal_hook(
             count += 1;
             STATUS_CHANGED_COUNTER.save(deps.storage, &count)?;
         }
+        ProposalHookMsg::ProposalUpdated { .. } => (),
     }
 
     Ok(Response::new().add_attribute("action", "proposal_hook"))
@@ -85,6 +86,11 @@ pub fn execute_vote_hook(
             count += 1;
             VOTE_COUNTER.save(deps.storage, &count)?;
         }
+        VoteHookMsg::NewVotes { votes } => {
+            let mut count = VOTE_COUNTER.load(deps.storage)?;
+            count += votes.len() as u64;
+            VOTE_COUNTER.save(deps.storage, &count)?;
+        }
     }
 
     Ok(Response::new().add_attribute("action", "vote_hook"))
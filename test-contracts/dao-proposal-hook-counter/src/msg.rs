@@ -1,17 +1,15 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use dao_proposal_hooks::ProposalHookMsg;
-use dao_vote_hooks::VoteHookMsg;
+use dao_macros::{proposal_hook_consumer, vote_hook_consumer};
 
 #[cw_serde]
 pub struct InstantiateMsg {
     pub should_error: bool, // Debug flag to test when hooks fail over
 }
 
+#[proposal_hook_consumer]
+#[vote_hook_consumer]
 #[cw_serde]
-pub enum ExecuteMsg {
-    ProposalHook(ProposalHookMsg),
-    VoteHook(VoteHookMsg),
-}
+pub enum ExecuteMsg {}
 
 #[cw_serde]
 #[derive(QueryResponses)]
@@ -4,6 +4,7 @@ use cw_hooks::HooksResponse;
 use cw_multi_test::{App, Contract, ContractWrapper, Executor};
 use dao_core::state::ProposalModule;
 use dao_interface::{Admin, ModuleInstantiateInfo};
+use dao_vote_hooks::{VoteHookDispatch, VoteHookDispatchResponse};
 
 use dao_voting::{
     pre_propose::PreProposeInfo,
@@ -149,6 +150,13 @@ fn test_counters() {
         allow_revoting: false,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
+        require_content_cid: None,
+        abstain_counting: None,
+        execution_delay: None,
+        vote_extension: None,
     };
 
     let governance_addr =
@@ -205,6 +213,7 @@ fn test_counters() {
         govmod_single.clone(),
         &dao_proposal_single::msg::ExecuteMsg::AddProposalHook {
             address: counters.to_string(),
+            include_content: false,
         },
         &[],
     )
@@ -253,6 +262,9 @@ fn test_counters() {
             description: "This is a simple text proposal".to_string(),
             msgs: vec![],
             proposer: None,
+            description_hash: None,
+            content_cid: None,
+            disable_revoting: false,
         }),
         &[],
     )
@@ -312,6 +324,7 @@ fn test_counters() {
         govmod_single.clone(),
         &dao_proposal_single::msg::ExecuteMsg::AddProposalHook {
             address: failing_counters.to_string(),
+            include_content: false,
         },
         &[],
     )
@@ -353,6 +366,9 @@ fn test_counters() {
             description: "This is a simple text proposal 2nd".to_string(),
             msgs: vec![],
             proposer: None,
+            description_hash: None,
+            content_cid: None,
+            disable_revoting: false,
         }),
         &[],
     )
@@ -467,3 +483,178 @@ fn test_counters() {
         .unwrap();
     assert_eq!(hooks.hooks.len(), 1);
 }
+
+#[test]
+fn test_batched_vote_hooks() {
+    let mut app = App::default();
+    let govmod_id = app.store_code(single_govmod_contract());
+    let counters_id = app.store_code(counters_contract());
+
+    let instantiate = dao_proposal_single::msg::InstantiateMsg {
+        threshold: Threshold::AbsolutePercentage {
+            percentage: PercentageThreshold::Majority {},
+        },
+        max_voting_period: cw_utils::Duration::Height(6),
+        min_voting_period: None,
+        only_members_execute: false,
+        allow_revoting: false,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        close_proposal_on_execution_failure: true,
+        max_proposal_size: None,
+        max_open_proposals: None,
+        pre_propose_fallback: None,
+        require_content_cid: None,
+        abstain_counting: None,
+        execution_delay: None,
+        vote_extension: None,
+    };
+    let voters = ["ekez", "keze", "zeke"];
+    let governance_addr = instantiate_with_default_governance(
+        &mut app,
+        govmod_id,
+        instantiate,
+        Some(
+            voters
+                .iter()
+                .map(|voter| Cw20Coin {
+                    address: voter.to_string(),
+                    amount: Uint128::new(10),
+                })
+                .collect(),
+        ),
+    );
+    let governance_modules: Vec<ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            governance_addr.clone(),
+            &dao_core::msg::QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let govmod_single = governance_modules.into_iter().next().unwrap().address;
+
+    let counters: Addr = app
+        .instantiate_contract(
+            counters_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &InstantiateMsg {
+                should_error: false,
+            },
+            &[],
+            "counters",
+            None,
+        )
+        .unwrap();
+    app.execute_contract(
+        governance_addr.clone(),
+        govmod_single.clone(),
+        &dao_proposal_single::msg::ExecuteMsg::AddVoteHook {
+            address: counters.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Only the DAO may change how vote hooks are dispatched.
+    let batched = VoteHookDispatch::Batched { max_votes: 2 };
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        govmod_single.clone(),
+        &dao_proposal_single::msg::ExecuteMsg::UpdateVoteHookDispatch {
+            dispatch: batched.clone(),
+        },
+        &[],
+    )
+    .unwrap_err();
+    app.execute_contract(
+        governance_addr,
+        govmod_single.clone(),
+        &dao_proposal_single::msg::ExecuteMsg::UpdateVoteHookDispatch {
+            dispatch: batched.clone(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        govmod_single.clone(),
+        &dao_proposal_single::msg::ExecuteMsg::Propose(ProposeMsg {
+            title: "A simple text proposal".to_string(),
+            description: "This is a simple text proposal".to_string(),
+            msgs: vec![],
+            proposer: None,
+            description_hash: None,
+            content_cid: None,
+            disable_revoting: false,
+        }),
+        &[],
+    )
+    .unwrap();
+
+    let vote_count = |app: &App| -> u64 {
+        let resp: CountResponse = app
+            .wrap()
+            .query_wasm_smart(counters.clone(), &QueryMsg::VoteCounter {})
+            .unwrap();
+        resp.count
+    };
+    let vote = |app: &mut App, voter: &str| {
+        app.execute_contract(
+            Addr::unchecked(voter),
+            govmod_single.clone(),
+            &dao_proposal_single::msg::ExecuteMsg::Vote {
+                proposal_id: 1,
+                vote: Vote::Yes,
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap();
+    };
+
+    // The first vote is buffered.
+    vote(&mut app, voters[0]);
+    assert_eq!(vote_count(&app), 0);
+    let dispatch: VoteHookDispatchResponse = app
+        .wrap()
+        .query_wasm_smart(
+            govmod_single.clone(),
+            &dao_proposal_single::msg::QueryMsg::VoteHookDispatch {},
+        )
+        .unwrap();
+    assert_eq!(
+        dispatch,
+        VoteHookDispatchResponse {
+            dispatch: batched,
+            buffered_votes: 1,
+        }
+    );
+
+    // The second fills the buffer and both votes are sent.
+    vote(&mut app, voters[1]);
+    assert_eq!(vote_count(&app), 2);
+
+    // Anyone may flush votes which have not filled the buffer.
+    vote(&mut app, voters[2]);
+    assert_eq!(vote_count(&app), 2);
+    app.execute_contract(
+        Addr::unchecked("crank"),
+        govmod_single.clone(),
+        &dao_proposal_single::msg::ExecuteMsg::FlushVoteHooks {},
+        &[],
+    )
+    .unwrap();
+    assert_eq!(vote_count(&app), 3);
+
+    let dispatch: VoteHookDispatchResponse = app
+        .wrap()
+        .query_wasm_smart(
+            govmod_single,
+            &dao_proposal_single::msg::QueryMsg::VoteHookDispatch {},
+        )
+        .unwrap();
+    assert_eq!(dispatch.buffered_votes, 0);
+}